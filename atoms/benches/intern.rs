@@ -0,0 +1,84 @@
+#![feature(test)]
+
+extern crate test;
+
+use std::thread;
+use swc_atoms::JsWord;
+use test::{black_box, Bencher};
+
+/// Property names which are not in the static set.
+const DYNAMIC: &[&str] = &[
+    "someLongPropertyName",
+    "anotherPropertyName",
+    "handleClickOutside",
+    "isCurrentlyVisible",
+];
+
+fn intern_all(b: &mut Bencher, words: &[&str]) {
+    b.iter(|| {
+        for _ in 0..100 {
+            for &w in words {
+                black_box(JsWord::from(black_box(w)));
+            }
+        }
+    })
+}
+
+/// Words shorter than 8 bytes are stored inline.
+#[bench]
+fn inline(b: &mut Bencher) {
+    intern_all(b, &["a", "foo", "length", "exports"])
+}
+
+#[bench]
+fn static_set(b: &mut Bencher) {
+    intern_all(
+        b,
+        &[
+            "prototype",
+            "hasOwnProperty",
+            "defineProperty",
+            "__esModule",
+        ],
+    )
+}
+
+#[bench]
+fn dynamic_set(b: &mut Bencher) {
+    // Keep the words alive, so the benchmark measures lookups.
+    let _words: Vec<JsWord> = DYNAMIC.iter().map(|&w| w.into()).collect();
+
+    intern_all(b, DYNAMIC)
+}
+
+#[bench]
+fn dynamic_set_contended(b: &mut Bencher) {
+    let _words: Vec<JsWord> = DYNAMIC.iter().map(|&w| w.into()).collect();
+
+    b.iter(|| {
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                thread::spawn(|| {
+                    for _ in 0..100 {
+                        for &w in DYNAMIC {
+                            black_box(JsWord::from(black_box(w)));
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for t in threads {
+            t.join().unwrap();
+        }
+    })
+}
+
+#[bench]
+fn compare(b: &mut Bencher) {
+    let l: JsWord = DYNAMIC[0].into();
+    let r: JsWord = DYNAMIC[0].into();
+
+    // Interned words are compared by pointer.
+    b.iter(|| black_box(&l) == black_box(&r))
+}
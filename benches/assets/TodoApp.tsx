@@ -0,0 +1,427 @@
+import * as React from "react";
+import { createContext, useCallback, useContext, useEffect, useMemo, useReducer, useRef, useState } from "react";
+
+export type Filter = "all" | "active" | "completed";
+
+export interface Todo {
+    id: string;
+    title: string;
+    completed: boolean;
+    createdAt: number;
+    tags?: string[];
+    assignee?: User | null;
+}
+
+export interface User {
+    id: string;
+    name: string;
+    avatarUrl?: string;
+}
+
+interface State {
+    todos: Todo[];
+    filter: Filter;
+    editing: string | null;
+    loading: boolean;
+    error?: string;
+}
+
+type Action =
+    | { type: "load" }
+    | { type: "loaded"; todos: Todo[] }
+    | { type: "failed"; error: string }
+    | { type: "add"; title: string; tags?: string[] }
+    | { type: "toggle"; id: string }
+    | { type: "toggleAll"; completed: boolean }
+    | { type: "edit"; id: string | null }
+    | { type: "rename"; id: string; title: string }
+    | { type: "remove"; id: string }
+    | { type: "clearCompleted" }
+    | { type: "filter"; filter: Filter };
+
+enum KeyCode {
+    Enter = 13,
+    Escape = 27,
+}
+
+const initialState: State = {
+    todos: [],
+    filter: "all",
+    editing: null,
+    loading: false,
+};
+
+let counter = 0;
+
+function uuid(): string {
+    counter += 1;
+    return `${Date.now().toString(36)}-${counter.toString(36)}`;
+}
+
+export function reducer(state: State, action: Action): State {
+    switch (action.type) {
+        case "load":
+            return { ...state, loading: true, error: undefined };
+        case "loaded":
+            return { ...state, loading: false, todos: action.todos };
+        case "failed":
+            return { ...state, loading: false, error: action.error };
+        case "add": {
+            const title = action.title.trim();
+            if (!title) {
+                return state;
+            }
+            const todo: Todo = {
+                id: uuid(),
+                title,
+                completed: false,
+                createdAt: Date.now(),
+                tags: action.tags ?? [],
+            };
+            return { ...state, todos: [...state.todos, todo] };
+        }
+        case "toggle":
+            return {
+                ...state,
+                todos: state.todos.map((todo) =>
+                    todo.id === action.id ? { ...todo, completed: !todo.completed } : todo
+                ),
+            };
+        case "toggleAll":
+            return {
+                ...state,
+                todos: state.todos.map((todo) => ({ ...todo, completed: action.completed })),
+            };
+        case "edit":
+            return { ...state, editing: action.id };
+        case "rename":
+            return {
+                ...state,
+                editing: null,
+                todos: state.todos
+                    .map((todo) => (todo.id === action.id ? { ...todo, title: action.title.trim() } : todo))
+                    .filter((todo) => todo.title.length > 0),
+            };
+        case "remove":
+            return { ...state, todos: state.todos.filter(({ id }) => id !== action.id) };
+        case "clearCompleted":
+            return { ...state, todos: state.todos.filter((todo) => !todo.completed) };
+        case "filter":
+            return { ...state, filter: action.filter };
+        default:
+            return state;
+    }
+}
+
+export class ApiError extends Error {
+    constructor(public readonly status: number, message: string) {
+        super(message);
+        this.name = "ApiError";
+    }
+}
+
+export class TodoClient {
+    private cache = new Map<string, Todo[]>();
+
+    constructor(private readonly baseUrl: string, private readonly token?: string) {}
+
+    private async request<T>(path: string, init: RequestInit = {}): Promise<T> {
+        const headers: Record<string, string> = {
+            "Content-Type": "application/json",
+            ...(init.headers as Record<string, string> | undefined),
+        };
+        if (this.token) {
+            headers.Authorization = `Bearer ${this.token}`;
+        }
+
+        const res = await fetch(`${this.baseUrl}${path}`, { ...init, headers });
+        if (!res.ok) {
+            throw new ApiError(res.status, await res.text());
+        }
+        return (await res.json()) as T;
+    }
+
+    async list(user?: User): Promise<Todo[]> {
+        const key = user?.id ?? "anonymous";
+        const cached = this.cache.get(key);
+        if (cached) {
+            return cached;
+        }
+
+        const todos = await this.request<Todo[]>(`/todos?user=${encodeURIComponent(key)}`);
+        this.cache.set(key, todos);
+        return todos;
+    }
+
+    async save(todos: readonly Todo[]): Promise<void> {
+        this.cache.clear();
+        await this.request<void>("/todos", { method: "PUT", body: JSON.stringify(todos) });
+    }
+}
+
+interface TodoContextValue {
+    state: State;
+    dispatch: React.Dispatch<Action>;
+    client: TodoClient;
+}
+
+const TodoContext = createContext<TodoContextValue | undefined>(undefined);
+
+export function useTodos(): TodoContextValue {
+    const value = useContext(TodoContext);
+    if (value === undefined) {
+        throw new Error("useTodos() must be used inside of <TodoProvider />");
+    }
+    return value;
+}
+
+function useDebounced<T>(value: T, delay = 300): T {
+    const [debounced, setDebounced] = useState(value);
+    useEffect(() => {
+        const timer = setTimeout(() => setDebounced(value), delay);
+        return () => clearTimeout(timer);
+    }, [value, delay]);
+    return debounced;
+}
+
+export const TodoProvider: React.FC<{ client: TodoClient; user?: User }> = ({ client, user, children }) => {
+    const [state, dispatch] = useReducer(reducer, initialState);
+    const todos = useDebounced(state.todos, 1000);
+    const first = useRef(true);
+
+    useEffect(() => {
+        let cancelled = false;
+        dispatch({ type: "load" });
+        client
+            .list(user)
+            .then((todos) => !cancelled && dispatch({ type: "loaded", todos }))
+            .catch((err: unknown) => {
+                if (!cancelled) {
+                    dispatch({ type: "failed", error: err instanceof Error ? err.message : String(err) });
+                }
+            });
+        return () => {
+            cancelled = true;
+        };
+    }, [client, user?.id]);
+
+    useEffect(() => {
+        if (first.current) {
+            first.current = false;
+            return;
+        }
+        client.save(todos).catch(console.error);
+    }, [client, todos]);
+
+    const value = useMemo(() => ({ state, dispatch, client }), [state, client]);
+    return <TodoContext.Provider value={value}>{children}</TodoContext.Provider>;
+};
+
+const Avatar = ({ user, size = 24 }: { user: User; size?: number }) =>
+    user.avatarUrl ? (
+        <img className="avatar" src={user.avatarUrl} alt={user.name} width={size} height={size} />
+    ) : (
+        <span className="avatar avatar--initials" style={{ width: size, height: size, lineHeight: `${size}px` }}>
+            {user.name
+                .split(/\s+/)
+                .map((part) => part[0])
+                .join("")
+                .toUpperCase()}
+        </span>
+    );
+
+function TodoItem({ todo }: { todo: Todo }) {
+    const { state, dispatch } = useTodos();
+    const [title, setTitle] = useState(todo.title);
+    const input = useRef<HTMLInputElement>(null);
+    const editing = state.editing === todo.id;
+
+    useEffect(() => {
+        if (editing) {
+            input.current?.focus();
+        }
+    }, [editing]);
+
+    const submit = () => dispatch({ type: "rename", id: todo.id, title });
+
+    const onKeyDown = (e: React.KeyboardEvent<HTMLInputElement>) => {
+        switch (e.keyCode) {
+            case KeyCode.Enter:
+                submit();
+                break;
+            case KeyCode.Escape:
+                setTitle(todo.title);
+                dispatch({ type: "edit", id: null });
+                break;
+        }
+    };
+
+    return (
+        <li className={[todo.completed && "completed", editing && "editing"].filter(Boolean).join(" ")}>
+            <div className="view">
+                <input
+                    className="toggle"
+                    type="checkbox"
+                    checked={todo.completed}
+                    onChange={() => dispatch({ type: "toggle", id: todo.id })}
+                />
+                <label onDoubleClick={() => dispatch({ type: "edit", id: todo.id })}>{todo.title}</label>
+                {todo.tags?.length ? (
+                    <ul className="tags">
+                        {todo.tags.map((tag) => (
+                            <li key={tag} className="tag">
+                                #{tag}
+                            </li>
+                        ))}
+                    </ul>
+                ) : null}
+                {todo.assignee && <Avatar user={todo.assignee} />}
+                <button className="destroy" aria-label="Remove" onClick={() => dispatch({ type: "remove", id: todo.id })} />
+            </div>
+            {editing && (
+                <input
+                    ref={input}
+                    className="edit"
+                    value={title}
+                    onBlur={submit}
+                    onChange={(e) => setTitle(e.target.value)}
+                    onKeyDown={onKeyDown}
+                />
+            )}
+        </li>
+    );
+}
+
+const FILTERS: ReadonlyArray<[Filter, string]> = [
+    ["all", "All"],
+    ["active", "Active"],
+    ["completed", "Completed"],
+];
+
+function Footer() {
+    const { state, dispatch } = useTodos();
+    const active = state.todos.filter((todo) => !todo.completed).length;
+    const completed = state.todos.length - active;
+
+    return (
+        <footer className="footer">
+            <span className="todo-count">
+                <strong>{active}</strong> {active === 1 ? "item" : "items"} left
+            </span>
+            <ul className="filters">
+                {FILTERS.map(([filter, label]) => (
+                    <li key={filter}>
+                        <a
+                            href={`#/${filter}`}
+                            className={state.filter === filter ? "selected" : undefined}
+                            onClick={(e) => {
+                                e.preventDefault();
+                                dispatch({ type: "filter", filter });
+                            }}
+                        >
+                            {label}
+                        </a>
+                    </li>
+                ))}
+            </ul>
+            {completed > 0 && (
+                <button className="clear-completed" onClick={() => dispatch({ type: "clearCompleted" })}>
+                    Clear completed
+                </button>
+            )}
+        </footer>
+    );
+}
+
+function NewTodo() {
+    const { dispatch } = useTodos();
+    const [title, setTitle] = useState("");
+
+    const onKeyDown = useCallback(
+        (e: React.KeyboardEvent<HTMLInputElement>) => {
+            if (e.keyCode !== KeyCode.Enter) {
+                return;
+            }
+            const tags = Array.from(title.matchAll(/#(\w+)/g), (m) => m[1]);
+            dispatch({ type: "add", title: title.replace(/#\w+/g, ""), tags });
+            setTitle("");
+        },
+        [dispatch, title]
+    );
+
+    return (
+        <input
+            className="new-todo"
+            placeholder="What needs to be done?"
+            autoFocus
+            value={title}
+            onChange={(e) => setTitle(e.target.value)}
+            onKeyDown={onKeyDown}
+        />
+    );
+}
+
+export function TodoList() {
+    const { state, dispatch } = useTodos();
+    const visible = useMemo(() => {
+        switch (state.filter) {
+            case "active":
+                return state.todos.filter((todo) => !todo.completed);
+            case "completed":
+                return state.todos.filter((todo) => todo.completed);
+            default:
+                return state.todos;
+        }
+    }, [state.todos, state.filter]);
+    const allCompleted = state.todos.every((todo) => todo.completed);
+
+    if (state.loading) {
+        return <p className="loading">Loading…</p>;
+    }
+
+    return (
+        <>
+            {state.error !== undefined && (
+                <p className="error" role="alert">
+                    {state.error}
+                </p>
+            )}
+            <section className="main">
+                <input
+                    id="toggle-all"
+                    className="toggle-all"
+                    type="checkbox"
+                    checked={allCompleted}
+                    onChange={() => dispatch({ type: "toggleAll", completed: !allCompleted })}
+                />
+                <label htmlFor="toggle-all">Mark all as complete</label>
+                <ul className="todo-list">
+                    {visible.map((todo) => (
+                        <TodoItem key={todo.id} todo={todo} />
+                    ))}
+                </ul>
+            </section>
+            {state.todos.length > 0 && <Footer />}
+        </>
+    );
+}
+
+export default function App({ baseUrl, user }: { baseUrl: string; user?: User }) {
+    const client = useMemo(() => new TodoClient(baseUrl), [baseUrl]);
+
+    return (
+        <TodoProvider client={client} user={user}>
+            <section className="todoapp">
+                <header className="header">
+                    <h1>todos</h1>
+                    {user && <Avatar user={user} size={32} />}
+                    <NewTodo />
+                </header>
+                <TodoList />
+            </section>
+            <footer className="info">
+                <p>Double-click to edit a todo</p>
+            </footer>
+        </TodoProvider>
+    );
+}
@@ -0,0 +1,244 @@
+#![feature(test)]
+//! Measures each stage of the pipeline separately, over real-world inputs.
+//!
+//! ```sh
+//! cargo bench --bench stages -- jquery::
+//! ```
+
+extern crate test;
+
+use std::hint::black_box;
+use swc_common::{chain, comments::SingleThreadedComments, sync::Lrc, FileName, Mark, SourceMap};
+use swc_ecma_ast::Module;
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
+use swc_ecma_transforms::{helpers, resolver, typescript};
+use swc_ecma_visit::{Fold, FoldWith};
+use test::Bencher;
+
+fn parse(cm: &Lrc<SourceMap>, syntax: Syntax, src: &'static str) -> Module {
+    let fm = cm.new_source_file(FileName::Anon, src.into());
+    let mut parser = Parser::new(syntax, StringInput::from(&*fm), None);
+    parser
+        .parse_module()
+        .map_err(|err| panic!("failed to parse: {:?}", err))
+        .unwrap()
+}
+
+fn bench_lexer(b: &mut Bencher, syntax: Syntax, src: &'static str) {
+    b.bytes = src.len() as _;
+
+    let _ = testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+
+        b.iter(|| {
+            let lexer = Lexer::new(syntax, Default::default(), StringInput::from(&*fm), None);
+            black_box(lexer.count())
+        });
+
+        Ok(())
+    });
+}
+
+fn bench_parser(b: &mut Bencher, syntax: Syntax, src: &'static str) {
+    b.bytes = src.len() as _;
+
+    let _ = testing::run_test(false, |cm, _| {
+        b.iter(|| black_box(parse(&cm, syntax, src)));
+
+        Ok(())
+    });
+}
+
+fn bench_codegen(b: &mut Bencher, syntax: Syntax, src: &'static str) {
+    b.bytes = src.len() as _;
+
+    let _ = testing::run_test(false, |cm, _| {
+        let module = parse(&cm, syntax, src).fold_with(&mut typescript::strip());
+
+        b.iter(|| {
+            let mut buf = vec![];
+            {
+                let mut emitter = Emitter {
+                    cfg: Default::default(),
+                    comments: None,
+                    cm: cm.clone(),
+                    wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+                };
+                emitter.emit_module(&module).unwrap();
+            }
+            black_box(buf)
+        });
+
+        Ok(())
+    });
+}
+
+/// Benchmarks `tr` on a module which is stripped of types and resolved, like
+/// the input of passes in the real pipeline.
+///
+/// The module is cloned on each iteration, so `base` should be subtracted
+/// from the result.
+fn bench_pass<V>(
+    b: &mut Bencher,
+    syntax: Syntax,
+    src: &'static str,
+    tr: impl Fn(Lrc<SourceMap>) -> V,
+) where
+    V: Fold,
+{
+    b.bytes = src.len() as _;
+
+    let _ = testing::run_test(false, |cm, _| {
+        let module = parse(&cm, syntax, src)
+            .fold_with(&mut typescript::strip())
+            .fold_with(&mut resolver());
+
+        b.iter(|| {
+            let module = module.clone();
+
+            helpers::HELPERS.set(&Default::default(), || {
+                black_box(module.fold_with(&mut tr(cm.clone())))
+            })
+        });
+
+        Ok(())
+    });
+}
+
+fn bench_strip(b: &mut Bencher, syntax: Syntax, src: &'static str) {
+    b.bytes = src.len() as _;
+
+    let _ = testing::run_test(false, |cm, _| {
+        let module = parse(&cm, syntax, src);
+
+        b.iter(|| black_box(module.clone().fold_with(&mut typescript::strip())));
+
+        Ok(())
+    });
+}
+
+macro_rules! stages {
+    ($name:ident, $syntax:expr, $src:literal) => {
+        mod $name {
+            use super::*;
+            use swc_ecma_transforms::{compat, pass::noop, react};
+
+            static SOURCE: &str = include_str!($src);
+
+            fn syntax() -> Syntax {
+                $syntax
+            }
+
+            #[bench]
+            fn lexer(b: &mut Bencher) {
+                bench_lexer(b, syntax(), SOURCE);
+            }
+
+            #[bench]
+            fn parser(b: &mut Bencher) {
+                bench_parser(b, syntax(), SOURCE);
+            }
+
+            #[bench]
+            fn codegen(b: &mut Bencher) {
+                bench_codegen(b, syntax(), SOURCE);
+            }
+
+            #[bench]
+            fn base(b: &mut Bencher) {
+                bench_pass(b, syntax(), SOURCE, |_| noop());
+            }
+
+            #[bench]
+            fn typescript_strip(b: &mut Bencher) {
+                bench_strip(b, syntax(), SOURCE);
+            }
+
+            #[bench]
+            fn resolver(b: &mut Bencher) {
+                bench_pass(b, syntax(), SOURCE, |_| super::resolver());
+            }
+
+            #[bench]
+            fn hygiene(b: &mut Bencher) {
+                bench_pass(b, syntax(), SOURCE, |_| swc_ecma_transforms::hygiene());
+            }
+
+            #[bench]
+            fn fixer(b: &mut Bencher) {
+                bench_pass(b, syntax(), SOURCE, |_| swc_ecma_transforms::fixer(None));
+            }
+
+            #[bench]
+            fn react(b: &mut Bencher) {
+                bench_pass(b, syntax(), SOURCE, |cm| {
+                    react::react(cm, None::<SingleThreadedComments>, Default::default())
+                });
+            }
+
+            #[bench]
+            fn es2020(b: &mut Bencher) {
+                bench_pass(b, syntax(), SOURCE, |_| compat::es2020());
+            }
+
+            #[bench]
+            fn es2018(b: &mut Bencher) {
+                bench_pass(b, syntax(), SOURCE, |_| compat::es2018());
+            }
+
+            #[bench]
+            fn es2017(b: &mut Bencher) {
+                bench_pass(b, syntax(), SOURCE, |_| compat::es2017());
+            }
+
+            #[bench]
+            fn es2016(b: &mut Bencher) {
+                bench_pass(b, syntax(), SOURCE, |_| compat::es2016());
+            }
+
+            #[bench]
+            fn es2015(b: &mut Bencher) {
+                // Class properties are removed by es2020, which runs before es2015.
+                bench_pass(b, syntax(), SOURCE, |_| {
+                    chain!(
+                        compat::es2020::class_properties(),
+                        compat::es2015(Mark::fresh(Mark::root()), None, Default::default())
+                    )
+                });
+            }
+
+            #[bench]
+            fn es3(b: &mut Bencher) {
+                bench_pass(b, syntax(), SOURCE, |_| compat::es3(true));
+            }
+        }
+    };
+}
+
+stages!(
+    angular,
+    Syntax::Es(Default::default()),
+    "../ecmascript/parser/benches/files/angular-1.2.5.js"
+);
+
+stages!(
+    jquery,
+    Syntax::Es(Default::default()),
+    "../ecmascript/parser/benches/files/jquery-1.9.1.js"
+);
+
+stages!(
+    ajax_observable,
+    Syntax::Typescript(Default::default()),
+    "assets/AjaxObservable.ts"
+);
+
+stages!(
+    todo_app,
+    Syntax::Typescript(TsConfig {
+        tsx: true,
+        ..Default::default()
+    }),
+    "assets/TodoApp.tsx"
+);
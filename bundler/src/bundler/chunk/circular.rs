@@ -1,14 +1,16 @@
+use self::lazy::CircularModule;
 use super::{
     merge::{ImportDropper, Unexporter},
     plan::{CircularPlan, Plan},
 };
-use crate::{bundler::load::TransformedModule, util::CHashSet, Bundler, Load, ModuleId, Resolve};
+use crate::{util::CHashSet, Bundler, Load, ModuleId, Resolve};
 use anyhow::{Context, Error};
 use std::borrow::Borrow;
 use swc_common::DUMMY_SP;
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_type, FoldWith, Node, Visit, VisitMutWith, VisitWith};
 
+mod lazy;
 #[cfg(test)]
 mod tests;
 
@@ -41,11 +43,6 @@ where
             .chain(entry_module.exports.reexports.iter().map(|v| v.0.module_id))
             .collect::<Vec<_>>();
 
-        let modules = circular_plan
-            .chunks
-            .iter()
-            .map(|&id| self.scope.get_module(id).unwrap())
-            .collect::<Vec<_>>();
         merged.insert(entry_id);
         let mut entry = self
             .merge_modules(plan, entry_id, false, true, merged)
@@ -58,6 +55,7 @@ where
         let mut deps = circular_plan.chunks.clone();
         deps.sort_by_key(|&dep| (!direct_deps.contains(&dep), dep));
 
+        let mut modules = vec![];
         for dep in deps {
             if dep == entry_id {
                 continue;
@@ -68,48 +66,93 @@ where
             }
             log::debug!("Circular merge: {:?}", dep);
 
-            let new_module = self.merge_two_circular_modules(plan, &modules, entry, dep, merged)?;
+            let module = self
+                .merge_modules(plan, dep, false, true, merged)
+                .context("failed to merge dependency of a cyclic module")?;
+
+            // print_hygiene("[circular] dep:init", &self.cm, &module);
+
+            modules.push((dep, module.fold_with(&mut Unexporter)));
+        }
+
+        let mut members = vec![entry_id];
+        members.extend(modules.iter().map(|v| v.0));
+        let mut order = vec![];
+        self.sort_in_evaluation_order(entry_id, &members, &mut vec![], &mut order);
+        for &id in &members {
+            if !order.contains(&id) {
+                order.push(id);
+            }
+        }
 
-            entry = new_module;
+        let body = self.run(|| {
+            lazy::wrap_lazily(
+                order
+                    .iter()
+                    .map(|&id| {
+                        let body = if id == entry_id {
+                            entry.body.clone()
+                        } else {
+                            modules.iter().find(|v| v.0 == id).unwrap().1.body.clone()
+                        };
+
+                        CircularModule {
+                            name: lazy::module_name(
+                                &self.scope.get_module(id).unwrap().fm.name.to_string(),
+                            ),
+                            body,
+                        }
+                    })
+                    .collect(),
+            )
+        });
 
-            // print_hygiene(
-            //     "[circular] entry:merge_two_circular_modules",
-            //     &self.cm,
-            //     &entry,
-            // );
+        match body {
+            Some(body) => entry.body = body,
+            None => {
+                log::debug!("[circular] Cannot wrap modules; merging them in order");
+
+                for (_, dep) in modules {
+                    entry.body = merge_respecting_order(dep.body, entry.body);
+                }
+            }
         }
 
+        // print_hygiene("[circular] END :merge_circular_modules", &self.cm, &entry);
+
         Ok(entry)
     }
 
-    /// Merges `a` and `b` into one module.
-    fn merge_two_circular_modules(
+    /// Sorts `members` of a cycle in the order a javascript engine evaluates
+    /// them, starting from `id`.
+    fn sort_in_evaluation_order(
         &self,
-        plan: &Plan,
-        _circular_modules: &[TransformedModule],
-        mut entry: Module,
-        dep: ModuleId,
-        merged: &CHashSet<ModuleId>,
-    ) -> Result<Module, Error> {
-        self.run(|| {
-            let mut dep = self
-                .merge_modules(plan, dep, false, true, merged)
-                .context("failed to merge dependency of a cyclic module")?;
-
-            // print_hygiene("[circular] dep:init", &self.cm, &dep);
+        id: ModuleId,
+        members: &[ModuleId],
+        visited: &mut Vec<ModuleId>,
+        order: &mut Vec<ModuleId>,
+    ) {
+        if !members.contains(&id) || visited.contains(&id) {
+            return;
+        }
+        visited.push(id);
 
-            dep = dep.fold_with(&mut Unexporter);
+        let module = self.scope.get_module(id).unwrap();
+        let mut deps = module
+            .imports
+            .specifiers
+            .iter()
+            .map(|v| &v.0)
+            .chain(module.exports.reexports.iter().map(|v| &v.0))
+            .filter(|src| src.is_loaded_synchronously)
+            .collect::<Vec<_>>();
+        deps.sort_by_key(|src| src.src.span.lo());
 
-            // Merge code
-            entry.body = merge_respecting_order(dep.body, entry.body);
+        for src in deps {
+            self.sort_in_evaluation_order(src.module_id, members, visited, order);
+        }
 
-            // print_hygiene(
-            //     "[circular] END :merge_two_circular_modules",
-            //     &self.cm,
-            //     &entry,
-            // );
-            Ok(entry)
-        })
+        order.push(id);
     }
 }
 
@@ -0,0 +1,582 @@
+use std::collections::{HashMap, HashSet};
+use swc_atoms::JsWord;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::{find_ids, ident::IdentLike, private_ident, ExprFactory, Id};
+use swc_ecma_visit::{
+    noop_visit_mut_type, noop_visit_type, Node, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+/// A member of a cycle, which is already merged with its non-circular
+/// dependencies.
+pub(super) struct CircularModule {
+    /// Used to name the generated identifiers.
+    pub name: String,
+    pub body: Vec<ModuleItem>,
+}
+
+/// Concatenates members of a cycle without breaking bindings used before
+/// their module is evaluated.
+///
+/// `modules` should be sorted in evaluation order. A module with a binding
+/// which is used by another member of the cycle is wrapped in a function
+/// which is invoked at most once, and the binding is accessed through a getter
+/// which invokes the function.
+///
+/// ```js
+/// var B;
+/// var _b = {
+///     get B () {
+///         _init_b();
+///         return B;
+///     }
+/// };
+/// var _b_initialized = false;
+/// function _init_b() {
+///     if (_b_initialized) return;
+///     _b_initialized = true;
+///     B = class B extends _a.A {
+///     };
+/// }
+/// ```
+///
+/// Other modules are inlined as is.
+///
+/// Returns [None] if a module which should be wrapped contains code which
+/// cannot be moved into a function, like top-level await or a nested `var`.
+pub(super) fn wrap_lazily(modules: Vec<CircularModule>) -> Option<Vec<ModuleItem>> {
+    let mut owners = HashMap::<Id, usize>::default();
+    let mut keys = HashMap::<Id, JsWord>::default();
+    for (idx, module) in modules.iter().enumerate() {
+        let mut used = HashSet::<JsWord>::default();
+
+        for id in module.body.iter().flat_map(declared_ids) {
+            if owners.contains_key(&id) {
+                continue;
+            }
+
+            // Bindings of inlined dependencies may share a name.
+            let mut key = id.0.clone();
+            let mut n = 1;
+            while !used.insert(key.clone()) {
+                key = format!("{}_{}", id.0, n).into();
+                n += 1;
+            }
+
+            owners.insert(id.clone(), idx);
+            keys.insert(id, key);
+        }
+    }
+
+    let names = modules
+        .iter()
+        .map(|m| {
+            (
+                private_ident!(format!("_{}", m.name)),
+                private_ident!(format!("_{}_initialized", m.name)),
+                private_ident!(format!("_init_{}", m.name)),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut accessed = vec![vec![]; modules.len()];
+    let modules = modules
+        .into_iter()
+        .enumerate()
+        .map(|(idx, mut module)| {
+            module.body.visit_mut_with(&mut Rewriter {
+                idx,
+                owners: &owners,
+                keys: &keys,
+                objs: &names,
+                accessed: &mut accessed,
+            });
+            module
+        })
+        .collect::<Vec<_>>();
+
+    if modules
+        .iter()
+        .zip(&accessed)
+        .any(|(m, accessed)| !accessed.is_empty() && !can_wrap(&m.body))
+    {
+        return None;
+    }
+
+    let mut imports = vec![];
+    let mut preambles = vec![];
+    let mut seq = vec![];
+
+    for (idx, module) in modules.into_iter().enumerate() {
+        let (obj, initialized, init) = names[idx].clone();
+
+        if accessed[idx].is_empty() {
+            for item in module.body {
+                match item {
+                    ModuleItem::ModuleDecl(ModuleDecl::Import(..)) => imports.push(item),
+                    ModuleItem::Stmt(Stmt::Empty(..)) => {}
+                    _ => seq.push(item),
+                }
+            }
+            continue;
+        }
+
+        let mut exported = vec![];
+        let mut hoisted = vec![];
+        let mut lifted = vec![];
+        let mut stmts = vec![];
+        let mut named_exports = vec![];
+
+        for item in module.body {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(..)) => imports.push(item),
+                ModuleItem::Stmt(Stmt::Empty(..)) => {}
+
+                ModuleItem::Stmt(Stmt::Decl(Decl::Fn(..)))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Fn(..),
+                    ..
+                }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(..)) => lifted.push(item),
+
+                // These may be converted to variables reading the exported
+                // bindings, so they should be placed after initialization.
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(..))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportAll(..)) => named_exports.push(item),
+
+                ModuleItem::Stmt(Stmt::Decl(decl)) => {
+                    hoisted.extend(decl_idents(&decl));
+                    stmts.extend(init_stmts(decl));
+                }
+
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+                    exported.extend(decl_idents(&export.decl));
+                    stmts.extend(init_stmts(export.decl));
+                }
+
+                ModuleItem::Stmt(stmt) => stmts.push(stmt),
+
+                ModuleItem::ModuleDecl(decl) => unreachable!("can_wrap() allowed {:?}", decl),
+            }
+        }
+
+        if !exported.is_empty() {
+            preambles.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                span: DUMMY_SP,
+                decl: Decl::Var(var(exported, None)),
+            })));
+        }
+        if !hoisted.is_empty() {
+            preambles.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(var(hoisted, None)))));
+        }
+        preambles.extend(lifted);
+
+        // var _b = { get B () { _init_b(); return B; } };
+        let getters = accessed[idx]
+            .iter()
+            .map(|id: &Id| {
+                PropOrSpread::Prop(Box::new(Prop::Getter(GetterProp {
+                    span: DUMMY_SP,
+                    key: PropName::Ident(Ident::new(keys[id].clone(), DUMMY_SP)),
+                    type_ann: None,
+                    body: Some(BlockStmt {
+                        span: DUMMY_SP,
+                        stmts: vec![
+                            call(init.clone()).into_stmt(),
+                            Stmt::Return(ReturnStmt {
+                                span: DUMMY_SP,
+                                arg: Some(Box::new(Expr::Ident(Ident::new(
+                                    id.0.clone(),
+                                    DUMMY_SP.with_ctxt(id.1),
+                                )))),
+                            }),
+                        ],
+                    }),
+                })))
+            })
+            .collect();
+        preambles.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(var(
+            vec![obj],
+            Some(Box::new(Expr::Object(ObjectLit {
+                span: DUMMY_SP,
+                props: getters,
+            }))),
+        )))));
+
+        // var _b_initialized = false;
+        preambles.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(var(
+            vec![initialized.clone()],
+            Some(Box::new(bool(false))),
+        )))));
+
+        // function _init_b() {
+        //     if (_b_initialized) return;
+        //     _b_initialized = true;
+        //     ...
+        // }
+        let mut body = vec![
+            Stmt::If(IfStmt {
+                span: DUMMY_SP,
+                test: Box::new(Expr::Ident(initialized.clone())),
+                cons: Box::new(Stmt::Return(ReturnStmt {
+                    span: DUMMY_SP,
+                    arg: None,
+                })),
+                alt: None,
+            }),
+            Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: AssignOp::Assign,
+                left: PatOrExpr::Pat(Box::new(Pat::Ident(initialized))),
+                right: Box::new(bool(true)),
+            })
+            .into_stmt(),
+        ];
+        body.extend(stmts);
+        preambles.push(ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl {
+            ident: init.clone(),
+            declare: false,
+            function: Function {
+                params: Default::default(),
+                decorators: Default::default(),
+                span: DUMMY_SP,
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: body,
+                }),
+                is_generator: false,
+                is_async: false,
+                type_params: Default::default(),
+                return_type: Default::default(),
+            },
+        }))));
+
+        seq.push(ModuleItem::Stmt(call(init).into_stmt()));
+        seq.extend(named_exports);
+    }
+
+    imports.extend(preambles);
+    imports.extend(seq);
+    Some(imports)
+}
+
+/// Returns false if `body` cannot be moved into a function.
+fn can_wrap(body: &[ModuleItem]) -> bool {
+    let mut v = WrapChecker { ok: true };
+
+    for item in body {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(..))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(..))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportAll(..)) => {}
+
+            // Functions are hoisted.
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::Fn(..),
+                ..
+            }))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Fn(..),
+                ..
+            }))
+            | ModuleItem::Stmt(Stmt::Decl(Decl::Fn(..))) => {}
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Var(var),
+                ..
+            }))
+            | ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => {
+                // Top-level `var`s are hoisted out of the function.
+                var.decls
+                    .visit_with(&Invalid { span: DUMMY_SP } as _, &mut v)
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Class(class),
+                ..
+            }))
+            | ModuleItem::Stmt(Stmt::Decl(Decl::Class(class))) => {
+                class.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v)
+            }
+
+            ModuleItem::Stmt(Stmt::Decl(..)) | ModuleItem::ModuleDecl(..) => return false,
+
+            ModuleItem::Stmt(stmt) => stmt.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v),
+        }
+
+        if !v.ok {
+            return false;
+        }
+    }
+
+    true
+}
+
+struct WrapChecker {
+    ok: bool,
+}
+
+impl Visit for WrapChecker {
+    noop_visit_type!();
+
+    fn visit_await_expr(&mut self, _: &AwaitExpr, _: &dyn Node) {
+        self.ok = false;
+    }
+
+    fn visit_for_of_stmt(&mut self, s: &ForOfStmt, _: &dyn Node) {
+        if s.await_token.is_some() {
+            self.ok = false;
+            return;
+        }
+
+        s.visit_children_with(self);
+    }
+
+    fn visit_var_decl(&mut self, v: &VarDecl, _: &dyn Node) {
+        if v.kind == VarDeclKind::Var {
+            self.ok = false;
+            return;
+        }
+
+        v.visit_children_with(self);
+    }
+
+    /// `await` and `var` in functions are not affected.
+    #[inline]
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+    #[inline]
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+    #[inline]
+    fn visit_constructor(&mut self, _: &Constructor, _: &dyn Node) {}
+
+    #[inline]
+    fn visit_getter_prop(&mut self, _: &GetterProp, _: &dyn Node) {}
+
+    #[inline]
+    fn visit_setter_prop(&mut self, _: &SetterProp, _: &dyn Node) {}
+}
+
+/// Top-level bindings which are not hoisted with their value.
+fn declared_ids(item: &ModuleItem) -> Vec<Id> {
+    match item {
+        ModuleItem::Stmt(Stmt::Decl(decl))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+            decl_idents(decl).iter().map(Ident::to_id).collect()
+        }
+        _ => vec![],
+    }
+}
+
+fn decl_idents(decl: &Decl) -> Vec<Ident> {
+    match decl {
+        Decl::Class(ClassDecl { ident, .. }) => vec![ident.clone()],
+        Decl::Var(var) => find_ids(var),
+        _ => vec![],
+    }
+}
+
+/// Converts a declaration to assignments to the hoisted bindings.
+fn init_stmts(decl: Decl) -> Vec<Stmt> {
+    match decl {
+        // B = class B {}
+        Decl::Class(ClassDecl { ident, class, .. }) => vec![Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: AssignOp::Assign,
+            left: PatOrExpr::Pat(Box::new(Pat::Ident(ident.clone()))),
+            right: Box::new(Expr::Class(ClassExpr {
+                ident: Some(ident),
+                class,
+            })),
+        })
+        .into_stmt()],
+
+        // a = 1; ({ b } = c);
+        Decl::Var(var) => var
+            .decls
+            .into_iter()
+            .filter_map(|decl| {
+                let init = decl.init?;
+                Some(
+                    Expr::Assign(AssignExpr {
+                        span: decl.span,
+                        op: AssignOp::Assign,
+                        left: PatOrExpr::Pat(Box::new(decl.name)),
+                        right: init,
+                    })
+                    .into_stmt(),
+                )
+            })
+            .collect(),
+
+        _ => unreachable!("can_wrap() allowed {:?}", decl),
+    }
+}
+
+fn var(ids: Vec<Ident>, init: Option<Box<Expr>>) -> VarDecl {
+    VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Var,
+        declare: false,
+        decls: ids
+            .into_iter()
+            .map(|id| VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(id),
+                init: init.clone(),
+                definite: false,
+            })
+            .collect(),
+    }
+}
+
+fn call(callee: Ident) -> Expr {
+    Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: callee.as_callee(),
+        args: vec![],
+        type_args: None,
+    })
+}
+
+fn bool(value: bool) -> Expr {
+    Expr::Lit(Lit::Bool(Bool {
+        span: DUMMY_SP,
+        value,
+    }))
+}
+
+/// Replaces references to bindings of other modules with getters.
+struct Rewriter<'a> {
+    idx: usize,
+    owners: &'a HashMap<Id, usize>,
+    keys: &'a HashMap<Id, JsWord>,
+    objs: &'a [(Ident, Ident, Ident)],
+    /// Bindings of each module used by other modules, in order of appearance.
+    accessed: &'a mut Vec<Vec<Id>>,
+}
+
+impl Rewriter<'_> {
+    /// Returns `_a.A` if `i` is a binding of another member of the cycle.
+    fn getter(&mut self, i: &Ident) -> Option<Expr> {
+        let id = i.to_id();
+        let owner = *self.owners.get(&id)?;
+        if owner == self.idx {
+            return None;
+        }
+
+        let key = Ident::new(self.keys[&id].clone(), DUMMY_SP);
+        if !self.accessed[owner].contains(&id) {
+            self.accessed[owner].push(id);
+        }
+
+        Some(self.objs[owner].0.clone().make_member(key))
+    }
+}
+
+impl VisitMut for Rewriter<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module_item(&mut self, item: &mut ModuleItem) {
+        match item {
+            // Specifiers are not references.
+            ModuleItem::ModuleDecl(ModuleDecl::Import(..))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(..))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportAll(..)) => {}
+            _ => item.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        match e {
+            Expr::Ident(i) => {
+                if let Some(getter) = self.getter(i) {
+                    *e = getter;
+                }
+            }
+            _ => e.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_call_expr(&mut self, e: &mut CallExpr) {
+        if let ExprOrSuper::Expr(callee) = &mut e.callee {
+            let getter = match &**callee {
+                Expr::Ident(i) => self.getter(i),
+                _ => None,
+            };
+
+            match getter {
+                // Use `(0, _a.foo)()` so `this` is not `_a`.
+                Some(getter) => {
+                    *callee = Box::new(
+                        Expr::Seq(SeqExpr {
+                            span: DUMMY_SP,
+                            exprs: vec![
+                                Box::new(Expr::Lit(Lit::Num(Number {
+                                    span: DUMMY_SP,
+                                    value: 0.0,
+                                    raw: None,
+                                }))),
+                                Box::new(getter),
+                            ],
+                        })
+                        .wrap_with_paren(),
+                    )
+                }
+                None => callee.visit_mut_with(self),
+            }
+        }
+
+        e.args.visit_mut_with(self);
+    }
+
+    fn visit_mut_member_expr(&mut self, e: &mut MemberExpr) {
+        e.obj.visit_mut_with(self);
+
+        if e.computed {
+            e.prop.visit_mut_with(self);
+        }
+    }
+
+    fn visit_mut_prop(&mut self, p: &mut Prop) {
+        match p {
+            Prop::Shorthand(i) => {
+                if let Some(getter) = self.getter(i) {
+                    *p = Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident(i.clone()),
+                        value: Box::new(getter),
+                    });
+                }
+            }
+            _ => p.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_prop_name(&mut self, n: &mut PropName) {
+        match n {
+            PropName::Computed(..) => n.visit_mut_children_with(self),
+            _ => {}
+        }
+    }
+}
+
+/// Used to name generated identifiers after a module.
+pub(super) fn module_name(file: &str) -> String {
+    let stem = file
+        .rsplit(|c: char| c == '/' || c == '\\')
+        .next()
+        .unwrap_or(file);
+    let stem = stem.split('.').next().unwrap_or(stem);
+
+    let name = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+
+    if name.is_empty() {
+        return "mod".into();
+    }
+
+    name
+}
@@ -1,5 +1,8 @@
 use super::*;
+use crate::bundler::tests::suite;
+use std::{collections::HashMap, process::Command};
 use swc_common::{sync::Lrc, FileName, SourceMap};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
 use swc_ecma_parser::{lexer::Lexer, JscTarget, Parser, StringInput, Syntax};
 use swc_ecma_utils::drop_span;
 use testing::assert_eq;
@@ -81,3 +84,110 @@ fn dep_index_class() {
 fn dep_index_export_class() {
     assert_dependency_index("class A extends B {}", "export class B {}", 0);
 }
+
+/// Bundles `files` and runs the bundle of `main.js` with node.
+#[track_caller]
+fn assert_stdout(files: &[(&str, &str)], expected: &str) {
+    let mut builder = suite();
+    for (name, src) in files {
+        builder = builder.file(name, src);
+    }
+
+    builder.run(|t| {
+        let mut entries = HashMap::default();
+        entries.insert("main.js".to_string(), FileName::Real("main.js".into()));
+
+        let bundles = t.bundler.bundle(entries)?;
+        assert_eq!(bundles.len(), 1);
+
+        let mut buf = vec![];
+        {
+            Emitter {
+                cfg: Default::default(),
+                cm: t.cm.clone(),
+                comments: None,
+                wr: Box::new(JsWriter::new(t.cm.clone(), "\n", &mut buf, None)),
+            }
+            .emit_module(&bundles[0].module)?;
+        }
+        let code = String::from_utf8(buf)?;
+
+        let output = Command::new("node").arg("-e").arg(&code).output()?;
+        assert!(
+            output.status.success(),
+            "{}\n{}",
+            code,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            expected,
+            "{}",
+            code
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn exec_binding_used_while_evaluating_cycle() {
+    assert_stdout(
+        &[
+            (
+                "main.js",
+                "import { getB } from './a'; console.log(getB());",
+            ),
+            (
+                "a.js",
+                "
+                import { b } from './b';
+                export const a = 'a';
+                export function getB() {
+                    return b;
+                }
+                ",
+            ),
+            ("b.js", "import { a } from './a'; export const b = a + 'b';"),
+        ],
+        "ab\n",
+    );
+}
+
+#[test]
+fn exec_class_extending_class_of_cycle() {
+    assert_stdout(
+        &[
+            (
+                "main.js",
+                "import { A } from './a'; console.log(new A().method().name());",
+            ),
+            (
+                "a.js",
+                "
+                import { B } from './b';
+                export class A {
+                    method() {
+                        return new B();
+                    }
+                    name() {
+                        return 'A';
+                    }
+                }
+                ",
+            ),
+            (
+                "b.js",
+                "
+                import { A } from './a';
+                export class B extends A {
+                    name() {
+                        return 'B' + super.name();
+                    }
+                }
+                ",
+            ),
+        ],
+        "BA\n",
+    );
+}
@@ -1,12 +1,10 @@
 use super::plan::{NormalPlan, Plan};
 use crate::{
     bundler::load::{Source, Specifier, TransformedModule},
-    util::{CHashSet, IntoParallelIterator},
+    util::CHashSet,
     Bundler, Load, ModuleId, Resolve,
 };
 use anyhow::{Context, Error};
-#[cfg(feature = "concurrent")]
-use rayon::iter::ParallelIterator;
 use std::{
     collections::HashMap,
     mem::{replace, take},
@@ -148,8 +146,9 @@ where
             };
         }
 
+        // Merged in order, because the first module to merge a dependency owns it.
         let deps = reexports
-            .into_par_iter()
+            .into_iter()
             .map(|(src, specifiers)| -> Result<_, Error> {
                 let imported = self.scope.get_module(src.module_id).unwrap();
                 assert!(imported.is_es6, "Reexports are es6 only");
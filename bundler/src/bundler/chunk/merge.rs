@@ -4,12 +4,9 @@ use crate::{
     id::ModuleId,
     load::Load,
     resolve::Resolve,
-    util::{self, IntoParallelIterator},
-    Bundler, Hook, ModuleRecord,
+    util, Bundler, Hook, ModuleRecord,
 };
 use anyhow::{Context, Error};
-#[cfg(feature = "concurrent")]
-use rayon::iter::ParallelIterator;
 use retain_mut::RetainMut;
 use std::{borrow::Cow, mem::take};
 use swc_atoms::js_word;
@@ -132,142 +129,130 @@ where
             })
             .collect();
 
-        let (deps, transitive_deps) = util::join(
-            || {
-                to_merge
-                    .into_par_iter()
-                    .map(|(src, specifiers)| -> Result<Option<_>, Error> {
-                        self.run(|| {
-                            let dep_info = self.scope.get_module(src.module_id).unwrap();
-                            info.helpers.extend(&dep_info.helpers);
-                            info.swc_helpers.extend_from(&dep_info.swc_helpers);
-
-                            if !merged.insert(src.module_id) {
-                                log::debug!("Skipping: {} <= {}", info.fm.name, src.src.value);
-                                return Ok(None);
-                            }
+        // Dependencies are merged in order, because the first module to merge a
+        // dependency owns it.
+        let deps = to_merge
+            .into_iter()
+            .map(|(src, specifiers)| -> Result<Option<_>, Error> {
+                self.run(|| {
+                    let dep_info = self.scope.get_module(src.module_id).unwrap();
+                    info.helpers.extend(&dep_info.helpers);
+                    info.swc_helpers.extend_from(&dep_info.swc_helpers);
+
+                    if !merged.insert(src.module_id) {
+                        log::debug!("Skipping: {} <= {}", info.fm.name, src.src.value);
+                        return Ok(None);
+                    }
 
-                            log::debug!("Merging: {} <= {}", info.fm.name, src.src.value);
-
-                            // In the case of
-                            //
-                            //  a <- b
-                            //  b <- c
-                            //
-                            // we change it to
-                            //
-                            // a <- b + chunk(c)
-                            //
-                            let mut dep = self
-                                .merge_modules(plan, src.module_id, false, false, merged)
-                                .with_context(|| {
-                                    format!(
-                                        "failed to merge: ({}):{} <= ({}):{}",
-                                        info.id, info.fm.name, src.module_id, src.src.value
-                                    )
-                                })?;
-
-                            if dep_info.is_es6 {
-                                // print_hygiene("dep:before:tree-shaking", &self.cm, &dep);
-
-                                let is_acccessed_with_computed_key =
-                                    self.scope.should_be_wrapped_with_a_fn(dep_info.id);
-
-                                // If an import with a computed key exists, we can't shake tree
-                                if is_acccessed_with_computed_key {
-                                    let id = specifiers
-                                        .iter()
-                                        .find_map(|s| match s {
-                                            Specifier::Namespace { local, all: true } => {
-                                                Some(local)
-                                            }
-                                            _ => None,
-                                        })
-                                        .unwrap();
-
-                                    dep = self.wrap_esm_as_a_var(
-                                        plan,
-                                        dep,
-                                        &dep_info,
-                                        merged,
-                                        id.clone().replace_mark(dep_info.mark()).into_ident(),
-                                    )?;
-                                } else {
-                                    if let Some(imports) = info
-                                        .imports
-                                        .specifiers
-                                        .iter()
-                                        .find(|(s, _)| s.module_id == dep_info.id)
-                                        .map(|v| &v.1)
-                                    {
-                                        // print_hygiene(
-                                        //     "dep: before remarking exports",
-                                        //     &self.cm,
-                                        //     &dep,
-                                        // );
-
-                                        dep = self.remark_exports(
-                                            dep,
-                                            dep_info.ctxt(),
-                                            Some(&imports),
-                                            true,
-                                        );
-                                    }
-                                }
-                                // print_hygiene("dep:after:tree-shaking", &self.cm, &dep);
-
-                                // if let Some(imports) = info
-                                //     .imports
-                                //     .specifiers
-                                //     .iter()
-                                //     .find(|(s, _)| s.module_id == dep_info.id)
-                                //     .map(|v| &v.1)
-                                // {
-                                //     dep = dep.fold_with(&mut ExportRenamer {
-                                //         mark: dep_info.mark(),
-                                //         _exports: &dep_info.exports,
-                                //         imports: &imports,
-                                //         extras: vec![],
-                                //     });
-                                // }
-                                // print_hygiene("dep:after:export-renamer", &self.cm, &dep);
-
-                                dep = dep.fold_with(&mut Unexporter);
+                    log::debug!("Merging: {} <= {}", info.fm.name, src.src.value);
+
+                    // In the case of
+                    //
+                    //  a <- b
+                    //  b <- c
+                    //
+                    // we change it to
+                    //
+                    // a <- b + chunk(c)
+                    //
+                    let mut dep = self
+                        .merge_modules(plan, src.module_id, false, false, merged)
+                        .with_context(|| {
+                            format!(
+                                "failed to merge: ({}):{} <= ({}):{}",
+                                info.id, info.fm.name, src.module_id, src.src.value
+                            )
+                        })?;
+
+                    if dep_info.is_es6 {
+                        // print_hygiene("dep:before:tree-shaking", &self.cm, &dep);
+
+                        let is_acccessed_with_computed_key =
+                            self.scope.should_be_wrapped_with_a_fn(dep_info.id);
+
+                        // If an import with a computed key exists, we can't shake tree
+                        if is_acccessed_with_computed_key {
+                            let id = specifiers
+                                .iter()
+                                .find_map(|s| match s {
+                                    Specifier::Namespace { local, all: true } => Some(local),
+                                    _ => None,
+                                })
+                                .unwrap();
+
+                            dep = self.wrap_esm_as_a_var(
+                                plan,
+                                dep,
+                                &dep_info,
+                                merged,
+                                id.clone().replace_mark(dep_info.mark()).into_ident(),
+                            )?;
+                        } else {
+                            if let Some(imports) = info
+                                .imports
+                                .specifiers
+                                .iter()
+                                .find(|(s, _)| s.module_id == dep_info.id)
+                                .map(|v| &v.1)
+                            {
+                                // print_hygiene(
+                                //     "dep: before remarking exports",
+                                //     &self.cm,
+                                //     &dep,
+                                // );
+
+                                dep =
+                                    self.remark_exports(dep, dep_info.ctxt(), Some(imports), true);
                             }
-                            // print_hygiene("dep:before-injection", &self.cm, &dep);
-
-                            Ok(Some((dep, dep_info)))
-                        })
-                    })
-                    .collect::<Vec<_>>()
-            },
-            || {
-                let deps = module_plan
-                    .transitive_chunks
-                    .clone()
-                    .into_par_iter()
-                    .map(|id| -> Result<_, Error> {
-                        if !merged.insert(id) {
-                            return Ok(None);
                         }
+                        // print_hygiene("dep:after:tree-shaking", &self.cm, &dep);
+
+                        // if let Some(imports) = info
+                        //     .imports
+                        //     .specifiers
+                        //     .iter()
+                        //     .find(|(s, _)| s.module_id == dep_info.id)
+                        //     .map(|v| &v.1)
+                        // {
+                        //     dep = dep.fold_with(&mut ExportRenamer {
+                        //         mark: dep_info.mark(),
+                        //         _exports: &dep_info.exports,
+                        //         imports: &imports,
+                        //         extras: vec![],
+                        //     });
+                        // }
+                        // print_hygiene("dep:after:export-renamer", &self.cm, &dep);
 
-                        let dep_info = self.scope.get_module(id).unwrap();
-                        let mut dep = self.merge_modules(plan, id, false, true, merged)?;
+                        dep = dep.fold_with(&mut Unexporter);
+                    }
+                    // print_hygiene("dep:before-injection", &self.cm, &dep);
+
+                    Ok(Some((dep, dep_info)))
+                })
+            })
+            .collect::<Vec<_>>();
+        let transitive_deps = module_plan
+            .transitive_chunks
+            .clone()
+            .into_iter()
+            .map(|id| -> Result<_, Error> {
+                if !merged.insert(id) {
+                    return Ok(None);
+                }
 
-                        // print_hygiene("transitive dep", &self.cm, &dep);
+                let dep_info = self.scope.get_module(id).unwrap();
+                let mut dep = self.merge_modules(plan, id, false, true, merged)?;
 
-                        dep = self.remark_exports(dep, dep_info.ctxt(), None, true);
-                        dep = dep.fold_with(&mut Unexporter);
+                // print_hygiene("transitive dep", &self.cm, &dep);
 
-                        // As transitive deps can have no direct relation with entry,
-                        // remark_exports is not enough.
-                        Ok(Some((dep, dep_info)))
-                    })
-                    .collect::<Vec<_>>();
+                dep = self.remark_exports(dep, dep_info.ctxt(), None, true);
+                dep = dep.fold_with(&mut Unexporter);
 
-                deps
-            },
-        );
+                // As transitive deps can have no direct relation with entry,
+                // remark_exports is not enough.
+                Ok(Some((dep, dep_info)))
+            })
+            .collect::<Vec<_>>();
 
         let mut targets = module_plan.chunks.clone();
 
@@ -1,8 +1,6 @@
 use super::{load::TransformedModule, Bundler};
-use crate::{id::ModuleId, load::Load, resolve::Resolve, util::IntoParallelIterator, Bundle};
+use crate::{id::ModuleId, load::Load, resolve::Resolve, Bundle};
 use anyhow::{Context, Error};
-#[cfg(feature = "rayon")]
-use rayon::iter::ParallelIterator;
 use std::collections::{HashMap, HashSet};
 
 mod circular;
@@ -45,8 +43,11 @@ where
         let plan = self.determine_entries(entries).context("failed to plan")?;
         let merged = Default::default();
 
-        Ok((&*plan.entries)
-            .into_par_iter()
+        // Bundles share `merged`, so they are merged one by one to make the output
+        // independent of thread scheduling.
+        Ok(plan
+            .entries
+            .iter()
             .map(|&entry| {
                 self.run(|| {
                     let kind = plan
@@ -76,8 +77,9 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::bundler::tests::suite;
+    use crate::{bundler::tests::suite, BundleKind};
     use swc_common::FileName;
+    use swc_ecma_ast::*;
 
     #[test]
     fn cjs_chunk() {
@@ -106,4 +108,252 @@ mod tests {
                 Ok(())
             });
     }
+
+    #[test]
+    fn es_modules_are_hoisted() {
+        suite()
+            .file(
+                "main.js",
+                "
+                import { a } from './a';
+                import { b } from './b';
+                const x = 0;
+                console.log(a(), b(), x);
+                ",
+            )
+            .file("a.js", "const x = 1; export function a() { return x; }")
+            .file("b.js", "const x = 2; export function b() { return x; }")
+            .run(|t| {
+                let mut entries = HashMap::default();
+                entries.insert("main.js".to_string(), FileName::Real("main.js".into()));
+
+                let bundles = t.bundler.bundle(entries)?;
+                assert_eq!(bundles.len(), 1);
+
+                let mut names = HashSet::new();
+                for item in &bundles[0].module.body {
+                    match item {
+                        ModuleItem::Stmt(Stmt::Decl(Decl::Var(v))) => {
+                            for decl in &v.decls {
+                                if let Pat::Ident(i) = &decl.name {
+                                    assert!(
+                                        names.insert(i.sym.clone()),
+                                        "{} is declared twice",
+                                        i.sym
+                                    );
+                                }
+                            }
+                        }
+                        ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f))) => {
+                            assert!(
+                                names.insert(f.ident.sym.clone()),
+                                "{} is declared twice",
+                                f.ident.sym
+                            );
+                        }
+                        ModuleItem::Stmt(_) => {}
+                        ModuleItem::ModuleDecl(decl) => {
+                            panic!("modules should be merged into one scope: {:?}", decl)
+                        }
+                    }
+                }
+                assert!(names.contains(&"a".into()) && names.contains(&"b".into()));
+
+                Ok(())
+            });
+    }
+
+    #[test]
+    fn dynamic_import_chunk() {
+        suite()
+            .file(
+                "main.js",
+                "
+                import('./a').then(({ a }) => a());
+                ",
+            )
+            .file("a.js", "export function a() {}")
+            .run(|t| {
+                let mut entries = HashMap::default();
+                entries.insert("main.js".to_string(), FileName::Real("main.js".into()));
+
+                let (bundles, manifest) = t.bundler.bundle_with_manifest(entries)?;
+                assert_eq!(bundles.len(), 2);
+
+                let chunk = &manifest["a.js"];
+                assert!(
+                    chunk.starts_with("a-") && chunk.ends_with(".js"),
+                    "{}",
+                    chunk
+                );
+
+                let main = bundles
+                    .iter()
+                    .find(|b| {
+                        b.kind
+                            == BundleKind::Named {
+                                name: "main.js".into(),
+                            }
+                    })
+                    .unwrap();
+                let src = match &main.module.body[..] {
+                    [ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. }))] => match &**expr {
+                        Expr::Call(CallExpr {
+                            callee: ExprOrSuper::Expr(callee),
+                            ..
+                        }) => match &**callee {
+                            Expr::Member(MemberExpr {
+                                obj: ExprOrSuper::Expr(obj),
+                                ..
+                            }) => match &**obj {
+                                Expr::Call(CallExpr { args, .. }) => match &*args[0].expr {
+                                    Expr::Lit(Lit::Str(s)) => s.value.to_string(),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            },
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    },
+                    body => unreachable!("{:?}", body),
+                };
+                assert_eq!(src, format!("./{}", chunk));
+
+                Ok(())
+            });
+    }
+
+    #[test]
+    fn module_files() {
+        suite()
+            .file("main.js", "import { a } from './a'; console.log(a);")
+            .file("other.js", "export { b } from './b';")
+            .file(
+                "a.js",
+                "export * from './b'; export const a = import('./c');",
+            )
+            .file("b.js", "export const b = 1;")
+            .file("c.js", "export const c = 1;")
+            .run(|t| {
+                let mut entries = HashMap::default();
+                entries.insert("main.js".to_string(), FileName::Real("main.js".into()));
+                entries.insert("other.js".to_string(), FileName::Real("other.js".into()));
+                t.bundler.bundle(entries)?;
+
+                let files = |entry: &str| {
+                    let mut files = t
+                        .bundler
+                        .module_files(&FileName::Real(entry.into()))
+                        .into_iter()
+                        .map(|f| f.to_string())
+                        .collect::<Vec<_>>();
+                    files.sort();
+                    files
+                };
+                assert_eq!(files("main.js"), vec!["a.js", "b.js", "c.js", "main.js"]);
+                assert_eq!(files("other.js"), vec!["b.js", "other.js"]);
+
+                Ok(())
+            });
+    }
+
+    #[test]
+    fn side_effect_free_imports() {
+        suite()
+            .file("main.js", "import './a'; import './b'; console.log(1);")
+            .file("a.js", "console.log('a');")
+            .file("b.js", "console.log('b');")
+            .pure_file("a.js")
+            .run(|t| {
+                let mut entries = HashMap::default();
+                entries.insert("main.js".to_string(), FileName::Real("main.js".into()));
+                t.bundler.bundle(entries)?;
+
+                let mut files = t
+                    .bundler
+                    .module_files(&FileName::Real("main.js".into()))
+                    .into_iter()
+                    .map(|f| f.to_string())
+                    .collect::<Vec<_>>();
+                files.sort();
+                assert_eq!(files, vec!["b.js", "main.js"]);
+
+                Ok(())
+            });
+    }
+
+    #[test]
+    fn rebundle() {
+        suite()
+            .file("main.js", "import { a } from './a'; console.log(a);")
+            .file("other.js", "export { b } from './b';")
+            .file("a.js", "export * from './b'; export const a = 1;")
+            .file("b.js", "export const b = 1;")
+            .run(|t| {
+                let entries = || {
+                    let mut entries = HashMap::default();
+                    entries.insert("main.js".to_string(), FileName::Real("main.js".into()));
+                    entries.insert("other.js".to_string(), FileName::Real("other.js".into()));
+                    entries
+                };
+                t.bundler.bundle(entries())?;
+
+                let names = |changed: &str| -> Result<Vec<String>, Error> {
+                    let (bundles, _) = t
+                        .bundler
+                        .rebundle(entries(), &[FileName::Real(changed.into())])?;
+                    let mut names = bundles
+                        .into_iter()
+                        .filter_map(|b| match b.kind {
+                            BundleKind::Named { name } => Some(name),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>();
+                    names.sort();
+                    Ok(names)
+                };
+                assert_eq!(names("a.js")?, vec!["main.js"]);
+                assert_eq!(names("b.js")?, vec!["main.js", "other.js"]);
+                assert!(names("c.js")?.is_empty());
+
+                Ok(())
+            });
+    }
+
+    #[test]
+    fn node_scheme_is_external() {
+        suite()
+            .file(
+                "main.js",
+                "
+                import { readFile } from 'node:fs';
+                import { a } from './a';
+                console.log(readFile, a, require('node:path'));
+                ",
+            )
+            .file("a.js", "export const a = 1;")
+            .run(|t| {
+                let mut entries = HashMap::default();
+                entries.insert("main.js".to_string(), FileName::Real("main.js".into()));
+
+                let bundles = t.bundler.bundle(entries)?;
+                assert_eq!(bundles.len(), 1);
+
+                let imports = bundles[0]
+                    .module
+                    .body
+                    .iter()
+                    .filter_map(|item| match item {
+                        ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                            Some(&*import.src.value)
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                assert_eq!(imports, vec!["node:fs"]);
+
+                Ok(())
+            });
+    }
 }
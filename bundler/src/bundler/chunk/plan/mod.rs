@@ -36,6 +36,13 @@ struct PlanBuilder {
 
     circular: Circulars,
 
+    /// Import cycles, which start and end with the same module.
+    cycles: Vec<Vec<ModuleId>>,
+
+    /// Modules imported with `import()`, which are not merged into the
+    /// importer but emitted as separate chunks.
+    dynamic_entries: Vec<ModuleId>,
+
     kinds: HashMap<ModuleId, BundleKind>,
 }
 
@@ -84,6 +91,24 @@ impl PlanBuilder {
         self.circular.push(set);
     }
 
+    /// Records `cycle` unless the same cycle, starting from another module,
+    /// is already recorded.
+    fn add_cycle(&mut self, mut cycle: Vec<ModuleId>) {
+        cycle.pop();
+        let start = cycle
+            .iter()
+            .enumerate()
+            .min_by_key(|&(_, id)| id)
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        cycle.rotate_left(start);
+        cycle.push(cycle[0]);
+
+        if !self.cycles.contains(&cycle) {
+            self.cycles.push(cycle);
+        }
+    }
+
     fn is_circular(&self, id: ModuleId) -> bool {
         for set in self.circular.iter() {
             if set.contains(&id) {
@@ -104,6 +129,9 @@ pub(super) struct Plan {
     /// key is entry
     pub circular: HashMap<ModuleId, CircularPlan>,
 
+    /// Import cycles, which start and end with the same module.
+    pub cycles: Vec<Vec<ModuleId>>,
+
     pub bundle_kinds: HashMap<ModuleId, BundleKind>,
 }
 
@@ -152,6 +180,14 @@ where
         let plan = self.calculate_plan(entries)?;
         let plan = self.handle_duplicates(plan);
 
+        for cycle in &plan.cycles {
+            let names = cycle
+                .iter()
+                .map(|&id| self.scope.get_module(id).unwrap().fm.name.clone())
+                .collect::<Vec<_>>();
+            self.hook.on_circular_dependency(&names);
+        }
+
         Ok(plan)
     }
 
@@ -164,6 +200,9 @@ where
     fn calculate_plan(&self, entries: HashMap<String, TransformedModule>) -> Result<Plan, Error> {
         let mut builder = PlanBuilder::default();
 
+        let mut entries = entries.into_iter().collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
         for (name, module) in entries {
             match builder.kinds.insert(module.id, BundleKind::Named { name }) {
                 Some(v) => bail!("Multiple entries with same input path detected: {:?}", v),
@@ -173,6 +212,15 @@ where
             self.add_to_graph(&mut builder, module.id, &mut vec![], true);
         }
 
+        while let Some(id) = builder.dynamic_entries.pop() {
+            if builder.kinds.contains_key(&id) {
+                continue;
+            }
+
+            builder.kinds.insert(id, BundleKind::Dynamic);
+            self.add_to_graph(&mut builder, id, &mut vec![], true);
+        }
+
         let mut metadata = HashMap::<ModuleId, Metadata>::default();
 
         // Draw dependency graph to calculte
@@ -192,7 +240,11 @@ where
         // Promote modules to entry.
         for (id, md) in &metadata {
             if md.bundle_cnt > 1 {
-                // TODO: Dynamic import
+                // Chunks of dynamic imports can be shared like libraries.
+                if let Some(BundleKind::Dynamic) = builder.kinds.get(id) {
+                    continue;
+                }
+
                 let module = self.scope.get_module(*id).unwrap();
                 match builder.kinds.insert(
                     *id,
@@ -218,10 +270,11 @@ where
             plans.entries.push(*id);
             plans.bundle_kinds.insert(*id, kind.clone());
         }
+        // Don't depend on the iteration order of `HashMap`.
+        plans.entries.sort();
 
         // Convert graph to plan
-        for (root_entry, _) in &builder.kinds {
-            let root_entry = *root_entry;
+        for &root_entry in &plans.entries {
             let mut bfs = Bfs::new(&builder.direct_deps, root_entry);
 
             let mut done = HashSet::new();
@@ -389,8 +442,8 @@ where
         }
 
         // Handle circular imports
-        for (root_entry, _) in builder.kinds.iter() {
-            let mut bfs = Bfs::new(&builder.direct_deps, *root_entry);
+        for &root_entry in &plans.entries {
+            let mut bfs = Bfs::new(&builder.direct_deps, root_entry);
 
             while let Some(entry) = bfs.next(&builder.direct_deps) {
                 let deps: Vec<_> = builder
@@ -449,6 +502,7 @@ where
                                     circular_plan
                                         .chunks
                                         .extend(v.iter().copied().filter(|&v| v != dep));
+                                    circular_plan.chunks.sort();
                                 }
                             }
                         }
@@ -465,6 +519,8 @@ where
             plans.normal.entry(entry).or_default();
         }
 
+        plans.cycles = builder.cycles;
+
         // dbg!(&plans);
 
         plans
@@ -491,6 +547,11 @@ where
             .map(|v| (&v.0, false))
             .chain(m.exports.reexports.iter().map(|v| (&v.0, true)))
         {
+            if !src.is_loaded_synchronously {
+                builder.dynamic_entries.push(src.module_id);
+                continue;
+            }
+
             if !builder.direct_deps.contains_edge(module_id, src.module_id) {
                 log::debug!(
                     "Dependency: {:?} => {:?}; in export = {:?}; export = {:?}",
@@ -526,6 +587,10 @@ where
             .iter()
             .chain(m.exports.reexports.iter())
         {
+            if !src.is_loaded_synchronously {
+                continue;
+            }
+
             if builder.all_deps.contains_key(&(src.module_id, module_id)) {
                 log::debug!("Circular dep: {:?} => {:?}", module_id, src.module_id);
 
@@ -540,6 +605,16 @@ where
                 )
                 .collect::<Vec<_>>();
 
+                match circular_paths.iter().min_by_key(|path| path.len()) {
+                    Some(path) => builder.add_cycle(
+                        Some(module_id)
+                            .into_iter()
+                            .chain(path.iter().copied())
+                            .collect(),
+                    ),
+                    None => builder.add_cycle(vec![module_id, src.module_id, module_id]),
+                }
+
                 for path in circular_paths {
                     for dep in path {
                         builder.mark_as_circular(module_id, dep)
@@ -73,6 +73,25 @@ fn assert_circular(t: &mut Tester, p: &Plan, entry: &str, members: &[&str]) {
     );
 }
 
+#[track_caller]
+fn assert_cycles(t: &mut Tester, p: &Plan, cycles: &[&[&str]]) {
+    let mut expected = vec![];
+    for cycle in cycles {
+        let mut ids = cycle
+            .iter()
+            .map(|s| t.id(&format!("{}.js", s)))
+            .collect::<Vec<_>>();
+        // Cycles start with the smallest id.
+        ids.pop();
+        let start = ids.iter().position(|id| id == ids.iter().min().unwrap());
+        ids.rotate_left(start.unwrap());
+        ids.push(ids[0]);
+        expected.push(ids);
+    }
+
+    assert_eq!(p.cycles, expected);
+}
+
 #[test]
 fn concurrency_001() {
     suite()
@@ -247,6 +266,7 @@ fn circular_001() {
             assert_normal(t, &p, "main", &["a", "b"]);
             assert_normal(t, &p, "a", &[]);
             assert_normal(t, &p, "b", &[]);
+            assert_cycles(t, &p, &[&["a", "b", "a"]]);
 
             Ok(())
         });
@@ -696,6 +716,7 @@ fn circular_002() {
 
             assert_normal(t, &p, "main", &["a"]);
             assert_circular(t, &p, "a", &["b", "c"]);
+            assert_cycles(t, &p, &[&["a", "b", "c", "a"]]);
 
             Ok(())
         });
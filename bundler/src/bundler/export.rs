@@ -65,13 +65,7 @@ where
 {
     fn ctxt_for(&self, src: &JsWord) -> Option<SyntaxContext> {
         // Don't apply mark if it's a core module.
-        if self
-            .bundler
-            .config
-            .external_modules
-            .iter()
-            .any(|v| v == src)
-        {
+        if self.bundler.is_external(src) {
             return None;
         }
         let path = self.bundler.resolve(self.file_name, src).ok()?;
@@ -83,13 +77,7 @@ where
 
     fn mark_as_wrapping_required(&self, src: &JsWord) {
         // Don't apply mark if it's a core module.
-        if self
-            .bundler
-            .config
-            .external_modules
-            .iter()
-            .any(|v| v == src)
-        {
+        if self.bundler.is_external(src) {
             return;
         }
         let path = self.bundler.resolve(self.file_name, src);
@@ -26,7 +26,13 @@ where
     /// - inject helpers
     /// - rename chunks
     /// - invoke fixer
-    pub(super) fn finalize(&self, bundles: Vec<Bundle>) -> Result<Vec<Bundle>, Error> {
+    ///
+    /// Returns the bundles with the paths of renamed chunks by the path of
+    /// their modules.
+    pub(super) fn finalize(
+        &self,
+        bundles: Vec<Bundle>,
+    ) -> Result<(Vec<Bundle>, HashMap<PathBuf, String>), Error> {
         self.run(|| {
             let mut new = Vec::with_capacity(bundles.len());
             let mut renamed = HashMap::default();
@@ -52,7 +58,10 @@ where
                         HELPERS.set(&swc_helpers, || module.fold_with(&mut inject_helpers()));
                 }
 
-                bundle.module = self.may_wrap_with_iife(bundle.module);
+                // Chunks of dynamic imports are loaded with `import()`.
+                if bundle.kind != BundleKind::Dynamic {
+                    bundle.module = self.may_wrap_with_iife(bundle.module);
+                }
 
                 match bundle.kind {
                     BundleKind::Named { .. } => {
@@ -63,54 +72,38 @@ where
                             .expect("module should exist at this point")
                             .helpers;
 
-                        helpers.add_to(&mut bundle.module.body);
+                        helpers.add_to(&self.cm, &mut bundle.module.body);
 
                         new.push(Bundle { ..bundle });
                     }
                     BundleKind::Lib { name } => {
-                        let hash = calc_hash(self.cm.clone(), &bundle.module)?;
-                        let mut new_name = PathBuf::from(name);
-                        let key = new_name.clone();
-                        let file_name = new_name
-                            .file_name()
-                            .map(|path| -> PathBuf {
-                                let path = Path::new(path);
-                                let ext = path.extension();
-                                if let Some(ext) = ext {
-                                    return format!(
-                                        "{}-{}.{}",
-                                        path.file_stem().unwrap().to_string_lossy(),
-                                        hash,
-                                        ext.to_string_lossy()
-                                    )
-                                    .into();
-                                }
-                                return format!(
-                                    "{}-{}",
-                                    path.file_stem().unwrap().to_string_lossy(),
-                                    hash,
-                                )
-                                .into();
-                            })
-                            .expect("javascript file should have name");
-                        new_name.pop();
-                        new_name = new_name.join(file_name.clone());
-
-                        renamed.insert(key, new_name.to_string_lossy().to_string());
+                        let name = self.hashed_name(&bundle.module, name, &mut renamed)?;
 
                         new.push(Bundle {
-                            kind: BundleKind::Named {
-                                name: file_name.display().to_string(),
-                            },
+                            kind: BundleKind::Named { name },
+                            ..bundle
+                        })
+                    }
+                    BundleKind::Dynamic => {
+                        let name = self
+                            .scope
+                            .get_module(bundle.id)
+                            .expect("module should exist at this point")
+                            .fm
+                            .name
+                            .to_string();
+                        let name = self.hashed_name(&bundle.module, name, &mut renamed)?;
+
+                        new.push(Bundle {
+                            kind: BundleKind::Named { name },
                             ..bundle
                         })
                     }
-                    _ => new.push(bundle),
                 }
             }
 
             if new.len() == 1 {
-                return Ok(new);
+                return Ok((new, renamed));
             }
 
             new = new.move_map(|bundle| {
@@ -135,10 +128,41 @@ where
                 Bundle { module, ..bundle }
             });
 
-            Ok(new)
+            Ok((new, renamed))
         })
     }
 
+    /// Returns the file name of a chunk with the hash of `module`, like
+    /// `common-[hash].js` for `name` of `src/common.js`, and records the new
+    /// path in `renamed`.
+    fn hashed_name(
+        &self,
+        module: &Module,
+        name: String,
+        renamed: &mut HashMap<PathBuf, String>,
+    ) -> Result<String, Error> {
+        let hash = calc_hash(self.cm.clone(), module)?;
+        let mut new_name = PathBuf::from(name);
+        let key = new_name.clone();
+        let file_name = new_name
+            .file_name()
+            .map(|path| -> PathBuf {
+                let path = Path::new(path);
+                let stem = path.file_stem().unwrap().to_string_lossy();
+                match path.extension() {
+                    Some(ext) => format!("{}-{}.{}", stem, hash, ext.to_string_lossy()).into(),
+                    None => format!("{}-{}", stem, hash).into(),
+                }
+            })
+            .expect("javascript file should have name");
+        new_name.pop();
+        new_name = new_name.join(file_name.clone());
+
+        renamed.insert(key, new_name.to_string_lossy().to_string());
+
+        Ok(file_name.display().to_string())
+    }
+
     fn may_wrap_with_iife(&self, module: Module) -> Module {
         if self.config.module != ModuleType::Iife {
             return module;
@@ -378,45 +402,81 @@ where
     noop_fold_type!();
 
     fn fold_import_decl(&mut self, import: ImportDecl) -> ImportDecl {
+        match self.rename(&import.src) {
+            Some(src) => ImportDecl { src, ..import },
+            None => import,
+        }
+    }
+
+    /// Changes the path of dynamic imports, like `import('./a')`.
+    fn fold_call_expr(&mut self, e: CallExpr) -> CallExpr {
+        let mut e = e.fold_children_with(self);
+
+        let is_import = match &e.callee {
+            ExprOrSuper::Expr(callee) => matches!(
+                &**callee,
+                Expr::Ident(Ident {
+                    sym: js_word!("import"),
+                    ..
+                })
+            ),
+            _ => false,
+        };
+        if !is_import || e.args.len() != 1 {
+            return e;
+        }
+
+        if let Expr::Lit(Lit::Str(src)) = &mut *e.args[0].expr {
+            if let Some(renamed) = self.rename(src) {
+                *src = renamed;
+            }
+        }
+
+        e
+    }
+}
+
+impl<R> Renamer<'_, R>
+where
+    R: Resolve,
+{
+    /// Returns the new path of `src` if it's renamed.
+    fn rename(&self, src: &Str) -> Option<Str> {
         let resolved = match self
             .resolver
-            .resolve(&FileName::Real(self.base.clone()), &import.src.value)
+            .resolve(&FileName::Real(self.base.clone()), &src.value)
         {
             Ok(v) => match v {
                 FileName::Real(v) => v,
-                _ => panic!("rename_bundles called with non-path module"),
+                // Modules without a path are not renamed.
+                _ => return None,
             },
-            Err(_) => return import,
+            Err(_) => return None,
         };
 
-        if let Some(v) = self.renamed.get(&resolved) {
-            // We use parent because RelativePath uses ../common-[hash].js
-            // if we use `entry-a.js` as a base.
-            //
-            // entry-a.js
-            // common.js
-            let base = self
-                .base
-                .parent()
-                .unwrap_or(self.base)
-                .as_os_str()
-                .to_string_lossy();
-            let base = RelativePath::new(&*base);
-            let v = base.relative(&*v);
-            let value = v.as_str();
-            return ImportDecl {
-                src: Str {
-                    value: if value.starts_with(".") {
-                        value.into()
-                    } else {
-                        format!("./{}", value).into()
-                    },
-                    ..import.src
-                },
-                ..import
-            };
-        }
-
-        import
+        let v = self.renamed.get(&resolved)?;
+        // We use parent because RelativePath uses ../common-[hash].js
+        // if we use `entry-a.js` as a base.
+        //
+        // entry-a.js
+        // common.js
+        let base = self
+            .base
+            .parent()
+            .unwrap_or(self.base)
+            .as_os_str()
+            .to_string_lossy();
+        let base = RelativePath::new(&*base);
+        let v = base.relative(&*v);
+        let value = v.as_str();
+        Some(Str {
+            value: if value.starts_with(".") {
+                value.into()
+            } else {
+                format!("./{}", value).into()
+            },
+            raw: None,
+            ..src.clone()
+        })
     }
 }
@@ -3,7 +3,8 @@ use std::sync::atomic::{AtomicBool, Ordering::SeqCst};
 use swc_common::{FileName, FilePathMapping, SourceMap};
 use swc_ecma_ast::*;
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput};
-use swc_ecma_utils::{drop_span, prepend_stmts};
+use swc_ecma_utils::{prepend_stmts, MoveSpans};
+use swc_ecma_visit::VisitMutWith;
 
 #[derive(Debug, Default)]
 pub(super) struct Helpers {
@@ -20,12 +21,12 @@ macro_rules! define {
         )*
     ) => {
         $(
-            fn $build(to: &mut Vec<ModuleItem>) {
+            fn $build(cm: &SourceMap, to: &mut Vec<ModuleItem>) {
+                const CODE: &str = include_str!(concat!("_", stringify!($name), ".js"));
                 static STMTS: Lazy<Vec<ModuleItem>> = Lazy::new(|| {
                     let cm = SourceMap::new(FilePathMapping::empty());
-                    let code = include_str!(concat!("_", stringify!($name), ".js"));
                     let fm =
-                       cm.new_source_file(FileName::Custom(stringify!($name).into()), code.into());
+                       cm.new_source_file(FileName::Custom(stringify!($name).into()), CODE.into());
                     let lexer = Lexer::new(
                         Default::default(),
                         Default::default(),
@@ -34,7 +35,7 @@ macro_rules! define {
                     );
                     let stmts = Parser::new_from(lexer)
                         .parse_module()
-                        .map(|script| drop_span(script.body))
+                        .map(|script| script.body)
                         .map_err(|_| {
                             ()
                         })
@@ -42,7 +43,13 @@ macro_rules! define {
                     stmts
                 });
 
-                to.extend((*STMTS).clone());
+                let fm = cm.get_or_new_source_file(FileName::Internal(stringify!($name).into()), CODE);
+                to.extend(STMTS.iter().cloned().map(|mut item| {
+                    item.visit_mut_with(&mut MoveSpans {
+                        start_pos: fm.start_pos,
+                    });
+                    item
+                }));
             }
         )*
     };
@@ -59,11 +66,12 @@ impl Helpers {
         }
     }
 
-    pub fn add_to(&self, to: &mut Vec<ModuleItem>) {
+    /// Spans of the helpers point to files added to `cm`.
+    pub fn add_to(&self, cm: &SourceMap, to: &mut Vec<ModuleItem>) {
         let mut buf = vec![];
 
         if self.require.load(SeqCst) {
-            build_spack_require(&mut buf);
+            build_spack_require(cm, &mut buf);
         }
 
         prepend_stmts(to, buf.into_iter());
@@ -129,13 +129,7 @@ where
 {
     fn ctxt_for(&self, src: &JsWord) -> Option<SyntaxContext> {
         // Don't apply mark if it's a core module.
-        if self
-            .bundler
-            .config
-            .external_modules
-            .iter()
-            .any(|v| v == src)
-        {
+        if self.bundler.is_external(src) {
             return None;
         }
         let path = self.bundler.resolve(self.path, src).ok()?;
@@ -147,13 +141,7 @@ where
 
     fn mark_as_wrapping_required(&self, src: &JsWord) {
         // Don't apply mark if it's a core module.
-        if self
-            .bundler
-            .config
-            .external_modules
-            .iter()
-            .any(|v| v == src)
-        {
+        if self.bundler.is_external(src) {
             return;
         }
         let path = self.bundler.resolve(self.path, src);
@@ -165,6 +153,18 @@ where
 
         self.bundler.scope.mark_as_wrapping_required(id);
     }
+
+    /// Returns false if the resolver marks the imported file as free of side
+    /// effects.
+    fn has_side_effects(&self, src: &JsWord) -> bool {
+        if self.bundler.is_external(src) {
+            return true;
+        }
+        match self.bundler.resolve(self.path, src) {
+            Ok(path) => self.bundler.resolver.has_side_effects(&path),
+            Err(_) => true,
+        }
+    }
 }
 
 impl<L, R> Fold for ImportHandler<'_, '_, L, R>
@@ -177,12 +177,7 @@ where
     fn fold_import_decl(&mut self, mut import: ImportDecl) -> ImportDecl {
         if !self.deglob_phase {
             // Ignore if it's a core module.
-            if self
-                .bundler
-                .config
-                .external_modules
-                .contains(&import.src.value)
-            {
+            if self.bundler.is_external(&import.src.value) {
                 return import;
             }
             if let Some(ctxt) = self.ctxt_for(&import.src.value) {
@@ -270,6 +265,14 @@ where
 
             match item {
                 ModuleItem::Stmt(Stmt::Empty(..)) => None,
+                // `import 'foo'` is useless if `foo` does not have side effects.
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ref import))
+                    if !self.deglob_phase
+                        && import.specifiers.is_empty()
+                        && !self.has_side_effects(&import.src.value) =>
+                {
+                    None
+                }
                 ModuleItem::Stmt(Stmt::Decl(Decl::Var(mut var))) => {
                     var.decls.retain(|d| match d.name {
                         Pat::Invalid(..) => false,
@@ -481,6 +484,10 @@ where
                     },
                     _ => return Expr::Call(e),
                 };
+                // Ignore core modules.
+                if self.bundler.is_external(&src.value) {
+                    return Expr::Call(e);
+                }
 
                 match &mut e.callee {
                     ExprOrSuper::Expr(callee)
@@ -580,7 +587,7 @@ where
                         _ => return node,
                     };
                     // Ignore core modules.
-                    if self.bundler.config.external_modules.contains(&src.value) {
+                    if self.bundler.is_external(&src.value) {
                         return node;
                     }
 
@@ -3,7 +3,6 @@ use crate::{
     bundler::{export::RawExports, import::RawImports},
     id::{Id, ModuleId},
     load::ModuleData,
-    util,
     util::IntoParallelIterator,
     Load, Resolve,
 };
@@ -11,6 +10,7 @@ use anyhow::{Context, Error};
 use is_macro::Is;
 #[cfg(feature = "rayon")]
 use rayon::iter::ParallelIterator;
+use std::collections::HashSet;
 use swc_atoms::js_word;
 use swc_common::{sync::Lrc, FileName, Mark, SourceFile, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::{
@@ -72,30 +72,49 @@ where
                 return Ok(Some(cached));
             }
 
-            let (_, data) = self.load(&file_name).context("Bundler.load() failed")?;
-            let (v, mut files) = self
-                .analyze(&file_name, data)
-                .context("failed to analyze module")?;
-            files.dedup_by_key(|v| v.1.clone());
+            // Modules are loaded in parallel, one level of the dependency graph at a time,
+            // but analyzed in order. Module ids and marks are assigned while analyzing, so
+            // they don't depend on thread scheduling.
+            let mut entry = None;
+            let mut seen = HashSet::new();
+            let mut queue = vec![Lrc::new(file_name.clone())];
+            seen.insert(queue[0].clone());
+
+            while !queue.is_empty() {
+                let results = queue
+                    .into_par_iter()
+                    .map(|path| -> Result<_, Error> {
+                        log::trace!("loading: {}", path);
+                        let (_, data) = self.load(&path).context("Bundler.load() failed")?;
+                        Ok((path, data))
+                    })
+                    .collect::<Vec<_>>();
 
-            log::debug!("({}, {:?}) Storing module: {}", v.id, v.ctxt(), file_name);
-            self.scope.store_module(v.clone());
+                queue = vec![];
+                for result in results {
+                    let (path, data) = result?;
+                    let (v, files) = self
+                        .analyze(&path, data)
+                        .context("failed to analyze module")?;
 
-            // Load dependencies and store them in the `Scope`
-            let results = files
-                .into_par_iter()
-                .map(|(_src, path)| {
-                    log::trace!("loading dependency: {}", path);
-                    self.load_transformed(&path)
-                })
-                .collect::<Vec<_>>();
+                    log::debug!("({}, {:?}) Storing module: {}", v.id, v.ctxt(), path);
+                    self.scope.store_module(v.clone());
+
+                    for (_src, dep) in files {
+                        if seen.contains(&dep) || self.scope.get_module_by_path(&dep).is_some() {
+                            continue;
+                        }
+                        seen.insert(dep.clone());
+                        queue.push(dep);
+                    }
 
-            // Do tasks in parallel, and then wait for result
-            for result in results {
-                result?;
+                    if entry.is_none() {
+                        entry = Some(v);
+                    }
+                }
             }
 
-            Ok(Some(v))
+            Ok(entry)
         })
     }
 
@@ -169,12 +188,8 @@ where
                 v.forced_es6 || !v.found_other
             };
 
-            let (imports, exports) = util::join(
-                || self.resolve_imports(file_name, imports),
-                || self.resolve_exports(file_name, exports),
-            );
-            let (imports, mut import_files) = imports?;
-            let (exports, reexport_files) = exports?;
+            let (imports, mut import_files) = self.resolve_imports(file_name, imports)?;
+            let (exports, reexport_files) = self.resolve_exports(file_name, exports)?;
             import_files.extend(reexport_files);
 
             let module = Lrc::new(module);
@@ -216,8 +231,7 @@ where
                         let info = match src {
                             Some(src) => {
                                 let name = self.resolve(base, &src.value)?;
-                                let (id, mark) = self.scope.module_id_gen.gen(&name);
-                                Some((id, mark, name, src))
+                                Some((name, src))
                             }
                             None => None,
                         };
@@ -232,8 +246,8 @@ where
 
                 match info {
                     None => exports.items.extend(specifiers),
-                    Some((id, mark, name, src)) => {
-                        //
+                    Some((name, src)) => {
+                        let (id, mark) = self.scope.module_id_gen.gen(&name);
                         let src = Source {
                             is_loaded_synchronously: true,
                             is_unconditional: false,
@@ -290,16 +304,18 @@ where
                     self.run(|| {
                         //
                         let file_name = self.resolve(base, &decl.src.value)?;
-                        let (id, mark) = self.scope.module_id_gen.gen(&file_name);
 
-                        Ok((id, mark, file_name, decl, dynamic, unconditional))
+                        Ok((file_name, decl, dynamic, unconditional))
                     })
                 })
                 .collect::<Vec<_>>();
 
             for res in loaded {
                 // TODO: Report error and proceed instead of returning an error
-                let (id, mark, file_name, decl, is_dynamic, is_unconditional) = res?;
+                let (file_name, decl, is_dynamic, is_unconditional) = res?;
+                // Ids are generated in the order of imports, not in the order they are
+                // resolved.
+                let (id, mark) = self.scope.module_id_gen.gen(&file_name);
 
                 let src = Source {
                     is_loaded_synchronously: !is_dynamic,
@@ -1,7 +1,7 @@
 use self::scope::Scope;
 use crate::{Hook, Load, ModuleId, Resolve};
 use anyhow::{Context, Error};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use swc_atoms::JsWord;
 use swc_common::{sync::Lrc, FileName, Globals, Mark, SourceMap, SyntaxContext, DUMMY_SP, GLOBALS};
 use swc_ecma_ast::Module;
@@ -29,6 +29,9 @@ pub struct Config {
     pub disable_inliner: bool,
 
     /// List of modules which should be preserved.
+    ///
+    /// See [Hook::is_external] for deciding it dynamically. Modules with the
+    /// `node:` scheme are always preserved.
     pub external_modules: Vec<JsWord>,
 
     /// Type of emiited module
@@ -57,6 +60,10 @@ pub enum BundleKind {
     Lib { name: String },
 }
 
+/// Paths of the chunks created for dynamic imports and shared modules, by the
+/// path of the module they are created for.
+pub type Manifest = HashMap<String, String>;
+
 /// Built bundle
 #[derive(Debug)]
 pub struct Bundle {
@@ -127,6 +134,23 @@ where
     /// circular manner. However, it applies only to the provided `entries`, and
     /// dependencies with circular reference is ok.
     pub fn bundle(&self, entries: HashMap<String, FileName>) -> Result<Vec<Bundle>, Error> {
+        let (bundles, _) = self.bundle_with_manifest(entries)?;
+        Ok(bundles)
+    }
+
+    /// Like [Bundler::bundle], but also returns the paths of the chunks.
+    ///
+    /// Modules imported with `import()` are emitted as separate chunks,
+    /// which are named like `foo-[hash].js`.
+    pub fn bundle_with_manifest(
+        &self,
+        entries: HashMap<String, FileName>,
+    ) -> Result<(Vec<Bundle>, Manifest), Error> {
+        // Entries are loaded in order of their names, because module ids are assigned
+        // while loading.
+        let mut entries = entries.into_iter().collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
         let results = entries
             .into_iter()
             .map(|(name, path)| -> Result<_, Error> {
@@ -155,8 +179,88 @@ where
 
         let bundles = self.chunk(local)?;
 
-        let bundles = self.finalize(bundles)?;
-        Ok(bundles)
+        let (bundles, renamed) = self.finalize(bundles)?;
+        let manifest = renamed
+            .into_iter()
+            .map(|(path, chunk)| (path.to_string_lossy().to_string(), chunk))
+            .collect();
+        Ok((bundles, manifest))
+    }
+
+    /// Returns the files of the modules which are bundled for `entry`,
+    /// including `entry` itself. Modules are known once they are loaded by
+    /// [Bundler::bundle].
+    ///
+    /// This can be used to bundle only the entries affected by modified
+    /// files in watch mode.
+    pub fn module_files(&self, entry: &FileName) -> Vec<FileName> {
+        let mut files = vec![];
+        let mut visited = HashSet::new();
+        let mut queue = vec![];
+        queue.extend(self.scope.get_module_by_path(entry).map(|m| m.id));
+
+        while let Some(id) = queue.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+            let m = match self.scope.get_module(id) {
+                Some(m) => m,
+                None => continue,
+            };
+
+            files.push(m.fm.name.clone());
+            queue.extend(m.imports.specifiers.iter().map(|(src, _)| src.module_id));
+            queue.extend(m.exports.reexports.iter().map(|(src, _)| src.module_id));
+        }
+
+        files
+    }
+
+    /// Bundles the entries which depend on `changed` files, after loading
+    /// the changed files again. Other entries are skipped, so the returned
+    /// bundles and manifest contain only the affected entries.
+    ///
+    /// Entries which are not bundled yet are always bundled.
+    pub fn rebundle(
+        &self,
+        entries: HashMap<String, FileName>,
+        changed: &[FileName],
+    ) -> Result<(Vec<Bundle>, Manifest), Error> {
+        let affected = entries
+            .into_iter()
+            .filter(|(_, entry)| {
+                let files = self.module_files(entry);
+                files.is_empty() || files.iter().any(|file| changed.contains(file))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut removed = vec![];
+        for file in changed {
+            if let Some(m) = self.scope.get_module_by_path(file) {
+                self.scope.remove_module(m.id);
+                removed.push(file);
+            }
+        }
+        // Modules depending on the removed modules are still cached, so the
+        // removed modules are not loaded while loading entries.
+        for file in removed {
+            self.load_transformed(file)
+                .with_context(|| format!("failed to load {}", file))?;
+        }
+
+        if affected.is_empty() {
+            return Ok(Default::default());
+        }
+
+        self.bundle_with_manifest(affected)
+    }
+
+    /// Returns true if imports of `src` should be preserved instead of
+    /// bundling the module.
+    pub(crate) fn is_external(&self, src: &str) -> bool {
+        src.starts_with("node:")
+            || self.config.external_modules.iter().any(|v| v == src)
+            || self.hook.is_external(src)
     }
 
     /// Sets `swc_common::GLOBALS`
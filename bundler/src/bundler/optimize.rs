@@ -21,6 +21,7 @@ where
             node = node.fold_with(&mut dce::dce(dce::Config {
                 used: None,
                 used_mark: self.used_mark,
+                side_effect_ctx: Default::default(),
             }));
 
             node
@@ -35,6 +35,12 @@ impl Scope {
         self.transformed_modules.insert(info.id, info);
     }
 
+    /// Forgets the module, so it's loaded again by the next build.
+    pub fn remove_module(&self, id: ModuleId) {
+        self.transformed_modules.remove(&id);
+        self.loaded_modules.remove(&id);
+    }
+
     pub fn get_module_by_path(&self, file_name: &FileName) -> Option<TransformedModule> {
         let (id, _) = self.module_id_gen.gen(file_name);
         self.get_module(id)
@@ -2,10 +2,13 @@
 use super::{load::TransformedModule, Bundler, Config};
 use crate::{load::ModuleData, util::HygieneRemover, Load, ModuleId, ModuleRecord, Resolve};
 use anyhow::Error;
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
 use swc_common::{sync::Lrc, FileName, SourceMap, Span, GLOBALS};
 use swc_ecma_ast::*;
-use swc_ecma_parser::{lexer::Lexer, JscTarget, Parser, StringInput};
+use swc_ecma_parser::{lexer::Lexer, EsConfig, JscTarget, Parser, StringInput, Syntax};
 use swc_ecma_utils::drop_span;
 use swc_ecma_visit::VisitMutWith;
 
@@ -28,7 +31,10 @@ impl Load for Loader {
         let fm = self.cm.new_source_file(f.clone(), v.to_string());
 
         let lexer = Lexer::new(
-            Default::default(),
+            Syntax::Es(EsConfig {
+                dynamic_import: true,
+                ..Default::default()
+            }),
             JscTarget::Es2020,
             StringInput::from(&*fm),
             None,
@@ -46,7 +52,10 @@ impl Load for Loader {
 }
 
 #[derive(Debug, Default)]
-pub struct Resolver;
+pub struct Resolver {
+    /// Files without side effects.
+    pure_files: HashSet<String>,
+}
 
 impl Resolve for Resolver {
     fn resolve(&self, _: &FileName, s: &str) -> Result<FileName, Error> {
@@ -60,6 +69,10 @@ impl Resolve for Resolver {
 
         Ok(FileName::Real(path))
     }
+
+    fn has_side_effects(&self, path: &FileName) -> bool {
+        !self.pure_files.contains(&path.to_string())
+    }
 }
 
 impl<'a> Tester<'a> {
@@ -110,6 +123,7 @@ pub(super) fn suite() -> TestBuilder {
 #[derive(Default)]
 pub(super) struct TestBuilder {
     files: HashMap<String, String>,
+    pure_files: HashSet<String>,
 }
 
 impl TestBuilder {
@@ -118,6 +132,12 @@ impl TestBuilder {
         self
     }
 
+    /// Marks the file `name` as free of side effects.
+    pub fn pure_file(mut self, name: &str) -> Self {
+        self.pure_files.insert(name.to_string());
+        self
+    }
+
     pub fn run<F>(self, op: F)
     where
         F: FnOnce(&mut Tester) -> Result<(), Error>,
@@ -131,7 +151,9 @@ impl TestBuilder {
                         cm: cm.clone(),
                         files: self.files,
                     },
-                    Default::default(),
+                    Resolver {
+                        pure_files: self.pure_files,
+                    },
                     Config {
                         require: true,
                         disable_inliner: true,
@@ -14,4 +14,28 @@ pub trait Hook: swc_common::sync::Sync + swc_common::sync::Send {
         span: Span,
         module_record: &ModuleRecord,
     ) -> Result<Vec<KeyValueProp>, Error>;
+
+    /// Returns true if imports of `specifier` should be preserved, like
+    /// [crate::Config::external_modules].
+    fn is_external(&self, _specifier: &str) -> bool {
+        false
+    }
+
+    /// Called for each import cycle in the module graph. `cycle` starts and
+    /// ends with the same module.
+    ///
+    /// A module of a cycle is evaluated when another module of the cycle
+    /// first reads its bindings, so such reads don't throw. A binding read
+    /// while its own module is still being evaluated is `undefined`. The
+    /// default implementation logs a warning.
+    fn on_circular_dependency(&self, cycle: &[FileName]) {
+        log::warn!(
+            "Circular dependency: {}",
+            cycle
+                .iter()
+                .map(|name| name.to_string())
+                .collect::<Vec<_>>()
+                .join(" -> ")
+        );
+    }
 }
@@ -1,18 +1,38 @@
 use anyhow::Error;
 use swc_common::FileName;
 
+/// Resolves module specifiers of imports to files.
+///
+/// `spack` provides implementations for node.js style resolution and
+/// `tsconfig.json` path mapping.
 pub trait Resolve: swc_common::sync::Send + swc_common::sync::Sync {
+    /// Resolves `module_specifier` imported by the file `base`.
     fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<FileName, Error>;
+
+    /// Returns false if the resolved file can be dropped when none of its
+    /// exports are used, like files of packages with `"sideEffects": false`
+    /// in their `package.json`.
+    fn has_side_effects(&self, _path: &FileName) -> bool {
+        true
+    }
 }
 
 impl<T: ?Sized + Resolve> Resolve for Box<T> {
     fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<FileName, Error> {
         (**self).resolve(base, module_specifier)
     }
+
+    fn has_side_effects(&self, path: &FileName) -> bool {
+        (**self).has_side_effects(path)
+    }
 }
 
 impl<'a, T: ?Sized + Resolve> Resolve for &'a T {
     fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<FileName, Error> {
         (**self).resolve(base, module_specifier)
     }
+
+    fn has_side_effects(&self, path: &FileName) -> bool {
+        (**self).has_side_effects(path)
+    }
 }
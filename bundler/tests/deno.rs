@@ -132,7 +132,10 @@ fn bundle(url: &str) -> String {
             let mut buf = vec![];
             {
                 Emitter {
-                    cfg: swc_ecma_codegen::Config { minify: false },
+                    cfg: swc_ecma_codegen::Config {
+                        minify: false,
+                        ..Default::default()
+                    },
                     cm: cm.clone(),
                     comments: None,
                     wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
@@ -266,6 +269,7 @@ impl swc_bundler::Hook for Hook {
                     span,
                     value: module_record.file_name.to_string().into(),
                     has_escape: false,
+                    raw: None,
                 }))),
             },
             KeyValueProp {
@@ -0,0 +1,145 @@
+#![cfg(feature = "concurrent")]
+
+use anyhow::Error;
+use std::{collections::HashMap, path::PathBuf};
+use swc_bundler::{BundleKind, Bundler, Config, Load, ModuleData, ModuleRecord, Resolve};
+use swc_common::{sync::Lrc, FileName, Globals, SourceMap, Span};
+use swc_ecma_ast::KeyValueProp;
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_parser::{lexer::Lexer, EsConfig, JscTarget, Parser, StringInput, Syntax};
+use swc_ecma_transforms::fixer;
+use swc_ecma_visit::FoldWith;
+
+const FILES: &[(&str, &str)] = &[
+    (
+        "a.js",
+        "import { c } from './c'; import { d } from './d'; export const a = c + d;",
+    ),
+    (
+        "b.js",
+        "import { c } from './c'; import { e } from './e'; export const b = () => import('./f') + \
+         c + e;",
+    ),
+    ("c.js", "import { g } from './g'; export const c = g * 2;"),
+    ("d.js", "import { e } from './e'; export const d = e + 1;"),
+    ("e.js", "import { h } from './h'; export const e = h;"),
+    ("f.js", "import { c } from './c'; export default c;"),
+    ("g.js", "export const g = 1;"),
+    ("h.js", "import { i } from './i'; export const h = () => i;"),
+    ("i.js", "import { h } from './h'; export const i = () => h;"),
+];
+
+struct Loader {
+    cm: Lrc<SourceMap>,
+}
+
+impl Load for Loader {
+    fn load(&self, f: &FileName) -> Result<ModuleData, Error> {
+        let src = FILES
+            .iter()
+            .find(|(name, _)| *name == f.to_string())
+            .map(|(_, src)| *src)
+            .unwrap();
+        let fm = self.cm.new_source_file(f.clone(), src.to_string());
+
+        let lexer = Lexer::new(
+            Syntax::Es(EsConfig {
+                dynamic_import: true,
+                ..Default::default()
+            }),
+            JscTarget::Es2020,
+            StringInput::from(&*fm),
+            None,
+        );
+        let module = Parser::new_from(lexer).parse_module().unwrap();
+
+        Ok(ModuleData {
+            fm,
+            module,
+            helpers: Default::default(),
+        })
+    }
+}
+
+struct Resolver;
+
+impl Resolve for Resolver {
+    fn resolve(&self, _: &FileName, s: &str) -> Result<FileName, Error> {
+        Ok(FileName::Real(
+            PathBuf::from(s.trim_start_matches("./")).with_extension("js"),
+        ))
+    }
+}
+
+struct Hook;
+
+impl swc_bundler::Hook for Hook {
+    fn get_import_meta_props(&self, _: Span, _: &ModuleRecord) -> Result<Vec<KeyValueProp>, Error> {
+        unreachable!()
+    }
+}
+
+/// Bundles `FILES` using `threads` threads.
+fn bundle(threads: usize) -> Vec<(String, String)> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+
+    testing::run_test2(false, |cm, _| {
+        let globals = Globals::default();
+        let bundler = Bundler::new(
+            &globals,
+            cm.clone(),
+            Loader { cm: cm.clone() },
+            Resolver,
+            Config {
+                require: false,
+                disable_inliner: true,
+                external_modules: vec![],
+                module: Default::default(),
+            },
+            Box::new(Hook),
+        );
+
+        let mut entries = HashMap::new();
+        entries.insert("a".to_string(), FileName::Real("a.js".into()));
+        entries.insert("b".to_string(), FileName::Real("b.js".into()));
+
+        let bundles = pool.install(|| bundler.bundle(entries)).unwrap();
+
+        Ok(bundles
+            .into_iter()
+            .map(|bundle| {
+                let name = match bundle.kind {
+                    BundleKind::Named { name } | BundleKind::Lib { name } => name,
+                    BundleKind::Dynamic => format!("dynamic.{}", bundle.id),
+                };
+
+                let mut buf = vec![];
+                {
+                    let mut emitter = Emitter {
+                        cfg: Default::default(),
+                        cm: cm.clone(),
+                        comments: None,
+                        wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+                    };
+                    emitter
+                        .emit_module(&bundle.module.fold_with(&mut fixer(None)))
+                        .unwrap();
+                }
+
+                (name, String::from_utf8(buf).unwrap())
+            })
+            .collect())
+    })
+    .unwrap()
+}
+
+#[test]
+fn same_output_regardless_of_thread_count() {
+    let expected = bundle(1);
+    for _ in 0..8 {
+        assert_eq!(bundle(8), expected);
+    }
+}
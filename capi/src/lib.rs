@@ -0,0 +1,141 @@
+//! C api for swc.
+//!
+//! Inputs and outputs are nul-terminated UTF-8 strings. Strings returned by
+//! this library are owned by the caller and must be released with
+//! [swc_free_string].
+//!
+//! ```c
+//! char *out = swc_transform("const a = () => 1;", "{\"jsc\":{\"target\":\"es5\"}}");
+//! // out is `{"code":"var a = function() {..."}`
+//! swc_free_string(out);
+//! ```
+
+use anyhow::{anyhow, bail, Context, Error};
+use serde::Serialize;
+use std::{
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::Arc,
+};
+use swc::{
+    config::Options,
+    error::{new_handler, BufferedError},
+    Compiler, TransformOutput,
+};
+use swc_common::{FileName, FilePathMapping, SourceMap};
+
+#[cfg(feature = "deno")]
+pub mod ops;
+
+/// Result of [swc_transform], serialized as json.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum Output {
+    Ok(TransformOutput),
+    Err { error: String },
+}
+
+/// Transforms `input` using `options_json`, which is the json
+/// representation of `.swcrc`.
+///
+/// Returns `{"code": "...", "map": "..."}` on success and `{"error": "..."}`
+/// on failure. `options_json` can be null to use the default options.
+///
+/// The returned string must be released with [swc_free_string].
+///
+/// # Safety
+///
+/// `input` and `options_json` must be null or point to nul-terminated
+/// strings.
+#[no_mangle]
+pub unsafe extern "C" fn swc_transform(
+    input: *const c_char,
+    options_json: *const c_char,
+) -> *mut c_char {
+    let output = match catch_unwind(AssertUnwindSafe(|| transform(input, options_json))) {
+        Ok(Ok(output)) => Output::Ok(output),
+        Ok(Err(err)) => Output::Err {
+            error: format!("{:?}", err),
+        },
+        Err(_) => Output::Err {
+            error: "swc panicked while transforming the input".into(),
+        },
+    };
+
+    let json = serde_json::to_string(&output).expect("failed to serialize output");
+    // serde_json escapes nul characters in strings.
+    CString::new(json).unwrap().into_raw()
+}
+
+/// Releases a string returned by this library.
+///
+/// # Safety
+///
+/// `s` must be null or a string returned by this library which is not
+/// released yet.
+#[no_mangle]
+pub unsafe extern "C" fn swc_free_string(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Returns the version of this library. The returned string is static and
+/// must not be released.
+#[no_mangle]
+pub extern "C" fn swc_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr() as *const c_char
+}
+
+unsafe fn transform(
+    input: *const c_char,
+    options_json: *const c_char,
+) -> Result<TransformOutput, Error> {
+    let input = match to_str(input)? {
+        Some(v) => v,
+        None => bail!("input is null"),
+    };
+    let options: Options = match to_str(options_json)? {
+        Some(v) => serde_json::from_str(v).context("failed to parse options")?,
+        None => Default::default(),
+    };
+
+    transform_str(input, &options)
+}
+
+fn transform_str(input: &str, options: &Options) -> Result<TransformOutput, Error> {
+    let (c, errors) = compiler();
+
+    let fm = c.cm.new_source_file(
+        if options.filename.is_empty() {
+            FileName::Anon
+        } else {
+            FileName::Real(options.filename.clone().into())
+        },
+        input.to_string(),
+    );
+
+    c.process_js_file(fm, options)
+        .map_err(|err| anyhow!("failed to process code: {:?}\n{}", err, errors))
+}
+
+/// Creates a compiler which renders errors to the returned buffer.
+fn compiler() -> (Compiler, BufferedError) {
+    let cm = Arc::new(SourceMap::new(FilePathMapping::empty()));
+    let (handler, errors) = new_handler(cm.clone());
+
+    (Compiler::new(cm, handler), errors)
+}
+
+unsafe fn to_str<'a>(s: *const c_char) -> Result<Option<&'a str>, Error> {
+    if s.is_null() {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        CStr::from_ptr(s)
+            .to_str()
+            .context("input is not valid utf-8")?,
+    ))
+}
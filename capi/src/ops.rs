@@ -0,0 +1,177 @@
+//! Ops for hosts which exchange json encoded buffers, like native plugins of
+//! Deno.
+//!
+//! Each op takes the json encoded arguments and returns the json encoded
+//! result, which is `{"error": "..."}` on failure. A native plugin of Deno can
+//! register all of them at once.
+//!
+//! ```rust,ignore
+//! use deno_core::plugin_api::{Interface, Op, ZeroCopyBuf};
+//!
+//! #[no_mangle]
+//! pub fn deno_plugin_init(interface: &mut dyn Interface) {
+//!     for &(name, op) in swc_capi::ops::OPS {
+//!         interface.register_op(name, move |_, zero_copy: &mut [ZeroCopyBuf]| {
+//!             Op::Sync(op(&zero_copy[0]).into_boxed_slice())
+//!         });
+//!     }
+//! }
+//! ```
+
+use crate::{compiler, transform_str, Output};
+use anyhow::{anyhow, Context, Error};
+use serde::{Deserialize, Serialize};
+use spack::{loaders::swc::SwcLoader, resolvers::NodeResolver};
+use std::{
+    collections::BTreeMap,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::Arc,
+};
+use swc::{
+    config::{Options, SourceMapsConfig},
+    TransformOutput,
+};
+use swc_atoms::js_word;
+use swc_bundler::{BundleKind, Bundler, ModuleRecord};
+use swc_common::Span;
+use swc_ecma_ast::{Bool, Expr, Ident, KeyValueProp, Lit, PropName, Str};
+
+/// An op which takes json encoded arguments and returns a json encoded
+/// result.
+pub type OpFn = fn(&[u8]) -> Vec<u8>;
+
+/// All ops, with their names.
+pub const OPS: &[(&str, OpFn)] = &[("op_swc_transform", transform), ("op_swc_bundle", bundle)];
+
+#[derive(Debug, Deserialize)]
+struct TransformArgs {
+    code: String,
+    #[serde(default)]
+    options: Options,
+}
+
+/// Transforms code.
+///
+/// The arguments are `{"code": "...", "options": {...}}`, where `options` is
+/// the json representation of `.swcrc`. The result is `{"code": "...",
+/// "map": "..."}`.
+pub fn transform(args: &[u8]) -> Vec<u8> {
+    run(|| {
+        let args: TransformArgs =
+            serde_json::from_slice(args).context("failed to parse arguments")?;
+
+        transform_str(&args.code, &args.options).map(Output::Ok)
+    })
+}
+
+#[derive(Debug, Serialize)]
+struct BundleOutput {
+    bundles: BTreeMap<String, TransformOutput>,
+}
+
+/// Bundles files on the disk.
+///
+/// The arguments are the json representation of `spack.config.js`. The
+/// result is `{"bundles": {"name": {"code": "...", "map": "..."}}}`.
+pub fn bundle(args: &[u8]) -> Vec<u8> {
+    run(|| {
+        let config: spack::config::Config =
+            serde_json::from_slice(args).context("failed to parse arguments")?;
+
+        let (c, errors) = compiler();
+        let c = Arc::new(c);
+        let options = config.options.clone().unwrap_or_default();
+        let minify = options
+            .config
+            .as_ref()
+            .and_then(|v| v.minify)
+            .unwrap_or(false);
+
+        let bundler = Bundler::new(
+            c.globals(),
+            c.cm.clone(),
+            SwcLoader::new(c.clone(), options),
+            NodeResolver::new(),
+            swc_bundler::Config {
+                require: true,
+                external_modules: config.external.iter().map(|s| (&**s).into()).collect(),
+                ..Default::default()
+            },
+            Box::new(Hook),
+        );
+
+        let bundles = bundler
+            .bundle(config.entry.into())
+            .map_err(|err| anyhow!("failed to bundle: {:?}\n{}", err, errors))?;
+
+        let bundles = bundles
+            .into_iter()
+            .map(|bundle| {
+                let name = match bundle.kind {
+                    BundleKind::Named { name } | BundleKind::Lib { name } => name,
+                    BundleKind::Dynamic => format!("dynamic.{}", bundle.id),
+                };
+
+                let output = c.print(
+                    &bundle.module,
+                    SourceMapsConfig::Bool(true),
+                    &Default::default(),
+                    None,
+                    minify,
+                )?;
+
+                Ok((name, output))
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(BundleOutput { bundles })
+    })
+}
+
+/// Runs `op` and serializes the result.
+fn run<F, T>(op: F) -> Vec<u8>
+where
+    F: FnOnce() -> Result<T, Error>,
+    T: Serialize,
+{
+    let res = match catch_unwind(AssertUnwindSafe(op)) {
+        Ok(Ok(v)) => serde_json::to_vec(&v),
+        Ok(Err(err)) => serde_json::to_vec(&Output::Err {
+            error: format!("{:?}", err),
+        }),
+        Err(_) => serde_json::to_vec(&Output::Err {
+            error: "swc panicked while running an op".into(),
+        }),
+    };
+
+    res.expect("failed to serialize output")
+}
+
+struct Hook;
+
+impl swc_bundler::Hook for Hook {
+    fn get_import_meta_props(
+        &self,
+        span: Span,
+        module_record: &ModuleRecord,
+    ) -> Result<Vec<KeyValueProp>, Error> {
+        Ok(vec![
+            KeyValueProp {
+                key: PropName::Ident(Ident::new(js_word!("url"), span)),
+                value: Box::new(Expr::Lit(Lit::Str(Str {
+                    span,
+                    value: module_record.file_name.to_string().into(),
+                    has_escape: false,
+                    raw: None,
+                }))),
+            },
+            KeyValueProp {
+                key: PropName::Ident(Ident::new(js_word!("main"), span)),
+                value: Box::new(Expr::Lit(Lit::Bool(Bool {
+                    span,
+                    value: module_record.is_entry,
+                }))),
+            },
+        ])
+    }
+}
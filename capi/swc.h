@@ -0,0 +1,27 @@
+#ifndef SWC_H
+#define SWC_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/*
+ * Transforms `input` using `options_json`, which is the json representation
+ * of `.swcrc`. `options_json` can be NULL to use the default options.
+ *
+ * Returns `{"code": "...", "map": "..."}` on success and `{"error": "..."}`
+ * on failure. The returned string must be released with `swc_free_string`.
+ */
+char *swc_transform(const char *input, const char *options_json);
+
+/* Releases a string returned by this library. */
+void swc_free_string(char *s);
+
+/* Returns the version of this library. The string must not be released. */
+const char *swc_version(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,77 @@
+#![cfg(feature = "deno")]
+
+use serde_json::{json, Value};
+use swc_capi::ops::{bundle, transform, OPS};
+
+fn call(op: fn(&[u8]) -> Vec<u8>, args: Value) -> Value {
+    let out = op(&serde_json::to_vec(&args).unwrap());
+
+    serde_json::from_slice(&out).unwrap()
+}
+
+#[test]
+fn strip_types() {
+    let out = call(
+        transform,
+        json!({
+            "code": "const a: number = 1; export type B = string;",
+            "options": {
+                "jsc": {
+                    "parser": { "syntax": "typescript" },
+                },
+            },
+        }),
+    );
+
+    let code = out["code"].as_str().unwrap();
+    assert!(code.contains("var a = 1"), "{}", out);
+    assert!(!code.contains("type"), "{}", out);
+}
+
+#[test]
+fn invalid_args() {
+    let out = transform(b"{");
+    let out: Value = serde_json::from_slice(&out).unwrap();
+
+    assert!(
+        out["error"]
+            .as_str()
+            .unwrap()
+            .contains("failed to parse arguments"),
+        "{}",
+        out
+    );
+}
+
+#[test]
+fn bundle_files() {
+    let dir = std::env::temp_dir().join(format!("swc-capi-ops-test-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        dir.join("main.js"),
+        "import { a } from './a'; console.log(a);",
+    )
+    .unwrap();
+    std::fs::write(dir.join("a.js"), "export const a = 1;").unwrap();
+
+    let out = call(
+        bundle,
+        json!({
+            "entry": {
+                "main": dir.join("main.js"),
+            },
+        }),
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let code = out["bundles"]["main"]["code"].as_str().unwrap();
+    assert_eq!(code, "console.log(1);\n", "{}", out);
+}
+
+#[test]
+fn names() {
+    let names: Vec<_> = OPS.iter().map(|(name, _)| *name).collect();
+
+    assert_eq!(names, vec!["op_swc_transform", "op_swc_bundle"]);
+}
@@ -0,0 +1,83 @@
+use serde_json::Value;
+use std::{
+    ffi::{CStr, CString},
+    ptr,
+};
+use swc_capi::{swc_free_string, swc_transform, swc_version};
+
+fn transform(input: &str, options: Option<&str>) -> Value {
+    let input = CString::new(input).unwrap();
+    let options = options.map(|s| CString::new(s).unwrap());
+
+    unsafe {
+        let out = swc_transform(
+            input.as_ptr(),
+            options.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
+        );
+        let json = CStr::from_ptr(out).to_str().unwrap().to_string();
+        swc_free_string(out);
+
+        serde_json::from_str(&json).unwrap()
+    }
+}
+
+#[test]
+fn transform_with_options() {
+    let out = transform(
+        "const a = () => 1;",
+        Some(r#"{ "jsc": { "target": "es5" }, "sourceMaps": true }"#),
+    );
+
+    assert!(
+        out["code"].as_str().unwrap().contains("var a = function"),
+        "{}",
+        out
+    );
+    assert!(out["map"].is_string(), "{}", out);
+    assert!(out.get("error").is_none(), "{}", out);
+}
+
+#[test]
+fn transform_with_default_options() {
+    let out = transform("class Foo {}", None);
+
+    assert!(out["code"].as_str().unwrap().contains("Foo"), "{}", out);
+}
+
+#[test]
+fn syntax_error() {
+    let out = transform("const a = ;", None);
+
+    let error = out["error"].as_str().unwrap();
+    assert!(error.contains("failed to process code"), "{}", error);
+    assert!(out.get("code").is_none(), "{}", out);
+}
+
+#[test]
+fn invalid_options() {
+    let out = transform("a", Some("{ jsc"));
+
+    assert!(
+        out["error"].as_str().unwrap().contains("failed to parse options"),
+        "{}",
+        out
+    );
+}
+
+#[test]
+fn null_input() {
+    unsafe {
+        let out = swc_transform(ptr::null(), ptr::null());
+        let json = CStr::from_ptr(out).to_str().unwrap().to_string();
+        swc_free_string(out);
+
+        assert!(json.contains("input is null"), "{}", json);
+    }
+}
+
+#[test]
+fn version() {
+    let version = unsafe { CStr::from_ptr(swc_version()) };
+
+    assert_eq!(version.to_str().unwrap(), env!("CARGO_PKG_VERSION"));
+}
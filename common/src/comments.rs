@@ -33,6 +33,35 @@ pub trait Comments {
     fn has_trailing(&self, pos: BytePos) -> bool;
     fn move_trailing(&self, from: BytePos, to: BytePos);
     fn take_trailing(&self, pos: BytePos) -> Option<Vec<Comment>>;
+
+    /// Returns true if there's a leading annotation comment like
+    /// `/*#__PURE__*/` at `pos`, where `flag` is `PURE`.
+    fn has_flag(&self, pos: BytePos, flag: &str) -> bool {
+        match self.take_leading(pos) {
+            Some(comments) => {
+                let found = comments.iter().any(|c| c.is_flag(flag));
+                self.add_leading_comments(pos, comments);
+                found
+            }
+            None => false,
+        }
+    }
+
+    /// Adds `/*#__PURE__*/` at `pos`, unless it's already annotated.
+    fn add_pure_comment(&self, pos: BytePos) {
+        if self.has_flag(pos, "PURE") {
+            return;
+        }
+
+        self.add_leading(
+            pos,
+            Comment {
+                kind: CommentKind::Block,
+                span: Span::new(pos, pos, Default::default()),
+                text: "#__PURE__".into(),
+            },
+        )
+    }
 }
 
 macro_rules! delegate {
@@ -76,6 +105,14 @@ macro_rules! delegate {
         fn take_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
             (**self).take_trailing(pos)
         }
+
+        fn has_flag(&self, pos: BytePos, flag: &str) -> bool {
+            (**self).has_flag(pos, flag)
+        }
+
+        fn add_pure_comment(&self, pos: BytePos) {
+            (**self).add_pure_comment(pos)
+        }
     };
 }
 
@@ -176,6 +213,10 @@ impl Comments for SingleThreadedComments {
     fn take_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
         self.trailing.borrow_mut().remove(&pos)
     }
+
+    fn has_flag(&self, pos: BytePos, flag: &str) -> bool {
+        self.with_leading(pos, |comments| comments.iter().any(|c| c.is_flag(flag)))
+    }
 }
 
 impl SingleThreadedComments {
@@ -213,6 +254,22 @@ pub struct Comment {
     pub text: String,
 }
 
+impl Comment {
+    /// Returns true for block comments like `/*#__PURE__*/` or
+    /// `/*@__PURE__*/`, where `flag` is `PURE`.
+    pub fn is_flag(&self, flag: &str) -> bool {
+        if self.kind != CommentKind::Block {
+            return false;
+        }
+
+        let text = self.text.trim();
+        (text.starts_with("#__") || text.starts_with("@__"))
+            && text.ends_with("__")
+            && text.len() == flag.len() + 5
+            && &text[3..3 + flag.len()] == flag
+    }
+}
+
 impl Spanned for Comment {
     fn span(&self) -> Span {
         self.span
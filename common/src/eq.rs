@@ -0,0 +1,121 @@
+use crate::{Span, SyntaxContext};
+use std::{rc::Rc, sync::Arc};
+use string_cache::{Atom, StaticAtomSet};
+
+/// Structural equality which ignores spans.
+///
+/// This is useful for comparing ast nodes which are created at different
+/// positions, e.g. an expected ast created by hand and the output of a pass.
+///
+/// Note that [SyntaxContext] is stored in a span, so it's ignored too.
+///
+/// Can be derived with `#[derive(EqIgnoreSpan)]`. Fields marked with
+/// `#[use_eq]` are compared using [PartialEq].
+pub trait EqIgnoreSpan {
+    fn eq_ignore_span(&self, other: &Self) -> bool;
+}
+
+impl EqIgnoreSpan for Span {
+    /// Always returns `true`.
+    #[inline]
+    fn eq_ignore_span(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl EqIgnoreSpan for SyntaxContext {
+    /// Always returns `true`.
+    #[inline]
+    fn eq_ignore_span(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+macro_rules! eq {
+    ($($T:ty),*) => {
+        $(
+            impl EqIgnoreSpan for $T {
+                #[inline]
+                fn eq_ignore_span(&self, other: &Self) -> bool {
+                    *self == *other
+                }
+            }
+        )*
+    };
+}
+
+eq!(bool, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64, char, str, String);
+
+impl<S> EqIgnoreSpan for Atom<S>
+where
+    S: StaticAtomSet,
+{
+    #[inline]
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        *self == *other
+    }
+}
+
+impl<T> EqIgnoreSpan for Option<T>
+where
+    T: EqIgnoreSpan,
+{
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(l), Some(r)) => l.eq_ignore_span(r),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T> EqIgnoreSpan for [T]
+where
+    T: EqIgnoreSpan,
+{
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(l, r)| l.eq_ignore_span(r))
+    }
+}
+
+impl<T> EqIgnoreSpan for Vec<T>
+where
+    T: EqIgnoreSpan,
+{
+    #[inline]
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        (**self).eq_ignore_span(&**other)
+    }
+}
+
+macro_rules! deref {
+    ($($T:ident),*) => {
+        $(
+            impl<T> EqIgnoreSpan for $T<T>
+            where
+                T: ?Sized + EqIgnoreSpan,
+            {
+                #[inline]
+                fn eq_ignore_span(&self, other: &Self) -> bool {
+                    (**self).eq_ignore_span(&**other)
+                }
+            }
+        )*
+    };
+}
+
+deref!(Box, Rc, Arc);
+
+impl<T> EqIgnoreSpan for &'_ T
+where
+    T: ?Sized + EqIgnoreSpan,
+{
+    #[inline]
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        (**self).eq_ignore_span(&**other)
+    }
+}
@@ -13,7 +13,7 @@ use self::Level::*;
 pub use self::{
     diagnostic::{Diagnostic, DiagnosticId, DiagnosticStyledString, SubDiagnostic},
     diagnostic_builder::DiagnosticBuilder,
-    emitter::{ColorConfig, Emitter},
+    emitter::{BufferedEmitter, ColorConfig, Emitter, TeeEmitter},
 };
 #[cfg(feature = "tty-emitter")]
 use crate::sync::Lrc;
@@ -806,6 +806,23 @@ impl Level {
         }
     }
 
+    /// Returns true if this is at least as severe as `level`.
+    ///
+    /// Bugs and fatal errors are as severe as errors, and notes as severe as
+    /// help messages.
+    pub fn is_at_least(self, level: Level) -> bool {
+        self.severity() <= level.severity()
+    }
+
+    fn severity(self) -> u8 {
+        match self {
+            Bug | Fatal | PhaseFatal | Error => 0,
+            Warning => 1,
+            Note | Help | FailureNote => 2,
+            Cancelled => 3,
+        }
+    }
+
     pub fn is_failure_note(self) -> bool {
         match self {
             FailureNote => true,
@@ -12,15 +12,14 @@
 use self::Destination::*;
 #[cfg(feature = "tty-emitter")]
 use super::snippet::{Annotation, Line};
-use super::DiagnosticBuilder;
 #[cfg(feature = "tty-emitter")]
 use super::{
     snippet::{AnnotationType, MultilineAnnotation, Style, StyledString},
     styled_buffer::StyledBuffer,
-    CodeSuggestion, DiagnosticId, Level, SourceMapperDyn, SubDiagnostic,
+    CodeSuggestion, DiagnosticId, SourceMapperDyn, SubDiagnostic,
 };
-#[cfg(feature = "tty-emitter")]
-use crate::sync::Lrc;
+use super::{Diagnostic, DiagnosticBuilder, Level};
+use crate::sync::{Lock, Lrc};
 #[cfg(feature = "tty-emitter")]
 use crate::syntax_pos::SourceFile;
 #[cfg(feature = "tty-emitter")]
@@ -33,9 +32,12 @@ use std::borrow::Cow;
 use std::cmp::{min, Reverse};
 #[cfg(feature = "tty-emitter")]
 use std::collections::HashMap;
-use std::io::{self, prelude::*};
 #[cfg(feature = "tty-emitter")]
 use std::ops::Range;
+use std::{
+    io::{self, prelude::*},
+    mem::take,
+};
 #[cfg(feature = "tty-emitter")]
 use termcolor::{Buffer, BufferWriter, Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 #[cfg(feature = "tty-emitter")]
@@ -55,6 +57,63 @@ pub trait Emitter: crate::sync::Send {
     }
 }
 
+/// Collects diagnostics, so that they can be inspected instead of printed.
+#[derive(Clone, Default)]
+pub struct BufferedEmitter(Lrc<Lock<Vec<Diagnostic>>>);
+
+impl BufferedEmitter {
+    /// Removes and returns the diagnostics emitted so far.
+    pub fn take(&self) -> Vec<Diagnostic> {
+        take(&mut *self.0.lock())
+    }
+}
+
+impl Emitter for BufferedEmitter {
+    fn emit(&mut self, db: &DiagnosticBuilder<'_>) {
+        self.0.lock().push((**db).clone());
+    }
+}
+
+/// Emits diagnostics with multiple emitters, for example to collect them and
+/// also print them.
+///
+/// Each emitter has its own verbosity, so warnings can be collected without
+/// being printed. The [Handler](super::Handler) should still be allowed to
+/// emit warnings.
+#[derive(Default)]
+pub struct TeeEmitter {
+    emitters: Vec<(Box<dyn Emitter>, Level)>,
+}
+
+impl TeeEmitter {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds an emitter which receives diagnostics at least as severe as
+    /// `level`.
+    pub fn with_emitter(mut self, emitter: Box<dyn Emitter>, level: Level) -> Self {
+        self.emitters.push((emitter, level));
+        self
+    }
+}
+
+impl Emitter for TeeEmitter {
+    fn emit(&mut self, db: &DiagnosticBuilder<'_>) {
+        for (emitter, level) in &mut self.emitters {
+            if db.level.is_at_least(*level) {
+                emitter.emit(db);
+            }
+        }
+    }
+
+    fn should_show_explain(&self) -> bool {
+        self.emitters
+            .iter()
+            .any(|(emitter, _)| emitter.should_show_explain())
+    }
+}
+
 #[cfg(feature = "tty-emitter")]
 impl Emitter for EmitterWriter {
     fn emit(&mut self, db: &DiagnosticBuilder<'_>) {
@@ -174,6 +174,11 @@ impl<'a> Input for StringInput<'a> {
             self.iter.as_str().as_bytes()[0] == c
         }
     }
+
+    #[inline]
+    fn as_str(&self) -> Option<&str> {
+        Some(self.iter.as_str())
+    }
 }
 
 pub trait Input: Clone {
@@ -222,6 +227,15 @@ pub trait Input: Clone {
             false
         }
     }
+
+    /// Returns the remaining input, if it's stored as a string.
+    ///
+    /// This allows scanning bytes instead of chars. Use [reset_to()] to skip
+    /// the scanned bytes.
+    #[inline]
+    fn as_str(&self) -> Option<&str> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -308,6 +322,19 @@ mod tests {
         });
     }
 
+    #[test]
+    fn src_input_as_str_01() {
+        let _ = with_test_sess("foo/d", |mut i| {
+            assert_eq!(i.as_str(), Some("foo/d"));
+
+            i.bump();
+            assert_eq!(i.as_str(), Some("oo/d"));
+
+            i.reset_to(BytePos(4));
+            assert_eq!(i.as_str(), Some("d"));
+        });
+    }
+
     //    #[test]
     //    fn src_input_smoke_02() {
     //        let _ = crate::with_test_sess("℘℘/℘℘", | mut i| {
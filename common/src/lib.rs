@@ -14,6 +14,7 @@
 #![deny(unused)]
 
 pub use self::{
+    eq::EqIgnoreSpan,
     errors::{SourceMapper, SourceMapperDyn},
     pos::{
         hygiene, BytePos, CharPos, FileName, Globals, Loc, LocWithOpt, Mark, MultiSpan, SourceFile,
@@ -23,7 +24,7 @@ pub use self::{
     source_map::{FileLines, FileLoader, FilePathMapping, SourceMap, SpanSnippetError},
     syntax_pos::LineCol,
 };
-pub use ast_node::{ast_node, DeserializeEnum, Spanned};
+pub use ast_node::{ast_node, DeserializeEnum, EqIgnoreSpan, Spanned};
 pub use from_variant::FromVariant;
 use serde::Serialize;
 use std::fmt::Debug;
@@ -35,6 +36,7 @@ pub trait AstNode: Debug + PartialEq + Clone + Spanned + Serialize {
 }
 
 pub mod comments;
+mod eq;
 pub mod errors;
 pub mod input;
 pub mod iter;
@@ -159,6 +159,13 @@ impl SourceMap {
         Ok(self.new_source_file(filename, src))
     }
 
+    /// Same as [SourceMap::load_file], but replaces the file loaded from
+    /// `path` before. See [SourceMap::replace_source_file].
+    pub fn reload_file(&self, path: &Path) -> io::Result<Lrc<SourceFile>> {
+        let src = self.file_loader.read_file(path)?;
+        Ok(self.replace_source_file(path.to_owned().into(), src))
+    }
+
     pub fn files(&self) -> MappedLockGuard<'_, Vec<Lrc<SourceFile>>> {
         LockGuard::map(self.files.borrow(), |files| &mut files.source_files)
     }
@@ -222,6 +229,46 @@ impl SourceMap {
         source_file
     }
 
+    /// Returns the file named `name`, creating it with `src` if it doesn't
+    /// exist yet.
+    ///
+    /// This is useful for code which is inserted in many files, like helpers.
+    pub fn get_or_new_source_file(&self, filename: FileName, src: &str) -> Lrc<SourceFile> {
+        match self.get_source_file(&filename) {
+            Some(file) => file,
+            None => self.new_source_file(filename, src.to_string()),
+        }
+    }
+
+    /// Creates a file named `filename`, replacing the file created with the
+    /// same name before. The old file is returned if `src` is not changed.
+    ///
+    /// The replaced file is dropped, so sources of files loaded repeatedly,
+    /// like in watch mode, are not kept alive. Positions are not reused, so
+    /// spans in the replaced file don't resolve to any file anymore.
+    pub fn replace_source_file(&self, filename: FileName, src: String) -> Lrc<SourceFile> {
+        {
+            let mut files = self.files.borrow_mut();
+            let idx = files
+                .source_files
+                .iter()
+                .position(|sf| sf.unmapped_path.as_ref() == Some(&filename));
+
+            if let Some(idx) = idx {
+                if *files.source_files[idx].src == src {
+                    return files.source_files[idx].clone();
+                }
+
+                let old = files.source_files.remove(idx);
+                files
+                    .stable_id_to_source_file
+                    .remove(&StableSourceFileId::new(&old));
+            }
+        }
+
+        self.new_source_file(filename, src)
+    }
+
     pub fn mk_substr_filename(&self, sp: Span) -> String {
         let pos = self.lookup_char_pos(sp.lo());
         format!(
@@ -889,6 +936,11 @@ impl SourceMap {
             return None;
         }
 
+        // Positions of removed files, see [SourceMap::replace_source_file].
+        if pos < files[a].start_pos || pos > files[a].end_pos {
+            return None;
+        }
+
         Some(files[a].clone())
     }
 
@@ -1028,8 +1080,7 @@ impl SourceMap {
             let pos = *pos;
             let lc = *lc;
 
-            // TODO: Use correct algorithm
-            if pos >= BytePos(4294967295) {
+            if pos.is_reserved_for_comments() {
                 continue;
             }
 
@@ -1037,7 +1088,10 @@ impl SourceMap {
             let f = match cur_file {
                 Some(ref f) if f.start_pos <= pos && pos < f.end_pos => f,
                 _ => {
-                    f = self.lookup_source_file(pos);
+                    f = match Self::lookup_source_file_in(&self.files(), pos) {
+                        Some(f) => f,
+                        None => continue,
+                    };
                     src_id = builder.add_source(&f.name.to_string());
                     builder.set_source_contents(src_id, Some(&f.src));
                     cur_file = Some(f.clone());
@@ -1161,6 +1215,25 @@ mod tests {
         sm
     }
 
+    #[test]
+    fn replace_source_file() {
+        let sm = init_source_map();
+
+        let a = sm.replace_source_file(PathBuf::from("a.rs").into(), "a".into());
+        let b = sm.replace_source_file(PathBuf::from("a.rs").into(), "a".into());
+        assert!(Lrc::ptr_eq(&a, &b));
+        assert_eq!(sm.files().len(), 4);
+
+        let c = sm.replace_source_file(PathBuf::from("a.rs").into(), "c".into());
+        assert_eq!(&**c.src, "c");
+        assert_eq!(sm.files().len(), 4);
+        assert_eq!(sm.lookup_char_pos(c.start_pos).file.src, c.src);
+
+        // Positions of the replaced file don't resolve to its neighbours.
+        assert!(SourceMap::lookup_source_file_in(&sm.files(), a.start_pos).is_none());
+        assert!(SourceMap::lookup_source_file_in(&sm.files(), a.end_pos).is_none());
+    }
+
     #[test]
     fn t3() {
         // Test lookup_byte_offset
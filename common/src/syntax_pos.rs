@@ -7,7 +7,10 @@ use std::{
     hash::{Hash, Hasher},
     ops::{Add, Sub},
     path::PathBuf,
-    sync::Mutex,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Mutex,
+    },
 };
 
 mod analyze_source_file;
@@ -53,12 +56,15 @@ pub const DUMMY_SP: Span = Span {
 #[derive(Default)]
 pub struct Globals {
     hygiene_data: Mutex<hygiene::HygieneData>,
+    /// Number of spans created by [Span::dummy_with_cmt].
+    dummy_cnt: AtomicU32,
 }
 
 impl Globals {
     pub fn new() -> Globals {
         Globals {
             hygiene_data: Mutex::new(hygiene::HygieneData::new()),
+            dummy_cnt: AtomicU32::new(0),
         }
     }
 }
@@ -91,6 +97,9 @@ pub enum FileName {
     ProcMacroSourceCode,
     /// Custom sources for explicit parser calls from plugins and drivers
     Custom(String),
+    /// Code generated by a pass, such as helpers, so that it isn't attributed
+    /// to the file it's inserted in.
+    Internal(String),
 }
 
 impl std::fmt::Display for FileName {
@@ -103,6 +112,7 @@ impl std::fmt::Display for FileName {
             FileName::Anon => write!(fmt, "<anon>"),
             FileName::ProcMacroSourceCode => write!(fmt, "<proc-macro source code>"),
             FileName::Custom(ref s) => write!(fmt, "<{}>", s),
+            FileName::Internal(ref s) => write!(fmt, "<swc-generated:{}>", s),
         }
     }
 }
@@ -123,6 +133,7 @@ impl FileName {
             | FileName::MacroExpansion
             | FileName::ProcMacroSourceCode
             | FileName::Custom(_)
+            | FileName::Internal(_)
             | FileName::QuoteExpansion => false,
         }
     }
@@ -134,6 +145,7 @@ impl FileName {
             | FileName::MacroExpansion
             | FileName::ProcMacroSourceCode
             | FileName::Custom(_)
+            | FileName::Internal(_)
             | FileName::QuoteExpansion => false,
             FileName::Macros(_) => true,
         }
@@ -194,6 +206,19 @@ impl Span {
         self.lo.0 == 0 && self.hi.0 == 0
     }
 
+    /// Creates an empty span which is not part of any source file, but can
+    /// hold comments, e.g. a `/*#__PURE__*/` annotation of a generated call.
+    ///
+    /// Each call returns a span with a distinct position, until the reserved
+    /// positions wrap around.
+    pub fn dummy_with_cmt() -> Self {
+        GLOBALS.with(|globals| {
+            let cnt = globals.dummy_cnt.fetch_add(1, Ordering::SeqCst);
+            let lo = BytePos(BytePos::MIN_RESERVED.0 + cnt % (1 << 24));
+            Span::new(lo, lo, SyntaxContext::empty())
+        })
+    }
+
     /// Returns a new span representing an empty span at the beginning of this
     /// span
     #[inline]
@@ -741,6 +766,19 @@ pub trait Pos {
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BytePos(pub u32);
 
+impl BytePos {
+    /// Positions from this one are not used by source files, and are reserved
+    /// for [Span::dummy_with_cmt].
+    pub const MIN_RESERVED: Self = BytePos(u32::MAX - (1 << 24));
+
+    /// Returns true if this position belongs to a span created by
+    /// [Span::dummy_with_cmt].
+    #[inline]
+    pub const fn is_reserved_for_comments(self) -> bool {
+        self.0 >= Self::MIN_RESERVED.0
+    }
+}
+
 /// A character offset. Because of multibyte utf8 characters, a byte offset
 /// is not equivalent to a character offset. The SourceMap will convert BytePos
 /// values to CharPos values as necessary.
@@ -1,3 +1,4 @@
 pub mod iter;
 pub mod map;
 pub mod move_map;
+pub mod take;
@@ -0,0 +1,48 @@
+use std::mem::replace;
+
+/// Helper for in-place mutation of ast nodes.
+///
+/// A node can be moved out of a `&mut` reference with [Take::take], which
+/// leaves a cheap dummy value behind.
+pub trait Take: Sized {
+    /// Creates a dummy value, which is used as a placeholder.
+    fn dummy() -> Self;
+
+    /// Replaces `self` with a dummy value and returns the original value.
+    fn take(&mut self) -> Self {
+        replace(self, Self::dummy())
+    }
+
+    /// Calls `op` with the value of `self` and stores the result in `self`.
+    fn map_with_mut<F>(&mut self, op: F)
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        let v = self.take();
+        *self = op(v);
+    }
+}
+
+impl<T> Take for Option<T> {
+    #[inline(always)]
+    fn dummy() -> Self {
+        None
+    }
+}
+
+impl<T> Take for Vec<T> {
+    #[inline(always)]
+    fn dummy() -> Self {
+        Vec::new()
+    }
+}
+
+impl<T> Take for Box<T>
+where
+    T: Take,
+{
+    #[inline(always)]
+    fn dummy() -> Self {
+        Box::new(T::dummy())
+    }
+}
@@ -1,6 +1,6 @@
 //! Test that `#[span]` and `#[fold]` can be used at same time.
 use serde::{self, Deserialize, Serialize};
-use swc_common::{self, ast_node, Span, Spanned};
+use swc_common::{self, ast_node, EqIgnoreSpan, Span, Spanned};
 
 #[ast_node("Class")]
 // See https://github.com/rust-lang/rust/issues/44925
@@ -13,7 +13,7 @@ pub struct Class {
 #[ast_node("Tuple")]
 pub struct Tuple(#[span] HasSpan, usize, usize);
 
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, EqIgnoreSpan, Serialize, Deserialize)]
 pub struct HasSpan {
     pub span: Span,
 }
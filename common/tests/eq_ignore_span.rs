@@ -0,0 +1,72 @@
+use swc_common::{BytePos, EqIgnoreSpan, Span, DUMMY_SP};
+
+#[derive(Debug, EqIgnoreSpan)]
+struct Lit {
+    span: Span,
+    value: String,
+    #[use_eq]
+    raw: Raw,
+}
+
+#[derive(Debug, PartialEq)]
+struct Raw(&'static str);
+
+#[derive(Debug, EqIgnoreSpan)]
+enum Expr {
+    Lit(Lit),
+    Seq { span: Span, exprs: Vec<Expr> },
+    Invalid,
+}
+
+fn lit(span: Span, value: &str) -> Expr {
+    Expr::Lit(Lit {
+        span,
+        value: value.into(),
+        raw: Raw("raw"),
+    })
+}
+
+fn span(lo: u32, hi: u32) -> Span {
+    Span::new(BytePos(lo), BytePos(hi), Default::default())
+}
+
+#[test]
+fn ignores_span() {
+    let l = Expr::Seq {
+        span: span(0, 10),
+        exprs: vec![lit(span(1, 2), "a"), lit(span(3, 4), "b")],
+    };
+    let r = Expr::Seq {
+        span: DUMMY_SP,
+        exprs: vec![lit(DUMMY_SP, "a"), lit(DUMMY_SP, "b")],
+    };
+
+    assert!(l.eq_ignore_span(&r));
+}
+
+#[test]
+fn compares_fields() {
+    assert!(!lit(DUMMY_SP, "a").eq_ignore_span(&lit(DUMMY_SP, "b")));
+    assert!(!Expr::Seq {
+        span: DUMMY_SP,
+        exprs: vec![lit(DUMMY_SP, "a")],
+    }
+    .eq_ignore_span(&Expr::Seq {
+        span: DUMMY_SP,
+        exprs: vec![],
+    }));
+
+    // `raw` is compared with `PartialEq`.
+    let other = Expr::Lit(Lit {
+        span: DUMMY_SP,
+        value: "a".into(),
+        raw: Raw("other"),
+    });
+    assert!(!lit(DUMMY_SP, "a").eq_ignore_span(&other));
+}
+
+#[test]
+fn compares_variants() {
+    assert!(Expr::Invalid.eq_ignore_span(&Expr::Invalid));
+    assert!(!Expr::Invalid.eq_ignore_span(&lit(DUMMY_SP, "a")));
+}
@@ -0,0 +1,32 @@
+use swc_common::errors::{BufferedEmitter, Handler, HandlerFlags, Level, TeeEmitter};
+
+#[test]
+fn tee_emitter() {
+    let all = BufferedEmitter::default();
+    let errors = BufferedEmitter::default();
+
+    let handler = Handler::with_emitter_and_flags(
+        Box::new(
+            TeeEmitter::new()
+                .with_emitter(Box::new(all.clone()), Level::Help)
+                .with_emitter(Box::new(errors.clone()), Level::Error),
+        ),
+        HandlerFlags {
+            can_emit_warnings: true,
+            ..Default::default()
+        },
+    );
+
+    handler.warn("warning");
+    handler.struct_err("error").emit();
+
+    let messages = |buf: &BufferedEmitter| {
+        buf.take()
+            .into_iter()
+            .map(|d| d.message())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(messages(&all), vec!["warning", "error"]);
+    assert_eq!(messages(&errors), vec!["error"]);
+    assert!(all.take().is_empty());
+}
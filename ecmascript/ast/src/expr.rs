@@ -17,7 +17,7 @@ use crate::{
 };
 use is_macro::Is;
 use serde::{self, Deserialize, Serialize};
-use swc_common::{ast_node, Span, Spanned, DUMMY_SP};
+use swc_common::{ast_node, util::take::Take, EqIgnoreSpan, Span, Spanned, DUMMY_SP};
 
 #[ast_node]
 #[derive(Eq, Hash, Is)]
@@ -151,6 +151,31 @@ pub enum Expr {
     #[tag("OptionalChainingExpression")]
     OptChain(OptChainExpr),
 
+    /// `?` in `f(?, a)`
+    #[tag("ArgumentPlaceholder")]
+    ArgPlaceholder(ArgPlaceholder),
+
+    /// `do { ... }`
+    #[tag("DoExpression")]
+    #[is(name = "do_expr")]
+    Do(DoExpr),
+
+    /// `obj::func` or `::obj.func`
+    #[tag("BindExpression")]
+    Bind(BindExpr),
+
+    /// `throw` in expression position.
+    #[tag("ThrowExpression")]
+    Throw(ThrowExpr),
+
+    /// `#{ a: 1 }`
+    #[tag("RecordExpression")]
+    Record(RecordLit),
+
+    /// `#[1, 2]`
+    #[tag("TupleExpression")]
+    Tuple(TupleLit),
+
     #[tag("Invalid")]
     Invalid(Invalid),
 }
@@ -184,6 +209,28 @@ pub struct ObjectLit {
     pub props: Vec<PropOrSpread>,
 }
 
+/// Record literal.
+#[ast_node("RecordExpression")]
+#[derive(Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RecordLit {
+    pub span: Span,
+
+    #[serde(default, rename = "properties")]
+    pub props: Vec<PropOrSpread>,
+}
+
+/// Tuple literal.
+#[ast_node("TupleExpression")]
+#[derive(Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct TupleLit {
+    pub span: Span,
+
+    #[serde(default, rename = "elements")]
+    pub elems: Vec<ExprOrSpread>,
+}
+
 #[ast_node]
 #[derive(Eq, Hash, Is)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -489,7 +536,48 @@ pub struct Super {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Eq, Hash)]
+#[ast_node("DoExpression")]
+#[derive(Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DoExpr {
+    pub span: Span,
+
+    pub body: BlockStmt,
+}
+
+/// Function bind.
+#[ast_node("BindExpression")]
+#[derive(Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BindExpr {
+    pub span: Span,
+
+    /// `None` for `::obj.func`.
+    #[serde(default, rename = "object")]
+    pub obj: Option<Box<Expr>>,
+
+    pub callee: Box<Expr>,
+}
+
+#[ast_node("ThrowExpression")]
+#[derive(Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ThrowExpr {
+    pub span: Span,
+
+    #[serde(rename = "argument")]
+    pub arg: Box<Expr>,
+}
+
+/// Partial application.
+#[ast_node("ArgumentPlaceholder")]
+#[derive(Eq, Hash, Copy)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ArgPlaceholder {
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ExprOrSpread {
     #[serde(default)]
@@ -567,6 +655,19 @@ pub enum PatOrExpr {
     Pat(Box<Pat>),
 }
 
+impl Take for Expr {
+    #[inline(always)]
+    fn dummy() -> Self {
+        Expr::Invalid(Invalid { span: DUMMY_SP })
+    }
+}
+
+impl Take for PatOrExpr {
+    fn dummy() -> Self {
+        PatOrExpr::Pat(Box::new(Pat::Ident(Ident::dummy())))
+    }
+}
+
 impl From<bool> for Expr {
     fn from(value: bool) -> Self {
         Expr::Lit(Lit::Bool(Bool {
@@ -581,6 +682,7 @@ impl From<f64> for Expr {
         Expr::Lit(Lit::Num(Number {
             span: DUMMY_SP,
             value,
+            raw: None,
         }))
     }
 }
@@ -645,3 +747,44 @@ test_de!(
       "closing": null
     }"#
 );
+
+#[cfg(test)]
+mod tests {
+    use crate::{BinExpr, BinaryOp, Expr, Ident, Number};
+    use swc_atoms::js_word;
+    use swc_common::{util::take::Take, BytePos, EqIgnoreSpan, Span, DUMMY_SP};
+
+    fn bin(span: Span, value: f64) -> Expr {
+        Expr::Bin(BinExpr {
+            span,
+            op: BinaryOp::Add,
+            left: Box::new(Expr::Ident(Ident::new(js_word!("undefined"), span))),
+            right: Box::new(Expr::Lit(
+                Number {
+                    span,
+                    value,
+                    raw: None,
+                }
+                .into(),
+            )),
+        })
+    }
+
+    #[test]
+    fn eq_ignore_span() {
+        let span = Span::new(BytePos(1), BytePos(5), Default::default());
+
+        assert_ne!(bin(span, 1.0), bin(DUMMY_SP, 1.0));
+        assert!(bin(span, 1.0).eq_ignore_span(&bin(DUMMY_SP, 1.0)));
+        assert!(!bin(span, 1.0).eq_ignore_span(&bin(DUMMY_SP, 2.0)));
+    }
+
+    #[test]
+    fn take() {
+        let mut e = Box::new(bin(DUMMY_SP, 1.0));
+        let taken = e.take();
+
+        assert_eq!(*taken, bin(DUMMY_SP, 1.0));
+        assert_eq!(*e, Expr::dummy());
+    }
+}
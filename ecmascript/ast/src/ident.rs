@@ -1,6 +1,6 @@
 use crate::typescript::TsTypeAnn;
-use swc_atoms::JsWord;
-use swc_common::{ast_node, Span};
+use swc_atoms::{js_word, JsWord};
+use swc_common::{ast_node, util::take::Take, Mark, Span, DUMMY_SP};
 
 /// Ident with span.
 #[ast_node("Identifier")]
@@ -53,6 +53,12 @@ impl AsRef<str> for Ident {
     }
 }
 
+impl Take for Ident {
+    fn dummy() -> Self {
+        Ident::new(js_word!(""), DUMMY_SP)
+    }
+}
+
 impl Ident {
     pub const fn new(sym: JsWord, span: Span) -> Self {
         Ident {
@@ -62,6 +68,15 @@ impl Ident {
             optional: false,
         }
     }
+
+    /// Creates an identifier with a fresh mark, which can't conflict with
+    /// identifiers written by the user or created by other passes.
+    ///
+    /// The hygiene pass renames it if another binding has the same name.
+    pub fn new_private(sym: JsWord, span: Span) -> Self {
+        let mark = Mark::fresh(Mark::root());
+        Ident::new(sym, span.apply_mark(mark))
+    }
 }
 
 pub trait IdentExt: AsRef<str> {
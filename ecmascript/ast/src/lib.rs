@@ -1,3 +1,19 @@
+//! The ecmascript (and typescript) ast used by swc.
+//!
+//! This crate only contains the ast, so tools which exchange asts with swc
+//! don't need to depend on the parser or on the transforms.
+//!
+//! # Stability
+//!
+//! This crate follows semver, and the ast is its public interface. Adding a
+//! node, a variant, or a field is a breaking change and bumps the minor
+//! version while the crate is `0.x`. Patch releases never change the shape of
+//! the ast, including its serde representation.
+//!
+//! All nodes implement `Clone`, `Debug`, `PartialEq`, `Eq`, `Hash`,
+//! [Spanned](swc_common::Spanned), `Serialize` and `Deserialize`. Note that
+//! spans are compared and hashed too. Use
+//! [EqIgnoreSpan](swc_common::EqIgnoreSpan) to compare nodes structurally.
 #![deny(unreachable_patterns)]
 #![deny(missing_copy_implementations)]
 #![deny(missing_debug_implementations)]
@@ -9,14 +25,15 @@
 pub use self::{
     class::{
         Class, ClassMember, ClassMethod, ClassProp, Constructor, Decorator, MethodKind,
-        PrivateMethod, PrivateProp,
+        PrivateMethod, PrivateProp, StaticBlock,
     },
     decl::{ClassDecl, Decl, FnDecl, VarDecl, VarDeclKind, VarDeclarator},
     expr::{
-        ArrayLit, ArrowExpr, AssignExpr, AwaitExpr, BinExpr, BlockStmtOrExpr, CallExpr, ClassExpr,
-        CondExpr, Expr, ExprOrSpread, ExprOrSuper, FnExpr, MemberExpr, MetaPropExpr, NewExpr,
-        ObjectLit, OptChainExpr, ParenExpr, PatOrExpr, PropOrSpread, SeqExpr, SpreadElement, Super,
-        TaggedTpl, ThisExpr, Tpl, TplElement, UnaryExpr, UpdateExpr, YieldExpr,
+        ArgPlaceholder, ArrayLit, ArrowExpr, AssignExpr, AwaitExpr, BinExpr, BindExpr,
+        BlockStmtOrExpr, CallExpr, ClassExpr, CondExpr, DoExpr, Expr, ExprOrSpread, ExprOrSuper,
+        FnExpr, MemberExpr, MetaPropExpr, NewExpr, ObjectLit, OptChainExpr, ParenExpr, PatOrExpr,
+        PropOrSpread, RecordLit, SeqExpr, SpreadElement, Super, TaggedTpl, ThisExpr, ThrowExpr,
+        Tpl, TplElement, TupleLit, UnaryExpr, UpdateExpr, YieldExpr,
     },
     function::{Function, Param, ParamOrTsParamProp},
     ident::{Ident, IdentExt, PrivateName},
@@ -63,6 +80,7 @@ pub use self::{
         TsTypeOperatorOp, TsTypeParam, TsTypeParamDecl, TsTypeParamInstantiation, TsTypePredicate,
         TsTypeQuery, TsTypeQueryExpr, TsTypeRef, TsUnionOrIntersectionType, TsUnionType,
     },
+    version::EsVersion,
 };
 use swc_common::{ast_node, Span};
 
@@ -82,6 +100,7 @@ mod pat;
 mod prop;
 mod stmt;
 mod typescript;
+mod version;
 
 /// Represents a invalid node.
 #[ast_node("Invalid")]
@@ -38,6 +38,7 @@ pub enum Lit {
 #[derive(Eq, Hash)]
 pub struct BigInt {
     pub span: Span,
+    #[use_eq]
     pub value: BigIntValue,
 }
 
@@ -61,6 +62,13 @@ pub struct Str {
     /// This includes line escape.
     #[serde(default)]
     pub has_escape: bool,
+
+    /// The source text of the literal, including quotes.
+    ///
+    /// [None] if the literal is not parsed from source code. This should be
+    /// reset when `value` is modified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<JsWord>,
 }
 
 #[cfg(feature = "arbitrary")]
@@ -73,6 +81,7 @@ impl arbitrary::Arbitrary for Str {
             span,
             value,
             has_escape: false,
+            raw: None,
         })
     }
 }
@@ -123,14 +132,33 @@ impl arbitrary::Arbitrary for Regex {
 }
 
 #[ast_node("NumericLiteral")]
-#[derive(Copy)]
-#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Number {
     pub span: Span,
     /// **Note**: This should not be `NaN`. Use [crate::Ident] to represent NaN.
     ///
     /// If you store `NaN` in this field, a hash map will behave strangely.
     pub value: f64,
+
+    /// The source text of the literal, like `0x10` or `1e3`.
+    ///
+    /// [None] if the literal is not parsed from source code. This should be
+    /// reset when `value` is modified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<JsWord>,
+}
+
+#[cfg(feature = "arbitrary")]
+impl arbitrary::Arbitrary for Number {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let span = u.arbitrary()?;
+        let value = u.arbitrary()?;
+
+        Ok(Self {
+            span,
+            value,
+            raw: None,
+        })
+    }
 }
 
 impl Eq for Number {}
@@ -153,6 +181,7 @@ impl Hash for Number {
 
         self.span.hash(state);
         integer_decode(self.value).hash(state);
+        self.raw.hash(state);
     }
 }
 
@@ -1,7 +1,7 @@
 use crate::{module_decl::ModuleDecl, stmt::Stmt};
 use is_macro::Is;
 use swc_atoms::JsWord;
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, util::take::Take, Span};
 
 #[ast_node]
 #[derive(Eq, Hash, Is)]
@@ -78,3 +78,10 @@ pub enum ModuleItem {
     #[tag("*")]
     Stmt(Stmt),
 }
+
+impl Take for ModuleItem {
+    #[inline(always)]
+    fn dummy() -> Self {
+        ModuleItem::Stmt(Stmt::dummy())
+    }
+}
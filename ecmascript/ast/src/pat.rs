@@ -1,6 +1,6 @@
 use crate::{expr::Expr, ident::Ident, prop::PropName, typescript::TsTypeAnn, Invalid};
 use is_macro::Is;
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, util::take::Take, Span, DUMMY_SP};
 
 #[ast_node]
 #[derive(Eq, Hash, Is)]
@@ -130,3 +130,20 @@ pub struct AssignPatProp {
     #[serde(default)]
     pub value: Option<Box<Expr>>,
 }
+
+impl Take for Pat {
+    #[inline(always)]
+    fn dummy() -> Self {
+        Pat::Invalid(Invalid { span: DUMMY_SP })
+    }
+}
+
+impl Take for ObjectPatProp {
+    fn dummy() -> Self {
+        ObjectPatProp::Assign(AssignPatProp {
+            span: DUMMY_SP,
+            key: Ident::dummy(),
+            value: None,
+        })
+    }
+}
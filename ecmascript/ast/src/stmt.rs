@@ -5,7 +5,7 @@ use crate::{
     pat::Pat,
 };
 use is_macro::Is;
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, util::take::Take, Span, DUMMY_SP};
 
 /// Use when only block statements are allowed.
 #[ast_node("BlockStatement")]
@@ -325,3 +325,19 @@ pub enum VarDeclOrExpr {
     #[tag("*")]
     Expr(Box<Expr>),
 }
+
+impl Take for Stmt {
+    #[inline(always)]
+    fn dummy() -> Self {
+        Stmt::Empty(EmptyStmt { span: DUMMY_SP })
+    }
+}
+
+impl Take for BlockStmt {
+    fn dummy() -> Self {
+        BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![],
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+/// A version of the ecmascript specification, like the `target` of
+/// `.swcrc`.
+///
+/// Versions are ordered, so `EsVersion::Es2015 < EsVersion::Es2020`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum EsVersion {
+    #[serde(rename = "es3")]
+    Es3,
+    #[serde(rename = "es5")]
+    Es5,
+    #[serde(rename = "es2015")]
+    Es2015,
+    #[serde(rename = "es2016")]
+    Es2016,
+    #[serde(rename = "es2017")]
+    Es2017,
+    #[serde(rename = "es2018")]
+    Es2018,
+    #[serde(rename = "es2019")]
+    Es2019,
+    #[serde(rename = "es2020")]
+    Es2020,
+}
+
+impl EsVersion {
+    /// The latest version supported by swc.
+    pub const fn latest() -> Self {
+        EsVersion::Es2020
+    }
+}
+
+impl Default for EsVersion {
+    fn default() -> Self {
+        EsVersion::Es5
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EsVersion;
+
+    #[test]
+    fn order() {
+        assert!(EsVersion::Es3 < EsVersion::Es5);
+        assert!(EsVersion::Es2015 < EsVersion::latest());
+    }
+
+    #[test]
+    fn serde() {
+        assert_eq!(
+            serde_json::to_string(&EsVersion::Es2015).unwrap(),
+            "\"es2015\""
+        );
+        assert_eq!(
+            serde_json::from_str::<EsVersion>("\"es2020\"").unwrap(),
+            EsVersion::Es2020
+        );
+    }
+}
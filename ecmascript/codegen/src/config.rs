@@ -1,4 +1,70 @@
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Config {
     pub minify: bool,
+
+    /// Quotes of string literals.
+    pub quotes: QuoteStyle,
+
+    /// Used by [JsWriter](crate::text_writer::JsWriter).
+    pub semicolons: Semicolons,
+
+    /// Used by [JsWriter](crate::text_writer::JsWriter).
+    pub indent: Indent,
+
+    /// Ends the output with a line break, even if it's minified.
+    pub trailing_newline: bool,
+
+    /// Enables the formatter mode, which produces the same style regardless
+    /// of the line breaks of the input.
+    ///
+    /// Lists like arguments, parameters, array literals and object literals
+    /// are printed on a single line if they fit within this width, and one
+    /// element per line otherwise. Attributes and children of jsx elements
+    /// are broken into lines in the same way.
+    pub print_width: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Keeps the quotes of the source, and uses single quotes for generated
+    /// strings.
+    Preserve,
+    /// Prefers double quotes, unless a string contains more double quotes than
+    /// single quotes.
+    Double,
+    /// Prefers single quotes, unless a string contains more single quotes than
+    /// double quotes.
+    Single,
+}
+
+impl Default for QuoteStyle {
+    fn default() -> Self {
+        QuoteStyle::Preserve
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Semicolons {
+    Always,
+    /// Omits semicolons at the end of lines, and inserts them before lines
+    /// which would continue the previous statement.
+    Asi,
+}
+
+impl Default for Semicolons {
+    fn default() -> Self {
+        Semicolons::Always
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Indent {
+    Spaces(usize),
+    Tabs,
+}
+
+impl Default for Indent {
+    fn default() -> Self {
+        Indent::Spaces(4)
+    }
 }
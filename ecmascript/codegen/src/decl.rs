@@ -33,11 +33,14 @@ impl<'a> Emitter<'a> {
         for dec in &node.class.decorators {
             emit!(dec);
         }
+        if node.class.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
         keyword!("class");
         space!();
         emit!(node.ident);
         emit!(node.class.type_params);
-        formatting_space!();
 
         self.emit_class_trailing(&node.class)?;
     }
@@ -113,16 +116,16 @@ mod tests {
             "function* foo(){
             yield getServiceHosts()
         }",
-            "function*foo(){yield getServiceHosts();}",
+            "function*foo(){yield getServiceHosts()}",
         );
     }
 
     #[test]
     fn single_argument_arrow_expression() {
-        assert_min("function* f(){ yield x => x}", "function*f(){yield x=>x;}");
+        assert_min("function* f(){ yield x => x}", "function*f(){yield x=>x}");
         assert_min(
             "function* f(){ yield ({x}) => x}",
-            "function*f(){yield({x})=>x;}",
+            "function*f(){yield({x})=>x}",
         );
     }
 }
@@ -0,0 +1,127 @@
+//! Line breaking of the formatter mode. See [Config::print_width].
+
+use crate::{config::Config, list::ListFormat, text_writer::JsWriter, Emitter, Node, Result};
+use std::io::{self, Write};
+use swc_common::Span;
+
+/// `print_width` of the emitters used to measure nodes. They print everything
+/// on a single line, if possible.
+const FLAT: usize = usize::MAX;
+
+impl<'a> Emitter<'a> {
+    /// Returns true if the formatter mode is enabled, and this emitter is not
+    /// the one measuring nodes.
+    pub(crate) fn is_formatting(&self) -> bool {
+        match self.cfg.print_width {
+            Some(width) => width != FLAT,
+            None => false,
+        }
+    }
+
+    /// Returns true if this emitter is measuring nodes.
+    pub(crate) fn is_flat(&self) -> bool {
+        self.cfg.print_width == Some(FLAT)
+    }
+
+    /// Returns true if the first line printed by `op` fits within the print
+    /// width, when it's printed from the current column without line breaks.
+    pub(crate) fn fits<F>(&self, op: F) -> bool
+    where
+        F: FnOnce(&mut Emitter<'_>) -> Result,
+    {
+        let width = match self.cfg.print_width {
+            Some(width) if width != FLAT => width,
+            _ => return true,
+        };
+
+        let mut line = FirstLine {
+            remaining: width.saturating_sub(self.wr.column()),
+            overflowed: false,
+        };
+        {
+            let cfg = Config {
+                print_width: Some(FLAT),
+                ..self.cfg
+            };
+            let mut e = Emitter {
+                cfg,
+                cm: self.cm.clone(),
+                comments: None,
+                wr: Box::new(
+                    JsWriter::new(self.cm.clone(), "\n", &mut line, None).with_config(&cfg),
+                ),
+            };
+
+            // `FirstLine` stops printing with an error.
+            let _ = op(&mut e);
+        }
+
+        !line.overflowed
+    }
+
+    /// Chooses the line breaks of a comma delimited list in the formatter
+    /// mode.
+    pub(crate) fn format_list<N: Node>(
+        &self,
+        parent_node: Span,
+        children: Option<&[N]>,
+        format: ListFormat,
+        start: usize,
+        count: usize,
+    ) -> ListFormat {
+        if self.cfg.print_width.is_none()
+            || !format.contains(ListFormat::CommaDelimited)
+            || !format.intersects(ListFormat::BracketsMask)
+        {
+            return format;
+        }
+
+        let single_line = format
+            - (ListFormat::LinesMask | ListFormat::Indented | ListFormat::AllowTrailingComma);
+        if self.is_flat() || count == 0 {
+            return single_line;
+        }
+
+        let children = match children {
+            Some(children) => children,
+            None => return single_line,
+        };
+
+        if self.fits(|e| e.emit_list5(parent_node, Some(children), single_line, start, count)) {
+            single_line
+        } else {
+            (format - (ListFormat::LinesMask | ListFormat::AllowTrailingComma))
+                | ListFormat::MultiLine
+                | ListFormat::Indented
+        }
+    }
+}
+
+/// Stops at the end of the first line, or when the line gets longer than
+/// `remaining`.
+struct FirstLine {
+    remaining: usize,
+    overflowed: bool,
+}
+
+impl Write for FirstLine {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for &b in buf {
+            if b == b'\n' {
+                return Err(io::Error::new(io::ErrorKind::Other, "end of line"));
+            }
+
+            if self.remaining == 0 {
+                self.overflowed = true;
+                return Err(io::Error::new(io::ErrorKind::Other, "line is too long"));
+            }
+            self.remaining -= 1;
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -1,18 +1,18 @@
 use super::{Emitter, Result};
 use crate::list::ListFormat;
-use swc_common::Spanned;
+use swc_common::{Span, Spanned};
 use swc_ecma_ast::*;
 use swc_ecma_codegen_macros::emitter;
 
 impl<'a> Emitter<'a> {
     #[emitter]
     fn emit_jsx_element(&mut self, node: &JSXElement) -> Result {
+        let multi_line = self.is_formatting()
+            && !node.children.is_empty()
+            && !self.fits(|e| e.emit_jsx_element(node));
+
         emit!(node.opening);
-        self.emit_list(
-            node.span(),
-            Some(&node.children),
-            ListFormat::JsxElementOrFragmentChildren,
-        )?;
+        self.emit_jsx_children(node.span(), &node.children, multi_line)?;
         if let Some(ref closing) = node.closing {
             emit!(closing)
         }
@@ -23,13 +23,30 @@ impl<'a> Emitter<'a> {
         punct!("<");
         emit!(node.name);
 
-        space!();
-
-        self.emit_list(
-            node.span(),
-            Some(&node.attrs),
-            ListFormat::JsxElementAttributes,
-        )?;
+        if self.is_formatting()
+            && !node.attrs.is_empty()
+            && !self.fits(|e| e.emit_jsx_opening_element(node))
+        {
+            // One attribute per line.
+            self.wr.increase_indent()?;
+            for attr in &node.attrs {
+                self.wr.write_line()?;
+                emit!(attr);
+            }
+            self.wr.decrease_indent()?;
+            self.wr.write_line()?;
+        } else {
+            // The formatter mode omits the space in `<div >`.
+            if self.cfg.print_width.is_none() || !node.attrs.is_empty() || node.self_closing {
+                space!();
+            }
+
+            self.emit_list(
+                node.span(),
+                Some(&node.attrs),
+                ListFormat::JsxElementAttributes,
+            )?;
+        }
 
         if node.self_closing {
             punct!("/");
@@ -37,6 +54,51 @@ impl<'a> Emitter<'a> {
         punct!(">");
     }
 
+    /// Emits children of an element or a fragment.
+    ///
+    /// In the formatter mode, whitespaces which are removed by jsx are not
+    /// printed, and each child is printed on its own line if `multi_line` is
+    /// true. Children are printed as is if they contain texts, because line
+    /// breaks around texts change the output.
+    fn emit_jsx_children(
+        &mut self,
+        parent_node: Span,
+        children: &[JSXElementChild],
+        multi_line: bool,
+    ) -> Result {
+        let has_text = children.iter().any(|child| match child {
+            JSXElementChild::JSXText(text) => !is_removed_whitespace(&text.value),
+            _ => false,
+        });
+        if self.cfg.print_width.is_none() || has_text {
+            return self.emit_list(
+                parent_node,
+                Some(children),
+                ListFormat::JsxElementOrFragmentChildren,
+            );
+        }
+
+        let children = children
+            .iter()
+            .filter(|child| !matches!(child, JSXElementChild::JSXText(..)));
+
+        if multi_line {
+            self.wr.increase_indent()?;
+            for child in children {
+                self.wr.write_line()?;
+                emit!(self, child);
+            }
+            self.wr.decrease_indent()?;
+            self.wr.write_line()?;
+        } else {
+            for child in children {
+                emit!(self, child);
+            }
+        }
+
+        Ok(())
+    }
+
     #[emitter]
     fn emit_jsx_element_name(&mut self, node: &JSXElementName) -> Result {
         match *node {
@@ -126,14 +188,12 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     fn emit_jsx_fragment(&mut self, node: &JSXFragment) -> Result {
-        emit!(node.opening);
-
-        self.emit_list(
-            node.span(),
-            Some(&node.children),
-            ListFormat::JsxElementOrFragmentChildren,
-        )?;
+        let multi_line = self.is_formatting()
+            && !node.children.is_empty()
+            && !self.fits(|e| e.emit_jsx_fragment(node));
 
+        emit!(node.opening);
+        self.emit_jsx_children(node.span(), &node.children, multi_line)?;
         emit!(node.closing);
     }
 
@@ -177,3 +237,9 @@ impl<'a> Emitter<'a> {
         }
     }
 }
+
+/// Returns true if `text` is removed by jsx, which is the case for whitespaces
+/// containing a line break.
+fn is_removed_whitespace(text: &str) -> bool {
+    text.contains('\n') && text.chars().all(char::is_whitespace)
+}
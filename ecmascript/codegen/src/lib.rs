@@ -1,13 +1,13 @@
 #![recursion_limit = "1024"]
 #![allow(unused_variables)]
 
-pub use self::config::Config;
+pub use self::config::{Config, Indent, QuoteStyle, Semicolons};
 use self::{
     list::ListFormat,
     text_writer::WriteJs,
     util::{SourceMapperExt, SpanExt, StartsWithAlphaNum},
 };
-use std::{borrow::Cow, fmt::Write, io, sync::Arc};
+use std::{fmt::Write, io, sync::Arc};
 use swc_atoms::JsWord;
 use swc_common::{
     comments::Comments, sync::Lrc, BytePos, SourceMap, Span, Spanned, SyntaxContext, DUMMY_SP,
@@ -21,6 +21,7 @@ mod comments;
 mod config;
 mod decl;
 mod expr;
+mod format;
 mod jsx;
 pub mod list;
 mod stmt;
@@ -74,6 +75,9 @@ impl<'a> Emitter<'a> {
         for stmt in &node.body {
             emit!(stmt);
         }
+        if self.cfg.trailing_newline {
+            self.wr.write_line()?;
+        }
     }
 
     #[emitter]
@@ -86,6 +90,9 @@ impl<'a> Emitter<'a> {
         for stmt in &node.body {
             emit!(stmt);
         }
+        if self.cfg.trailing_newline {
+            self.wr.write_line()?;
+        }
     }
 
     #[emitter]
@@ -111,7 +118,9 @@ impl<'a> Emitter<'a> {
             ModuleDecl::TsImportEquals(ref n) => emit!(n),
             ModuleDecl::TsNamespaceExport(ref n) => emit!(n),
         }
-        self.wr.write_line()?;
+        if !self.cfg.minify {
+            self.wr.write_line()?;
+        }
     }
 
     #[emitter]
@@ -144,7 +153,6 @@ impl<'a> Emitter<'a> {
             DefaultDecl::Fn(ref n) => emit!(n),
             DefaultDecl::TsInterfaceDecl(ref n) => emit!(n),
         }
-        semi!();
     }
 
     #[emitter]
@@ -352,7 +360,9 @@ impl<'a> Emitter<'a> {
 
         match *node {
             Lit::Bool(Bool { value, span }) => {
-                if value {
+                if self.cfg.minify {
+                    self.wr.write_lit(span, if value { "!0" } else { "!1" })?;
+                } else if value {
                     keyword!(span, "true")
                 } else {
                     keyword!(span, "false")
@@ -382,24 +392,53 @@ impl<'a> Emitter<'a> {
     fn emit_str_lit(&mut self, node: &Str) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        let single_quote = if let Ok(s) = self.cm.span_to_snippet(node.span) {
-            s.starts_with("'")
-        } else {
-            true
+        let single_quote = match self.cfg.quotes {
+            QuoteStyle::Preserve => {
+                if !self.cfg.minify {
+                    if let Some(raw) = &node.raw {
+                        self.wr.write_lit(node.span, raw)?;
+                        return Ok(());
+                    }
+                }
+
+                if let Ok(s) = self.cm.span_to_snippet(node.span) {
+                    s.starts_with("'")
+                } else {
+                    true
+                }
+            }
+            QuoteStyle::Double | QuoteStyle::Single => {
+                let single = node.value.matches('\'').count();
+                let double = node.value.matches('"').count();
+                let single_quote = match self.cfg.quotes {
+                    QuoteStyle::Single => single <= double,
+                    _ => single < double,
+                };
+
+                if !self.cfg.minify {
+                    let quote = if single_quote { '\'' } else { '"' };
+                    if let Some(raw) = node.raw.as_ref().filter(|raw| raw.starts_with(quote)) {
+                        self.wr.write_lit(node.span, raw)?;
+                        return Ok(());
+                    }
+                }
+
+                single_quote
+            }
         };
 
         // if let Some(s) = get_text_of_node(&self.cm, node, false) {
         //     self.wr.write_str_lit(node.span, &s)?;
         //     return Ok(());
         // }
-        let value = escape(&node.value);
-        // let value = node.value.replace("\n", "\\n");
 
         if single_quote {
+            let value = escape(&node.value, '\'');
             punct!("'");
             self.wr.write_str_lit(node.span, &value)?;
             punct!("'");
         } else {
+            let value = escape(&node.value, '"');
             punct!("\"");
             self.wr.write_str_lit(node.span, &value)?;
             punct!("\"");
@@ -410,18 +449,45 @@ impl<'a> Emitter<'a> {
     fn emit_num_lit(&mut self, num: &Number) -> Result {
         self.emit_leading_comments_of_pos(num.span().lo())?;
 
-        // Handle infinity
+        let text = self.num_text(num);
+        self.wr.write_str_lit(num.span, &text)?;
+    }
+
+    /// Returns the text of a numeric literal, as it's emitted.
+    fn num_text(&self, num: &Number) -> String {
+        // Numeric separators are removed, as they are not supported by older
+        // engines.
+        if !self.cfg.minify {
+            if let Some(raw) = num.raw.as_ref().filter(|raw| !raw.contains('_')) {
+                return raw.to_string();
+            }
+        }
+
         if num.value.is_infinite() {
             if num.value.is_sign_negative() {
-                self.wr.write_str_lit(num.span, "-")?;
+                return "-Infinity".into();
             }
-            self.wr.write_str_lit(num.span, "Infinity")?;
+            return "Infinity".into();
+        }
+        if num.value.is_sign_negative() && num.value == 0.0 {
+            return "-0.0".into();
+        }
+
+        let text = num.value.to_string();
+        if !self.cfg.minify || num.value.is_nan() {
+            return text;
+        }
+
+        // Both are the shortest representations which round-trip.
+        let text = match text.strip_prefix("0.") {
+            Some(fract) => format!(".{}", fract),
+            None => text,
+        };
+        let exp = format!("{:e}", num.value);
+        if exp.len() < text.len() {
+            exp
         } else {
-            if num.value.is_sign_negative() && num.value == 0.0 {
-                self.wr.write_str_lit(num.span, "-0.0")?;
-            } else {
-                self.wr.write_str_lit(num.span, &format!("{}", num.value))?;
-            }
+            text
         }
     }
 
@@ -509,10 +575,76 @@ impl<'a> Emitter<'a> {
             Expr::TsConstAssertion(ref n) => emit!(n),
             Expr::TsTypeCast(ref n) => emit!(n),
             Expr::OptChain(ref n) => emit!(n),
+            Expr::ArgPlaceholder(ref n) => emit!(n),
+            Expr::Do(ref n) => emit!(n),
+            Expr::Bind(ref n) => emit!(n),
+            Expr::Throw(ref n) => emit!(n),
+            Expr::Record(ref n) => emit!(n),
+            Expr::Tuple(ref n) => emit!(n),
             Expr::Invalid(ref n) => emit!(n),
         }
     }
 
+    #[emitter]
+    fn emit_arg_placeholder(&mut self, n: &ArgPlaceholder) -> Result {
+        self.emit_leading_comments_of_pos(n.span.lo())?;
+
+        punct!("?");
+    }
+
+    #[emitter]
+    fn emit_do_expr(&mut self, n: &DoExpr) -> Result {
+        self.emit_leading_comments_of_pos(n.span.lo())?;
+
+        keyword!("do");
+        formatting_space!();
+        emit!(n.body);
+    }
+
+    #[emitter]
+    fn emit_bind_expr(&mut self, n: &BindExpr) -> Result {
+        self.emit_leading_comments_of_pos(n.span.lo())?;
+
+        if let Some(ref obj) = n.obj {
+            emit!(obj);
+        }
+        punct!("::");
+        emit!(n.callee);
+    }
+
+    #[emitter]
+    fn emit_throw_expr(&mut self, n: &ThrowExpr) -> Result {
+        self.emit_leading_comments_of_pos(n.span.lo())?;
+
+        keyword!("throw");
+        space!();
+        emit!(n.arg);
+    }
+
+    /// Record and tuple literals have no runtime support yet, so they must be
+    /// lowered before emitting.
+    #[emitter]
+    fn emit_record_lit(&mut self, n: &RecordLit) -> Result {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "record literal at {} should be lowered before emitting",
+                self.cm.span_to_string(n.span)
+            ),
+        ));
+    }
+
+    #[emitter]
+    fn emit_tuple_lit(&mut self, n: &TupleLit) -> Result {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "tuple literal at {} should be lowered before emitting",
+                self.cm.span_to_string(n.span)
+            ),
+        ));
+    }
+
     #[emitter]
     fn emit_opt_chain(&mut self, n: &OptChainExpr) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
@@ -553,7 +685,10 @@ impl<'a> Emitter<'a> {
     fn emit_call_expr(&mut self, node: &CallExpr) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        emit!(node.callee);
+        match node.callee {
+            ExprOrSuper::Expr(ref callee) => self.emit_postfix_operand(callee)?,
+            ExprOrSuper::Super(ref callee) => emit!(callee),
+        }
 
         punct!("(");
         self.emit_expr_or_spreads(node.span(), &node.args, ListFormat::CallExpressionArguments)?;
@@ -566,7 +701,7 @@ impl<'a> Emitter<'a> {
 
         keyword!("new");
         space!();
-        emit!(node.callee);
+        self.emit_postfix_operand(&node.callee)?;
 
         if let Some(type_args) = &node.type_args {
             emit!(type_args);
@@ -583,7 +718,10 @@ impl<'a> Emitter<'a> {
     fn emit_member_expr(&mut self, node: &MemberExpr) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        emit!(node.obj);
+        match node.obj {
+            ExprOrSuper::Expr(ref obj) => self.emit_postfix_operand(obj)?,
+            ExprOrSuper::Super(ref obj) => emit!(obj),
+        }
 
         if node.computed {
             punct!("[");
@@ -607,30 +745,32 @@ impl<'a> Emitter<'a> {
     /// `1..toString` is a valid property access, emit a dot after the literal
     pub fn needs_2dots_for_property_access(&self, expr: &ExprOrSuper) -> bool {
         match *expr {
-            ExprOrSuper::Expr(ref expr) => {
-                match **expr {
-                    Expr::Lit(Lit::Num(Number { span, value })) => {
-                        if value.fract() == 0.0 {
-                            return true;
-                        }
-                        // check if numeric literal is a decimal literal that was originally written
-                        // with a dot
-                        if let Ok(text) = self.cm.span_to_snippet(span) {
-                            if text.contains('.') {
-                                return false;
-                            }
-                            text.starts_with('0') || text.ends_with(' ')
-                        } else {
-                            true
-                        }
-                    }
-                    _ => false,
+            ExprOrSuper::Expr(ref expr) => match **expr {
+                // A dot after decimal digits is a part of the literal, unlike
+                // after `1.5`, `1e3`, `0x10` or legacy octal literals.
+                Expr::Lit(Lit::Num(ref num)) => {
+                    let text = self.num_text(num);
+                    text.bytes().all(|b| b.is_ascii_digit())
+                        && (text.len() == 1 || !text.starts_with('0'))
                 }
-            }
+                _ => false,
+            },
             _ => false,
         }
     }
 
+    /// Emits the object of a member expression, a callee or a tag, where `!0`
+    /// and `!1` can't be used for booleans.
+    fn emit_postfix_operand(&mut self, node: &Expr) -> Result {
+        match *node {
+            Expr::Lit(Lit::Bool(Bool { span, value })) => {
+                keyword!(self, span, if value { "true" } else { "false" });
+                Ok(())
+            }
+            _ => node.emit_with(self),
+        }
+    }
+
     #[emitter]
     fn emit_arrow_expr(&mut self, node: &ArrowExpr) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
@@ -758,7 +898,11 @@ impl<'a> Emitter<'a> {
 
             for (i, left) in lefts.into_iter().rev().enumerate() {
                 if i == 0 {
-                    emit!(left.left);
+                    if left.op == op!("**") {
+                        self.emit_postfix_operand(&left.left)?;
+                    } else {
+                        emit!(left.left);
+                    }
                 }
                 // Check if it's last
                 if i + 1 != len {
@@ -793,10 +937,6 @@ impl<'a> Emitter<'a> {
             space!();
             emit!(i);
             emit!(node.class.type_params);
-
-            formatting_space!();
-        } else {
-            space!();
         }
 
         self.emit_class_trailing(&node.class)?;
@@ -805,12 +945,27 @@ impl<'a> Emitter<'a> {
     #[emitter]
     fn emit_class_trailing(&mut self, node: &Class) -> Result {
         if node.super_class.is_some() {
+            space!();
             keyword!("extends");
             space!();
-            emit!(node.super_class);
+            if let Some(super_class) = &node.super_class {
+                self.emit_postfix_operand(super_class)?;
+            }
+            emit!(node.super_type_params);
+        }
+
+        if !node.implements.is_empty() {
+            space!();
+            keyword!("implements");
             space!();
+            self.emit_list(
+                node.span,
+                Some(&node.implements),
+                ListFormat::HeritageClauseTypes,
+            )?;
         }
 
+        formatting_space!();
         punct!("{");
         self.emit_list(node.span, Some(&node.body), ListFormat::ClassMembers)?;
         punct!("}");
@@ -826,9 +981,19 @@ impl<'a> Emitter<'a> {
             ClassMember::PrivateProp(ref n) => emit!(n),
             ClassMember::TsIndexSignature(ref n) => emit!(n),
             ClassMember::Empty(ref n) => emit!(n),
+            ClassMember::StaticBlock(ref n) => emit!(n),
         }
     }
 
+    #[emitter]
+    fn emit_static_block(&mut self, n: &StaticBlock) -> Result {
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        keyword!("static");
+        formatting_space!();
+        emit!(n.body);
+    }
+
     #[emitter]
     fn emit_private_method(&mut self, n: &PrivateMethod) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
@@ -873,7 +1038,7 @@ impl<'a> Emitter<'a> {
         if n.value {
             keyword!(n.span, "true")
         } else {
-            keyword!(n.span, "falsee")
+            keyword!(n.span, "false")
         }
     }
 
@@ -887,6 +1052,10 @@ impl<'a> Emitter<'a> {
             keyword!("static");
             space!();
         }
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
         match n.kind {
             MethodKind::Method => {
                 if n.function.is_async {
@@ -913,6 +1082,10 @@ impl<'a> Emitter<'a> {
             }
         }
 
+        if n.is_optional {
+            punct!("?");
+        }
+
         punct!("(");
         self.emit_list(
             n.function.span,
@@ -960,7 +1133,7 @@ impl<'a> Emitter<'a> {
             emit!(value);
         }
 
-        semi!();
+        punct!(";");
     }
 
     #[emitter]
@@ -971,16 +1144,21 @@ impl<'a> Emitter<'a> {
             self.emit_accesibility(n.accessibility)?;
         }
 
-        if n.readonly {
-            keyword!("readonly");
-            space!()
-        }
-
         if n.is_static {
             keyword!("static");
             space!();
         }
 
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
+        if n.readonly {
+            keyword!("readonly");
+            space!()
+        }
+
         if n.computed {
             punct!("[");
             emit!(n.key);
@@ -989,6 +1167,14 @@ impl<'a> Emitter<'a> {
             emit!(n.key);
         }
 
+        if n.is_optional {
+            punct!("?");
+        }
+
+        if n.definite {
+            punct!("!");
+        }
+
         if let Some(ty) = &n.type_ann {
             punct!(":");
             space!();
@@ -1003,7 +1189,10 @@ impl<'a> Emitter<'a> {
             emit!(v);
         }
 
-        semi!();
+        // Required even with `Semicolons::Asi`, as a field followed by a
+        // generator method, or named `get` or `set`, would be merged with the
+        // next member.
+        punct!(";");
     }
 
     fn emit_accesibility(&mut self, n: Option<Accessibility>) -> Result {
@@ -1098,7 +1287,7 @@ impl<'a> Emitter<'a> {
         }
 
         punct!("(");
-        self.emit_list(node.span, Some(&node.params), ListFormat::CommaListElements)?;
+        self.emit_list(node.span, Some(&node.params), ListFormat::Parameters)?;
         punct!(")");
 
         if let Some(ty) = &node.return_type {
@@ -1165,7 +1354,7 @@ impl<'a> Emitter<'a> {
 
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        emit!(node.tag);
+        self.emit_postfix_operand(&node.tag)?;
         emit!(node.type_params);
         punct!("`");
         let i = 0;
@@ -1296,15 +1485,20 @@ impl<'a> Emitter<'a> {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
         punct!("{");
-        if !self.cfg.minify {
-            self.wr.write_line()?;
-        }
-        self.emit_list(
+        // The formatter mode prints short objects on a single line.
+        let format = self.format_list(
             node.span(),
             Some(&node.props),
             ListFormat::ObjectLiteralExpressionProperties,
-        )?;
-        if !self.cfg.minify {
+            0,
+            node.props.len(),
+        );
+        let multi_line = !self.cfg.minify && format.contains(ListFormat::MultiLine);
+        if multi_line {
+            self.wr.write_line()?;
+        }
+        self.emit_list(node.span(), Some(&node.props), format)?;
+        if multi_line {
             self.wr.write_line()?;
         }
         punct!("}");
@@ -1394,6 +1588,23 @@ impl<'a> Emitter<'a> {
     fn emit_paren_expr(&mut self, node: &ParenExpr) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
+        // Parens around these are never required. Strings may become
+        // directives, and numbers and regexes may be merged with a dot or a
+        // slash.
+        let is_primary = match *node.expr {
+            Expr::Ident(..)
+            | Expr::This(..)
+            | Expr::Array(..)
+            | Expr::Paren(..)
+            | Expr::Tpl(..)
+            | Expr::Lit(Lit::Null(..)) => true,
+            _ => false,
+        };
+        if self.cfg.minify && is_primary {
+            emit!(node.expr);
+            return Ok(());
+        }
+
         punct!("(");
         emit!(node.expr);
         punct!(")");
@@ -1467,6 +1678,8 @@ impl<'a> Emitter<'a> {
         start: usize,
         count: usize,
     ) -> Result {
+        let format = self.format_list(parent_node, children, format, start, count);
+
         if children.is_none() && format.contains(ListFormat::OptionalIfUndefined) {
             return Ok(());
         }
@@ -1811,7 +2024,7 @@ impl<'a> Emitter<'a> {
         punct!(":");
         formatting_space!();
         emit!(node.value);
-        space!();
+        formatting_space!();
     }
 
     #[emitter]
@@ -2035,7 +2248,7 @@ impl<'a> Emitter<'a> {
             punct!(")");
         }
 
-        space!();
+        formatting_space!();
 
         emit!(node.body);
     }
@@ -2144,9 +2357,9 @@ impl<'a> Emitter<'a> {
         keyword!("for");
         punct!("(");
         opt!(node.init);
-        semi!();
+        punct!(";");
         opt_leading_space!(node.test);
-        semi!();
+        punct!(";");
         opt_leading_space!(node.update);
         punct!(")");
 
@@ -2413,33 +2626,16 @@ fn unescape(s: &str) -> String {
     result
 }
 
-fn escape(s: &str) -> Cow<str> {
-    // let patterns = &[
-    //     "\\", "\u{0008}", "\u{000C}", "\n", "\r", "\t", "\u{000B}", "\00", "\01",
-    // "\02", "\03",     "\04", "\05", "\06", "\07", "\08", "\09", "\0",
-    // ];
-    // let replace_with = &[
-    //     "\\\\", "\\b", "\\f", "\\n", "\\r", "\\t", "\\v", "\\x000", "\\x001",
-    // "\\x002", "\\x003",     "\\x004", "\\x005", "\\x006", "\\x007", "\\x008",
-    // "\\x009", "\\0", ];
-    //
-    // {
-    //     let mut found = false;
-    //     for pat in patterns {
-    //         if s.contains(pat) {
-    //             found = true;
-    //             break;
-    //         }
-    //     }
-    //     if !found {
-    //         return Cow::Borrowed(s);
-    //     }
-    // }
-    //
-    // let ac = AhoCorasick::new(patterns);
-    //
-    // Cow::Owned(ac.replace_all(s, replace_with))
-    Cow::Owned(s.escape_default().to_string())
+/// Escapes `s` to be written between `quote`s.
+fn escape(s: &str, quote: char) -> String {
+    let mut buf = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\'' | '"' if c != quote => buf.push(c),
+            _ => buf.extend(c.escape_default()),
+        }
+    }
+    buf
 }
 
 #[cold]
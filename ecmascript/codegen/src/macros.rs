@@ -34,9 +34,6 @@ macro_rules! keyword {
 }
 
 macro_rules! punct {
-    ($emitter:expr, ";") => {
-        $emitter.wr.write_semi()?;
-    };
     ($emitter:expr, $s:expr) => {
         $emitter.wr.write_punct($s)?;
     };
@@ -68,11 +65,13 @@ macro_rules! formatting_space {
     };
 }
 
+/// Ends a statement or a member. Use `punct!(";")` if the semicolon is
+/// required.
 macro_rules! semi {
     ($emitter:expr) => {
-        punct!($emitter, ";")
+        $emitter.wr.write_semi()?;
     };
     ($emitter:expr, ) => {
-        punct!($emitter, ";")
+        semi!($emitter)
     };
 }
@@ -8,13 +8,13 @@ mod tests {
     #[test]
     fn block_statement() {
         assert_min("{}", "{}");
-        assert_min("{foo;}", "{foo;}");
+        assert_min("{foo;}", "{foo}");
     }
 
     #[test]
     fn labeled_statement() {
         assert_min("foo: {}", "foo:{}");
-        assert_min("foo: bar;", "foo:bar;");
+        assert_min("foo: bar;", "foo:bar");
     }
 
     #[test]
@@ -24,46 +24,43 @@ mod tests {
 
     #[test]
     fn declaration_statement() {
-        assert_min("var foo;", "var foo;");
-        assert_min("let foo;", "let foo;");
-        assert_min("const foo;", "const foo;");
-        assert_min("var foo = 10;", "var foo=10;");
-        assert_min("let foo = 10;", "let foo=10;");
-        assert_min("const foo = 10;", "const foo=10;");
-        assert_min("var foo, bar;", "var foo,bar;");
-        assert_min("let foo, bar;", "let foo,bar;");
-        assert_min("const foo, bar;", "const foo,bar;");
-        assert_min("var foo = 10, bar = 20;", "var foo=10,bar=20;");
-        assert_min("let foo = 10, bar = 20;", "let foo=10,bar=20;");
-        assert_min("const foo = 10, bar = 20;", "const foo=10,bar=20;");
-        assert_min("const a = {...foo};", "const a={...foo};");
+        assert_min("var foo;", "var foo");
+        assert_min("let foo;", "let foo");
+        assert_min("const foo;", "const foo");
+        assert_min("var foo = 10;", "var foo=10");
+        assert_min("let foo = 10;", "let foo=10");
+        assert_min("const foo = 10;", "const foo=10");
+        assert_min("var foo, bar;", "var foo,bar");
+        assert_min("let foo, bar;", "let foo,bar");
+        assert_min("const foo, bar;", "const foo,bar");
+        assert_min("var foo = 10, bar = 20;", "var foo=10,bar=20");
+        assert_min("let foo = 10, bar = 20;", "let foo=10,bar=20");
+        assert_min("const foo = 10, bar = 20;", "const foo=10,bar=20");
+        assert_min("const a = {...foo};", "const a={...foo}");
     }
 
     #[test]
     fn if_statement() {
-        assert_min("if (true) foo;", "if(true)foo;");
-        assert_min("if (true) { foo; }", "if(true){foo;}");
-        assert_min("if (true) foo; else bar;", "if(true)foo;else bar;");
-        assert_min(
-            "if (true) { foo; } else { bar; }",
-            "if(true){foo;}else{bar;}",
-        );
-        assert_min("if (true) foo; else { bar; }", "if(true)foo;else{bar;}");
-        assert_min("if (true) { foo; } else bar;", "if(true){foo;}else bar;");
-        assert_min("if (true) y(); else x++;", "if(true)y();else x++;");
-        assert_min("if (true) y(); else x--;", "if(true)y();else x--;");
+        assert_min("if (true) foo;", "if(!0)foo");
+        assert_min("if (true) { foo; }", "if(!0){foo}");
+        assert_min("if (true) foo; else bar;", "if(!0)foo;else bar");
+        assert_min("if (true) { foo; } else { bar; }", "if(!0){foo}else{bar}");
+        assert_min("if (true) foo; else { bar; }", "if(!0)foo;else{bar}");
+        assert_min("if (true) { foo; } else bar;", "if(!0){foo}else bar");
+        assert_min("if (true) y(); else x++;", "if(!0)y();else x++");
+        assert_min("if (true) y(); else x--;", "if(!0)y();else x--");
     }
 
     #[test]
     fn while_statement() {
-        assert_min("while (true) foo;", "while(true)foo;");
-        assert_min("while (true) { foo; }", "while(true){foo;}");
+        assert_min("while (true) foo;", "while(!0)foo");
+        assert_min("while (true) { foo; }", "while(!0){foo}");
     }
 
     #[test]
     fn do_statement() {
-        assert_min("do { foo; } while (true)", "do{foo;}while(true)");
-        assert_min("do foo; while (true)", "do foo;while(true)");
+        assert_min("do { foo; } while (true)", "do{foo}while(!0)");
+        assert_min("do foo; while (true)", "do foo;while(!0)");
     }
 
     #[test]
@@ -81,7 +78,7 @@ mod tests {
     fn import() {
         assert_min(
             "import colors, { color } from 'patterns/colors';",
-            "import colors,{color}from'patterns/colors';",
+            "import colors,{color}from'patterns/colors'",
         );
         assert_pretty(
             "import colors, { color } from 'patterns/colors';",
@@ -91,12 +88,12 @@ mod tests {
 
     #[test]
     fn issue_204_01() {
-        assert_min(r#"'\r\n';"#, r#"'\r\n';"#);
+        assert_min(r#"'\r\n';"#, r#"'\r\n'"#);
     }
 
     #[test]
     fn issue_204_02() {
-        assert_min(r#"const a = fn() + '\r\n';"#, r#"const a=fn()+'\r\n';"#);
+        assert_min(r#"const a = fn() + '\r\n';"#, r#"const a=fn()+'\r\n'"#);
     }
 
     #[test]
@@ -105,7 +102,7 @@ mod tests {
             "#!/usr/bin/env node
 let x = 4;",
             "#!/usr/bin/env node
-let x=4;",
+let x=4",
         );
     }
 
@@ -123,7 +120,7 @@ const Link = 'Boo';",
     fn issue_266() {
         assert_min(
             "'Q' + +x1 + ',' + +y1 + ',' + (this._x1 = +x) + ',' + (this._y1 = +y);",
-            "'Q'+ +x1+','+ +y1+','+(this._x1=+x)+','+(this._y1=+y);",
+            "'Q'+ +x1+','+ +y1+','+(this._x1=+x)+','+(this._y1=+y)",
         );
     }
 }
@@ -23,7 +23,9 @@ impl Builder {
         let mut e = Emitter {
             cfg: self.cfg,
             cm: self.cm.clone(),
-            wr: Box::new(text_writer::JsWriter::new(self.cm.clone(), "\n", s, None)),
+            wr: Box::new(
+                text_writer::JsWriter::new(self.cm.clone(), "\n", s, None).with_config(&self.cfg),
+            ),
             comments: Some(&self.comments),
         };
 
@@ -73,13 +75,27 @@ fn parse_then_emit(from: &str, cfg: Config, syntax: Syntax) -> String {
 }
 
 pub(crate) fn assert_min(from: &str, to: &str) {
-    let out = parse_then_emit(from, Config { minify: true }, Syntax::default());
+    let out = parse_then_emit(
+        from,
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        Syntax::default(),
+    );
 
     assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to),);
 }
 
 pub(crate) fn assert_pretty(from: &str, to: &str) {
-    let out = parse_then_emit(from, Config { minify: false }, Syntax::default());
+    let out = parse_then_emit(
+        from,
+        Config {
+            minify: false,
+            ..Default::default()
+        },
+        Syntax::default(),
+    );
 
     assert_eq!(DebugUsingDisplay(&out.trim()), DebugUsingDisplay(to),);
 }
@@ -167,13 +183,33 @@ fn no_octal_escape() {
 '\x000';
 '\x001';
 '\x009'"#,
-        r#"'\u{0}a';
-'\u{0}0';
-'\u{0}1';
-'\u{0}9';"#,
+        r#"'\x00a';
+'\x000';
+'\x001';
+'\x009';"#,
+    );
+    assert_min(
+        r#"'\x00a';
+'\x000';
+'\x001';
+'\x009'"#,
+        r#"'\u{0}a';'\u{0}0';'\u{0}1';'\u{0}9'"#,
     );
 }
 
+#[test]
+fn raw_num() {
+    test_identical("0x10;\n1e3;\n.5;\n0b11;\n0o17;\n1.50;");
+    test_from_to("1_000;", "1000;");
+    assert_min("0x10;1e3", "16;1e3");
+}
+
+#[test]
+fn raw_str() {
+    test_identical("'a';\n\"b\";\n'\\x41';\n\"\\u0041\";\n'\\\nb';");
+    assert_min("'\\x41'", "'A'");
+}
+
 #[test]
 fn empty_named_export() {
     test_from_to("export { }", "export { };");
@@ -183,8 +219,11 @@ fn empty_named_export() {
 fn empty_named_export_min() {
     test_from_to_custom_config(
         "export { }",
-        "export{};",
-        Config { minify: true },
+        "export{}",
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Default::default(),
     );
 }
@@ -198,8 +237,11 @@ fn empty_named_export_from() {
 fn empty_named_export_from_min() {
     test_from_to_custom_config(
         "export { } from 'foo';",
-        "export{}from'foo';",
-        Config { minify: true },
+        "export{}from'foo'",
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Default::default(),
     );
 }
@@ -213,8 +255,11 @@ fn named_export_from() {
 fn named_export_from_min() {
     test_from_to_custom_config(
         "export { bar } from 'foo';",
-        "export{bar}from'foo';",
-        Config { minify: true },
+        "export{bar}from'foo'",
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Default::default(),
     );
 }
@@ -236,8 +281,11 @@ fn export_namespace_from() {
 fn export_namespace_from_min() {
     test_from_to_custom_config(
         "export * as Foo from 'foo';",
-        "export*as Foo from'foo';",
-        Config { minify: true },
+        "export*as Foo from'foo'",
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Syntax::Es(EsConfig {
             export_namespace_from: true,
             ..EsConfig::default()
@@ -262,8 +310,11 @@ fn named_and_namespace_export_from() {
 fn named_and_namespace_export_from_min() {
     test_from_to_custom_config(
         "export * as Foo, { bar } from 'foo';",
-        "export*as Foo,{bar}from'foo';",
-        Config { minify: true },
+        "export*as Foo,{bar}from'foo'",
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Syntax::Es(EsConfig {
             export_namespace_from: true,
             ..EsConfig::default()
@@ -432,6 +483,87 @@ fn jsx_1() {
     );
 }
 
+#[test]
+fn static_block() {
+    test_from_to_custom_config(
+        "class Foo { static { this.bar = 1; } }",
+        "class Foo{static{this.bar=1}}",
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        Syntax::Es(EsConfig {
+            static_blocks: true,
+            ..Default::default()
+        }),
+    );
+}
+
+#[test]
+fn pipeline_and_partial_application() {
+    test_from_to_custom_config(
+        "a |> f(?, b);",
+        "a|>f(?,b)",
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        Syntax::Es(EsConfig {
+            pipeline_operator: true,
+            partial_application: true,
+            ..Default::default()
+        }),
+    );
+}
+
+#[test]
+fn do_expression() {
+    test_from_to_custom_config(
+        "let a = do { 1; };",
+        "let a=do{1}",
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        Syntax::Es(EsConfig {
+            do_expressions: true,
+            ..Default::default()
+        }),
+    );
+}
+
+#[test]
+fn function_bind() {
+    test_from_to_custom_config(
+        "a::b; ::a.b(c);",
+        "a::b;::a.b(c)",
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        Syntax::Es(EsConfig {
+            fn_bind: true,
+            ..Default::default()
+        }),
+    );
+}
+
+#[test]
+fn throw_expression() {
+    test_from_to_custom_config(
+        "a || throw b;",
+        "a||throw b",
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        Syntax::Es(EsConfig {
+            throw_expressions: true,
+            ..Default::default()
+        }),
+    );
+}
+
 #[derive(Debug, Clone)]
 struct Buf(Arc<RwLock<Vec<u8>>>);
 impl Write for Buf {
@@ -452,3 +584,242 @@ impl<'a> Debug for DebugUsingDisplay<'a> {
         Display::fmt(self.0, f)
     }
 }
+
+#[test]
+fn quotes() {
+    let src = r#"a = "foo"; b = 'bar'; c = 'it\'s'; d = "say \"hi\"";"#;
+    let cfg = |quotes| Config {
+        quotes,
+        ..Default::default()
+    };
+
+    test_from_to_custom_config(
+        src,
+        r#"a = "foo";
+b = 'bar';
+c = 'it\'s';
+d = "say \"hi\"";"#,
+        cfg(QuoteStyle::Preserve),
+        Default::default(),
+    );
+    test_from_to_custom_config(
+        src,
+        r#"a = "foo";
+b = "bar";
+c = "it's";
+d = 'say "hi"';"#,
+        cfg(QuoteStyle::Double),
+        Default::default(),
+    );
+    test_from_to_custom_config(
+        src,
+        r#"a = 'foo';
+b = 'bar';
+c = "it's";
+d = 'say "hi"';"#,
+        cfg(QuoteStyle::Single),
+        Default::default(),
+    );
+}
+
+#[test]
+fn asi_semicolons() {
+    let cfg = Config {
+        semicolons: Semicolons::Asi,
+        ..Default::default()
+    };
+
+    test_from_to_custom_config(
+        "let a = 1; (b || c).d(); [1, 2].e(); for (;;) { if (x) { break; } else ; }",
+        "let a = 1
+;(b || c).d()
+;[
+    1,
+    2
+].e()
+for(;;){
+    if (x) {
+        break
+    } else ;
+}",
+        cfg,
+        Default::default(),
+    );
+    test_from_to_custom_config(
+        "class A { a = 1; static b; c() { return; } }",
+        "class A {
+    a = 1;
+    static b;
+    c() {
+        return
+    }
+}",
+        cfg,
+        Syntax::Es(EsConfig {
+            class_props: true,
+            ..Default::default()
+        }),
+    );
+}
+
+#[test]
+fn indent() {
+    let src = "if (a) { if (b) { c(); } }";
+
+    test_from_to_custom_config(
+        src,
+        "if (a) {\n  if (b) {\n    c();\n  }\n}",
+        Config {
+            indent: Indent::Spaces(2),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+    test_from_to_custom_config(
+        src,
+        "if (a) {\n\tif (b) {\n\t\tc();\n\t}\n}",
+        Config {
+            indent: Indent::Tabs,
+            ..Default::default()
+        },
+        Default::default(),
+    );
+}
+
+#[test]
+fn trailing_newline() {
+    let cfg = |minify, trailing_newline| Config {
+        minify,
+        trailing_newline,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        parse_then_emit("a; b", cfg(true, false), Default::default()),
+        "a;b"
+    );
+    assert_eq!(
+        parse_then_emit("a; b", cfg(true, true), Default::default()),
+        "a;b\n"
+    );
+    assert_eq!(
+        parse_then_emit("a; b", cfg(false, true), Default::default()),
+        "a;\nb;\n"
+    );
+}
+
+#[test]
+fn minify_bool() {
+    assert_min("a = true; b = false", "a=!0;b=!1");
+    assert_min("true.toString()", "true.toString()");
+    assert_min("false[a]", "false[a]");
+    assert_min("true()", "true()");
+    assert_min("new true", "new true");
+    assert_min("true``", "true``");
+    assert_min("true ** 2", "true**2");
+    assert_min("a ** true", "a**!0");
+    assert_min("class A extends true {}", "class A extends true{}");
+}
+
+#[test]
+fn minify_num() {
+    assert_min("1000000", "1e6");
+    assert_min("0.5; 1.50", ".5;1.5");
+    assert_min("0.000001", "1e-6");
+    assert_min("0x10.a; 1.5.a; 0.5.a", "16..a;1.5.a;.5.a");
+    assert_min("1_000", "1e3");
+}
+
+#[test]
+fn minify_class() {
+    assert_min("class A extends B {}", "class A extends B{}");
+    assert_min("class A {}", "class A{}");
+}
+
+#[test]
+fn minify_parens() {
+    assert_min("(a); (this); ([]); (null); (`a`)", "a;this;[];null;`a`");
+    assert_min("('a')", "('a')");
+    assert_min("(1).a; (/a/).b", "(1).a;(/a/).b");
+    assert_min("(a, b)", "(a,b)");
+}
+
+#[test]
+fn minify_semicolons() {
+    assert_min("if (a) { b; c; }", "if(a){b;c}");
+    assert_min("function f() { return; }", "function f(){return}");
+    assert_min("a; ; b", "a;;b");
+    assert_min("if (a) ;", "if(a);");
+    assert_min("class A { a = 1; }", "class A{a=1;}");
+}
+
+#[track_caller]
+fn assert_formatted(from: &str, to: &str, syntax: Syntax) {
+    let cfg = Config {
+        indent: Indent::Spaces(2),
+        print_width: Some(30),
+        ..Default::default()
+    };
+
+    let out = parse_then_emit(from, cfg, syntax);
+    assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to));
+
+    // The output should be stable.
+    let out = parse_then_emit(&out, cfg, syntax);
+    assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to));
+}
+
+#[test]
+fn format_lists() {
+    let s = Syntax::default();
+
+    assert_formatted("foo(a,\n b)", "foo(a, b);", s);
+    assert_formatted(
+        "foo(first, second, third, fourth)",
+        "foo(\n  first,\n  second,\n  third,\n  fourth\n);",
+        s,
+    );
+    assert_formatted(
+        "function f(first, second, third) {}",
+        "function f(\n  first,\n  second,\n  third\n) {\n}",
+        s,
+    );
+    assert_formatted("a = [\n1,\n2\n]", "a = [1, 2];", s);
+    assert_formatted("a = { b: 1, c: 2 }", "a = { b: 1, c: 2 };", s);
+    assert_formatted(
+        "a = { b: 1, c: [first, second, third] }",
+        "a = {\n  b: 1,\n  c: [first, second, third]\n};",
+        s,
+    );
+    assert_formatted(
+        "foo(a, function () { b(); })",
+        "foo(a, function() {\n  b();\n});",
+        s,
+    );
+}
+
+#[test]
+fn format_jsx() {
+    let s = Syntax::Es(EsConfig {
+        jsx: true,
+        ..Default::default()
+    });
+
+    assert_formatted("<a>\n  <b />\n</a>", "<a><b /></a>;", s);
+    assert_formatted(
+        "<div><span>first</span><span>second</span></div>",
+        "<div>\n  <span>first</span>\n  <span>second</span>\n</div>;",
+        s,
+    );
+    assert_formatted(
+        "<input type=\"text\" value={value} disabled />",
+        "<input\n  type=\"text\"\n  value={value}\n  disabled\n/>;",
+        s,
+    );
+    // Line breaks around texts are significant.
+    assert_formatted(
+        "<p>some long text in a paragraph <b>bold</b></p>",
+        "<p>some long text in a paragraph <b>bold</b></p>;",
+        s,
+    );
+}
@@ -35,6 +35,14 @@ pub trait WriteJs {
     fn write_symbol(&mut self, span: Span, s: &str) -> Result;
 
     fn write_punct(&mut self, s: &'static str) -> Result;
+
+    /// Returns the column where the next character will be written.
+    ///
+    /// Used by the formatter mode to break long lines. Writers which don't
+    /// track columns can use the default implementation.
+    fn column(&self) -> usize {
+        0
+    }
 }
 
 impl<W> WriteJs for Box<W>
@@ -93,4 +101,8 @@ where
     fn write_punct(&mut self, s: &'static str) -> Result {
         (**self).write_punct(s)
     }
+
+    fn column(&self) -> usize {
+        (**self).column()
+    }
 }
@@ -1,4 +1,5 @@
 use super::{Result, WriteJs};
+use crate::config::{Config, Indent, Semicolons};
 use std::io::{self, Write};
 use swc_common::{sync::Lrc, BytePos, LineCol, SourceMap, Span};
 
@@ -12,6 +13,12 @@ pub struct JsWriter<'a, W: Write> {
     /// We may use this in future...
     _cm: Lrc<SourceMap>,
     indent: usize,
+    indent_str: String,
+    semicolons: Semicolons,
+    /// A semicolon which is written only if the line continues.
+    pending_semi: bool,
+    /// The semicolon at the end of the previous line was omitted.
+    omitted_semi: bool,
     line_start: bool,
     line_count: usize,
     line_pos: usize,
@@ -31,6 +38,10 @@ impl<'a, W: Write> JsWriter<'a, W> {
         JsWriter {
             _cm: cm,
             indent: Default::default(),
+            indent_str: "    ".into(),
+            semicolons: Semicolons::Always,
+            pending_semi: false,
+            omitted_semi: false,
             line_start: true,
             line_count: 0,
             line_pos: Default::default(),
@@ -41,12 +52,51 @@ impl<'a, W: Write> JsWriter<'a, W> {
         }
     }
 
-    fn write_indent_string(&mut self) -> io::Result<usize> {
-        const INDENT: &[u8] = b"    ";
+    /// Applies the indentation and the semicolon policy of `cfg`.
+    pub fn with_config(mut self, cfg: &Config) -> Self {
+        self.indent_str = match cfg.indent {
+            Indent::Spaces(width) => " ".repeat(width),
+            Indent::Tabs => "\t".into(),
+        };
+        // Minified output has no line breaks, so only the semicolons before `}`
+        // and at the end are omitted.
+        self.semicolons = if cfg.minify {
+            Semicolons::Asi
+        } else {
+            cfg.semicolons
+        };
+        self
+    }
 
+    fn write_indent_string(&mut self) -> io::Result<usize> {
         let mut cnt = 0;
         for _ in 0..self.indent {
-            cnt += self.raw_write(INDENT)?;
+            let written = self.wr.write(self.indent_str.as_bytes())?;
+            self.written_bytes += written;
+            self.line_pos += written;
+            cnt += written;
+        }
+
+        Ok(cnt)
+    }
+
+    /// Writes the pending semicolon unless `data` closes a block, and inserts
+    /// an omitted one if `data` would continue the previous statement.
+    fn write_semi_before(&mut self, data: &str) -> io::Result<usize> {
+        let mut cnt = 0;
+
+        if self.pending_semi {
+            self.pending_semi = false;
+            if !data.starts_with('}') {
+                cnt += self.raw_write(b";")?;
+            }
+        }
+
+        if self.omitted_semi {
+            self.omitted_semi = false;
+            if data.starts_with(&['(', '[', '`', '+', '-', '/', '<'][..]) {
+                cnt += self.raw_write(b";")?;
+            }
         }
 
         Ok(cnt)
@@ -73,6 +123,7 @@ impl<'a, W: Write> JsWriter<'a, W> {
                 cnt += self.write_indent_string()?;
                 self.line_start = false;
             }
+            cnt += self.write_semi_before(data)?;
             cnt += self.raw_write(data.as_bytes())?;
 
             if let Some(span) = span {
@@ -109,7 +160,17 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
     }
 
     fn write_semi(&mut self) -> Result {
-        self.write(None, ";")?;
+        match self.semicolons {
+            Semicolons::Always => {
+                self.write(None, ";")?;
+            }
+            Semicolons::Asi => {
+                if self.pending_semi {
+                    self.raw_write(b";")?;
+                }
+                self.pending_semi = true;
+            }
+        }
         Ok(())
     }
     fn write_space(&mut self) -> Result {
@@ -138,6 +199,11 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
     }
 
     fn write_line(&mut self) -> Result {
+        if self.pending_semi {
+            self.pending_semi = false;
+            self.omitted_semi = true;
+        }
+
         if !self.line_start {
             self.raw_write(self.new_line.as_bytes())?;
             self.line_count += 1;
@@ -164,7 +230,11 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
     }
 
     fn write_comment(&mut self, span: Span, s: &str) -> Result {
+        // Comments don't continue statements.
+        let omitted_semi = self.omitted_semi;
+        self.omitted_semi = false;
         self.write(Some(span), s)?;
+        self.omitted_semi = omitted_semi;
         Ok(())
     }
 
@@ -187,6 +257,14 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
         self.write(None, s)?;
         Ok(())
     }
+
+    fn column(&self) -> usize {
+        if self.line_start {
+            self.indent * self.indent_str.len()
+        } else {
+            self.line_pos
+        }
+    }
 }
 
 fn compute_line_starts(s: &str) -> Vec<usize> {
@@ -54,6 +54,10 @@ impl<W: WriteJs> WriteJs for OmitTrailingSemi<W> {
     with_semi!(write_str(s: &str));
     with_semi!(write_symbol(span: Span, s: &str));
     with_semi!(write_punct(s: &'static str));
+
+    fn column(&self) -> usize {
+        self.inner.column() + self.pending_semi as usize
+    }
 }
 
 impl<W: WriteJs> OmitTrailingSemi<W> {
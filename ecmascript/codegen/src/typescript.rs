@@ -303,6 +303,7 @@ impl<'a> Emitter<'a> {
         space!();
 
         emit!(n.id);
+        emit!(n.type_params);
 
         if !n.extends.is_empty() {
             space!();
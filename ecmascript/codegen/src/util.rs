@@ -169,9 +169,11 @@ impl StartsWithAlphaNum for Expr {
             | Expr::This(_)
             | Expr::Yield(_)
             | Expr::New(_)
+            | Expr::Do(_)
+            | Expr::Throw(_)
             | Expr::MetaProp(_) => true,
 
-            Expr::PrivateName(_) => false,
+            Expr::PrivateName(_) | Expr::ArgPlaceholder(_) => false,
 
             // Handle other literals.
             Expr::Lit(_) => false,
@@ -192,6 +194,11 @@ impl StartsWithAlphaNum for Expr {
             })
             | Expr::Member(MemberExpr { obj: ref left, .. }) => left.starts_with_alpha_num(),
 
+            Expr::Bind(BindExpr { ref obj, .. }) => match obj {
+                Some(obj) => obj.starts_with_alpha_num(),
+                None => false,
+            },
+
             Expr::Unary(UnaryExpr { op, .. }) => match op {
                 op!("void") | op!("delete") | op!("typeof") => true,
                 _ => false,
@@ -212,6 +219,8 @@ impl StartsWithAlphaNum for Expr {
 
             Expr::Tpl(_) | Expr::Array(_) | Expr::Object(_) | Expr::Paren(_) => false,
 
+            Expr::Record(_) | Expr::Tuple(_) => false,
+
             Expr::TaggedTpl(TaggedTpl { ref tag, .. }) => tag.starts_with_alpha_num(),
 
             // it's empty
@@ -0,0 +1,353 @@
+use serde::Serialize;
+use swc_atoms::{js_word, JsWord};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Node, Visit, VisitWith};
+
+/// Static imports, dynamic imports and exported names of a module.
+///
+/// Type-only imports and exports are not included.
+///
+/// `require()` calls and exports of CommonJS are detected by names, so local
+/// bindings named `require`, `module` or `exports` are not distinguished from
+/// the ones of CommonJS.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleInfo {
+    /// Specifiers of imports and re-exports, in source order.
+    pub imports: Vec<JsWord>,
+    /// Specifiers of `import()` with a string literal.
+    pub dynamic_imports: Vec<JsWord>,
+    /// Names exported by the module, including `default`.
+    pub exports: Vec<JsWord>,
+    /// Specifiers of `export * from`. Names exported by them are not known
+    /// without analyzing the imported modules.
+    pub star_exports: Vec<JsWord>,
+
+    /// Specifiers of `require()` with a string literal.
+    pub requires: Vec<JsWord>,
+    /// True if the module uses `module.exports` or `exports`.
+    pub commonjs: bool,
+    /// Names exported by assignments to `exports.foo` or
+    /// `module.exports.foo`, `Object.defineProperty(exports, 'foo', ...)`,
+    /// and keys of an object literal assigned to `module.exports`.
+    pub cjs_exports: Vec<JsWord>,
+    /// True if a value other than an object literal is assigned to
+    /// `module.exports`, like `module.exports = require('./foo')`. Names
+    /// exported by the module are not known in this case.
+    pub cjs_exports_replaced: bool,
+}
+
+/// Collects [ModuleInfo] of a module. This only looks at the syntax, so it
+/// can be used without transforming the module.
+pub fn analyze_module(module: &Module) -> ModuleInfo {
+    let mut v = InfoCollector::default();
+    module.visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+    v.info
+}
+
+/// Collects [ModuleInfo] of a script, which can be a CommonJS module.
+pub fn analyze_script(script: &Script) -> ModuleInfo {
+    let mut v = InfoCollector::default();
+    script.visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+    v.info
+}
+
+#[derive(Default)]
+struct InfoCollector {
+    info: ModuleInfo,
+}
+
+impl InfoCollector {
+    fn add_import(&mut self, src: &Str) {
+        if !self.info.imports.contains(&src.value) {
+            self.info.imports.push(src.value.clone());
+        }
+    }
+
+    fn add_export(&mut self, name: &JsWord) {
+        if !self.info.exports.contains(name) {
+            self.info.exports.push(name.clone());
+        }
+    }
+
+    fn add_pat(&mut self, pat: &Pat) {
+        for name in binding_idents(pat) {
+            self.add_export(&name.sym);
+        }
+    }
+
+    fn add_cjs_export(&mut self, name: &JsWord) {
+        self.info.commonjs = true;
+        if !self.info.cjs_exports.contains(name) {
+            self.info.cjs_exports.push(name.clone());
+        }
+    }
+
+    /// Handles `module.exports = value`.
+    fn replace_module_exports(&mut self, value: &Expr) {
+        self.info.commonjs = true;
+
+        let props = match value {
+            Expr::Object(obj) => &obj.props,
+            _ => {
+                self.info.cjs_exports_replaced = true;
+                return;
+            }
+        };
+
+        for prop in props {
+            let key = match prop {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::Shorthand(i) => Some(&i.sym),
+                    Prop::KeyValue(KeyValueProp { key, .. })
+                    | Prop::Getter(GetterProp { key, .. })
+                    | Prop::Setter(SetterProp { key, .. })
+                    | Prop::Method(MethodProp { key, .. }) => prop_name(key),
+                    Prop::Assign(..) => None,
+                },
+                PropOrSpread::Spread(..) => None,
+            };
+
+            match key {
+                Some(key) => self.add_cjs_export(key),
+                None => self.info.cjs_exports_replaced = true,
+            }
+        }
+    }
+}
+
+fn prop_name(key: &PropName) -> Option<&JsWord> {
+    match key {
+        PropName::Ident(i) => Some(&i.sym),
+        PropName::Str(s) => Some(&s.value),
+        PropName::Num(..) | PropName::Computed(..) => None,
+    }
+}
+
+fn is_ident(e: &Expr, name: &str) -> bool {
+    match e {
+        Expr::Ident(i) => &*i.sym == name,
+        _ => false,
+    }
+}
+
+/// Returns true for `exports` and `module.exports`.
+fn is_exports(e: &Expr) -> bool {
+    is_ident(e, "exports") || is_module_exports(e)
+}
+
+fn is_module_exports(e: &Expr) -> bool {
+    match e {
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            prop,
+            computed: false,
+            ..
+        }) => is_ident(obj, "module") && is_ident(prop, "exports"),
+        _ => false,
+    }
+}
+
+/// Returns the name of `foo` in `exports.foo` or `module.exports['foo']`.
+fn exported_name(e: &Expr) -> Option<&JsWord> {
+    match e {
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            prop,
+            computed,
+            ..
+        }) if is_exports(obj) => match &**prop {
+            Expr::Ident(i) if !computed => Some(&i.sym),
+            Expr::Lit(Lit::Str(s)) if *computed => Some(&s.value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Binding identifiers of a pattern.
+fn binding_idents(pat: &Pat) -> Vec<Ident> {
+    struct BindingCollector(Vec<Ident>);
+
+    impl Visit for BindingCollector {
+        fn visit_pat(&mut self, p: &Pat, _: &dyn Node) {
+            match p {
+                Pat::Ident(i) => self.0.push(i.clone()),
+                _ => p.visit_children_with(self),
+            }
+        }
+
+        fn visit_assign_pat_prop(&mut self, p: &AssignPatProp, _: &dyn Node) {
+            self.0.push(p.key.clone());
+        }
+
+        // Default values and computed keys are not bindings.
+        fn visit_expr(&mut self, _: &Expr, _: &dyn Node) {}
+    }
+
+    let mut v = BindingCollector(vec![]);
+    pat.visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+    v.0
+}
+
+impl Visit for InfoCollector {
+    fn visit_module_decl(&mut self, decl: &ModuleDecl, _: &dyn Node) {
+        match decl {
+            ModuleDecl::Import(import) => {
+                if !import.type_only {
+                    self.add_import(&import.src);
+                }
+            }
+
+            ModuleDecl::ExportDecl(export) => match &export.decl {
+                Decl::Class(ClassDecl { ident, .. }) | Decl::Fn(FnDecl { ident, .. }) => {
+                    self.add_export(&ident.sym)
+                }
+                Decl::Var(var) => {
+                    for decl in &var.decls {
+                        self.add_pat(&decl.name);
+                    }
+                }
+                Decl::TsEnum(e) if !e.declare => self.add_export(&e.id.sym),
+                Decl::TsModule(TsModuleDecl {
+                    declare: false,
+                    id: TsModuleName::Ident(id),
+                    ..
+                }) => self.add_export(&id.sym),
+                _ => {}
+            },
+
+            ModuleDecl::ExportNamed(export) if !export.type_only => {
+                if let Some(src) = &export.src {
+                    self.add_import(src);
+                }
+
+                for specifier in &export.specifiers {
+                    let name = match specifier {
+                        ExportSpecifier::Namespace(s) => &s.name,
+                        ExportSpecifier::Default(s) => &s.exported,
+                        ExportSpecifier::Named(s) => s.exported.as_ref().unwrap_or(&s.orig),
+                    };
+                    self.add_export(&name.sym);
+                }
+            }
+
+            ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::TsInterfaceDecl(..),
+                ..
+            }) => {}
+
+            ModuleDecl::ExportDefaultDecl(..) | ModuleDecl::ExportDefaultExpr(..) => {
+                self.add_export(&js_word!("default"))
+            }
+
+            ModuleDecl::ExportAll(export) => {
+                self.add_import(&export.src);
+                if !self.info.star_exports.contains(&export.src.value) {
+                    self.info.star_exports.push(export.src.value.clone());
+                }
+            }
+
+            _ => {}
+        }
+
+        decl.visit_children_with(self);
+    }
+
+    fn visit_call_expr(&mut self, call: &CallExpr, _: &dyn Node) {
+        call.visit_children_with(self);
+
+        let is_import = match &call.callee {
+            ExprOrSuper::Expr(callee) => matches!(
+                &**callee,
+                Expr::Ident(Ident {
+                    sym: js_word!("import"),
+                    ..
+                })
+            ),
+            _ => false,
+        };
+
+        if is_import {
+            if let Some(ExprOrSpread { spread: None, expr }) = call.args.first() {
+                if let Expr::Lit(Lit::Str(src)) = &**expr {
+                    if !self.info.dynamic_imports.contains(&src.value) {
+                        self.info.dynamic_imports.push(src.value.clone());
+                    }
+                }
+            }
+            return;
+        }
+
+        let callee = match &call.callee {
+            ExprOrSuper::Expr(callee) => &**callee,
+            _ => return,
+        };
+
+        if is_ident(callee, "require") {
+            if let Some(ExprOrSpread { spread: None, expr }) = call.args.first() {
+                if let Expr::Lit(Lit::Str(src)) = &**expr {
+                    if !self.info.requires.contains(&src.value) {
+                        self.info.requires.push(src.value.clone());
+                    }
+                }
+            }
+            return;
+        }
+
+        // `Object.defineProperty(exports, 'foo', { ... })`
+        if let Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            prop,
+            computed: false,
+            ..
+        }) = callee
+        {
+            if is_ident(obj, "Object") && is_ident(prop, "defineProperty") {
+                match &*call.args {
+                    [target, name, ..] if target.spread.is_none() && is_exports(&target.expr) => {
+                        if let Expr::Lit(Lit::Str(name)) = &*name.expr {
+                            self.add_cjs_export(&name.value);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn visit_assign_expr(&mut self, assign: &AssignExpr, _: &dyn Node) {
+        assign.visit_children_with(self);
+
+        if assign.op != AssignOp::Assign {
+            return;
+        }
+
+        let left = match &assign.left {
+            PatOrExpr::Expr(e) => &**e,
+            PatOrExpr::Pat(pat) => match &**pat {
+                Pat::Expr(e) => &**e,
+                _ => return,
+            },
+        };
+
+        if is_module_exports(left) {
+            self.replace_module_exports(&assign.right);
+        } else if let Some(name) = exported_name(left) {
+            self.add_cjs_export(name);
+        }
+    }
+
+    fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+        e.visit_children_with(self);
+
+        if let ExprOrSuper::Expr(obj) = &e.obj {
+            if is_ident(obj, "exports")
+                || (!e.computed && is_ident(obj, "module") && is_ident(&e.prop, "exports"))
+            {
+                self.info.commonjs = true;
+            }
+        }
+    }
+}
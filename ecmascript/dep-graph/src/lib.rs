@@ -1,3 +1,4 @@
+pub use self::info::{analyze_module, analyze_script, ModuleInfo};
 use swc_atoms::JsWord;
 use swc_common::{
     comments::{Comment, SingleThreadedComments},
@@ -6,6 +7,8 @@ use swc_common::{
 use swc_ecma_ast as ast;
 use swc_ecma_visit::{self, Node, Visit, VisitWith};
 
+mod info;
+
 pub fn analyze_dependencies(
     module: &ast::Module,
     source_map: &SourceMap,
@@ -359,4 +362,88 @@ try {
             ]
         );
     }
+
+    #[test]
+    fn test_analyze_module() {
+        let source = r#"import a, { b } from "./a.ts";
+import type { T } from "./types.ts";
+export { c as d } from "./c.ts";
+export * from "./star.ts";
+export * as ns from "./ns.ts";
+export type { U } from "./types.ts";
+export const { e, f: [g = 1] } = a, h = import("./lazy.ts");
+export function i() {
+    return import("./lazy.ts").then(() => import(b));
+}
+export enum J {}
+export interface K {}
+export default class {}
+"#;
+        let (module, _, _) = helper("test.ts", &source).unwrap();
+        let info = analyze_module(&module);
+        assert_eq!(
+            info,
+            ModuleInfo {
+                imports: vec![
+                    "./a.ts".into(),
+                    "./c.ts".into(),
+                    "./star.ts".into(),
+                    "./ns.ts".into()
+                ],
+                dynamic_imports: vec!["./lazy.ts".into()],
+                exports: vec![
+                    "d".into(),
+                    "ns".into(),
+                    "e".into(),
+                    "g".into(),
+                    "h".into(),
+                    "i".into(),
+                    "J".into(),
+                    "default".into()
+                ],
+                star_exports: vec!["./star.ts".into()],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_analyze_commonjs() {
+        let source = r#"const a = require("./a.js");
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.b = a;
+module.exports["c"] = function () {
+    return require("./lazy.js");
+};
+exports.b = 2;
+exports.d += 1;
+"#;
+        let (module, _, _) = helper("test.js", &source).unwrap();
+        let info = analyze_module(&module);
+        assert_eq!(
+            info,
+            ModuleInfo {
+                requires: vec!["./a.js".into(), "./lazy.js".into()],
+                commonjs: true,
+                cjs_exports: vec!["__esModule".into(), "b".into(), "c".into()],
+                ..Default::default()
+            }
+        );
+
+        let (module, _, _) = helper("test.js", "module.exports = { a, b: 1, 'c'() {} };").unwrap();
+        let info = analyze_module(&module);
+        assert!(!info.cjs_exports_replaced);
+        assert_eq!(
+            info.cjs_exports,
+            vec![JsWord::from("a"), JsWord::from("b"), JsWord::from("c")]
+        );
+
+        let (module, _, _) = helper("test.js", "module.exports = require('./a');").unwrap();
+        let info = analyze_module(&module);
+        assert!(info.cjs_exports_replaced);
+        assert_eq!(info.requires, vec![JsWord::from("./a")]);
+
+        let (module, _, _) = helper("test.js", "export const a = 1;").unwrap();
+        assert!(!analyze_module(&module).commonjs);
+    }
 }
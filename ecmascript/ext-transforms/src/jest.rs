@@ -1,5 +1,5 @@
-use crate::util::MapWithMut;
 use phf::phf_set;
+use swc_common::util::take::Take;
 use swc_ecma_ast::*;
 use swc_ecma_utils::{prepend_stmts, StmtLike};
 use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
@@ -34,34 +34,9 @@ impl Jest {
         items.into_iter().for_each(|item| {
             match item.try_into_stmt() {
                 Ok(stmt) => match &stmt {
-                    Stmt::Expr(ExprStmt { expr, .. }) => match &**expr {
-                        Expr::Call(CallExpr {
-                            callee: ExprOrSuper::Expr(callee),
-                            ..
-                        }) => match &**callee {
-                            Expr::Member(
-                                callee
-                                @
-                                MemberExpr {
-                                    computed: false, ..
-                                },
-                            ) => match &callee.obj {
-                                ExprOrSuper::Super(_) => new.push(T::from_stmt(stmt)),
-                                ExprOrSuper::Expr(callee_obj) => match &**callee_obj {
-                                    Expr::Ident(i) if i.sym == *"jest" => match &*callee.prop {
-                                        Expr::Ident(prop) if HOIST_METHODS.contains(&*prop.sym) => {
-                                            hoisted.push(T::from_stmt(stmt));
-                                            return;
-                                        }
-                                        _ => new.push(T::from_stmt(stmt)),
-                                    },
-                                    _ => new.push(T::from_stmt(stmt)),
-                                },
-                            },
-                            _ => new.push(T::from_stmt(stmt)),
-                        },
-                        _ => new.push(T::from_stmt(stmt)),
-                    },
+                    Stmt::Expr(ExprStmt { expr, .. }) if is_jest_call(expr) => {
+                        hoisted.push(T::from_stmt(stmt));
+                    }
 
                     _ => new.push(T::from_stmt(stmt)),
                 },
@@ -75,6 +50,36 @@ impl Jest {
     }
 }
 
+/// Returns true if `e` is a call to one of [HOIST_METHODS] on `jest`,
+/// including chained calls like `jest.mock('a').unmock('b')`.
+fn is_jest_call(e: &Expr) -> bool {
+    match e {
+        Expr::Call(CallExpr {
+            callee: ExprOrSuper::Expr(callee),
+            ..
+        }) => match &**callee {
+            Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Expr(obj),
+                prop,
+                computed: false,
+                ..
+            }) => {
+                match &**prop {
+                    Expr::Ident(prop) if HOIST_METHODS.contains(&*prop.sym) => {}
+                    _ => return false,
+                }
+
+                match &**obj {
+                    Expr::Ident(i) if i.sym == *"jest" => true,
+                    _ => is_jest_call(obj),
+                }
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 impl VisitMut for Jest {
     noop_visit_mut_type!();
 
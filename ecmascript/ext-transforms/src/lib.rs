@@ -1,2 +1 @@
 pub mod jest;
-mod util;
@@ -0,0 +1,26 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use swc_common::{FileName, SourceMap};
+use swc_ecma_parser::{lexer::Lexer, StringInput, Syntax, TsConfig};
+
+fuzz_target!(|data: &[u8]| {
+    let src = match std::str::from_utf8(data) {
+        Ok(src) => src,
+        Err(..) => return,
+    };
+
+    let cm = SourceMap::default();
+    let fm = cm.new_source_file(FileName::Anon, src.into());
+
+    let lexer = Lexer::new(
+        Syntax::Typescript(TsConfig {
+            tsx: true,
+            ..Default::default()
+        }),
+        Default::default(),
+        StringInput::from(&*fm),
+        None,
+    );
+
+    for _ in lexer {}
+});
@@ -0,0 +1,32 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use swc_common::{FileName, SourceMap};
+use swc_ecma_parser::{EsConfig, Parser, StringInput, Syntax, TsConfig};
+
+fuzz_target!(|data: &[u8]| {
+    let src = match std::str::from_utf8(data) {
+        Ok(src) => src,
+        Err(..) => return,
+    };
+
+    let cm = SourceMap::default();
+    let fm = cm.new_source_file(FileName::Anon, src.into());
+
+    let syntaxes = [
+        Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        }),
+        Syntax::Typescript(TsConfig {
+            tsx: true,
+            decorators: true,
+            ..Default::default()
+        }),
+    ];
+
+    // Errors are fine, but panics are not.
+    for &syntax in &syntaxes {
+        let mut parser = Parser::new(syntax, StringInput::from(&*fm), None);
+        let _ = parser.parse_module();
+    }
+});
@@ -134,6 +134,7 @@ pub enum SyntaxError {
     EmptyJSXAttr,
     InvalidJSXValue,
     JSXExpectedClosingTagForLtGt,
+    JSXNamespacedNameAsObject,
     JSXExpectedClosingTag {
         tag: JsWord,
     },
@@ -163,6 +164,10 @@ pub enum SyntaxError {
 
     NumericSeparatorIsAllowedOnlyBetweenTwoDigits,
 
+    BindCalleeNotMember,
+    InvalidRecordProp,
+    TupleHole,
+
     TS1003,
     TS1005,
     TS1009,
@@ -344,6 +349,9 @@ impl SyntaxError {
             SyntaxError::InvalidJSXValue => {
                 "JSX value should be either an expression or a quoted JSX text".into()
             }
+            SyntaxError::JSXNamespacedNameAsObject => {
+                "Namespaced JSX name cannot be used as an object of member expression".into()
+            }
             SyntaxError::JSXExpectedClosingTagForLtGt => {
                 "Expected corresponding JSX closing tag for <>".into()
             }
@@ -401,6 +409,13 @@ impl SyntaxError {
             SyntaxError::NumericSeparatorIsAllowedOnlyBetweenTwoDigits => {
                 "A numeric separator is only allowed between two digits".into()
             }
+            SyntaxError::BindCalleeNotMember => {
+                "Binding should be performed on object property".into()
+            }
+            SyntaxError::InvalidRecordProp => {
+                "Records can only contain properties and spread elements".into()
+            }
+            SyntaxError::TupleHole => "Tuples cannot have holes".into(),
 
             SyntaxError::NullishCoalescingWithLogicalOp => {
                 "Nullish coalescing operator(??) requires parens when mixing with logical operators"
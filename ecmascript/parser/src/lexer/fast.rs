@@ -0,0 +1,90 @@
+//! Byte-level scanning used to skip whitespaces and comments.
+//!
+//! The scalar implementation in [super::util] is used if the input does not
+//! provide a string.
+
+use memchr::{memchr, memchr3};
+
+/// Returns the length of the leading run of ascii whitespaces, and whether
+/// it contains a line break.
+pub(super) fn ascii_space(s: &str) -> (usize, bool) {
+    let mut has_line_break = false;
+
+    for (i, &b) in s.as_bytes().iter().enumerate() {
+        match b {
+            b' ' | b'\t' | b'\x0b' | b'\x0c' => {}
+            b'\n' | b'\r' => has_line_break = true,
+            _ => return (i, has_line_break),
+        }
+    }
+
+    (s.len(), has_line_break)
+}
+
+/// Returns the index of the first line break, or the length of `s` if there's
+/// no line break.
+pub(super) fn line_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut start = 0;
+
+    // U+2028 and U+2029 are encoded as e2 80 a8 and e2 80 a9.
+    while let Some(i) = memchr3(b'\n', b'\r', 0xe2, &bytes[start..]) {
+        let i = start + i;
+        match bytes[i..] {
+            [0xe2, 0x80, 0xa8, ..] | [0xe2, 0x80, 0xa9, ..] | [b'\n', ..] | [b'\r', ..] => {
+                return i
+            }
+            _ => start = i + 1,
+        }
+    }
+
+    bytes.len()
+}
+
+/// Returns the index of `*/`.
+pub(super) fn block_comment_len(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let mut start = 0;
+
+    while let Some(i) = memchr(b'*', &bytes[start..]) {
+        let i = start + i;
+        if bytes.get(i + 1) == Some(&b'/') {
+            return Some(i);
+        }
+        start = i + 1;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_space_run() {
+        assert_eq!(ascii_space(""), (0, false));
+        assert_eq!(ascii_space("  \t foo"), (4, false));
+        assert_eq!(ascii_space(" \r\n foo \n"), (4, true));
+        assert_eq!(ascii_space("\u{a0}"), (0, false));
+    }
+
+    #[test]
+    fn line_len_line_breaks() {
+        assert_eq!(line_len("foo"), 3);
+        assert_eq!(line_len("foo\nbar"), 3);
+        assert_eq!(line_len("foo\r\nbar"), 3);
+        assert_eq!(line_len("foo\u{2028}bar"), 3);
+        assert_eq!(line_len("foo\u{2029}bar"), 3);
+        assert_eq!(line_len("€\u{2029}"), 3);
+        assert_eq!(line_len("\u{2027}\u{2030}"), 6);
+    }
+
+    #[test]
+    fn block_comment_end() {
+        assert_eq!(block_comment_len("*/"), Some(0));
+        assert_eq!(block_comment_len(" * foo **/"), Some(8));
+        assert_eq!(block_comment_len(" * foo *"), None);
+        assert_eq!(block_comment_len(""), None);
+    }
+}
@@ -139,6 +139,7 @@ impl<'a, I: Input> Lexer<'a, I> {
     pub(super) fn read_jsx_str(&mut self, quote: char) -> LexResult<Token> {
         debug_assert!(self.syntax.jsx());
 
+        let start = self.input.cur_pos();
         self.input.bump(); // `quote`
         let mut has_escape = false;
         let mut out = String::new();
@@ -185,8 +186,10 @@ impl<'a, I: Input> Lexer<'a, I> {
         let cur_pos = self.input.cur_pos();
         out.push_str(self.input.slice(chunk_start, cur_pos));
         self.input.bump();
+        let end = self.input.cur_pos();
         Ok(Token::Str {
             value: out.into(),
+            raw: self.input.slice(start, end).into(),
             has_escape,
         })
     }
@@ -10,7 +10,8 @@ use crate::{
     token::*,
     Context, JscTarget, Syntax,
 };
-use either::Either::{Left, Right};
+use either::Either::{self, Left, Right};
+use num_bigint::BigInt as BigIntValue;
 use smallvec::{smallvec, SmallVec};
 use std::{cell::RefCell, char, iter::FusedIterator, mem::take, rc::Rc};
 use swc_atoms::{js_word, JsWord};
@@ -19,6 +20,8 @@ use swc_common::{
     BytePos, Span,
 };
 
+#[cfg(feature = "memchr")]
+mod fast;
 pub mod input;
 mod jsx;
 mod number;
@@ -110,6 +113,9 @@ pub struct Lexer<'a, I: Input> {
     errors: Rc<RefCell<Vec<Error>>>,
     module_errors: Rc<RefCell<Vec<Error>>>,
 
+    /// If true, comments and whitespaces are emitted as tokens.
+    trivia: bool,
+
     buf: String,
 }
 
@@ -137,10 +143,26 @@ impl<'a, I: Input> Lexer<'a, I> {
             target,
             errors: Default::default(),
             module_errors: Default::default(),
+            trivia: false,
             buf: String::with_capacity(16),
         }
     }
 
+    /// Creates a token from the result of [Lexer::read_number], which started
+    /// at `start`.
+    fn num_token(&mut self, start: BytePos, v: Either<f64, BigIntValue>) -> Token {
+        match v {
+            Left(value) => {
+                let end = self.cur_pos();
+                Num {
+                    value,
+                    raw: self.input.slice(start, end).into(),
+                }
+            }
+            Right(v) => BigInt(v),
+        }
+    }
+
     /// Utility method to reuse buffer.
     fn with_buf<F, Ret>(&mut self, op: F) -> LexResult<Ret>
     where
@@ -185,10 +207,7 @@ impl<'a, I: Input> Lexer<'a, I> {
                 if '0' <= next && next <= '9' {
                     return self
                         .read_number(true)
-                        .map(|v| match v {
-                            Left(v) => Num(v),
-                            Right(v) => BigInt(v),
-                        })
+                        .map(|v| self.num_token(start, v))
                         .map(Some);
                 }
 
@@ -263,29 +282,20 @@ impl<'a, I: Input> Lexer<'a, I> {
                     _ => {
                         return self
                             .read_number(false)
-                            .map(|v| match v {
-                                Left(v) => Num(v),
-                                Right(v) => BigInt(v),
-                            })
+                            .map(|v| self.num_token(start, v))
                             .map(Some)
                     }
                 };
 
                 return self
                     .read_radix_number(radix)
-                    .map(|v| match v {
-                        Left(v) => Num(v),
-                        Right(v) => BigInt(v),
-                    })
+                    .map(|v| self.num_token(start, v))
                     .map(Some);
             }
             '1'..='9' => {
                 return self
                     .read_number(false)
-                    .map(|v| match v {
-                        Left(v) => Num(v),
-                        Right(v) => BigInt(v),
-                    })
+                    .map(|v| self.num_token(start, v))
                     .map(Some)
             }
 
@@ -352,6 +362,12 @@ impl<'a, I: Input> Lexer<'a, I> {
                     })));
                 }
 
+                // '|>'
+                if c == '|' && self.syntax.pipeline_operator() && self.input.cur() == Some('>') {
+                    self.input.bump();
+                    return Ok(Some(BinOp(Pipeline)));
+                }
+
                 BinOp(token)
             }
             '^' => {
@@ -447,7 +463,10 @@ impl<'a, I: Input> Lexer<'a, I> {
             return Ok(None);
         }
 
-        if self.syntax.class_private_props() || self.syntax.class_private_methods() {
+        if self.syntax.class_private_props()
+            || self.syntax.class_private_methods()
+            || self.syntax.record_and_tuple()
+        {
             self.input.bump(); // '#'
             return Ok(Some(Token::Hash));
         }
@@ -508,13 +527,13 @@ impl<'a, I: Input> Lexer<'a, I> {
             'b' => push_c_and_ret!('\u{0008}'),
             'v' => push_c_and_ret!('\u{000b}'),
             'f' => push_c_and_ret!('\u{000c}'),
+            // The raw value of a line continuation is normalized like line breaks
+            // in templates.
             '\r' => {
-                raw.push_str("\r");
+                raw.push_str("\n");
                 self.bump(); // remove '\r'
 
-                if self.eat(b'\n') {
-                    raw.push_str("\n");
-                }
+                self.eat(b'\n');
                 return Ok(None);
             }
             '\n' | '\u{2028}' | '\u{2029}' => {
@@ -669,47 +688,8 @@ impl<'a, I: Input> Lexer<'a, I> {
         debug_assert!(self.cur().is_some());
         let start = self.cur_pos();
 
-        let (word, has_escape) = self.read_word_as_str_with(|s| match s {
-            "null" => Word::Null,
-            "true" => Word::True,
-            "false" => Word::False,
-            "await" => Await.into(),
-            "break" => Break.into(),
-            "case" => Case.into(),
-            "catch" => Catch.into(),
-            "continue" => Continue.into(),
-            "debugger" => Debugger.into(),
-            "default" => Default_.into(),
-            "do" => Do.into(),
-            "export" => Export.into(),
-            "else" => Else.into(),
-            "finally" => Finally.into(),
-            "for" => For.into(),
-            "function" => Function.into(),
-            "if" => If.into(),
-            "return" => Return.into(),
-            "switch" => Switch.into(),
-            "throw" => Throw.into(),
-            "try" => Try.into(),
-            "var" => Var.into(),
-            "let" => Let.into(),
-            "const" => Const.into(),
-            "while" => While.into(),
-            "with" => With.into(),
-            "new" => New.into(),
-            "this" => This.into(),
-            "super" => Super.into(),
-            "class" => Class.into(),
-            "extends" => Extends.into(),
-            "import" => Import.into(),
-            "yield" => Yield.into(),
-            "in" => In.into(),
-            "instanceof" => InstanceOf.into(),
-            "typeof" => TypeOf.into(),
-            "void" => Void.into(),
-            "delete" => Delete.into(),
-            _ => Word::Ident(s.into()),
-        })?;
+        let (word, has_escape) =
+            self.read_word_as_str_with(|s| keyword(s).unwrap_or_else(|| Word::Ident(s.into())))?;
 
         // Note: ctx is store in lexer because of this error.
         // 'await' and 'yield' may have semantic of reserved word, which means lexer
@@ -744,9 +724,18 @@ impl<'a, I: Input> Lexer<'a, I> {
         F: FnOnce(&str) -> Ret,
     {
         debug_assert!(self.cur().is_some());
-        let mut first = true;
+
+        // Words without escape sequences are converted from the source directly.
+        let start = self.cur_pos();
+        self.input.uncons_while(|c| c.is_ident_part());
+        let end = self.cur_pos();
+        if !self.input.is_byte(b'\\') {
+            return Ok((convert(self.input.slice(start, end)), false));
+        }
+        let mut first = start == end;
 
         self.with_buf(|l, buf| {
+            buf.push_str(l.input.slice(start, end));
             let mut has_escape = false;
 
             while let Some(c) = {
@@ -856,7 +845,24 @@ impl<'a, I: Input> Lexer<'a, I> {
         let quote = self.cur().unwrap();
         self.bump(); // '"'
 
+        // Strings without escape sequences are converted from the source directly.
+        let content_start = self.cur_pos();
+        self.input
+            .uncons_while(|c| c != quote && c != '\\' && !c.is_line_break());
+        let content_end = self.cur_pos();
+        if self.input.is_byte(quote as u8) {
+            let value = self.input.slice(content_start, content_end).into();
+            self.bump();
+            let end = self.cur_pos();
+            return Ok(Token::Str {
+                value,
+                raw: self.input.slice(start, end).into(),
+                has_escape: false,
+            });
+        }
+
         self.with_buf(|l, out| {
+            out.push_str(l.input.slice(content_start, content_end));
             let mut has_escape = false;
 
             while let Some(c) = {
@@ -872,8 +878,10 @@ impl<'a, I: Input> Lexer<'a, I> {
                 match c {
                     c if c == quote => {
                         l.bump();
+                        let end = l.cur_pos();
                         return Ok(Token::Str {
                             value: (&**out).into(),
+                            raw: l.input.slice(start, end).into(),
                             has_escape,
                         });
                     }
@@ -1014,21 +1022,16 @@ impl<'a, I: Input> Lexer<'a, I> {
                 raw = wrapped.0.unwrap();
             } else if c.is_line_break() {
                 self.state.had_line_break = true;
-                let c = if c == '\r' && self.peek() == Some('\n') {
-                    raw.push_str("\\r\\n");
+                // Both of the raw and cooked values of `<CR><LF>` and `<CR>` are
+                // `<LF>`.
+                //
+                // https://tc39.es/ecma262/#sec-static-semantics-trv
+                if c == '\r' && self.peek() == Some('\n') {
                     self.bump(); // '\r'
-                    '\n'
-                } else {
-                    match c {
-                        '\n' => raw.push_str("\n"),
-                        '\r' => raw.push_str("\r"),
-                        '\u{2028}' => raw.push_str("\u{2028}"),
-                        '\u{2029}' => raw.push_str("\u{2029}"),
-                        _ => unreachable!(),
-                    }
-                    c
-                };
+                }
                 self.bump();
+                let c = if c == '\r' { '\n' } else { c };
+                raw.push(c);
                 if let Some(ref mut cooked) = cooked {
                     cooked.push(c);
                 }
@@ -1051,8 +1054,66 @@ impl<'a, I: Input> Lexer<'a, I> {
     pub fn set_expr_allowed(&mut self, allow: bool) {
         self.state.is_expr_allowed = allow;
     }
+
+    /// Emits comments and whitespaces as [Token::Comment] and
+    /// [Token::Whitespace], for tools which preserve them.
+    ///
+    /// Trivia tokens do not affect the state of the lexer, but the parser does
+    /// not expect them, so this should not be enabled for a lexer passed to
+    /// [crate::Parser].
+    pub fn set_trivia(&mut self, trivia: bool) {
+        self.trivia = trivia;
+    }
 }
 
 fn pos_span(p: BytePos) -> Span {
     Span::new(p, p, Default::default())
 }
+
+/// Returns the keyword, `null`, `true` or `false` spelled by `s`.
+///
+/// Matching on bytes lets the compiler branch on the length and then on each
+/// byte, instead of comparing `s` with every keyword in turn.
+fn keyword(s: &str) -> Option<Word> {
+    Some(match s.as_bytes() {
+        [b'd', b'o'] => Do.into(),
+        [b'i', b'f'] => If.into(),
+        [b'i', b'n'] => In.into(),
+        [b'f', b'o', b'r'] => For.into(),
+        [b'l', b'e', b't'] => Let.into(),
+        [b'n', b'e', b'w'] => New.into(),
+        [b't', b'r', b'y'] => Try.into(),
+        [b'v', b'a', b'r'] => Var.into(),
+        [b'c', b'a', b's', b'e'] => Case.into(),
+        [b'e', b'l', b's', b'e'] => Else.into(),
+        [b'n', b'u', b'l', b'l'] => Word::Null,
+        [b't', b'h', b'i', b's'] => This.into(),
+        [b't', b'r', b'u', b'e'] => Word::True,
+        [b'v', b'o', b'i', b'd'] => Void.into(),
+        [b'w', b'i', b't', b'h'] => With.into(),
+        [b'a', b'w', b'a', b'i', b't'] => Await.into(),
+        [b'b', b'r', b'e', b'a', b'k'] => Break.into(),
+        [b'c', b'a', b't', b'c', b'h'] => Catch.into(),
+        [b'c', b'l', b'a', b's', b's'] => Class.into(),
+        [b'c', b'o', b'n', b's', b't'] => Const.into(),
+        [b'f', b'a', b'l', b's', b'e'] => Word::False,
+        [b's', b'u', b'p', b'e', b'r'] => Super.into(),
+        [b't', b'h', b'r', b'o', b'w'] => Throw.into(),
+        [b'w', b'h', b'i', b'l', b'e'] => While.into(),
+        [b'y', b'i', b'e', b'l', b'd'] => Yield.into(),
+        [b'd', b'e', b'l', b'e', b't', b'e'] => Delete.into(),
+        [b'e', b'x', b'p', b'o', b'r', b't'] => Export.into(),
+        [b'i', b'm', b'p', b'o', b'r', b't'] => Import.into(),
+        [b'r', b'e', b't', b'u', b'r', b'n'] => Return.into(),
+        [b's', b'w', b'i', b't', b'c', b'h'] => Switch.into(),
+        [b't', b'y', b'p', b'e', b'o', b'f'] => TypeOf.into(),
+        [b'd', b'e', b'f', b'a', b'u', b'l', b't'] => Default_.into(),
+        [b'e', b'x', b't', b'e', b'n', b'd', b's'] => Extends.into(),
+        [b'f', b'i', b'n', b'a', b'l', b'l', b'y'] => Finally.into(),
+        [b'c', b'o', b'n', b't', b'i', b'n', b'u', b'e'] => Continue.into(),
+        [b'd', b'e', b'b', b'u', b'g', b'g', b'e', b'r'] => Debugger.into(),
+        [b'f', b'u', b'n', b'c', b't', b'i', b'o', b'n'] => Function.into(),
+        [b'i', b'n', b's', b't', b'a', b'n', b'c', b'e', b'o', b'f'] => InstanceOf.into(),
+        _ => return None,
+    })
+}
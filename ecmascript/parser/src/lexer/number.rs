@@ -586,9 +586,21 @@ mod tests {
                 };
                 assert_eq!(vec.len(), 1);
                 let token = vec.into_iter().next().unwrap();
-                assert_eq!(Num(expected), token);
+                assert_eq!(
+                    Num {
+                        value: expected,
+                        raw: (*case).into()
+                    },
+                    token
+                );
             } else if let Ok(vec) = vec {
-                assert_ne!(vec![Num(expected)], vec)
+                assert_ne!(
+                    vec![Num {
+                        value: expected,
+                        raw: (*case).into()
+                    }],
+                    vec
+                )
             }
         }
     }
@@ -16,6 +16,8 @@ pub(super) struct State {
     pub had_line_break: bool,
     /// TODO: Remove this field.
     is_first: bool,
+    /// Was the last token a comment or whitespaces?
+    after_trivia: bool,
     pub start: BytePos,
     pub cur_line: usize,
     pub line_start: BytePos,
@@ -83,7 +85,7 @@ impl<'a> From<&'a Token> for TokenType {
             _ => TokenType::Other {
                 before_expr: t.before_expr(),
                 can_have_trailing_comment: match *t {
-                    Token::Num(..)
+                    Token::Num { .. }
                     | Token::Str { .. }
                     | Token::Word(Word::Ident(..))
                     | Token::DollarLBrace
@@ -155,6 +157,8 @@ impl<'a, I: Input> Iterator for Lexer<'a, I> {
     type Item = TokenAndSpan;
     fn next(&mut self) -> Option<Self::Item> {
         let mut start = self.cur_pos();
+        // Line breaks in trivia are reported by the next token.
+        let mut had_line_break_before_trivia = None;
 
         let res = (|| -> Result<Option<_>, _> {
             if self.state.is_first {
@@ -163,13 +167,22 @@ impl<'a, I: Input> Iterator for Lexer<'a, I> {
                 }
             }
 
-            self.state.had_line_break = self.state.is_first;
+            if !self.state.after_trivia {
+                self.state.had_line_break = self.state.is_first;
+            }
             self.state.is_first = false;
 
             // skip spaces before getting next character, if we are allowed to.
             if self.state.can_skip_space() {
-                self.skip_space()?;
-                start = self.input.cur_pos();
+                if self.trivia {
+                    had_line_break_before_trivia = Some(self.state.had_line_break);
+                    if let Some(trivia) = self.read_trivia()? {
+                        return Ok(Some(trivia));
+                    }
+                } else {
+                    self.skip_space()?;
+                    start = self.input.cur_pos();
+                }
             };
 
             let c = match self.input.cur() {
@@ -269,7 +282,10 @@ impl<'a, I: Input> Iterator for Lexer<'a, I> {
         };
 
         let span = self.span(start);
-        if let Some(ref token) = token {
+        let is_trivia = matches!(token, Some(Token::Comment { .. }) | Some(Token::Whitespace));
+        self.state.after_trivia = is_trivia;
+        // Trivia does not change the state.
+        if let Some(token) = token.as_ref().filter(|_| !is_trivia) {
             if self.leading_comments_buffer.is_some()
                 && !self
                     .leading_comments_buffer
@@ -290,7 +306,7 @@ impl<'a, I: Input> Iterator for Lexer<'a, I> {
                     ),
                 );
             }
-            self.state.update(start, &token);
+            self.state.update(start, token, self.ctx);
             self.state.prev_hi = self.last_pos();
         }
 
@@ -298,7 +314,10 @@ impl<'a, I: Input> Iterator for Lexer<'a, I> {
             // Attach span to token.
             TokenAndSpan {
                 token,
-                had_line_break: self.had_line_break_before_last(),
+                had_line_break: match had_line_break_before_trivia {
+                    Some(had_line_break) if is_trivia => had_line_break,
+                    _ => self.had_line_break_before_last(),
+                },
                 span,
             }
         })
@@ -311,6 +330,7 @@ impl State {
             is_expr_allowed: true,
             octal_pos: None,
             is_first: true,
+            after_trivia: false,
             had_line_break: false,
             prev_hi: BytePos(0),
             context: TokenContexts(vec![TokenContext::BraceStmt]),
@@ -351,7 +371,7 @@ impl State {
         }
     }
 
-    fn update(&mut self, start: BytePos, next: &Token) {
+    fn update(&mut self, start: BytePos, next: &Token, ctx: Context) {
         trace!(
             "updating state: next={:?}, had_line_break={} ",
             next,
@@ -363,6 +383,7 @@ impl State {
         self.is_expr_allowed = Self::is_expr_allowed_on_next(
             &mut self.context,
             self.syntax,
+            ctx,
             prev,
             start,
             next,
@@ -376,6 +397,7 @@ impl State {
     fn is_expr_allowed_on_next(
         context: &mut TokenContexts,
         syntax: Syntax,
+        ctx: Context,
         prev: Option<TokenType>,
         start: BytePos,
         next: &Token,
@@ -420,9 +442,10 @@ impl State {
                     !out.is_expr()
                 }
 
-                tok!("function") => {
+                tok!("function") | tok!("class") => {
                     // This is required to lex
                     // `x = function(){}/42/i`
+                    // `x = class {}/42/i`
                     if is_expr_allowed
                         && !context.is_brace_block(prev, had_line_break, is_expr_allowed)
                     {
@@ -441,6 +464,13 @@ impl State {
                         .before_expr()
                 }
 
+                // `yield` and `await` are identifiers in functions which are not
+                // generators or async functions.
+                //
+                // `function f() { x = yield / 2 }`
+                tok!("yield") if ctx.in_function && !ctx.in_generator => false,
+                tok!("await") if ctx.in_function && !ctx.in_async && !ctx.module => false,
+
                 Word(Word::Ident(..)) => {
                     // variable declaration
                     match prev {
@@ -503,8 +533,11 @@ impl State {
                     true
                 }
 
-                // remains unchanged.
-                tok!("++") | tok!("--") => is_expr_allowed,
+                // remains unchanged, unless a line break makes it a prefix operator.
+                //
+                // a
+                // ++/b/.lastIndex
+                tok!("++") | tok!("--") => had_line_break || is_expr_allowed,
 
                 tok!('`') => {
                     // If we are in template, ` terminates template.
@@ -6,6 +6,7 @@ use super::{
 };
 use crate::error::{Error, SyntaxError};
 use std::{ops::Range, str};
+use swc_common::comments::CommentKind;
 use test::{black_box, Bencher};
 
 fn sp(r: Range<usize>) -> Span {
@@ -73,12 +74,10 @@ impl WithSpan for Token {
 }
 impl WithSpan for usize {
     fn into_token(self) -> Token {
-        Num(self as f64)
-    }
-}
-impl WithSpan for f64 {
-    fn into_token(self) -> Token {
-        Num(self)
+        Num {
+            value: self as f64,
+            raw: self.to_string().into(),
+        }
     }
 }
 impl<'a> WithSpan for &'a str {
@@ -155,7 +154,12 @@ fn test262_lexer_error_0001() {
     assert_eq!(
         lex(Syntax::default(), "123..a(1)"),
         vec![
-            123f64.span(0..4).lb(),
+            Num {
+                value: 123.0,
+                raw: "123.".into(),
+            }
+            .span(0..4)
+            .lb(),
             Dot.span(4..5),
             "a".span(5..6),
             LParen.span(6..7),
@@ -172,6 +176,7 @@ fn test262_lexer_error_0002() {
         vec![
             Token::Str {
                 value: "use strict".into(),
+                raw: r#"'use\x20strict'"#.into(),
                 has_escape: true,
             }
             .span(0..15)
@@ -215,6 +220,71 @@ fn ident_escape_unicode_2() {
     );
 }
 
+#[test]
+fn ident_id_start() {
+    // Not in XID_Start
+    assert_eq!(
+        lex(Syntax::default(), "\u{37a}"),
+        vec!["\u{37a}".span(0..2).lb()]
+    );
+    assert_eq!(
+        lex(Syntax::default(), "\u{309b}a"),
+        vec!["\u{309b}a".span(0..4).lb()]
+    );
+}
+
+#[test]
+fn ident_escape_astral() {
+    assert_eq!(
+        lex(Syntax::default(), r#"\u{1d4d0}x"#),
+        vec!["\u{1d4d0}x".span(0..10).lb()]
+    );
+}
+
+#[test]
+fn ident_zwnj_zwj() {
+    assert_eq!(
+        lex(Syntax::default(), "a\u{200c}\u{200d}"),
+        vec!["a\u{200c}\u{200d}".span(0..7).lb()]
+    );
+    assert_eq!(
+        lex(Syntax::default(), r#"$\u200d_"#),
+        vec!["$\u{200d}_".span(0..8).lb()]
+    );
+}
+
+#[test]
+fn ident_escape_zwnj_start() {
+    assert_eq!(
+        lex_tokens(Syntax::default(), r#"\u200ca"#),
+        vec![
+            Token::Error(Error {
+                error: Box::new((sp(0..6), SyntaxError::InvalidIdentChar)),
+            }),
+            Word(Word::Ident("a".into())),
+        ]
+    );
+}
+
+#[test]
+fn str_escape_after_text() {
+    assert_eq!(
+        lex_tokens(Syntax::default(), r#"'abc' 'ab\x63'"#),
+        vec![
+            Token::Str {
+                value: "abc".into(),
+                raw: "'abc'".into(),
+                has_escape: false
+            },
+            Token::Str {
+                value: "abc".into(),
+                raw: r#"'ab\x63'"#.into(),
+                has_escape: true
+            },
+        ]
+    );
+}
+
 #[test]
 fn tpl_multiline() {
     assert_eq!(
@@ -289,6 +359,7 @@ fn str_escape() {
         lex_tokens(Syntax::default(), r#"'\n'"#),
         vec![Token::Str {
             value: "\n".into(),
+            raw: r#"'\n'"#.into(),
             has_escape: true
         }]
     );
@@ -300,6 +371,7 @@ fn str_escape_2() {
         lex_tokens(Syntax::default(), r#"'\\n'"#),
         vec![Token::Str {
             value: "\\n".into(),
+            raw: r#"'\\n'"#.into(),
             has_escape: true
         }]
     );
@@ -311,6 +383,7 @@ fn str_escape_3() {
         lex_tokens(Syntax::default(), r#"'\x00'"#),
         vec![Token::Str {
             value: "\x00".into(),
+            raw: r#"'\x00'"#.into(),
             has_escape: true
         }]
     );
@@ -322,6 +395,7 @@ fn str_escape_hex() {
         lex(Syntax::default(), r#"'\x61'"#),
         vec![Token::Str {
             value: "a".into(),
+            raw: r#"'\x61'"#.into(),
             has_escape: true,
         }
         .span(0..6)
@@ -335,6 +409,7 @@ fn str_escape_octal() {
         lex(Syntax::default(), r#"'Hello\012World'"#),
         vec![Token::Str {
             value: "Hello\nWorld".into(),
+            raw: r#"'Hello\012World'"#.into(),
             has_escape: true,
         }
         .span(0..16)
@@ -348,6 +423,7 @@ fn str_escape_unicode_long() {
         lex(Syntax::default(), r#"'\u{00000000034}'"#),
         vec![Token::Str {
             value: "4".into(),
+            raw: r#"'\u{00000000034}'"#.into(),
             has_escape: true,
         }
         .span(0..17)
@@ -663,6 +739,7 @@ fn str_lit() {
         lex_tokens(Syntax::default(), "'abcde'"),
         vec![Token::Str {
             value: "abcde".into(),
+            raw: "'abcde'".into(),
             has_escape: false,
         }],
     );
@@ -670,6 +747,7 @@ fn str_lit() {
         lex_tokens(Syntax::default(), "'\\\nabc'"),
         vec![Token::Str {
             value: "abc".into(),
+            raw: "'\\\nabc'".into(),
             has_escape: true,
         }]
     );
@@ -715,6 +793,112 @@ fn tpl() {
     )
 }
 
+#[test]
+fn tpl_nested() {
+    let tpl = |s: &str| Template {
+        raw: s.into(),
+        cooked: Some(s.into()),
+        has_escape: false,
+    };
+
+    assert_eq!(
+        lex(Syntax::default(), "`a${`b`}c`"),
+        vec![
+            tok!('`').span(0).lb(),
+            tpl("a").span(1),
+            tok!("${").span(2..4),
+            tok!('`').span(4),
+            tpl("b").span(5),
+            tok!('`').span(6),
+            tok!('}').span(7),
+            tpl("c").span(8),
+            tok!('`').span(9),
+        ]
+    )
+}
+
+#[test]
+fn tpl_braces_in_expr() {
+    let tpl = |s: &str| Template {
+        raw: s.into(),
+        cooked: Some(s.into()),
+        has_escape: false,
+    };
+
+    assert_eq!(
+        lex_tokens(Syntax::default(), "`${{a: `}`}}}`"),
+        vec![
+            tok!('`'),
+            tpl(""),
+            tok!("${"),
+            tok!('{'),
+            "a".into_token(),
+            tok!(':'),
+            tok!('`'),
+            tpl("}"),
+            tok!('`'),
+            tok!('}'),
+            tok!('}'),
+            tpl("}"),
+            tok!('`'),
+        ]
+    )
+}
+
+#[test]
+fn tpl_line_breaks() {
+    assert_eq!(
+        lex_tokens(Syntax::default(), "`a\r\nb\rc\\\r\nd`"),
+        vec![
+            tok!('`'),
+            Template {
+                raw: "a\nb\nc\\\nd".into(),
+                cooked: Some("a\nb\ncd".into()),
+                has_escape: true,
+            },
+            tok!('`'),
+        ]
+    )
+}
+
+#[test]
+fn trivia() {
+    let tokens = with_lexer(
+        Syntax::default(),
+        Default::default(),
+        "a // b\n/* c */ /d/",
+        |l| {
+            l.set_trivia(true);
+            Ok(l.collect::<Vec<_>>())
+        },
+    )
+    .unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            "a".span(0).lb(),
+            Whitespace.span(1),
+            Token::Comment {
+                kind: CommentKind::Line,
+                text: " b".into(),
+            }
+            .span(2..6),
+            Whitespace.span(6),
+            Token::Comment {
+                kind: CommentKind::Block,
+                text: " c ".into(),
+            }
+            .span(7..14)
+            .lb(),
+            Whitespace.span(14).lb(),
+            tok!('/').span(15).lb(),
+            "d".span(16),
+            tok!('/').span(17),
+        ]
+    );
+}
+
 #[test]
 fn comment() {
     assert_eq!(
@@ -937,6 +1121,7 @@ fn issue_299_01() {
             tok!('='),
             Token::Str {
                 value: " ".into(),
+                raw: "'\\ '".into(),
                 has_escape: true
             },
             Token::JSXTagEnd,
@@ -971,6 +1156,7 @@ fn issue_299_02() {
             tok!('='),
             Token::Str {
                 value: "'".into(),
+                raw: "'\\''".into(),
                 has_escape: true
             },
             Token::JSXTagEnd,
@@ -1005,6 +1191,7 @@ fn issue_299_03() {
             tok!('='),
             Token::Str {
                 value: "\\".into(),
+                raw: "'\\\\'".into(),
                 has_escape: true
             },
             Token::JSXTagEnd,
@@ -1026,6 +1213,7 @@ fn issue_316() {
         lex_tokens(Default::default(), "'Hi\\r\\n..'"),
         vec![Token::Str {
             value: "Hi\r\n..".into(),
+            raw: "'Hi\\r\\n..'".into(),
             has_escape: true
         }]
     );
@@ -1038,6 +1226,7 @@ fn issue_401() {
         vec![
             Token::Str {
                 value: "17".into(),
+                raw: "'17'".into(),
                 has_escape: false
             },
             tok!("as"),
@@ -1090,6 +1279,7 @@ fn issue_915_1() {
             LParen,
             Token::Str {
                 value: "\r\n".into(),
+                raw: r#""\r\n""#.into(),
                 has_escape: true
             },
             RParen
@@ -1097,6 +1287,83 @@ fn issue_915_1() {
     );
 }
 
+#[test]
+fn line_break_in_comments() {
+    assert_eq!(
+        lex(
+            Syntax::default(),
+            "a /* \u{2028} */ b // c\n c /** */ d\t/***/ e"
+        ),
+        vec![
+            "a".span(0..1).lb(),
+            "b".span(12..13).lb(),
+            "c".span(20..21).lb(),
+            "d".span(29..30),
+            "e".span(37..38),
+        ]
+    );
+}
+
+#[test]
+fn keyword_lookup() {
+    for &s in &[
+        "null",
+        "true",
+        "false",
+        "await",
+        "break",
+        "case",
+        "catch",
+        "continue",
+        "debugger",
+        "default",
+        "do",
+        "export",
+        "else",
+        "finally",
+        "for",
+        "function",
+        "if",
+        "return",
+        "switch",
+        "throw",
+        "try",
+        "var",
+        "let",
+        "const",
+        "while",
+        "with",
+        "new",
+        "this",
+        "super",
+        "class",
+        "extends",
+        "import",
+        "yield",
+        "in",
+        "instanceof",
+        "typeof",
+        "void",
+        "delete",
+    ] {
+        assert_eq!(keyword(s), Some(Word::from(JsWord::from(s))), "{}", s);
+    }
+
+    for &s in &[
+        "",
+        "d",
+        "dO",
+        "doo",
+        "If",
+        "instanceOf",
+        "undefined",
+        "async",
+        "of",
+    ] {
+        assert_eq!(keyword(s), None, "{}", s);
+    }
+}
+
 #[bench]
 fn lex_colors_js(b: &mut Bencher) {
     b.bytes = include_str!("../../colors.js").len() as _;
@@ -1226,6 +1493,18 @@ fn lex_ident(b: &mut Bencher) {
     );
 }
 
+#[bench]
+fn lex_keywords(b: &mut Bencher) {
+    bench_simple(
+        b,
+        "if;in;do;for;let;new;try;var;case;else;this;void;with;break;catch;class;const;super;\
+         throw;while;yield;delete;export;import;return;switch;typeof;default;extends;finally;\
+         continue;debugger;function;instanceof;null;true;false;iff;doo;fore;vars;cases;elses;\
+         thiss;voids;withs;breaks;catches;classes;consts;supers;throws;whiles;yields;deletes;\
+         exports;imports",
+    );
+}
+
 #[bench]
 fn lex_regex(b: &mut Bencher) {
     bench_simple(
@@ -1,13 +1,15 @@
 //! Ported from [babylon/util/identifier.js][]
 //!
-//! Note: Currently this use xid instead of id because unicode_xid crate
-//! exists.
+//! Identifiers are checked with `ID_Start` and `ID_Continue` of [UAX #31][],
+//! as required by the spec.
 //!
 //!
+//! [UAX #31]:https://unicode.org/reports/tr31/
 //! [babylon/util/identifier.js]:https://github.com/babel/babel/blob/master/packages/babylon/src/util/identifier.js
 use super::{input::Input, Char, LexResult, Lexer};
 use crate::{
     error::{Error, SyntaxError},
+    token::Token,
     Tokens,
 };
 use std::char;
@@ -15,7 +17,7 @@ use swc_common::{
     comments::{Comment, CommentKind},
     BytePos, Span, SyntaxContext,
 };
-use unicode_xid::UnicodeXID;
+use unicode_id::UnicodeID;
 
 /// Collector for raw string.
 ///
@@ -168,6 +170,13 @@ impl<'a, I: Input> Lexer<'a, I> {
     /// See https://tc39.github.io/ecma262/#sec-white-space
     pub(super) fn skip_space(&mut self) -> LexResult<()> {
         while let Some(c) = self.cur() {
+            #[cfg(feature = "memchr")]
+            {
+                if c.is_ascii_whitespace() && self.skip_ascii_space() {
+                    continue;
+                }
+            }
+
             match c {
                 // white spaces
                 '\u{0009}' | '\u{000b}' | '\u{000c}' | '\u{0020}' | '\u{00a0}' | '\u{feff}' => {}
@@ -198,6 +207,62 @@ impl<'a, I: Input> Lexer<'a, I> {
         Ok(())
     }
 
+    /// Reads a comment or a run of whitespaces, if any. Comments are also
+    /// collected as usual.
+    pub(super) fn read_trivia(&mut self) -> LexResult<Option<Token>> {
+        let start = self.cur_pos();
+
+        match (self.cur(), self.peek()) {
+            (Some('/'), Some('/')) => {
+                // The line break belongs to the next whitespaces.
+                let had_line_break = self.state.had_line_break;
+                self.skip_line_comment(2);
+                self.state.had_line_break = had_line_break;
+
+                let end = self.cur_pos();
+                Ok(Some(Token::Comment {
+                    kind: CommentKind::Line,
+                    text: self.input.slice(start + BytePos(2), end).into(),
+                }))
+            }
+            (Some('/'), Some('*')) => {
+                self.skip_block_comment()?;
+
+                let end = self.cur_pos();
+                let text = self
+                    .input
+                    .slice(start + BytePos(2), end - BytePos(2))
+                    .into();
+                self.input.reset_to(end);
+                Ok(Some(Token::Comment {
+                    kind: CommentKind::Block,
+                    text,
+                }))
+            }
+            _ => {
+                while let Some(c) = self.cur() {
+                    match c {
+                        '\u{0009}' | '\u{000b}' | '\u{000c}' | '\u{0020}' | '\u{00a0}'
+                        | '\u{feff}' => {}
+                        '\r' | '\n' | '\u{2028}' | '\u{2029}' => {
+                            self.state.had_line_break = true;
+                        }
+                        _ if c.is_whitespace() => {}
+                        _ => break,
+                    }
+
+                    self.bump();
+                }
+
+                if self.cur_pos() == start {
+                    Ok(None)
+                } else {
+                    Ok(Some(Token::Whitespace))
+                }
+            }
+        }
+    }
+
     pub(super) fn skip_line_comment(&mut self, start_skip: usize) {
         let start = self.cur_pos();
         for _ in 0..start_skip {
@@ -213,22 +278,7 @@ impl<'a, I: Input> Lexer<'a, I> {
         // bar
         //
         let is_for_next = self.state.had_line_break;
-        let mut end = self.cur_pos();
-
-        while let Some(c) = self.cur() {
-            self.bump();
-            if c.is_line_break() {
-                self.state.had_line_break = true;
-            }
-            match c {
-                '\n' | '\r' | '\u{2028}' | '\u{2029}' => {
-                    break;
-                }
-                _ => {
-                    end = self.cur_pos();
-                }
-            }
-        }
+        let end = self.line_comment_end();
 
         if let Some(ref comments) = self.comments {
             let s = self.input.slice(slice_start, end);
@@ -264,8 +314,14 @@ impl<'a, I: Input> Lexer<'a, I> {
         self.bump();
         self.bump();
 
-        // jsdoc
         let slice_start = self.cur_pos();
+        let is_for_next = self.state.had_line_break || !self.state.can_have_trailing_comment();
+
+        // Moves to `*/`, which is handled below.
+        #[cfg(feature = "memchr")]
+        self.skip_block_comment_text();
+
+        // jsdoc
         let mut was_star = if self.cur() == Some('*') {
             self.bump();
             true
@@ -273,8 +329,6 @@ impl<'a, I: Input> Lexer<'a, I> {
             false
         };
 
-        let is_for_next = self.state.had_line_break || !self.state.can_have_trailing_comment();
-
         while let Some(c) = self.cur() {
             if was_star && c == '/' {
                 debug_assert_eq!(self.cur(), Some('/'));
@@ -315,6 +369,78 @@ impl<'a, I: Input> Lexer<'a, I> {
 
         self.error(start, SyntaxError::UnterminatedBlockComment)?
     }
+
+    /// Returns the end of the line comment, which is the position of the next
+    /// line break.
+    fn line_comment_end(&mut self) -> BytePos {
+        let mut end = self.cur_pos();
+
+        #[cfg(feature = "memchr")]
+        {
+            if let Some(s) = self.input.as_str() {
+                let len = super::fast::line_len(s);
+                if len < s.len() {
+                    self.state.had_line_break = true;
+                }
+                return end + BytePos(len as u32);
+            }
+        }
+
+        while let Some(c) = self.cur() {
+            self.bump();
+            if c.is_line_break() {
+                self.state.had_line_break = true;
+            }
+            match c {
+                '\n' | '\r' | '\u{2028}' | '\u{2029}' => {
+                    break;
+                }
+                _ => {
+                    end = self.cur_pos();
+                }
+            }
+        }
+
+        end
+    }
+
+    /// Skips a run of ascii whitespaces. Returns false if the input cannot be
+    /// scanned as bytes.
+    #[cfg(feature = "memchr")]
+    fn skip_ascii_space(&mut self) -> bool {
+        let (len, has_line_break) = match self.input.as_str() {
+            Some(s) => super::fast::ascii_space(s),
+            None => return false,
+        };
+
+        if has_line_break {
+            self.state.had_line_break = true;
+        }
+        let pos = self.cur_pos();
+        self.input.reset_to(pos + BytePos(len as u32));
+
+        true
+    }
+
+    /// Skips the text of a block comment, stopping at `*/`.
+    ///
+    /// If the comment is not terminated, nothing is skipped.
+    #[cfg(feature = "memchr")]
+    fn skip_block_comment_text(&mut self) {
+        let (len, has_line_break) = match self.input.as_str() {
+            Some(s) => match super::fast::block_comment_len(s) {
+                Some(len) => (len, super::fast::line_len(&s[..len]) < len),
+                None => return,
+            },
+            None => return,
+        };
+
+        if has_line_break {
+            self.state.had_line_break = true;
+        }
+        let pos = self.cur_pos();
+        self.input.reset_to(pos + BytePos(len as u32));
+    }
 }
 
 /// Implemented for `char`.
@@ -330,29 +456,29 @@ pub trait CharExt: Copy {
             Some(c) => c,
             None => return false,
         };
-        // TODO: Use Unicode ID instead of XID.
         c == '$' || c == '_' || c.is_ascii_alphabetic() || {
             if c.is_ascii() {
                 false
             } else {
-                UnicodeXID::is_xid_start(c)
+                UnicodeID::is_id_start(c)
             }
         }
     }
 
     /// Test whether a given character is part of an identifier.
+    ///
+    /// https://tc39.github.io/ecma262/#prod-IdentifierPart
     #[inline]
     fn is_ident_part(self) -> bool {
         let c = match self.to_char() {
             Some(c) => c,
             None => return false,
         };
-        // TODO: Use Unicode ID instead of XID.
         c == '$' || c == '_' || c == '\u{200c}' || c == '\u{200d}' || c.is_ascii_alphanumeric() || {
             if c.is_ascii() {
                 false
             } else {
-                UnicodeXID::is_xid_continue(c)
+                UnicodeID::is_id_continue(c)
             }
         }
     }
@@ -98,6 +98,9 @@ pub use self::{
 };
 use serde::{Deserialize, Serialize};
 use swc_common::Span;
+/// Renamed to [EsVersion], which is defined in `swc_ecma_ast`.
+pub use swc_ecma_ast::EsVersion as JscTarget;
+pub use swc_ecma_ast::EsVersion;
 
 #[macro_use]
 mod macros;
@@ -203,6 +206,85 @@ impl Syntax {
         true
     }
 
+    /// Stage 1.
+    pub fn pipeline_operator(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                pipeline_operator: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    /// `|> await` of the F# pipeline operator.
+    pub fn fsharp_pipeline(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                pipeline_operator: true,
+                fsharp_pipeline: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    /// Stage 1.
+    pub fn partial_application(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                partial_application: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    /// Stage 1.
+    pub fn do_expressions(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                do_expressions: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    /// Stage 2.
+    pub fn throw_expressions(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                throw_expressions: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    /// Stage 2.
+    pub fn record_and_tuple(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                record_and_tuple: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    /// `static { ... }` in class bodies.
+    pub fn static_blocks(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                static_blocks: true,
+                ..
+            })
+            | Syntax::Typescript(..) => true,
+            _ => false,
+        }
+    }
+
     pub fn decorators_before_export(self) -> bool {
         match self {
             Syntax::Es(EsConfig {
@@ -304,32 +386,6 @@ pub struct TsConfig {
     pub import_assertions: bool,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialOrd, Ord, PartialEq, Eq)]
-pub enum JscTarget {
-    #[serde(rename = "es3")]
-    Es3,
-    #[serde(rename = "es5")]
-    Es5,
-    #[serde(rename = "es2015")]
-    Es2015,
-    #[serde(rename = "es2016")]
-    Es2016,
-    #[serde(rename = "es2017")]
-    Es2017,
-    #[serde(rename = "es2018")]
-    Es2018,
-    #[serde(rename = "es2019")]
-    Es2019,
-    #[serde(rename = "es2020")]
-    Es2020,
-}
-
-impl Default for JscTarget {
-    fn default() -> Self {
-        JscTarget::Es5
-    }
-}
-
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct EsConfig {
@@ -353,6 +409,47 @@ pub struct EsConfig {
     #[serde(default)]
     pub class_props: bool,
 
+    /// Support class static initialization blocks.
+    #[serde(default)]
+    pub static_blocks: bool,
+
+    /// Support minimal pipeline operator (`a |> f`).
+    ///
+    /// Stage 1.
+    #[serde(default)]
+    pub pipeline_operator: bool,
+
+    /// Use the F# variant of the pipeline operator, which supports
+    /// `a |> await`. Arrow functions in pipelines should be parenthesized.
+    ///
+    /// Requires `pipeline_operator`.
+    #[serde(default)]
+    pub fsharp_pipeline: bool,
+
+    /// Support partial application (`f(?, a)`).
+    ///
+    /// Stage 1.
+    #[serde(default)]
+    pub partial_application: bool,
+
+    /// Support `do { ... }` expressions.
+    ///
+    /// Stage 1.
+    #[serde(default)]
+    pub do_expressions: bool,
+
+    /// Support `throw` expressions.
+    ///
+    /// Stage 2.
+    #[serde(default)]
+    pub throw_expressions: bool,
+
+    /// Support record (`#{}`) and tuple (`#[]`) literals.
+    ///
+    /// Stage 2.
+    #[serde(default)]
+    pub record_and_tuple: bool,
+
     /// Support function bind expression.
     #[serde(rename = "functionBind")]
     #[serde(default)]
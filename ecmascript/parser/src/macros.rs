@@ -22,6 +22,9 @@ macro_rules! tok {
     ("??") => {
         crate::token::Token::BinOp(crate::token::BinOpToken::NullishCoalescing)
     };
+    ("|>") => {
+        crate::token::Token::BinOp(crate::token::BinOpToken::Pipeline)
+    };
     ('~') => {
         crate::token::Token::Tilde
     };
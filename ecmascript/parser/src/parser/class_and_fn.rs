@@ -405,6 +405,8 @@ impl<'a, I: Tokens> Parser<I> {
                     declare,
                     false,
                 );
+            } else if self.input.syntax().static_blocks() && is!('{') {
+                return self.parse_static_block(start);
             } else {
                 // TODO: error if static contains escape
             }
@@ -419,6 +421,28 @@ impl<'a, I: Tokens> Parser<I> {
         )
     }
 
+    /// Parses the body of `static { ... }`. `static` should be already eaten.
+    fn parse_static_block(&mut self, start: BytePos) -> PResult<ClassMember> {
+        let ctx = Context {
+            in_async: false,
+            in_generator: false,
+            in_function: false,
+            is_break_allowed: false,
+            is_continue_allowed: false,
+            ..self.ctx()
+        };
+        let state = State {
+            labels: vec![],
+            ..Default::default()
+        };
+        let body = self.with_ctx(ctx).with_state(state).parse_block(false)?;
+
+        Ok(ClassMember::StaticBlock(StaticBlock {
+            span: span!(start),
+            body,
+        }))
+    }
+
     #[allow(clippy::cognitive_complexity)]
     fn parse_class_member_with_is_static(
         &mut self,
@@ -36,6 +36,10 @@ impl<'a, I: Tokens> Parser<I> {
     pub(super) fn parse_assignment_expr(&mut self) -> PResult<Box<Expr>> {
         trace_cur!(parse_assignment_expr);
 
+        // Error of parsing `<` as a jsx element, which is reported if it's not a
+        // generic arrow function either.
+        let mut jsx_err = None;
+
         if self.input.syntax().typescript() {
             // Note: When the JSX plugin is on, type assertions (`<T> x`) aren't valid
             // syntax.
@@ -49,21 +53,27 @@ impl<'a, I: Tokens> Parser<I> {
                     TokenContext::JSXExpr
                 );
 
-                let res = self.try_parse_ts(|p| p.parse_assignment_expr_base().map(Some));
-                if let Some(res) = res {
-                    return Ok(res);
-                } else {
-                    debug_assert_eq!(
-                        self.input.token_context().current(),
-                        Some(TokenContext::JSXOpeningTag)
-                    );
-                    self.input.token_context_mut().pop();
-                    debug_assert_eq!(
-                        self.input.token_context().current(),
-                        Some(TokenContext::JSXExpr)
-                    );
-                    self.input.token_context_mut().pop();
+                let mut cloned = self.clone();
+                cloned.emit_err = false;
+                match cloned.parse_assignment_expr_base() {
+                    Ok(res) => {
+                        *self = cloned;
+                        self.emit_err = true;
+                        return Ok(res);
+                    }
+                    Err(err) => jsx_err = Some(err),
                 }
+
+                debug_assert_eq!(
+                    self.input.token_context().current(),
+                    Some(TokenContext::JSXOpeningTag)
+                );
+                self.input.token_context_mut().pop();
+                debug_assert_eq!(
+                    self.input.token_context().current(),
+                    Some(TokenContext::JSXExpr)
+                );
+                self.input.token_context_mut().pop();
             }
         }
 
@@ -90,6 +100,12 @@ impl<'a, I: Tokens> Parser<I> {
             }
         }
 
+        // The token context for jsx is already popped, so parsing it again would
+        // result in a confusing error.
+        if let Some(err) = jsx_err {
+            return Err(err);
+        }
+
         self.parse_assignment_expr_base()
     }
 
@@ -205,6 +221,28 @@ impl<'a, I: Tokens> Parser<I> {
         }
     }
 
+    /// `do { ... }`
+    fn parse_do_expr(&mut self) -> PResult<Box<Expr>> {
+        let start = cur_pos!();
+        assert_and_bump!("do");
+
+        let ctx = Context {
+            is_break_allowed: false,
+            is_continue_allowed: false,
+            ..self.ctx()
+        };
+        let state = State {
+            labels: vec![],
+            ..Default::default()
+        };
+        let body = self.with_ctx(ctx).with_state(state).parse_block(false)?;
+
+        Ok(Box::new(Expr::Do(DoExpr {
+            span: span!(start),
+            body,
+        })))
+    }
+
     /// Parse a primary expression or arrow function
     #[allow(clippy::cognitive_complexity)]
     pub(super) fn parse_primary_expr(&mut self) -> PResult<Box<Expr>> {
@@ -276,11 +314,40 @@ impl<'a, I: Tokens> Parser<I> {
                     return self.parse_fn_expr();
                 }
 
+                tok!("do") => {
+                    if self.input.syntax().do_expressions() {
+                        return self.parse_do_expr();
+                    }
+                }
+
+                tok!('#') => {
+                    if self.input.syntax().record_and_tuple()
+                        && (peeked_is!('{') || peeked_is!('['))
+                    {
+                        return self.parse_record_or_tuple();
+                    }
+                }
+
+                // `::obj.func`
+                tok!("::") => {
+                    bump!();
+                    let callee = self.parse_member_expr_or_new_expr(false)?;
+                    if !callee.is_member() {
+                        syntax_error!(callee.span(), SyntaxError::BindCalleeNotMember);
+                    }
+
+                    return Ok(Box::new(Expr::Bind(BindExpr {
+                        span: span!(start),
+                        obj: None,
+                        callee,
+                    })));
+                }
+
                 // Literals
                 tok!("null")
                 | tok!("true")
                 | tok!("false")
-                | Token::Num(..)
+                | Token::Num { .. }
                 | Token::BigInt(..)
                 | Token::Str { .. } => {
                     return Ok(Box::new(Expr::Lit(self.parse_lit()?)));
@@ -415,6 +482,47 @@ impl<'a, I: Tokens> Parser<I> {
         Ok(Box::new(Expr::Array(ArrayLit { span, elems })))
     }
 
+    /// `#{ ... }` or `#[ ... ]`
+    fn parse_record_or_tuple(&mut self) -> PResult<Box<Expr>> {
+        let start = cur_pos!();
+        assert_and_bump!('#');
+
+        if is!('{') {
+            let props = match *self.parse_object::<Box<Expr>>()? {
+                Expr::Object(obj) => obj.props,
+                _ => unreachable!(),
+            };
+            for prop in &props {
+                match prop {
+                    PropOrSpread::Spread(..) => {}
+                    PropOrSpread::Prop(prop) => match **prop {
+                        Prop::KeyValue(..) | Prop::Shorthand(..) => {}
+                        _ => self.emit_err(prop.span(), SyntaxError::InvalidRecordProp),
+                    },
+                }
+            }
+
+            return Ok(Box::new(Expr::Record(RecordLit {
+                span: span!(start),
+                props,
+            })));
+        }
+
+        let elems = match *self.parse_array_lit()? {
+            Expr::Array(arr) => arr.elems,
+            _ => unreachable!(),
+        };
+        let span = span!(start);
+        if elems.iter().any(|elem| elem.is_none()) {
+            self.emit_err(span, SyntaxError::TupleHole);
+        }
+
+        Ok(Box::new(Expr::Tuple(TupleLit {
+            span,
+            elems: elems.into_iter().flatten().collect(),
+        })))
+    }
+
     fn parse_member_expr(&mut self) -> PResult<Box<Expr>> {
         self.parse_member_expr_or_new_expr(false)
     }
@@ -543,6 +651,21 @@ impl<'a, I: Tokens> Parser<I> {
                 }
             }
 
+            // Partial application, `f(?, a)`
+            if self.input.syntax().partial_application()
+                && !is_dynamic_import
+                && is!('?')
+                && (peeked_is!(',') || peeked_is!(')'))
+            {
+                let start = cur_pos!();
+                bump!();
+                expr_or_spreads.push(ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::ArgPlaceholder(ArgPlaceholder { span: span!(start) })),
+                });
+                continue;
+            }
+
             expr_or_spreads.push(self.include_in_expr(true).parse_expr_or_spread()?);
         }
 
@@ -834,11 +957,13 @@ impl<'a, I: Tokens> Parser<I> {
                         span: span!(start),
                         value: raw,
                         has_escape,
+                        raw: None,
                     },
                     cooked.map(|cooked| Str {
                         span: span!(start),
                         value: cooked,
                         has_escape,
+                        raw: None,
                     }),
                 ),
                 _ => unreachable!(),
@@ -876,7 +1001,9 @@ impl<'a, I: Tokens> Parser<I> {
                 assert_and_bump!('!');
 
                 let expr = match obj {
-                    ExprOrSuper::Super(..) => unimplemented!("super!"),
+                    ExprOrSuper::Super(..) => {
+                        syntax_error!(span!(start), SyntaxError::InvalidSuper)
+                    }
                     ExprOrSuper::Expr(expr) => expr,
                 };
                 return Ok((
@@ -1038,6 +1165,26 @@ impl<'a, I: Tokens> Parser<I> {
             ));
         }
 
+        // `obj::func`
+        if !no_call && eat!("::") {
+            let obj = match obj {
+                ExprOrSuper::Expr(obj) => obj,
+                ExprOrSuper::Super(..) => {
+                    syntax_error!(self.input.prev_span(), SyntaxError::InvalidSuper)
+                }
+            };
+            let callee = self.parse_member_expr_or_new_expr(false)?;
+
+            return Ok((
+                Box::new(Expr::Bind(BindExpr {
+                    span: span!(start),
+                    obj: Some(obj),
+                    callee,
+                })),
+                true,
+            ));
+        }
+
         match obj {
             ExprOrSuper::Expr(expr) => {
                 // MemberExpression[?Yield, ?Await] TemplateLiteral[?Yield, ?Await, +Tagged]
@@ -1132,6 +1279,11 @@ impl<'a, I: Tokens> Parser<I> {
         // 'CallExpr' rule contains 'MemberExpr (...)',
         // and 'MemberExpr' rule contains 'new MemberExpr (...)'
 
+        // `obj::func`
+        if type_args.is_none() && is!("::") {
+            return self.parse_subscripts(ExprOrSuper::Expr(callee), false);
+        }
+
         if is!('(') {
             // This is parsed using production MemberExpression,
             // which is left-recursive.
@@ -1506,17 +1658,23 @@ impl<'a, I: Tokens> Parser<I> {
                 Lit::Bool(Bool { span, value })
             }
             Token::Str { .. } => match bump!() {
-                Token::Str { value, has_escape } => Lit::Str(Str {
+                Token::Str {
+                    value,
+                    raw,
+                    has_escape,
+                } => Lit::Str(Str {
                     span: span!(start),
                     value,
                     has_escape,
+                    raw: Some(raw),
                 }),
                 _ => unreachable!(),
             },
-            Token::Num(..) => match bump!() {
-                Token::Num(value) => Lit::Num(Number {
+            Token::Num { .. } => match bump!() {
+                Token::Num { value, raw } => Lit::Num(Number {
                     span: span!(start),
                     value,
+                    raw: Some(raw),
                 }),
                 _ => unreachable!(),
             },
@@ -10,7 +10,7 @@ impl<'a, I: Tokens> Parser<I> {
     pub(super) fn parse_bin_expr(&mut self) -> PResult<Box<Expr>> {
         let ctx = self.ctx();
 
-        let left = match self.parse_unary_expr() {
+        let left = match self.parse_bin_operand(0) {
             Ok(v) => v,
             Err(err) => match cur!(true)? {
                 &Word(Word::Keyword(Keyword::In)) if ctx.include_in_expr => {
@@ -28,7 +28,53 @@ impl<'a, I: Tokens> Parser<I> {
         };
 
         return_if_arrow!(left);
-        self.parse_bin_op_recursively(left, 0)
+        let mut left = self.parse_bin_op_recursively(left, 0)?;
+
+        // `|>` has the lowest precedence among binary operators.
+        while is!("|>") {
+            bump!();
+
+            // `a |> await` of the F# variant awaits `a`.
+            if self.input.syntax().fsharp_pipeline()
+                && self.ctx().in_async
+                && is!("await")
+                && (self.input.has_linebreak_between_cur_and_peeked()
+                    || match peek!().ok() {
+                        None => true,
+                        Some(t) => match *t {
+                            tok!("|>")
+                            | tok!(')')
+                            | tok!(']')
+                            | tok!('}')
+                            | tok!(',')
+                            | Token::Semi => true,
+                            _ => false,
+                        },
+                    })
+            {
+                let start = cur_pos!();
+                bump!();
+                left = Box::new(Expr::Await(AwaitExpr {
+                    span: Span::new(left.span().lo(), span!(start).hi(), Default::default()),
+                    arg: left,
+                }));
+                continue;
+            }
+
+            let right = {
+                let left_of_right = self.parse_bin_operand(0)?;
+                self.parse_bin_op_recursively(left_of_right, 0)?
+            };
+
+            left = Box::new(Expr::Bin(BinExpr {
+                span: Span::new(left.span().lo(), right.span().hi(), Default::default()),
+                op: op!("|>"),
+                left,
+                right,
+            }));
+        }
+
+        Ok(left)
     }
 
     /// Parse binary operators with the operator precedence parsing
@@ -167,16 +213,14 @@ impl<'a, I: Tokens> Parser<I> {
         }
 
         let right = {
-            let left_of_right = self.parse_unary_expr()?;
-            self.parse_bin_op_recursively(
-                left_of_right,
-                if op == op!("**") {
-                    // exponential operator is right associative
-                    op.precedence() - 1
-                } else {
-                    op.precedence()
-                },
-            )?
+            let prec = if op == op!("**") {
+                // exponential operator is right associative
+                op.precedence() - 1
+            } else {
+                op.precedence()
+            };
+            let left_of_right = self.parse_bin_operand(prec)?;
+            self.parse_bin_op_recursively(left_of_right, prec)?
         };
         /* this check is for all ?? operators
          * a ?? b && c for this example
@@ -216,6 +260,37 @@ impl<'a, I: Tokens> Parser<I> {
         return Ok((node, Some(min_prec)));
     }
 
+    /// Parses `#x` of `#x in obj`, or an unary expression.
+    ///
+    /// `#x` can only start a relational expression, so it's not allowed after
+    /// an operator which binds tighter than `in`, like `1 + #x in obj`.
+    fn parse_bin_operand(&mut self, min_prec: u8) -> PResult<Box<Expr>> {
+        // `#{` and `#[` start a record or a tuple.
+        if op!("in").precedence() > min_prec && is!('#') && !peeked_is!('{') && !peeked_is!('[') {
+            let name = self.parse_private_name()?;
+            if !self.ctx().include_in_expr || !is!("in") {
+                unexpected!("in")
+            }
+
+            return Ok(Box::new(Expr::PrivateName(name)));
+        }
+
+        self.parse_unary_expr()
+    }
+
+    /// `throw` in expression position.
+    fn parse_throw_expr(&mut self) -> PResult<Box<Expr>> {
+        let start = cur_pos!();
+        assert_and_bump!("throw");
+
+        let arg = self.parse_unary_expr()?;
+
+        Ok(Box::new(Expr::Throw(ThrowExpr {
+            span: span!(start),
+            arg,
+        })))
+    }
+
     /// Parse unary expression and update expression.
     ///
     /// spec: 'UnaryExpression'
@@ -318,6 +393,10 @@ impl<'a, I: Tokens> Parser<I> {
             return self.parse_await_expr();
         }
 
+        if self.input.syntax().throw_expressions() && is!("throw") {
+            return self.parse_throw_expr();
+        }
+
         // UpdateExpression
         let expr = self.parse_lhs_expr()?;
         return_if_arrow!(expr);
@@ -394,6 +473,49 @@ mod tests {
         );
     }
 
+    fn fsharp_pipeline(s: &'static str) -> Box<Expr> {
+        let syntax = Syntax::Es(crate::EsConfig {
+            pipeline_operator: true,
+            fsharp_pipeline: true,
+            ..Default::default()
+        });
+        test_parser(s, syntax, |p| {
+            p.with_ctx(Context {
+                in_async: true,
+                ..p.ctx()
+            })
+            .parse_expr()
+        })
+    }
+
+    #[test]
+    fn fsharp_pipeline_await() {
+        assert_eq_ignore_span!(
+            fsharp_pipeline("a |> f |> await |> g"),
+            Box::new(Expr::Bin(BinExpr {
+                span,
+                op: op!("|>"),
+                left: Box::new(Expr::Await(AwaitExpr {
+                    span,
+                    arg: fsharp_pipeline("a |> f"),
+                })),
+                right: bin("g"),
+            }))
+        );
+        assert_eq_ignore_span!(
+            fsharp_pipeline("a |> await f"),
+            Box::new(Expr::Bin(BinExpr {
+                span,
+                op: op!("|>"),
+                left: bin("a"),
+                right: Box::new(Expr::Await(AwaitExpr {
+                    span,
+                    arg: bin("f"),
+                })),
+            }))
+        );
+    }
+
     #[test]
     fn same_prec() {
         assert_eq_ignore_span!(
@@ -48,6 +48,166 @@ fn arrow_assign() {
     );
 }
 
+#[test]
+fn private_in() {
+    assert_eq_ignore_span!(
+        expr("#foo in bar"),
+        Box::new(Expr::Bin(BinExpr {
+            span,
+            op: op!("in"),
+            left: Box::new(Expr::PrivateName(PrivateName {
+                span,
+                id: Ident::new("foo".into(), span),
+            })),
+            right: expr("bar"),
+        }))
+    );
+}
+
+#[test]
+fn do_expr() {
+    let syntax = Syntax::Es(EsConfig {
+        do_expressions: true,
+        ..Default::default()
+    });
+
+    assert_eq_ignore_span!(
+        test_parser("do { a; }", syntax, |p| p.parse_expr()),
+        Box::new(Expr::Do(DoExpr {
+            span,
+            body: BlockStmt {
+                span,
+                stmts: vec![Stmt::Expr(ExprStmt {
+                    span,
+                    expr: expr("a"),
+                })],
+            },
+        }))
+    );
+}
+
+#[test]
+fn bind_expr() {
+    let syntax = Syntax::Es(EsConfig {
+        fn_bind: true,
+        ..Default::default()
+    });
+
+    assert_eq_ignore_span!(
+        test_parser("a::b.c(d)", syntax, |p| p.parse_expr()),
+        Box::new(Expr::Call(CallExpr {
+            span,
+            callee: ExprOrSuper::Expr(Box::new(Expr::Bind(BindExpr {
+                span,
+                obj: Some(expr("a")),
+                callee: member_expr("b.c"),
+            }))),
+            args: vec![ExprOrSpread {
+                spread: None,
+                expr: expr("d"),
+            }],
+            type_args: None,
+        }))
+    );
+
+    assert_eq_ignore_span!(
+        test_parser("::a.b", syntax, |p| p.parse_expr()),
+        Box::new(Expr::Bind(BindExpr {
+            span,
+            obj: None,
+            callee: member_expr("a.b"),
+        }))
+    );
+}
+
+#[test]
+fn throw_expr() {
+    let syntax = Syntax::Es(EsConfig {
+        throw_expressions: true,
+        ..Default::default()
+    });
+
+    assert_eq_ignore_span!(
+        test_parser("a || throw b + c", syntax, |p| p.parse_expr()),
+        Box::new(Expr::Bin(BinExpr {
+            span,
+            op: op!("||"),
+            left: expr("a"),
+            right: Box::new(Expr::Bin(BinExpr {
+                span,
+                op: op!(bin, "+"),
+                left: Box::new(Expr::Throw(ThrowExpr {
+                    span,
+                    arg: expr("b"),
+                })),
+                right: expr("c"),
+            })),
+        }))
+    );
+}
+
+#[test]
+fn record_and_tuple() {
+    let syntax = Syntax::Es(EsConfig {
+        record_and_tuple: true,
+        ..Default::default()
+    });
+
+    assert_eq_ignore_span!(
+        test_parser("#{ a: #[b, ...c] }", syntax, |p| p.parse_expr()),
+        Box::new(Expr::Record(RecordLit {
+            span,
+            props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(Ident::new("a".into(), span)),
+                value: Box::new(Expr::Tuple(TupleLit {
+                    span,
+                    elems: vec![
+                        ExprOrSpread {
+                            spread: None,
+                            expr: expr("b"),
+                        },
+                        ExprOrSpread {
+                            spread: Some(span),
+                            expr: expr("c"),
+                        },
+                    ],
+                })),
+            })))],
+        }))
+    );
+}
+
+#[test]
+fn private_in_logical() {
+    assert_eq_ignore_span!(
+        expr("a && #foo in bar"),
+        Box::new(Expr::Bin(BinExpr {
+            span,
+            op: op!("&&"),
+            left: expr("a"),
+            right: expr("#foo in bar"),
+        }))
+    );
+}
+
+#[test]
+#[should_panic(expected = "failed to parse")]
+fn private_in_after_additive() {
+    expr("1 + #foo in bar");
+}
+
+#[test]
+#[should_panic(expected = "failed to parse")]
+fn private_in_after_multiplicative() {
+    expr("a * #foo in bar");
+}
+
+#[test]
+#[should_panic(expected = "failed to parse")]
+fn private_name_without_in() {
+    expr("#foo + 1");
+}
+
 #[test]
 fn async_call() {
     assert_eq_ignore_span!(
@@ -294,7 +454,8 @@ fn max_integer() {
         expr("1.7976931348623157e+308"),
         Box::new(Expr::Lit(Lit::Num(Number {
             span,
-            value: 1.797_693_134_862_315_7e308
+            value: 1.797_693_134_862_315_7e308,
+            raw: Some("1.7976931348623157e+308".into()),
         })))
     )
 }
@@ -349,6 +510,7 @@ fn issue_328() {
                     expr: Box::new(Expr::Lit(Lit::Str(Str {
                         span,
                         value: "test".into(),
+                        raw: Some("'test'".into()),
                         has_escape: false
                     }))),
                 }],
@@ -378,6 +540,7 @@ hehe.";"#,
         Box::new(Expr::Lit(Lit::Str(Str {
             span,
             value: "okokhehe.".into(),
+            raw: Some("\"ok\\\nok\\\nhehe.\"".into()),
             has_escape: true,
         })))
     );
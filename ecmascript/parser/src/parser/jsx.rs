@@ -57,7 +57,9 @@ impl<'a, I: Tokens> Parser<I> {
                 obj: match node {
                     JSXElementName::Ident(i) => JSXObject::Ident(i),
                     JSXElementName::JSXMemberExpr(i) => JSXObject::JSXMemberExpr(Box::new(i)),
-                    _ => unimplemented!("JSXNamespacedName -> JSXObject"),
+                    JSXElementName::JSXNamespacedName(..) => {
+                        syntax_error!(span!(start_pos), SyntaxError::JSXNamespacedNameAsObject)
+                    }
                 },
                 prop,
             });
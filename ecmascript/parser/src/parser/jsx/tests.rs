@@ -74,6 +74,7 @@ fn escape_in_attr() {
                         span,
                         value: "w < w".into(),
                         has_escape: false,
+                        raw: Some(r#""w &lt; w""#.into()),
                     }))),
                 })],
                 name: JSXElementName::Ident(Ident::new("div".into(), span)),
@@ -102,7 +103,8 @@ fn issue_584() {
                         span,
                         expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Num(Number {
                             span,
-                            value: 4.0
+                            value: 4.0,
+                            raw: Some("4".into()),
                         }))))
                     })),
                 })],
@@ -55,10 +55,15 @@ impl<'a, I: Tokens> Parser<I> {
         let str_start = cur_pos!();
         if let Ok(&Token::Str { .. }) = cur!(false) {
             let src = match bump!() {
-                Token::Str { value, has_escape } => Str {
+                Token::Str {
+                    value,
+                    raw,
+                    has_escape,
+                } => Str {
                     span: span!(str_start),
                     value,
                     has_escape,
+                    raw: Some(raw),
                 },
                 _ => unreachable!(),
             };
@@ -124,10 +129,15 @@ impl<'a, I: Tokens> Parser<I> {
             let str_start = cur_pos!();
             let src = match *cur!(true)? {
                 Token::Str { .. } => match bump!() {
-                    Token::Str { value, has_escape } => Str {
+                    Token::Str {
+                        value,
+                        raw,
+                        has_escape,
+                    } => Str {
                         value,
                         has_escape,
                         span: span!(str_start),
+                        raw: Some(raw),
                     },
                     _ => unreachable!(),
                 },
@@ -546,10 +556,15 @@ impl<'a, I: Tokens> Parser<I> {
         let str_start = cur_pos!();
         let src = match *cur!(true)? {
             Token::Str { .. } => match bump!() {
-                Token::Str { value, has_escape } => Str {
+                Token::Str {
+                    value,
+                    raw,
+                    has_escape,
+                } => Str {
                     value,
                     has_escape,
                     span: span!(str_start),
+                    raw: Some(raw),
                 },
                 _ => unreachable!(),
             },
@@ -1,5 +1,7 @@
-use crate::test_parser;
+use crate::{test_parser, EsConfig, Syntax};
+use swc_common::DUMMY_SP;
 use swc_ecma_ast::*;
+use swc_ecma_visit::{Node, Visit, VisitWith};
 
 fn program(src: &'static str) -> Program {
     test_parser(src, Default::default(), |p| p.parse_program())
@@ -68,3 +70,123 @@ fn parse_program_module_error_01() {
         ",
     );
 }
+
+/// Counts regex literals and divisions.
+#[derive(Default)]
+struct SlashCounter {
+    regex: usize,
+    div: usize,
+}
+
+impl Visit for SlashCounter {
+    fn visit_regex(&mut self, _: &Regex, _: &dyn Node) {
+        self.regex += 1;
+    }
+
+    fn visit_bin_expr(&mut self, e: &BinExpr, _: &dyn Node) {
+        if e.op == BinaryOp::Div {
+            self.div += 1;
+        }
+        e.visit_children_with(self);
+    }
+}
+
+/// Returns the number of regex literals and divisions in `src`.
+fn slashes(syntax: Syntax, src: &'static str) -> (usize, usize) {
+    let program = test_parser(src, syntax, |p| p.parse_program());
+
+    let mut v = SlashCounter::default();
+    program.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+    (v.regex, v.div)
+}
+
+#[test]
+fn regex_or_div() {
+    let cases: &[(&str, (usize, usize))] = &[
+        ("a++ /b/ i", (0, 2)),
+        ("a-- /b/ i", (0, 2)),
+        ("a\n++/b/.lastIndex", (1, 0)),
+        ("a\n/b/g", (0, 2)),
+        ("{}/foo/", (1, 0)),
+        ("x = {}/foo/g", (0, 2)),
+        ("if (a) /foo/.test(b)", (1, 0)),
+        ("while (a) {} /foo/", (1, 0)),
+        ("(a) / b / c", (0, 2)),
+        ("x = a[0] / 2 / 3", (0, 2)),
+        ("x = this / 2 / 3", (0, 2)),
+        ("x = null / 2 / 3", (0, 2)),
+        ("x = /a/ / 2 / 3", (1, 2)),
+        ("a ? /b/ : /c/", (2, 0)),
+        ("x = {a: /b/}", (1, 0)),
+        ("label: /foo/", (1, 0)),
+        ("switch (a) { case /a/: }", (1, 0)),
+        ("function f() { return /foo/ }", (1, 0)),
+        ("typeof /x/", (1, 0)),
+        ("for (a of /b/) {}", (1, 0)),
+        // Keywords as property names
+        ("a.return / 2 / 3", (0, 2)),
+        ("a.if / 2 / 3", (0, 2)),
+        // Functions and classes
+        ("function f() {} /foo/", (1, 0)),
+        ("x = function () {} / 2 / 3", (0, 2)),
+        ("class A {} /foo/", (1, 0)),
+        ("x = class {} / 2 / 3", (0, 2)),
+        ("x = class A extends B {} / 2 / 3", (0, 2)),
+        // `yield` and `await` are identifiers in functions which are not
+        // generators or async functions.
+        ("function* f() { yield /x/ }", (1, 0)),
+        ("async function f() { await /x/ }", (1, 0)),
+        ("function f() { x = yield / 2 / 3 }", (0, 2)),
+        ("function f() { x = await / 2 / 3 }", (0, 2)),
+        (
+            "function* f() { function g() { x = yield / 2 / 3 } }",
+            (0, 2),
+        ),
+        // Templates
+        ("x = `a` / 2 / 3", (0, 2)),
+        ("x = `${a}` / 2 / 3", (0, 2)),
+        ("x = `${ {a: 1}.a / 2 }` / 3", (0, 2)),
+        ("x = `${`${a}` / 2}` / 3", (0, 2)),
+        ("x = `${ /a/ }${ b / 2 }`", (1, 1)),
+    ];
+
+    for &(src, expected) in cases {
+        assert_eq!(slashes(Default::default(), src), expected, "{}", src);
+    }
+}
+
+#[test]
+fn regex_or_div_jsx() {
+    let syntax = Syntax::Es(EsConfig {
+        jsx: true,
+        ..Default::default()
+    });
+    let cases: &[(&str, (usize, usize))] = &[
+        ("<a b={/x/} />", (1, 0)),
+        ("<a>{x / 2 / 3}</a>", (0, 2)),
+        ("<a>/text/</a>", (0, 0)),
+        ("x = <a /> / 2 / 3", (0, 2)),
+        ("x = <a></a> / 2 / 3", (0, 2)),
+        ("<a>{/x/}</a>", (1, 0)),
+        ("<a>{`${/x/}`}</a>", (1, 0)),
+    ];
+
+    for &(src, expected) in cases {
+        assert_eq!(slashes(syntax, src), expected, "{}", src);
+    }
+}
+
+#[test]
+fn regex_or_div_typescript() {
+    let syntax = Syntax::Typescript(Default::default());
+    let cases: &[(&str, (usize, usize))] = &[
+        ("x = a! / 2 / 3", (0, 2)),
+        ("x = <T>a / 2 / 3", (0, 2)),
+        ("x = (a as T) / 2 / 3", (0, 2)),
+        ("x = a as any / 2", (0, 1)),
+    ];
+
+    for &(src, expected) in cases {
+        assert_eq!(slashes(syntax, src), expected, "{}", src);
+    }
+}
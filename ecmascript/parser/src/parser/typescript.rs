@@ -305,6 +305,7 @@ impl<I: Tokens> Parser<I> {
                     span: lit.span(),
                     value: "".into(),
                     has_escape: false,
+                    raw: None,
                 }
             }
         };
@@ -580,7 +581,7 @@ impl<I: Tokens> Parser<I> {
                 Lit::Str(s) => TsEnumMemberId::Str(s),
                 _ => unreachable!(),
             })?,
-            Token::Num(v) => {
+            Token::Num { value, .. } => {
                 bump!();
                 let span = span!(start);
 
@@ -589,8 +590,9 @@ impl<I: Tokens> Parser<I> {
 
                 TsEnumMemberId::Str(Str {
                     span,
-                    value: v.to_string().into(),
+                    value: value.to_string().into(),
                     has_escape: false,
+                    raw: None,
                 })
             }
             Token::LBracket => {
@@ -1218,7 +1220,7 @@ impl<I: Tokens> Parser<I> {
             self.with_ctx(ctx).parse_with(|p| {
                 // We check if it's valid for it to be a private name when we push it.
                 let key = match *cur!(true)? {
-                    Token::Num(..) | Token::Str { .. } => p.parse_new_expr(),
+                    Token::Num { .. } | Token::Str { .. } => p.parse_new_expr(),
                     _ => p.parse_maybe_private_name().map(|e| match e {
                         Either::Left(_) => unreachable!(
                             "private name inside parse_ts_property_or_method_signature"
@@ -1810,7 +1812,7 @@ impl<I: Tokens> Parser<I> {
                 let start = cur_pos!();
                 bump!();
                 if match *cur!(true)? {
-                    Token::Num(..) => false,
+                    Token::Num { .. } => false,
                     _ => true,
                 } {
                     unexpected!("a numeric literal")
@@ -1820,6 +1822,7 @@ impl<I: Tokens> Parser<I> {
                     Lit::Num(num) => TsLit::Number(Number {
                         span: num.span,
                         value: -num.value,
+                        raw: None,
                     }),
                     _ => unreachable!(),
                 };
@@ -2484,6 +2487,7 @@ mod tests {
                         lit: TsLit::Number(Number {
                             span: DUMMY_SP,
                             value: -1.0,
+                            raw: None,
                         }),
                     })),
                 })));
@@ -2519,6 +2523,7 @@ mod tests {
                             arg: Box::new(Expr::Lit(Lit::Num(Number {
                                 span: DUMMY_SP,
                                 value: 1.0,
+                                raw: Some("1".into()),
                             }))),
                         }))),
                         definite: false,
@@ -297,6 +297,12 @@ pub(super) trait ExprExt {
             // MemberExpression is valid assignment target
             Expr::PrivateName(..) => false,
 
+            Expr::ArgPlaceholder(..) => false,
+
+            Expr::Do(..) | Expr::Bind(..) | Expr::Throw(..) => false,
+
+            Expr::Record(..) | Expr::Tuple(..) => false,
+
             // jsx
             Expr::JSXMember(..)
             | Expr::JSXNamespacedName(..)
@@ -10,7 +10,7 @@ use std::{
     fmt::{self, Debug, Display, Formatter},
 };
 use swc_atoms::{js_word, JsWord};
-use swc_common::Span;
+use swc_common::{comments::CommentKind, Span};
 pub(crate) use swc_ecma_ast::AssignOp as AssignOpToken;
 use swc_ecma_ast::BinaryOp;
 
@@ -109,6 +109,8 @@ pub enum Token {
     #[kind(starts_expr)]
     Str {
         value: JsWord,
+        /// The source text, including quotes.
+        raw: JsWord,
         /// This field exsits because 'use\x20strict' is **not** an use strict
         /// directive.
         has_escape: bool,
@@ -120,7 +122,11 @@ pub enum Token {
 
     /// TODO: Make Num as enum and separate decimal, binary, ..etc
     #[kind(starts_expr)]
-    Num(f64),
+    Num {
+        value: f64,
+        /// The source text, like `0x10` or `1e3`.
+        raw: JsWord,
+    },
 
     #[kind(starts_expr)]
     BigInt(BigIntValue),
@@ -138,6 +144,17 @@ pub enum Token {
 
     Shebang(JsWord),
     Error(Error),
+
+    /// Comment, which is emitted only if trivia is enabled.
+    ///
+    /// `text` does not contain `//`, `/*` or `*/`.
+    Comment {
+        kind: CommentKind,
+        text: JsWord,
+    },
+    /// Whitespaces and line breaks, which are emitted only if trivia is
+    /// enabled.
+    Whitespace,
 }
 
 #[derive(Kind, Debug, Clone, Copy, Eq, PartialEq, Hash)]
@@ -202,6 +219,9 @@ pub enum BinOpToken {
 
     /// `??`
     NullishCoalescing,
+
+    /// `|>`
+    Pipeline,
 }
 
 impl BinOpToken {
@@ -537,6 +557,7 @@ impl From<BinOpToken> for BinaryOp {
             BinOpToken::LogicalAnd => LogicalAnd,
             BinOpToken::Exp => Exp,
             BinOpToken::NullishCoalescing => NullishCoalescing,
+            BinOpToken::Pipeline => Pipeline,
         }
     }
 }
@@ -603,7 +624,7 @@ impl Debug for Token {
             Tilde => write!(f, "~")?,
             Str { value, .. } => write!(f, "string literal ({})", value)?,
             Regex(exp, flags) => write!(f, "regexp literal ({}, {})", exp, flags)?,
-            Num(..) => write!(f, "numeric literal")?,
+            Num { .. } => write!(f, "numeric literal")?,
             BigInt(..) => write!(f, "bigint literal")?,
             JSXName { name } => write!(f, "jsx name ({})", name)?,
             JSXText { raw } => write!(f, "jsx text ({})", raw)?,
@@ -611,6 +632,8 @@ impl Debug for Token {
             JSXTagEnd => write!(f, "> (jsx tag end)")?,
             Shebang(_) => write!(f, "#!")?,
             Token::Error(_) => write!(f, "<lexing error>")?,
+            Comment { .. } => write!(f, "comment")?,
+            Whitespace => write!(f, "whitespace")?,
         }
 
         Ok(())
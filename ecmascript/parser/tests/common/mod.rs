@@ -81,11 +81,13 @@ impl Fold for Normalizer {
                 span,
                 value: sym,
                 has_escape: false,
+                raw: None,
             }),
             PropName::Num(num) => PropName::Str(Str {
                 span: num.span,
                 value: num.to_string().into(),
                 has_escape: false,
+                raw: None,
             }),
             _ => n,
         }
@@ -98,6 +100,7 @@ impl Fold for Normalizer {
             Str {
                 span,
                 has_escape: false,
+                raw: None,
                 ..s
             }
         } else {
@@ -105,6 +108,16 @@ impl Fold for Normalizer {
         }
     }
 
+    fn fold_number(&mut self, n: Number) -> Number {
+        let n = n.fold_children_with(self);
+
+        if self.is_test262 {
+            Number { raw: None, ..n }
+        } else {
+            n
+        }
+    }
+
     fn fold_span(&mut self, span: Span) -> Span {
         if self.drop_span {
             Span::default()
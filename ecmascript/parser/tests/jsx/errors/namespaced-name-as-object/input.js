@@ -0,0 +1 @@
+<a:b.c></a:b.c>;
@@ -0,0 +1,4 @@
+const a = <T,>(x: T) => x;
+const b = <T extends unknown>(x: T) => x;
+const c = async <T,>(x: T) => x;
+const d = <div>{<T,>(x: T) => x}</div>;
@@ -118,6 +118,7 @@ impl Fold for Entry {
                 src: Str {
                     span: DUMMY_SP,
                     value: js_word!(""),
+                    raw: None,
                     ..i.src
                 },
                 ..i
@@ -1,6 +1,6 @@
 pub(crate) use self::{entry::Entry, usage::UsageVisitor};
 
-mod compat;
+pub(crate) mod compat;
 mod data;
 mod entry;
 mod usage;
@@ -15,7 +15,7 @@ use swc_common::{chain, FromVariant, Mark, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_transforms::{
     compat::{es2015, es2016, es2017, es2018, es2020, es3},
-    pass::{noop, Optional},
+    pass::{fused, noop, Optional},
     util::prepend_stmts,
 };
 use swc_ecma_visit::{Fold, FoldWith, VisitWith};
@@ -30,6 +30,7 @@ mod version;
 
 pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
     let loose = c.loose;
+    let use_builtins = c.supports_builtin("es.object.assign");
     let targets: Versions = c.targets.try_into().expect("failed to parse targets");
     let is_any_target = targets.is_any_target();
 
@@ -49,18 +50,27 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
         }};
     }
 
-    macro_rules! add {
-        ($prev:expr, $feature:ident, $pass:expr) => {{
-            add!($prev, $feature, $pass, false)
+    macro_rules! optional {
+        ($feature:ident, $pass:expr) => {{
+            optional!($feature, $pass, false)
         }};
-        ($prev:expr, $feature:ident, $pass:expr, $default:expr) => {{
+        ($feature:ident, $pass:expr, $default:expr) => {{
             let f = transform_data::Feature::$feature;
 
             let enable = should_enable!($feature, $default);
             if c.debug {
                 println!("{}: {:?}", f.as_str(), enable);
             }
-            chain!($prev, Optional::new($pass, enable))
+            Optional::new($pass, enable)
+        }};
+    }
+
+    macro_rules! add {
+        ($prev:expr, $feature:ident, $pass:expr) => {{
+            add!($prev, $feature, $pass, false)
+        }};
+        ($prev:expr, $feature:ident, $pass:expr, $default:expr) => {{
+            chain!($prev, optional!($feature, $pass, $default))
         }};
     }
 
@@ -71,7 +81,11 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
     let pass = add!(pass, ClassProperties, es2020::class_properties());
 
     // ES2018
-    let pass = add!(pass, ObjectRestSpread, es2018::object_rest_spread());
+    let pass = add!(
+        pass,
+        ObjectRestSpread,
+        es2018::object_rest_spread(es2018::object_rest_spread::Config { use_builtins })
+    );
     let pass = add!(pass, OptionalCatchBinding, es2018::optional_catch_binding());
 
     // ES2017
@@ -83,7 +97,18 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
     // ES2015
     let pass = add!(pass, BlockScopedFunctions, es2015::block_scoped_functions());
     let pass = add!(pass, TemplateLiterals, es2015::template_literal(), true);
-    let pass = add!(pass, Classes, es2015::classes());
+    let pass = add!(pass, Literals, es2015::literals(), true);
+    let pass = add!(
+        pass,
+        Classes,
+        es2015::classes(
+            None,
+            es2015::classes::Config {
+                set_class_methods: loose,
+                no_class_calls: loose,
+            }
+        )
+    );
     let pass = add!(
         pass,
         Spread,
@@ -93,10 +118,16 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
     let pass = add!(pass, FunctionName, es2015::function_name());
     let pass = add!(pass, ArrowFunctions, es2015::arrow());
     let pass = add!(pass, DuplicateKeys, es2015::duplicate_keys());
-    let pass = add!(pass, StickyRegex, es2015::sticky_regex());
-    // TODO:    InstanceOf,
+    // Leaf transforms share a single traversal.
+    let pass = chain!(
+        pass,
+        fused(chain!(
+            optional!(StickyRegex, es2015::sticky_regex()),
+            // TODO:    InstanceOf,
+            optional!(ShorthandProperties, es2015::shorthand())
+        ))
+    );
     let pass = add!(pass, TypeOfSymbol, es2015::typeof_symbol());
-    let pass = add!(pass, ShorthandProperties, es2015::shorthand());
     let pass = add!(pass, Parameters, es2015::parameters());
     let pass = add!(
         pass,
@@ -109,7 +140,7 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
     let pass = add!(
         pass,
         ComputedProperties,
-        es2015::computed_properties(),
+        es2015::computed_properties(es2015::computed_props::Config { loose }),
         true
     );
     let pass = add!(
@@ -122,7 +153,6 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
     let pass = add!(pass, BlockScoping, es2015::block_scoping(), true);
 
     // TODO:
-    //    Literals,
     //    ObjectSuper,
     //    DotAllRegex,
     //    UnicodeRegex,
@@ -133,11 +163,12 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
     //    NamedCapturingGroupsRegex,
 
     // ES 3
-    let pass = add!(pass, PropertyLiterals, es3::property_literals());
-    let pass = add!(
+    let pass = chain!(
         pass,
-        MemberExpressionLiterals,
-        es3::member_expression_literals()
+        fused(chain!(
+            optional!(PropertyLiterals, es3::property_literals()),
+            optional!(MemberExpressionLiterals, es3::member_expression_literals())
+        ))
     );
     let pass = add!(pass, ReservedWords, es3::reserved_words(c.dynamic_import));
 
@@ -164,7 +195,7 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
 }
 
 /// A map without allocation.
-#[derive(Debug, Default, Deserialize, Clone, Copy, StaticMap)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, StaticMap)]
 #[serde(deny_unknown_fields)]
 pub struct BrowserData<T: Default> {
     #[serde(default)]
@@ -284,6 +315,7 @@ impl Fold for Polyfills {
                             span: DUMMY_SP,
                             value: src,
                             has_escape: false,
+                            raw: None,
                         },
                         type_only: false,
                         asserts: None,
@@ -301,6 +333,7 @@ impl Fold for Polyfills {
                             span: DUMMY_SP,
                             value: src,
                             has_escape: false,
+                            raw: None,
                         },
                         type_only: false,
                         asserts: None,
@@ -330,7 +363,7 @@ impl Fold for Polyfills {
     }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum Mode {
     #[serde(rename = "usage")]
     Usage,
@@ -390,7 +423,7 @@ impl BrowserData<Option<Version>> {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Config {
     #[serde(default)]
@@ -432,11 +465,35 @@ pub struct Config {
     pub force_all_transforms: bool,
 }
 
+impl Config {
+    /// Returns true if all targets support the core-js module `name` (e.g.
+    /// `es.object.assign`) natively, so helpers can use it instead of an
+    /// implementation.
+    pub fn supports_builtin(&self, name: &str) -> bool {
+        if self.force_all_transforms {
+            return false;
+        }
+
+        let targets: Versions = match self.targets.clone().try_into() {
+            Ok(targets) => targets,
+            Err(()) => return false,
+        };
+        if targets.is_any_target() {
+            return false;
+        }
+
+        match corejs3::compat::DATA.get(name) {
+            Some(feature) => !version::should_enable(targets, *feature, true),
+            None => false,
+        }
+    }
+}
+
 fn default_targets() -> Option<Targets> {
     Some(Targets::Query(Query::Single("".into())))
 }
 
-#[derive(Debug, Clone, Deserialize, FromVariant)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromVariant)]
 #[serde(untagged)]
 pub enum FeatureOrModule {
     Feature(Feature),
@@ -461,7 +518,7 @@ impl FeatureOrModule {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, FromVariant)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromVariant)]
 #[serde(untagged)]
 pub enum Targets {
     Query(Query),
@@ -470,19 +527,21 @@ pub enum Targets {
     HashMap(FxHashMap<String, QueryOrVersion>),
 }
 
-#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct EsModules {
     esmodules: bool,
 }
 
-#[derive(Debug, Clone, Deserialize, FromVariant)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromVariant)]
 #[serde(untagged)]
 pub enum QueryOrVersion {
     Query(Query),
     Version(Version),
 }
 
-#[derive(Debug, Clone, Deserialize, FromVariant, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[derive(
+    Debug, Clone, Serialize, Deserialize, FromVariant, Eq, PartialEq, PartialOrd, Ord, Hash,
+)]
 #[serde(untagged)]
 pub enum Query {
     Single(String),
@@ -1,5 +1,5 @@
 use crate::Versions;
-use serde::{de, de::Visitor, Deserialize, Deserializer};
+use serde::{de, de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
 use std::{cmp, cmp::Ordering, fmt, hash, str::FromStr};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -145,6 +145,18 @@ impl<'de> Visitor<'de> for SerdeVisitor {
     }
 }
 
+impl Serialize for Version {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&format_args!(
+            "{}.{}.{}",
+            self.major, self.minor, self.patch
+        ))
+    }
+}
+
 impl<'de> Deserialize<'de> for Version {
     fn deserialize<D>(deserializer: D) -> Result<Version, D::Error>
     where
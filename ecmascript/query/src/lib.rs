@@ -0,0 +1,372 @@
+//! Queries and rewrites for ecmascript ast nodes.
+//!
+//! This crate is meant to be used for writing codemods.
+//!
+//! ```rust,ignore
+//! use swc_ecma_query::{find_all, rewrite, select};
+//!
+//! // Find all calls with three arguments.
+//! let calls = find_all(&module, |call: &CallExpr| call.args.len() == 3);
+//!
+//! // Find identifiers used as the property of a member expression.
+//! let props: Vec<Ident> = select(&module, &"MemberExpr > Ident".parse()?);
+//!
+//! // Rename `foo` to `bar`, except for property names.
+//! rewrite(&mut module, |i: &mut Ident, path| {
+//!     if i.sym == *"foo" && path.parent() != Some("MemberExpr") {
+//!         i.sym = "bar".into();
+//!     }
+//! });
+//! ```
+//!
+//! # Paths
+//!
+//! Callbacks receive the [Path] of the node, which contains the kinds of its
+//! ancestors. Enums which wrap other nodes, like `Expr` or `Stmt`, can be
+//! queried but they are not part of paths. So the parent of the `Ident` in
+//! `foo.bar` is `MemberExpr`, not `Expr`.
+
+pub use self::selector::{Selector, SelectorError};
+use std::any::Any;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Node, Visit, VisitMut, VisitMutWith, VisitWith};
+
+mod selector;
+
+/// Position of a node in the ast.
+#[derive(Debug, Clone, Copy)]
+pub struct Path<'a> {
+    kind: &'static str,
+    /// Kinds of the ancestors. The last one is the parent.
+    ancestors: &'a [&'static str],
+}
+
+impl<'a> Path<'a> {
+    /// Name of the type of the node, like `CallExpr`.
+    pub fn kind(&self) -> &'static str {
+        self.kind
+    }
+
+    /// Kind of the nearest ancestor.
+    pub fn parent(&self) -> Option<&'static str> {
+        self.ancestors.last().copied()
+    }
+
+    /// Kinds of the ancestors, from the parent to the root.
+    pub fn ancestors(&self) -> impl Iterator<Item = &'static str> + 'a {
+        self.ancestors.iter().rev().copied()
+    }
+
+    /// Returns true if the node is inside of a node with the kind.
+    pub fn is_inside(&self, kind: &str) -> bool {
+        self.ancestors.contains(&kind)
+    }
+
+    /// Returns true if `selector` matches the node.
+    pub fn matches(&self, selector: &Selector) -> bool {
+        selector.matches(self)
+    }
+}
+
+/// Ast nodes which can be queried.
+///
+/// This is implemented for the common node types.
+pub trait Queryable: Any {
+    /// Name of the type, which is used by [Path] and [Selector].
+    const KIND: &'static str;
+
+    /// Calls `op` for `self` and all descendants, in pre-order.
+    #[doc(hidden)]
+    fn walk(&self, op: &mut dyn FnMut(&dyn Any, &Path<'_>));
+
+    /// Calls `op` for `self` and all descendants, in post-order.
+    #[doc(hidden)]
+    fn walk_mut(&mut self, op: &mut dyn FnMut(&mut dyn Any, &Path<'_>));
+}
+
+/// Calls `op` for each node of type `T` in `root`, including `root` itself.
+///
+/// Parents are visited before their children.
+pub fn for_each<T, N, F>(root: &N, mut op: F)
+where
+    T: Queryable,
+    N: Queryable,
+    F: FnMut(&T, &Path<'_>),
+{
+    root.walk(&mut |n, path| {
+        if let Some(n) = n.downcast_ref::<T>() {
+            op(n, path)
+        }
+    })
+}
+
+/// Returns clones of the nodes of type `T` in `root` for which `pred` returns
+/// true.
+pub fn find_all<T, N, F>(root: &N, mut pred: F) -> Vec<T>
+where
+    T: Queryable + Clone,
+    N: Queryable,
+    F: FnMut(&T) -> bool,
+{
+    let mut found = vec![];
+    for_each(root, |n: &T, _| {
+        if pred(n) {
+            found.push(n.clone())
+        }
+    });
+    found
+}
+
+/// Returns clones of the nodes of type `T` in `root` which are matched by
+/// `selector`.
+pub fn select<T, N>(root: &N, selector: &Selector) -> Vec<T>
+where
+    T: Queryable + Clone,
+    N: Queryable,
+{
+    let mut found = vec![];
+    for_each(root, |n: &T, path| {
+        if selector.matches(path) {
+            found.push(n.clone())
+        }
+    });
+    found
+}
+
+/// Calls `op` for each node of type `T` in `root`, including `root` itself.
+///
+/// Children are visited before their parents, so nodes created by `op` are
+/// not visited again.
+pub fn rewrite<T, N, F>(root: &mut N, mut op: F)
+where
+    T: Queryable,
+    N: Queryable,
+    F: FnMut(&mut T, &Path<'_>),
+{
+    root.walk_mut(&mut |n, path| {
+        if let Some(n) = n.downcast_mut::<T>() {
+            op(n, path)
+        }
+    })
+}
+
+struct Walker<'a> {
+    ancestors: Vec<&'static str>,
+    op: &'a mut dyn FnMut(&dyn Any, &Path<'_>),
+}
+
+struct WalkerMut<'a> {
+    ancestors: Vec<&'static str>,
+    op: &'a mut dyn FnMut(&mut dyn Any, &Path<'_>),
+}
+
+macro_rules! queryable {
+    (
+        nodes: { $($N:ident: $visit_n:ident, $visit_mut_n:ident;)* }
+        wrappers: { $($W:ident: $visit_w:ident, $visit_mut_w:ident;)* }
+    ) => {
+        /// Kinds which appear in paths.
+        const NODES: &[&str] = &[$(stringify!($N)),*];
+
+        /// Kinds which don't appear in paths.
+        const WRAPPERS: &[&str] = &[$(stringify!($W)),*];
+
+        impl Visit for Walker<'_> {
+            $(
+                fn $visit_n(&mut self, n: &$N, _: &dyn Node) {
+                    (self.op)(n, &Path {
+                        kind: stringify!($N),
+                        ancestors: &self.ancestors,
+                    });
+
+                    self.ancestors.push(stringify!($N));
+                    n.visit_children_with(self);
+                    self.ancestors.pop();
+                }
+            )*
+
+            $(
+                fn $visit_w(&mut self, n: &$W, _: &dyn Node) {
+                    (self.op)(n, &Path {
+                        kind: stringify!($W),
+                        ancestors: &self.ancestors,
+                    });
+
+                    n.visit_children_with(self);
+                }
+            )*
+        }
+
+        impl VisitMut for WalkerMut<'_> {
+            $(
+                fn $visit_mut_n(&mut self, n: &mut $N) {
+                    self.ancestors.push(stringify!($N));
+                    n.visit_mut_children_with(self);
+                    self.ancestors.pop();
+
+                    (self.op)(n, &Path {
+                        kind: stringify!($N),
+                        ancestors: &self.ancestors,
+                    });
+                }
+            )*
+
+            $(
+                fn $visit_mut_w(&mut self, n: &mut $W) {
+                    n.visit_mut_children_with(self);
+
+                    (self.op)(n, &Path {
+                        kind: stringify!($W),
+                        ancestors: &self.ancestors,
+                    });
+                }
+            )*
+        }
+
+        $(
+            queryable!(@impl $N);
+        )*
+        $(
+            queryable!(@impl $W);
+        )*
+    };
+
+    (@impl $T:ident) => {
+        impl Queryable for $T {
+            const KIND: &'static str = stringify!($T);
+
+            fn walk(&self, op: &mut dyn FnMut(&dyn Any, &Path<'_>)) {
+                self.visit_with(
+                    &Invalid { span: DUMMY_SP } as _,
+                    &mut Walker {
+                        ancestors: vec![],
+                        op,
+                    },
+                );
+            }
+
+            fn walk_mut(&mut self, op: &mut dyn FnMut(&mut dyn Any, &Path<'_>)) {
+                self.visit_mut_with(&mut WalkerMut {
+                    ancestors: vec![],
+                    op,
+                });
+            }
+        }
+    };
+}
+
+queryable!(
+    nodes: {
+        Module: visit_module, visit_mut_module;
+        Script: visit_script, visit_mut_script;
+
+        ImportDecl: visit_import_decl, visit_mut_import_decl;
+        ImportNamedSpecifier: visit_import_named_specifier, visit_mut_import_named_specifier;
+        ImportDefaultSpecifier: visit_import_default_specifier, visit_mut_import_default_specifier;
+        ImportStarAsSpecifier: visit_import_star_as_specifier, visit_mut_import_star_as_specifier;
+        ExportDecl: visit_export_decl, visit_mut_export_decl;
+        NamedExport: visit_named_export, visit_mut_named_export;
+        ExportNamedSpecifier: visit_export_named_specifier, visit_mut_export_named_specifier;
+        ExportDefaultDecl: visit_export_default_decl, visit_mut_export_default_decl;
+        ExportDefaultExpr: visit_export_default_expr, visit_mut_export_default_expr;
+        ExportAll: visit_export_all, visit_mut_export_all;
+
+        BlockStmt: visit_block_stmt, visit_mut_block_stmt;
+        ExprStmt: visit_expr_stmt, visit_mut_expr_stmt;
+        VarDecl: visit_var_decl, visit_mut_var_decl;
+        VarDeclarator: visit_var_declarator, visit_mut_var_declarator;
+        FnDecl: visit_fn_decl, visit_mut_fn_decl;
+        ClassDecl: visit_class_decl, visit_mut_class_decl;
+        IfStmt: visit_if_stmt, visit_mut_if_stmt;
+        ForStmt: visit_for_stmt, visit_mut_for_stmt;
+        ForInStmt: visit_for_in_stmt, visit_mut_for_in_stmt;
+        ForOfStmt: visit_for_of_stmt, visit_mut_for_of_stmt;
+        WhileStmt: visit_while_stmt, visit_mut_while_stmt;
+        DoWhileStmt: visit_do_while_stmt, visit_mut_do_while_stmt;
+        ReturnStmt: visit_return_stmt, visit_mut_return_stmt;
+        ThrowStmt: visit_throw_stmt, visit_mut_throw_stmt;
+        TryStmt: visit_try_stmt, visit_mut_try_stmt;
+        CatchClause: visit_catch_clause, visit_mut_catch_clause;
+        SwitchStmt: visit_switch_stmt, visit_mut_switch_stmt;
+        SwitchCase: visit_switch_case, visit_mut_switch_case;
+        LabeledStmt: visit_labeled_stmt, visit_mut_labeled_stmt;
+        BreakStmt: visit_break_stmt, visit_mut_break_stmt;
+        ContinueStmt: visit_continue_stmt, visit_mut_continue_stmt;
+
+        Function: visit_function, visit_mut_function;
+        Param: visit_param, visit_mut_param;
+        Class: visit_class, visit_mut_class;
+        ClassMethod: visit_class_method, visit_mut_class_method;
+        ClassProp: visit_class_prop, visit_mut_class_prop;
+        Constructor: visit_constructor, visit_mut_constructor;
+
+        CallExpr: visit_call_expr, visit_mut_call_expr;
+        NewExpr: visit_new_expr, visit_mut_new_expr;
+        MemberExpr: visit_member_expr, visit_mut_member_expr;
+        OptChainExpr: visit_opt_chain_expr, visit_mut_opt_chain_expr;
+        ArrowExpr: visit_arrow_expr, visit_mut_arrow_expr;
+        FnExpr: visit_fn_expr, visit_mut_fn_expr;
+        ClassExpr: visit_class_expr, visit_mut_class_expr;
+        AssignExpr: visit_assign_expr, visit_mut_assign_expr;
+        BinExpr: visit_bin_expr, visit_mut_bin_expr;
+        UnaryExpr: visit_unary_expr, visit_mut_unary_expr;
+        UpdateExpr: visit_update_expr, visit_mut_update_expr;
+        CondExpr: visit_cond_expr, visit_mut_cond_expr;
+        SeqExpr: visit_seq_expr, visit_mut_seq_expr;
+        ParenExpr: visit_paren_expr, visit_mut_paren_expr;
+        AwaitExpr: visit_await_expr, visit_mut_await_expr;
+        YieldExpr: visit_yield_expr, visit_mut_yield_expr;
+        ThisExpr: visit_this_expr, visit_mut_this_expr;
+        ArrayLit: visit_array_lit, visit_mut_array_lit;
+        ObjectLit: visit_object_lit, visit_mut_object_lit;
+        ExprOrSpread: visit_expr_or_spread, visit_mut_expr_or_spread;
+        SpreadElement: visit_spread_element, visit_mut_spread_element;
+        KeyValueProp: visit_key_value_prop, visit_mut_key_value_prop;
+        MethodProp: visit_method_prop, visit_mut_method_prop;
+        Tpl: visit_tpl, visit_mut_tpl;
+        TaggedTpl: visit_tagged_tpl, visit_mut_tagged_tpl;
+
+        Ident: visit_ident, visit_mut_ident;
+        Str: visit_str, visit_mut_str;
+        Number: visit_number, visit_mut_number;
+        Bool: visit_bool, visit_mut_bool;
+        Null: visit_null, visit_mut_null;
+        Regex: visit_regex, visit_mut_regex;
+
+        ArrayPat: visit_array_pat, visit_mut_array_pat;
+        ObjectPat: visit_object_pat, visit_mut_object_pat;
+        AssignPat: visit_assign_pat, visit_mut_assign_pat;
+        RestPat: visit_rest_pat, visit_mut_rest_pat;
+
+        JSXElement: visit_jsx_element, visit_mut_jsx_element;
+        JSXOpeningElement: visit_jsx_opening_element, visit_mut_jsx_opening_element;
+        JSXAttr: visit_jsx_attr, visit_mut_jsx_attr;
+        JSXExprContainer: visit_jsx_expr_container, visit_mut_jsx_expr_container;
+        JSXFragment: visit_jsx_fragment, visit_mut_jsx_fragment;
+        JSXText: visit_jsx_text, visit_mut_jsx_text;
+
+        TsTypeAnn: visit_ts_type_ann, visit_mut_ts_type_ann;
+        TsTypeRef: visit_ts_type_ref, visit_mut_ts_type_ref;
+        TsAsExpr: visit_ts_as_expr, visit_mut_ts_as_expr;
+        TsInterfaceDecl: visit_ts_interface_decl, visit_mut_ts_interface_decl;
+        TsTypeAliasDecl: visit_ts_type_alias_decl, visit_mut_ts_type_alias_decl;
+        TsEnumDecl: visit_ts_enum_decl, visit_mut_ts_enum_decl;
+        TsModuleDecl: visit_ts_module_decl, visit_mut_ts_module_decl;
+    }
+    wrappers: {
+        Program: visit_program, visit_mut_program;
+        ModuleItem: visit_module_item, visit_mut_module_item;
+        ModuleDecl: visit_module_decl, visit_mut_module_decl;
+        Stmt: visit_stmt, visit_mut_stmt;
+        Decl: visit_decl, visit_mut_decl;
+        Expr: visit_expr, visit_mut_expr;
+        Pat: visit_pat, visit_mut_pat;
+        Lit: visit_lit, visit_mut_lit;
+        Prop: visit_prop, visit_mut_prop;
+        PropName: visit_prop_name, visit_mut_prop_name;
+        ClassMember: visit_class_member, visit_mut_class_member;
+        JSXElementChild: visit_jsx_element_child, visit_mut_jsx_element_child;
+        TsType: visit_ts_type, visit_mut_ts_type;
+    }
+);
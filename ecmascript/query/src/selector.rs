@@ -0,0 +1,141 @@
+use crate::{Path, NODES, WRAPPERS};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// Path based selector, like `CallExpr > MemberExpr Ident`.
+///
+/// A selector is a list of node kinds separated by combinators.
+///
+///  - `A B` matches `B` if it's inside of `A`.
+///  - `A > B` matches `B` if its parent is `A`.
+///  - `*` matches any node.
+///
+/// Node kinds are names of the ast types, like `CallExpr`. Enums like `Expr`
+/// can only be used as the last component because they don't appear in
+/// paths. See [Path].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    steps: Vec<Step>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Step {
+    /// Relation to the previous step. Ignored for the first step.
+    combinator: Combinator,
+    /// `None` for `*`.
+    kind: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+impl Selector {
+    /// Returns true if the node at `path` is matched by this selector.
+    pub fn matches(&self, path: &Path<'_>) -> bool {
+        let last = self.steps.len() - 1;
+        if !self.steps[last].matches(path.kind()) {
+            return false;
+        }
+
+        self.matches_ancestors(last, path.ancestors)
+    }
+
+    /// Matches `self.steps[..idx]` against `ancestors`, where `steps[idx]` is
+    /// matched by the node inside of `ancestors`.
+    fn matches_ancestors(&self, idx: usize, ancestors: &[&'static str]) -> bool {
+        if idx == 0 {
+            return true;
+        }
+
+        let step = &self.steps[idx - 1];
+        match self.steps[idx].combinator {
+            Combinator::Child => match ancestors.split_last() {
+                Some((&parent, rest)) => {
+                    step.matches(parent) && self.matches_ancestors(idx - 1, rest)
+                }
+                None => false,
+            },
+            Combinator::Descendant => (0..ancestors.len()).rev().any(|i| {
+                step.matches(ancestors[i]) && self.matches_ancestors(idx - 1, &ancestors[..i])
+            }),
+        }
+    }
+}
+
+impl Step {
+    fn matches(&self, kind: &str) -> bool {
+        match self.kind {
+            Some(k) => k == kind,
+            None => true,
+        }
+    }
+}
+
+impl FromStr for Selector {
+    type Err = SelectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut steps = vec![];
+        let mut combinator = Combinator::Descendant;
+        let mut after_combinator = false;
+
+        for token in s.replace('>', " > ").split_whitespace() {
+            if token == ">" {
+                if steps.is_empty() || after_combinator {
+                    return Err(SelectorError(format!("unexpected `>` in `{}`", s)));
+                }
+                combinator = Combinator::Child;
+                after_combinator = true;
+                continue;
+            }
+
+            let kind = if token == "*" {
+                None
+            } else {
+                match NODES.iter().chain(WRAPPERS).find(|&&k| k == token) {
+                    Some(&k) => Some(k),
+                    None => return Err(SelectorError(format!("unknown node kind `{}`", token))),
+                }
+            };
+
+            steps.push(Step { combinator, kind });
+            combinator = Combinator::Descendant;
+            after_combinator = false;
+        }
+
+        if steps.is_empty() || after_combinator {
+            return Err(SelectorError(format!("incomplete selector `{}`", s)));
+        }
+
+        if let Some(kind) = steps[..steps.len() - 1]
+            .iter()
+            .filter_map(|step| step.kind)
+            .find(|kind| WRAPPERS.contains(kind))
+        {
+            return Err(SelectorError(format!(
+                "`{}` can only be used as the last component of a selector",
+                kind
+            )));
+        }
+
+        Ok(Selector { steps })
+    }
+}
+
+/// Error returned by [Selector::from_str].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorError(String);
+
+impl Display for SelectorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Error for SelectorError {}
@@ -0,0 +1,146 @@
+use swc_common::FileName;
+use swc_ecma_ast::*;
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_parser::{lexer::Lexer, JscTarget, Parser, StringInput, Syntax};
+use swc_ecma_query::{find_all, for_each, rewrite, select, Selector};
+
+/// Parses `src`, calls `op` and prints the module.
+fn run<F>(src: &str, op: F) -> String
+where
+    F: FnOnce(&mut Module),
+{
+    testing::run_test2(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let lexer = Lexer::new(
+            Syntax::default(),
+            JscTarget::Es2020,
+            StringInput::from(&*fm),
+            None,
+        );
+        let mut module = Parser::new_from(lexer).parse_module().unwrap();
+
+        op(&mut module);
+
+        let mut buf = vec![];
+        {
+            let mut emitter = Emitter {
+                cfg: Default::default(),
+                cm: cm.clone(),
+                comments: None,
+                wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+            };
+            emitter.emit_module(&module).unwrap();
+        }
+
+        Ok(String::from_utf8(buf).unwrap())
+    })
+    .unwrap()
+}
+
+fn names(idents: Vec<Ident>) -> Vec<String> {
+    idents.into_iter().map(|i| i.sym.to_string()).collect()
+}
+
+#[test]
+fn find_all_calls() {
+    run("a(1, 2); b(c(3));", |m| {
+        let calls = find_all(&*m, |c: &CallExpr| c.args.len() == 1);
+        assert_eq!(calls.len(), 2);
+
+        let exprs = find_all(&*m, |e: &Expr| match e {
+            Expr::Lit(Lit::Num(n)) => n.value > 1.0,
+            _ => false,
+        });
+        assert_eq!(exprs.len(), 2);
+    });
+}
+
+#[test]
+fn paths() {
+    run("function f(x) { return x.y; }", |m| {
+        let mut found = vec![];
+        for_each(&*m, |i: &Ident, path| {
+            found.push((
+                i.sym.to_string(),
+                path.parent(),
+                path.is_inside("ReturnStmt"),
+            ))
+        });
+
+        assert_eq!(
+            found,
+            vec![
+                ("f".into(), Some("FnDecl"), false),
+                ("x".into(), Some("Param"), false),
+                ("x".into(), Some("MemberExpr"), true),
+                ("y".into(), Some("MemberExpr"), true),
+            ]
+        );
+    });
+}
+
+#[test]
+fn selectors() {
+    run("a.b(c.d); e(f);", |m| {
+        let sel: Selector = "CallExpr > MemberExpr > Ident".parse().unwrap();
+        assert_eq!(names(select(&*m, &sel)), vec!["a", "b"]);
+
+        let sel: Selector = "CallExpr Ident".parse().unwrap();
+        assert_eq!(names(select(&*m, &sel)), vec!["a", "b", "c", "d", "e", "f"]);
+
+        let sel: Selector = "ExprOrSpread > *".parse().unwrap();
+        assert_eq!(select::<MemberExpr, _>(&*m, &sel).len(), 1);
+        assert_eq!(names(select(&*m, &sel)), vec!["f"]);
+
+        let sel: Selector = "CallExpr > Expr".parse().unwrap();
+        assert_eq!(select::<Expr, _>(&*m, &sel).len(), 2);
+    });
+}
+
+#[test]
+fn invalid_selectors() {
+    assert!("".parse::<Selector>().is_err());
+    assert!("> Ident".parse::<Selector>().is_err());
+    assert!("CallExpr >".parse::<Selector>().is_err());
+    assert!("CallExpr > > Ident".parse::<Selector>().is_err());
+    assert!("Foo".parse::<Selector>().is_err());
+    assert!("Expr > Ident".parse::<Selector>().is_err());
+}
+
+#[test]
+fn rewrite_idents() {
+    let out = run("foo(foo.foo); var foo = 1;", |m| {
+        rewrite(m, |i: &mut Ident, path| {
+            if &*i.sym == "foo" && path.parent() != Some("MemberExpr") {
+                i.sym = "bar".into();
+            }
+        })
+    });
+
+    assert_eq!(out, "bar(foo.foo);\nvar bar = 1;\n");
+}
+
+#[test]
+fn rewrite_calls() {
+    let out = run("console.log(a); log(b);", |m| {
+        // Callees. Arguments are inside of `ExprOrSpread`.
+        let sel: Selector = "CallExpr > Expr".parse().unwrap();
+        rewrite(m, |e: &mut Expr, path| {
+            if !path.matches(&sel) {
+                return;
+            }
+
+            if let Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Expr(obj),
+                ..
+            }) = e
+            {
+                if let Expr::Ident(i) = &mut **obj {
+                    i.sym = "logger".into();
+                }
+            }
+        })
+    });
+
+    assert_eq!(out, "logger.log(a);\nlog(b);\n");
+}
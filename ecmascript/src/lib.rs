@@ -1,3 +1,5 @@
+#[cfg(feature = "bundler")]
+pub use swc_bundler as bundler;
 pub use swc_ecma_ast as ast;
 #[cfg(feature = "codegen")]
 pub use swc_ecma_codegen as codegen;
@@ -5,6 +7,8 @@ pub use swc_ecma_codegen as codegen;
 pub use swc_ecma_dep_graph as dep_graph;
 #[cfg(feature = "parser")]
 pub use swc_ecma_parser as parser;
+#[cfg(feature = "query")]
+pub use swc_ecma_query as query;
 #[cfg(feature = "transforms")]
 pub use swc_ecma_transforms as transforms;
 #[cfg(feature = "utils")]
@@ -5,7 +5,7 @@ extern crate test;
 use swc_common::{chain, sync::Lrc, FileName, Mark, SourceMap};
 use swc_ecma_ast::Module;
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
-use swc_ecma_transforms::{compat, helpers, typescript};
+use swc_ecma_transforms::{compat, helpers, pass::fused, typescript};
 use swc_ecma_visit::{Fold, FoldWith};
 use test::Bencher;
 
@@ -117,7 +117,7 @@ fn es2018(b: &mut Bencher) {
 
 #[bench]
 fn es2018_object_rest_spread(b: &mut Bencher) {
-    run(b, || compat::es2018::object_rest_spread());
+    run(b, || compat::es2018::object_rest_spread(Default::default()));
 }
 
 #[bench]
@@ -148,7 +148,7 @@ fn es2016_exponentation(b: &mut Bencher) {
 #[bench]
 fn es2015(b: &mut Bencher) {
     run(b, || {
-        compat::es2015(Mark::fresh(Mark::root()), Default::default())
+        compat::es2015(Mark::fresh(Mark::root()), None, Default::default())
     });
 }
 
@@ -169,7 +169,7 @@ fn es2015_block_scoping(b: &mut Bencher) {
 
 #[bench]
 fn es2015_classes(b: &mut Bencher) {
-    run(b, || compat::es2015::classes());
+    run(b, || compat::es2015::classes(None, Default::default()));
 }
 
 #[bench]
@@ -231,6 +231,28 @@ fn es2015_typeof_symbol(b: &mut Bencher) {
     run(b, || compat::es2015::typeof_symbol());
 }
 
+#[bench]
+fn es2015_leaf_chained(b: &mut Bencher) {
+    run(b, || {
+        chain!(
+            compat::es2015::sticky_regex(),
+            compat::es2015::instance_of(),
+            compat::es2015::shorthand()
+        )
+    });
+}
+
+#[bench]
+fn es2015_leaf_fused(b: &mut Bencher) {
+    run(b, || {
+        fused(chain!(
+            compat::es2015::sticky_regex(),
+            compat::es2015::instance_of(),
+            compat::es2015::shorthand()
+        ))
+    });
+}
+
 #[bench]
 fn es3(b: &mut Bencher) {
     run(b, || compat::es3(Default::default()));
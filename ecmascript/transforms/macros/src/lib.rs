@@ -3,6 +3,7 @@ use proc_macro::TokenStream;
 use swc_macros_common::print;
 
 mod fast;
+mod template;
 
 /// This macro adds fast-path to the `swc_ecma_visit::Fold` and
 /// `swc_ecma_visit::Visit`.
@@ -24,3 +25,23 @@ pub fn fast_path(attr: TokenStream, item: TokenStream) -> TokenStream {
     let expanded = fast::expand(attr.into(), item);
     print("fast_path", expanded.dump())
 }
+
+/// Builds an expression from a javascript snippet, like `@babel/template`.
+///
+/// The snippet is parsed at compile time, and the macro expands to code which
+/// creates the ast. The resulting expression is a `Box<Expr>` with dummy
+/// spans.
+///
+/// Identifiers starting with `$` are placeholders, which are replaced with
+/// the values given after the snippet. A value should be an `Expr` or a
+/// `Box<Expr>`.
+///
+/// # Usage
+///
+/// `quote!("$obj.hasOwnProperty($key)", obj = obj, key = key)`
+#[proc_macro]
+pub fn quote(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as template::Input);
+    let expanded = template::expand(input).unwrap_or_else(|err| err.to_compile_error());
+    print("quote", expanded)
+}
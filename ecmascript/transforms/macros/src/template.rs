@@ -0,0 +1,386 @@
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::quote;
+use std::collections::HashMap;
+use swc_common::{BytePos, Globals, GLOBALS};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{Parser, StringInput, Syntax};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Error, Expr as RustExpr, Ident as RustIdent, LitStr, Token,
+};
+
+pub struct Input {
+    src: LitStr,
+    vars: Punctuated<Var, Token![,]>,
+}
+
+struct Var {
+    name: RustIdent,
+    value: RustExpr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let src = input.parse()?;
+        let vars = if input.is_empty() {
+            Default::default()
+        } else {
+            input.parse::<Token![,]>()?;
+            Punctuated::parse_terminated(input)?
+        };
+
+        Ok(Input { src, vars })
+    }
+}
+
+impl Parse for Var {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+
+        Ok(Var { name, value })
+    }
+}
+
+pub fn expand(input: Input) -> syn::Result<TokenStream> {
+    let span = input.src.span();
+    let src = input.src.value();
+    let expr = parse(&src).map_err(|msg| Error::new(span, msg))?;
+
+    let mut vars = HashMap::new();
+    for var in &input.vars {
+        if vars.insert(var.name.to_string(), (var, false)).is_some() {
+            return Err(Error::new(var.name.span(), "duplicate placeholder"));
+        }
+    }
+
+    let mut q = Quoter { span, vars };
+    let expr = q.expr(&expr)?;
+
+    for (var, used) in q.vars.values() {
+        if !used {
+            return Err(Error::new(
+                var.name.span(),
+                format!("`${}` is not used in the snippet", var.name),
+            ));
+        }
+    }
+
+    // Values are evaluated once, before building the ast.
+    let bindings = input.vars.iter().map(|var| {
+        let name = placeholder(&var.name.to_string());
+        let value = &var.value;
+        quote!(let #name: ::std::boxed::Box<::swc_ecma_ast::Expr> = ::std::boxed::Box::from(#value);)
+    });
+
+    Ok(quote!({
+        #(#bindings)*
+        #expr
+    }))
+}
+
+fn parse(src: &str) -> Result<Box<Expr>, String> {
+    GLOBALS.set(&Globals::new(), || {
+        let input = StringInput::new(src, BytePos(0), BytePos(src.len() as _));
+        let script = Parser::new(Syntax::default(), input, None)
+            .parse_script()
+            .map_err(|err| format!("failed to parse snippet: {}", err.kind().msg()))?;
+
+        match &*script.body {
+            [Stmt::Expr(ExprStmt { expr, .. })] => Ok(expr.clone()),
+            _ => Err("snippet should be a single expression".into()),
+        }
+    })
+}
+
+fn placeholder(name: &str) -> RustIdent {
+    RustIdent::new(&format!("__quote_{}", name), Span::mixed_site())
+}
+
+struct Quoter<'a> {
+    span: Span,
+    /// Placeholders, and whether they are used.
+    vars: HashMap<String, (&'a Var, bool)>,
+}
+
+impl Quoter<'_> {
+    fn unsupported(&self, kind: &str) -> Error {
+        Error::new(self.span, format!("{} is not supported by quote!", kind))
+    }
+
+    fn exprs(&mut self, exprs: &[Box<Expr>]) -> syn::Result<Vec<TokenStream>> {
+        exprs.iter().map(|e| self.expr(e)).collect()
+    }
+
+    fn args(&mut self, args: &[ExprOrSpread]) -> syn::Result<Vec<TokenStream>> {
+        args.iter().map(|arg| self.arg(arg)).collect()
+    }
+
+    fn arg(&mut self, arg: &ExprOrSpread) -> syn::Result<TokenStream> {
+        let spread = match arg.spread {
+            Some(..) => quote!(Some(::swc_common::DUMMY_SP)),
+            None => quote!(None),
+        };
+        let expr = self.expr(&arg.expr)?;
+
+        Ok(quote!(::swc_ecma_ast::ExprOrSpread {
+            spread: #spread,
+            expr: #expr,
+        }))
+    }
+
+    fn expr_or_super(&mut self, e: &ExprOrSuper) -> syn::Result<TokenStream> {
+        match e {
+            ExprOrSuper::Super(..) => Err(self.unsupported("`super`")),
+            ExprOrSuper::Expr(e) => {
+                let e = self.expr(e)?;
+                Ok(quote!(::swc_ecma_ast::ExprOrSuper::Expr(#e)))
+            }
+        }
+    }
+
+    /// Returns tokens which evaluate to `Box<Expr>`.
+    fn expr(&mut self, e: &Expr) -> syn::Result<TokenStream> {
+        let expr = match e {
+            Expr::Ident(i) if i.sym.starts_with('$') => {
+                let name = &i.sym[1..];
+                match self.vars.get_mut(name) {
+                    Some((_, used)) => {
+                        *used = true;
+                        let name = placeholder(name);
+                        // A placeholder can be used multiple times.
+                        return Ok(quote!(#name.clone()));
+                    }
+                    None => {
+                        return Err(Error::new(
+                            self.span,
+                            format!("no value is given for `{}`", i.sym),
+                        ))
+                    }
+                }
+            }
+            Expr::Ident(i) => {
+                let i = ident(i);
+                quote!(::swc_ecma_ast::Expr::Ident(#i))
+            }
+            Expr::This(..) => quote!(::swc_ecma_ast::Expr::This(::swc_ecma_ast::ThisExpr {
+                span: ::swc_common::DUMMY_SP,
+            })),
+            Expr::Lit(lit) => {
+                let lit = self.lit(lit)?;
+                quote!(::swc_ecma_ast::Expr::Lit(#lit))
+            }
+            Expr::Array(ArrayLit { elems, .. }) => {
+                let elems = elems
+                    .iter()
+                    .map(|elem| match elem {
+                        Some(elem) => self.arg(elem).map(|elem| quote!(Some(#elem))),
+                        None => Ok(quote!(None)),
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
+
+                quote!(::swc_ecma_ast::Expr::Array(::swc_ecma_ast::ArrayLit {
+                    span: ::swc_common::DUMMY_SP,
+                    elems: vec![#(#elems),*],
+                }))
+            }
+            Expr::Unary(UnaryExpr { op, arg, .. }) => {
+                let op = unary_op(*op);
+                let arg = self.expr(arg)?;
+
+                quote!(::swc_ecma_ast::Expr::Unary(::swc_ecma_ast::UnaryExpr {
+                    span: ::swc_common::DUMMY_SP,
+                    op: ::swc_ecma_ast::UnaryOp::#op,
+                    arg: #arg,
+                }))
+            }
+            Expr::Bin(BinExpr {
+                op, left, right, ..
+            }) => {
+                let op = binary_op(*op);
+                let left = self.expr(left)?;
+                let right = self.expr(right)?;
+
+                quote!(::swc_ecma_ast::Expr::Bin(::swc_ecma_ast::BinExpr {
+                    span: ::swc_common::DUMMY_SP,
+                    op: ::swc_ecma_ast::BinaryOp::#op,
+                    left: #left,
+                    right: #right,
+                }))
+            }
+            Expr::Member(MemberExpr {
+                obj,
+                prop,
+                computed,
+                ..
+            }) => {
+                let obj = self.expr_or_super(obj)?;
+                let prop = match &**prop {
+                    // `$name` is a property name, not a placeholder.
+                    Expr::Ident(i) if !computed => {
+                        let i = ident(i);
+                        quote!(::std::boxed::Box::new(::swc_ecma_ast::Expr::Ident(#i)))
+                    }
+                    _ => self.expr(prop)?,
+                };
+
+                quote!(::swc_ecma_ast::Expr::Member(::swc_ecma_ast::MemberExpr {
+                    span: ::swc_common::DUMMY_SP,
+                    obj: #obj,
+                    prop: #prop,
+                    computed: #computed,
+                }))
+            }
+            Expr::Cond(CondExpr {
+                test, cons, alt, ..
+            }) => {
+                let test = self.expr(test)?;
+                let cons = self.expr(cons)?;
+                let alt = self.expr(alt)?;
+
+                quote!(::swc_ecma_ast::Expr::Cond(::swc_ecma_ast::CondExpr {
+                    span: ::swc_common::DUMMY_SP,
+                    test: #test,
+                    cons: #cons,
+                    alt: #alt,
+                }))
+            }
+            Expr::Call(CallExpr { callee, args, .. }) => {
+                let callee = self.expr_or_super(callee)?;
+                let args = self.args(args)?;
+
+                quote!(::swc_ecma_ast::Expr::Call(::swc_ecma_ast::CallExpr {
+                    span: ::swc_common::DUMMY_SP,
+                    callee: #callee,
+                    args: vec![#(#args),*],
+                    type_args: None,
+                }))
+            }
+            Expr::New(NewExpr { callee, args, .. }) => {
+                let callee = self.expr(callee)?;
+                let args = match args {
+                    Some(args) => {
+                        let args = self.args(args)?;
+                        quote!(Some(vec![#(#args),*]))
+                    }
+                    None => quote!(None),
+                };
+
+                quote!(::swc_ecma_ast::Expr::New(::swc_ecma_ast::NewExpr {
+                    span: ::swc_common::DUMMY_SP,
+                    callee: #callee,
+                    args: #args,
+                    type_args: None,
+                }))
+            }
+            Expr::Seq(SeqExpr { exprs, .. }) => {
+                let exprs = self.exprs(exprs)?;
+
+                quote!(::swc_ecma_ast::Expr::Seq(::swc_ecma_ast::SeqExpr {
+                    span: ::swc_common::DUMMY_SP,
+                    exprs: vec![#(#exprs),*],
+                }))
+            }
+            Expr::Paren(ParenExpr { expr, .. }) => {
+                let expr = self.expr(expr)?;
+
+                quote!(::swc_ecma_ast::Expr::Paren(::swc_ecma_ast::ParenExpr {
+                    span: ::swc_common::DUMMY_SP,
+                    expr: #expr,
+                }))
+            }
+            _ => return Err(self.unsupported("this expression")),
+        };
+
+        Ok(quote!(::std::boxed::Box::new(#expr)))
+    }
+
+    fn lit(&mut self, lit: &Lit) -> syn::Result<TokenStream> {
+        Ok(match lit {
+            Lit::Str(Str {
+                value, has_escape, ..
+            }) => {
+                let value = &**value;
+                quote!(::swc_ecma_ast::Lit::Str(::swc_ecma_ast::Str {
+                    span: ::swc_common::DUMMY_SP,
+                    value: ::swc_atoms::JsWord::from(#value),
+                    has_escape: #has_escape,
+                    raw: None,
+                }))
+            }
+            Lit::Bool(Bool { value, .. }) => {
+                quote!(::swc_ecma_ast::Lit::Bool(::swc_ecma_ast::Bool {
+                    span: ::swc_common::DUMMY_SP,
+                    value: #value,
+                }))
+            }
+            Lit::Null(..) => quote!(::swc_ecma_ast::Lit::Null(::swc_ecma_ast::Null {
+                span: ::swc_common::DUMMY_SP,
+            })),
+            Lit::Num(Number { value, .. }) => {
+                let value = Literal::f64_suffixed(*value);
+                quote!(::swc_ecma_ast::Lit::Num(::swc_ecma_ast::Number {
+                    span: ::swc_common::DUMMY_SP,
+                    value: #value,
+                    raw: None,
+                }))
+            }
+            _ => return Err(self.unsupported("this literal")),
+        })
+    }
+}
+
+fn ident(i: &Ident) -> TokenStream {
+    let sym = &*i.sym;
+    quote!(::swc_ecma_ast::Ident::new(
+        ::swc_atoms::JsWord::from(#sym),
+        ::swc_common::DUMMY_SP,
+    ))
+}
+
+fn unary_op(op: UnaryOp) -> RustIdent {
+    let name = match op {
+        UnaryOp::Minus => "Minus",
+        UnaryOp::Plus => "Plus",
+        UnaryOp::Bang => "Bang",
+        UnaryOp::Tilde => "Tilde",
+        UnaryOp::TypeOf => "TypeOf",
+        UnaryOp::Void => "Void",
+        UnaryOp::Delete => "Delete",
+    };
+    RustIdent::new(name, Span::call_site())
+}
+
+fn binary_op(op: BinaryOp) -> RustIdent {
+    let name = match op {
+        BinaryOp::EqEq => "EqEq",
+        BinaryOp::NotEq => "NotEq",
+        BinaryOp::EqEqEq => "EqEqEq",
+        BinaryOp::NotEqEq => "NotEqEq",
+        BinaryOp::Lt => "Lt",
+        BinaryOp::LtEq => "LtEq",
+        BinaryOp::Gt => "Gt",
+        BinaryOp::GtEq => "GtEq",
+        BinaryOp::LShift => "LShift",
+        BinaryOp::RShift => "RShift",
+        BinaryOp::ZeroFillRShift => "ZeroFillRShift",
+        BinaryOp::Add => "Add",
+        BinaryOp::Sub => "Sub",
+        BinaryOp::Mul => "Mul",
+        BinaryOp::Div => "Div",
+        BinaryOp::Mod => "Mod",
+        BinaryOp::BitOr => "BitOr",
+        BinaryOp::BitXor => "BitXor",
+        BinaryOp::BitAnd => "BitAnd",
+        BinaryOp::LogicalOr => "LogicalOr",
+        BinaryOp::LogicalAnd => "LogicalAnd",
+        BinaryOp::In => "In",
+        BinaryOp::InstanceOf => "InstanceOf",
+        BinaryOp::Exp => "Exp",
+        BinaryOp::NullishCoalescing => "NullishCoalescing",
+        BinaryOp::Pipeline => "Pipeline",
+    };
+    RustIdent::new(name, Span::call_site())
+}
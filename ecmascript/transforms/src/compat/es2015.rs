@@ -2,24 +2,26 @@ pub use self::{
     arrow::arrow, block_scoped_fn::block_scoped_functions, block_scoping::block_scoping,
     classes::classes, computed_props::computed_properties, destructuring::destructuring,
     duplicate_keys::duplicate_keys, for_of::for_of, function_name::function_name,
-    instanceof::instance_of, parameters::parameters, regenerator::regenerator,
+    instanceof::instance_of, literals::literals, parameters::parameters, regenerator::regenerator,
     shorthand_property::shorthand, spread::spread, sticky_regex::sticky_regex,
     template_literal::template_literal, typeof_symbol::typeof_symbol,
 };
+use crate::pass::{fused, Optional};
 use serde::Deserialize;
-use swc_common::{chain, Mark};
+use swc_common::{chain, comments::Comments, Mark};
 use swc_ecma_visit::Fold;
 
 mod arrow;
 mod block_scoped_fn;
 mod block_scoping;
 pub mod classes;
-mod computed_props;
+pub mod computed_props;
 pub mod destructuring;
 mod duplicate_keys;
 pub mod for_of;
 mod function_name;
 mod instanceof;
+mod literals;
 mod parameters;
 mod regenerator;
 mod shorthand_property;
@@ -32,29 +34,43 @@ fn exprs() -> impl Fold {
     chain!(
         arrow(),
         duplicate_keys(),
-        sticky_regex(),
-        instance_of(),
+        fused(chain!(
+            literals(),
+            sticky_regex(),
+            instance_of(),
+            shorthand()
+        )),
         typeof_symbol(),
-        shorthand(),
     )
 }
 
 /// Compiles es2015 to es5.
-pub fn es2015(global_mark: Mark, c: Config) -> impl Fold {
+///
+/// If `comments` is given, generated calls which are known to be pure are
+/// annotated with `/*#__PURE__*/`.
+pub fn es2015<'a>(
+    global_mark: Mark,
+    comments: Option<&'a dyn Comments>,
+    c: Config,
+) -> impl 'a + Fold {
     chain!(
         block_scoped_functions(),
         template_literal(),
-        classes(),
-        spread(c.spread),
+        // Names anonymous classes before they are converted to functions.
+        Optional::new(function_name(), c.keep_class_names),
+        classes(comments, c.classes),
+        spread(spread::Config { loose: c.loose }),
         function_name(),
         exprs(),
+        // Names functions converted from arrows.
+        Optional::new(function_name(), c.keep_fn_names),
         for_of(c.for_of),
         // Should come before parameters
         // See: https://github.com/swc-project/swc/issues/1036
         regenerator(global_mark),
         parameters(),
-        computed_properties(),
-        destructuring(c.destructuring),
+        computed_properties(computed_props::Config { loose: c.loose }),
+        destructuring(destructuring::Config { loose: c.loose }),
         block_scoping(),
     )
 }
@@ -62,13 +78,24 @@ pub fn es2015(global_mark: Mark, c: Config) -> impl Fold {
 #[derive(Debug, Default, Deserialize)]
 pub struct Config {
     #[serde(flatten)]
-    pub for_of: for_of::Config,
+    pub classes: classes::Config,
 
     #[serde(flatten)]
-    pub destructuring: destructuring::Config,
+    pub for_of: for_of::Config,
 
-    #[serde(flatten)]
-    pub spread: spread::Config,
+    /// Enables loose mode of computed properties, destructuring and spread.
+    #[serde(default)]
+    pub loose: bool,
+
+    /// Preserves `.name` of classes assigned to variables, like
+    /// `var Foo = class {}`.
+    #[serde(default)]
+    pub keep_class_names: bool,
+
+    /// Preserves `.name` of arrow functions assigned to variables or
+    /// properties, like `var foo = () => {}`.
+    #[serde(default)]
+    pub keep_fn_names: bool,
 }
 
 #[cfg(test)]
@@ -76,9 +103,26 @@ mod tests {
     use super::*;
     use crate::resolver;
 
+    #[test]
+    fn deserialize_config() {
+        let c: Config = serde_json::from_str(
+            r#"{ "loose": true, "assumeArray": true, "keep_class_names": true }"#,
+        )
+        .unwrap();
+
+        assert!(c.loose);
+        assert!(c.for_of.assume_array);
+        assert!(c.keep_class_names);
+        assert!(!c.keep_fn_names);
+
+        let c: Config = serde_json::from_str("{}").unwrap();
+        assert!(!c.loose);
+        assert!(!c.for_of.assume_array);
+    }
+
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| es2015(Mark::fresh(Mark::root()), Default::default()),
+        |_| es2015(Mark::fresh(Mark::root()), None, Default::default()),
         issue_169,
         r#"
 export class Foo {
@@ -110,7 +154,7 @@ export var Foo = function() {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| es2015(Mark::fresh(Mark::root()), Default::default()),
+        |_| es2015(Mark::fresh(Mark::root()), None, Default::default()),
         issue_189,
         r#"
 class HomePage extends React.Component {}
@@ -130,7 +174,7 @@ var HomePage = function(_Component) {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| es2015(Mark::fresh(Mark::root()), Default::default()),
+        |_| es2015(Mark::fresh(Mark::root()), None, Default::default()),
         issue_227,
         "export default function fn1(...args) {
   fn2(...args);
@@ -197,7 +241,7 @@ function foo(scope) {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| es2015(Mark::fresh(Mark::root()), Default::default()),
+        |_| es2015(Mark::fresh(Mark::root()), None, Default::default()),
         issue_413,
         r#"
 export const getBadgeBorderRadius = (text, color) => {
@@ -213,7 +257,7 @@ export var getBadgeBorderRadius = function(text, color) {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| es2015(Mark::fresh(Mark::root()), Default::default()),
+        |_| es2015(Mark::fresh(Mark::root()), None, Default::default()),
         issue_400_1,
         "class A {
     constructor() {
@@ -274,7 +318,7 @@ var B = function(A) {
 
     test_exec!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| es2015(Mark::fresh(Mark::root()), Default::default()),
+        |_| es2015(Mark::fresh(Mark::root()), None, Default::default()),
         issue_400_2,
         "class A {
     constructor() {
@@ -300,4 +344,39 @@ class B extends A {
 
 return new B(20).print()"
     );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| es2015(
+            Mark::fresh(Mark::root()),
+            None,
+            Config {
+                keep_class_names: true,
+                ..Default::default()
+            }
+        ),
+        keep_class_names,
+        "var Foo = class {};",
+        "
+var Foo = function Foo() {
+    'use strict';
+    _classCallCheck(this, Foo);
+};
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| es2015(
+            Mark::fresh(Mark::root()),
+            None,
+            Config {
+                keep_fn_names: true,
+                ..Default::default()
+            }
+        ),
+        keep_fn_names,
+        "var foo = () => {}, bar = { baz: () => {} };",
+        "var foo = function foo() {}, bar = { baz: function baz() {} };"
+    );
 }
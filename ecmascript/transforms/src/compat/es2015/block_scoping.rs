@@ -283,6 +283,7 @@ impl BlockScoping {
                                         span: DUMMY_SP,
                                         value: js_word!("object"),
                                         has_escape: false,
+                                        raw: None,
                                     }))
                                     .into(),
                                 })),
@@ -788,6 +789,7 @@ impl Fold for FlowHelper<'_> {
                             span,
                             value: "continue".into(),
                             has_escape: false,
+                            raw: None,
                         }))
                         .into(),
                     ),
@@ -801,6 +803,7 @@ impl Fold for FlowHelper<'_> {
                         span,
                         value: "break".into(),
                         has_escape: false,
+                        raw: None,
                     })))),
                 });
             }
@@ -1211,6 +1214,7 @@ expect(foo()).toBe(false);
             let mark = Mark::fresh(Mark::root());
             es2015::es2015(
                 mark,
+                None,
                 es2015::Config {
                     ..Default::default()
                 },
@@ -1240,6 +1244,7 @@ expect(foo()).toBe(false);
             let mark = Mark::fresh(Mark::root());
             es2015::es2015(
                 mark,
+                None,
                 es2015::Config {
                     ..Default::default()
                 },
@@ -1270,6 +1275,7 @@ expect(foo()).toBe(false);
             let mark = Mark::fresh(Mark::root());
             es2015::es2015(
                 mark,
+                None,
                 es2015::Config {
                     ..Default::default()
                 },
@@ -1305,6 +1311,7 @@ expect(foo()).toBe(false);
             let mark = Mark::fresh(Mark::root());
             es2015::es2015(
                 mark,
+                None,
                 es2015::Config {
                     ..Default::default()
                 },
@@ -1340,6 +1347,7 @@ expect(foo()).toBe(false);
             let mark = Mark::fresh(Mark::root());
             es2015::es2015(
                 mark,
+                None,
                 es2015::Config {
                     ..Default::default()
                 },
@@ -1383,6 +1391,7 @@ expect(foo()).toBe(false);
             let mark = Mark::fresh(Mark::root());
             es2015::es2015(
                 mark,
+                None,
                 es2015::Config {
                     ..Default::default()
                 },
@@ -1426,6 +1435,7 @@ expect(foo()).toBe(false);
                 async_to_generator(),
                 es2015::es2015(
                     mark,
+                    None,
                     es2015::Config {
                         ..Default::default()
                     },
@@ -1498,6 +1508,7 @@ expect(foo()).toBe(false);
                 async_to_generator(),
                 es2015::es2015(
                     mark,
+                    None,
                     es2015::Config {
                         ..Default::default()
                     },
@@ -12,8 +12,9 @@ use crate::util::{
     ModuleItemLike, StmtLike,
 };
 use fxhash::FxBuildHasher;
+use serde::Deserialize;
 use std::iter;
-use swc_common::{Mark, Spanned, DUMMY_SP};
+use swc_common::{comments::Comments, Mark, Span, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
 
@@ -24,8 +25,28 @@ pub(crate) mod native;
 mod prop_name;
 mod super_field;
 
-pub fn classes() -> impl Fold {
-    Classes::default()
+/// If `comments` is given, the function wrapping a class is annotated with
+/// `/*#__PURE__*/`, so unused classes can be removed by minifiers.
+pub fn classes<'a>(comments: Option<&'a dyn Comments>, c: Config) -> impl 'a + Fold {
+    Classes {
+        comments,
+        c,
+        in_strict: false,
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Assigns methods to the prototype instead of defining them with
+    /// `_createClass`, which makes them enumerable.
+    #[serde(default)]
+    pub set_class_methods: bool,
+
+    /// Omits `_classCallCheck`, so calling a class without `new` doesn't
+    /// throw.
+    #[serde(default)]
+    pub no_class_calls: bool,
 }
 
 type IndexMap<K, V> = indexmap::IndexMap<K, V, FxBuildHasher>;
@@ -47,7 +68,7 @@ type IndexMap<K, V> = indexmap::IndexMap<K, V, FxBuildHasher>;
 ///
 /// # Out
 /// ```js
-/// var Test = function () {
+/// var Test = /*#__PURE__*/ function () {
 ///   function Test(name) {
 ///     _classCallCheck(this, Test);
 ///
@@ -61,8 +82,10 @@ type IndexMap<K, V> = indexmap::IndexMap<K, V, FxBuildHasher>;
 ///   return Test;
 /// }();
 /// ```
-#[derive(Default, Clone, Copy)]
-struct Classes {
+#[derive(Clone, Copy)]
+struct Classes<'a> {
+    comments: Option<&'a dyn Comments>,
+    c: Config,
     in_strict: bool,
 }
 
@@ -73,7 +96,7 @@ struct Data {
     get: Option<Box<Expr>>,
 }
 
-impl Classes {
+impl Classes<'_> {
     fn fold_stmt_like<T>(&mut self, stmts: Vec<T>) -> Vec<T>
     where
         T: StmtLike + ModuleItemLike + FoldWith<Self>,
@@ -91,7 +114,7 @@ impl Classes {
                                 decl: DefaultDecl::Class(ClassExpr { ident, class }),
                                 ..
                             }) => {
-                                let ident = ident.unwrap_or_else(|| quote_ident!("_default"));
+                                let ident = ident.unwrap_or_else(|| private_ident!("_default"));
 
                                 let decl = self.fold_class_as_var_decl(ident.clone(), class);
                                 let decl = decl.fold_children_with(self);
@@ -168,7 +191,7 @@ impl Classes {
     }
 }
 
-impl Fold for Classes {
+impl Fold for Classes<'_> {
     noop_fold_type!();
 
     fn fold_module_items(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
@@ -219,7 +242,7 @@ impl Fold for Classes {
     }
 }
 
-impl Classes {
+impl Classes<'_> {
     fn fold_class_as_var_decl(&mut self, ident: Ident, class: Class) -> VarDecl {
         let span = class.span;
         let rhs = self.fold_class(Some(ident.clone()), class);
@@ -342,8 +365,17 @@ impl Classes {
             stmts,
         };
 
+        let span = match self.comments {
+            Some(comments) => {
+                let span = Span::dummy_with_cmt();
+                comments.add_pure_comment(span.lo);
+                span
+            }
+            None => DUMMY_SP,
+        };
+
         Expr::Call(CallExpr {
-            span: DUMMY_SP,
+            span,
             callee: Expr::Fn(FnExpr {
                 ident: None,
                 function: Function {
@@ -395,6 +427,9 @@ impl Classes {
                 ClassMember::PrivateProp(..) => unreachable!(
                     "classes pass: private property\nclass_properties pass should remove this"
                 ),
+                ClassMember::StaticBlock(..) => unreachable!(
+                    "classes pass: static block\nclass_properties pass should remove this"
+                ),
                 ClassMember::TsIndexSignature(..) => {
                     // We just strip this.
                 }
@@ -549,7 +584,9 @@ impl Classes {
             );
 
             // inject _classCallCheck(this, Bar);
-            inject_class_call_check(&mut body, class_name.clone());
+            if !self.c.no_class_calls {
+                inject_class_call_check(&mut body, class_name.clone());
+            }
 
             stmts.push(Stmt::Decl(Decl::Fn(FnDecl {
                 ident: class_name.clone(),
@@ -575,6 +612,7 @@ impl Classes {
                     span: DUMMY_SP,
                     value: "use strict".into(),
                     has_escape: false,
+                    raw: None,
                 })
                 .into_stmt(),
             );
@@ -679,7 +717,7 @@ impl Classes {
                         Box::new(Expr::Lit(Lit::Str(quote_str!(i.span, i.sym.clone()))))
                     }
                     PropName::Str(ref s) => Box::new(Expr::Lit(Lit::Str(s.clone()))),
-                    PropName::Num(n) => Box::new(Expr::Lit(Lit::Num(n))),
+                    PropName::Num(ref n) => Box::new(Expr::Lit(Lit::Num(n.clone()))),
                     PropName::Computed(ref c) => c.expr.clone(),
                 },
             })
@@ -743,6 +781,7 @@ impl Classes {
             .into_stmt()
         }
 
+        let mut stmts = vec![];
         let (mut props, mut static_props) = (IndexMap::default(), IndexMap::default());
 
         for m in methods {
@@ -754,6 +793,21 @@ impl Classes {
             };
             let prop_name = prop_name_to_expr(m.key);
 
+            // Foo.prototype.bar = function bar() {}
+            let assign_to = if self.c.set_class_methods && m.kind == MethodKind::Method {
+                let obj = if m.is_static {
+                    Expr::Ident(class_name.clone())
+                } else {
+                    class_name.clone().make_member(quote_ident!("prototype"))
+                };
+                Some(match prop_name {
+                    Expr::Ident(ref ident) if !computed => obj.make_member(ident.clone()),
+                    _ => obj.computed_member(prop_name.clone()),
+                })
+            } else {
+                None
+            };
+
             let append_to: &mut IndexMap<_, _> = if m.is_static {
                 &mut static_props
             } else {
@@ -817,6 +871,19 @@ impl Classes {
                 function,
             }));
 
+            if let Some(left) = assign_to {
+                stmts.push(
+                    AssignExpr {
+                        span: DUMMY_SP,
+                        left: PatOrExpr::Expr(Box::new(left)),
+                        op: op!("="),
+                        right: value,
+                    }
+                    .into_stmt(),
+                );
+                continue;
+            }
+
             let data = append_to.entry(key).or_insert_with(|| Data {
                 key_prop,
                 get: None,
@@ -831,9 +898,9 @@ impl Classes {
         }
 
         if props.is_empty() && static_props.is_empty() {
-            return vec![];
+            return stmts;
         }
-        vec![mk_create_class_call(
+        stmts.push(mk_create_class_call(
             class_name,
             mk_arg_obj_for_create_class(props),
             if static_props.is_empty() {
@@ -841,7 +908,8 @@ impl Classes {
             } else {
                 Some(mk_arg_obj_for_create_class(static_props))
             },
-        )]
+        ));
+        stmts
     }
 }
 
@@ -122,6 +122,7 @@ impl<'a> Fold for SuperCalleeFolder<'a> {
                         Box::new(Expr::Lit(Lit::Num(Number {
                             span: DUMMY_SP,
                             value: 1.0,
+                            raw: None,
                         }))),
                     )
                 }
@@ -229,6 +230,7 @@ impl<'a> SuperCalleeFolder<'a> {
                 span,
                 value: value.clone(),
                 has_escape: false,
+                raw: None,
             })),
             ref expr => expr.clone(),
         }
@@ -308,6 +310,7 @@ impl<'a> SuperCalleeFolder<'a> {
                 span,
                 value: value.clone(),
                 has_escape: false,
+                raw: None,
             })),
             ref e => e.clone(),
         };
@@ -1,4 +1,5 @@
 use crate::util::{ExprFactory, StmtLike};
+use serde::Deserialize;
 use swc_common::{Mark, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
@@ -34,14 +35,25 @@ use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
 ///
 /// TODO(kdy1): cache reference like (_f = f, mutatorMap[_f].get = function(){})
 ///     instead of (mutatorMap[f].get = function(){}
-pub fn computed_properties() -> impl Fold {
-    ComputedProps
+pub fn computed_properties(c: Config) -> impl Fold {
+    ComputedProps { c }
 }
 
-struct ComputedProps;
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+pub struct Config {
+    /// Assigns properties (`_obj[key] = value`) instead of defining them with
+    /// `_defineProperty`, so setters on `Object.prototype` are invoked.
+    #[serde(default)]
+    pub loose: bool,
+}
+
+struct ComputedProps {
+    c: Config,
+}
 
 #[derive(Default)]
 struct ObjectLitFolder {
+    c: Config,
     vars: Vec<VarDeclarator>,
     used_define_enum_props: bool,
 }
@@ -74,7 +86,8 @@ impl Fold for ObjectLitFolder {
 
                 let props_cnt = props.len();
 
-                exprs.push(if props_cnt == 1 {
+                // In loose mode, `_obj` is the target of assignments.
+                exprs.push(if props_cnt == 1 && !self.c.loose {
                     Box::new(Expr::Object(ObjectLit {
                         span: DUMMY_SP,
                         props: obj_props,
@@ -101,6 +114,7 @@ impl Fold for ObjectLitFolder {
                                     span: ident.span,
                                     value: ident.sym.clone(),
                                     has_escape: false,
+                                    raw: None,
                                 })),
                                 Expr::Ident(ident),
                             ),
@@ -212,6 +226,15 @@ impl Fold for ObjectLitFolder {
                         PropOrSpread::Spread(..) => unimplemented!("computed spread property"),
                     };
 
+                    if self.c.loose {
+                        exprs.push(Box::new(Expr::Assign(AssignExpr {
+                            span,
+                            left: PatOrExpr::Expr(Box::new(obj_ident.clone().computed_member(key))),
+                            op: op!("="),
+                            right: Box::new(value),
+                        })));
+                        continue;
+                    }
                     if props_cnt == 1 {
                         return Expr::Call(CallExpr {
                             span,
@@ -318,7 +341,10 @@ impl ComputedProps {
                 continue;
             }
 
-            let mut folder = ObjectLitFolder::default();
+            let mut folder = ObjectLitFolder {
+                c: self.c,
+                ..Default::default()
+            };
             let stmt = stmt.fold_with(&mut folder);
 
             // Add variable declaration
@@ -345,6 +371,7 @@ fn prop_name_to_expr(p: PropName) -> Expr {
             value: i.sym,
             span: i.span,
             has_escape: false,
+            raw: None,
         })),
         PropName::Str(s) => Expr::Lit(Lit::Str(s)),
         PropName::Num(n) => Expr::Lit(Lit::Num(n)),
@@ -235,6 +235,7 @@ impl AssignFolder {
                                 args: vec![Lit::Num(Number {
                                     value: i as f64,
                                     span: dot3_token,
+                                    raw: None,
                                 })
                                 .as_arg()],
                                 type_args: Default::default(),
@@ -300,6 +301,7 @@ impl AssignFolder {
                                         span: DUMMY_SP,
                                         value: "Cannot destructure undefined".into(),
                                         has_escape: false,
+                                        raw: None,
                                     })
                                     .as_arg()]),
                                     type_args: Default::default(),
@@ -981,6 +983,7 @@ fn make_ref_ident_for_array(
                                     Lit::Num(Number {
                                         span: DUMMY_SP,
                                         value: value as _,
+                                        raw: None,
                                     })
                                     .as_arg(),
                                 ],
@@ -1026,6 +1029,7 @@ fn make_cond_expr(tmp: Ident, def_value: Box<Expr>) -> Expr {
                 arg: Box::new(Expr::Lit(Lit::Num(Number {
                     span: DUMMY_SP,
                     value: 0.0,
+                    raw: None,
                 }))),
             })),
         })),
@@ -1040,6 +1044,10 @@ fn can_be_null(e: &Expr) -> bool {
         | Expr::This(..)
         | Expr::Ident(..)
         | Expr::PrivateName(..)
+        | Expr::ArgPlaceholder(..)
+        | Expr::Do(..)
+        | Expr::Bind(..)
+        | Expr::Throw(..)
         | Expr::Member(..)
         | Expr::Call(..)
         | Expr::New(..)
@@ -1053,6 +1061,8 @@ fn can_be_null(e: &Expr) -> bool {
         Expr::Array(..)
         | Expr::Arrow(..)
         | Expr::Object(..)
+        | Expr::Record(..)
+        | Expr::Tuple(..)
         | Expr::Fn(..)
         | Expr::Class(..)
         | Expr::Tpl(..) => false,
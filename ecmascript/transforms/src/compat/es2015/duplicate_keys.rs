@@ -105,6 +105,7 @@ impl<'a> Fold for PropNameFolder<'a> {
                             span,
                             value: ident.sym,
                             has_escape: false,
+                            raw: None,
                         }))),
                     })
                 } else {
@@ -46,6 +46,7 @@ pub fn for_of(c: Config) -> impl Fold {
 #[derive(Debug, Clone, Copy, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Config {
+    #[serde(default)]
     pub assume_array: bool,
 }
 
@@ -110,6 +111,7 @@ impl Actual {
                 init: Some(Box::new(Expr::Lit(Lit::Num(Number {
                     span: DUMMY_SP,
                     value: 0f64,
+                    raw: None,
                 })))),
                 definite: false,
             });
@@ -1,7 +1,9 @@
-use crate::util::ExprFactory;
-use swc_common::DUMMY_SP;
+use crate::{
+    pass::{fused, LeafTransform},
+    util::ExprFactory,
+};
 use swc_ecma_ast::*;
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
+use swc_ecma_visit::Fold;
 
 /// `@babel/plugin-transform-instanceof`
 ///
@@ -28,39 +30,13 @@ use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
 ///
 /// _instanceof(foo, Bar);
 /// ```
-pub fn instance_of() -> impl Fold {
-    InstanceOf
+pub fn instance_of() -> impl Fold + LeafTransform {
+    fused(InstanceOf)
 }
 struct InstanceOf;
 
-impl Fold for InstanceOf {
-    noop_fold_type!();
-
-    fn fold_expr(&mut self, expr: Expr) -> Expr {
-        fn should_work(node: &Expr) -> bool {
-            struct Visitor {
-                found: bool,
-            }
-            impl Visit for Visitor {
-                noop_visit_type!();
-
-                fn visit_bin_expr(&mut self, e: &BinExpr, _: &dyn Node) {
-                    if e.op == op!("instanceof") {
-                        self.found = true
-                    }
-                }
-            }
-            let mut v = Visitor { found: false };
-            node.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
-            v.found
-        }
-        // fast path
-        if !should_work(&expr) {
-            return expr;
-        }
-
-        let expr = expr.fold_children_with(self);
-
+impl LeafTransform for InstanceOf {
+    fn transform_expr(&mut self, expr: Expr) -> Expr {
         match expr {
             Expr::Bin(BinExpr {
                 span,
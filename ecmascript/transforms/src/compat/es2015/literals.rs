@@ -0,0 +1,149 @@
+use crate::pass::{fused, LeafTransform};
+use swc_ecma_ast::*;
+use swc_ecma_visit::Fold;
+
+/// Compile ES2015 binary and octal number literals and unicode code point
+/// escapes in strings to ES5.
+///
+/// The raw text of such literals is dropped, so the code generator prints
+/// numbers as decimals and escapes strings itself.
+///
+///# Example
+///## In
+///
+/// ```js
+/// var b = 0b11;
+/// var o = 0o7;
+/// ```
+///
+///## Out
+///
+/// ```js
+/// var b = 3;
+/// var o = 7;
+/// ```
+pub fn literals() -> impl 'static + Fold + LeafTransform {
+    fused(Literals)
+}
+
+#[derive(Clone, Copy)]
+struct Literals;
+
+impl Literals {
+    fn num(&self, n: Number) -> Number {
+        match n.raw {
+            Some(ref raw)
+                if raw.starts_with("0b")
+                    || raw.starts_with("0B")
+                    || raw.starts_with("0o")
+                    || raw.starts_with("0O") =>
+            {
+                Number { raw: None, ..n }
+            }
+            _ => n,
+        }
+    }
+
+    fn str(&self, s: Str) -> Str {
+        match s.raw {
+            Some(ref raw) if raw.contains("\\u{") => Str { raw: None, ..s },
+            _ => s,
+        }
+    }
+}
+
+impl LeafTransform for Literals {
+    fn transform_expr(&mut self, e: Expr) -> Expr {
+        match e {
+            Expr::Lit(Lit::Num(n)) => Expr::Lit(Lit::Num(self.num(n))),
+            Expr::Lit(Lit::Str(s)) => Expr::Lit(Lit::Str(self.str(s))),
+            _ => e,
+        }
+    }
+
+    fn transform_prop_name(&mut self, n: PropName) -> PropName {
+        match n {
+            PropName::Num(n) => PropName::Num(self.num(n)),
+            PropName::Str(s) => PropName::Str(self.str(s)),
+            _ => n,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_common::DUMMY_SP;
+
+    fn str_lit(raw: &str) -> Str {
+        Str {
+            span: DUMMY_SP,
+            value: "".into(),
+            has_escape: true,
+            raw: Some(raw.into()),
+        }
+    }
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| literals(),
+        babel_binary_integer,
+        "var i = 0b11;
+var j = 0B0;
+var k = 0b1101;",
+        "var i = 3;
+var j = 0;
+var k = 13;"
+    );
+
+    #[test]
+    fn code_point_escape() {
+        let e = Literals.transform_expr(Expr::Lit(Lit::Str(str_lit("'\\u{1F600}'"))));
+        match e {
+            Expr::Lit(Lit::Str(s)) => assert_eq!(s.raw, None),
+            _ => unreachable!(),
+        }
+
+        let n = Literals.transform_prop_name(PropName::Str(str_lit("'\\u{61}'")));
+        match n {
+            PropName::Str(s) => assert_eq!(s.raw, None),
+            _ => unreachable!(),
+        }
+
+        let e = Literals.transform_expr(Expr::Lit(Lit::Str(str_lit("'\\u0061'"))));
+        match e {
+            Expr::Lit(Lit::Str(s)) => assert_eq!(s.raw, Some("'\\u0061'".into())),
+            _ => unreachable!(),
+        }
+    }
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| literals(),
+        babel_octal_integer,
+        "var i = 0o7;
+var j = 0O17;",
+        "var i = 7;
+var j = 15;"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| literals(),
+        prop_name,
+        "var o = { 0b11: 1, 0x10: 2 };",
+        "var o = { 3: 1, 0x10: 2 };"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| literals(),
+        keep_es5_literals,
+        "var i = 0x10;
+var j = 1e3;
+var k = .5;",
+        "var i = 0x10;
+var j = 1e3;
+var k = .5;"
+    );
+}
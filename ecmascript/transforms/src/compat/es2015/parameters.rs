@@ -98,6 +98,7 @@ impl Params {
                                 right: Box::new(Expr::Lit(Lit::Num(Number {
                                     span,
                                     value: i as f64,
+                                    raw: None,
                                 }))),
                             })
                             .into();
@@ -115,12 +116,17 @@ impl Params {
                                         right: Box::new(Expr::Lit(Lit::Num(Number {
                                             span,
                                             value: i as _,
+                                            raw: None,
                                         }))),
                                     }
                                     .into(),
                                 ),
                                 cons: Box::new(bin),
-                                alt: Box::new(Expr::Lit(Lit::Num(Number { span, value: 0.0 }))),
+                                alt: Box::new(Expr::Lit(Lit::Num(Number {
+                                    span,
+                                    value: 0.0,
+                                    raw: None,
+                                }))),
                             })
                         }
                     };
@@ -160,6 +166,7 @@ impl Params {
                                     init: Some(Box::new(Expr::Lit(Lit::Num(Number {
                                         span,
                                         value: i as f64,
+                                        raw: None,
                                     })))),
                                     definite: false,
                                 },
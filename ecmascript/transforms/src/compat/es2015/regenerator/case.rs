@@ -24,6 +24,7 @@ impl Loc {
         Expr::Lit(Lit::Num(Number {
             span: DUMMY_SP,
             value: self.stmt_index as _,
+            raw: None,
         }))
     }
 
@@ -300,6 +301,11 @@ impl CaseHandler<'_> {
             | Expr::TsTypeCast(..)
             | Expr::TsAs(..)
             | Expr::PrivateName(..)
+            | Expr::ArgPlaceholder(..)
+            | Expr::Do(..)
+            | Expr::Bind(..)
+            | Expr::Record(..)
+            | Expr::Tuple(..)
             | Expr::Invalid(..) => return e,
 
             Expr::OptChain(e) => {
@@ -318,6 +324,11 @@ impl CaseHandler<'_> {
                 })
             }
 
+            Expr::Throw(ThrowExpr { span, arg }) => Expr::Throw(ThrowExpr {
+                span,
+                arg: arg.map(|e| self.explode_expr(e, false)),
+            }),
+
             Expr::Member(me) => {
                 let obj = match me.obj {
                     ExprOrSuper::Expr(obj) => {
@@ -413,6 +424,7 @@ impl CaseHandler<'_> {
                                             Lit::Num(Number {
                                                 span: DUMMY_SP,
                                                 value: 0.0,
+                                                raw: None,
                                             })
                                             .into(),
                                         ),
@@ -742,6 +754,7 @@ impl CaseHandler<'_> {
                 test: Some(Box::new(Expr::Lit(Lit::Num(Number {
                     span: DUMMY_SP,
                     value: i as _,
+                    raw: None,
                 })))),
                 cons: vec![],
             };
@@ -811,6 +824,7 @@ impl CaseHandler<'_> {
                             span: DUMMY_SP,
                             value: ty.into(),
                             has_escape: false,
+                            raw: None,
                         })
                         .as_arg();
 
@@ -1454,6 +1468,7 @@ impl Fold for UnmarkedInvalidHandler {
             Expr::Invalid(Invalid { span }) => Expr::Lit(Lit::Num(Number {
                 span,
                 value: self.case_id as _,
+                raw: None,
             })),
             _ => e,
         }
@@ -1481,6 +1496,7 @@ impl Fold for InvalidToLit<'_> {
                         return Expr::Lit(Lit::Num(Number {
                             span: DUMMY_SP,
                             value: (*stmt_index) as _,
+                            raw: None,
                         }));
                     }
                 }
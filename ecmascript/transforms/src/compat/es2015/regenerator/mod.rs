@@ -4,7 +4,10 @@ use std::mem::replace;
 use swc_atoms::js_word;
 use swc_common::{Mark, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
+use swc_ecma_visit::{
+    noop_fold_type, noop_visit_mut_type, Fold, FoldWith, Node, Visit, VisitMut, VisitMutWith,
+    VisitWith,
+};
 
 mod case;
 mod hoist;
@@ -300,7 +303,8 @@ impl Regenerator {
         let ctx = private_ident!("_ctx");
         let mut handler = CaseHandler::new(&ctx);
 
-        f.body = f.body.fold_with(&mut FnSentVisitor { ctx: ctx.clone() });
+        f.body
+            .visit_mut_with(&mut FnSentVisitor { ctx: ctx.clone() });
         let uses_this = contains_this_expr(&f.body);
         let (body, hoister) = hoist(f.body.unwrap());
         self.outer_fn_vars
@@ -340,6 +344,7 @@ impl Regenerator {
             test: Some(Box::new(Expr::Lit(Lit::Num(Number {
                 span: DUMMY_SP,
                 value: handler.final_loc() as _,
+                raw: None,
             })))),
             // fallthrough
             cons: vec![],
@@ -350,6 +355,7 @@ impl Regenerator {
                 span: DUMMY_SP,
                 value: "end".into(),
                 has_escape: false,
+                raw: None,
             })))),
             cons: vec![ReturnStmt {
                 span: DUMMY_SP,
@@ -369,6 +375,7 @@ impl Regenerator {
             test: Box::new(Expr::Lit(Lit::Num(Number {
                 span: DUMMY_SP,
                 value: 1.0,
+                raw: None,
             }))),
             body: Box::new(
                 SwitchStmt {
@@ -484,23 +491,21 @@ struct FnSentVisitor {
     ctx: Ident,
 }
 
-impl Fold for FnSentVisitor {
-    noop_fold_type!();
+impl VisitMut for FnSentVisitor {
+    noop_visit_mut_type!();
 
-    fn fold_expr(&mut self, e: Expr) -> Expr {
-        let e: Expr = e.fold_children_with(self);
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
 
         match e {
             Expr::MetaProp(MetaPropExpr { meta, prop })
                 if meta.sym == *"function" && prop.sym == *"sent" =>
             {
-                return self.ctx.clone().make_member(quote_ident!("_sent"));
+                *e = self.ctx.clone().make_member(quote_ident!("_sent"));
             }
 
             _ => {}
         }
-
-        e
     }
 }
 
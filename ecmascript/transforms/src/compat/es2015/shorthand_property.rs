@@ -1,5 +1,6 @@
+use crate::pass::{fused, LeafTransform};
 use swc_ecma_ast::*;
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+use swc_ecma_visit::Fold;
 
 /// Compile ES2015 shorthand properties to ES5
 ///
@@ -35,19 +36,15 @@ use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
 ///   }
 /// };
 /// ```
-pub fn shorthand() -> impl 'static + Fold {
-    Shorthand
+pub fn shorthand() -> impl 'static + Fold + LeafTransform {
+    fused(Shorthand)
 }
 
 #[derive(Clone, Copy)]
 struct Shorthand;
 
-impl Fold for Shorthand {
-    noop_fold_type!();
-
-    fn fold_prop(&mut self, prop: Prop) -> Prop {
-        let prop = prop.fold_children_with(self);
-
+impl LeafTransform for Shorthand {
+    fn transform_prop(&mut self, prop: Prop) -> Prop {
         match prop {
             Prop::Shorthand(Ident { sym, span, .. }) => Prop::KeyValue(KeyValueProp {
                 key: PropName::Ident(quote_ident!(span, sym.clone())),
@@ -71,7 +68,7 @@ mod tests {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| Shorthand,
+        |_| shorthand(),
         babel_method_plain,
         "var obj = {
   method() {
@@ -87,7 +84,7 @@ mod tests {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| Shorthand,
+        |_| shorthand(),
         babel_comments,
         "var A = 'a';
 var o = {
@@ -102,7 +99,7 @@ var o = {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| Shorthand,
+        |_| shorthand(),
         babel_mixed,
         "var coords = { x, y, foo: 'bar' };",
         "var coords = {
@@ -114,7 +111,7 @@ var o = {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| Shorthand,
+        |_| shorthand(),
         babel_multiple,
         "var coords = { x, y };",
         "var coords = {
@@ -125,7 +122,7 @@ var o = {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| Shorthand,
+        |_| shorthand(),
         babel_single,
         "var coords = { x };",
         "var coords = {
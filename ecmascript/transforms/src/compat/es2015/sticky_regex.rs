@@ -1,8 +1,11 @@
-use crate::util::ExprFactory;
+use crate::{
+    pass::{fused, LeafTransform},
+    util::ExprFactory,
+};
 use swc_atoms::JsWord;
 use swc_common::DUMMY_SP;
 use swc_ecma_ast::*;
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+use swc_ecma_visit::Fold;
 
 /// Compile ES2015 sticky regex to an ES5 RegExp constructor
 ///
@@ -18,19 +21,15 @@ use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
 /// ```js
 /// new RegExp("o+", "y")
 /// ```
-pub fn sticky_regex() -> impl 'static + Fold {
-    StickyRegex
+pub fn sticky_regex() -> impl 'static + Fold + LeafTransform {
+    fused(StickyRegex)
 }
 
 #[derive(Clone, Copy)]
 struct StickyRegex;
 
-impl Fold for StickyRegex {
-    noop_fold_type!();
-
-    fn fold_expr(&mut self, e: Expr) -> Expr {
-        let e = e.fold_children_with(self);
-
+impl LeafTransform for StickyRegex {
+    fn transform_expr(&mut self, e: Expr) -> Expr {
         match e {
             Expr::Lit(Lit::Regex(Regex { exp, flags, span })) => {
                 if flags.contains('y') {
@@ -39,6 +38,7 @@ impl Fold for StickyRegex {
                             span: DUMMY_SP,
                             value: s,
                             has_escape: false,
+                            raw: None,
                         })))
                     };
 
@@ -63,7 +63,7 @@ mod tests {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| StickyRegex,
+        |_| sticky_regex(),
         babel_basic,
         "var re = /o+/y;",
         "var re = new RegExp('o+', 'y');"
@@ -71,7 +71,7 @@ mod tests {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| StickyRegex,
+        |_| sticky_regex(),
         babel_ignore_non_sticky,
         "var re = /o+/;",
         "var re = /o+/;"
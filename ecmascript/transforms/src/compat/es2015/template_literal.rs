@@ -97,18 +97,21 @@ impl Fold for TemplateLiteral {
                                 span,
                                 value,
                                 has_escape,
+                                ..
                             })) = *obj
                             {
                                 if let Expr::Lit(Lit::Str(Str {
                                     span: r_span,
                                     value: r_value,
                                     has_escape: r_has_escape,
+                                    ..
                                 })) = *expr
                                 {
                                     obj = Box::new(Expr::Lit(Lit::Str(Str {
                                         span: span.with_hi(r_span.hi()),
                                         value: format!("{}{}", value, r_value).into(),
                                         has_escape: has_escape || r_has_escape,
+                                        raw: None,
                                     })));
 
                                     continue;
@@ -117,6 +120,7 @@ impl Fold for TemplateLiteral {
                                         span,
                                         value,
                                         has_escape,
+                                        raw: None,
                                     })))
                                 }
                             }
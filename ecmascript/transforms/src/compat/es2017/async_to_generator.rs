@@ -220,16 +220,11 @@ impl Fold for Actual {
                         .collect(),
                 });
 
-                Expr::Call(CallExpr {
-                    span: DUMMY_SP,
-                    callee: Expr::Fn(FnExpr {
-                        ident: None,
-                        function: Function { body, ..function },
-                    })
-                    .as_callee(),
-                    args: vec![],
-                    type_args: Default::default(),
+                Expr::Fn(FnExpr {
+                    ident: None,
+                    function: Function { body, ..function },
                 })
+                .into_iife()
             }
 
             _ => expr,
@@ -4,9 +4,12 @@ pub use self::{
 use swc_common::chain;
 use swc_ecma_visit::Fold;
 
-mod object_rest_spread;
+pub mod object_rest_spread;
 mod optional_catch_binding;
 
 pub fn es2018() -> impl Fold {
-    chain!(object_rest_spread(), optional_catch_binding())
+    chain!(
+        object_rest_spread(Default::default()),
+        optional_catch_binding()
+    )
 }
@@ -4,6 +4,7 @@ use crate::{
         alias_ident_for, alias_if_required, is_literal, var::VarCollector, ExprFactory, StmtLike,
     },
 };
+use serde::Deserialize;
 use std::{iter, mem};
 use swc_common::{chain, util::move_map::MoveMap, Mark, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
@@ -11,14 +12,24 @@ use swc_ecma_transforms_macros::fast_path;
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
 
 /// `@babel/plugin-proposal-object-rest-spread`
-pub fn object_rest_spread() -> impl Fold {
-    chain!(ObjectRest, ObjectSpread)
+pub fn object_rest_spread(c: Config) -> impl Fold {
+    chain!(ObjectRest { c }, ObjectSpread)
 }
 
-struct ObjectRest;
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Use `Object.assign` instead of the `_extends` helper.
+    pub use_builtins: bool,
+}
+
+struct ObjectRest {
+    c: Config,
+}
 
 #[allow(clippy::vec_box)]
 struct RestFolder {
+    c: Config,
     /// Injected before the original statement.
     vars: Vec<VarDeclarator>,
     /// Variables which should ceclaraed using `var`
@@ -306,7 +317,7 @@ impl Fold for RestFolder {
                             name: *prop.arg,
                             init: Some(Box::new(Expr::Call(CallExpr {
                                 span: DUMMY_SP,
-                                callee: helper!(extends, "extends"),
+                                callee: extends(self.c),
                                 args: vec![
                                     ObjectLit {
                                         span: DUMMY_SP,
@@ -428,6 +439,7 @@ impl ObjectRest {
 
         for stmt in stmts {
             let mut folder = RestFolder {
+                c: self.c,
                 vars: vec![],
                 mutable_vars: vec![],
                 exprs: vec![],
@@ -817,12 +829,13 @@ impl RestFolder {
                             let value = value.clone();
                             (key, Box::new(Expr::Ident(quote_ident!(span, value))))
                         }
-                        PropName::Num(Number { span, value }) => (
+                        PropName::Num(Number { span, value, .. }) => (
                             key,
                             Box::new(Expr::Lit(Lit::Str(Str {
                                 span,
                                 value: format!("{}", value).into(),
                                 has_escape: false,
+                                raw: None,
                             }))),
                         ),
                         PropName::Computed(ref c) if is_literal(&c.expr) => {
@@ -898,14 +911,18 @@ impl RestFolder {
                 span: DUMMY_SP,
                 left: PatOrExpr::Pat(last.arg),
                 op: op!("="),
-                right: Box::new(object_without_properties(obj, excluded_props)),
+                right: Box::new(object_without_properties(self.c, obj, excluded_props)),
             })));
         } else {
             // println!("Var: rest = objectWithoutProperties()",);
             self.push_var_if_not_empty(VarDeclarator {
                 span: DUMMY_SP,
                 name: *last.arg,
-                init: Some(Box::new(object_without_properties(obj, excluded_props))),
+                init: Some(Box::new(object_without_properties(
+                    self.c,
+                    obj,
+                    excluded_props,
+                ))),
                 definite: false,
             });
         }
@@ -919,11 +936,15 @@ impl RestFolder {
     }
 }
 
-fn object_without_properties(obj: Box<Expr>, excluded_props: Vec<Option<ExprOrSpread>>) -> Expr {
+fn object_without_properties(
+    c: Config,
+    obj: Box<Expr>,
+    excluded_props: Vec<Option<ExprOrSpread>>,
+) -> Expr {
     if excluded_props.is_empty() {
         return Expr::Call(CallExpr {
             span: DUMMY_SP,
-            callee: helper!(extends, "extends"),
+            callee: extends(c),
             args: vec![
                 ObjectLit {
                     span: DUMMY_SP,
@@ -940,11 +961,12 @@ fn object_without_properties(obj: Box<Expr>, excluded_props: Vec<Option<ExprOrSp
         .into_iter()
         .map(|v| {
             v.map(|v| match *v.expr {
-                Expr::Lit(Lit::Num(Number { span, value })) => ExprOrSpread {
+                Expr::Lit(Lit::Num(Number { span, value, .. })) => ExprOrSpread {
                     expr: Box::new(Expr::Lit(Lit::Str(Str {
                         span,
                         value: value.to_string().into(),
                         has_escape: false,
+                        raw: None,
                     }))),
                     ..v
                 },
@@ -992,13 +1014,15 @@ fn excluded_props(props: &[ObjectPatProp]) -> Vec<Option<ExprOrSpread>> {
                     span: ident.span,
                     value: ident.sym.clone(),
                     has_escape: false,
+                    raw: None,
                 })
                 .as_arg(),
                 PropName::Str(s) => Lit::Str(s.clone()).as_arg(),
-                PropName::Num(Number { span, value }) => Lit::Str(Str {
+                PropName::Num(Number { span, value, .. }) => Lit::Str(Str {
                     span: *span,
                     value: format!("{}", value).into(),
                     has_escape: false,
+                    raw: None,
                 })
                 .as_arg(),
                 PropName::Computed(c) => c.expr.clone().as_arg(),
@@ -1007,6 +1031,7 @@ fn excluded_props(props: &[ObjectPatProp]) -> Vec<Option<ExprOrSpread>> {
                 span: key.span,
                 value: key.sym.clone(),
                 has_escape: false,
+                raw: None,
             })
             .as_arg(),
             ObjectPatProp::Rest(..) => unreachable!("invalid syntax (multiple rest element)"),
@@ -1060,6 +1085,15 @@ fn simplify_pat(pat: Pat) -> Pat {
     pat.fold_with(&mut PatSimplifier)
 }
 
+/// `Object.assign` or the `_extends` helper.
+fn extends(c: Config) -> ExprOrSuper {
+    if c.use_builtins {
+        member_expr!(DUMMY_SP, Object.assign).as_callee()
+    } else {
+        helper!(extends, "extends")
+    }
+}
+
 struct ObjectSpread;
 
 #[fast_path(SpreadVisitor)]
@@ -1,6 +1,6 @@
 use self::{
     class_name_tdz::ClassNameTdzFolder,
-    private_field::FieldAccessFolder,
+    private_field::{BrandCheckCollector, FieldAccessFolder},
     this_in_static::ThisInStaticFolder,
     used_name::{UsedNameCollector, UsedNameRenamer},
 };
@@ -312,12 +312,62 @@ impl ClassProperties {
         let mut used_names = vec![];
         let mut used_key_names = vec![];
         let mut statics = HashSet::default();
+        let mut brands = HashSet::default();
+        let mut brand_exprs = vec![];
+
+        // Only private methods used in `#m in obj` need a brand.
+        let mut brand_checks = HashSet::default();
+        class.body.visit_with(
+            &Invalid { span: DUMMY_SP } as _,
+            &mut BrandCheckCollector {
+                names: &mut brand_checks,
+            },
+        );
 
         for member in class.body {
             match member {
-                ClassMember::PrivateMethod(..)
-                | ClassMember::Empty(..)
-                | ClassMember::TsIndexSignature(..) => members.push(member),
+                ClassMember::Empty(..) | ClassMember::TsIndexSignature(..) => members.push(member),
+
+                ClassMember::PrivateMethod(ref method) => {
+                    // Private methods are not lowered, but `#m in obj` needs a brand.
+                    let name = &method.key.id;
+                    if method.is_static {
+                        statics.insert(name.sym.clone());
+                    } else if brand_checks.contains(&name.sym) && brands.insert(name.sym.clone()) {
+                        let brand = Ident::new(
+                            format!("_{}", name.sym).into(),
+                            name.span.apply_mark(self.mark),
+                        );
+
+                        // _m.add(this)
+                        brand_exprs.push(Box::new(Expr::Call(CallExpr {
+                            span: DUMMY_SP,
+                            callee: brand.clone().make_member(quote_ident!("add")).as_callee(),
+                            args: vec![ThisExpr { span: DUMMY_SP }.as_arg()],
+                            type_args: Default::default(),
+                        })));
+
+                        // var _m = new WeakSet();
+                        extra_stmts.push(Stmt::Decl(Decl::Var(VarDecl {
+                            span: DUMMY_SP,
+                            kind: VarDeclKind::Var,
+                            declare: false,
+                            decls: vec![VarDeclarator {
+                                span: DUMMY_SP,
+                                definite: false,
+                                name: Pat::Ident(brand),
+                                init: Some(Box::new(Expr::New(NewExpr {
+                                    span: DUMMY_SP,
+                                    callee: Box::new(Expr::Ident(quote_ident!("WeakSet"))),
+                                    args: Some(vec![]),
+                                    type_args: Default::default(),
+                                }))),
+                            }],
+                        })));
+                    }
+
+                    members.push(member)
+                }
 
                 ClassMember::Method(method) => {
                     // we handle computed key here to preserve the execution order
@@ -381,6 +431,7 @@ impl ClassProperties {
                                     span: i.span,
                                     value: i.sym.clone(),
                                     has_escape: false,
+                                    raw: None,
                                 })))
                             }
                             Expr::Lit(ref lit) if !prop.computed => {
@@ -590,6 +641,48 @@ impl ClassProperties {
                     })));
                 }
 
+                ClassMember::StaticBlock(block) => {
+                    let body = block
+                        .body
+                        .fold_with(&mut SuperFieldAccessFolder {
+                            class_name: &ident,
+                            vars: &mut vars,
+                            constructor_this_mark: None,
+                            is_static: true,
+                            folding_constructor: false,
+                            in_injected_define_property_call: false,
+                            in_nested_scope: false,
+                            this_alias_mark: None,
+                        })
+                        .fold_with(&mut ThisInStaticFolder {
+                            ident: ident.clone(),
+                        });
+
+                    // (function () { ... })();
+                    extra_stmts.push(
+                        CallExpr {
+                            span: block.span,
+                            callee: FnExpr {
+                                ident: None,
+                                function: Function {
+                                    params: vec![],
+                                    decorators: vec![],
+                                    span: DUMMY_SP,
+                                    body: Some(body),
+                                    is_generator: false,
+                                    is_async: false,
+                                    type_params: Default::default(),
+                                    return_type: Default::default(),
+                                },
+                            }
+                            .as_callee(),
+                            args: vec![],
+                            type_args: Default::default(),
+                        }
+                        .into_stmt(),
+                    );
+                }
+
                 ClassMember::Constructor(mut c) => {
                     if self.typescript {
                         let store = |i: &Ident| {
@@ -652,6 +745,8 @@ impl ClassProperties {
         }
 
         let constructor_exprs = {
+            // Private methods are installed before fields are initialized.
+            typescript_constructor_properties.extend(brand_exprs);
             typescript_constructor_properties.extend(constructor_exprs);
             typescript_constructor_properties
         };
@@ -808,6 +903,10 @@ impl Visit for ShouldWork {
     fn visit_constructor(&mut self, _: &Constructor, _: &dyn Node) {
         self.found = true;
     }
+
+    fn visit_static_block(&mut self, _: &StaticBlock, _: &dyn Node) {
+        self.found = true;
+    }
 }
 
 impl Check for ShouldWork {
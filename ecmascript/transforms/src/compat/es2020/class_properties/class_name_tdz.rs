@@ -26,6 +26,7 @@ impl<'a> Fold for ClassNameTdzFolder<'a> {
                                     span: i.span,
                                     value: i.sym.clone(),
                                     has_escape: false,
+                                    raw: None,
                                 })
                                 .as_arg()],
 
@@ -6,7 +6,26 @@ use std::{collections::HashSet, iter, mem};
 use swc_atoms::JsWord;
 use swc_common::{Mark, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
+
+/// Collects names of private methods used in brand checks (`#x in obj`).
+pub(super) struct BrandCheckCollector<'a> {
+    pub names: &'a mut HashSet<JsWord>,
+}
+
+impl<'a> Visit for BrandCheckCollector<'a> {
+    noop_visit_type!();
+
+    fn visit_bin_expr(&mut self, e: &BinExpr, _: &dyn Node) {
+        e.visit_children_with(self);
+
+        if e.op == op!("in") {
+            if let Expr::PrivateName(ref n) = *e.left {
+                self.names.insert(n.id.sym.clone());
+            }
+        }
+    }
+}
 
 pub(super) struct FieldAccessFolder<'a> {
     pub mark: Mark,
@@ -155,6 +174,7 @@ impl<'a> Fold for FieldAccessFolder<'a> {
                         right: Box::new(Expr::Lit(Lit::Num(Number {
                             span: DUMMY_SP,
                             value: 1.0,
+                            raw: None,
                         }))),
                     }
                     .as_arg()
@@ -372,6 +392,40 @@ impl<'a> Fold for FieldAccessFolder<'a> {
                 }
             }
             Expr::Member(e) => self.fold_private_get(e, None).0,
+
+            // `#x in obj`
+            Expr::Bin(BinExpr {
+                span,
+                op: op!("in"),
+                left,
+                right,
+            }) if left.is_private_name() => {
+                let n = left.private_name().unwrap();
+                let right = right.fold_with(self);
+
+                if self.statics.contains(&n.id.sym) {
+                    // obj === Foo
+                    Expr::Bin(BinExpr {
+                        span,
+                        op: op!("==="),
+                        left: right,
+                        right: Box::new(Expr::Ident(self.class_name.clone())),
+                    })
+                } else {
+                    // _x.has(obj)
+                    let ident = Ident::new(
+                        format!("_{}", n.id.sym).into(),
+                        n.id.span.apply_mark(self.mark),
+                    );
+
+                    Expr::Call(CallExpr {
+                        span,
+                        callee: ident.make_member(quote_ident!("has")).as_callee(),
+                        args: vec![right.as_arg()],
+                        type_args: Default::default(),
+                    })
+                }
+            }
             _ => e.fold_children_with(self),
         }
     }
@@ -8,7 +8,7 @@ use swc_ecma_ast::*;
 use swc_ecma_transforms_macros::fast_path;
 use swc_ecma_visit::{noop_fold_type, noop_visit_type, Fold, FoldWith, Node, Visit, VisitWith};
 
-#[cfg(test)]
+#[cfg(all(test, feature = "typescript"))]
 mod tests;
 
 pub fn nullish_coalescing() -> impl Fold + 'static {
@@ -2,6 +2,7 @@ pub use self::{
     member_expr_lits::member_expression_literals, prop_lits::property_literals,
     reserved_word::reserved_words,
 };
+use crate::pass::fused;
 use swc_common::chain;
 use swc_ecma_visit::Fold;
 
@@ -12,8 +13,7 @@ mod reserved_word;
 /// Make output es3-compatible.
 pub fn es3(preserve_import: bool) -> impl Fold {
     chain!(
-        property_literals(),
-        member_expression_literals(),
+        fused(chain!(property_literals(), member_expression_literals())),
         reserved_words(preserve_import)
     )
 }
@@ -1,6 +1,9 @@
-use crate::util::is_valid_ident;
+use crate::{
+    pass::{fused, LeafTransform},
+    util::is_valid_ident,
+};
 use swc_ecma_ast::*;
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+use swc_ecma_visit::Fold;
 
 /// babel: `transform-member-expression-literals`
 ///
@@ -19,18 +22,14 @@ use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
 /// obj["const"] = "isKeyword";
 /// obj["var"] = "isKeyword";
 /// ```
-pub fn member_expression_literals() -> impl Fold {
-    MemberExprLit
+pub fn member_expression_literals() -> impl Fold + LeafTransform {
+    fused(MemberExprLit)
 }
 #[derive(Default, Clone, Copy)]
 struct MemberExprLit;
 
-impl Fold for MemberExprLit {
-    noop_fold_type!();
-
-    fn fold_member_expr(&mut self, e: MemberExpr) -> MemberExpr {
-        let mut e = validate!(e.fold_children_with(self));
-
+impl LeafTransform for MemberExprLit {
+    fn transform_member_expr(&mut self, mut e: MemberExpr) -> MemberExpr {
         macro_rules! handle {
             ($sym:expr, $span:expr) => {
                 if $sym.is_reserved_for_es3() || !is_valid_ident(&$sym) {
@@ -40,6 +39,7 @@ impl Fold for MemberExprLit {
                             span: $span,
                             value: $sym,
                             has_escape: false,
+                            raw: None,
                         }))),
                         ..e
                     };
@@ -66,10 +66,6 @@ impl Fold for MemberExprLit {
 
         e
     }
-
-    fn fold_module(&mut self, node: Module) -> Module {
-        validate!(node.fold_children_with(self))
-    }
 }
 
 #[cfg(test)]
@@ -78,7 +74,7 @@ mod tests {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| MemberExprLit,
+        |_| member_expression_literals(),
         basic,
         r#"obj["foo"] = "isValid";
 
@@ -92,7 +88,7 @@ obj["var"] = "isKeyword";"#
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| MemberExprLit,
+        |_| member_expression_literals(),
         issue_206,
         "const number = foo[bar1][baz1]",
         "const number = foo[bar1][baz1]"
@@ -100,7 +96,7 @@ obj["var"] = "isKeyword";"#
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| MemberExprLit,
+        |_| member_expression_literals(),
         issue_211,
         "_query[idx]=$this.attr('data-ref');",
         "_query[idx]=$this.attr('data-ref');"
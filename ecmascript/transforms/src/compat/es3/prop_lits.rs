@@ -1,6 +1,9 @@
-use crate::util::is_valid_ident;
+use crate::{
+    pass::{fused, LeafTransform},
+    util::is_valid_ident,
+};
 use swc_ecma_ast::*;
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+use swc_ecma_visit::Fold;
 
 /// babel: `transform-property-literals`
 ///
@@ -29,18 +32,14 @@ use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
 ///   foo: 1
 /// };
 /// ```
-pub fn property_literals() -> impl Fold {
-    PropertyLiteral
+pub fn property_literals() -> impl Fold + LeafTransform {
+    fused(PropertyLiteral)
 }
 
 struct PropertyLiteral;
 
-impl Fold for PropertyLiteral {
-    noop_fold_type!();
-
-    fn fold_prop_name(&mut self, n: PropName) -> PropName {
-        let n = validate!(n.fold_children_with(self));
-
+impl LeafTransform for PropertyLiteral {
+    fn transform_prop_name(&mut self, n: PropName) -> PropName {
         match n {
             PropName::Str(Str {
                 value: sym, span, ..
@@ -50,6 +49,7 @@ impl Fold for PropertyLiteral {
                         span,
                         value: sym,
                         has_escape: false,
+                        raw: None,
                     })
                 } else {
                     PropName::Ident(Ident::new(sym, span))
@@ -62,6 +62,7 @@ impl Fold for PropertyLiteral {
                         span,
                         value: sym,
                         has_escape: false,
+                        raw: None,
                     })
                 } else {
                     PropName::Ident(Ident { span, sym, ..i })
@@ -78,7 +79,7 @@ mod tests {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| PropertyLiteral,
+        |_| property_literals(),
         babel_basic,
         r#"var foo = {
   // changed
@@ -103,7 +104,7 @@ mod tests {
 
     test!(
         ::swc_ecma_parser::Syntax::default(),
-        |_| PropertyLiteral,
+        |_| property_literals(),
         str_lit,
         r#"'use strict';
 var x = {
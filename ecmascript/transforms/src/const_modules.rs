@@ -5,10 +5,10 @@ use dashmap::DashMap;
 use once_cell::sync::Lazy;
 use std::{collections::HashMap, sync::Arc};
 use swc_atoms::JsWord;
-use swc_common::{sync::Lrc, util::move_map::MoveMap, FileName, SourceMap};
+use swc_common::{sync::Lrc, util::move_map::MoveMap, FileName, SourceMap, Span, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput};
-use swc_ecma_utils::HANDLER;
+use swc_ecma_utils::{quote_str, undefined, HANDLER};
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
 
 pub fn const_modules(
@@ -71,6 +71,12 @@ fn parse_option(cm: &SourceMap, name: &str, src: String) -> Arc<Expr> {
     expr
 }
 
+fn emit_error(span: Span, msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.struct_span_err(span, msg).emit())
+    }
+}
+
 struct ConstModules {
     globals: HashMap<JsWord, HashMap<JsWord, Arc<Expr>>>,
     scope: Scope,
@@ -79,6 +85,30 @@ struct ConstModules {
 #[derive(Default)]
 struct Scope {
     imported: HashMap<JsWord, Arc<Expr>>,
+    /// Local name of namespace import to the source of the const module.
+    namespace: HashMap<JsWord, JsWord>,
+}
+
+impl ConstModules {
+    /// Creates an object literal for a namespace import which is used as a
+    /// value.
+    fn namespace_object(&self, src: &JsWord) -> Expr {
+        let mut values = self.globals[src].iter().collect::<Vec<_>>();
+        values.sort_by(|a, b| a.0.cmp(b.0));
+
+        Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props: values
+                .into_iter()
+                .map(|(key, value)| {
+                    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: PropName::Str(quote_str!(key.clone())),
+                        value: Box::new((**value).clone()),
+                    })))
+                })
+                .collect(),
+        })
+    }
 }
 
 impl Fold for ConstModules {
@@ -91,22 +121,35 @@ impl Fold for ConstModules {
 
                 if let Some(entry) = entry {
                     for s in &import.specifiers {
-                        let i = match *s {
-                            ImportSpecifier::Named(ref s) => &s.local,
-                            ImportSpecifier::Namespace(..) => unimplemented!(
-                                "const modules does not support namespace import yet"
-                            ),
-                            ImportSpecifier::Default(..) => {
-                                panic!("const_modules does not support default import")
+                        let (imported, local) = match *s {
+                            ImportSpecifier::Named(ref s) => {
+                                (s.imported.as_ref().unwrap_or(&s.local), &s.local)
+                            }
+                            ImportSpecifier::Namespace(ref s) => {
+                                self.scope
+                                    .namespace
+                                    .insert(s.local.sym.clone(), import.src.value.clone());
+                                continue;
+                            }
+                            ImportSpecifier::Default(ref s) => {
+                                emit_error(s.span, "const_modules does not support default import");
+                                self.scope
+                                    .imported
+                                    .insert(s.local.sym.clone(), Arc::new(*undefined(DUMMY_SP)));
+                                continue;
                             }
                         };
-                        let value = entry.get(&i.sym).cloned().unwrap_or_else(|| {
-                            panic!(
-                                "const_modules: {} does not contain flags named {}",
-                                import.src.value, i.sym
-                            )
+                        let value = entry.get(&imported.sym).cloned().unwrap_or_else(|| {
+                            emit_error(
+                                imported.span,
+                                &format!(
+                                    "const_modules: {} does not contain flags named {}",
+                                    import.src.value, imported.sym
+                                ),
+                            );
+                            Arc::new(*undefined(DUMMY_SP))
                         });
-                        self.scope.imported.insert(i.sym.clone(), value);
+                        self.scope.imported.insert(local.sym.clone(), value);
                     }
 
                     None
@@ -119,6 +162,32 @@ impl Fold for ConstModules {
     }
 
     fn fold_expr(&mut self, expr: Expr) -> Expr {
+        if let Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            prop,
+            computed: false,
+            ..
+        }) = &expr
+        {
+            if let (Expr::Ident(obj), Expr::Ident(prop)) = (&**obj, &**prop) {
+                if let Some(src) = self.scope.namespace.get(&obj.sym) {
+                    return match self.globals[src].get(&prop.sym) {
+                        Some(value) => (**value).clone(),
+                        None => {
+                            emit_error(
+                                prop.span,
+                                &format!(
+                                    "const_modules: {} does not contain flags named {}",
+                                    src, prop.sym
+                                ),
+                            );
+                            *undefined(prop.span)
+                        }
+                    };
+                }
+            }
+        }
+
         let expr = match expr {
             Expr::Member(expr) => {
                 if expr.computed {
@@ -141,6 +210,8 @@ impl Fold for ConstModules {
                 // It's ok because we don't recurse into member expressions.
                 if let Some(value) = self.scope.imported.get(sym) {
                     (**value).clone()
+                } else if let Some(src) = self.scope.namespace.get(sym) {
+                    self.namespace_object(src)
                 } else {
                     expr
                 }
@@ -148,4 +219,23 @@ impl Fold for ConstModules {
             _ => expr,
         }
     }
+
+    fn fold_prop(&mut self, prop: Prop) -> Prop {
+        match prop {
+            Prop::Shorthand(key) => match self.scope.imported.get(&key.sym) {
+                Some(value) => Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(key),
+                    value: Box::new((**value).clone()),
+                }),
+                None => match self.scope.namespace.get(&key.sym) {
+                    Some(src) => Prop::KeyValue(KeyValueProp {
+                        value: Box::new(self.namespace_object(src)),
+                        key: PropName::Ident(key),
+                    }),
+                    None => Prop::Shorthand(key),
+                },
+            },
+            _ => prop.fold_children_with(self),
+        }
+    }
 }
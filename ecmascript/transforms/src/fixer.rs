@@ -1,6 +1,6 @@
-use crate::ext::{AsOptExpr, MapWithMut, PatOrExprExt};
+use crate::ext::{AsOptExpr, PatOrExprExt};
 use fxhash::FxHashMap;
-use swc_common::{comments::Comments, Span, Spanned};
+use swc_common::{comments::Comments, util::take::Take, Span, Spanned};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
 
@@ -218,6 +218,7 @@ impl VisitMut for Fixer<'_> {
                 || obj.is_class()
                 || obj.is_yield_expr()
                 || obj.is_await_expr()
+                || obj.is_throw()
                 || match **obj {
                     Expr::New(NewExpr { args: None, .. }) => true,
                     _ => false,
@@ -603,6 +604,9 @@ impl Fixer<'_> {
             // It's important for arrow pass to work properly.
             Expr::Object(..) | Expr::Class(..) | Expr::Fn(..) => self.wrap(expr),
 
+            // `do { }` would be parsed as a do-while statement.
+            Expr::Do(..) => self.wrap(expr),
+
             // ({ a } = foo)
             Expr::Assign(AssignExpr {
                 left: PatOrExpr::Pat(left),
@@ -1,10 +1,13 @@
 use once_cell::sync::Lazy;
 use scoped_tls::scoped_thread_local;
-use std::sync::atomic::{AtomicBool, Ordering};
-use swc_common::{FileName, FilePathMapping, Mark, SourceMap, DUMMY_SP};
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::atomic::{AtomicBool, Ordering},
+};
+use swc_common::{sync::Lrc, FileName, FilePathMapping, Mark, SourceMap, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput};
-use swc_ecma_utils::{prepend_stmts, quote_ident, quote_str, DropSpan};
+use swc_ecma_utils::{prepend_stmts, quote_ident, quote_str, DropSpan, MoveSpans};
 use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
 
 #[macro_export]
@@ -18,11 +21,11 @@ macro_rules! enable_helper {
 }
 
 macro_rules! add_to {
-    ($buf:expr, $name:ident, $b:expr, $mark:expr) => {{
+    ($buf:expr, $name:ident, $b:expr, $mark:expr, $cm:expr) => {{
+        const CODE: &str = include_str!(concat!("helpers/_", stringify!($name), ".js"));
         static STMTS: Lazy<Vec<Stmt>> = Lazy::new(|| {
             let cm = SourceMap::new(FilePathMapping::empty());
-            let code = include_str!(concat!("helpers/_", stringify!($name), ".js"));
-            let fm = cm.new_source_file(FileName::Custom(stringify!($name).into()), code.into());
+            let fm = cm.new_source_file(FileName::Custom(stringify!($name).into()), CODE.into());
             let lexer = Lexer::new(
                 Default::default(),
                 Default::default(),
@@ -31,12 +34,7 @@ macro_rules! add_to {
             );
             let stmts = Parser::new_from(lexer)
                 .parse_script()
-                .map(|mut script| {
-                    script.body.visit_mut_with(&mut DropSpan {
-                        preserve_ctxt: false,
-                    });
-                    script.body
-                })
+                .map(|script| script.body)
                 .map_err(|e| {
                     unreachable!("Error occurred while parsing error: {:?}", e);
                 })
@@ -46,11 +44,24 @@ macro_rules! add_to {
 
         let enable = $b.load(Ordering::Relaxed);
         if enable {
+            // Spans point to a file with the code of the helper if possible.
+            let fm = $cm.map(|cm: &SourceMap| {
+                cm.get_or_new_source_file(FileName::Internal(stringify!($name).into()), CODE)
+            });
+
             $buf.extend(
                 STMTS
                     .iter()
                     .cloned()
                     .map(|mut stmt| {
+                        match &fm {
+                            Some(fm) => stmt.visit_mut_with(&mut MoveSpans {
+                                start_pos: fm.start_pos,
+                            }),
+                            None => stmt.visit_mut_with(&mut DropSpan {
+                                preserve_ctxt: false,
+                            }),
+                        }
                         stmt.visit_mut_with(&mut Marker($mark));
                         stmt
                     })
@@ -63,11 +74,22 @@ macro_rules! add_to {
 scoped_thread_local!(pub static HELPERS: Helpers);
 
 /// Tracks used helper methods. (e.g. __extends)
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct Helpers {
     external: bool,
     mark: HelperMark,
     inner: Inner,
+    cm: Option<Lrc<SourceMap>>,
+}
+
+impl Debug for Helpers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Helpers")
+            .field("external", &self.external)
+            .field("mark", &self.mark)
+            .field("inner", &self.inner)
+            .finish()
+    }
 }
 
 impl Helpers {
@@ -76,9 +98,19 @@ impl Helpers {
             external,
             mark: Default::default(),
             inner: Default::default(),
+            cm: None,
         }
     }
 
+    /// Adds the code of injected helpers to `cm`, so that their spans point to
+    /// it instead of being dropped.
+    ///
+    /// Each helper is added once, with a [FileName::Internal] named after it.
+    pub fn with_source_map(mut self, cm: Lrc<SourceMap>) -> Self {
+        self.cm = Some(cm);
+        self
+    }
+
     pub(crate) const fn mark(&self) -> Mark {
         self.mark.0
     }
@@ -147,7 +179,7 @@ macro_rules! define_helpers {
                 HELPERS.with(|helpers|{
                     debug_assert!(!helpers.external);
                     $(
-                            add_to!(buf, $name, helpers.inner.$name, helpers.mark.0);
+                            add_to!(buf, $name, helpers.inner.$name, helpers.mark.0, helpers.cm.as_deref());
                     )*
                 });
 
@@ -168,6 +200,7 @@ define_helpers!(Helpers {
     async_to_generator: (),
     await_async_generator: (await_value),
     await_value: (),
+    check_spread_key: (),
     class_call_check: (),
     class_name_tdz_error: (),
     class_private_field_get: (),
@@ -184,6 +217,7 @@ define_helpers!(Helpers {
     defaults: (),
     define_enumerable_properties: (),
     define_property: (),
+    export_star: (),
     extends: (),
     get: (super_prop_base),
     get_prototype_of: (),
@@ -219,6 +253,7 @@ define_helpers!(Helpers {
     super_prop_base: (get_prototype_of),
     tagged_template_literal: (),
     tagged_template_literal_loose: (),
+    ts_param: (),
     // temporal_ref: (temporal_undefined),
     // temporal_undefined: (),
     throw: (),
@@ -292,6 +327,7 @@ impl VisitMut for Marker {
 mod tests {
     use super::*;
     use crate::pass::noop;
+    use swc_common::Spanned;
     use swc_ecma_visit::{as_folder, FoldWith};
 
     #[test]
@@ -341,6 +377,27 @@ swcHelpers._throw();",
         });
     }
 
+    #[test]
+    fn source_map() {
+        crate::tests::Tester::run(|tester| {
+            let helpers = Helpers::new(false).with_source_map(tester.cm.clone());
+            HELPERS.set(&helpers, || {
+                enable_helper!(throw);
+                let mut module = Module {
+                    span: DUMMY_SP,
+                    body: vec![],
+                    shebang: None,
+                };
+                module.visit_mut_with(&mut InjectHelpers);
+
+                let file = tester.cm.lookup_char_pos(module.body[0].span().lo()).file;
+                assert_eq!(file.name, FileName::Internal("throw".into()));
+                assert_eq!(file.name.to_string(), "<swc-generated:throw>");
+            });
+            Ok(())
+        });
+    }
+
     #[test]
     fn use_strict_before_helper() {
         crate::tests::test_transform(
@@ -0,0 +1,7 @@
+function _checkSpreadKey(props) {
+  if (props != null && Object.prototype.hasOwnProperty.call(props, "key")) {
+    console.error("Warning: A props object containing a \"key\" prop is being spread into JSX. " + "React keys must be passed directly to JSX without using spread.");
+  }
+
+  return props;
+}
@@ -0,0 +1,14 @@
+function _exportStar(from, to, exportNames) {
+  Object.keys(from).forEach(function (key) {
+    if (key === "default" || key === "__esModule") return;
+    if (exportNames && Object.prototype.hasOwnProperty.call(exportNames, key)) return;
+    if (key in to && to[key] === from[key]) return;
+    Object.defineProperty(to, key, {
+      enumerable: true,
+      get: function () {
+        return from[key];
+      }
+    });
+  });
+  return from;
+}
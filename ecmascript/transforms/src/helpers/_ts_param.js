@@ -0,0 +1,5 @@
+function _tsParam(paramIndex, decorator) {
+  return function (target, key) {
+    decorator(target, key, paramIndex);
+  };
+}
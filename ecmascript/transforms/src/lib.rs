@@ -29,6 +29,7 @@ pub mod compat;
 mod const_modules;
 pub mod debug;
 mod fixer;
+pub mod minify;
 pub mod modules;
 pub mod optimization;
 pub mod pass;
@@ -0,0 +1,14 @@
+//! Passes which reduce the size of the output without changing its behavior.
+//!
+//! Unlike [optimization](crate::optimization), these passes are only useful
+//! for minification.
+#![cfg(feature = "minifier")]
+pub use self::{
+    compress::compressor,
+    exports::retain_exports,
+    mangle::{mangler, mangler_with_cache},
+};
+
+pub mod compress;
+mod exports;
+pub mod mangle;
@@ -0,0 +1,430 @@
+use self::{inline::inline, unused::drop_unused};
+use serde::{Deserialize, Serialize};
+use std::mem::take;
+use swc_common::{util::take::Take, Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{drop_unreachable_code, StmtLike};
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+mod inline;
+mod unused;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Config {
+    /// Converts `if` statements into conditional or logical expressions.
+    pub conditionals: bool,
+
+    /// Joins consecutive expression statements with the comma operator.
+    pub sequences: bool,
+
+    /// Converts `if` statements returning a value into a `return` of a
+    /// conditional expression.
+    pub if_return: bool,
+
+    /// Removes blocks which don't declare anything.
+    pub join_blocks: bool,
+
+    /// Removes statements in functions which are never reached. Variables
+    /// they declare are still declared.
+    pub dead_code: bool,
+
+    /// Removes functions, classes and variables which are never read.
+    pub unused: bool,
+
+    /// Inlines calls to functions which only return an expression without side
+    /// effects, and `const` bindings with a constant value which are read once.
+    ///
+    /// `0` disables inlining, `1` inlines functions without parameters, `2`
+    /// also inlines functions with parameters, and `3` also inlines `const`
+    /// bindings.
+    pub inline: u8,
+
+    /// Removes and inlines top-level bindings. Exported bindings are never
+    /// removed.
+    pub top_level: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            conditionals: true,
+            sequences: true,
+            if_return: true,
+            join_blocks: true,
+            dead_code: true,
+            unused: true,
+            inline: 3,
+            top_level: false,
+        }
+    }
+}
+
+/// Rewrites statements into shorter forms.
+///
+/// The [fixer](crate::fixer) should be invoked after this pass. If `unused` or
+/// `inline` is enabled, resolver should be invoked before this pass.
+///
+/// Bindings visible from a direct `eval` or a `with` statement are never
+/// removed, and nothing is inlined if the code contains one.
+///
+/// # Example
+///
+/// ```js
+/// if (a) b(); else c();
+/// d();
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// a ? b() : c(), d();
+/// ```
+pub fn compressor(config: Config) -> impl 'static + Fold {
+    as_folder(Compressor { config })
+}
+
+struct Compressor {
+    config: Config,
+}
+
+impl Compressor {
+    fn compress_if(&self, s: IfStmt) -> Stmt {
+        let IfStmt {
+            span,
+            test,
+            cons,
+            alt,
+        } = s;
+
+        let alt = alt
+            .map(|alt| self.unwrap_block(*alt))
+            .and_then(|alt| match alt {
+                Stmt::Empty(..) => None,
+                _ => Some(alt),
+            });
+        let cons = match self.unwrap_block(*cons) {
+            // `if (a) { if (b) c(); } else d();` should not be changed to
+            // `if (a) if (b) c(); else d();`
+            cons if alt.is_some() && ends_with_if(&cons) => Stmt::Block(BlockStmt {
+                span: DUMMY_SP,
+                stmts: vec![cons],
+            }),
+            cons => cons,
+        };
+
+        match (cons, alt) {
+            (
+                Stmt::Return(ReturnStmt {
+                    arg: Some(cons), ..
+                }),
+                Some(Stmt::Return(ReturnStmt { arg: Some(alt), .. })),
+            ) if self.config.if_return => Stmt::Return(ReturnStmt {
+                span,
+                arg: Some(cond(test, cons, alt)),
+            }),
+            (cons, alt) => self.compress_if_expr(span, test, cons, alt),
+        }
+    }
+
+    fn compress_if_expr(&self, span: Span, test: Box<Expr>, cons: Stmt, alt: Option<Stmt>) -> Stmt {
+        if !self.config.conditionals {
+            return Stmt::If(IfStmt {
+                span,
+                test,
+                cons: Box::new(cons),
+                alt: alt.map(Box::new),
+            });
+        }
+
+        let expr = match (cons, alt) {
+            (Stmt::Expr(cons), Some(Stmt::Expr(alt))) => cond(test, cons.expr, alt.expr),
+            (Stmt::Expr(cons), None) => logical(test, op!("&&"), cons.expr),
+            (Stmt::Empty(..), Some(Stmt::Expr(alt))) => logical(test, op!("||"), alt.expr),
+            (Stmt::Empty(..), None) => test,
+            (cons, alt) => {
+                return Stmt::If(IfStmt {
+                    span,
+                    test,
+                    cons: Box::new(cons),
+                    alt: alt.map(Box::new),
+                })
+            }
+        };
+
+        Stmt::Expr(ExprStmt { span, expr })
+    }
+
+    /// Returns the statement in `s` if `s` is a block with only one statement.
+    fn unwrap_block(&self, s: Stmt) -> Stmt {
+        if !self.config.join_blocks {
+            return s;
+        }
+
+        match s {
+            Stmt::Block(BlockStmt { span, mut stmts }) => match stmts.len() {
+                0 => Stmt::Empty(EmptyStmt { span }),
+                1 if !is_block_scoped(&stmts[0]) => stmts.remove(0),
+                _ => Stmt::Block(BlockStmt { span, stmts }),
+            },
+            _ => s,
+        }
+    }
+
+    fn compress_stmts<T>(&self, stmts: &mut Vec<T>)
+    where
+        T: StmtLike,
+    {
+        let mut buf = Vec::with_capacity(stmts.len());
+
+        for stmt in take(stmts) {
+            match stmt.try_into_stmt() {
+                Ok(stmt) => self.push_stmt(&mut buf, stmt),
+                Err(item) => buf.push(item),
+            }
+        }
+
+        *stmts = buf;
+    }
+
+    fn push_stmt<T>(&self, buf: &mut Vec<T>, stmt: Stmt)
+    where
+        T: StmtLike,
+    {
+        match stmt {
+            Stmt::Empty(..) => {}
+
+            Stmt::Block(BlockStmt { stmts, .. })
+                if self.config.join_blocks && !stmts.iter().any(is_block_scoped) =>
+            {
+                for stmt in stmts {
+                    self.push_stmt(buf, stmt);
+                }
+            }
+
+            Stmt::Expr(ExprStmt { span, expr })
+                if self.config.sequences && !is_directive(&expr) =>
+            {
+                match pop_stmt(buf) {
+                    Some(Stmt::Expr(prev)) if !is_directive(&prev.expr) => {
+                        buf.push(T::from_stmt(Stmt::Expr(ExprStmt {
+                            span: prev.span,
+                            expr: seq(prev.expr, expr),
+                        })))
+                    }
+                    prev => {
+                        buf.extend(prev.map(T::from_stmt));
+                        buf.push(T::from_stmt(Stmt::Expr(ExprStmt { span, expr })))
+                    }
+                }
+            }
+
+            Stmt::Return(ReturnStmt {
+                span,
+                arg: Some(arg),
+            }) => match pop_stmt(buf) {
+                // `if (a) return b; return c;`
+                Some(Stmt::If(IfStmt {
+                    test,
+                    cons,
+                    alt: None,
+                    ..
+                })) if self.config.if_return => match *cons {
+                    Stmt::Return(ReturnStmt {
+                        arg: Some(cons), ..
+                    }) => self.push_stmt(
+                        buf,
+                        Stmt::Return(ReturnStmt {
+                            span,
+                            arg: Some(cond(test, cons, arg)),
+                        }),
+                    ),
+                    cons => {
+                        buf.push(T::from_stmt(Stmt::If(IfStmt {
+                            span: DUMMY_SP,
+                            test,
+                            cons: Box::new(cons),
+                            alt: None,
+                        })));
+                        buf.push(T::from_stmt(Stmt::Return(ReturnStmt {
+                            span,
+                            arg: Some(arg),
+                        })))
+                    }
+                },
+
+                // `a(); return b;`
+                Some(Stmt::Expr(prev)) if self.config.sequences && !is_directive(&prev.expr) => buf
+                    .push(T::from_stmt(Stmt::Return(ReturnStmt {
+                        span,
+                        arg: Some(seq(prev.expr, arg)),
+                    }))),
+
+                prev => {
+                    buf.extend(prev.map(T::from_stmt));
+                    buf.push(T::from_stmt(Stmt::Return(ReturnStmt {
+                        span,
+                        arg: Some(arg),
+                    })))
+                }
+            },
+
+            _ => buf.push(T::from_stmt(stmt)),
+        }
+    }
+}
+
+impl VisitMut for Compressor {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        if self.config.dead_code {
+            drop_unreachable_code(m);
+        }
+        if self.config.inline > 0 {
+            inline(m, self.config.inline, self.config.top_level);
+        }
+        if self.config.unused {
+            drop_unused(m, self.config.top_level);
+        }
+        m.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.visit_mut_children_with(self);
+        self.compress_stmts(items);
+    }
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        if self.config.dead_code {
+            drop_unreachable_code(s);
+        }
+        if self.config.inline > 0 {
+            inline(s, self.config.inline, self.config.top_level);
+        }
+        if self.config.unused {
+            drop_unused(s, self.config.top_level);
+        }
+        s.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        s.visit_mut_children_with(self);
+
+        match s {
+            Stmt::If(..) => s.map_with_mut(|s| match s {
+                Stmt::If(s) => self.compress_if(s),
+                _ => unreachable!(),
+            }),
+            Stmt::While(WhileStmt { body, .. })
+            | Stmt::DoWhile(DoWhileStmt { body, .. })
+            | Stmt::For(ForStmt { body, .. })
+            | Stmt::ForIn(ForInStmt { body, .. })
+            | Stmt::ForOf(ForOfStmt { body, .. }) => {
+                (**body).map_with_mut(|s| self.unwrap_block(s))
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.visit_mut_children_with(self);
+        self.compress_stmts(stmts);
+    }
+}
+
+/// Pops the last item of `buf` if it's a statement.
+fn pop_stmt<T>(buf: &mut Vec<T>) -> Option<Stmt>
+where
+    T: StmtLike,
+{
+    match buf.pop()?.try_into_stmt() {
+        Ok(stmt) => Some(stmt),
+        Err(item) => {
+            buf.push(item);
+            None
+        }
+    }
+}
+
+fn is_block_scoped(s: &Stmt) -> bool {
+    match s {
+        Stmt::Decl(Decl::Var(VarDecl {
+            kind: VarDeclKind::Var,
+            ..
+        })) => false,
+        Stmt::Decl(..) => true,
+        _ => false,
+    }
+}
+
+fn is_directive(e: &Expr) -> bool {
+    matches!(e, Expr::Lit(Lit::Str(..)))
+}
+
+/// Returns true if an `else` after `s` would belong to an `if` in `s`.
+fn ends_with_if(s: &Stmt) -> bool {
+    match s {
+        Stmt::If(..) => true,
+        Stmt::Labeled(LabeledStmt { body, .. })
+        | Stmt::While(WhileStmt { body, .. })
+        | Stmt::For(ForStmt { body, .. })
+        | Stmt::ForIn(ForInStmt { body, .. })
+        | Stmt::ForOf(ForOfStmt { body, .. })
+        | Stmt::With(WithStmt { body, .. }) => ends_with_if(body),
+        _ => false,
+    }
+}
+
+fn seq(a: Box<Expr>, b: Box<Expr>) -> Box<Expr> {
+    let mut exprs = vec![];
+    let mut push = |e: Box<Expr>| match *e {
+        Expr::Seq(SeqExpr { exprs: e, .. }) => exprs.extend(e),
+        _ => exprs.push(e),
+    };
+    push(a);
+    push(b);
+
+    Box::new(Expr::Seq(SeqExpr {
+        span: DUMMY_SP,
+        exprs,
+    }))
+}
+
+fn cond(test: Box<Expr>, cons: Box<Expr>, alt: Box<Expr>) -> Box<Expr> {
+    match *test {
+        // `!a ? b : c` => `a ? c : b`
+        Expr::Unary(UnaryExpr {
+            op: op!("!"), arg, ..
+        }) => cond(arg, alt, cons),
+        test => Box::new(Expr::Cond(CondExpr {
+            span: DUMMY_SP,
+            test: Box::new(test),
+            cons,
+            alt,
+        })),
+    }
+}
+
+fn logical(test: Box<Expr>, op: BinaryOp, right: Box<Expr>) -> Box<Expr> {
+    match *test {
+        // `!a && b` => `a || b`
+        Expr::Unary(UnaryExpr {
+            op: op!("!"), arg, ..
+        }) => logical(
+            arg,
+            if op == op!("&&") {
+                op!("||")
+            } else {
+                op!("&&")
+            },
+            right,
+        ),
+        test => Box::new(Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            op,
+            left: Box::new(test),
+            right,
+        })),
+    }
+}
@@ -0,0 +1,534 @@
+use fxhash::{FxHashMap, FxHashSet};
+use swc_atoms::js_word;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::{
+    find_ids, ident::IdentLike, undefined, DestructuringFinder, DynamicScopeFinder, DynamicScopes,
+    ExprExt, Id,
+};
+use swc_ecma_visit::{
+    noop_visit_mut_type, noop_visit_type, Node, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+/// Inlines calls to functions which only return a side-effect-free
+/// expression, and `const` bindings with a constant value which are read once.
+///
+/// See [Config::inline](super::Config::inline) for `level`.
+///
+/// Note: this assumes that resolver is invoked before the pass.
+pub(super) fn inline<N>(node: &mut N, level: u8, top_level: bool)
+where
+    N: VisitWith<Analyzer> + VisitWith<DynamicScopeFinder> + VisitMutWith<Inliner>,
+{
+    // A direct `eval` or a `with` statement can change any binding.
+    if DynamicScopes::analyze(node).is_top_level_dynamic() {
+        return;
+    }
+
+    let mut analyzer = Analyzer {
+        top_level,
+        depth: 0,
+        var_kind: VarDeclKind::Var,
+        fns: Default::default(),
+        consts: Default::default(),
+        const_ids: Default::default(),
+        reads: Default::default(),
+        written: Default::default(),
+        exported: Default::default(),
+    };
+    node.visit_with(&Invalid { span: DUMMY_SP } as _, &mut analyzer);
+
+    let Analyzer {
+        fns,
+        consts,
+        const_ids,
+        reads,
+        written,
+        exported,
+        ..
+    } = analyzer;
+
+    let fns = fns
+        .into_iter()
+        .filter_map(|(id, f)| Some((id, f?)))
+        .filter(|(id, f)| !written.contains(id) && level >= if f.params.is_empty() { 1 } else { 2 })
+        .collect();
+
+    let consts = consts
+        .into_iter()
+        .filter(|(id, init)| {
+            level >= 3
+                && reads.get(id) == Some(&1)
+                && !written.contains(id)
+                && !exported.contains(id)
+                && is_constant(init, &const_ids)
+        })
+        .collect();
+
+    node.visit_mut_with(&mut Inliner { fns, consts });
+}
+
+/// A function which returns an expression without side effects.
+#[derive(Debug, Clone)]
+struct TrivialFn {
+    params: Vec<Id>,
+    /// The number of times each parameter is read.
+    uses: Vec<usize>,
+    body: Box<Expr>,
+}
+
+impl TrivialFn {
+    fn new(params: Vec<&Pat>, body: &Expr) -> Option<Self> {
+        let params = params
+            .into_iter()
+            .map(|p| match p {
+                Pat::Ident(i) => Some(i.to_id()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        if body.may_have_side_effects() {
+            return None;
+        }
+        let mut checker = BodyChecker {
+            params: &params,
+            uses: vec![0; params.len()],
+            ok: true,
+        };
+        body.visit_with(&Invalid { span: DUMMY_SP } as _, &mut checker);
+        if !checker.ok {
+            return None;
+        }
+
+        Some(TrivialFn {
+            uses: checker.uses,
+            params,
+            body: Box::new(body.clone()),
+        })
+    }
+
+    fn from_function(f: &Function) -> Option<Self> {
+        if f.is_async || f.is_generator || !f.decorators.is_empty() {
+            return None;
+        }
+        if f.params.iter().any(|p| !p.decorators.is_empty()) {
+            return None;
+        }
+
+        match &f.body.as_ref()?.stmts[..] {
+            [Stmt::Return(ReturnStmt { arg: Some(arg), .. })] => {
+                Self::new(f.params.iter().map(|p| &p.pat).collect(), arg)
+            }
+            _ => None,
+        }
+    }
+
+    fn from_expr(e: &Expr) -> Option<Self> {
+        match e {
+            Expr::Fn(FnExpr {
+                ident: None,
+                function,
+            }) => Self::from_function(function),
+            Expr::Arrow(f) if !f.is_async && !f.is_generator => match &f.body {
+                BlockStmtOrExpr::Expr(body) => Self::new(f.params.iter().collect(), body),
+                BlockStmtOrExpr::BlockStmt(BlockStmt { stmts, .. }) => match &stmts[..] {
+                    [Stmt::Return(ReturnStmt { arg: Some(arg), .. })] => {
+                        Self::new(f.params.iter().collect(), arg)
+                    }
+                    _ => None,
+                },
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the body with parameters replaced by `args`, if `args` can be
+    /// evaluated in any order and any number of times.
+    fn call(&self, args: &[ExprOrSpread]) -> Option<Box<Expr>> {
+        if args
+            .iter()
+            .any(|arg| arg.spread.is_some() || arg.expr.may_have_side_effects())
+        {
+            return None;
+        }
+
+        let mut values = FxHashMap::default();
+        for (i, param) in self.params.iter().enumerate() {
+            let value = match args.get(i) {
+                Some(arg) if self.uses[i] <= 1 || is_simple(&arg.expr) => arg.expr.clone(),
+                Some(..) => return None,
+                None => undefined(DUMMY_SP),
+            };
+            values.insert(param.clone(), value);
+        }
+
+        let mut body = self.body.clone();
+        body.visit_mut_with(&mut Substitute { values });
+        Some(body)
+    }
+}
+
+/// Checks if a function body can be moved to a call site.
+struct BodyChecker<'a> {
+    params: &'a [Id],
+    uses: Vec<usize>,
+    ok: bool,
+}
+
+impl Visit for BodyChecker<'_> {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {
+        self.ok = false;
+    }
+
+    fn visit_class(&mut self, _: &Class, _: &dyn Node) {
+        self.ok = false;
+    }
+
+    fn visit_expr_or_super(&mut self, n: &ExprOrSuper, _: &dyn Node) {
+        match n {
+            ExprOrSuper::Super(..) => self.ok = false,
+            ExprOrSuper::Expr(e) => e.visit_with(n as _, self),
+        }
+    }
+
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {
+        self.ok = false;
+    }
+
+    fn visit_ident(&mut self, i: &Ident, _: &dyn Node) {
+        if i.sym == js_word!("arguments") {
+            self.ok = false;
+        }
+
+        let id = i.to_id();
+        if let Some(i) = self.params.iter().position(|p| *p == id) {
+            self.uses[i] += 1;
+        }
+    }
+
+    fn visit_member_expr(&mut self, n: &MemberExpr, _: &dyn Node) {
+        n.obj.visit_with(n as _, self);
+        if n.computed {
+            n.prop.visit_with(n as _, self);
+        }
+    }
+
+    fn visit_meta_prop_expr(&mut self, _: &MetaPropExpr, _: &dyn Node) {
+        self.ok = false;
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
+        if let PropName::Computed(e) = n {
+            e.visit_with(n as _, self);
+        }
+    }
+
+    fn visit_this_expr(&mut self, _: &ThisExpr, _: &dyn Node) {
+        self.ok = false;
+    }
+}
+
+pub(super) struct Analyzer {
+    top_level: bool,
+    /// The number of functions being visited.
+    depth: usize,
+    var_kind: VarDeclKind,
+    /// `None` if a binding can't be inlined.
+    fns: FxHashMap<Id, Option<TrivialFn>>,
+    consts: FxHashMap<Id, Box<Expr>>,
+    const_ids: FxHashSet<Id>,
+    reads: FxHashMap<Id, usize>,
+    written: FxHashSet<Id>,
+    exported: FxHashSet<Id>,
+}
+
+impl Analyzer {
+    fn add_fn(&mut self, id: Id, f: Option<TrivialFn>) {
+        let f = f.filter(|_| self.depth > 0 || self.top_level);
+        match self.fns.get_mut(&id) {
+            // Declared multiple times.
+            Some(old) => *old = None,
+            None => {
+                self.fns.insert(id, f);
+            }
+        }
+    }
+
+    fn write<T>(&mut self, node: &T)
+    where
+        T: for<'a> VisitWith<DestructuringFinder<'a, Id>>,
+    {
+        let ids: Vec<Id> = find_ids(node);
+        self.written.extend(ids);
+    }
+}
+
+impl Visit for Analyzer {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr, _: &dyn Node) {
+        self.depth += 1;
+        n.visit_children_with(self);
+        self.depth -= 1;
+    }
+
+    fn visit_assign_expr(&mut self, n: &AssignExpr, _: &dyn Node) {
+        match &n.left {
+            PatOrExpr::Pat(p) => self.write(&**p),
+            PatOrExpr::Expr(e) => {
+                if let Expr::Ident(i) = &**e {
+                    self.written.insert(i.to_id());
+                }
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_break_stmt(&mut self, _: &BreakStmt, _: &dyn Node) {}
+
+    fn visit_class_prop(&mut self, n: &ClassProp, _: &dyn Node) {
+        n.decorators.visit_with(n as _, self);
+        if n.computed {
+            n.key.visit_with(n as _, self);
+        }
+        n.value.visit_with(n as _, self);
+    }
+
+    fn visit_continue_stmt(&mut self, _: &ContinueStmt, _: &dyn Node) {}
+
+    fn visit_export_decl(&mut self, n: &ExportDecl, _: &dyn Node) {
+        if let Decl::Var(v) = &n.decl {
+            let ids: Vec<Id> = find_ids(&v.decls);
+            self.exported.extend(ids);
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, n: &FnDecl, _: &dyn Node) {
+        self.add_fn(n.ident.to_id(), TrivialFn::from_function(&n.function));
+        n.function.visit_with(n as _, self);
+    }
+
+    fn visit_for_in_stmt(&mut self, n: &ForInStmt, _: &dyn Node) {
+        self.write(&n.left);
+        n.visit_children_with(self);
+    }
+
+    fn visit_for_of_stmt(&mut self, n: &ForOfStmt, _: &dyn Node) {
+        self.write(&n.left);
+        n.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, n: &Function, _: &dyn Node) {
+        self.depth += 1;
+        n.visit_children_with(self);
+        self.depth -= 1;
+    }
+
+    fn visit_ident(&mut self, i: &Ident, _: &dyn Node) {
+        *self.reads.entry(i.to_id()).or_default() += 1;
+    }
+
+    fn visit_labeled_stmt(&mut self, n: &LabeledStmt, _: &dyn Node) {
+        n.body.visit_with(n as _, self);
+    }
+
+    fn visit_member_expr(&mut self, n: &MemberExpr, _: &dyn Node) {
+        n.obj.visit_with(n as _, self);
+        if n.computed {
+            n.prop.visit_with(n as _, self);
+        }
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
+        if let PropName::Computed(e) = n {
+            e.visit_with(n as _, self);
+        }
+    }
+
+    fn visit_update_expr(&mut self, n: &UpdateExpr, _: &dyn Node) {
+        if let Expr::Ident(i) = &*n.arg {
+            self.written.insert(i.to_id());
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_var_decl(&mut self, n: &VarDecl, _: &dyn Node) {
+        let old = self.var_kind;
+        self.var_kind = n.kind;
+        n.visit_children_with(self);
+        self.var_kind = old;
+    }
+
+    fn visit_var_declarator(&mut self, n: &VarDeclarator, _: &dyn Node) {
+        let i = match &n.name {
+            Pat::Ident(i) => i,
+            _ => {
+                n.visit_children_with(self);
+                return;
+            }
+        };
+        let id = i.to_id();
+
+        if self.var_kind == VarDeclKind::Const {
+            self.const_ids.insert(id.clone());
+            if let Some(init) = &n.init {
+                self.add_fn(id.clone(), TrivialFn::from_expr(init));
+                if self.depth > 0 || self.top_level {
+                    self.consts.insert(id, init.clone());
+                }
+            }
+        } else if n.init.is_some() {
+            self.written.insert(id);
+        }
+
+        n.init.visit_with(n as _, self);
+    }
+}
+
+pub(super) struct Inliner {
+    fns: FxHashMap<Id, TrivialFn>,
+    consts: FxHashMap<Id, Box<Expr>>,
+}
+
+impl Inliner {
+    fn const_value(&mut self, i: &Ident) -> Option<Box<Expr>> {
+        let id = i.to_id();
+        // Removed while visiting the value, which may contain other constants,
+        // so `const a = b, b = a;` doesn't recurse forever.
+        let init = self.consts.remove(&id)?;
+        let mut value = init.clone();
+        value.visit_mut_with(self);
+        self.consts.insert(id, init);
+        Some(value)
+    }
+}
+
+impl VisitMut for Inliner {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        match e {
+            Expr::Ident(i) => {
+                if let Some(value) = self.const_value(i) {
+                    *e = *value;
+                }
+            }
+            Expr::Call(CallExpr {
+                callee: ExprOrSuper::Expr(callee),
+                args,
+                ..
+            }) => {
+                let body = match &**callee {
+                    Expr::Ident(i) => self.fns.get(&i.to_id()).and_then(|f| f.call(args)),
+                    _ => None,
+                };
+                if let Some(body) = body {
+                    *e = *body;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_mut_member_expr(&mut self, n: &mut MemberExpr) {
+        n.obj.visit_mut_with(self);
+        if n.computed {
+            n.prop.visit_mut_with(self);
+        }
+    }
+
+    fn visit_mut_prop(&mut self, n: &mut Prop) {
+        n.visit_mut_children_with(self);
+
+        if let Prop::Shorthand(i) = n {
+            if let Some(value) = self.const_value(i) {
+                *n = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(i.clone()),
+                    value,
+                });
+            }
+        }
+    }
+}
+
+struct Substitute {
+    values: FxHashMap<Id, Box<Expr>>,
+}
+
+impl VisitMut for Substitute {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        match e {
+            Expr::Ident(i) => {
+                if let Some(value) = self.values.get(&i.to_id()) {
+                    *e = (**value).clone();
+                }
+            }
+            _ => e.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_member_expr(&mut self, n: &mut MemberExpr) {
+        n.obj.visit_mut_with(self);
+        if n.computed {
+            n.prop.visit_mut_with(self);
+        }
+    }
+
+    fn visit_mut_prop(&mut self, n: &mut Prop) {
+        n.visit_mut_children_with(self);
+
+        if let Prop::Shorthand(i) = n {
+            if let Some(value) = self.values.get(&i.to_id()) {
+                *n = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(i.clone()),
+                    value: value.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Returns true if reading `e` more than once is fine.
+fn is_simple(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(Lit::Regex(..)) => false,
+        Expr::Lit(..) | Expr::Ident(..) => true,
+        Expr::Unary(UnaryExpr { op, arg, .. }) => {
+            matches!(op, op!(unary, "-") | op!("!") | op!("void")) && matches!(**arg, Expr::Lit(..))
+        }
+        _ => false,
+    }
+}
+
+/// Returns true if `e` evaluates to the same value wherever it's moved to.
+fn is_constant(e: &Expr, consts: &FxHashSet<Id>) -> bool {
+    match e {
+        Expr::Lit(Lit::Regex(..)) | Expr::Lit(Lit::JSXText(..)) => false,
+        Expr::Lit(..) => true,
+        Expr::Ident(i) => consts.contains(&i.to_id()),
+        Expr::Paren(ParenExpr { expr, .. }) => is_constant(expr, consts),
+        Expr::Unary(UnaryExpr { op, arg, .. }) => {
+            !matches!(op, op!("delete")) && is_constant(arg, consts)
+        }
+        Expr::Bin(BinExpr {
+            op, left, right, ..
+        }) => {
+            !matches!(op, op!("in") | op!("instanceof"))
+                && is_constant(left, consts)
+                && is_constant(right, consts)
+        }
+        Expr::Cond(CondExpr {
+            test, cons, alt, ..
+        }) => is_constant(test, consts) && is_constant(cons, consts) && is_constant(alt, consts),
+        _ => false,
+    }
+}
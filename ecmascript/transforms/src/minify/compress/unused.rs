@@ -0,0 +1,424 @@
+use fxhash::{FxHashMap, FxHashSet};
+use swc_common::{util::take::Take, Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{find_ids, ident::IdentLike, DynamicScopeFinder, DynamicScopes, ExprExt, Id};
+use swc_ecma_visit::{
+    noop_visit_mut_type, noop_visit_type, Node, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+/// Removes functions, classes and variables which are never read.
+///
+/// Note: this assumes that resolver is invoked before the pass.
+pub(super) fn drop_unused<N>(node: &mut N, top_level: bool)
+where
+    N: VisitWith<Analyzer> + VisitWith<DynamicScopeFinder> + VisitMutWith<Remover>,
+{
+    let dynamic = DynamicScopes::analyze(node);
+    let mut analyzer = Analyzer {
+        scopes: vec![Scope {
+            bailout: dynamic.is_top_level_dynamic(),
+        }],
+        dynamic,
+        stack: vec![0],
+        owner: None,
+        decls: Default::default(),
+        roots: Default::default(),
+        exported: Default::default(),
+    };
+    node.visit_with(&Invalid { span: DUMMY_SP } as _, &mut analyzer);
+
+    let removed = analyzer.unused(top_level);
+    if !removed.is_empty() {
+        node.visit_mut_with(&mut Remover { removed });
+    }
+}
+
+/// A function, or the top level.
+#[derive(Debug)]
+struct Scope {
+    /// Contains a direct `eval` or a `with` statement, or a child scope does.
+    bailout: bool,
+}
+
+#[derive(Debug)]
+struct Binding {
+    scope: usize,
+    /// The declaration which contains this declaration.
+    owner: Option<Id>,
+    /// False if any of the declarations can't be removed without changing the
+    /// behavior.
+    removable: bool,
+    /// Bindings read by the declarations.
+    refs: FxHashSet<Id>,
+    /// Declarations in the declarations.
+    children: Vec<Id>,
+}
+
+pub(super) struct Analyzer {
+    dynamic: DynamicScopes,
+    scopes: Vec<Scope>,
+    /// Scopes being visited.
+    stack: Vec<usize>,
+    /// The declaration being visited.
+    owner: Option<Id>,
+    decls: FxHashMap<Id, Binding>,
+    /// Bindings read outside of any declaration.
+    roots: FxHashSet<Id>,
+    exported: FxHashSet<Id>,
+}
+
+impl Analyzer {
+    fn unused(self, top_level: bool) -> FxHashSet<Id> {
+        let Analyzer {
+            scopes,
+            decls,
+            roots,
+            exported,
+            ..
+        } = self;
+
+        let is_removable = |id: &Id, b: &Binding| {
+            b.removable
+                && !scopes[b.scope].bailout
+                && (b.scope != 0 || top_level)
+                && !exported.contains(id)
+        };
+
+        let mut used = FxHashSet::default();
+        let mut queue = roots.into_iter().collect::<Vec<_>>();
+        queue.extend(
+            decls
+                .iter()
+                .filter(|(id, b)| b.owner.is_none() && !is_removable(id, b))
+                .map(|(id, _)| id.clone()),
+        );
+
+        while let Some(id) = queue.pop() {
+            let b = match decls.get(&id) {
+                Some(b) => b,
+                None => continue,
+            };
+            if !used.insert(id) {
+                continue;
+            }
+
+            queue.extend(b.refs.iter().cloned());
+            queue.extend(
+                b.children
+                    .iter()
+                    .filter(|id| !is_removable(id, &decls[*id]))
+                    .cloned(),
+            );
+        }
+
+        decls
+            .into_iter()
+            .map(|(id, _)| id)
+            .filter(|id| !used.contains(id))
+            .collect()
+    }
+
+    fn with_scope<F>(&mut self, span: Span, op: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.stack.push(self.scopes.len());
+        self.scopes.push(Scope {
+            bailout: self.dynamic.is_dynamic(span),
+        });
+
+        op(self);
+
+        self.stack.pop();
+    }
+
+    fn declare(&mut self, id: Id, removable: bool) {
+        let scope = *self.stack.last().unwrap();
+        let owner = self.owner.clone();
+
+        if !self.decls.contains_key(&id) {
+            if let Some(owner) = &owner {
+                self.decls.get_mut(owner).unwrap().children.push(id.clone());
+            }
+        }
+
+        self.decls
+            .entry(id)
+            .or_insert_with(|| Binding {
+                scope,
+                owner,
+                removable: true,
+                refs: Default::default(),
+                children: vec![],
+            })
+            .removable &= removable;
+    }
+
+    fn with_owner<F>(&mut self, id: Id, op: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let old = self.owner.replace(id);
+        op(self);
+        self.owner = old;
+    }
+
+    fn add_ref(&mut self, id: Id) {
+        match &self.owner {
+            Some(owner) => {
+                self.decls.get_mut(owner).unwrap().refs.insert(id);
+            }
+            None => {
+                self.roots.insert(id);
+            }
+        }
+    }
+}
+
+impl Visit for Analyzer {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr, _: &dyn Node) {
+        self.with_scope(n.span, |a| n.visit_children_with(a))
+    }
+
+    fn visit_assign_expr(&mut self, n: &AssignExpr, _: &dyn Node) {
+        // Writing to a binding is not a read.
+        if n.op == op!("=") && assigned_ident(&n.left).is_some() {
+            n.right.visit_with(n as _, self);
+            return;
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_break_stmt(&mut self, _: &BreakStmt, _: &dyn Node) {}
+
+    fn visit_class_decl(&mut self, n: &ClassDecl, _: &dyn Node) {
+        let id = n.ident.to_id();
+        self.declare(id.clone(), is_pure_class(&n.class));
+        self.with_owner(id, |a| n.class.visit_with(n as _, a));
+    }
+
+    fn visit_class_prop(&mut self, n: &ClassProp, _: &dyn Node) {
+        n.decorators.visit_with(n as _, self);
+        if n.computed {
+            n.key.visit_with(n as _, self);
+        }
+        n.value.visit_with(n as _, self);
+    }
+
+    fn visit_continue_stmt(&mut self, _: &ContinueStmt, _: &dyn Node) {}
+
+    fn visit_export_decl(&mut self, n: &ExportDecl, _: &dyn Node) {
+        match &n.decl {
+            Decl::Class(ClassDecl { ident, .. }) | Decl::Fn(FnDecl { ident, .. }) => {
+                self.exported.insert(ident.to_id());
+            }
+            Decl::Var(v) => {
+                let ids: Vec<Id> = find_ids(&v.decls);
+                self.exported.extend(ids);
+            }
+            _ => {}
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, n: &FnDecl, _: &dyn Node) {
+        let id = n.ident.to_id();
+        self.declare(id.clone(), true);
+        self.with_owner(id, |a| n.function.visit_with(n as _, a));
+    }
+
+    fn visit_for_in_stmt(&mut self, n: &ForInStmt, _: &dyn Node) {
+        if let VarDeclOrPat::VarDecl(v) = &n.left {
+            let ids: Vec<Id> = find_ids(&v.decls);
+            for id in ids {
+                self.declare(id, false);
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_for_of_stmt(&mut self, n: &ForOfStmt, _: &dyn Node) {
+        if let VarDeclOrPat::VarDecl(v) = &n.left {
+            let ids: Vec<Id> = find_ids(&v.decls);
+            for id in ids {
+                self.declare(id, false);
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, n: &Function, _: &dyn Node) {
+        self.with_scope(n.span, |a| n.visit_children_with(a))
+    }
+
+    fn visit_ident(&mut self, i: &Ident, _: &dyn Node) {
+        self.add_ref(i.to_id());
+    }
+
+    fn visit_labeled_stmt(&mut self, n: &LabeledStmt, _: &dyn Node) {
+        n.body.visit_with(n as _, self);
+    }
+
+    fn visit_member_expr(&mut self, n: &MemberExpr, _: &dyn Node) {
+        n.obj.visit_with(n as _, self);
+        if n.computed {
+            n.prop.visit_with(n as _, self);
+        }
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
+        if let PropName::Computed(e) = n {
+            e.visit_with(n as _, self);
+        }
+    }
+
+    fn visit_var_declarator(&mut self, n: &VarDeclarator, _: &dyn Node) {
+        match &n.name {
+            Pat::Ident(i) => {
+                let id = i.to_id();
+                let removable = match &n.init {
+                    Some(init) => !init.may_have_side_effects(),
+                    None => true,
+                };
+                self.declare(id.clone(), removable);
+                self.with_owner(id, |a| n.init.visit_with(n as _, a));
+            }
+            _ => {
+                let ids: Vec<Id> = find_ids(&n.name);
+                for id in ids {
+                    self.declare(id, false);
+                }
+                n.visit_children_with(self);
+            }
+        }
+    }
+}
+
+pub(super) struct Remover {
+    removed: FxHashSet<Id>,
+}
+
+impl Remover {
+    fn is_removed(&self, i: &Ident) -> bool {
+        self.removed.contains(&i.to_id())
+    }
+}
+
+impl VisitMut for Remover {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if let Expr::Assign(AssignExpr {
+            op: op!("="),
+            left,
+            right,
+            ..
+        }) = e
+        {
+            if matches!(assigned_ident(left), Some(i) if self.is_removed(i)) {
+                *e = *right.take();
+            }
+        }
+    }
+
+    fn visit_mut_for_stmt(&mut self, n: &mut ForStmt) {
+        n.visit_mut_children_with(self);
+
+        if let Some(VarDeclOrExpr::VarDecl(VarDecl { decls, .. })) = &n.init {
+            if decls.is_empty() {
+                n.init = None;
+            }
+        }
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.visit_mut_children_with(self);
+        items.retain(|item| !matches!(item, ModuleItem::Stmt(Stmt::Empty(..))));
+    }
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        s.visit_mut_children_with(self);
+
+        let removed = match s {
+            Stmt::Decl(Decl::Class(ClassDecl { ident, .. }))
+            | Stmt::Decl(Decl::Fn(FnDecl { ident, .. })) => self.is_removed(ident),
+            Stmt::Decl(Decl::Var(VarDecl { decls, .. })) => decls.is_empty(),
+            _ => false,
+        };
+        if removed {
+            *s = Stmt::Empty(EmptyStmt { span: DUMMY_SP });
+        }
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.visit_mut_children_with(self);
+        stmts.retain(|s| !matches!(s, Stmt::Empty(..)));
+    }
+
+    fn visit_mut_var_declarators(&mut self, decls: &mut Vec<VarDeclarator>) {
+        decls.visit_mut_children_with(self);
+        decls.retain(|d| match &d.name {
+            Pat::Ident(i) => !self.is_removed(i),
+            _ => true,
+        });
+    }
+}
+
+/// Returns the binding if `left` is a plain identifier.
+fn assigned_ident(left: &PatOrExpr) -> Option<&Ident> {
+    match left {
+        PatOrExpr::Pat(p) => match &**p {
+            Pat::Ident(i) => Some(i),
+            Pat::Expr(e) => match &**e {
+                Expr::Ident(i) => Some(i),
+                _ => None,
+            },
+            _ => None,
+        },
+        PatOrExpr::Expr(e) => match &**e {
+            Expr::Ident(i) => Some(i),
+            _ => None,
+        },
+    }
+}
+
+/// Returns true if declaring `c` has no side effects.
+fn is_pure_class(c: &Class) -> bool {
+    let is_pure_key = |key: &PropName| match key {
+        PropName::Computed(c) => !c.expr.may_have_side_effects(),
+        _ => true,
+    };
+    let is_pure_value = |is_static: bool, value: &Option<Box<Expr>>| {
+        !is_static || !matches!(value, Some(v) if v.may_have_side_effects())
+    };
+
+    c.decorators.is_empty()
+        && match &c.super_class {
+            Some(e) => matches!(**e, Expr::Ident(..)),
+            None => true,
+        }
+        && c.body.iter().all(|m| match m {
+            ClassMember::Method(m) => m.function.decorators.is_empty() && is_pure_key(&m.key),
+            ClassMember::PrivateMethod(m) => m.function.decorators.is_empty(),
+            ClassMember::ClassProp(p) => {
+                p.decorators.is_empty()
+                    && (!p.computed || !p.key.may_have_side_effects())
+                    && is_pure_value(p.is_static, &p.value)
+            }
+            ClassMember::PrivateProp(p) => {
+                p.decorators.is_empty() && is_pure_value(p.is_static, &p.value)
+            }
+            ClassMember::Constructor(..)
+            | ClassMember::TsIndexSignature(..)
+            | ClassMember::Empty(..) => true,
+            ClassMember::StaticBlock(..) => false,
+        })
+}
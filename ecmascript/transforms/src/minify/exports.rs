@@ -0,0 +1,203 @@
+use std::mem::take;
+use swc_atoms::{js_word, JsWord};
+use swc_common::{Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::find_ids;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut};
+
+/// Removes all exports except `exports`, for libraries which only need their
+/// entry exports to be preserved.
+///
+/// Bindings which were exported are kept, so this should be followed by the
+/// [compressor](super::compressor) and the [mangler](super::mangler) with
+/// `top_level` enabled, which are then free to remove or mangle them.
+///
+/// Modules which re-exported bindings are still imported for their side
+/// effects. `export * from 'foo'` is kept, as the names it exports are not
+/// known.
+///
+/// # Example
+///
+/// With `exports` of `["foo"]`,
+///
+/// ```js
+/// export function foo() {}
+/// export function bar() {}
+/// export default 1;
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// export function foo() {}
+/// function bar() {}
+/// 1;
+/// ```
+pub fn retain_exports(exports: Vec<JsWord>) -> impl Fold {
+    as_folder(RetainExports { exports })
+}
+
+struct RetainExports {
+    exports: Vec<JsWord>,
+}
+
+impl RetainExports {
+    fn is_retained(&self, name: &JsWord) -> bool {
+        self.exports.contains(name)
+    }
+
+    fn retain_export_decl(&self, export: ExportDecl, buf: &mut Vec<ModuleItem>) {
+        let ExportDecl { span, decl } = export;
+        let decl = match decl {
+            Decl::Var(var) => return self.retain_var_decl(span, var, buf),
+            decl => decl,
+        };
+
+        let retained = match &decl {
+            Decl::Class(ClassDecl { ident, .. })
+            | Decl::Fn(FnDecl { ident, .. })
+            | Decl::TsInterface(TsInterfaceDecl { id: ident, .. })
+            | Decl::TsTypeAlias(TsTypeAliasDecl { id: ident, .. })
+            | Decl::TsEnum(TsEnumDecl { id: ident, .. }) => self.is_retained(&ident.sym),
+            Decl::TsModule(..) | Decl::Var(..) => true,
+        };
+
+        buf.push(if retained {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { span, decl }))
+        } else {
+            ModuleItem::Stmt(Stmt::Decl(decl))
+        });
+    }
+
+    /// Splits `export var a = 1, b = 2;` into `export var a = 1; var b = 2;` if
+    /// only `a` is retained.
+    fn retain_var_decl(&self, span: Span, var: VarDecl, buf: &mut Vec<ModuleItem>) {
+        let VarDecl {
+            span: var_span,
+            kind,
+            declare,
+            decls,
+        } = var;
+
+        let mut groups: Vec<(bool, Vec<VarDeclarator>)> = vec![];
+        for decl in decls {
+            let ids: Vec<Ident> = find_ids(&decl.name);
+            let retained = ids.iter().any(|i| self.is_retained(&i.sym));
+
+            match groups.last_mut() {
+                Some((last, decls)) if *last == retained => decls.push(decl),
+                _ => groups.push((retained, vec![decl])),
+            }
+        }
+
+        for (retained, decls) in groups {
+            let decl = Decl::Var(VarDecl {
+                span: var_span,
+                kind,
+                declare,
+                decls,
+            });
+
+            buf.push(if retained {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { span, decl }))
+            } else {
+                ModuleItem::Stmt(Stmt::Decl(decl))
+            });
+        }
+    }
+
+    fn retain_named_export(&self, mut export: NamedExport, buf: &mut Vec<ModuleItem>) {
+        export.specifiers.retain(|s| {
+            let name = match s {
+                ExportSpecifier::Namespace(s) => &s.name,
+                ExportSpecifier::Default(s) => &s.exported,
+                ExportSpecifier::Named(s) => s.exported.as_ref().unwrap_or(&s.orig),
+            };
+            self.is_retained(&name.sym)
+        });
+
+        if !export.specifiers.is_empty() {
+            buf.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)));
+            return;
+        }
+
+        // `export { foo } from 'foo'` still evaluates `foo`.
+        if let Some(src) = export.src {
+            buf.push(ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                span: export.span,
+                specifiers: vec![],
+                src,
+                type_only: export.type_only,
+                asserts: None,
+            })));
+        }
+    }
+}
+
+impl VisitMut for RetainExports {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        let mut buf = Vec::with_capacity(items.len());
+
+        for item in take(items) {
+            let decl = match item {
+                ModuleItem::ModuleDecl(decl) => decl,
+                ModuleItem::Stmt(..) => {
+                    buf.push(item);
+                    continue;
+                }
+            };
+
+            match decl {
+                ModuleDecl::ExportDecl(export) => self.retain_export_decl(export, &mut buf),
+
+                ModuleDecl::ExportNamed(export) => self.retain_named_export(export, &mut buf),
+
+                ModuleDecl::ExportDefaultDecl(export)
+                    if !self.is_retained(&js_word!("default")) =>
+                {
+                    match export.decl {
+                        DefaultDecl::Class(ClassExpr {
+                            ident: Some(ident),
+                            class,
+                        }) => buf.push(ModuleItem::Stmt(Stmt::Decl(Decl::Class(ClassDecl {
+                            ident,
+                            class,
+                            declare: false,
+                        })))),
+                        DefaultDecl::Fn(FnExpr {
+                            ident: Some(ident),
+                            function,
+                        }) => buf.push(ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl {
+                            ident,
+                            function,
+                            declare: false,
+                        })))),
+                        // A class may have static properties or decorators.
+                        DefaultDecl::Class(class) => {
+                            buf.push(ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+                                span: DUMMY_SP,
+                                expr: Box::new(Expr::Class(class)),
+                            })))
+                        }
+                        DefaultDecl::Fn(..) | DefaultDecl::TsInterfaceDecl(..) => {}
+                    }
+                }
+
+                ModuleDecl::ExportDefaultExpr(export)
+                    if !self.is_retained(&js_word!("default")) =>
+                {
+                    buf.push(ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+                        span: DUMMY_SP,
+                        expr: export.expr,
+                    })))
+                }
+
+                _ => buf.push(ModuleItem::ModuleDecl(decl)),
+            }
+        }
+
+        *items = buf;
+    }
+}
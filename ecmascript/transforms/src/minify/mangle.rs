@@ -0,0 +1,677 @@
+use self::props::mangle_props;
+pub use self::props::{NameCache, PropsConfig};
+use fxhash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::{borrow::BorrowMut, mem::take};
+use swc_atoms::JsWord;
+use swc_common::{Span, SyntaxContext, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{find_ids, ident::IdentLike, DynamicScopeFinder, DynamicScopes, Id};
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Node, Visit, VisitMut, VisitMutWith,
+    VisitWith,
+};
+
+mod props;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Mangles top-level bindings. Exported bindings are never mangled.
+    pub top_level: bool,
+
+    /// Names which are not used as mangled names, and bindings which are not
+    /// mangled.
+    pub reserved: Vec<JsWord>,
+
+    /// Preserves the names of classes.
+    pub keep_classnames: bool,
+
+    /// Preserves the names of functions.
+    pub keep_fnames: bool,
+
+    /// Mangles the names of properties.
+    pub props: Option<PropsConfig>,
+}
+
+/// Renames bindings to short names. Bindings used more often get shorter
+/// names.
+///
+/// Note: this pass assumes that resolver is invoked before the pass.
+///
+/// Bindings visible from a direct `eval` or a `with` statement are not
+/// renamed. Syntax contexts of renamed identifiers are removed, so the hygiene
+/// pass should not be invoked after this pass.
+///
+/// # Example
+///
+/// ```js
+/// function foo(first, second) {
+///     return second(first, first);
+/// }
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// function foo(a, b) {
+///     return b(a, a);
+/// }
+/// ```
+pub fn mangler(config: Config) -> impl 'static + Fold {
+    as_folder(Mangler {
+        config,
+        cache: NameCache::default(),
+    })
+}
+
+/// [mangler] which reuses the mangled names of properties in `cache`, and
+/// stores new ones to it.
+pub fn mangler_with_cache(config: Config, cache: &mut NameCache) -> impl '_ + Fold {
+    as_folder(Mangler { config, cache })
+}
+
+struct Mangler<C> {
+    config: Config,
+    cache: C,
+}
+
+impl<C> Mangler<C>
+where
+    C: BorrowMut<NameCache>,
+{
+    fn rename<N>(&mut self, node: &mut N)
+    where
+        N: for<'a> VisitWith<Analyzer<'a>>
+            + VisitWith<DynamicScopeFinder>
+            + VisitMutWith<Renamer>
+            + VisitWith<props::Analyzer>
+            + VisitMutWith<props::Renamer>,
+    {
+        let dynamic = DynamicScopes::analyze(node);
+        let mut analyzer = Analyzer {
+            config: &self.config,
+            data: Default::default(),
+            cur: 0,
+            dynamic,
+        };
+        analyzer.data.scopes.push(Scope {
+            bailout: analyzer.dynamic.is_top_level_dynamic(),
+            ..Default::default()
+        });
+        node.visit_with(&Invalid { span: DUMMY_SP } as _, &mut analyzer);
+
+        let names = analyzer.data.names(&self.config);
+        node.visit_mut_with(&mut Renamer { names });
+
+        if let Some(props) = &self.config.props {
+            mangle_props(node, props, self.cache.borrow_mut());
+        }
+    }
+}
+
+impl<C> VisitMut for Mangler<C>
+where
+    C: BorrowMut<NameCache>,
+{
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        self.rename(m)
+    }
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        self.rename(s)
+    }
+}
+
+/// A function, or the top level.
+///
+/// Block scopes are merged into the function, which only makes the names of
+/// some bindings longer than needed.
+#[derive(Debug, Default)]
+struct Scope {
+    parent: Option<usize>,
+    /// In the order of declaration.
+    decls: Vec<Id>,
+    /// Identifiers referenced in this scope or its children.
+    refs: FxHashSet<Id>,
+    /// Contains a direct `eval` or a `with` statement, or a child scope does.
+    bailout: bool,
+}
+
+#[derive(Debug, Default)]
+struct Data {
+    /// Children come after their parents.
+    scopes: Vec<Scope>,
+    decl_scopes: FxHashMap<Id, usize>,
+    usages: FxHashMap<Id, usize>,
+    preserved: FxHashSet<Id>,
+}
+
+impl Data {
+    fn names(mut self, config: &Config) -> FxHashMap<Id, JsWord> {
+        let mut kept = vec![FxHashSet::<JsWord>::default(); self.scopes.len()];
+
+        for i in (0..self.scopes.len()).rev() {
+            let bailout = self.scopes[i].bailout || (i == 0 && !config.top_level);
+            self.scopes[i].bailout = bailout;
+
+            for id in &self.scopes[i].decls {
+                if bailout || self.preserved.contains(id) {
+                    kept[i].insert(id.0.clone());
+                }
+            }
+
+            if let Some(parent) = self.scopes[i].parent {
+                self.scopes[parent].bailout |= self.scopes[i].bailout;
+
+                let names = take(&mut kept[i]);
+                kept[parent].extend(names.iter().cloned());
+                kept[i] = names;
+            }
+        }
+
+        let mut names = FxHashMap::default();
+
+        for (i, scope) in self.scopes.iter().enumerate() {
+            if scope.bailout {
+                continue;
+            }
+
+            let mut unavailable = take(&mut kept[i]);
+            unavailable.extend(config.reserved.iter().cloned());
+            for id in &scope.refs {
+                match self.decl_scopes.get(id) {
+                    // Unresolved
+                    None => {
+                        unavailable.insert(id.0.clone());
+                    }
+                    // Declared by a parent, as children come after their parents.
+                    Some(&d) if d < i => {
+                        unavailable.insert(names.get(id).unwrap_or(&id.0).clone());
+                    }
+                    _ => {}
+                }
+            }
+
+            let mut decls = scope
+                .decls
+                .iter()
+                .filter(|id| !self.preserved.contains(id))
+                .collect::<Vec<_>>();
+            // Stable, so the order of declaration is kept for ties.
+            decls.sort_by_key(|id| std::cmp::Reverse(self.usages.get(id).copied().unwrap_or(0)));
+
+            let mut n = 0;
+            for id in decls {
+                let name = loop {
+                    let name = base54(n);
+                    n += 1;
+                    if !is_reserved_word(&name) && !unavailable.contains(&name) {
+                        break name;
+                    }
+                };
+                names.insert(id.clone(), name);
+            }
+        }
+
+        names
+    }
+}
+
+pub(super) fn base54(mut n: usize) -> JsWord {
+    const FIRST: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_";
+    const REST: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_0123456789";
+
+    let mut s = String::new();
+    s.push(FIRST[n % FIRST.len()] as char);
+    n /= FIRST.len();
+    while n > 0 {
+        n -= 1;
+        s.push(REST[n % REST.len()] as char);
+        n /= REST.len();
+    }
+    s.into()
+}
+
+pub(super) fn is_reserved_word(s: &JsWord) -> bool {
+    s.is_reserved_for_es3()
+        || matches!(
+            &**s,
+            "await" | "yield" | "let" | "static" | "eval" | "arguments"
+        )
+}
+
+struct Analyzer<'a> {
+    config: &'a Config,
+    data: Data,
+    cur: usize,
+    dynamic: DynamicScopes,
+}
+
+impl Analyzer<'_> {
+    fn with_scope<F>(&mut self, span: Span, op: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let parent = self.cur;
+        self.cur = self.data.scopes.len();
+        self.data.scopes.push(Scope {
+            parent: Some(parent),
+            bailout: self.dynamic.is_dynamic(span),
+            ..Default::default()
+        });
+
+        op(self);
+
+        self.cur = parent;
+    }
+
+    fn declare(&mut self, i: &Ident) {
+        let id = i.to_id();
+
+        if self.config.reserved.contains(&i.sym) {
+            self.data.preserved.insert(id.clone());
+        }
+        *self.data.usages.entry(id.clone()).or_default() += 1;
+
+        if !self.data.decl_scopes.contains_key(&id) {
+            self.data.decl_scopes.insert(id.clone(), self.cur);
+            self.data.scopes[self.cur].decls.push(id);
+        }
+    }
+
+    fn declare_pat(&mut self, p: &Pat) {
+        match p {
+            Pat::Ident(i) => self.declare(i),
+            Pat::Array(p) => {
+                for elem in p.elems.iter().flatten() {
+                    self.declare_pat(elem);
+                }
+            }
+            Pat::Rest(p) => self.declare_pat(&p.arg),
+            Pat::Object(p) => {
+                for prop in &p.props {
+                    match prop {
+                        ObjectPatProp::KeyValue(prop) => {
+                            prop.key.visit_with(p as _, self);
+                            self.declare_pat(&prop.value);
+                        }
+                        ObjectPatProp::Assign(prop) => {
+                            self.declare(&prop.key);
+                            prop.value.visit_with(p as _, self);
+                        }
+                        ObjectPatProp::Rest(prop) => self.declare_pat(&prop.arg),
+                    }
+                }
+            }
+            Pat::Assign(p) => {
+                self.declare_pat(&p.left);
+                p.right.visit_with(p as _, self);
+            }
+            Pat::Invalid(..) | Pat::Expr(..) => p.visit_with(p as _, self),
+        }
+    }
+
+    fn preserve(&mut self, i: &Ident) {
+        self.data.preserved.insert(i.to_id());
+    }
+
+    fn add_ref(&mut self, i: &Ident) {
+        let id = i.to_id();
+        *self.data.usages.entry(id.clone()).or_default() += 1;
+
+        let mut scope = Some(self.cur);
+        while let Some(i) = scope {
+            self.data.scopes[i].refs.insert(id.clone());
+            scope = self.data.scopes[i].parent;
+        }
+    }
+
+    fn visit_fn_params_and_body(&mut self, f: &Function) {
+        f.decorators.visit_with(f as _, self);
+        for param in &f.params {
+            param.decorators.visit_with(param as _, self);
+            self.declare_pat(&param.pat);
+        }
+        f.body.visit_with(f as _, self);
+    }
+}
+
+impl Visit for Analyzer<'_> {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, f: &ArrowExpr, _: &dyn Node) {
+        self.with_scope(f.span, |a| {
+            for p in &f.params {
+                a.declare_pat(p);
+            }
+            f.body.visit_with(f as _, a);
+        })
+    }
+
+    fn visit_break_stmt(&mut self, _: &BreakStmt, _: &dyn Node) {}
+
+    fn visit_catch_clause(&mut self, n: &CatchClause, _: &dyn Node) {
+        if let Some(param) = &n.param {
+            self.declare_pat(param);
+        }
+        n.body.visit_with(n as _, self);
+    }
+
+    fn visit_class_decl(&mut self, n: &ClassDecl, _: &dyn Node) {
+        if self.config.keep_classnames {
+            self.preserve(&n.ident);
+        }
+        self.declare(&n.ident);
+        n.class.visit_with(n as _, self);
+    }
+
+    fn visit_class_expr(&mut self, n: &ClassExpr, _: &dyn Node) {
+        self.with_scope(n.class.span, |a| {
+            if let Some(ident) = &n.ident {
+                if a.config.keep_classnames {
+                    a.preserve(ident);
+                }
+                a.declare(ident);
+            }
+            n.class.visit_with(n as _, a);
+        })
+    }
+
+    fn visit_class_prop(&mut self, n: &ClassProp, _: &dyn Node) {
+        n.decorators.visit_with(n as _, self);
+        if n.computed {
+            n.key.visit_with(n as _, self);
+        }
+        n.value.visit_with(n as _, self);
+    }
+
+    fn visit_constructor(&mut self, n: &Constructor, _: &dyn Node) {
+        self.with_scope(n.span, |a| {
+            for param in &n.params {
+                match param {
+                    ParamOrTsParamProp::Param(param) => {
+                        param.decorators.visit_with(param as _, a);
+                        a.declare_pat(&param.pat);
+                    }
+                    ParamOrTsParamProp::TsParamProp(param) => {
+                        param.decorators.visit_with(param as _, a);
+                        match &param.param {
+                            TsParamPropParam::Ident(i) => a.declare(i),
+                            TsParamPropParam::Assign(p) => {
+                                a.declare_pat(&p.left);
+                                p.right.visit_with(p as _, a);
+                            }
+                        }
+                    }
+                }
+            }
+            n.body.visit_with(n as _, a);
+        })
+    }
+
+    fn visit_continue_stmt(&mut self, _: &ContinueStmt, _: &dyn Node) {}
+
+    fn visit_export_decl(&mut self, n: &ExportDecl, _: &dyn Node) {
+        n.decl.visit_with(n as _, self);
+
+        match &n.decl {
+            Decl::Class(ClassDecl { ident, .. }) | Decl::Fn(FnDecl { ident, .. }) => {
+                self.preserve(ident)
+            }
+            Decl::Var(v) => {
+                let ids: Vec<Id> = find_ids(&v.decls);
+                self.data.preserved.extend(ids);
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_export_default_decl(&mut self, n: &ExportDefaultDecl, _: &dyn Node) {
+        match &n.decl {
+            DefaultDecl::Class(c) => {
+                if let Some(ident) = &c.ident {
+                    self.declare(ident);
+                    self.preserve(ident);
+                }
+                c.class.visit_with(c as _, self);
+            }
+            DefaultDecl::Fn(f) => {
+                if let Some(ident) = &f.ident {
+                    self.declare(ident);
+                    self.preserve(ident);
+                }
+                f.function.visit_with(f as _, self);
+            }
+            DefaultDecl::TsInterfaceDecl(..) => {}
+        }
+    }
+
+    fn visit_fn_decl(&mut self, n: &FnDecl, _: &dyn Node) {
+        if self.config.keep_fnames {
+            self.preserve(&n.ident);
+        }
+        self.declare(&n.ident);
+        n.function.visit_with(n as _, self);
+    }
+
+    fn visit_fn_expr(&mut self, n: &FnExpr, _: &dyn Node) {
+        self.with_scope(n.function.span, |a| {
+            if let Some(ident) = &n.ident {
+                if a.config.keep_fnames {
+                    a.preserve(ident);
+                }
+                a.declare(ident);
+            }
+            a.visit_fn_params_and_body(&n.function);
+        })
+    }
+
+    fn visit_function(&mut self, n: &Function, _: &dyn Node) {
+        self.with_scope(n.span, |a| a.visit_fn_params_and_body(n))
+    }
+
+    fn visit_getter_prop(&mut self, n: &GetterProp, _: &dyn Node) {
+        n.key.visit_with(n as _, self);
+        self.with_scope(n.span, |a| n.body.visit_with(n as _, a))
+    }
+
+    fn visit_ident(&mut self, i: &Ident, _: &dyn Node) {
+        self.add_ref(i)
+    }
+
+    fn visit_import_default_specifier(&mut self, n: &ImportDefaultSpecifier, _: &dyn Node) {
+        self.declare(&n.local)
+    }
+
+    fn visit_import_named_specifier(&mut self, n: &ImportNamedSpecifier, _: &dyn Node) {
+        self.declare(&n.local)
+    }
+
+    fn visit_import_star_as_specifier(&mut self, n: &ImportStarAsSpecifier, _: &dyn Node) {
+        self.declare(&n.local)
+    }
+
+    /// Renaming a component changes it to an intrinsic element if the new name
+    /// starts with a lowercase letter.
+    fn visit_jsx_element_name(&mut self, n: &JSXElementName, _: &dyn Node) {
+        match n {
+            JSXElementName::Ident(i) => {
+                self.add_ref(i);
+                self.preserve(i);
+            }
+            JSXElementName::JSXMemberExpr(e) => e.visit_with(n as _, self),
+            JSXElementName::JSXNamespacedName(..) => {}
+        }
+    }
+
+    fn visit_jsx_attr_name(&mut self, _: &JSXAttrName, _: &dyn Node) {}
+
+    fn visit_jsx_member_expr(&mut self, n: &JSXMemberExpr, _: &dyn Node) {
+        n.obj.visit_with(n as _, self);
+    }
+
+    fn visit_labeled_stmt(&mut self, n: &LabeledStmt, _: &dyn Node) {
+        n.body.visit_with(n as _, self);
+    }
+
+    fn visit_member_expr(&mut self, n: &MemberExpr, _: &dyn Node) {
+        n.obj.visit_with(n as _, self);
+        if n.computed {
+            n.prop.visit_with(n as _, self);
+        }
+    }
+
+    fn visit_meta_prop_expr(&mut self, _: &MetaPropExpr, _: &dyn Node) {}
+
+    fn visit_named_export(&mut self, n: &NamedExport, _: &dyn Node) {
+        if n.src.is_some() {
+            return;
+        }
+
+        for s in &n.specifiers {
+            if let ExportSpecifier::Named(s) = s {
+                self.add_ref(&s.orig);
+                self.preserve(&s.orig);
+            }
+        }
+    }
+
+    fn visit_private_name(&mut self, _: &PrivateName, _: &dyn Node) {}
+
+    fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
+        if let PropName::Computed(n) = n {
+            n.visit_with(n as _, self);
+        }
+    }
+
+    fn visit_setter_prop(&mut self, n: &SetterProp, _: &dyn Node) {
+        n.key.visit_with(n as _, self);
+        self.with_scope(n.span, |a| {
+            a.declare_pat(&n.param);
+            n.body.visit_with(n as _, a);
+        })
+    }
+
+    fn visit_var_declarator(&mut self, n: &VarDeclarator, _: &dyn Node) {
+        self.declare_pat(&n.name);
+        n.init.visit_with(n as _, self);
+    }
+}
+
+struct Renamer {
+    names: FxHashMap<Id, JsWord>,
+}
+
+impl Renamer {
+    fn renamed(&self, i: &Ident) -> Option<Ident> {
+        let sym = self.names.get(&i.to_id())?;
+
+        Some(Ident {
+            sym: sym.clone(),
+            span: i.span.with_ctxt(SyntaxContext::empty()),
+            ..i.clone()
+        })
+    }
+}
+
+impl VisitMut for Renamer {
+    noop_visit_mut_type!();
+
+    fn visit_mut_break_stmt(&mut self, _: &mut BreakStmt) {}
+
+    fn visit_mut_class_prop(&mut self, n: &mut ClassProp) {
+        n.decorators.visit_mut_with(self);
+        if n.computed {
+            n.key.visit_mut_with(self);
+        }
+        n.value.visit_mut_with(self);
+    }
+
+    fn visit_mut_continue_stmt(&mut self, _: &mut ContinueStmt) {}
+
+    fn visit_mut_ident(&mut self, i: &mut Ident) {
+        if let Some(renamed) = self.renamed(i) {
+            *i = renamed;
+        }
+    }
+
+    fn visit_mut_import_named_specifier(&mut self, n: &mut ImportNamedSpecifier) {
+        if let Some(local) = self.renamed(&n.local) {
+            if n.imported.is_none() {
+                n.imported = Some(Ident::new(n.local.sym.clone(), n.local.span));
+            }
+            n.local = local;
+        }
+    }
+
+    fn visit_mut_jsx_attr_name(&mut self, _: &mut JSXAttrName) {}
+
+    fn visit_mut_jsx_element_name(&mut self, _: &mut JSXElementName) {}
+
+    fn visit_mut_labeled_stmt(&mut self, n: &mut LabeledStmt) {
+        n.body.visit_mut_with(self);
+    }
+
+    fn visit_mut_member_expr(&mut self, n: &mut MemberExpr) {
+        n.obj.visit_mut_with(self);
+        if n.computed {
+            n.prop.visit_mut_with(self);
+        }
+    }
+
+    fn visit_mut_meta_prop_expr(&mut self, _: &mut MetaPropExpr) {}
+
+    fn visit_mut_named_export(&mut self, _: &mut NamedExport) {}
+
+    fn visit_mut_object_pat_prop(&mut self, n: &mut ObjectPatProp) {
+        if let ObjectPatProp::Assign(AssignPatProp { span, key, value }) = n {
+            if let Some(renamed) = self.renamed(key) {
+                value.visit_mut_with(self);
+
+                let orig = Ident::new(key.sym.clone(), key.span);
+                let value = match value.take() {
+                    Some(right) => Pat::Assign(AssignPat {
+                        span: *span,
+                        left: Box::new(Pat::Ident(renamed)),
+                        right,
+                        type_ann: None,
+                    }),
+                    None => Pat::Ident(renamed),
+                };
+
+                *n = ObjectPatProp::KeyValue(KeyValuePatProp {
+                    key: PropName::Ident(orig),
+                    value: Box::new(value),
+                });
+                return;
+            }
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_private_name(&mut self, _: &mut PrivateName) {}
+
+    fn visit_mut_prop(&mut self, n: &mut Prop) {
+        if let Prop::Shorthand(i) = n {
+            if let Some(renamed) = self.renamed(i) {
+                *n = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(Ident::new(i.sym.clone(), i.span)),
+                    value: Box::new(Expr::Ident(renamed)),
+                });
+            }
+            return;
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_prop_name(&mut self, n: &mut PropName) {
+        if let PropName::Computed(n) = n {
+            n.visit_mut_with(self);
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use super::{base54, is_reserved_word};
+use fxhash::{FxHashMap, FxHashSet};
+use regex::Regex;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+use swc_atoms::JsWord;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{
+    noop_visit_mut_type, noop_visit_type, Node, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropsConfig {
+    /// Only properties matching this are mangled. An invalid regex is
+    /// rejected when the config is deserialized.
+    #[serde(
+        serialize_with = "serialize_regex",
+        deserialize_with = "deserialize_regex"
+    )]
+    pub regex: Regex,
+
+    /// Properties which are not mangled, and names which are not used as
+    /// mangled names.
+    #[serde(default)]
+    pub reserved: Vec<JsWord>,
+
+    /// Properties written as a string, like `a["foo"]` or `{ "foo": 1 }`, are
+    /// not mangled, even where they are not quoted.
+    #[serde(default)]
+    pub keep_quoted: bool,
+}
+
+fn serialize_regex<S>(regex: &Regex, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(regex.as_str())
+}
+
+fn deserialize_regex<'de, D>(deserializer: D) -> Result<Regex, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    Regex::new(&s).map_err(serde::de::Error::custom)
+}
+
+/// Mangled names of properties, which can be stored in a file to mangle
+/// multiple files consistently.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NameCache {
+    /// From the original name to the mangled name.
+    pub props: BTreeMap<JsWord, JsWord>,
+}
+
+pub(super) fn mangle_props<N>(node: &mut N, config: &PropsConfig, cache: &mut NameCache)
+where
+    N: VisitWith<Analyzer> + VisitMutWith<Renamer>,
+{
+    let mut analyzer = Analyzer::default();
+    node.visit_with(&Invalid { span: DUMMY_SP } as _, &mut analyzer);
+
+    let can_mangle = |name: &JsWord| {
+        config.regex.is_match(name)
+            && !config.reserved.contains(name)
+            && !(config.keep_quoted && analyzer.quoted.contains(name))
+    };
+
+    let mut unavailable = analyzer
+        .usages
+        .keys()
+        .chain(&config.reserved)
+        .chain(cache.props.values())
+        .cloned()
+        .collect::<FxHashSet<_>>();
+
+    let mut names = analyzer
+        .names
+        .iter()
+        .filter(|name| !cache.props.contains_key(name) && can_mangle(name))
+        .collect::<Vec<_>>();
+    // Stable, so the order of appearance is kept for ties.
+    names.sort_by_key(|name| std::cmp::Reverse(analyzer.usages[*name]));
+
+    let mut n = 0;
+    for name in names {
+        let mangled = loop {
+            let mangled = base54(n);
+            n += 1;
+            if !is_reserved_word(&mangled) && !unavailable.contains(&mangled) {
+                break mangled;
+            }
+        };
+        unavailable.insert(mangled.clone());
+        cache.props.insert(name.clone(), mangled);
+    }
+
+    let names = analyzer
+        .names
+        .iter()
+        .filter(|name| can_mangle(name))
+        .filter_map(|name| Some((name.clone(), cache.props.get(name)?.clone())))
+        .collect();
+    node.visit_mut_with(&mut Renamer { names });
+}
+
+#[derive(Default)]
+pub(super) struct Analyzer {
+    /// In the order of appearance.
+    names: Vec<JsWord>,
+    usages: FxHashMap<JsWord, usize>,
+    quoted: FxHashSet<JsWord>,
+}
+
+impl Analyzer {
+    fn add(&mut self, name: &JsWord, quoted: bool) {
+        let usages = self.usages.entry(name.clone()).or_default();
+        if *usages == 0 {
+            self.names.push(name.clone());
+        }
+        *usages += 1;
+
+        if quoted {
+            self.quoted.insert(name.clone());
+        }
+    }
+}
+
+impl Visit for Analyzer {
+    noop_visit_type!();
+
+    fn visit_assign_pat_prop(&mut self, n: &AssignPatProp, _: &dyn Node) {
+        self.add(&n.key.sym, false);
+        n.value.visit_with(n as _, self);
+    }
+
+    fn visit_class_prop(&mut self, n: &ClassProp, _: &dyn Node) {
+        n.decorators.visit_with(n as _, self);
+        match &*n.key {
+            Expr::Ident(i) if !n.computed => self.add(&i.sym, false),
+            Expr::Lit(Lit::Str(s)) if !n.computed => self.add(&s.value, true),
+            _ => n.key.visit_with(n as _, self),
+        }
+        n.value.visit_with(n as _, self);
+    }
+
+    fn visit_member_expr(&mut self, n: &MemberExpr, _: &dyn Node) {
+        n.obj.visit_with(n as _, self);
+
+        match &*n.prop {
+            Expr::Ident(i) if !n.computed => self.add(&i.sym, false),
+            Expr::Lit(Lit::Str(s)) if n.computed => self.add(&s.value, true),
+            _ if n.computed => n.prop.visit_with(n as _, self),
+            _ => {}
+        }
+    }
+
+    fn visit_prop(&mut self, n: &Prop, _: &dyn Node) {
+        if let Prop::Shorthand(i) = n {
+            self.add(&i.sym, false);
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
+        match n {
+            PropName::Ident(i) => self.add(&i.sym, false),
+            PropName::Str(s) => self.add(&s.value, true),
+            PropName::Num(..) => {}
+            PropName::Computed(e) => e.visit_with(n as _, self),
+        }
+    }
+}
+
+pub(super) struct Renamer {
+    names: FxHashMap<JsWord, JsWord>,
+}
+
+impl Renamer {
+    fn rename_str(&self, s: &mut Str) {
+        if let Some(name) = self.names.get(&s.value) {
+            *s = Str {
+                span: s.span,
+                value: name.clone(),
+                has_escape: false,
+                raw: None,
+            };
+        }
+    }
+}
+
+impl VisitMut for Renamer {
+    noop_visit_mut_type!();
+
+    fn visit_mut_class_prop(&mut self, n: &mut ClassProp) {
+        n.decorators.visit_mut_with(self);
+        match &mut *n.key {
+            Expr::Ident(i) if !n.computed => {
+                if let Some(name) = self.names.get(&i.sym) {
+                    i.sym = name.clone();
+                }
+            }
+            Expr::Lit(Lit::Str(s)) if !n.computed => self.rename_str(s),
+            _ => n.key.visit_mut_with(self),
+        }
+        n.value.visit_mut_with(self);
+    }
+
+    fn visit_mut_member_expr(&mut self, n: &mut MemberExpr) {
+        n.obj.visit_mut_with(self);
+
+        match &mut *n.prop {
+            Expr::Ident(i) if !n.computed => {
+                if let Some(name) = self.names.get(&i.sym) {
+                    i.sym = name.clone();
+                }
+            }
+            Expr::Lit(Lit::Str(s)) if n.computed => self.rename_str(s),
+            _ if n.computed => n.prop.visit_mut_with(self),
+            _ => {}
+        }
+    }
+
+    fn visit_mut_object_pat_prop(&mut self, n: &mut ObjectPatProp) {
+        n.visit_mut_children_with(self);
+
+        if let ObjectPatProp::Assign(AssignPatProp { span, key, value }) = n {
+            let name = match self.names.get(&key.sym) {
+                Some(name) => name.clone(),
+                None => return,
+            };
+            let value = match value.take() {
+                Some(right) => Pat::Assign(AssignPat {
+                    span: *span,
+                    left: Box::new(Pat::Ident(key.clone())),
+                    right,
+                    type_ann: None,
+                }),
+                None => Pat::Ident(key.clone()),
+            };
+
+            *n = ObjectPatProp::KeyValue(KeyValuePatProp {
+                key: PropName::Ident(Ident::new(name, key.span)),
+                value: Box::new(value),
+            });
+        }
+    }
+
+    fn visit_mut_prop(&mut self, n: &mut Prop) {
+        n.visit_mut_children_with(self);
+
+        if let Prop::Shorthand(i) = n {
+            if let Some(name) = self.names.get(&i.sym) {
+                *n = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(Ident::new(name.clone(), i.span)),
+                    value: Box::new(Expr::Ident(i.clone())),
+                });
+            }
+        }
+    }
+
+    fn visit_mut_prop_name(&mut self, n: &mut PropName) {
+        match n {
+            PropName::Ident(i) => {
+                if let Some(name) = self.names.get(&i.sym) {
+                    i.sym = name.clone();
+                }
+            }
+            PropName::Str(s) => self.rename_str(s),
+            PropName::Num(..) => {}
+            PropName::Computed(e) => e.visit_mut_with(self),
+        }
+    }
+}
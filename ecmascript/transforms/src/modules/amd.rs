@@ -1,23 +1,29 @@
 use super::util::{
-    self, define_es_module, define_property, has_use_strict, initialize_to_undefined,
-    local_name_for_src, make_descriptor, use_strict, Exports, ModulePass, Scope,
+    self, define_es_module, define_property, initialize_to_undefined, local_name_for_src,
+    make_descriptor, use_strict, Exports, ModulePass, Scope,
+};
+use crate::{
+    compat::es2020::export_namespace_from,
+    util::{has_use_strict, prepend_stmts, var::VarCollector, DestructuringFinder, ExprFactory},
 };
-use crate::util::{prepend_stmts, var::VarCollector, DestructuringFinder, ExprFactory};
 use fxhash::FxHashSet;
 use serde::{Deserialize, Serialize};
 use std::iter;
 use swc_atoms::js_word;
-use swc_common::{Mark, Span, DUMMY_SP};
+use swc_common::{chain, Mark, Span, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, VisitWith};
 
 pub fn amd(config: Config) -> impl Fold {
-    Amd {
-        config,
-        in_top_level: Default::default(),
-        scope: Default::default(),
-        exports: Default::default(),
-    }
+    chain!(
+        export_namespace_from(),
+        Amd {
+            config,
+            in_top_level: Default::default(),
+            scope: Default::default(),
+            exports: Default::default(),
+        }
+    )
 }
 
 struct Amd {
@@ -416,7 +422,7 @@ impl Fold for Amd {
         };
 
         let mut factory_params = Vec::with_capacity(self.scope.imports.len() + 1);
-        if has_export {
+        if has_export || self.scope.uses_exports {
             define_deps_arg
                 .elems
                 .push(Some(Lit::Str(quote_str!("exports")).as_arg()));
@@ -504,7 +510,7 @@ impl Fold for Amd {
                 let ty = self.scope.import_types.get(&src);
 
                 if let Some(&wildcard) = ty {
-                    if !self.config.config.no_interop {
+                    if self.config.config.import_interop().needs_helper(wildcard) {
                         let imported = ident.clone();
                         let right = Box::new(Expr::Call(CallExpr {
                             span: DUMMY_SP,
@@ -1,23 +1,29 @@
 pub use super::util::Config;
 use super::util::{
-    define_es_module, define_property, has_use_strict, initialize_to_undefined, make_descriptor,
-    make_require_call, use_strict, ModulePass, Scope,
+    define_es_module, define_property, initialize_to_undefined, make_descriptor, make_require_call,
+    use_strict, ModulePass, Scope,
+};
+use crate::{
+    compat::es2020::export_namespace_from,
+    util::{has_use_strict, var::VarCollector, DestructuringFinder, ExprFactory},
 };
-use crate::util::{var::VarCollector, DestructuringFinder, ExprFactory};
 use fxhash::FxHashSet;
 use swc_atoms::js_word;
-use swc_common::{Mark, Span, DUMMY_SP};
+use swc_common::{chain, Mark, Span, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_utils::ident::IdentLike;
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, VisitWith};
 
 pub fn common_js(root_mark: Mark, config: Config) -> impl Fold {
-    CommonJs {
-        root_mark,
-        config,
-        scope: Default::default(),
-        in_top_level: Default::default(),
-    }
+    chain!(
+        export_namespace_from(),
+        CommonJs {
+            root_mark,
+            config,
+            scope: Default::default(),
+            in_top_level: Default::default(),
+        }
+    )
 }
 
 struct CommonJs {
@@ -514,19 +520,25 @@ impl Fold for CommonJs {
                 Some(import) => {
                     let ty = self.scope.import_types.get(&src);
 
+                    let interop = self.config.import_interop();
+
                     let rhs = match ty {
-                        Some(true) if !self.config.no_interop => Box::new(Expr::Call(CallExpr {
-                            span: DUMMY_SP,
-                            callee: helper!(interop_require_wildcard, "interopRequireWildcard"),
-                            args: vec![require.as_arg()],
-                            type_args: Default::default(),
-                        })),
-                        Some(false) if !self.config.no_interop => Box::new(Expr::Call(CallExpr {
-                            span: DUMMY_SP,
-                            callee: helper!(interop_require_default, "interopRequireDefault"),
-                            args: vec![require.as_arg()],
-                            type_args: Default::default(),
-                        })),
+                        Some(true) if interop.needs_helper(true) => {
+                            Box::new(Expr::Call(CallExpr {
+                                span: DUMMY_SP,
+                                callee: helper!(interop_require_wildcard, "interopRequireWildcard"),
+                                args: vec![require.as_arg()],
+                                type_args: Default::default(),
+                            }))
+                        }
+                        Some(false) if interop.needs_helper(false) => {
+                            Box::new(Expr::Call(CallExpr {
+                                span: DUMMY_SP,
+                                callee: helper!(interop_require_default, "interopRequireDefault"),
+                                args: vec![require.as_arg()],
+                                type_args: Default::default(),
+                            }))
+                        }
                         _ => Box::new(require),
                     };
 
@@ -1,17 +1,24 @@
-use super::util::Scope;
+use super::util::{ImportInterop, Scope};
 use swc_atoms::js_word;
 use swc_common::DUMMY_SP;
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_fold_type, Fold, Node, Visit};
 
 pub fn import_analyzer() -> impl Fold {
+    import_analyzer_with_interop(Default::default())
+}
+
+/// Like [import_analyzer], but only injects the helpers used with `interop`.
+pub fn import_analyzer_with_interop(interop: ImportInterop) -> impl Fold {
     ImportAnalyzer {
+        interop,
         scope: Default::default(),
     }
 }
 
 /// Inject required helpers methods **for** module transform passes.
 struct ImportAnalyzer {
+    interop: ImportInterop,
     scope: Scope,
 }
 
@@ -22,6 +29,10 @@ impl Fold for ImportAnalyzer {
         self.visit_module(&module, &Invalid { span: DUMMY_SP } as _);
 
         for (_, ty) in self.scope.import_types.drain() {
+            if !self.interop.needs_helper(ty) {
+                continue;
+            }
+
             if ty {
                 enable_helper!(interop_require_wildcard);
             } else {
@@ -1,28 +1,37 @@
 use self::config::BuiltConfig;
 pub use self::config::Config;
 use super::util::{
-    self, define_es_module, define_property, has_use_strict, initialize_to_undefined,
-    local_name_for_src, make_descriptor, make_require_call, use_strict, Exports, ModulePass, Scope,
+    self, define_es_module, define_property, initialize_to_undefined, local_name_for_src,
+    make_descriptor, make_require_call, use_strict, Exports, ModulePass, Scope,
+};
+use crate::{
+    compat::es2020::export_namespace_from,
+    util::{
+        has_use_strict, object_lit, prepend_stmts, var::VarCollector, DestructuringFinder,
+        ExprFactory,
+    },
 };
-use crate::util::{prepend_stmts, var::VarCollector, DestructuringFinder, ExprFactory};
 use fxhash::FxHashSet;
 use swc_atoms::js_word;
-use swc_common::{sync::Lrc, Mark, SourceMap, DUMMY_SP};
+use swc_common::{chain, sync::Lrc, Mark, SourceMap, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, VisitWith};
 
 mod config;
 
 pub fn umd(cm: Lrc<SourceMap>, root_mark: Mark, config: Config) -> impl Fold {
-    Umd {
-        config: config.build(cm.clone()),
-        root_mark,
-        cm,
-
-        in_top_level: Default::default(),
-        scope: Default::default(),
-        exports: Default::default(),
-    }
+    chain!(
+        export_namespace_from(),
+        Umd {
+            config: config.build(cm.clone()),
+            root_mark,
+            cm,
+
+            in_top_level: Default::default(),
+            scope: Default::default(),
+            exports: Default::default(),
+        }
+    )
 }
 
 struct Umd {
@@ -413,7 +422,7 @@ impl Fold for Umd {
         let mut factory_params = Vec::with_capacity(self.scope.imports.len() + 1);
         let mut factory_args = Vec::with_capacity(factory_params.capacity());
         let mut global_factory_args = Vec::with_capacity(factory_params.capacity());
-        if has_export {
+        if has_export || self.scope.uses_exports {
             define_deps_arg
                 .elems
                 .push(Some(Lit::Str(quote_str!("exports")).as_arg()));
@@ -509,7 +518,7 @@ impl Fold for Umd {
                     Some(&wildcard) => {
                         let imported = ident.clone();
 
-                        if !self.config.config.no_interop {
+                        if self.config.config.import_interop().needs_helper(wildcard) {
                             let right = Box::new(Expr::Call(CallExpr {
                                 span: DUMMY_SP,
                                 callee: if wildcard {
@@ -628,20 +637,16 @@ impl Fold for Umd {
                                         decls: vec![VarDeclarator {
                                             span: DUMMY_SP,
                                             name: Pat::Ident(quote_ident!("mod")),
-                                            init: Some(Box::new(Expr::Object(ObjectLit {
-                                                span: DUMMY_SP,
-                                                props: vec![PropOrSpread::Prop(Box::new(
-                                                    Prop::KeyValue(KeyValueProp {
-                                                        key: PropName::Ident(quote_ident!(
-                                                            "exports"
-                                                        )),
-                                                        value: Box::new(Expr::Object(ObjectLit {
-                                                            span: DUMMY_SP,
-                                                            props: vec![],
-                                                        })),
+                                            init: Some(Box::new(object_lit(
+                                                DUMMY_SP,
+                                                vec![(
+                                                    quote_ident!("exports"),
+                                                    Expr::Object(ObjectLit {
+                                                        span: DUMMY_SP,
+                                                        props: vec![],
                                                     }),
-                                                ))],
-                                            }))),
+                                                )],
+                                            ))),
                                             definite: false,
                                         }],
                                         declare: false,
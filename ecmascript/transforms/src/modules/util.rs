@@ -31,6 +31,18 @@ pub struct Config {
     pub lazy: Lazy,
     #[serde(default)]
     pub no_interop: bool,
+    /// How imports from CommonJS modules are mapped. Ignored if `no_interop`
+    /// is set.
+    #[serde(default)]
+    pub import_interop: Option<ImportInterop>,
+    /// Keeps `this` at the top level of the module instead of replacing it
+    /// with `undefined`.
+    #[serde(default)]
+    pub allow_top_level_this: bool,
+    /// Replaces `this` at the top level of the module with `exports` instead
+    /// of `undefined`. Ignored if `allow_top_level_this` is set.
+    #[serde(default)]
+    pub loose: bool,
 }
 
 impl Default for Config {
@@ -40,6 +52,21 @@ impl Default for Config {
             strict_mode: default_strict_mode(),
             lazy: Lazy::default(),
             no_interop: false,
+            import_interop: None,
+            allow_top_level_this: false,
+            loose: false,
+        }
+    }
+}
+
+impl Config {
+    /// The interop used for imports, which is [ImportInterop::None] if
+    /// `no_interop` is set.
+    pub fn import_interop(&self) -> ImportInterop {
+        if self.no_interop {
+            ImportInterop::None
+        } else {
+            self.import_interop.unwrap_or_default()
         }
     }
 }
@@ -48,6 +75,38 @@ const fn default_strict_mode() -> bool {
     true
 }
 
+/// Mapping of `import foo from "cjs"` to CommonJS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportInterop {
+    /// `foo` is `_interopRequireDefault(require("cjs")).default`, so it's
+    /// `module.exports` unless the module has `__esModule` set.
+    Babel,
+    /// `foo` is `require("cjs")`, like in node and webpack.
+    Node,
+    /// `foo` is `require("cjs").default`.
+    None,
+}
+
+impl Default for ImportInterop {
+    fn default() -> Self {
+        ImportInterop::Babel
+    }
+}
+
+impl ImportInterop {
+    /// Returns true if the required module should be wrapped with
+    /// `_interopRequireWildcard` (if `wildcard` is true) or
+    /// `_interopRequireDefault`.
+    pub fn needs_helper(self, wildcard: bool) -> bool {
+        match self {
+            ImportInterop::Babel => true,
+            ImportInterop::Node => wildcard,
+            ImportInterop::None => false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged, deny_unknown_fields, rename_all = "camelCase")]
 pub enum Lazy {
@@ -118,22 +177,22 @@ pub(super) struct Scope {
     /// This is required to handle
     /// `export * from 'foo';`
     pub lazy_blacklist: HashSet<JsWord>,
+
+    /// True if `this` at the top level is replaced with `exports`, which
+    /// requires `exports` even if nothing is exported.
+    pub uses_exports: bool,
 }
 
 impl Scope {
     ///
     /// ```js
-    /// Object.keys(_foo).forEach(function (key) {
-    ///   if (key === "default" || key === "__esModule") return;
-    ///   Object.defineProperty(exports, key, {
-    ///     enumerable: true,
-    ///     get: function () {
-    ///       return _foo[key];
-    ///     }
-    ///   });
-    /// })
+    /// _exportStar(_foo, exports, _exportNames);
     /// ```
     ///
+    /// The helper defines a getter on `exports` for each name exported by
+    /// `_foo`, except `default`, names in `_exportNames` and names which are
+    /// already exported with the same value.
+    ///
     /// # Parameters
     /// - `exported_names` Ident of the object literal.
     pub fn handle_export_all(
@@ -144,101 +203,13 @@ impl Scope {
     ) -> Stmt {
         let imported = self.import_to_export(&export.src, true).unwrap();
 
-        let key_ident = private_ident!("key");
-
-        let function = Function {
-            span: DUMMY_SP,
-            is_async: false,
-            is_generator: false,
-            decorators: Default::default(),
-            params: vec![Param {
-                span: DUMMY_SP,
-                decorators: Default::default(),
-                pat: Pat::Ident(key_ident.clone()),
-            }],
-            body: Some(BlockStmt {
-                span: DUMMY_SP,
-                stmts: iter::once(Stmt::If(IfStmt {
-                    span: DUMMY_SP,
-                    // key === "default" || key === "__esModule"
-                    test: Box::new(
-                        key_ident
-                            .clone()
-                            .make_eq(Lit::Str(quote_str!("default")))
-                            .make_bin(
-                                op!("||"),
-                                key_ident
-                                    .clone()
-                                    .make_eq(Lit::Str(quote_str!("__esModule"))),
-                            ),
-                    ),
-                    cons: Box::new(Stmt::Return(ReturnStmt {
-                        span: DUMMY_SP,
-                        arg: None,
-                    })),
-                    alt: None,
-                }))
-                .chain({
-                    // We should skip if the file explicitly exports
-                    if let Some(exported_names) = exported_names {
-                        // `if (Object.prototype.hasOwnProperty.call(_exportNames, key))
-                        //      return;`
-                        Some(Stmt::If(IfStmt {
-                            span: DUMMY_SP,
-                            test: Box::new(
-                                CallExpr {
-                                    span: DUMMY_SP,
-                                    callee: member_expr!(
-                                        DUMMY_SP,
-                                        Object.prototype.hasOwnProperty.call
-                                    )
-                                    .as_callee(),
-                                    args: vec![exported_names.as_arg(), key_ident.clone().as_arg()],
-                                    type_args: Default::default(),
-                                }
-                                .into(),
-                            ),
-                            cons: Box::new(Stmt::Return(ReturnStmt {
-                                span: DUMMY_SP,
-                                arg: None,
-                            })),
-                            alt: None,
-                        }))
-                    } else {
-                        None
-                    }
-                })
-                .chain(iter::once(
-                    define_property(vec![
-                        exports.as_arg(),
-                        key_ident.clone().as_arg(),
-                        make_descriptor(Box::new(imported.clone().computed_member(key_ident)))
-                            .as_arg(),
-                    ])
-                    .into_stmt(),
-                ))
-                .collect(),
-            }),
-            return_type: Default::default(),
-            type_params: Default::default(),
-        };
-
         CallExpr {
             span: DUMMY_SP,
-            // Object.keys(_foo).forEach
-            callee: CallExpr {
-                span: DUMMY_SP,
-                callee: member_expr!(DUMMY_SP, Object.keys).as_callee(),
-                args: vec![imported.as_arg()],
-                type_args: Default::default(),
-            }
-            .make_member(quote_ident!("forEach"))
-            .as_callee(),
-            args: vec![FnExpr {
-                ident: None,
-                function,
-            }
-            .as_arg()],
+            callee: helper!(export_star, "exportStar"),
+            args: iter::once(imported.as_arg())
+                .chain(iter::once(exports.as_arg()))
+                .chain(exported_names.map(|names| names.as_arg()))
+                .collect(),
             type_args: Default::default(),
         }
         .into_stmt()
@@ -421,6 +392,13 @@ impl Scope {
                 if *prop == js_word!("") {
                     // import * as foo from 'foo';
                     Ok(obj)
+                } else if prop == js_word!("default")
+                    && folder.config().import_interop() == ImportInterop::Node
+                    && folder.scope().import_types.get(&src) != Some(&true)
+                {
+                    // import foo from 'foo';
+                    //   -> require('foo')
+                    Ok(obj)
                 } else {
                     Ok(obj.make_member(Ident::new(prop, DUMMY_SP)))
                 }
@@ -463,7 +441,14 @@ impl Scope {
         }
 
         match expr {
-            Expr::This(ThisExpr { span }) if top_level => *undefined(span),
+            Expr::This(ThisExpr { span }) if top_level && !folder.config().allow_top_level_this => {
+                if folder.config().loose {
+                    folder.scope_mut().uses_exports = true;
+                    Expr::Ident(exports)
+                } else {
+                    *undefined(span)
+                }
+            }
             Expr::Ident(i) => match Self::fold_ident(folder, top_level, i) {
                 Ok(expr) => expr,
                 Err(ident) => Expr::Ident(ident),
@@ -534,6 +519,7 @@ impl Scope {
                                     right: Box::new(Expr::Lit(Lit::Num(Number {
                                         span: DUMMY_SP,
                                         value: 1.0,
+                                        raw: None,
                                     }))),
                                 })),
                             }))
@@ -693,6 +679,7 @@ pub(super) fn make_require_call(mark: Mark, src: JsWord) -> Expr {
             span: DUMMY_SP,
             value: src,
             has_escape: false,
+            raw: None,
         })
         .as_arg()],
 
@@ -748,22 +735,6 @@ pub(super) fn define_es_module(exports: Ident) -> Stmt {
     .into_stmt()
 }
 
-pub(super) fn has_use_strict(stmts: &[ModuleItem]) -> bool {
-    if stmts.is_empty() {
-        return false;
-    }
-
-    match &*stmts.first().unwrap() {
-        ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match &**expr {
-            Expr::Lit(Lit::Str(Str { ref value, .. })) => return &*value == "use strict",
-            _ => {}
-        },
-        _ => {}
-    }
-
-    false
-}
-
 pub(super) fn use_strict() -> Stmt {
     Lit::Str(quote_str!("use strict")).into_stmt()
 }
@@ -850,6 +821,23 @@ macro_rules! mark_as_nested {
         mark_as_nested!(fold_constructor, Constructor);
         mark_as_nested!(fold_setter_prop, SetterProp);
         mark_as_nested!(fold_getter_prop, GetterProp);
+        mark_as_nested!(fold_private_prop, PrivateProp);
+
+        fn fold_class_prop(&mut self, p: ClassProp) -> ClassProp {
+            // Computed keys are evaluated in the scope of the class.
+            let key = if p.computed {
+                p.key.fold_with(self)
+            } else {
+                p.key
+            };
+
+            let old = self.in_top_level;
+            self.in_top_level = false.into();
+            let value = p.value.fold_with(self);
+            self.in_top_level = old;
+
+            ClassProp { key, value, ..p }
+        }
     };
 
     ($name:ident, $T:tt) => {
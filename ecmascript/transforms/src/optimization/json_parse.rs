@@ -68,6 +68,7 @@ impl Fold for JsonParse {
                                 })
                                 .into(),
                             has_escape: false,
+                            raw: None,
                         })
                         .as_arg()],
                         type_args: Default::default(),
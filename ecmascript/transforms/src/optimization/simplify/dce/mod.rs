@@ -11,7 +11,10 @@ use swc_common::{
     Mark, Span, Spanned,
 };
 use swc_ecma_ast::*;
-use swc_ecma_utils::{find_ids, ident::IdentLike, Id, StmtLike};
+use swc_ecma_utils::{
+    drop_unreachable_code, find_ids, find_no_side_effect_fns, ident::IdentLike, DynamicScopes, Id,
+    NoSideEffectFnFinder, SideEffectCtx, StmtLike,
+};
 use swc_ecma_visit::{as_folder, noop_visit_mut_type, VisitMut, VisitMutWith, VisitWith};
 
 macro_rules! preserve {
@@ -33,6 +36,13 @@ pub struct Config<'a> {
     ///
     /// Should not be `Mark::root()`. Used to reduce allocation of [Mark].
     pub used_mark: Mark,
+
+    /// Calls annotated with `/*#__PURE__*/` are removed if they are unused and
+    /// this has comments.
+    ///
+    /// If this has comments but no functions annotated with
+    /// `/*#__NO_SIDE_EFFECTS__*/`, they are found in the module.
+    pub side_effect_ctx: SideEffectCtx<'a>,
 }
 
 impl Default for Config<'_> {
@@ -40,6 +50,7 @@ impl Default for Config<'_> {
         Self {
             used: None,
             used_mark: Mark::fresh(Mark::root()),
+            side_effect_ctx: Default::default(),
         }
     }
 }
@@ -61,7 +72,8 @@ pub fn dce<'a>(config: Config<'a>) -> impl RepeatedJsPass + 'a {
             changed: false,
             marking_phase: false,
             decl_dropping_phase: false,
-            cur_defining: Default::default()
+            cur_defining: Default::default(),
+            no_side_effect_fns: Default::default(),
         }),
         as_folder(UsedMarkRemover { used_mark })
     )
@@ -119,6 +131,9 @@ struct Dce<'a> {
     /// Reference to function itself in a function should not make function
     /// preserved.
     cur_defining: FxHashSet<Id>,
+
+    /// Functions annotated with `/*#__NO_SIDE_EFFECTS__*/`.
+    no_side_effect_fns: Vec<Id>,
 }
 
 impl CompilerPass for Dce<'_> {
@@ -615,10 +630,38 @@ impl VisitMut for Dce<'_> {
         }
     }
 
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        if drop_unreachable_code(n) {
+            self.dropped = true;
+        }
+
+        // Bindings may be used by `eval`, or by identifiers in `with`.
+        if DynamicScopes::analyze(&*n).is_top_level_dynamic() {
+            return;
+        }
+
+        self.find_no_side_effect_fns(&*n);
+        n.visit_mut_children_with(self);
+    }
+
     fn visit_mut_module_items(&mut self, n: &mut Vec<ModuleItem>) {
         self.visit_mut_stmt_like(n)
     }
 
+    fn visit_mut_script(&mut self, n: &mut Script) {
+        if drop_unreachable_code(n) {
+            self.dropped = true;
+        }
+
+        // Bindings may be used by `eval`, or by identifiers in `with`.
+        if DynamicScopes::analyze(&*n).is_top_level_dynamic() {
+            return;
+        }
+
+        self.find_no_side_effect_fns(&*n);
+        n.visit_mut_children_with(self);
+    }
+
     fn visit_mut_stmts(&mut self, n: &mut Vec<Stmt>) {
         if !self.decl_dropping_phase {
             n.visit_mut_children_with(self);
@@ -629,6 +672,18 @@ impl VisitMut for Dce<'_> {
 }
 
 impl Dce<'_> {
+    fn find_no_side_effect_fns<N>(&mut self, node: &N)
+    where
+        N: for<'any> VisitWith<NoSideEffectFnFinder<'any>>,
+    {
+        let ctx = self.config.side_effect_ctx;
+        if let Some(comments) = ctx.comments {
+            if ctx.no_side_effect_fns.is_empty() {
+                self.no_side_effect_fns = find_no_side_effect_fns(node, comments);
+            }
+        }
+    }
+
     fn visit_mut_stmt_like<T>(&mut self, items: &mut Vec<T>)
     where
         T: Debug + StmtLike + VisitMutWith<Self> + Spanned + std::fmt::Debug,
@@ -3,7 +3,7 @@ use fxhash::FxHashSet;
 use swc_atoms::JsWord;
 use swc_common::DUMMY_SP;
 use swc_ecma_ast::*;
-use swc_ecma_utils::{ident::IdentLike, ExprExt, Id};
+use swc_ecma_utils::{ident::IdentLike, ExprExt, Id, SideEffectCtx};
 use swc_ecma_visit::{Node, Visit, VisitWith};
 
 pub(super) struct ImportDetector {
@@ -34,9 +34,15 @@ impl Dce<'_> {
             }
         }
 
+        let mut ctx = self.config.side_effect_ctx;
+        if ctx.no_side_effect_fns.is_empty() {
+            ctx.no_side_effect_fns = &self.no_side_effect_fns;
+        }
+
         let mut v = SideEffectVisitor {
             included: &mut self.included,
             exports: self.config.used.as_ref().map(|v| &**v),
+            ctx,
             found: false,
         };
 
@@ -61,6 +67,7 @@ impl SideEffectVisitor<'_> {
 pub(super) struct SideEffectVisitor<'a> {
     included: &'a mut FxHashSet<Id>,
     exports: Option<&'a [Id]>,
+    ctx: SideEffectCtx<'a>,
     found: bool,
 }
 
@@ -142,6 +149,11 @@ impl Visit for SideEffectVisitor<'_> {
             _ => {}
         }
 
+        if self.ctx.is_pure_call(node.span, &node.callee) {
+            node.args.visit_with(node as _, self);
+            return;
+        }
+
         self.found = true;
     }
 
@@ -237,11 +249,16 @@ impl Visit for SideEffectVisitor<'_> {
         self.found = true
     }
 
-    fn visit_new_expr(&mut self, _: &NewExpr, _: &dyn Node) {
+    fn visit_new_expr(&mut self, node: &NewExpr, _: &dyn Node) {
         if self.found {
             return;
         }
 
+        if self.ctx.is_pure_annotated(node.span) {
+            node.args.visit_with(node as _, self);
+            return;
+        }
+
         self.found = true;
     }
 
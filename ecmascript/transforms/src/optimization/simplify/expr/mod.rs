@@ -86,6 +86,7 @@ impl SimplifyExpr {
                 KnownOp::Len => Expr::Lit(Lit::Num(Number {
                     value: value.chars().count() as f64,
                     span,
+                    raw: None,
                 })),
 
                 // 'foo'[1]
@@ -102,6 +103,7 @@ impl SimplifyExpr {
                                 .into(),
                             span,
                             has_escape: false,
+                            raw: None,
                         }))
                     }
                 }
@@ -132,6 +134,7 @@ impl SimplifyExpr {
                 Expr::Lit(Lit::Num(Number {
                     value: elems.len() as _,
                     span,
+                    raw: None,
                 }))
             }
 
@@ -278,7 +281,11 @@ impl SimplifyExpr {
                     Known(v) => {
                         return preserve_effects(
                             span,
-                            Expr::Lit(Lit::Num(Number { value: v, span })),
+                            Expr::Lit(Lit::Num(Number {
+                                value: v,
+                                span,
+                                raw: None,
+                            })),
                             { iter::once(left).chain(iter::once(right)) },
                         );
                     }
@@ -300,6 +307,7 @@ impl SimplifyExpr {
                             span,
                             // TODO
                             has_escape: false,
+                            raw: None,
                         }));
                     }
                 }
@@ -328,6 +336,7 @@ impl SimplifyExpr {
                                         span,
                                         // TODO
                                         has_escape: false,
+                                        raw: None,
                                     }));
                                 }
                             }
@@ -351,7 +360,11 @@ impl SimplifyExpr {
                                 Known(v) => {
                                     return preserve_effects(
                                         span,
-                                        Expr::Lit(Lit::Num(Number { value: v, span })),
+                                        Expr::Lit(Lit::Num(Number {
+                                            value: v,
+                                            span,
+                                            raw: None,
+                                        })),
                                         iter::once(left).chain(iter::once(right)),
                                     );
                                 }
@@ -539,7 +552,11 @@ impl SimplifyExpr {
                                 span,
                                 left: left_lhs,
                                 op: left_op,
-                                right: Box::new(Expr::Lit(Lit::Num(Number { value, span }))),
+                                right: Box::new(Expr::Lit(Lit::Num(Number {
+                                    value,
+                                    span,
+                                    raw: None,
+                                }))),
                             });
                         }
                     }
@@ -614,6 +631,7 @@ impl SimplifyExpr {
             span,
             value: val.into(),
             has_escape: false,
+            raw: None,
         }))
     }
 
@@ -632,7 +650,11 @@ impl SimplifyExpr {
                 Known(v) => {
                     return preserve_effects(
                         span,
-                        Expr::Lit(Lit::Num(Number { value: v, span })),
+                        Expr::Lit(Lit::Num(Number {
+                            value: v,
+                            span,
+                            raw: None,
+                        })),
                         iter::once(arg),
                     );
                 }
@@ -650,7 +672,11 @@ impl SimplifyExpr {
                     ..
                 }) => return *arg,
                 Expr::Lit(Lit::Num(Number { value: f, .. })) => {
-                    return Expr::Lit(Lit::Num(Number { value: -f, span }));
+                    return Expr::Lit(Lit::Num(Number {
+                        value: -f,
+                        span,
+                        raw: None,
+                    }));
                 }
                 _ => {
 
@@ -664,6 +690,7 @@ impl SimplifyExpr {
                     arg: Box::new(Expr::Lit(Lit::Num(Number {
                         value: 0.0,
                         span: arg.span(),
+                        raw: None,
                     }))),
                     span,
                 });
@@ -679,6 +706,7 @@ impl SimplifyExpr {
                             } else {
                                 !(value as u32) as i32 as f64
                             },
+                            raw: None,
                         }));
                     }
                     // TODO: Report error
@@ -906,7 +934,11 @@ impl SimplifyExpr {
                 self.perform_abstract_eq_cmp(
                     span,
                     left,
-                    &Expr::Lit(Lit::Num(Number { value: rv, span })),
+                    &Expr::Lit(Lit::Num(Number {
+                        value: rv,
+                        span,
+                        raw: None,
+                    })),
                 )
             }
 
@@ -914,7 +946,11 @@ impl SimplifyExpr {
                 let lv = try_val!(left.as_number());
                 self.perform_abstract_eq_cmp(
                     span,
-                    &Expr::Lit(Lit::Num(Number { value: lv, span })),
+                    &Expr::Lit(Lit::Num(Number {
+                        value: lv,
+                        span,
+                        raw: None,
+                    })),
                     right,
                 )
             }
@@ -1096,6 +1132,7 @@ impl Fold for SimplifyExpr {
                             span: e.span(),
                             value: value.into(),
                             has_escape: false,
+                            raw: None,
                         }));
                     }
                     unreachable!()
@@ -1,16 +1,15 @@
 use self::scope::{Scope, ScopeKind, VarType};
-use crate::{
-    ext::{MapWithMut, PatOrExprExt},
-    pass::RepeatedJsPass,
-    scope::IdentType,
-};
-use std::borrow::Cow;
+use crate::{ext::PatOrExprExt, pass::RepeatedJsPass, scope::IdentType};
+use std::{borrow::Cow, rc::Rc};
 use swc_common::{
     pass::{CompilerPass, Repeated},
+    util::take::Take,
     DUMMY_SP,
 };
 use swc_ecma_ast::*;
-use swc_ecma_utils::{contains_this_expr, find_ids, ident::IdentLike, undefined, Id};
+use swc_ecma_utils::{
+    contains_this_expr, find_ids, ident::IdentLike, undefined, DynamicScopes, Id,
+};
 use swc_ecma_visit::{
     as_folder, noop_visit_mut_type, Node, Visit, VisitMut, VisitMutWith, VisitWith,
 };
@@ -43,6 +42,8 @@ pub fn inlining(_: Config) -> impl RepeatedJsPass + 'static {
         ident_type: IdentType::Ref,
         in_test: false,
         pat_mode: PatFoldingMode::VarDecl,
+        dynamic_scopes: Default::default(),
+        dynamic: false,
     })
 }
 
@@ -65,7 +66,6 @@ impl Repeated for Inlining<'_> {
 
     fn reset(&mut self) {
         self.changed = false;
-        self.is_first_run = false;
     }
 }
 
@@ -78,6 +78,10 @@ struct Inlining<'a> {
     ident_type: IdentType,
     in_test: bool,
     pat_mode: PatFoldingMode,
+    dynamic_scopes: Rc<DynamicScopes>,
+    /// True if bindings of the current function may be accessed by `eval` or
+    /// `with`, so they are not inlined.
+    dynamic: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -113,7 +117,11 @@ impl VisitMut for Inlining<'_> {
     }
 
     fn visit_mut_arrow_expr(&mut self, node: &mut ArrowExpr) {
-        self.visit_with_child(ScopeKind::Fn { named: false }, node)
+        let dynamic = self.dynamic_scopes.is_dynamic(node.span);
+        self.with_child(ScopeKind::Fn { named: false }, |child| {
+            child.dynamic = dynamic;
+            node.visit_mut_children_with(child);
+        });
     }
 
     fn visit_mut_assign_expr(&mut self, e: &mut AssignExpr) {
@@ -365,7 +373,9 @@ impl VisitMut for Inlining<'_> {
             );
         }
 
+        let dynamic = self.dynamic_scopes.is_dynamic(node.function.span);
         self.with_child(ScopeKind::Fn { named: true }, |child| {
+            child.dynamic = dynamic;
             child.pat_mode = PatFoldingMode::Param;
             node.function.params.visit_mut_with(child);
             match &mut node.function.body {
@@ -474,7 +484,9 @@ impl VisitMut for Inlining<'_> {
     }
 
     fn visit_mut_function(&mut self, node: &mut Function) {
+        let dynamic = self.dynamic_scopes.is_dynamic(node.span);
         self.with_child(ScopeKind::Fn { named: false }, move |child| {
+            child.dynamic = dynamic;
             child.pat_mode = PatFoldingMode::Param;
             node.params.visit_mut_with(child);
             match &mut node.body {
@@ -605,7 +617,7 @@ impl VisitMut for Inlining<'_> {
                             if (e.is_lit() || e.is_ident())
                                 && self.var_decl_kind == VarDeclKind::Const =>
                         {
-                            if self.is_first_run {
+                            if self.is_first_run && !self.dynamic {
                                 self.scope
                                     .constants
                                     .insert(name.to_id(), Some((**e).clone()));
@@ -740,6 +752,15 @@ impl VisitMut for Inlining<'_> {
         self.visit_with_child(ScopeKind::Loop, &mut node.body);
     }
 
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        self.dynamic_scopes = Rc::new(DynamicScopes::analyze(&*m));
+        self.dynamic = self.dynamic_scopes.is_top_level_dynamic();
+
+        m.visit_mut_children_with(self);
+        // `reset` is called before the first run, so it can't be used for this.
+        self.is_first_run = false;
+    }
+
     fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
         let old_phase = self.phase;
 
@@ -755,6 +776,14 @@ impl VisitMut for Inlining<'_> {
         self.phase = old_phase;
     }
 
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        self.dynamic_scopes = Rc::new(DynamicScopes::analyze(&*s));
+        self.dynamic = self.dynamic_scopes.is_top_level_dynamic();
+
+        s.visit_mut_children_with(self);
+        self.is_first_run = false;
+    }
+
     fn visit_mut_stmts(&mut self, items: &mut Vec<Stmt>) {
         let old_phase = self.phase;
 
@@ -49,6 +49,8 @@ impl Inlining<'_> {
                 ident_type: self.ident_type,
                 pat_mode: self.pat_mode,
                 in_test: self.in_test,
+                dynamic_scopes: self.dynamic_scopes.clone(),
+                dynamic: self.dynamic,
             };
 
             op(&mut child);
@@ -135,7 +137,8 @@ impl Inlining<'_> {
             _ => None,
         };
 
-        let is_inline_prevented = self.scope.should_prevent_inline_because_of_scope(&id)
+        let is_inline_prevented = self.dynamic
+            || self.scope.should_prevent_inline_because_of_scope(&id)
             || match init {
                 Some(ref e) => self.scope.is_inline_prevented(&e),
                 _ => false,
@@ -1,7 +1,7 @@
-use swc_common::pass::CompilerPass;
+use swc_common::pass::{AndThen, CompilerPass};
 pub use swc_common::pass::{Optional, Repeated};
-use swc_ecma_ast::{Module, Script};
-use swc_ecma_visit::Fold;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
 
 pub fn noop() -> impl Fold {
     Noop
@@ -26,3 +26,201 @@ impl<T: ?Sized> JsPass for T where T: CompilerPass + Fold {}
 pub trait RepeatedJsPass: Repeated + JsPass {}
 
 impl<T: ?Sized> RepeatedJsPass for T where T: Repeated + JsPass {}
+
+/// A transform which only rewrites a node after its children are transformed.
+///
+/// Leaf transforms don't traverse the ast by themselves, so multiple leaf
+/// transforms chained with `chain!` can be [fused] to share a single
+/// traversal.
+pub trait LeafTransform {
+    #[inline(always)]
+    fn transform_expr(&mut self, e: Expr) -> Expr {
+        e
+    }
+
+    #[inline(always)]
+    fn transform_member_expr(&mut self, e: MemberExpr) -> MemberExpr {
+        e
+    }
+
+    #[inline(always)]
+    fn transform_prop(&mut self, p: Prop) -> Prop {
+        p
+    }
+
+    #[inline(always)]
+    fn transform_prop_name(&mut self, n: PropName) -> PropName {
+        n
+    }
+}
+
+impl<A, B> LeafTransform for AndThen<A, B>
+where
+    A: LeafTransform,
+    B: LeafTransform,
+{
+    fn transform_expr(&mut self, e: Expr) -> Expr {
+        let e = self.first.transform_expr(e);
+        self.second.transform_expr(e)
+    }
+
+    fn transform_member_expr(&mut self, e: MemberExpr) -> MemberExpr {
+        let e = self.first.transform_member_expr(e);
+        self.second.transform_member_expr(e)
+    }
+
+    fn transform_prop(&mut self, p: Prop) -> Prop {
+        let p = self.first.transform_prop(p);
+        self.second.transform_prop(p)
+    }
+
+    fn transform_prop_name(&mut self, n: PropName) -> PropName {
+        let n = self.first.transform_prop_name(n);
+        self.second.transform_prop_name(n)
+    }
+}
+
+impl<V> LeafTransform for Optional<V>
+where
+    V: LeafTransform,
+{
+    fn transform_expr(&mut self, e: Expr) -> Expr {
+        if self.enabled {
+            self.visitor.transform_expr(e)
+        } else {
+            e
+        }
+    }
+
+    fn transform_member_expr(&mut self, e: MemberExpr) -> MemberExpr {
+        if self.enabled {
+            self.visitor.transform_member_expr(e)
+        } else {
+            e
+        }
+    }
+
+    fn transform_prop(&mut self, p: Prop) -> Prop {
+        if self.enabled {
+            self.visitor.transform_prop(p)
+        } else {
+            p
+        }
+    }
+
+    fn transform_prop_name(&mut self, n: PropName) -> PropName {
+        if self.enabled {
+            self.visitor.transform_prop_name(n)
+        } else {
+            n
+        }
+    }
+}
+
+/// Runs `t` in a single traversal of the ast.
+///
+/// # Example
+///
+/// ```
+/// use swc_common::chain;
+/// use swc_ecma_transforms::{compat::es3, pass::fused};
+///
+/// // Visits each node once, instead of traversing the ast twice.
+/// let pass = fused(chain!(
+///     es3::property_literals(),
+///     es3::member_expression_literals()
+/// ));
+/// ```
+pub fn fused<T>(t: T) -> Fused<T>
+where
+    T: LeafTransform,
+{
+    Fused(t)
+}
+
+/// See [fused].
+#[derive(Debug, Clone, Copy)]
+pub struct Fused<T>(T);
+
+impl<T> LeafTransform for Fused<T>
+where
+    T: LeafTransform,
+{
+    fn transform_expr(&mut self, e: Expr) -> Expr {
+        self.0.transform_expr(e)
+    }
+
+    fn transform_member_expr(&mut self, e: MemberExpr) -> MemberExpr {
+        self.0.transform_member_expr(e)
+    }
+
+    fn transform_prop(&mut self, p: Prop) -> Prop {
+        self.0.transform_prop(p)
+    }
+
+    fn transform_prop_name(&mut self, n: PropName) -> PropName {
+        self.0.transform_prop_name(n)
+    }
+}
+
+impl<T> Fold for Fused<T>
+where
+    T: LeafTransform,
+{
+    noop_fold_type!();
+
+    fn fold_expr(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children_with(self);
+        self.0.transform_expr(e)
+    }
+
+    fn fold_member_expr(&mut self, e: MemberExpr) -> MemberExpr {
+        let e = e.fold_children_with(self);
+        self.0.transform_member_expr(e)
+    }
+
+    fn fold_prop(&mut self, p: Prop) -> Prop {
+        let p = p.fold_children_with(self);
+        self.0.transform_prop(p)
+    }
+
+    fn fold_prop_name(&mut self, n: PropName) -> PropName {
+        let n = n.fold_children_with(self);
+        self.0.transform_prop_name(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compat::{es2015, es3};
+    use swc_common::chain;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| fused(chain!(
+            es2015::sticky_regex(),
+            es2015::shorthand(),
+            es3::property_literals(),
+            es3::member_expression_literals()
+        )),
+        fused_leaf_transforms,
+        "var o = { a, 'b': /x/y, c() { return this.default; } };",
+        "var o = {
+            a: a,
+            b: new RegExp('x', 'y'),
+            c: function() { return this['default']; }
+        };"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| fused(chain!(
+            Optional::new(es2015::sticky_regex(), false),
+            Optional::new(es2015::shorthand(), true)
+        )),
+        fused_optional,
+        "var o = { a, b: /x/y };",
+        "var o = { a: a, b: /x/y };"
+    );
+}
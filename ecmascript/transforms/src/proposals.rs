@@ -1,8 +1,17 @@
 pub use self::{
-    decorators::decorators, export_default_from::export_default_from,
-    import_assertions::import_assertions,
+    decorators::decorators, do_expressions::do_expressions,
+    export_default_from::export_default_from, function_bind::function_bind,
+    import_assertions::import_assertions, partial_application::partial_application,
+    pipeline_operator::pipeline_operator, record_and_tuple::record_and_tuple,
+    throw_expressions::throw_expressions,
 };
 
 pub mod decorators;
+mod do_expressions;
 mod export_default_from;
+mod function_bind;
 mod import_assertions;
+mod partial_application;
+mod pipeline_operator;
+pub mod record_and_tuple;
+mod throw_expressions;
@@ -126,7 +126,7 @@ impl Fold for Decorators {
                 }
 
                 let decorate_call =
-                    self.fold_class_inner(ident.unwrap_or_else(|| quote_ident!("_class")), class);
+                    self.fold_class_inner(ident.unwrap_or_else(|| private_ident!("_class")), class);
 
                 decorate_call
             }
@@ -144,7 +144,7 @@ impl Fold for Decorators {
                 ..
             }) => {
                 let decorate_call = Box::new(
-                    self.fold_class_inner(ident.unwrap_or_else(|| quote_ident!("_class")), class),
+                    self.fold_class_inner(ident.unwrap_or_else(|| private_ident!("_class")), class),
                 );
 
                 ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
@@ -428,6 +428,7 @@ impl Decorators {
                             span: method.key.id.span,
                             value: method.key.id.sym,
                             has_escape: false,
+                            raw: None,
                         })));
                         fold_method!(method, Some(fn_name), key_prop_value)
                     }
@@ -438,6 +439,7 @@ impl Decorators {
                                 span: i.span,
                                 value: i.sym,
                                 has_escape: false,
+                                raw: None,
                             }))),
                             _ => prop.key,
                         };
@@ -216,6 +216,9 @@ impl Legacy {
             c = c.fold_with(&mut ParamMetadata).fold_with(&mut Metadata {
                 class_name: i.as_ref(),
             });
+        } else {
+            // Decorators of method parameters are handled below.
+            ParamMetadata.handle_constructor_params(&mut c.class);
         }
 
         let cls_ident = private_ident!("_class");
@@ -322,6 +325,7 @@ impl Legacy {
                                     Lit::Num(Number {
                                         span: param.span,
                                         value: index as _,
+                                        raw: None,
                                     })
                                     .as_arg(),
                                 ],
@@ -423,6 +427,7 @@ impl Legacy {
                         span: i.span,
                         value: i.sym.clone(),
                         has_escape: false,
+                        raw: None,
                     }))),
                     _ => p.key.clone(),
                 };
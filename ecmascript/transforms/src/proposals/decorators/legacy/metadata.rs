@@ -1,8 +1,11 @@
-use swc_common::{util::move_map::MoveMap, Spanned, DUMMY_SP};
+use std::mem::take;
+use swc_common::{Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_utils::{undefined, ExprFactory};
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
 
+/// Converts parameter decorators to `_tsParam` calls on the class or method.
+///
 /// https://github.com/leonardfactory/babel-plugin-transform-typescript-metadata/blob/master/src/parameter/parameterVisitor.ts
 pub(super) struct ParamMetadata;
 
@@ -11,43 +14,14 @@ impl Fold for ParamMetadata {
 
     fn fold_class(&mut self, mut cls: Class) -> Class {
         cls = cls.fold_children_with(self);
-        let mut decorators = cls.decorators;
-
-        cls.body = cls.body.move_map(|m| match m {
-            ClassMember::Constructor(mut c) => {
-                for (idx, param) in c.params.iter_mut().enumerate() {
-                    //
-                    match param {
-                        ParamOrTsParamProp::TsParamProp(p) => {
-                            for decorator in p.decorators.drain(..) {
-                                let new_dec =
-                                    self.create_param_decorator(idx, decorator.expr, true);
-                                decorators.push(new_dec);
-                            }
-                        }
-                        ParamOrTsParamProp::Param(param) => {
-                            for decorator in param.decorators.drain(..) {
-                                let new_dec =
-                                    self.create_param_decorator(idx, decorator.expr, true);
-                                decorators.push(new_dec);
-                            }
-                        }
-                    }
-                }
-
-                ClassMember::Constructor(c)
-            }
-            _ => m,
-        });
-        cls.decorators = decorators;
-
+        self.handle_constructor_params(&mut cls);
         cls
     }
 
     fn fold_class_method(&mut self, mut m: ClassMethod) -> ClassMethod {
         for (idx, param) in m.function.params.iter_mut().enumerate() {
             for decorator in param.decorators.drain(..) {
-                let new_dec = self.create_param_decorator(idx, decorator.expr, false);
+                let new_dec = self.create_param_decorator(idx, decorator.expr);
                 m.function.decorators.push(new_dec);
             }
         }
@@ -57,60 +31,46 @@ impl Fold for ParamMetadata {
 }
 
 impl ParamMetadata {
-    fn create_param_decorator(
-        &self,
-        param_index: usize,
-        decorator_expr: Box<Expr>,
-        is_constructor: bool,
-    ) -> Decorator {
+    /// Moves decorators of constructor parameters to the class.
+    pub(super) fn handle_constructor_params(&self, cls: &mut Class) {
+        let mut decorators = take(&mut cls.decorators);
+
+        for m in &mut cls.body {
+            if let ClassMember::Constructor(c) = m {
+                for (idx, param) in c.params.iter_mut().enumerate() {
+                    let param_decorators = match param {
+                        ParamOrTsParamProp::TsParamProp(p) => &mut p.decorators,
+                        ParamOrTsParamProp::Param(p) => &mut p.decorators,
+                    };
+
+                    for decorator in param_decorators.drain(..) {
+                        decorators.push(self.create_param_decorator(idx, decorator.expr));
+                    }
+                }
+            }
+        }
+
+        cls.decorators = decorators;
+    }
+
+    /// Creates `_tsParam(param_index, decorator_expr)`, which is equivalent to
+    /// `__param` of `tslib`.
+    fn create_param_decorator(&self, param_index: usize, decorator_expr: Box<Expr>) -> Decorator {
         Decorator {
             span: DUMMY_SP,
-            expr: Box::new(Expr::Fn(FnExpr {
-                ident: None,
-                function: Function {
-                    params: vec![
-                        Param {
-                            span: DUMMY_SP,
-                            decorators: Default::default(),
-                            pat: Pat::Ident(quote_ident!("target")),
-                        },
-                        Param {
-                            span: DUMMY_SP,
-                            decorators: Default::default(),
-                            pat: Pat::Ident(quote_ident!("key")),
-                        },
-                    ],
-                    body: Some(BlockStmt {
+            expr: Box::new(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: helper!(ts_param, "tsParam"),
+                args: vec![
+                    Lit::Num(Number {
                         span: DUMMY_SP,
-                        stmts: vec![Stmt::Return(ReturnStmt {
-                            span: DUMMY_SP,
-                            arg: Some(Box::new(Expr::Call(CallExpr {
-                                span: DUMMY_SP,
-                                callee: decorator_expr.as_callee(),
-                                args: vec![
-                                    quote_ident!("target").as_arg(),
-                                    if is_constructor {
-                                        quote_ident!("undefined").as_arg()
-                                    } else {
-                                        quote_ident!("key").as_arg()
-                                    },
-                                    Lit::Num(Number {
-                                        span: DUMMY_SP,
-                                        value: param_index as _,
-                                    })
-                                    .as_arg(),
-                                ],
-                                type_args: Default::default(),
-                            }))),
-                        })],
-                    }),
-                    decorators: Default::default(),
-                    span: Default::default(),
-                    is_generator: Default::default(),
-                    is_async: Default::default(),
-                    type_params: Default::default(),
-                    return_type: Default::default(),
-                },
+                        value: param_index as _,
+                        raw: None,
+                    })
+                    .as_arg(),
+                    decorator_expr.as_arg(),
+                ],
+                type_args: Default::default(),
             })),
         }
     }
@@ -249,6 +209,7 @@ impl Metadata<'_> {
                             span: DUMMY_SP,
                             value: "undefined".into(),
                             has_escape: false,
+                            raw: None,
                         }))),
                     })),
                     op: op!("&&"),
@@ -264,6 +225,7 @@ impl Metadata<'_> {
                             span: DUMMY_SP,
                             value: "function".into(),
                             has_escape: false,
+                            raw: None,
                         }))),
                     })),
                 })),
@@ -276,6 +238,7 @@ impl Metadata<'_> {
                             span: DUMMY_SP,
                             value: design.into(),
                             has_escape: false,
+                            raw: None,
                         }
                         .as_arg(),
                         type_arg,
@@ -324,6 +287,7 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
                                 span: DUMMY_SP,
                                 value: "undefined".into(),
                                 has_escape: false,
+                                raw: None,
                             }))),
                         })),
                     }))
@@ -340,6 +304,7 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
                         span: DUMMY_SP,
                         value: "undefined".into(),
                         has_escape: false,
+                        raw: None,
                     }))),
                 })),
             }
@@ -429,7 +394,8 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
             }
         }
 
-        *undefined(DUMMY_SP)
+        // If we were able to find a single common type, return it.
+        u.unwrap_or_else(|| *undefined(DUMMY_SP))
     }
 
     fn serialize_type_node(class_name: &str, ty: &TsType) -> Expr {
@@ -0,0 +1,300 @@
+use crate::util::{ExprFactory, HANDLER};
+use std::mem::replace;
+use swc_atoms::JsWord;
+use swc_common::{Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_fold_type, noop_visit_type, Fold, FoldWith, Node, Visit, VisitWith};
+
+/// `@babel/plugin-proposal-do-expressions`
+///
+/// `do` expressions are converted to an arrow function which is invoked
+/// immediately, so `this` and `arguments` are preserved. `await` is
+/// preserved by using an async arrow function.
+///
+/// `return`, `yield`, `var` declarations, and `break` or `continue` which
+/// jump out of the expression can't be expressed in the function, so they
+/// are reported as errors.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// let a = do {
+///     if (x > 10) {
+///         'big';
+///     } else {
+///         'small';
+///     }
+/// };
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// let a = (() => {
+///     if (x > 10) {
+///         return 'big';
+///     } else {
+///         return 'small';
+///     }
+/// })();
+/// ```
+pub fn do_expressions() -> impl Fold {
+    DoExpressions
+}
+
+struct DoExpressions;
+
+impl Fold for DoExpressions {
+    noop_fold_type!();
+
+    fn fold_expr(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children_with(self);
+
+        match e {
+            Expr::Do(DoExpr { span, mut body }) => {
+                report_unsupported(&body);
+
+                let is_async = contains_await(&body);
+                return_completion(&mut body.stmts);
+
+                let call = Expr::Call(CallExpr {
+                    span,
+                    callee: ArrowExpr {
+                        span: DUMMY_SP,
+                        params: vec![],
+                        body: BlockStmtOrExpr::BlockStmt(body),
+                        is_async,
+                        is_generator: false,
+                        type_params: Default::default(),
+                        return_type: Default::default(),
+                    }
+                    .as_callee(),
+                    args: vec![],
+                    type_args: Default::default(),
+                });
+
+                if is_async {
+                    Expr::Await(AwaitExpr {
+                        span,
+                        arg: Box::new(call),
+                    })
+                } else {
+                    call
+                }
+            }
+
+            _ => e,
+        }
+    }
+}
+
+/// Converts the statement producing the completion value of `stmts` into a
+/// return statement.
+fn return_completion(stmts: &mut [Stmt]) {
+    // Function declarations and empty statements do not change the completion
+    // value.
+    let last = stmts
+        .iter_mut()
+        .rev()
+        .find(|stmt| !matches!(stmt, Stmt::Empty(..) | Stmt::Decl(Decl::Fn(..))));
+
+    if let Some(stmt) = last {
+        return_completion_of_stmt(stmt);
+    }
+}
+
+fn return_completion_of_stmt(stmt: &mut Stmt) {
+    match stmt {
+        Stmt::Expr(ExprStmt { span, expr }) => {
+            let arg = Some(replace(
+                expr,
+                Box::new(Expr::Invalid(Invalid { span: DUMMY_SP })),
+            ));
+            *stmt = Stmt::Return(ReturnStmt { span: *span, arg });
+        }
+
+        Stmt::Block(block) => return_completion(&mut block.stmts),
+
+        Stmt::If(IfStmt { cons, alt, .. }) => {
+            return_completion_of_stmt(cons);
+            if let Some(alt) = alt {
+                return_completion_of_stmt(alt);
+            }
+        }
+
+        Stmt::Try(TryStmt { block, handler, .. }) => {
+            return_completion(&mut block.stmts);
+            if let Some(handler) = handler {
+                return_completion(&mut handler.body.stmts);
+            }
+        }
+
+        Stmt::Labeled(LabeledStmt { body, .. }) => return_completion_of_stmt(body),
+
+        _ => {}
+    }
+}
+
+fn contains_await(body: &BlockStmt) -> bool {
+    let mut v = AwaitFinder { found: false };
+    body.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+    v.found
+}
+
+struct AwaitFinder {
+    found: bool,
+}
+
+impl Visit for AwaitFinder {
+    noop_visit_type!();
+
+    fn visit_await_expr(&mut self, _: &AwaitExpr, _: &dyn Node) {
+        self.found = true;
+    }
+
+    fn visit_for_of_stmt(&mut self, s: &ForOfStmt, _: &dyn Node) {
+        if s.await_token.is_some() {
+            self.found = true;
+        }
+
+        s.visit_children_with(self);
+    }
+
+    /// Don't recurse into constructor
+    fn visit_constructor(&mut self, _: &Constructor, _: &dyn Node) {}
+
+    /// Don't recurse into fn
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+    /// Don't recurse into fn
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+}
+
+/// Reports constructs which behave differently in a function.
+fn report_unsupported(body: &BlockStmt) {
+    let mut v = UnsupportedFinder::default();
+    body.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+
+    for (span, msg) in v.errors {
+        HANDLER.with(|handler| handler.struct_span_err(span, msg).emit());
+    }
+}
+
+#[derive(Default)]
+struct UnsupportedFinder {
+    errors: Vec<(Span, &'static str)>,
+    /// Labels declared in the expression.
+    labels: Vec<JsWord>,
+    loop_depth: usize,
+    switch_depth: usize,
+}
+
+impl UnsupportedFinder {
+    fn visit_loop<N>(&mut self, n: &N)
+    where
+        N: VisitWith<Self>,
+    {
+        self.loop_depth += 1;
+        n.visit_children_with(self);
+        self.loop_depth -= 1;
+    }
+}
+
+impl Visit for UnsupportedFinder {
+    noop_visit_type!();
+
+    fn visit_return_stmt(&mut self, s: &ReturnStmt, _: &dyn Node) {
+        self.errors
+            .push((s.span, "`return` is not supported in do expressions"));
+        s.visit_children_with(self);
+    }
+
+    fn visit_yield_expr(&mut self, e: &YieldExpr, _: &dyn Node) {
+        self.errors
+            .push((e.span, "`yield` is not supported in do expressions"));
+        e.visit_children_with(self);
+    }
+
+    fn visit_var_decl(&mut self, v: &VarDecl, _: &dyn Node) {
+        if v.kind == VarDeclKind::Var {
+            self.errors.push((
+                v.span,
+                "`var` declarations are not supported in do expressions. Use `let` or `const` \
+                 instead",
+            ));
+        }
+        v.visit_children_with(self);
+    }
+
+    fn visit_break_stmt(&mut self, s: &BreakStmt, _: &dyn Node) {
+        let ok = match &s.label {
+            Some(label) => self.labels.contains(&label.sym),
+            None => self.loop_depth > 0 || self.switch_depth > 0,
+        };
+        if !ok {
+            self.errors
+                .push((s.span, "`break` out of a do expression is not supported"));
+        }
+    }
+
+    fn visit_continue_stmt(&mut self, s: &ContinueStmt, _: &dyn Node) {
+        let ok = match &s.label {
+            Some(label) => self.labels.contains(&label.sym),
+            None => self.loop_depth > 0,
+        };
+        if !ok {
+            self.errors
+                .push((s.span, "`continue` out of a do expression is not supported"));
+        }
+    }
+
+    fn visit_labeled_stmt(&mut self, s: &LabeledStmt, _: &dyn Node) {
+        self.labels.push(s.label.sym.clone());
+        s.body.visit_with(s as _, self);
+        self.labels.pop();
+    }
+
+    fn visit_switch_stmt(&mut self, s: &SwitchStmt, _: &dyn Node) {
+        s.discriminant.visit_with(s as _, self);
+        self.switch_depth += 1;
+        s.cases.visit_with(s as _, self);
+        self.switch_depth -= 1;
+    }
+
+    fn visit_for_stmt(&mut self, s: &ForStmt, _: &dyn Node) {
+        self.visit_loop(s)
+    }
+
+    fn visit_for_in_stmt(&mut self, s: &ForInStmt, _: &dyn Node) {
+        self.visit_loop(s)
+    }
+
+    fn visit_for_of_stmt(&mut self, s: &ForOfStmt, _: &dyn Node) {
+        self.visit_loop(s)
+    }
+
+    fn visit_while_stmt(&mut self, s: &WhileStmt, _: &dyn Node) {
+        self.visit_loop(s)
+    }
+
+    fn visit_do_while_stmt(&mut self, s: &DoWhileStmt, _: &dyn Node) {
+        self.visit_loop(s)
+    }
+
+    /// Don't recurse into constructor
+    fn visit_constructor(&mut self, _: &Constructor, _: &dyn Node) {}
+
+    /// Don't recurse into fn
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+    /// Don't recurse into fn
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+    /// Don't recurse into getter
+    fn visit_getter_prop(&mut self, _: &GetterProp, _: &dyn Node) {}
+
+    /// Don't recurse into setter
+    fn visit_setter_prop(&mut self, _: &SetterProp, _: &dyn Node) {}
+}
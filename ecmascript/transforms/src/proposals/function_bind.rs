@@ -0,0 +1,180 @@
+use crate::util::{alias_if_required, ExprFactory, HoistVars};
+use std::iter;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+
+/// `@babel/plugin-proposal-function-bind`
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// obj::func;
+/// ::obj.func;
+/// obj::func(val);
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// func.bind(obj);
+/// obj.func.bind(obj);
+/// func.call(obj, val);
+/// ```
+pub fn function_bind() -> impl Fold {
+    FunctionBind::default()
+}
+
+#[derive(Default)]
+struct FunctionBind {
+    vars: Vec<VarDeclarator>,
+}
+
+impl FunctionBind {
+    /// Returns `(function, this)`.
+    fn split(&mut self, e: BindExpr) -> (Box<Expr>, Box<Expr>) {
+        match e.obj {
+            // obj::func
+            Some(obj) => {
+                let (this, aliased) = alias_if_required(&obj, "_context");
+                if !aliased {
+                    return (e.callee, obj);
+                }
+                self.declare(this.clone());
+
+                let func = Box::new(Expr::Seq(SeqExpr {
+                    span: DUMMY_SP,
+                    exprs: vec![assign(this.clone(), obj), e.callee],
+                }));
+
+                (func, Box::new(Expr::Ident(this)))
+            }
+
+            // ::obj.func
+            None => match *e.callee {
+                Expr::Member(MemberExpr {
+                    span,
+                    obj: ExprOrSuper::Expr(obj),
+                    prop,
+                    computed,
+                }) => {
+                    let (this, aliased) = alias_if_required(&obj, "_context");
+                    let obj = if aliased {
+                        self.declare(this.clone());
+                        assign(this.clone(), obj)
+                    } else {
+                        obj
+                    };
+
+                    let func = Box::new(Expr::Member(MemberExpr {
+                        span,
+                        obj: obj.as_obj(),
+                        prop,
+                        computed,
+                    }));
+
+                    (func, Box::new(Expr::Ident(this)))
+                }
+
+                // `::super.foo` is not valid, and the parser rejects other callees.
+                callee => unreachable!("function bind: invalid callee {:?}", callee),
+            },
+        }
+    }
+
+    fn declare(&mut self, i: Ident) {
+        self.vars.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(i),
+            init: None,
+            definite: false,
+        });
+    }
+}
+
+impl HoistVars for FunctionBind {
+    fn vars(&mut self) -> &mut Vec<VarDeclarator> {
+        &mut self.vars
+    }
+}
+
+impl Fold for FunctionBind {
+    noop_fold_type!();
+
+    fn fold_block_stmt(&mut self, s: BlockStmt) -> BlockStmt {
+        s.fold_children_with(&mut FunctionBind::default())
+    }
+
+    fn fold_switch_case(&mut self, s: SwitchCase) -> SwitchCase {
+        s.fold_children_with(&mut FunctionBind::default())
+    }
+
+    fn fold_module_items(&mut self, n: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        self.fold_stmt_like(n)
+    }
+
+    fn fold_stmts(&mut self, n: Vec<Stmt>) -> Vec<Stmt> {
+        self.fold_stmt_like(n)
+    }
+
+    fn fold_expr(&mut self, e: Expr) -> Expr {
+        let e = match e {
+            Expr::Call(CallExpr {
+                span,
+                callee: ExprOrSuper::Expr(callee),
+                args,
+                type_args,
+            }) => match *callee {
+                // obj::func(args) => func.call(obj, args)
+                Expr::Bind(callee) => {
+                    let callee = callee.fold_children_with(self);
+                    let (func, this) = self.split(callee);
+                    let args = args.fold_with(self);
+
+                    return Expr::Call(CallExpr {
+                        span,
+                        callee: func.make_member(quote_ident!("call")).as_callee(),
+                        args: iter::once(this.as_arg()).chain(args).collect(),
+                        type_args,
+                    });
+                }
+                callee => Expr::Call(CallExpr {
+                    span,
+                    callee: callee.as_callee(),
+                    args,
+                    type_args,
+                }),
+            },
+            _ => e,
+        };
+
+        let e = e.fold_children_with(self);
+
+        match e {
+            Expr::Bind(e) => {
+                let span = e.span;
+                let (func, this) = self.split(e);
+
+                Expr::Call(CallExpr {
+                    span,
+                    callee: func.make_member(quote_ident!("bind")).as_callee(),
+                    args: vec![this.as_arg()],
+                    type_args: Default::default(),
+                })
+            }
+
+            _ => e,
+        }
+    }
+}
+
+fn assign(left: Ident, right: Box<Expr>) -> Box<Expr> {
+    Box::new(Expr::Assign(AssignExpr {
+        span: DUMMY_SP,
+        op: op!("="),
+        left: PatOrExpr::Pat(Box::new(Pat::Ident(left))),
+        right,
+    }))
+}
@@ -0,0 +1,190 @@
+use crate::util::{alias_ident_for, ExprFactory, HoistVars};
+use either::Either;
+use std::iter;
+use swc_common::{Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+
+/// `@babel/plugin-proposal-partial-application`
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// const addOne = add(1, ?);
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// var _add;
+/// const addOne = (_add = add, function(_argPlaceholder) {
+///     return _add(1, _argPlaceholder);
+/// });
+/// ```
+pub fn partial_application() -> impl Fold {
+    PartialApplication::default()
+}
+
+#[derive(Default)]
+struct PartialApplication {
+    vars: Vec<VarDeclarator>,
+}
+
+impl PartialApplication {
+    /// Declares a new variable, and pushes `_var = e` to `inits`.
+    fn alias(&mut self, e: Box<Expr>, default: &str, inits: &mut Vec<Expr>) -> Ident {
+        let ident = alias_ident_for(&e, default);
+
+        self.vars.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(ident.clone()),
+            init: None,
+            definite: false,
+        });
+        inits.push(Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: PatOrExpr::Pat(Box::new(Pat::Ident(ident.clone()))),
+            right: e,
+        }));
+
+        ident
+    }
+}
+
+impl HoistVars for PartialApplication {
+    fn vars(&mut self) -> &mut Vec<VarDeclarator> {
+        &mut self.vars
+    }
+}
+
+impl Fold for PartialApplication {
+    noop_fold_type!();
+
+    fn fold_block_stmt(&mut self, s: BlockStmt) -> BlockStmt {
+        s.fold_children_with(&mut PartialApplication::default())
+    }
+
+    fn fold_switch_case(&mut self, s: SwitchCase) -> SwitchCase {
+        s.fold_children_with(&mut PartialApplication::default())
+    }
+
+    fn fold_module_items(&mut self, n: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        self.fold_stmt_like(n)
+    }
+
+    fn fold_stmts(&mut self, n: Vec<Stmt>) -> Vec<Stmt> {
+        self.fold_stmt_like(n)
+    }
+
+    fn fold_expr(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children_with(self);
+
+        match e {
+            Expr::Call(CallExpr {
+                span,
+                callee: ExprOrSuper::Expr(callee),
+                args,
+                ..
+            }) if args.iter().any(|arg| arg.expr.is_arg_placeholder()) => {
+                // Values are evaluated when the partial application is created.
+                let mut inits = vec![];
+
+                let callee = match *callee {
+                    Expr::Member(MemberExpr {
+                        span: member_span,
+                        obj: ExprOrSuper::Expr(obj),
+                        prop,
+                        computed,
+                    }) => {
+                        let this = self.alias(obj, "_obj", &mut inits);
+                        let func = self.alias(
+                            Box::new(Expr::Member(MemberExpr {
+                                span: member_span,
+                                obj: this.clone().as_obj(),
+                                prop,
+                                computed,
+                            })),
+                            "_fn",
+                            &mut inits,
+                        );
+
+                        Either::Right((func, this))
+                    }
+                    callee => Either::Left(self.alias(Box::new(callee), "_fn", &mut inits)),
+                };
+
+                let mut params = vec![];
+                let mut call_args = vec![];
+
+                for arg in args {
+                    if arg.expr.is_arg_placeholder() {
+                        let param = private_ident!(arg.expr.span(), "_argPlaceholder");
+                        params.push(Param {
+                            span: DUMMY_SP,
+                            decorators: Default::default(),
+                            pat: Pat::Ident(param.clone()),
+                        });
+                        call_args.push(param.as_arg());
+                        continue;
+                    }
+
+                    match *arg.expr {
+                        Expr::Lit(..) => call_args.push(arg),
+                        _ => {
+                            let ident = self.alias(arg.expr, "_ref", &mut inits);
+                            call_args.push(ExprOrSpread {
+                                spread: arg.spread,
+                                expr: Box::new(Expr::Ident(ident)),
+                            });
+                        }
+                    }
+                }
+
+                let call = match callee {
+                    Either::Left(func) => CallExpr {
+                        span,
+                        callee: func.as_callee(),
+                        args: call_args,
+                        type_args: Default::default(),
+                    },
+                    Either::Right((func, this)) => CallExpr {
+                        span,
+                        callee: func.make_member(quote_ident!("call")).as_callee(),
+                        args: iter::once(this.as_arg()).chain(call_args).collect(),
+                        type_args: Default::default(),
+                    },
+                };
+
+                inits.push(Expr::Fn(FnExpr {
+                    ident: None,
+                    function: Function {
+                        params,
+                        decorators: Default::default(),
+                        span: DUMMY_SP,
+                        body: Some(BlockStmt {
+                            span: DUMMY_SP,
+                            stmts: vec![Stmt::Return(ReturnStmt {
+                                span: DUMMY_SP,
+                                arg: Some(Box::new(Expr::Call(call))),
+                            })],
+                        }),
+                        is_generator: false,
+                        is_async: false,
+                        type_params: Default::default(),
+                        return_type: Default::default(),
+                    },
+                }));
+
+                Expr::Seq(SeqExpr {
+                    span,
+                    exprs: inits.into_iter().map(Box::new).collect(),
+                })
+            }
+
+            _ => e,
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::util::{alias_if_required, ExprFactory, HoistVars};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+
+/// `@babel/plugin-proposal-pipeline-operator` with `proposal: "minimal"`
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// let result = "hello" |> doubleSay |> capitalize;
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// var _ref, _ref1;
+/// let result = (_ref1 = (_ref = "hello", doubleSay(_ref)), capitalize(_ref1));
+/// ```
+pub fn pipeline_operator() -> impl Fold {
+    PipelineOperator::default()
+}
+
+#[derive(Default)]
+struct PipelineOperator {
+    vars: Vec<VarDeclarator>,
+}
+
+impl HoistVars for PipelineOperator {
+    fn vars(&mut self) -> &mut Vec<VarDeclarator> {
+        &mut self.vars
+    }
+}
+
+impl Fold for PipelineOperator {
+    noop_fold_type!();
+
+    fn fold_block_stmt(&mut self, s: BlockStmt) -> BlockStmt {
+        s.fold_children_with(&mut PipelineOperator::default())
+    }
+
+    fn fold_switch_case(&mut self, s: SwitchCase) -> SwitchCase {
+        s.fold_children_with(&mut PipelineOperator::default())
+    }
+
+    fn fold_module_items(&mut self, n: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        self.fold_stmt_like(n)
+    }
+
+    fn fold_stmts(&mut self, n: Vec<Stmt>) -> Vec<Stmt> {
+        self.fold_stmt_like(n)
+    }
+
+    fn fold_expr(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children_with(self);
+
+        match e {
+            Expr::Bin(BinExpr {
+                span,
+                left,
+                op: op!("|>"),
+                right,
+            }) => {
+                let (arg, aliased) = alias_if_required(&left, "_ref");
+
+                let call = Box::new(Expr::Call(CallExpr {
+                    span,
+                    callee: right.as_callee(),
+                    args: vec![arg.clone().as_arg()],
+                    type_args: Default::default(),
+                }));
+
+                if !aliased {
+                    return *call;
+                }
+
+                self.vars.push(VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(arg.clone()),
+                    init: None,
+                    definite: false,
+                });
+
+                Expr::Seq(SeqExpr {
+                    span,
+                    exprs: vec![
+                        Box::new(Expr::Assign(AssignExpr {
+                            span: DUMMY_SP,
+                            op: op!("="),
+                            left: PatOrExpr::Pat(Box::new(Pat::Ident(arg))),
+                            right: left,
+                        })),
+                        call,
+                    ],
+                })
+            }
+
+            _ => e,
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use crate::util::{prepend, ExprFactory};
+use serde::{Deserialize, Serialize};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+
+/// `@babel/plugin-proposal-record-and-tuple`
+///
+/// Records and tuples are created by calling `Record` and `Tuple`, which are
+/// provided by a polyfill.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// const point = #{ x: 1, y: 2 };
+/// const pair = #[1, 2];
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// const point = Record({ x: 1, y: 2 });
+/// const pair = Tuple(1, 2);
+/// ```
+pub fn record_and_tuple(c: Config) -> impl Fold {
+    RecordAndTuple {
+        c,
+        record: None,
+        tuple: None,
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Config {
+    /// Import `Record` and `Tuple` from the polyfill instead of using globals.
+    ///
+    /// Scripts `require` the polyfill instead.
+    #[serde(default)]
+    pub import_polyfill: bool,
+
+    /// Defaults to `@bloomberg/record-tuple-polyfill`.
+    #[serde(default)]
+    pub polyfill_module_name: Option<String>,
+}
+
+struct RecordAndTuple {
+    c: Config,
+    /// Local name of the imported `Record`.
+    record: Option<Ident>,
+    /// Local name of the imported `Tuple`.
+    tuple: Option<Ident>,
+}
+
+impl RecordAndTuple {
+    fn callee(&mut self, name: &str, is_record: bool) -> Ident {
+        if !self.c.import_polyfill {
+            return quote_ident!(name);
+        }
+
+        let local = if is_record {
+            &mut self.record
+        } else {
+            &mut self.tuple
+        };
+
+        local
+            .get_or_insert_with(|| private_ident!(format!("_{}", name)))
+            .clone()
+    }
+
+    /// Takes the local names of the polyfill exports used so far.
+    fn take_locals(&mut self) -> Vec<(&'static str, Ident)> {
+        vec![("Record", self.record.take()), ("Tuple", self.tuple.take())]
+            .into_iter()
+            .filter_map(|(name, local)| local.map(|local| (name, local)))
+            .collect()
+    }
+
+    fn polyfill_module_name(&self) -> String {
+        self.c
+            .polyfill_module_name
+            .clone()
+            .unwrap_or_else(|| "@bloomberg/record-tuple-polyfill".into())
+    }
+}
+
+impl Fold for RecordAndTuple {
+    noop_fold_type!();
+
+    fn fold_module(&mut self, m: Module) -> Module {
+        let mut m = m.fold_children_with(self);
+
+        let specifiers: Vec<_> = self
+            .take_locals()
+            .into_iter()
+            .map(|(name, local)| {
+                ImportSpecifier::Named(ImportNamedSpecifier {
+                    span: DUMMY_SP,
+                    local,
+                    imported: Some(quote_ident!(name)),
+                })
+            })
+            .collect();
+
+        if !specifiers.is_empty() {
+            prepend(
+                &mut m.body,
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    span: DUMMY_SP,
+                    specifiers,
+                    src: quote_str!(self.polyfill_module_name()),
+                    type_only: false,
+                    asserts: None,
+                })),
+            );
+        }
+
+        m
+    }
+
+    /// Scripts can't import, so the polyfill is required instead.
+    fn fold_script(&mut self, s: Script) -> Script {
+        let mut s = s.fold_children_with(self);
+
+        let props: Vec<_> = self
+            .take_locals()
+            .into_iter()
+            .map(|(name, local)| {
+                ObjectPatProp::KeyValue(KeyValuePatProp {
+                    key: PropName::Ident(quote_ident!(name)),
+                    value: Box::new(Pat::Ident(local)),
+                })
+            })
+            .collect();
+
+        if !props.is_empty() {
+            let src = self.polyfill_module_name();
+
+            prepend(
+                &mut s.body,
+                Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: vec![VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Object(ObjectPat {
+                            span: DUMMY_SP,
+                            props,
+                            optional: false,
+                            type_ann: None,
+                        }),
+                        init: Some(Box::new(Expr::Call(CallExpr {
+                            span: DUMMY_SP,
+                            callee: quote_ident!("require").as_callee(),
+                            args: vec![Lit::Str(quote_str!(src)).as_arg()],
+                            type_args: Default::default(),
+                        }))),
+                        definite: false,
+                    }],
+                })),
+            );
+        }
+
+        s
+    }
+
+    fn fold_expr(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children_with(self);
+
+        match e {
+            Expr::Record(RecordLit { span, props }) => Expr::Call(CallExpr {
+                span,
+                callee: self.callee("Record", true).as_callee(),
+                args: vec![ObjectLit {
+                    span: DUMMY_SP,
+                    props,
+                }
+                .as_arg()],
+                type_args: Default::default(),
+            }),
+
+            Expr::Tuple(TupleLit { span, elems }) => Expr::Call(CallExpr {
+                span,
+                callee: self.callee("Tuple", false).as_callee(),
+                args: elems,
+                type_args: Default::default(),
+            }),
+
+            _ => e,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::util::ExprFactory;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+
+/// `@babel/plugin-proposal-throw-expressions`
+///
+/// The argument is evaluated before the function is invoked, so `await` and
+/// `yield` in it keep working.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// function save(filename = throw new TypeError("Argument required")) {}
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// function save(filename = (function(e) {
+///     throw e;
+/// })(new TypeError("Argument required"))) {}
+/// ```
+pub fn throw_expressions() -> impl Fold {
+    ThrowExpressions
+}
+
+struct ThrowExpressions;
+
+impl Fold for ThrowExpressions {
+    noop_fold_type!();
+
+    fn fold_expr(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children_with(self);
+
+        match e {
+            Expr::Throw(ThrowExpr { span, arg }) => {
+                let param = private_ident!("e");
+
+                Expr::Call(CallExpr {
+                    span,
+                    callee: FnExpr {
+                        ident: None,
+                        function: Function {
+                            params: vec![Param {
+                                span: DUMMY_SP,
+                                decorators: Default::default(),
+                                pat: Pat::Ident(param.clone()),
+                            }],
+                            decorators: Default::default(),
+                            span: DUMMY_SP,
+                            body: Some(BlockStmt {
+                                span: DUMMY_SP,
+                                stmts: vec![Stmt::Throw(ThrowStmt {
+                                    span: DUMMY_SP,
+                                    arg: Box::new(Expr::Ident(param)),
+                                })],
+                            }),
+                            is_generator: false,
+                            is_async: false,
+                            type_params: Default::default(),
+                            return_type: Default::default(),
+                        },
+                    }
+                    .as_callee(),
+                    args: vec![arg.as_arg()],
+                    type_args: Default::default(),
+                })
+            }
+
+            _ => e,
+        }
+    }
+}
@@ -2,7 +2,7 @@
 
 pub use self::{
     display_name::display_name,
-    jsx::{jsx, Options},
+    jsx::{jsx, Options, XmlNamespaceAttrs},
     jsx_self::jsx_self,
     jsx_src::jsx_src,
 };
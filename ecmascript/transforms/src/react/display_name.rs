@@ -39,6 +39,7 @@ impl Fold for DisplayName {
                         span: prop.span,
                         value: prop.sym.clone(),
                         has_escape: false,
+                        raw: None,
                     })))),
                 });
                 return AssignExpr { right, ..expr };
@@ -51,6 +52,7 @@ impl Fold for DisplayName {
                     span: ident.span,
                     value: ident.sym.clone(),
                     has_escape: false,
+                    raw: None,
                 })))),
             });
 
@@ -70,6 +72,7 @@ impl Fold for DisplayName {
                         span: DUMMY_SP,
                         value: "input".into(),
                         has_escape: false,
+                        raw: None,
                     })))),
                 }))
             }
@@ -88,9 +91,10 @@ impl Fold for DisplayName {
                             span: i.span,
                             value: i.sym.clone(),
                             has_escape: false,
+                            raw: None,
                         }))),
                         PropName::Str(ref s) => Box::new(Expr::Lit(Lit::Str(s.clone()))),
-                        PropName::Num(n) => Box::new(Expr::Lit(Lit::Num(n))),
+                        PropName::Num(ref n) => Box::new(Expr::Lit(Lit::Num(n.clone()))),
                         PropName::Computed(ref c) => c.expr.clone(),
                     }),
                 });
@@ -108,6 +112,7 @@ impl Fold for DisplayName {
                         span: ident.span,
                         value: ident.sym.clone(),
                         has_escape: false,
+                        raw: None,
                     })))),
                 });
 
@@ -1,37 +1,60 @@
-use crate::util::{drop_span, ExprFactory, HANDLER};
+use crate::util::{drop_span, span_of_all, warn, ExprFactory, HANDLER};
 use dashmap::DashMap;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::{iter, mem};
+use std::{collections::HashSet, iter, mem};
 use swc_atoms::{js_word, JsWord};
 use swc_common::{
     comments::{CommentKind, Comments},
     iter::IdentifyLast,
     sync::Lrc,
-    FileName, SourceMap, Spanned, DUMMY_SP,
+    FileName, SourceMap, Span, Spanned,
 };
 use swc_ecma_ast::*;
 use swc_ecma_parser::{Parser, StringInput, Syntax};
+use swc_ecma_transforms_macros::quote;
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
 
 #[cfg(test)]
 mod tests;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Options {
     #[serde(default = "default_pragma")]
     pub pragma: String,
     #[serde(default = "default_pragma_frag")]
     pub pragma_frag: String,
 
+    /// Used instead of `pragma` if set, so it does not have to be parsed.
+    #[serde(skip)]
+    pub pragma_expr: Option<Box<Expr>>,
+    /// Used instead of `pragma_frag` if set.
+    #[serde(skip)]
+    pub pragma_frag_expr: Option<Box<Expr>>,
+
     #[serde(default = "default_throw_if_namespace")]
     pub throw_if_namespace: bool,
 
+    /// How attributes in the `xlink`, `xml` and `xmlns` namespaces, like
+    /// `xlink:href`, are handled.
+    #[serde(default)]
+    pub xml_namespace_attrs: XmlNamespaceAttrs,
+
     #[serde(default)]
     pub development: bool,
 
+    /// Adds checks for common mistakes in development mode, like spreading
+    /// props which contain `key` or fragments without a key in lists.
+    #[serde(default)]
+    pub dev_checks: bool,
+
+    /// Reports warnings for invalid usage of DOM elements, like children of
+    /// void elements (`<img>children</img>`) and duplicate props.
+    #[serde(default)]
+    pub validate: bool,
+
     #[serde(default)]
     pub use_builtins: bool,
 }
@@ -41,13 +64,36 @@ impl Default for Options {
         Options {
             pragma: default_pragma(),
             pragma_frag: default_pragma_frag(),
+            pragma_expr: None,
+            pragma_frag_expr: None,
             throw_if_namespace: default_throw_if_namespace(),
+            xml_namespace_attrs: Default::default(),
             development: false,
+            dev_checks: false,
+            validate: false,
             use_builtins: false,
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum XmlNamespaceAttrs {
+    /// Handled like other namespaced names, as configured by
+    /// `throw_if_namespace`.
+    Check,
+    /// Kept as string props, like `"xlink:href"`.
+    Preserve,
+    /// Renamed to the camel cased props used by react, like `xlinkHref`.
+    CamelCase,
+}
+
+impl Default for XmlNamespaceAttrs {
+    fn default() -> Self {
+        XmlNamespaceAttrs::Check
+    }
+}
+
 fn default_pragma() -> String {
     "React.createElement".into()
 }
@@ -60,14 +106,17 @@ fn default_throw_if_namespace() -> bool {
     true
 }
 
+/// Parsed expressions are cached by source, so creating a pass for each file
+/// doesn't parse the same pragma again.
 fn parse_option(cm: &SourceMap, name: &str, src: String) -> Box<Expr> {
     static CACHE: Lazy<DashMap<String, Box<Expr>>> = Lazy::new(|| DashMap::with_capacity(2));
 
-    let fm = cm.new_source_file(FileName::Custom(format!("<jsx-config-{}.js>", name)), src);
-    if let Some(expr) = CACHE.get(&**fm.src) {
+    if let Some(expr) = CACHE.get(&src) {
         return expr.clone();
     }
 
+    let fm = cm.new_source_file(FileName::Custom(format!("<jsx-config-{}.js>", name)), src);
+
     let expr = Parser::new(Syntax::default(), StringInput::from(&*fm), None)
         .parse_expr()
         .map_err(|e| {
@@ -95,16 +144,31 @@ pub fn jsx<C>(cm: Lrc<SourceMap>, comments: Option<C>, options: Options) -> impl
 where
     C: Comments,
 {
+    // Default pragmas are parsed at compile time.
+    let pragma = match options.pragma_expr {
+        Some(expr) => expr,
+        None if options.pragma == default_pragma() => quote!("React.createElement"),
+        None => parse_option(&cm, "pragma", options.pragma),
+    };
+    let pragma_frag = match options.pragma_frag_expr {
+        Some(expr) => expr,
+        None if options.pragma_frag == default_pragma_frag() => quote!("React.Fragment"),
+        None => parse_option(&cm, "pragmaFrag", options.pragma_frag),
+    };
+
     Jsx {
-        cm: cm.clone(),
-        pragma: ExprOrSuper::Expr(parse_option(&cm, "pragma", options.pragma)),
+        cm,
+        pragma: ExprOrSuper::Expr(pragma),
         comments,
         pragma_frag: ExprOrSpread {
             spread: None,
-            expr: parse_option(&cm, "pragmaFrag", options.pragma_frag),
+            expr: pragma_frag,
         },
         use_builtins: options.use_builtins,
         throw_if_namespace: options.throw_if_namespace,
+        xml_namespace_attrs: options.xml_namespace_attrs,
+        dev_checks: options.development && options.dev_checks,
+        validate: options.validate,
     }
 }
 
@@ -118,6 +182,9 @@ where
     pragma_frag: ExprOrSpread,
     use_builtins: bool,
     throw_if_namespace: bool,
+    xml_namespace_attrs: XmlNamespaceAttrs,
+    dev_checks: bool,
+    validate: bool,
 }
 
 impl<C> Jsx<C>
@@ -132,7 +199,12 @@ where
             callee: self.pragma.clone(),
             args: iter::once(self.pragma_frag.clone())
                 // attribute: null
-                .chain(iter::once(Lit::Null(Null { span: DUMMY_SP }).as_arg()))
+                .chain(iter::once(
+                    Lit::Null(Null {
+                        span: el.opening.span,
+                    })
+                    .as_arg(),
+                ))
                 .chain({
                     // Children
                     el.children
@@ -147,6 +219,10 @@ where
     fn jsx_elem_to_expr(&mut self, el: JSXElement) -> Expr {
         let span = el.span();
 
+        if self.validate {
+            validate_elem(&el);
+        }
+
         let name = self.jsx_name(el.opening.name);
 
         Expr::Call(CallExpr {
@@ -155,7 +231,7 @@ where
             args: iter::once(name.as_arg())
                 .chain(iter::once({
                     // Attributes
-                    self.fold_attrs(el.opening.attrs).as_arg()
+                    self.fold_attrs(el.opening.span, el.opening.attrs).as_arg()
                 }))
                 .chain({
                     // Children
@@ -176,6 +252,7 @@ where
                     span: text.span,
                     has_escape: text.raw != text.value,
                     value: jsx_text_to_str(text.value),
+                    raw: None,
                 };
                 if s.value.is_empty() {
                     return None;
@@ -199,10 +276,14 @@ where
         })
     }
 
-    fn fold_attrs(&mut self, attrs: Vec<JSXAttrOrSpread>) -> Box<Expr> {
-        if attrs.is_empty() {
-            return Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP })));
-        }
+    /// `opening_span` is used if there's no attribute.
+    fn fold_attrs(&mut self, opening_span: Span, attrs: Vec<JSXAttrOrSpread>) -> Box<Expr> {
+        let span = match span_of_all(&attrs) {
+            Some(span) => span,
+            None => {
+                return Box::new(Expr::Lit(Lit::Null(Null { span: opening_span })));
+            }
+        };
 
         let is_complex = attrs.iter().any(|a| match *a {
             JSXAttrOrSpread::SpreadElement(..) => true,
@@ -212,27 +293,47 @@ where
         if is_complex {
             let mut args = vec![];
             let mut cur_obj_props = vec![];
+            let mut cur_obj_spans = vec![];
             macro_rules! check {
                 () => {{
                     if args.is_empty() || !cur_obj_props.is_empty() {
                         args.push(
                             ObjectLit {
-                                span: DUMMY_SP,
+                                span: span_of_all(&cur_obj_spans).unwrap_or(span),
                                 props: mem::replace(&mut cur_obj_props, vec![]),
                             }
                             .as_arg(),
-                        )
+                        );
+                        cur_obj_spans.clear();
                     }
                 }};
             }
             for attr in attrs {
                 match attr {
                     JSXAttrOrSpread::JSXAttr(a) => {
-                        cur_obj_props.push(PropOrSpread::Prop(Box::new(attr_to_prop(a))))
+                        cur_obj_spans.push(a.span);
+                        cur_obj_props.push(PropOrSpread::Prop(Box::new(self.attr_to_prop(a))))
                     }
                     JSXAttrOrSpread::SpreadElement(e) => {
                         check!();
-                        args.push(e.expr.as_arg());
+                        if self.dev_checks {
+                            // Warns at runtime if the spread object contains `key`.
+                            args.push(
+                                CallExpr {
+                                    span: e.dot3_token,
+                                    callee: helper!(
+                                        e.dot3_token,
+                                        check_spread_key,
+                                        "checkSpreadKey"
+                                    ),
+                                    args: vec![e.expr.as_arg()],
+                                    type_args: None,
+                                }
+                                .as_arg(),
+                            );
+                        } else {
+                            args.push(e.expr.as_arg());
+                        }
                     }
                 }
             }
@@ -240,12 +341,12 @@ where
 
             // calls `_extends` or `Object.assign`
             Box::new(Expr::Call(CallExpr {
-                span: DUMMY_SP,
+                span,
                 callee: {
                     if self.use_builtins {
-                        member_expr!(DUMMY_SP, Object.assign).as_callee()
+                        member_expr!(span, Object.assign).as_callee()
                     } else {
-                        helper!(extends, "extends")
+                        helper!(span, extends, "extends")
                     }
                 },
                 args,
@@ -253,15 +354,14 @@ where
             }))
         } else {
             Box::new(Expr::Object(ObjectLit {
-                span: DUMMY_SP,
+                span,
                 props: attrs
                     .into_iter()
                     .map(|a| match a {
                         JSXAttrOrSpread::JSXAttr(a) => a,
                         _ => unreachable!(),
                     })
-                    .map(attr_to_prop)
-                    .map(|v| v.fold_with(self))
+                    .map(|a| self.attr_to_prop(a))
                     .map(Box::new)
                     .map(PropOrSpread::Prop)
                     .collect(),
@@ -324,6 +424,10 @@ where
     }
 
     fn fold_expr(&mut self, expr: Expr) -> Expr {
+        if self.dev_checks {
+            check_list_keys(&expr);
+        }
+
         let mut expr = expr.fold_children_with(self);
 
         if let Expr::JSXElement(el) = expr {
@@ -378,29 +482,19 @@ where
                         span,
                         value: i.sym,
                         has_escape: false,
+                        raw: None,
                     })))
                 } else {
                     Box::new(Expr::Ident(i))
                 }
             }
             JSXElementName::JSXNamespacedName(JSXNamespacedName { ref ns, ref name }) => {
-                if self.throw_if_namespace {
-                    HANDLER.with(|handler| {
-                        handler
-                            .struct_span_err(
-                                span,
-                                "JSX Namespace is disabled by default because react does not \
-                                 support it yet. You can specify \
-                                 jsc.transform.react.throwIfNamespace to false to override \
-                                 default behavior",
-                            )
-                            .emit()
-                    });
-                }
+                self.check_namespace(span);
                 Box::new(Expr::Lit(Lit::Str(Str {
                     span,
                     value: format!("{}:{}", ns.sym, name.sym).into(),
                     has_escape: false,
+                    raw: None,
                 })))
             }
             JSXElementName::JSXMemberExpr(JSXMemberExpr { obj, prop }) => {
@@ -435,54 +529,236 @@ where
             }
         }
     }
-}
 
-fn attr_to_prop(a: JSXAttr) -> Prop {
-    let key = to_prop_name(a.name);
-    let value = a
-        .value
-        .map(|v| match v {
-            JSXAttrValue::JSXExprContainer(JSXExprContainer {
-                expr: JSXExpr::Expr(e),
-                ..
-            }) => e,
-            JSXAttrValue::JSXElement(e) => Box::new(Expr::JSXElement(e)),
-            JSXAttrValue::JSXFragment(e) => Box::new(Expr::JSXFragment(e)),
-            JSXAttrValue::Lit(lit) => Box::new(lit.into()),
-            JSXAttrValue::JSXExprContainer(JSXExprContainer {
-                span: _,
-                expr: JSXExpr::JSXEmptyExpr(_),
-            }) => unreachable!("attr_to_prop(JSXEmptyExpr)"),
-        })
-        .unwrap_or_else(|| {
-            Box::new(Expr::Lit(Lit::Bool(Bool {
-                span: key.span(),
-                value: true,
-            })))
-        });
-    Prop::KeyValue(KeyValueProp { key, value })
-}
+    /// Elements and fragments used as values, like `<Foo bar=<Baz /> />`, are
+    /// converted as well.
+    fn attr_to_prop(&mut self, a: JSXAttr) -> Prop {
+        let key = self.to_prop_name(a.name);
+        let value = a
+            .value
+            .map(|v| match v {
+                JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(e),
+                    ..
+                }) => e,
+                JSXAttrValue::JSXElement(e) => Box::new(self.jsx_elem_to_expr(*e)),
+                JSXAttrValue::JSXFragment(e) => Box::new(self.jsx_frag_to_expr(e)),
+                // Escapes are not allowed in jsx attributes, so the source text is
+                // not a valid string literal.
+                JSXAttrValue::Lit(Lit::Str(s)) => {
+                    Box::new(Expr::Lit(Lit::Str(Str { raw: None, ..s })))
+                }
+                JSXAttrValue::Lit(lit) => Box::new(lit.into()),
+                JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    span: _,
+                    expr: JSXExpr::JSXEmptyExpr(_),
+                }) => unreachable!("attr_to_prop(JSXEmptyExpr)"),
+            })
+            .unwrap_or_else(|| {
+                Box::new(Expr::Lit(Lit::Bool(Bool {
+                    span: key.span(),
+                    value: true,
+                })))
+            });
+        Prop::KeyValue(KeyValueProp { key, value })
+    }
+
+    fn to_prop_name(&self, n: JSXAttrName) -> PropName {
+        let span = n.span();
 
-fn to_prop_name(n: JSXAttrName) -> PropName {
-    let span = n.span();
+        match n {
+            JSXAttrName::Ident(i) => {
+                if i.sym.contains('-') {
+                    PropName::Str(Str {
+                        span,
+                        value: i.sym,
+                        has_escape: false,
+                        raw: None,
+                    })
+                } else {
+                    PropName::Ident(i)
+                }
+            }
+            JSXAttrName::JSXNamespacedName(JSXNamespacedName { ns, name }) => {
+                let is_xml = matches!(&*ns.sym, "xlink" | "xml" | "xmlns");
+
+                match self.xml_namespace_attrs {
+                    XmlNamespaceAttrs::CamelCase if is_xml => {
+                        let mut chars = name.sym.chars();
+                        let value = match chars.next() {
+                            Some(c) => {
+                                format!("{}{}{}", ns.sym, c.to_ascii_uppercase(), chars.as_str())
+                            }
+                            None => ns.sym.to_string(),
+                        };
+                        if value.contains('-') {
+                            return PropName::Str(Str {
+                                span,
+                                value: value.into(),
+                                has_escape: false,
+                                raw: None,
+                            });
+                        }
+                        return PropName::Ident(Ident::new(value.into(), span));
+                    }
+                    XmlNamespaceAttrs::Preserve if is_xml => {}
+                    _ => self.check_namespace(span),
+                }
 
-    match n {
-        JSXAttrName::Ident(i) => {
-            if i.sym.contains('-') {
                 PropName::Str(Str {
                     span,
-                    value: i.sym,
+                    value: format!("{}:{}", ns.sym, name.sym).into(),
                     has_escape: false,
+                    raw: None,
                 })
-            } else {
-                PropName::Ident(i)
             }
         }
-        JSXAttrName::JSXNamespacedName(JSXNamespacedName { ns, name }) => PropName::Str(Str {
-            span,
-            value: format!("{}:{}", ns.sym, name.sym).into(),
-            has_escape: false,
-        }),
+    }
+
+    /// Reports usage of a namespaced name, like `<svg:rect>` or `xlink:href`,
+    /// as an error if `throw_if_namespace` is set and as a warning otherwise.
+    fn check_namespace(&self, span: Span) {
+        if !HANDLER.is_set() {
+            return;
+        }
+
+        if self.throw_if_namespace {
+            HANDLER.with(|handler| {
+                handler
+                    .struct_span_err(
+                        span,
+                        "JSX Namespace is disabled by default because react does not support it \
+                         yet. You can specify jsc.transform.react.throwIfNamespace to false to \
+                         override default behavior",
+                    )
+                    .emit()
+            });
+        } else {
+            warn(span, "JSX Namespace is not supported by react");
+        }
+    }
+}
+
+/// Warns about elements without a key, and fragments which can't have one,
+/// returned from a `.map()` callback, like `items.map(item => <li />)`.
+fn check_list_keys(e: &Expr) {
+    let (callee, args) = match e {
+        Expr::Call(CallExpr {
+            callee: ExprOrSuper::Expr(callee),
+            args,
+            ..
+        }) => (callee, args),
+        _ => return,
+    };
+    match &**callee {
+        Expr::Member(MemberExpr {
+            prop,
+            computed: false,
+            ..
+        }) => match &**prop {
+            Expr::Ident(i) if &*i.sym == "map" => {}
+            _ => return,
+        },
+        _ => return,
+    }
+
+    let mut body = match args.first().map(|arg| &*arg.expr) {
+        Some(Expr::Arrow(ArrowExpr {
+            body: BlockStmtOrExpr::Expr(body),
+            ..
+        })) => &**body,
+        _ => return,
+    };
+    while let Expr::Paren(ParenExpr { expr, .. }) = body {
+        body = &**expr;
+    }
+
+    match body {
+        Expr::JSXFragment(frag) => warn(
+            frag.span,
+            "Fragments in a list can't have a key. Use <React.Fragment key={...}> instead",
+        ),
+        Expr::JSXElement(el) => {
+            // A spread may contain the key.
+            let has_key = el.opening.attrs.iter().any(|attr| match attr {
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(i),
+                    ..
+                }) => i.sym == js_word!("key"),
+                JSXAttrOrSpread::JSXAttr(..) => false,
+                JSXAttrOrSpread::SpreadElement(..) => true,
+            });
+            if !has_key {
+                warn(
+                    el.span,
+                    "Each child in a list should have a unique \"key\" prop",
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Elements which can't have children, as defined by the html spec.
+static VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Reports invalid usage of elements, as enabled by [Options::validate].
+fn validate_elem(el: &JSXElement) {
+    let mut names = HashSet::new();
+    let mut children_prop = false;
+    let mut inner_html = None;
+
+    for attr in &el.opening.attrs {
+        let attr = match attr {
+            JSXAttrOrSpread::JSXAttr(attr) => attr,
+            JSXAttrOrSpread::SpreadElement(..) => continue,
+        };
+        let name: JsWord = match &attr.name {
+            JSXAttrName::Ident(i) => i.sym.clone(),
+            JSXAttrName::JSXNamespacedName(JSXNamespacedName { ns, name }) => {
+                format!("{}:{}", ns.sym, name.sym).into()
+            }
+        };
+
+        match &*name {
+            "children" => children_prop = true,
+            "dangerouslySetInnerHTML" => inner_html = Some(attr.span),
+            _ => {}
+        }
+        if !names.insert(name.clone()) {
+            warn(attr.span, &format!("Duplicate prop `{}`", name));
+        }
+    }
+
+    let has_children = children_prop
+        || el.children.iter().any(|c| match c {
+            JSXElementChild::JSXText(text) => !text.value.trim().is_empty(),
+            JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::JSXEmptyExpr(..),
+                ..
+            }) => false,
+            _ => true,
+        });
+
+    if let Some(span) = inner_html {
+        if has_children {
+            warn(
+                span,
+                "Can only set one of `children` or `dangerouslySetInnerHTML`",
+            );
+        }
+    }
+
+    if let JSXElementName::Ident(i) = &el.opening.name {
+        if has_children && VOID_ELEMENTS.contains(&&*i.sym) {
+            warn(
+                el.span,
+                &format!("`{}` is a void element and can't have children", i.sym),
+            );
+        }
     }
 }
 
@@ -8,13 +8,13 @@ use crate::{
     react::display_name,
     tests::Tester,
 };
-use swc_common::{chain, Mark};
+use swc_common::{chain, comments::SingleThreadedComments, Mark};
 
 fn tr(t: &mut Tester, options: Options) -> impl Fold {
     chain!(
         jsx(t.cm.clone(), Some(t.comments.clone()), options),
         display_name(),
-        classes(),
+        classes(None, Default::default()),
         arrow(),
     )
 }
@@ -515,6 +515,34 @@ React.createElement("div", {
 });"#
 );
 
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default()),
+    react_should_allow_fragments_as_attributes,
+    r#"<div attr=<><div /></> />"#,
+    r#"
+React.createElement("div", {
+  attr: React.createElement(React.Fragment, null, React.createElement("div", null))
+});"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default()),
+    react_should_allow_elements_as_attributes_with_spread,
+    r#"<Foo {...props} bar=<Baz /> />"#,
+    r#"
+React.createElement(Foo, _extends({}, props, {
+  bar: React.createElement(Baz, null)
+}));"#
+);
+
 test!(
     ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
         jsx: true,
@@ -1170,3 +1198,314 @@ test!(
     "let page = React.createElement('p', null, 'Click ', React.createElement('em', null, 'New \
      melody'), ' listen to a randomly generated melody');"
 );
+
+#[test]
+fn attrs_span() {
+    Tester::run(|t| {
+        let module = t.with_parser(
+            "input.js",
+            ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+                jsx: true,
+                ..Default::default()
+            }),
+            r#"<div a="1" {...b} c />;"#,
+            |p| p.parse_module(),
+        )?;
+        let module = module.fold_with(&mut jsx(
+            t.cm.clone(),
+            Some(t.comments.clone()),
+            Default::default(),
+        ));
+
+        let snippet = |span| t.cm.span_to_snippet(span).unwrap();
+        let attrs = match &module.body[0] {
+            ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match &**expr {
+                Expr::Call(CallExpr { args, .. }) => &args[1].expr,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+
+        match &**attrs {
+            Expr::Call(CallExpr { span, args, .. }) => {
+                assert_eq!(snippet(*span), r#"a="1" {...b} c"#);
+                assert_eq!(snippet(args[0].expr.span()), r#"a="1""#);
+                assert_eq!(snippet(args[2].expr.span()), "c");
+            }
+            _ => unreachable!(),
+        }
+
+        Ok(())
+    });
+}
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            pragma_expr: Some(Box::new(Expr::Ident(quote_ident!("h")))),
+            pragma_frag_expr: Some(Box::new(Expr::Ident(quote_ident!("Fragment")))),
+            ..Default::default()
+        }
+    ),
+    pragma_expr,
+    "<><div /></>;",
+    r#"h(Fragment, null, h("div", null));"#
+);
+
+#[test]
+fn pragma_is_parsed_once() {
+    Tester::run(|t| {
+        let options = Options {
+            pragma: "preact.h".into(),
+            ..Default::default()
+        };
+
+        jsx(t.cm.clone(), Some(t.comments.clone()), options.clone());
+        let files = t.cm.files().len();
+        jsx(t.cm.clone(), Some(t.comments.clone()), options);
+        assert_eq!(t.cm.files().len(), files);
+
+        Ok(())
+    });
+}
+
+fn has_namespace_errors(src: &str, options: Options) -> bool {
+    let mut has_errors = false;
+
+    Tester::run(|t| {
+        let tr = jsx(t.cm.clone(), Some(t.comments.clone()), options);
+        t.apply_transform(
+            tr,
+            "input.js",
+            ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+                jsx: true,
+                ..Default::default()
+            }),
+            src,
+        )?;
+        has_errors = t.handler.has_errors();
+
+        Ok(())
+    });
+
+    has_errors
+}
+
+#[test]
+fn throw_if_namespace() {
+    let options = |throw_if_namespace| Options {
+        throw_if_namespace,
+        ..Default::default()
+    };
+
+    assert!(has_namespace_errors("<f:image />;", options(true)));
+    assert!(has_namespace_errors("<image n:attr />;", options(true)));
+    assert!(has_namespace_errors(
+        "<image {...props} n:attr />;",
+        options(true)
+    ));
+    assert!(!has_namespace_errors("<f:image n:attr />;", options(false)));
+}
+
+#[test]
+fn namespace_without_handler() {
+    ::testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon, "<use xlink:href='#a' />;".into());
+        let module = Parser::new(
+            ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+                jsx: true,
+                ..Default::default()
+            }),
+            StringInput::from(&*fm),
+            None,
+        )
+        .parse_module()
+        .unwrap();
+
+        for &throw_if_namespace in &[true, false] {
+            let options = Options {
+                throw_if_namespace,
+                ..Default::default()
+            };
+            module.clone().fold_with(&mut jsx::<SingleThreadedComments>(
+                cm.clone(),
+                None,
+                options,
+            ));
+        }
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            xml_namespace_attrs: XmlNamespaceAttrs::Preserve,
+            ..Default::default()
+        }
+    ),
+    xml_namespace_attrs_preserve,
+    r#"<svg xmlns:xlink="http://www.w3.org/1999/xlink"><use xlink:href="sprite.svg#a" /></svg>;"#,
+    r#"React.createElement("svg", {
+  "xmlns:xlink": "http://www.w3.org/1999/xlink"
+}, React.createElement("use", {
+  "xlink:href": "sprite.svg#a"
+}));"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            xml_namespace_attrs: XmlNamespaceAttrs::CamelCase,
+            ..Default::default()
+        }
+    ),
+    xml_namespace_attrs_camel_case,
+    r#"<svg xmlns:xlink="http://www.w3.org/1999/xlink" xml:lang="en"><use xlink:href="sprite.svg#a" /></svg>;"#,
+    r#"React.createElement("svg", {
+  xmlnsXlink: "http://www.w3.org/1999/xlink",
+  xmlLang: "en"
+}, React.createElement("use", {
+  xlinkHref: "sprite.svg#a"
+}));"#
+);
+
+#[test]
+fn xml_namespace_attrs() {
+    let options = |xml_namespace_attrs| Options {
+        xml_namespace_attrs,
+        ..Default::default()
+    };
+
+    assert!(has_namespace_errors(
+        "<use xlink:href='#a' />;",
+        options(XmlNamespaceAttrs::Check)
+    ));
+    assert!(!has_namespace_errors(
+        "<use xlink:href='#a' />;",
+        options(XmlNamespaceAttrs::Preserve)
+    ));
+    assert!(!has_namespace_errors(
+        "<use xlink:href='#a' />;",
+        options(XmlNamespaceAttrs::CamelCase)
+    ));
+    // Other namespaces are still checked.
+    assert!(has_namespace_errors(
+        "<use n:attr />;",
+        options(XmlNamespaceAttrs::Preserve)
+    ));
+    assert!(has_namespace_errors(
+        "<svg:use />;",
+        options(XmlNamespaceAttrs::CamelCase)
+    ));
+}
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            development: true,
+            dev_checks: true,
+            ..Default::default()
+        }
+    ),
+    dev_checks_spread_key,
+    r#"<div {...props} id="a" />;"#,
+    r#"React.createElement("div", _extends({}, _checkSpreadKey(props), {
+  id: "a"
+}));"#
+);
+
+fn has_warnings(src: &str, options: Options) -> bool {
+    let mut has_errors = false;
+
+    Tester::run(|t| {
+        let tr = jsx(t.cm.clone(), Some(t.comments.clone()), options);
+        // Warnings are reported as errors so they can be detected.
+        crate::util::WARNINGS.set(&crate::util::WarningsMode::Error, || {
+            t.apply_transform(
+                tr,
+                "input.js",
+                ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+                    jsx: true,
+                    ..Default::default()
+                }),
+                src,
+            )
+        })?;
+        has_errors = t.handler.has_errors();
+
+        Ok(())
+    });
+
+    has_errors
+}
+
+#[test]
+fn dev_checks_list_keys() {
+    let options = |development| Options {
+        development,
+        dev_checks: true,
+        ..Default::default()
+    };
+
+    assert!(has_warnings("items.map(item => <li />);", options(true)));
+    assert!(has_warnings(
+        "items.map(item => (<><li /></>));",
+        options(true)
+    ));
+    assert!(!has_warnings(
+        "items.map(item => <li key={item.id} />);",
+        options(true)
+    ));
+    assert!(!has_warnings(
+        "items.map(item => <li {...item} />);",
+        options(true)
+    ));
+    assert!(!has_warnings("items.map(item => <li />);", options(false)));
+}
+
+#[test]
+fn validate() {
+    let validate = || Options {
+        validate: true,
+        ..Default::default()
+    };
+
+    assert!(has_warnings("<img>children</img>;", validate()));
+    assert!(has_warnings("<br children={a} />;", validate()));
+    assert!(has_warnings("<div id='a' id='b' />;", validate()));
+    assert!(has_warnings(
+        "<div dangerouslySetInnerHTML={html}>children</div>;",
+        validate()
+    ));
+    assert!(!has_warnings("<img src='a.png' />;", validate()));
+    assert!(!has_warnings("<img>\n</img>;", validate()));
+    assert!(!has_warnings("<Img>children</Img>;", validate()));
+    assert!(!has_warnings("<div {...a} {...b} />;", validate()));
+    assert!(!has_warnings(
+        "<div dangerouslySetInnerHTML={html} />;",
+        validate()
+    ));
+}
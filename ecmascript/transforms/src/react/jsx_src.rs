@@ -46,6 +46,7 @@ impl Fold for JsxSrc {
                                         _ => unimplemented!("file name for other than real files"),
                                     },
                                     has_escape: false,
+                                    raw: None,
                                 }))),
                             }))),
                             PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
@@ -53,6 +54,7 @@ impl Fold for JsxSrc {
                                 value: Box::new(Expr::Lit(Lit::Num(Number {
                                     span: DUMMY_SP,
                                     value: (file_lines.lines[0].line_index + 1) as _,
+                                    raw: None,
                                 }))),
                             }))),
                         ],
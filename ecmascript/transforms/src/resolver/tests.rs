@@ -1041,7 +1041,7 @@ test!(
     syntax(),
     |_| chain!(
         tr(),
-        classes(),
+        classes(None, Default::default()),
         destructuring(Default::default()),
         common_js(Mark::fresh(Mark::root()), Default::default())
     ),
@@ -1533,7 +1533,7 @@ to_ts!(
     }
     "#,
     r#"
-    class PartWriter {
+    class PartWriter implements Deno.Writer__0. {
         constructor(private writer__2: Deno.Writer., readonly boundary__2: string, public headers__2: Headers, isFirstBoundary__2: boolean){
             let buf__2 = "";
             if (isFirstBoundary__2) {
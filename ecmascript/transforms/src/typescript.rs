@@ -1,3 +1,5 @@
-pub use self::strip::strip;
+#![cfg(feature = "typescript")]
+pub use self::{dts::dts, strip::strip};
 
+pub mod dts;
 pub mod strip;
@@ -0,0 +1,950 @@
+//! Emits declarations (`.d.ts`) from typescript modules.
+
+use fxhash::FxHashSet;
+use std::mem::take;
+use swc_atoms::{js_word, JsWord};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::{find_ids, private_ident, quote_ident};
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Node, Visit, VisitMut, VisitWith,
+};
+
+/// Converts a typescript module to a declaration file, which only contains
+/// signatures of the public api.
+///
+///  - Bodies of functions and values of variables are removed. Missing type
+///    annotations are inferred from simple expressions, and default to `any`.
+///  - Items which are not exported are removed, unless an exported item refers
+///    to them.
+///  - Private members of classes are emitted without types.
+///
+/// Input should not be stripped, and the output should be printed without
+/// any other pass.
+pub fn dts() -> impl Fold {
+    as_folder(Dts::default())
+}
+
+#[derive(Default)]
+struct Dts {
+    /// Names of functions and methods with overload signatures, whose
+    /// implementation should be removed.
+    overloaded: FxHashSet<JsWord>,
+}
+
+impl VisitMut for Dts {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        let is_module = m
+            .body
+            .iter()
+            .any(|item| matches!(item, ModuleItem::ModuleDecl(..)));
+
+        m.body = self.module_items(take(&mut m.body), !is_module, true);
+
+        if is_module
+            && !m
+                .body
+                .iter()
+                .any(|item| matches!(item, ModuleItem::ModuleDecl(..)))
+        {
+            // Keep it a module.
+            m.body.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
+                NamedExport {
+                    span: DUMMY_SP,
+                    specifiers: vec![],
+                    src: None,
+                    type_only: false,
+                },
+            )));
+        }
+    }
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        let items = take(&mut s.body)
+            .into_iter()
+            .map(ModuleItem::Stmt)
+            .collect();
+
+        s.body = self
+            .module_items(items, true, true)
+            .into_iter()
+            .filter_map(|item| item.stmt())
+            .collect();
+    }
+}
+
+impl Dts {
+    /// `keep_all` is true for scripts, where all declarations are global.
+    ///
+    /// `declare` is false in ambient contexts like namespaces.
+    fn module_items(
+        &mut self,
+        items: Vec<ModuleItem>,
+        keep_all: bool,
+        declare: bool,
+    ) -> Vec<ModuleItem> {
+        let old = take(&mut self.overloaded);
+        self.overloaded = overloaded_fns(&items);
+
+        // Items which are not kept yet are emitted only if they are referenced.
+        let mut out: Vec<(bool, ModuleItem)> = Vec::with_capacity(items.len());
+        for item in items {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(..)) => out.push((false, item)),
+
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { span, decl })) => {
+                    if let Some(decl) = self.decl(decl, declare) {
+                        out.push((
+                            true,
+                            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                                span,
+                                decl,
+                            })),
+                        ))
+                    }
+                }
+
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(mut export)) => {
+                    match &mut export.decl {
+                        DefaultDecl::Class(c) => self.class(&mut c.class),
+                        DefaultDecl::Fn(f) => {
+                            if f.function.body.is_some()
+                                && f.ident
+                                    .as_ref()
+                                    .map_or(false, |i| self.overloaded.contains(&i.sym))
+                            {
+                                continue;
+                            }
+                            self.function(&mut f.function, Some(MethodKind::Method))
+                        }
+                        DefaultDecl::TsInterfaceDecl(..) => {}
+                    }
+
+                    out.push((
+                        true,
+                        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export)),
+                    ))
+                }
+
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => {
+                    if let Expr::Ident(..) = *export.expr {
+                        out.push((
+                            true,
+                            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)),
+                        ));
+                        continue;
+                    }
+
+                    // export default 1;
+                    //
+                    // becomes
+                    //
+                    // declare const _default: number;
+                    // export default _default;
+                    let mut id = private_ident!("_default");
+                    id.type_ann = Some(type_ann(infer(&export.expr, true)));
+                    out.push((
+                        true,
+                        ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl {
+                            span: DUMMY_SP,
+                            kind: VarDeclKind::Const,
+                            declare,
+                            decls: vec![VarDeclarator {
+                                span: DUMMY_SP,
+                                name: Pat::Ident(id.clone()),
+                                init: None,
+                                definite: false,
+                            }],
+                        }))),
+                    ));
+                    id.type_ann = None;
+                    out.push((
+                        true,
+                        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                            span: export.span,
+                            expr: Box::new(Expr::Ident(id)),
+                        })),
+                    ));
+                }
+
+                ModuleItem::ModuleDecl(..) => out.push((true, item)),
+
+                ModuleItem::Stmt(Stmt::Decl(decl)) => {
+                    if let Some(decl) = self.decl(decl, declare) {
+                        out.push((keep_all, ModuleItem::Stmt(Stmt::Decl(decl))))
+                    }
+                }
+
+                // Statements are not part of the api.
+                ModuleItem::Stmt(..) => {}
+            }
+        }
+
+        self.overloaded = old;
+
+        // Keep items referenced by kept items.
+        let mut refs = Refs::default();
+        for (kept, item) in &out {
+            if *kept {
+                item.visit_with(&Invalid { span: DUMMY_SP }, &mut refs);
+            }
+        }
+        loop {
+            let mut changed = false;
+            for (kept, item) in &mut out {
+                if *kept {
+                    continue;
+                }
+                if let ModuleItem::Stmt(Stmt::Decl(decl)) = &*item {
+                    if decl_names(decl)
+                        .iter()
+                        .any(|name| refs.names.contains(name))
+                    {
+                        *kept = true;
+                        changed = true;
+                        item.visit_with(&Invalid { span: DUMMY_SP }, &mut refs);
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        out.into_iter()
+            .filter_map(|(kept, item)| match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(mut import)) => {
+                    if import.specifiers.is_empty() {
+                        // Side effect imports may augment global types.
+                        return Some(ModuleItem::ModuleDecl(ModuleDecl::Import(import)));
+                    }
+
+                    import.specifiers.retain(|s| {
+                        let local = match s {
+                            ImportSpecifier::Named(s) => &s.local,
+                            ImportSpecifier::Default(s) => &s.local,
+                            ImportSpecifier::Namespace(s) => &s.local,
+                        };
+                        refs.names.contains(&local.sym)
+                    });
+                    if import.specifiers.is_empty() {
+                        return None;
+                    }
+                    Some(ModuleItem::ModuleDecl(ModuleDecl::Import(import)))
+                }
+                _ if kept => Some(item),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns `None` if `decl` should be removed.
+    fn decl(&mut self, decl: Decl, declare: bool) -> Option<Decl> {
+        match decl {
+            Decl::Class(mut c) => {
+                c.declare = declare;
+                self.class(&mut c.class);
+                Some(Decl::Class(c))
+            }
+            Decl::Fn(mut f) => {
+                if f.function.body.is_some() && self.overloaded.contains(&f.ident.sym) {
+                    return None;
+                }
+                f.declare = declare;
+                self.function(&mut f.function, Some(MethodKind::Method));
+                Some(Decl::Fn(f))
+            }
+            Decl::Var(mut v) => {
+                v.declare = declare;
+                let kind = v.kind;
+                v.decls = v
+                    .decls
+                    .into_iter()
+                    .filter_map(|d| {
+                        // Destructuring patterns are not supported.
+                        let mut name = match d.name {
+                            Pat::Ident(i) => i,
+                            _ => return None,
+                        };
+                        let init = match d.init {
+                            // declare const a = 1;
+                            Some(init) if kind == VarDeclKind::Const && is_lit(&init) => Some(init),
+                            Some(init) => {
+                                if name.type_ann.is_none() {
+                                    name.type_ann = Some(type_ann(infer(&init, true)));
+                                }
+                                None
+                            }
+                            None => None,
+                        };
+                        if name.type_ann.is_none() && init.is_none() {
+                            name.type_ann =
+                                Some(type_ann(keyword(TsKeywordTypeKind::TsAnyKeyword)));
+                        }
+
+                        Some(VarDeclarator {
+                            span: d.span,
+                            name: Pat::Ident(name),
+                            init,
+                            definite: false,
+                        })
+                    })
+                    .collect();
+
+                if v.decls.is_empty() {
+                    return None;
+                }
+                Some(Decl::Var(v))
+            }
+            Decl::TsEnum(mut e) => {
+                e.declare = declare;
+                Some(Decl::TsEnum(e))
+            }
+            Decl::TsModule(mut m) => {
+                // `declare module 'foo' {}` is already ambient.
+                if !m.declare {
+                    m.declare = declare;
+                    if let Some(body) = &mut m.body {
+                        self.ns_body(body);
+                    }
+                }
+                Some(Decl::TsModule(m))
+            }
+            Decl::TsInterface(..) | Decl::TsTypeAlias(..) => Some(decl),
+        }
+    }
+
+    fn ns_body(&mut self, body: &mut TsNamespaceBody) {
+        match body {
+            TsNamespaceBody::TsModuleBlock(block) => {
+                block.body = self.module_items(take(&mut block.body), false, false);
+            }
+            TsNamespaceBody::TsNamespaceDecl(decl) => self.ns_body(&mut decl.body),
+        }
+    }
+
+    fn class(&mut self, c: &mut Class) {
+        c.decorators.clear();
+
+        let old = take(&mut self.overloaded);
+        self.overloaded = c
+            .body
+            .iter()
+            .filter_map(|member| match member {
+                ClassMember::Method(ClassMethod {
+                    key,
+                    function: Function { body: None, .. },
+                    ..
+                }) => prop_name_sym(key),
+                ClassMember::Constructor(Constructor { body: None, .. }) => {
+                    Some(js_word!("constructor"))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let mut has_private_name = false;
+        // Private members are emitted only once, even if there's a getter and a
+        // setter.
+        let mut private_members = FxHashSet::default();
+        let mut body = Vec::with_capacity(c.body.len());
+
+        for member in take(&mut c.body) {
+            match member {
+                ClassMember::Constructor(mut constructor) => {
+                    if constructor.body.is_some()
+                        && self.overloaded.contains(&js_word!("constructor"))
+                    {
+                        continue;
+                    }
+                    constructor.body = None;
+
+                    if constructor.accessibility == Some(Accessibility::Private) {
+                        constructor.params.clear();
+                    }
+
+                    let mut params = Vec::with_capacity(constructor.params.len());
+                    for param in take(&mut constructor.params) {
+                        let prop = match param {
+                            ParamOrTsParamProp::TsParamProp(prop) => prop,
+                            ParamOrTsParamProp::Param(mut param) => {
+                                self.param(&mut param);
+                                params.push(ParamOrTsParamProp::Param(param));
+                                continue;
+                            }
+                        };
+
+                        // constructor(private a: string)
+                        //
+                        // becomes
+                        //
+                        // private a;
+                        // constructor(a: string);
+                        let mut param = Param {
+                            span: prop.span,
+                            decorators: vec![],
+                            pat: match prop.param {
+                                TsParamPropParam::Ident(i) => Pat::Ident(i),
+                                TsParamPropParam::Assign(a) => Pat::Assign(a),
+                            },
+                        };
+                        self.param(&mut param);
+
+                        if let Pat::Ident(i) = &param.pat {
+                            let is_private = prop.accessibility == Some(Accessibility::Private);
+                            body.push(ClassMember::ClassProp(ClassProp {
+                                span: prop.span,
+                                key: Box::new(Expr::Ident(Ident::new(i.sym.clone(), i.span))),
+                                value: None,
+                                type_ann: if is_private { None } else { i.type_ann.clone() },
+                                is_static: false,
+                                decorators: vec![],
+                                computed: false,
+                                accessibility: prop.accessibility,
+                                is_abstract: false,
+                                is_optional: i.optional && !is_private,
+                                readonly: prop.readonly,
+                                declare: false,
+                                definite: false,
+                            }));
+                        }
+                        params.push(ParamOrTsParamProp::Param(param));
+                    }
+                    constructor.params = params;
+
+                    body.push(ClassMember::Constructor(constructor));
+                }
+
+                ClassMember::Method(mut m) => {
+                    if m.accessibility == Some(Accessibility::Private) {
+                        if let Some(sym) = prop_name_sym(&m.key) {
+                            if !private_members.insert((sym, m.is_static)) {
+                                continue;
+                            }
+                        }
+
+                        let (key, computed) = prop_name_to_expr(m.key);
+                        body.push(ClassMember::ClassProp(ClassProp {
+                            span: m.span,
+                            key,
+                            value: None,
+                            type_ann: None,
+                            is_static: m.is_static,
+                            decorators: vec![],
+                            computed,
+                            accessibility: m.accessibility,
+                            is_abstract: false,
+                            is_optional: false,
+                            readonly: false,
+                            declare: false,
+                            definite: false,
+                        }));
+                        continue;
+                    }
+
+                    if m.function.body.is_some()
+                        && prop_name_sym(&m.key).map_or(false, |sym| self.overloaded.contains(&sym))
+                    {
+                        continue;
+                    }
+
+                    self.function(&mut m.function, Some(m.kind));
+                    body.push(ClassMember::Method(m));
+                }
+
+                ClassMember::ClassProp(mut p) => {
+                    p.decorators.clear();
+                    p.declare = false;
+                    p.definite = false;
+
+                    let value = p.value.take();
+                    if p.accessibility == Some(Accessibility::Private) {
+                        p.type_ann = None;
+                        p.is_optional = false;
+                    } else if p.type_ann.is_none() {
+                        p.type_ann = Some(type_ann(match &value {
+                            Some(value) => infer(value, !p.readonly),
+                            None => keyword(TsKeywordTypeKind::TsAnyKeyword),
+                        }));
+                    }
+
+                    body.push(ClassMember::ClassProp(p));
+                }
+
+                ClassMember::PrivateMethod(..) | ClassMember::PrivateProp(..) => {
+                    has_private_name = true;
+                }
+
+                ClassMember::TsIndexSignature(..) => body.push(member),
+
+                ClassMember::Empty(..) | ClassMember::StaticBlock(..) => {}
+            }
+        }
+
+        // Private names affect the compatibility of classes.
+        if has_private_name {
+            body.insert(
+                0,
+                ClassMember::PrivateProp(PrivateProp {
+                    span: DUMMY_SP,
+                    key: PrivateName {
+                        span: DUMMY_SP,
+                        id: quote_ident!("private"),
+                    },
+                    value: None,
+                    type_ann: None,
+                    is_static: false,
+                    decorators: vec![],
+                    computed: false,
+                    accessibility: None,
+                    is_abstract: false,
+                    is_optional: false,
+                    readonly: false,
+                    definite: false,
+                }),
+            );
+        }
+
+        c.body = body;
+        self.overloaded = old;
+    }
+
+    /// `kind` is `None` for constructors.
+    fn function(&mut self, f: &mut Function, kind: Option<MethodKind>) {
+        f.decorators.clear();
+        f.params.iter_mut().for_each(|p| self.param(p));
+
+        if f.return_type.is_none() && kind != Some(MethodKind::Setter) && kind.is_some() {
+            f.return_type = Some(type_ann(infer_return(
+                f.body.as_ref(),
+                f.is_async,
+                f.is_generator,
+            )));
+        }
+
+        f.body = None;
+        f.is_async = false;
+        f.is_generator = false;
+    }
+
+    fn param(&mut self, p: &mut Param) {
+        p.decorators.clear();
+        p.pat = param_pat(take_pat(&mut p.pat));
+    }
+}
+
+/// Returns names of functions which have an overload signature.
+fn overloaded_fns(items: &[ModuleItem]) -> FxHashSet<JsWord> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Fn(f),
+                ..
+            }))
+            | ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f)))
+                if f.function.body.is_none() =>
+            {
+                Some(f.ident.sym.clone())
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl:
+                    DefaultDecl::Fn(FnExpr {
+                        ident: Some(ident),
+                        function: Function { body: None, .. },
+                    }),
+                ..
+            })) => Some(ident.sym.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns names declared by `decl`.
+fn decl_names(decl: &Decl) -> Vec<JsWord> {
+    match decl {
+        Decl::Class(c) => vec![c.ident.sym.clone()],
+        Decl::Fn(f) => vec![f.ident.sym.clone()],
+        Decl::Var(v) => find_ids::<_, Ident>(&v.decls)
+            .into_iter()
+            .map(|i| i.sym)
+            .collect(),
+        Decl::TsInterface(i) => vec![i.id.sym.clone()],
+        Decl::TsTypeAlias(a) => vec![a.id.sym.clone()],
+        Decl::TsEnum(e) => vec![e.id.sym.clone()],
+        Decl::TsModule(m) => match &m.id {
+            TsModuleName::Ident(i) => vec![i.sym.clone()],
+            TsModuleName::Str(..) => vec![],
+        },
+    }
+}
+
+/// Collects names referenced by declarations.
+#[derive(Default)]
+struct Refs {
+    names: FxHashSet<JsWord>,
+}
+
+impl Visit for Refs {
+    fn visit_expr(&mut self, e: &Expr, _: &dyn Node) {
+        match e {
+            Expr::Ident(i) => {
+                self.names.insert(i.sym.clone());
+            }
+            _ => e.visit_children_with(self),
+        }
+    }
+
+    fn visit_named_export(&mut self, e: &NamedExport, _: &dyn Node) {
+        if e.src.is_some() {
+            return;
+        }
+
+        for s in &e.specifiers {
+            if let ExportSpecifier::Named(s) = s {
+                self.names.insert(s.orig.sym.clone());
+            }
+        }
+    }
+
+    fn visit_ts_entity_name(&mut self, n: &TsEntityName, _: &dyn Node) {
+        match n {
+            TsEntityName::Ident(i) => {
+                self.names.insert(i.sym.clone());
+            }
+            TsEntityName::TsQualifiedName(q) => q.left.visit_with(&**q, self),
+        }
+    }
+}
+
+fn prop_name_sym(key: &PropName) -> Option<JsWord> {
+    match key {
+        PropName::Ident(i) => Some(i.sym.clone()),
+        PropName::Str(s) => Some(s.value.clone()),
+        _ => None,
+    }
+}
+
+fn prop_name_to_expr(key: PropName) -> (Box<Expr>, bool) {
+    match key {
+        PropName::Ident(i) => (Box::new(Expr::Ident(i)), false),
+        PropName::Str(s) => (Box::new(Expr::Lit(Lit::Str(s))), false),
+        PropName::Num(n) => (Box::new(Expr::Lit(Lit::Num(n))), false),
+        PropName::Computed(c) => (c.expr, true),
+    }
+}
+
+/// Removes default values, which are not allowed in declarations.
+fn param_pat(pat: Pat) -> Pat {
+    match pat {
+        // a = 1
+        //
+        // becomes
+        //
+        // a?: number
+        Pat::Assign(AssignPat {
+            left,
+            right,
+            type_ann,
+            ..
+        }) => match param_pat(*left) {
+            Pat::Ident(mut i) => {
+                i.optional = true;
+                if i.type_ann.is_none() {
+                    i.type_ann = type_ann.or_else(|| Some(self::type_ann(infer(&right, true))));
+                }
+                Pat::Ident(i)
+            }
+            pat => pat,
+        },
+        Pat::Array(mut a) => {
+            a.elems = a
+                .elems
+                .into_iter()
+                .map(|elem| elem.map(strip_default))
+                .collect();
+            Pat::Array(a)
+        }
+        Pat::Object(mut o) => {
+            for prop in &mut o.props {
+                match prop {
+                    ObjectPatProp::KeyValue(p) => *p.value = strip_default(take_pat(&mut p.value)),
+                    ObjectPatProp::Assign(p) => p.value = None,
+                    ObjectPatProp::Rest(..) => {}
+                }
+            }
+            Pat::Object(o)
+        }
+        _ => pat,
+    }
+}
+
+/// Removes default values in destructuring patterns.
+fn strip_default(pat: Pat) -> Pat {
+    match pat {
+        Pat::Assign(a) => strip_default(*a.left),
+        _ => param_pat(pat),
+    }
+}
+
+fn take_pat(pat: &mut Pat) -> Pat {
+    std::mem::replace(pat, Pat::Invalid(Invalid { span: DUMMY_SP }))
+}
+
+fn type_ann(ty: TsType) -> TsTypeAnn {
+    TsTypeAnn {
+        span: DUMMY_SP,
+        type_ann: Box::new(ty),
+    }
+}
+
+fn keyword(kind: TsKeywordTypeKind) -> TsType {
+    TsType::TsKeywordType(TsKeywordType {
+        span: DUMMY_SP,
+        kind,
+    })
+}
+
+fn type_ref(name: &str, params: Vec<TsType>) -> TsType {
+    TsType::TsTypeRef(TsTypeRef {
+        span: DUMMY_SP,
+        type_name: TsEntityName::Ident(quote_ident!(name)),
+        type_params: if params.is_empty() {
+            None
+        } else {
+            Some(TsTypeParamInstantiation {
+                span: DUMMY_SP,
+                params: params.into_iter().map(Box::new).collect(),
+            })
+        },
+    })
+}
+
+/// Returns true if `e` can be the value of `declare const`.
+fn is_lit(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(Lit::Str(..)) | Expr::Lit(Lit::Num(..)) | Expr::Lit(Lit::Bool(..)) => true,
+        Expr::Lit(Lit::BigInt(..)) => true,
+        Expr::Unary(UnaryExpr {
+            op: op!(unary, "-"),
+            arg,
+            ..
+        }) => matches!(&**arg, Expr::Lit(Lit::Num(..)) | Expr::Lit(Lit::BigInt(..))),
+        _ => false,
+    }
+}
+
+/// Infers the type of simple expressions.
+///
+/// Literal types are widened if `widen` is true, like `let a = 1`.
+fn infer(e: &Expr, widen: bool) -> TsType {
+    match e {
+        Expr::Lit(lit) => match lit {
+            Lit::Str(..) if widen => keyword(TsKeywordTypeKind::TsStringKeyword),
+            Lit::Num(..) if widen => keyword(TsKeywordTypeKind::TsNumberKeyword),
+            Lit::Bool(..) if widen => keyword(TsKeywordTypeKind::TsBooleanKeyword),
+            Lit::BigInt(..) if widen => keyword(TsKeywordTypeKind::TsBigIntKeyword),
+            Lit::Str(s) => TsType::TsLitType(TsLitType {
+                span: DUMMY_SP,
+                lit: TsLit::Str(s.clone()),
+            }),
+            Lit::Num(n) => TsType::TsLitType(TsLitType {
+                span: DUMMY_SP,
+                lit: TsLit::Number(n.clone()),
+            }),
+            Lit::Bool(b) => TsType::TsLitType(TsLitType {
+                span: DUMMY_SP,
+                lit: TsLit::Bool(*b),
+            }),
+            Lit::BigInt(n) => TsType::TsLitType(TsLitType {
+                span: DUMMY_SP,
+                lit: TsLit::BigInt(n.clone()),
+            }),
+            Lit::Regex(..) => type_ref("RegExp", vec![]),
+            Lit::Null(..) | Lit::JSXText(..) => keyword(TsKeywordTypeKind::TsAnyKeyword),
+        },
+        Expr::Tpl(..) => keyword(TsKeywordTypeKind::TsStringKeyword),
+        Expr::Unary(UnaryExpr { op, arg, .. }) => match op {
+            op!(unary, "-") | op!(unary, "+") | op!("~") => match &**arg {
+                Expr::Lit(Lit::BigInt(..)) => keyword(TsKeywordTypeKind::TsBigIntKeyword),
+                _ => keyword(TsKeywordTypeKind::TsNumberKeyword),
+            },
+            op!("!") | op!("delete") => keyword(TsKeywordTypeKind::TsBooleanKeyword),
+            op!("typeof") => keyword(TsKeywordTypeKind::TsStringKeyword),
+            op!("void") => keyword(TsKeywordTypeKind::TsUndefinedKeyword),
+        },
+        Expr::Array(ArrayLit { elems, .. }) => {
+            let mut elem_type = None;
+            for elem in elems {
+                let ty = match elem {
+                    Some(ExprOrSpread { spread: None, expr }) => infer(expr, true),
+                    _ => keyword(TsKeywordTypeKind::TsAnyKeyword),
+                };
+                match &elem_type {
+                    Some(prev) if *prev != ty => {
+                        elem_type = Some(keyword(TsKeywordTypeKind::TsAnyKeyword));
+                        break;
+                    }
+                    _ => elem_type = Some(ty),
+                }
+            }
+
+            TsType::TsArrayType(TsArrayType {
+                span: DUMMY_SP,
+                elem_type: Box::new(
+                    elem_type.unwrap_or_else(|| keyword(TsKeywordTypeKind::TsAnyKeyword)),
+                ),
+            })
+        }
+        Expr::Object(ObjectLit { props, .. }) => {
+            let mut members = Vec::with_capacity(props.len());
+            for prop in props {
+                let (key, ty) = match prop {
+                    PropOrSpread::Prop(prop) => match &**prop {
+                        Prop::KeyValue(KeyValueProp { key, value }) => {
+                            (key.clone(), infer(value, true))
+                        }
+                        Prop::Shorthand(..)
+                        | Prop::Assign(..)
+                        | Prop::Getter(..)
+                        | Prop::Setter(..)
+                        | Prop::Method(..) => return keyword(TsKeywordTypeKind::TsAnyKeyword),
+                    },
+                    PropOrSpread::Spread(..) => return keyword(TsKeywordTypeKind::TsAnyKeyword),
+                };
+                let (key, computed) = prop_name_to_expr(key);
+                if computed {
+                    return keyword(TsKeywordTypeKind::TsAnyKeyword);
+                }
+
+                members.push(TsTypeElement::TsPropertySignature(TsPropertySignature {
+                    span: DUMMY_SP,
+                    readonly: false,
+                    key,
+                    computed,
+                    optional: false,
+                    init: None,
+                    params: vec![],
+                    type_ann: Some(type_ann(ty)),
+                    type_params: None,
+                }))
+            }
+
+            TsType::TsTypeLit(TsTypeLit {
+                span: DUMMY_SP,
+                members,
+            })
+        }
+        Expr::New(NewExpr {
+            callee, type_args, ..
+        }) => match &**callee {
+            Expr::Ident(i) => TsType::TsTypeRef(TsTypeRef {
+                span: DUMMY_SP,
+                type_name: TsEntityName::Ident(Ident::new(i.sym.clone(), DUMMY_SP)),
+                type_params: type_args.clone(),
+            }),
+            _ => keyword(TsKeywordTypeKind::TsAnyKeyword),
+        },
+        Expr::Arrow(f) => {
+            let return_type = match &f.return_type {
+                Some(ty) => ty.clone(),
+                None => type_ann(match &f.body {
+                    BlockStmtOrExpr::BlockStmt(body) => {
+                        infer_return(Some(body), f.is_async, f.is_generator)
+                    }
+                    BlockStmtOrExpr::Expr(..) => keyword(TsKeywordTypeKind::TsAnyKeyword),
+                }),
+            };
+
+            fn_type(f.params.clone(), f.type_params.clone(), return_type)
+        }
+        Expr::Fn(FnExpr { function: f, .. }) => {
+            let return_type = match &f.return_type {
+                Some(ty) => ty.clone(),
+                None => type_ann(infer_return(f.body.as_ref(), f.is_async, f.is_generator)),
+            };
+
+            fn_type(
+                f.params.iter().map(|p| p.pat.clone()).collect(),
+                f.type_params.clone(),
+                return_type,
+            )
+        }
+        Expr::TsAs(TsAsExpr { type_ann, .. })
+        | Expr::TsTypeAssertion(TsTypeAssertion { type_ann, .. }) => (**type_ann).clone(),
+        Expr::Paren(ParenExpr { expr, .. }) => infer(expr, widen),
+        _ => keyword(TsKeywordTypeKind::TsAnyKeyword),
+    }
+}
+
+fn fn_type(
+    params: Vec<Pat>,
+    type_params: Option<TsTypeParamDecl>,
+    return_type: TsTypeAnn,
+) -> TsType {
+    let mut fn_params = Vec::with_capacity(params.len());
+    for pat in params {
+        fn_params.push(match param_pat(pat) {
+            Pat::Ident(i) => TsFnParam::Ident(i),
+            Pat::Array(a) => TsFnParam::Array(a),
+            Pat::Rest(r) => TsFnParam::Rest(r),
+            Pat::Object(o) => TsFnParam::Object(o),
+            _ => return keyword(TsKeywordTypeKind::TsAnyKeyword),
+        });
+    }
+
+    TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(TsFnType {
+        span: DUMMY_SP,
+        params: fn_params,
+        type_params,
+        type_ann: return_type,
+    }))
+}
+
+/// Returns `void` if the function does not return a value, and `any`
+/// otherwise.
+fn infer_return(body: Option<&BlockStmt>, is_async: bool, is_generator: bool) -> TsType {
+    if is_generator {
+        return keyword(TsKeywordTypeKind::TsAnyKeyword);
+    }
+
+    let mut v = ReturnFinder::default();
+    if let Some(body) = body {
+        body.visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+    }
+    let ty = if v.found {
+        keyword(TsKeywordTypeKind::TsAnyKeyword)
+    } else {
+        keyword(TsKeywordTypeKind::TsVoidKeyword)
+    };
+
+    if is_async {
+        type_ref("Promise", vec![ty])
+    } else {
+        ty
+    }
+}
+
+/// Finds `return` statements with a value.
+#[derive(Default)]
+struct ReturnFinder {
+    found: bool,
+}
+
+impl Visit for ReturnFinder {
+    noop_visit_type!();
+
+    fn visit_return_stmt(&mut self, s: &ReturnStmt, _: &dyn Node) {
+        if s.arg.is_some() {
+            self.found = true;
+        }
+    }
+
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+    fn visit_class(&mut self, _: &Class, _: &dyn Node) {}
+}
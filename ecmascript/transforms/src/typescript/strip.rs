@@ -1,12 +1,12 @@
-use crate::{
-    ext::MapWithMut,
-    util::{prepend_stmts, var::VarCollector, ExprFactory},
-};
-use fxhash::FxHashMap;
+use crate::util::{prepend_stmts, var::VarCollector, ExprFactory, HANDLER};
+use fxhash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
-use std::mem::take;
+use std::{collections::hash_map::Entry, mem::take};
 use swc_atoms::{js_word, JsWord};
-use swc_common::{util::move_map::MoveMap, Span, Spanned, SyntaxContext, DUMMY_SP};
+use swc_common::{
+    util::{move_map::MoveMap, take::Take},
+    Span, Spanned, SyntaxContext, DUMMY_SP,
+};
 use swc_ecma_ast::*;
 use swc_ecma_utils::{ident::IdentLike, Id, StmtLike};
 use swc_ecma_visit::{as_folder, Fold, Node, Visit, VisitMut, VisitMutWith, VisitWith};
@@ -14,7 +14,7 @@ use swc_ecma_visit::{as_folder, Fold, Node, Visit, VisitMut, VisitMutWith, Visit
 /// Value does not contain TsLit::Bool
 type EnumValues = FxHashMap<Id, TsLit>;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 // TODO(nayeemrmn): The name should be `ImportsNotUsedAsValues`. Rename as a
 // breaking change.
@@ -36,6 +36,93 @@ impl Default for ImportNotUsedAsValues {
 #[non_exhaustive]
 pub struct Config {
     pub import_not_used_as_values: ImportNotUsedAsValues,
+
+    /// Reports constructs which can't be compiled without type information
+    /// of other files, like `--isolatedModules` of tsc.
+    #[serde(default)]
+    pub isolated_modules: bool,
+
+    /// Values of const enums exported by other modules, keyed by import
+    /// specifiers like `./enums`. Members of imported const enums are
+    /// inlined, and the imports are removed if they are not used anymore.
+    ///
+    /// Values of a module can be collected with [collect_const_enums] when
+    /// it's compiled, or from its declaration file.
+    #[serde(default)]
+    pub const_enums: FxHashMap<JsWord, ConstEnums>,
+
+    /// Pragma used by jsx elements, which defaults to `React.createElement`.
+    /// The import of its root is kept if the module contains jsx.
+    #[serde(default)]
+    pub pragma: Option<String>,
+
+    /// Pragma used by jsx fragments, which defaults to `React.Fragment`.
+    #[serde(default)]
+    pub pragma_frag: Option<String>,
+}
+
+/// Value of a member of a const enum.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConstEnumValue {
+    Number(f64),
+    Str(JsWord),
+}
+
+/// Values of const enums exported by a module, keyed by names of enums and
+/// their members.
+pub type ConstEnums = FxHashMap<JsWord, FxHashMap<JsWord, ConstEnumValue>>;
+
+/// Collects values of const enums exported by `module`, including
+/// `export declare const enum` of declaration files.
+///
+/// Members which are not constant are skipped, along with the members after
+/// them without initializers.
+pub fn collect_const_enums(module: &Module) -> ConstEnums {
+    let mut enums = ConstEnums::default();
+
+    for item in &module.body {
+        let e = match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::TsEnum(e),
+                ..
+            })) if e.is_const => e,
+            _ => continue,
+        };
+
+        let mut default = Some(0);
+        let mut values = EnumValues::default();
+        let mut members = FxHashMap::default();
+        for m in &e.members {
+            let id = match &m.id {
+                TsEnumMemberId::Ident(i) => i.clone(),
+                TsEnumMemberId::Str(s) => Ident::new(s.value.clone(), s.span),
+            };
+            let val = match compute(e, m.id.span(), &mut values, default, m.init.as_deref()) {
+                Ok(val) => val,
+                Err(()) => {
+                    default = None;
+                    continue;
+                }
+            };
+
+            default = match &val {
+                TsLit::Number(n) => Some(n.value as i32 + 1),
+                _ => None,
+            };
+            let value = match &val {
+                TsLit::Number(n) => ConstEnumValue::Number(n.value),
+                TsLit::Str(s) => ConstEnumValue::Str(s.value.clone()),
+                _ => continue,
+            };
+            values.insert(id.to_id(), val);
+            members.insert(id.sym, value);
+        }
+
+        enums.insert(e.id.sym.clone(), members);
+    }
+
+    enums
 }
 
 pub fn strip_with_config(config: Config) -> impl Fold {
@@ -57,6 +144,23 @@ struct Strip {
     scope: Scope,
     is_side_effect_import: bool,
     is_type_only_export: bool,
+    /// True while visiting specifiers of `export { a }`.
+    is_local_export: bool,
+
+    /// Imported identifiers used as a value, excluding exports.
+    imported_values: FxHashSet<Id>,
+    /// Imported identifiers in `export { a }`.
+    reexported_imports: Vec<Ident>,
+    /// `declare const enum`
+    ambient_const_enums: FxHashSet<Id>,
+    /// Objects of member expressions which may be ambient const enums.
+    enum_accesses: Vec<(Id, Span)>,
+    /// Imports of modules in [Config::const_enums], mapped to the module
+    /// specifier and the imported name. The name is [None] for namespace
+    /// imports.
+    const_enum_imports: FxHashMap<Id, (JsWord, Option<JsWord>)>,
+    /// Roots of the jsx pragmas used by the module, like `React`.
+    jsx_pragma_roots: Vec<JsWord>,
 }
 
 #[derive(Default)]
@@ -107,7 +211,16 @@ impl Strip {
                 }
             }
 
-            Decl::TsEnum(TsEnumDecl { ref id, .. }) => {
+            Decl::TsEnum(TsEnumDecl {
+                ref id,
+                declare,
+                is_const,
+                ..
+            }) => {
+                if declare && is_const {
+                    self.ambient_const_enums.insert(id.to_id());
+                }
+
                 // Currently swc cannot remove constant enums
                 self.store(id.sym.clone(), id.span.ctxt, true);
                 self.store(id.sym.clone(), id.span.ctxt, false);
@@ -150,6 +263,23 @@ impl Strip {
                 *n = expr;
             }
 
+            Expr::Member(e) if self.const_enum_value(e).is_some() => {
+                let span = e.span;
+                *n = match self.const_enum_value(e).unwrap() {
+                    ConstEnumValue::Number(value) => Expr::Lit(Lit::Num(Number {
+                        span,
+                        value: *value,
+                        raw: None,
+                    })),
+                    ConstEnumValue::Str(value) => Expr::Lit(Lit::Str(Str {
+                        span,
+                        value: value.clone(),
+                        has_escape: false,
+                        raw: None,
+                    })),
+                };
+            }
+
             Expr::Member(MemberExpr {
                 obj,
                 prop,
@@ -183,155 +313,6 @@ impl Strip {
     where
         T: StmtLike,
     {
-        /// Called only for enums.
-        ///
-        /// If both of the default value and the initialization is None, this
-        /// method returns [Err].
-        fn compute(
-            e: &TsEnumDecl,
-            span: Span,
-            values: &mut EnumValues,
-            default: Option<i32>,
-            init: Option<&Expr>,
-        ) -> Result<TsLit, ()> {
-            fn compute_bin(
-                e: &TsEnumDecl,
-                span: Span,
-                values: &mut EnumValues,
-                expr: &BinExpr,
-            ) -> Result<TsLit, ()> {
-                let l = compute(e, span, values, None, Some(&expr.left))?;
-                let r = compute(e, span, values, None, Some(&expr.right))?;
-
-                Ok(match (l, r) {
-                    (
-                        TsLit::Number(Number { value: l, .. }),
-                        TsLit::Number(Number { value: r, .. }),
-                    ) => {
-                        TsLit::Number(Number {
-                            span,
-                            value: match expr.op {
-                                op!(bin, "+") => l + r,
-                                op!(bin, "-") => l - r,
-                                op!("*") => l * r,
-                                op!("/") => l / r,
-
-                                // TODO
-                                op!("&") => ((l.round() as i64) & (r.round() as i64)) as _,
-                                op!("|") => ((l.round() as i64) | (r.round() as i64)) as _,
-                                op!("^") => ((l.round() as i64) ^ (r.round() as i64)) as _,
-
-                                op!("<<") => ((l.round() as i64) << (r.round() as i64)) as _,
-                                op!(">>") => ((l.round() as i64) >> (r.round() as i64)) as _,
-                                // TODO: Verify this
-                                op!(">>>") => ((l.round() as u64) >> (r.round() as u64)) as _,
-                                _ => Err(())?,
-                            },
-                        })
-                    }
-                    (TsLit::Str(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
-                        span,
-                        value: format!("{}{}", l.value, r.value).into(),
-                        has_escape: l.has_escape || r.has_escape,
-                    }),
-                    (TsLit::Number(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => {
-                        TsLit::Str(Str {
-                            span,
-                            value: format!("{}{}", l.value, r.value).into(),
-                            has_escape: r.has_escape,
-                        })
-                    }
-                    (TsLit::Str(l), TsLit::Number(r)) if expr.op == op!(bin, "+") => {
-                        TsLit::Str(Str {
-                            span,
-                            value: format!("{}{}", l.value, r.value).into(),
-                            has_escape: l.has_escape,
-                        })
-                    }
-                    _ => Err(())?,
-                })
-            }
-
-            if let Some(expr) = init {
-                match expr {
-                    Expr::Lit(Lit::Str(s)) => return Ok(TsLit::Str(s.clone())),
-                    Expr::Lit(Lit::Num(s)) => return Ok(TsLit::Number(*s)),
-                    Expr::Bin(ref bin) => return compute_bin(e, span, values, &bin),
-                    Expr::Paren(ref paren) => {
-                        return compute(e, span, values, default, Some(&paren.expr))
-                    }
-
-                    Expr::Ident(ref id) => {
-                        if let Some(v) = values.get(&id.clone().into_id()) {
-                            return Ok(v.clone());
-                        }
-                        //
-                        for m in e.members.iter() {
-                            match m.id {
-                                TsEnumMemberId::Str(Str { value: ref sym, .. })
-                                | TsEnumMemberId::Ident(Ident { ref sym, .. }) => {
-                                    if *sym == id.sym {
-                                        return compute(
-                                            e,
-                                            span,
-                                            values,
-                                            None,
-                                            m.init.as_ref().map(|v| &**v),
-                                        );
-                                    }
-                                }
-                            }
-                        }
-                        return Err(());
-                    }
-                    Expr::Unary(ref expr) => {
-                        let v = compute(e, span, values, None, Some(&expr.arg))?;
-                        match v {
-                            TsLit::BigInt(BigInt { .. }) => {}
-                            TsLit::Number(Number { value: v, .. }) => {
-                                return Ok(TsLit::Number(Number {
-                                    span,
-                                    value: match expr.op {
-                                        op!(unary, "+") => v,
-                                        op!(unary, "-") => -v,
-                                        op!("!") => {
-                                            if v == 0.0f64 {
-                                                0.0
-                                            } else {
-                                                1.0
-                                            }
-                                        }
-                                        op!("~") => (!(v as i32)) as f64,
-                                        _ => Err(())?,
-                                    },
-                                }))
-                            }
-                            TsLit::Str(_) => {}
-                            TsLit::Bool(_) => {}
-                            TsLit::Tpl(_) => {}
-                        }
-                    }
-
-                    Expr::Tpl(ref t) if t.exprs.is_empty() => {
-                        if let Some(v) = &t.quasis[0].cooked {
-                            return Ok(v.clone().into());
-                        }
-                    }
-
-                    _ => {}
-                }
-            } else {
-                if let Some(value) = default {
-                    return Ok(TsLit::Number(Number {
-                        span,
-                        value: value as _,
-                    }));
-                }
-            }
-
-            Err(())
-        }
-
         let id = e.id.clone();
 
         let mut default = 0;
@@ -351,7 +332,7 @@ impl Strip {
                 )
                 .map(|val| {
                     match val {
-                        TsLit::Number(n) => {
+                        TsLit::Number(ref n) => {
                             default = n.value as i32 + 1;
                         }
                         _ => {}
@@ -425,6 +406,7 @@ impl Strip {
                                             span: i.span,
                                             value: i.sym,
                                             has_escape: false,
+                                            raw: None,
                                         },
                                     };
                                     let prop = if no_init_required {
@@ -497,14 +479,377 @@ impl Strip {
     }
 }
 
+impl Strip {
+    /// Returns the value of `e` if it's a member of an imported const enum,
+    /// like `Enum.A` or `ns.Enum['A']`.
+    fn const_enum_value(&self, e: &MemberExpr) -> Option<&ConstEnumValue> {
+        fn prop_name(e: &MemberExpr) -> Option<&JsWord> {
+            match &*e.prop {
+                Expr::Ident(i) if !e.computed => Some(&i.sym),
+                Expr::Lit(Lit::Str(s)) if e.computed => Some(&s.value),
+                _ => None,
+            }
+        }
+
+        let member = prop_name(e)?;
+        let (src, name) = match &e.obj {
+            ExprOrSuper::Expr(obj) => match &**obj {
+                Expr::Ident(i) => {
+                    let (src, name) = self.const_enum_imports.get(&i.to_id())?;
+                    (src, name.as_ref()?)
+                }
+                Expr::Member(
+                    ns
+                    @
+                    MemberExpr {
+                        obj: ExprOrSuper::Expr(ns_obj),
+                        ..
+                    },
+                ) => match &**ns_obj {
+                    Expr::Ident(i) => match self.const_enum_imports.get(&i.to_id())? {
+                        (src, None) => (src, prop_name(ns)?),
+                        _ => return None,
+                    },
+                    _ => return None,
+                },
+                _ => return None,
+            },
+            ExprOrSuper::Super(..) => return None,
+        };
+
+        self.config.const_enums.get(src)?.get(name)?.get(member)
+    }
+}
+
+/// Called only for enums.
+///
+/// If both of the default value and the initialization is None, this
+/// function returns [Err].
+fn compute(
+    e: &TsEnumDecl,
+    span: Span,
+    values: &mut EnumValues,
+    default: Option<i32>,
+    init: Option<&Expr>,
+) -> Result<TsLit, ()> {
+    fn compute_bin(
+        e: &TsEnumDecl,
+        span: Span,
+        values: &mut EnumValues,
+        expr: &BinExpr,
+    ) -> Result<TsLit, ()> {
+        let l = compute(e, span, values, None, Some(&expr.left))?;
+        let r = compute(e, span, values, None, Some(&expr.right))?;
+
+        Ok(match (l, r) {
+            (TsLit::Number(Number { value: l, .. }), TsLit::Number(Number { value: r, .. })) => {
+                TsLit::Number(Number {
+                    span,
+                    value: match expr.op {
+                        op!(bin, "+") => l + r,
+                        op!(bin, "-") => l - r,
+                        op!("*") => l * r,
+                        op!("/") => l / r,
+
+                        // TODO
+                        op!("&") => ((l.round() as i64) & (r.round() as i64)) as _,
+                        op!("|") => ((l.round() as i64) | (r.round() as i64)) as _,
+                        op!("^") => ((l.round() as i64) ^ (r.round() as i64)) as _,
+
+                        op!("<<") => ((l.round() as i64) << (r.round() as i64)) as _,
+                        op!(">>") => ((l.round() as i64) >> (r.round() as i64)) as _,
+                        // TODO: Verify this
+                        op!(">>>") => ((l.round() as u64) >> (r.round() as u64)) as _,
+                        _ => Err(())?,
+                    },
+                    raw: None,
+                })
+            }
+            (TsLit::Str(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
+                span,
+                value: format!("{}{}", l.value, r.value).into(),
+                has_escape: l.has_escape || r.has_escape,
+                raw: None,
+            }),
+            (TsLit::Number(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
+                span,
+                value: format!("{}{}", l.value, r.value).into(),
+                has_escape: r.has_escape,
+                raw: None,
+            }),
+            (TsLit::Str(l), TsLit::Number(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
+                span,
+                value: format!("{}{}", l.value, r.value).into(),
+                has_escape: l.has_escape,
+                raw: None,
+            }),
+            _ => Err(())?,
+        })
+    }
+
+    if let Some(expr) = init {
+        match expr {
+            Expr::Lit(Lit::Str(s)) => return Ok(TsLit::Str(s.clone())),
+            Expr::Lit(Lit::Num(s)) => return Ok(TsLit::Number(s.clone())),
+            Expr::Bin(ref bin) => return compute_bin(e, span, values, bin),
+            Expr::Paren(ref paren) => return compute(e, span, values, default, Some(&paren.expr)),
+
+            Expr::Ident(ref id) => {
+                if let Some(v) = values.get(&id.clone().into_id()) {
+                    return Ok(v.clone());
+                }
+                //
+                for m in e.members.iter() {
+                    match m.id {
+                        TsEnumMemberId::Str(Str { value: ref sym, .. })
+                        | TsEnumMemberId::Ident(Ident { ref sym, .. }) => {
+                            if *sym == id.sym {
+                                return compute(e, span, values, None, m.init.as_deref());
+                            }
+                        }
+                    }
+                }
+                return Err(());
+            }
+            Expr::Unary(ref expr) => {
+                let v = compute(e, span, values, None, Some(&expr.arg))?;
+                match v {
+                    TsLit::BigInt(BigInt { .. }) => {}
+                    TsLit::Number(Number { value: v, .. }) => {
+                        return Ok(TsLit::Number(Number {
+                            span,
+                            value: match expr.op {
+                                op!(unary, "+") => v,
+                                op!(unary, "-") => -v,
+                                op!("!") => {
+                                    if v == 0.0f64 {
+                                        0.0
+                                    } else {
+                                        1.0
+                                    }
+                                }
+                                op!("~") => (!(v as i32)) as f64,
+                                _ => Err(())?,
+                            },
+                            raw: None,
+                        }))
+                    }
+                    TsLit::Str(_) => {}
+                    TsLit::Bool(_) => {}
+                    TsLit::Tpl(_) => {}
+                }
+            }
+
+            Expr::Tpl(ref t) if t.exprs.is_empty() => {
+                if let Some(v) = &t.quasis[0].cooked {
+                    return Ok(v.clone().into());
+                }
+            }
+
+            _ => {}
+        }
+    } else {
+        if let Some(value) = default {
+            return Ok(TsLit::Number(Number {
+                span,
+                value: value as _,
+                raw: None,
+            }));
+        }
+    }
+
+    Err(())
+}
+
+impl Strip {
+    /// Reports constructs which can't be compiled per file.
+    fn check_isolated_modules(&mut self) {
+        if !self.config.isolated_modules || !HANDLER.is_set() {
+            return;
+        }
+
+        for i in take(&mut self.reexported_imports) {
+            let only_type = match self.scope.imported_idents.get(&i.to_id()) {
+                Some(info) => info.has_type && !self.imported_values.contains(&i.to_id()),
+                None => false,
+            };
+
+            if only_type {
+                HANDLER.with(|handler| {
+                    handler
+                        .struct_span_err(
+                            i.span,
+                            &format!(
+                                "`{}` is imported and used only as a type. Re-exporting a type \
+                                 with isolated modules requires using `export type {{ {} }}`",
+                                i.sym, i.sym
+                            ),
+                        )
+                        .emit()
+                });
+            }
+        }
+
+        for (id, span) in take(&mut self.enum_accesses) {
+            if self.ambient_const_enums.contains(&id) {
+                HANDLER.with(|handler| {
+                    handler
+                        .struct_span_err(
+                            span,
+                            &format!(
+                                "`{}` is an ambient const enum, which can't be accessed with \
+                                 isolated modules as its values are not known",
+                                id.0
+                            ),
+                        )
+                        .emit()
+                });
+            }
+        }
+    }
+}
+
+impl Strip {
+    /// Removes specifiers of imports which are not referenced once types are
+    /// stripped, like tsc. This catches namespace imports and usages removed
+    /// along with typescript only constructs.
+    ///
+    /// An import without remaining specifiers is removed, unless
+    /// `import_not_used_as_values` is `preserve`. Imports without specifiers
+    /// in the input are always preserved.
+    fn remove_unused_imports(&self, items: &mut Vec<ModuleItem>) {
+        let mut v = UsedIdents {
+            used: Default::default(),
+            pragma_roots: &self.jsx_pragma_roots,
+        };
+        items.visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+
+        let preserve = self.config.import_not_used_as_values == ImportNotUsedAsValues::Preserve;
+        items.retain(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) if !import.specifiers.is_empty() => {
+                preserve || import.specifiers.iter().any(|s| v.is_used(s))
+            }
+            _ => true,
+        });
+
+        for item in items.iter_mut() {
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item {
+                import.specifiers.retain(|s| v.is_used(s));
+            }
+        }
+    }
+}
+
+/// Identifiers referenced by a module, except the ones declared by imports.
+struct UsedIdents<'a> {
+    used: FxHashSet<Id>,
+    /// Identifiers referenced once jsx is transformed.
+    pragma_roots: &'a [JsWord],
+}
+
+impl UsedIdents<'_> {
+    fn is_used(&self, s: &ImportSpecifier) -> bool {
+        let local = match s {
+            ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+            | ImportSpecifier::Named(ImportNamedSpecifier { local, .. })
+            | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => local,
+        };
+        self.used.contains(&local.to_id()) || self.pragma_roots.contains(&local.sym)
+    }
+}
+
+impl Visit for UsedIdents<'_> {
+    noop_visit_type!();
+
+    fn visit_import_decl(&mut self, _: &ImportDecl, _: &dyn Node) {}
+
+    fn visit_ident(&mut self, i: &Ident, _: &dyn Node) {
+        self.used.insert(i.to_id());
+    }
+
+    fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+        e.obj.visit_with(e as _, self);
+
+        if e.computed {
+            e.prop.visit_with(e as _, self);
+        }
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
+        if let PropName::Computed(c) = n {
+            c.visit_with(n as _, self);
+        }
+    }
+}
+
+impl Strip {
+    /// Marks imports of the root of a jsx pragma as used, as the jsx transform
+    /// may run after this pass.
+    fn use_jsx_pragma(&mut self, frag: bool) {
+        let pragma = if frag {
+            self.config
+                .pragma_frag
+                .as_deref()
+                .unwrap_or("React.Fragment")
+        } else {
+            self.config
+                .pragma
+                .as_deref()
+                .unwrap_or("React.createElement")
+        };
+        let root = JsWord::from(pragma.split('.').next().unwrap_or(pragma).trim());
+        if self.jsx_pragma_roots.contains(&root) {
+            return;
+        }
+
+        for ((sym, _), info) in self.scope.imported_idents.iter_mut() {
+            if *sym == root {
+                info.has_concrete = true;
+            }
+        }
+        self.jsx_pragma_roots.push(root);
+    }
+}
+
 impl Visit for Strip {
+    fn visit_jsx_element(&mut self, n: &JSXElement, _: &dyn Node) {
+        self.use_jsx_pragma(false);
+        n.visit_children_with(self);
+    }
+
+    fn visit_jsx_fragment(&mut self, n: &JSXFragment, _: &dyn Node) {
+        self.use_jsx_pragma(false);
+        self.use_jsx_pragma(true);
+        n.visit_children_with(self);
+    }
+
     fn visit_ident(&mut self, n: &Ident, _: &dyn Node) {
         let is_type_only_export = self.is_type_only_export;
-        self.scope
+        let is_local_export = self.is_local_export;
+        let imported = self
+            .scope
             .imported_idents
             .entry((n.sym.clone(), n.span.ctxt()))
             .and_modify(|v| v.has_concrete = !is_type_only_export);
 
+        if let Entry::Occupied(..) = imported {
+            if is_local_export {
+                self.reexported_imports.push(n.clone());
+            } else {
+                self.imported_values.insert(n.to_id());
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_member_expr(&mut self, n: &MemberExpr, _: &dyn Node) {
+        if let ExprOrSuper::Expr(obj) = &n.obj {
+            if let Expr::Ident(obj) = &**obj {
+                self.enum_accesses.push((obj.to_id(), n.span));
+            }
+        }
+
         n.visit_children_with(self);
     }
 
@@ -531,9 +876,11 @@ impl Visit for Strip {
         n.iter().for_each(|n| {
             if let ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) = n {
                 self.is_type_only_export = export.type_only;
+                self.is_local_export = !export.type_only && export.src.is_none();
             }
             n.visit_with(&Invalid { span: DUMMY_SP }, self);
             self.is_type_only_export = false;
+            self.is_local_export = false;
         });
         self.non_top_level = old;
     }
@@ -553,6 +900,28 @@ impl Visit for Strip {
                 ImportSpecifier::Namespace(..) => {}
             }
         }
+
+        if self.config.const_enums.contains_key(&n.src.value) {
+            for s in &n.specifiers {
+                let (local, imported) = match s {
+                    ImportSpecifier::Named(import) => (
+                        &import.local,
+                        Some(
+                            import
+                                .imported
+                                .as_ref()
+                                .unwrap_or(&import.local)
+                                .sym
+                                .clone(),
+                        ),
+                    ),
+                    ImportSpecifier::Namespace(import) => (&import.local, None),
+                    ImportSpecifier::Default(..) => continue,
+                };
+                self.const_enum_imports
+                    .insert(local.to_id(), (n.src.value.clone(), imported));
+            }
+        }
     }
 
     fn visit_ts_entity_name(&mut self, name: &TsEntityName, _: &dyn Node) {
@@ -746,11 +1115,25 @@ impl VisitMut for Strip {
     fn visit_mut_private_prop(&mut self, prop: &mut PrivateProp) {
         prop.visit_mut_children_with(self);
         prop.readonly = false;
+        prop.is_optional = false;
+        prop.definite = false;
     }
 
     fn visit_mut_class_prop(&mut self, prop: &mut ClassProp) {
         prop.visit_mut_children_with(self);
         prop.readonly = false;
+        prop.is_optional = false;
+        prop.definite = false;
+    }
+
+    fn visit_mut_class_method(&mut self, method: &mut ClassMethod) {
+        method.visit_mut_children_with(self);
+        method.is_optional = false;
+    }
+
+    fn visit_mut_private_method(&mut self, method: &mut PrivateMethod) {
+        method.visit_mut_children_with(self);
+        method.is_optional = false;
     }
 
     fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
@@ -880,6 +1263,7 @@ impl VisitMut for Strip {
 
     fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
         items.visit_with(&Invalid { span: DUMMY_SP }, self);
+        self.check_isolated_modules();
 
         let mut stmts = Vec::with_capacity(items.len());
         for mut item in take(items) {
@@ -1087,6 +1471,7 @@ impl VisitMut for Strip {
         }
 
         *items = stmts;
+        self.remove_unused_imports(items);
     }
 
     fn visit_mut_var_declarator(&mut self, d: &mut VarDeclarator) {
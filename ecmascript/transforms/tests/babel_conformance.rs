@@ -0,0 +1,260 @@
+#![feature(test)]
+//! Runs the fixtures of babel plugins against the equivalent passes and
+//! reports how many of them produce the same code.
+//!
+//! Requires a checkout of babel:
+//!
+//! ```sh
+//! BABEL_DIR=../babel cargo test --test babel_conformance -- --ignored --nocapture
+//! ```
+//!
+//! Set `BABEL_MIN_CONFORMANCE` to a percentage to fail if the overall
+//! conformance drops below it.
+
+use std::{
+    env,
+    fs::read_to_string,
+    panic::{self, catch_unwind, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+use swc_common::chain;
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms::{
+    compat::{es2015, es2016, es2018, es2020},
+    resolver,
+};
+use swc_ecma_visit::Fold;
+use walkdir::WalkDir;
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        class_props: true,
+        class_private_props: true,
+        class_private_methods: true,
+        num_sep: true,
+        ..Default::default()
+    })
+}
+
+#[derive(Debug, Default)]
+struct Report {
+    passed: usize,
+    failed: Vec<PathBuf>,
+}
+
+impl Report {
+    fn total(&self) -> usize {
+        self.passed + self.failed.len()
+    }
+
+    fn percentage(&self) -> f64 {
+        if self.total() == 0 {
+            return 100.0;
+        }
+
+        self.passed as f64 * 100.0 / self.total() as f64
+    }
+}
+
+/// Runs the fixtures of `packages/{plugin}`. Fixtures without `output.js`
+/// are skipped, as they test errors or are only executed.
+fn run<F, P>(babel: &Path, plugin: &str, tr: F) -> Report
+where
+    F: Fn(&mut common::Tester<'_>) -> P,
+    P: Fold,
+{
+    let dir = babel
+        .join("packages")
+        .join(plugin)
+        .join("test")
+        .join("fixtures");
+
+    let mut report = Report::default();
+    if !dir.is_dir() {
+        println!("missing fixtures: {}", dir.display());
+        return report;
+    }
+
+    for entry in WalkDir::new(&dir).sort_by(|a, b| a.file_name().cmp(b.file_name())) {
+        let entry = entry.unwrap();
+        if entry.file_name() != "input.js" {
+            continue;
+        }
+
+        let fixture = entry.path().parent().unwrap();
+        let expected = match read_to_string(fixture.join("output.js")) {
+            Ok(expected) => expected,
+            Err(..) => continue,
+        };
+        let input = read_to_string(entry.path()).unwrap();
+
+        let res = catch_unwind(AssertUnwindSafe(|| {
+            common::test_transform(syntax(), &tr, &input, &expected, true)
+        }));
+        match res {
+            Ok(()) => report.passed += 1,
+            Err(..) => report.failed.push(fixture.to_path_buf()),
+        }
+    }
+
+    report
+}
+
+#[test]
+#[ignore]
+fn babel_conformance() {
+    let babel = PathBuf::from(env::var("BABEL_DIR").expect("BABEL_DIR should be set"));
+
+    // Failures are listed in the report.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let reports = vec![
+        (
+            "babel-plugin-transform-arrow-functions",
+            run(&babel, "babel-plugin-transform-arrow-functions", |_| {
+                chain!(resolver(), es2015::arrow())
+            }),
+        ),
+        (
+            "babel-plugin-transform-block-scoping",
+            run(&babel, "babel-plugin-transform-block-scoping", |_| {
+                chain!(resolver(), es2015::block_scoping())
+            }),
+        ),
+        (
+            "babel-plugin-transform-computed-properties",
+            run(&babel, "babel-plugin-transform-computed-properties", |_| {
+                chain!(resolver(), es2015::computed_properties(Default::default()))
+            }),
+        ),
+        (
+            "babel-plugin-transform-destructuring",
+            run(&babel, "babel-plugin-transform-destructuring", |_| {
+                chain!(resolver(), es2015::destructuring(Default::default()))
+            }),
+        ),
+        (
+            "babel-plugin-transform-duplicate-keys",
+            run(&babel, "babel-plugin-transform-duplicate-keys", |_| {
+                chain!(resolver(), es2015::duplicate_keys())
+            }),
+        ),
+        (
+            "babel-plugin-transform-for-of",
+            run(&babel, "babel-plugin-transform-for-of", |_| {
+                chain!(resolver(), es2015::for_of(Default::default()))
+            }),
+        ),
+        (
+            "babel-plugin-transform-function-name",
+            run(&babel, "babel-plugin-transform-function-name", |_| {
+                chain!(resolver(), es2015::function_name())
+            }),
+        ),
+        (
+            "babel-plugin-transform-parameters",
+            run(&babel, "babel-plugin-transform-parameters", |_| {
+                chain!(resolver(), es2015::parameters())
+            }),
+        ),
+        (
+            "babel-plugin-transform-shorthand-properties",
+            run(
+                &babel,
+                "babel-plugin-transform-shorthand-properties",
+                |_| chain!(resolver(), es2015::shorthand()),
+            ),
+        ),
+        (
+            "babel-plugin-transform-spread",
+            run(&babel, "babel-plugin-transform-spread", |_| {
+                chain!(resolver(), es2015::spread(Default::default()))
+            }),
+        ),
+        (
+            "babel-plugin-transform-template-literals",
+            run(&babel, "babel-plugin-transform-template-literals", |_| {
+                chain!(resolver(), es2015::template_literal())
+            }),
+        ),
+        (
+            "babel-plugin-transform-exponentiation-operator",
+            run(
+                &babel,
+                "babel-plugin-transform-exponentiation-operator",
+                |_| chain!(resolver(), es2016::exponentation()),
+            ),
+        ),
+        (
+            "babel-plugin-proposal-object-rest-spread",
+            run(&babel, "babel-plugin-proposal-object-rest-spread", |_| {
+                chain!(resolver(), es2018::object_rest_spread(Default::default()))
+            }),
+        ),
+        (
+            "babel-plugin-proposal-optional-catch-binding",
+            run(
+                &babel,
+                "babel-plugin-proposal-optional-catch-binding",
+                |_| chain!(resolver(), es2018::optional_catch_binding()),
+            ),
+        ),
+        (
+            "babel-plugin-proposal-nullish-coalescing-operator",
+            run(
+                &babel,
+                "babel-plugin-proposal-nullish-coalescing-operator",
+                |_| chain!(resolver(), es2020::nullish_coalescing()),
+            ),
+        ),
+        (
+            "babel-plugin-proposal-optional-chaining",
+            run(&babel, "babel-plugin-proposal-optional-chaining", |_| {
+                chain!(resolver(), es2020::optional_chaining())
+            }),
+        ),
+    ];
+
+    panic::set_hook(hook);
+
+    let mut passed = 0;
+    let mut total = 0;
+    for (plugin, report) in &reports {
+        println!(
+            "{:<52} {:>4} / {:<4} {:>6.2}%",
+            plugin,
+            report.passed,
+            report.total(),
+            report.percentage()
+        );
+        for fixture in &report.failed {
+            println!("    failed: {}", fixture.display());
+        }
+
+        passed += report.passed;
+        total += report.total();
+    }
+
+    let conformance = if total == 0 {
+        100.0
+    } else {
+        passed as f64 * 100.0 / total as f64
+    };
+    println!("Conformance: {} / {} ({:.2}%)", passed, total, conformance);
+
+    if let Ok(min) = env::var("BABEL_MIN_CONFORMANCE") {
+        let min: f64 = min
+            .parse()
+            .expect("BABEL_MIN_CONFORMANCE should be a number");
+        assert!(
+            conformance >= min,
+            "conformance {:.2}% is lower than {}%",
+            conformance,
+            min
+        );
+    }
+}
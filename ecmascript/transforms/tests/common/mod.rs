@@ -3,9 +3,10 @@
 
 use std::{
     fmt,
-    fs::{create_dir_all, remove_dir_all, OpenOptions},
+    fs::{create_dir_all, read_dir, read_to_string, remove_dir_all, OpenOptions},
     io::{self, Write},
     mem::replace,
+    panic::{catch_unwind, AssertUnwindSafe},
     path::Path,
     process::Command,
     sync::{Arc, RwLock},
@@ -91,7 +92,7 @@ impl<'a> Tester<'a> {
             .new_source_file(FileName::Real(name.into()), src.into());
 
         let module = {
-            let mut p = Parser::new(syntax, StringInput::from(&*fm), None);
+            let mut p = Parser::new(syntax, StringInput::from(&*fm), Some(&self.comments));
             let res = p.parse_module().map_err(|e| {
                 e.into_diagnostic(&self.handler).emit();
             });
@@ -256,6 +257,64 @@ macro_rules! test {
     };
 }
 
+/// Test transformation with each fixture in `tests/fixture/$dir`.
+///
+/// A fixture is a directory with `input.js` and the expected `output.js`.
+#[cfg(test)]
+macro_rules! fixture {
+    ($syntax:expr, $tr:expr, $test_name:ident, $dir:expr) => {
+        #[test]
+        fn $test_name() {
+            common::test_fixtures($dir, $syntax, $tr)
+        }
+    };
+}
+
+pub fn test_fixtures<F, P>(dir: &str, syntax: Syntax, tr: F)
+where
+    F: Fn(&mut Tester<'_>) -> P,
+    P: Fold,
+{
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixture")
+        .join(dir);
+
+    let mut fixtures = read_dir(&root)
+        .unwrap_or_else(|err| panic!("failed to read {}: {}", root.display(), err))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.join("input.js").is_file())
+        .collect::<Vec<_>>();
+    fixtures.sort();
+    assert!(!fixtures.is_empty(), "no fixture in {}", root.display());
+
+    let failed = fixtures
+        .iter()
+        .filter(|fixture| {
+            println!("===== {} =====", fixture.display());
+
+            let input = read_to_string(fixture.join("input.js")).unwrap();
+            let expected = match read_to_string(fixture.join("output.js")) {
+                Ok(expected) => expected,
+                Err(err) => {
+                    println!("failed to read output.js: {}", err);
+                    return true;
+                }
+            };
+
+            catch_unwind(AssertUnwindSafe(|| {
+                test_transform(syntax, &tr, &input, &expected, false)
+            }))
+            .is_err()
+        })
+        .map(|fixture| fixture.display().to_string())
+        .collect::<Vec<_>>();
+
+    if !failed.is_empty() {
+        panic!("{} fixture(s) failed:\n{}", failed.len(), failed.join("\n"));
+    }
+}
+
 macro_rules! exec_tr {
     ($syntax:expr, $tr:expr, $test_name:ident, $input:expr) => {{
         common::exec_tr(stringify!($test_name), $syntax, $tr, $input);
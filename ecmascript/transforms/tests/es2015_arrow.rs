@@ -4,6 +4,13 @@ use swc_ecma_transforms::compat::es2015::arrow;
 #[macro_use]
 mod common;
 
+fixture!(
+    ::swc_ecma_parser::Syntax::default(),
+    |_| arrow(),
+    fixture,
+    "es2015_arrow"
+);
+
 test!(
     ::swc_ecma_parser::Syntax::default(),
     |_| arrow(),
@@ -4,8 +4,10 @@ use swc_common::chain;
 use swc_ecma_parser::{EsConfig, Syntax};
 use swc_ecma_transforms::{
     compat::es2015::{arrow, block_scoping, classes, spread},
+    optimization::simplify::dce::{self, dce},
     react::jsx,
     resolver,
+    util::SideEffectCtx,
 };
 use swc_ecma_visit::Fold;
 
@@ -17,13 +19,13 @@ fn syntax() -> Syntax {
 }
 
 fn tr() -> impl Fold {
-    classes()
+    classes(None, Default::default())
 }
 
 fn spec_tr() -> impl Fold {
     chain!(
         resolver(),
-        classes(),
+        classes(None, Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
@@ -1292,6 +1294,24 @@ export { Foo as default };
 "#
 );
 
+test!(
+    syntax(),
+    |_| spec_tr(),
+    export_default_anonymous_conflict,
+    r#"
+var _default = 1;
+export default class {}
+"#,
+    r#"
+var _default = 1;
+var _default1 = function _default1() {
+  'use strict';
+  _classCallCheck(this, _default1);
+};
+export { _default1 as default };
+"#
+);
+
 // get_set_call_semantics_data_defined_on_parent
 test!(
     syntax(),
@@ -5180,7 +5200,7 @@ function (Array) {
 // extend_builtins_imported_babel_plugin_transform_builtin_classes
 test_exec!(
     syntax(),
-    |_| chain!(classes(), block_scoping()),
+    |_| chain!(classes(None, Default::default()), block_scoping()),
     extend_builtins_imported_babel_plugin_transform_builtin_classes_exec,
     r#"
 // Imported from
@@ -5481,7 +5501,7 @@ expect(obj.test).toBe(3);
 // extend_builtins_spec
 test_exec!(
     syntax(),
-    |_| chain!(classes(), block_scoping()),
+    |_| chain!(classes(None, Default::default()), block_scoping()),
     extend_builtins_spec_exec,
     r#"
 class List extends Array {}
@@ -6018,7 +6038,7 @@ expect(obj.test).toBe(3);
 // extend_builtins_builtin_objects_throw_when_wrapped
 test_exec!(
     syntax(),
-    |_| chain!(classes(), block_scoping()),
+    |_| chain!(classes(None, Default::default()), block_scoping()),
     extend_builtins_builtin_objects_throw_when_wrapped_exec,
     r#"
 // JSON is wrapped because it starts with an uppercase letter, but it
@@ -6067,7 +6087,7 @@ test_exec!(
     // Just don't do this.
     ignore,
     syntax(),
-    |_| chain!(classes(), block_scoping()),
+    |_| chain!(classes(None, Default::default()), block_scoping()),
     extend_builtins_overwritten_null_exec,
     r#"
 var env = {
@@ -6088,7 +6108,7 @@ test_exec!(
     // Just don't do this. With is evil.
     ignore,
     syntax(),
-    |_| chain!(classes(), block_scoping()),
+    |_| chain!(classes(None, Default::default()), block_scoping()),
     extend_builtins_super_called_exec,
     r#"
 var called = false;
@@ -6112,7 +6132,7 @@ with (env) {
 
 test_exec!(
     syntax(),
-    |_| classes(),
+    |_| classes(None, Default::default()),
     issue_846,
     r#"
 class SomeClass {
@@ -6132,3 +6152,87 @@ const obj = new OtherClass();
 expect(obj.anotherMethod()).toBe(2);
 "#
 );
+
+test!(
+    syntax(),
+    |_| classes(
+        None,
+        classes::Config {
+            set_class_methods: true,
+            no_class_calls: true,
+        }
+    ),
+    loose,
+    r#"
+class Foo {
+  constructor(a) {
+    this.a = a;
+  }
+
+  bar() {}
+
+  static baz() {}
+
+  [qux]() {}
+
+  get a() {}
+}
+"#,
+    r#"
+let Foo = function() {
+    'use strict';
+    function Foo(a) {
+        this.a = a;
+    }
+    Foo.prototype.bar = function bar() {};
+    Foo.baz = function baz() {};
+    Foo.prototype[qux] = function() {};
+    _createClass(Foo, [{
+        key: 'a',
+        get: function() {}
+    }]);
+    return Foo;
+}();
+"#
+);
+
+test!(
+    syntax(),
+    |t| {
+        let comments: &'static _ = Box::leak(Box::new(t.comments.clone()));
+
+        chain!(
+            classes(Some(comments), Default::default()),
+            resolver(),
+            dce(dce::Config {
+                side_effect_ctx: SideEffectCtx {
+                    comments: Some(comments),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+        )
+    },
+    pure_annotation_unused,
+    "
+const Foo = class {
+  bar() {}
+};
+export class Qux {
+  quux() {}
+}
+",
+    "
+export let Qux = function () {
+  'use strict';
+  function Qux() {
+    _classCallCheck(this, Qux);
+  }
+  _createClass(Qux, [{
+    key: 'quux',
+    value: function quux() {}
+  }]);
+  return Qux;
+}();
+"
+);
@@ -1,6 +1,6 @@
 #![feature(test)]
 use swc_ecma_parser::Syntax;
-use swc_ecma_transforms::compat::es2015::computed_properties;
+use swc_ecma_transforms::compat::es2015::{computed_properties, computed_props};
 use swc_ecma_visit::Fold;
 
 #[macro_use]
@@ -11,12 +11,12 @@ fn syntax() -> Syntax {
 }
 
 fn tr(_: ()) -> impl Fold {
-    computed_properties()
+    computed_properties(Default::default())
 }
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| computed_properties(),
+    |_| computed_properties(Default::default()),
     issue_210,
     "
 const b = {[a]: 1}
@@ -30,7 +30,7 @@ export const c = _defineProperty({
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| computed_properties(),
+    |_| computed_properties(Default::default()),
     accessors,
     r#"var obj = {
   get [foobar]() {
@@ -67,7 +67,7 @@ var obj = ( _obj = {
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| computed_properties(),
+    |_| computed_properties(Default::default()),
     argument,
     r#"foo({
   [bar]: "foobar"
@@ -78,7 +78,7 @@ test!(
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| computed_properties(),
+    |_| computed_properties(Default::default()),
     assignment,
     r#"foo = {
   [bar]: "foobar"
@@ -88,7 +88,7 @@ test!(
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| computed_properties(),
+    |_| computed_properties(Default::default()),
     method,
     r#"var obj = {
   [foobar]() {
@@ -109,7 +109,7 @@ var obj = (_obj = {}, _defineProperty(_obj, foobar, function () {
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| computed_properties(),
+    |_| computed_properties(Default::default()),
     mixed,
     r#"var obj = {
   ["x" + foo]: "heh",
@@ -125,7 +125,7 @@ var obj = (_obj = {}, _defineProperty(_obj, "x" + foo, "heh"), _defineProperty(_
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| computed_properties(),
+    |_| computed_properties(Default::default()),
     multiple,
     r#"var obj = {
   ["x" + foo]: "heh",
@@ -139,7 +139,7 @@ _defineProperty(_obj, "y" + bar, "noo"), _obj);"#
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| computed_properties(),
+    |_| computed_properties(Default::default()),
     single,
     r#"var obj = {
   ["x" + foo]: "heh"
@@ -149,7 +149,7 @@ test!(
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| computed_properties(),
+    |_| computed_properties(Default::default()),
     symbol,
     r#"var k = Symbol();
 var foo = {
@@ -171,7 +171,7 @@ var foo = ( _obj = {
 
 test_exec!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| computed_properties(),
+    |_| computed_properties(Default::default()),
     symbol_exec,
     r#"
 var k = Symbol();
@@ -188,7 +188,7 @@ expect(foo[k]).toBe(k)"#
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| computed_properties(),
+    |_| computed_properties(Default::default()),
     this,
     r#"var obj = {
   ["x" + foo.bar]: "heh"
@@ -198,7 +198,7 @@ test!(
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| computed_properties(),
+    |_| computed_properties(Default::default()),
     issue_315_1,
     "
 ({
@@ -228,7 +228,7 @@ export function corge() {
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| computed_properties(),
+    |_| computed_properties(Default::default()),
     issue_315_2,
     "
 export function corge() {}
@@ -240,7 +240,7 @@ export function corge() {}
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| computed_properties(),
+    |_| computed_properties(Default::default()),
     issue_315_3,
     "
 export function corge() {}
@@ -271,7 +271,7 @@ _defineProperty({
 
 test!(
     ::swc_ecma_parser::Syntax::default(),
-    |_| computed_properties(),
+    |_| computed_properties(Default::default()),
     issue_315_4,
     "
 export class Foo {}
@@ -515,3 +515,14 @@ expect(foo[k]).toBe(k)
 
 "#
 );
+
+test!(
+    syntax(),
+    |_| computed_properties(computed_props::Config { loose: true }),
+    loose,
+    "var obj = { foo: 1, [bar]: 2, baz() {} };",
+    "var _obj;
+var obj = (_obj = {
+    foo: 1
+}, _obj[bar] = 2, _obj['baz'] = function() {}, _obj);"
+);
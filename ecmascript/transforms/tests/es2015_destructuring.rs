@@ -518,7 +518,7 @@ test!(
     syntax(),
     |_| chain!(
         resolver(),
-        es2015(Mark::fresh(Mark::root()), Default::default()),
+        es2015(Mark::fresh(Mark::root()), None, Default::default()),
     ),
     issue_404_2,
     "function foo(bar) {
@@ -551,7 +551,10 @@ function foo(bar) {
 // destructuring_function_key_with_object_rest_spread
 test_exec!(
     syntax(),
-    |_| chain!(object_rest_spread(), destructuring(Default::default())),
+    |_| chain!(
+        object_rest_spread(Default::default()),
+        destructuring(Default::default())
+    ),
     destructuring_function_key_with_object_rest_spread_exec,
     r#"
 const { [(() => 1)()]: a, ...rest } = { 1: "a" };
@@ -595,7 +598,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_for_of,
     r#"
@@ -630,7 +633,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_object_basic,
     r#"
@@ -656,7 +659,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_assignment_arrow_function_block,
     r#"
@@ -681,7 +684,7 @@ test_exec!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_non_iterable_exec,
     r#"
@@ -708,7 +711,7 @@ test_exec!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_empty_object_pattern_exec,
     r#"
@@ -729,7 +732,7 @@ test_exec!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_chained_exec,
     r#"
@@ -747,7 +750,7 @@ expect(d).toBe(4);
 test_exec!(
     syntax(),
     |_| chain!(
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
@@ -805,7 +808,7 @@ test_exec!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_issue_5090_exec,
     r#"
@@ -832,7 +835,7 @@ test_exec!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_default_precedence_exec,
     r#"
@@ -867,7 +870,7 @@ expect(f2({a: 1})).toEqual([1, 1, 1]);
 //    spread(spread::Config{..Default::default()}),
 //    parameters(),
 //    block_scoping(),
-//    object_rest_spread(),
+//    object_rest_spread(Default::default()),
 //  ]
 //}
 //"#),
@@ -917,7 +920,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_parameters,
     r#"
@@ -984,7 +987,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_array_unpack_optimisation,
     r#"
@@ -1065,7 +1068,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_known_array,
     r#"
@@ -1085,14 +1088,14 @@ var x = z[0],
 test!(
     syntax(),
     |_| chain!(
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_es7_object_rest,
     r#"
@@ -1146,7 +1149,7 @@ expect(oldCourses).toEqual([]);
 test!(
     syntax(),
     |_| chain!(
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
@@ -1170,7 +1173,7 @@ var tmp = z.x, ref = tmp === void 0 ? {} : tmp, y = ref.y;
 test!(
     syntax(),
     |_| chain!(
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
@@ -1204,7 +1207,7 @@ var ref = [0, 1, 2, 3, 4, 5, 6],
 test!(
     syntax(),
     |_| chain!(
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
@@ -1240,7 +1243,7 @@ function isSorted(param) {
 test!(
     syntax(),
     |_| chain!(
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
@@ -1276,7 +1279,7 @@ test!(
             ..Default::default()
         }),
         block_scoping(),
-        object_rest_spread()
+        object_rest_spread(Default::default())
     ),
     destructuring_assignment_statement,
     r#"
@@ -1298,7 +1301,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_array,
     r#"
@@ -1323,7 +1326,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_assignment_arrow_function_no_block,
     r#"
@@ -1340,14 +1343,14 @@ var ref;
 test!(
     syntax(),
     |_| chain!(
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_issue_9834,
     r#"
@@ -1382,7 +1385,10 @@ var key = prefix + 'state',
 // destructuring_number_key_with_object_rest_spread
 test_exec!(
     syntax(),
-    |_| chain!(object_rest_spread(), destructuring(Default::default())),
+    |_| chain!(
+        object_rest_spread(Default::default()),
+        destructuring(Default::default())
+    ),
     destructuring_number_key_with_object_rest_spread_exec,
     r#"
 const foo = {
@@ -1409,7 +1415,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_for_in,
     r#"
@@ -1444,7 +1450,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_issue_5744,
     r#"
@@ -1468,7 +1474,7 @@ test_exec!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_spread_generator_exec,
     r#"
@@ -182,7 +182,7 @@ foo(1, 2, 3);"#
 
 test!(
     syntax(),
-    |_| chain!(classes(), tr()),
+    |_| chain!(classes(None, Default::default()), tr()),
     default_iife_4253,
     r#"class Ref {
   constructor(id = ++Ref.nextID) {
@@ -218,7 +218,7 @@ expect(new Ref().id).toBe(2);"#
 
 test!(
     syntax(),
-    |_| chain!(classes(), tr()),
+    |_| chain!(classes(None, Default::default()), tr()),
     default_iife_self,
     r#"class Ref {
   constructor(ref = Ref) {
@@ -1215,7 +1215,7 @@ test!(
     syntax(),
     |_| chain!(
         tr(),
-        classes(),
+        classes(None, Default::default()),
         swc_ecma_transforms::compat::es2015::spread(Default::default())
     ),
     rest_nested_iife,
@@ -1516,7 +1516,7 @@ function foo() {
 test!(
     syntax(),
     |_| chain!(
-        classes(),
+        classes(None, Default::default()),
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
@@ -973,7 +973,7 @@ test_exec!(
     |_| chain!(
         es2017(),
         es2016(),
-        es2015(Mark::fresh(Mark::root()), Default::default()),
+        es2015(Mark::fresh(Mark::root()), None, Default::default()),
     ),
     issue_600_full,
     "async function foo(b) {
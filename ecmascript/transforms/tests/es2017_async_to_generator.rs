@@ -666,7 +666,10 @@ test!(
 
 test_exec!(
     syntax(),
-    |_| chain!(tr(), es2015(Mark::fresh(Mark::root()), Default::default())),
+    |_| chain!(
+        tr(),
+        es2015(Mark::fresh(Mark::root()), None, Default::default())
+    ),
     issue_400_1,
     "class A {
     constructor() {
@@ -727,7 +730,7 @@ test_exec!(
     syntax(),
     |_| chain!(
         async_to_generator(),
-        es2015(Mark::fresh(Mark::root()), Default::default())
+        es2015(Mark::fresh(Mark::root()), None, Default::default())
     ),
     issue_400_3,
     "class A {
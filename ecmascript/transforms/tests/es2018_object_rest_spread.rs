@@ -19,7 +19,7 @@ fn syntax() -> Syntax {
 }
 
 fn tr() -> impl Fold {
-    object_rest_spread()
+    object_rest_spread(Default::default())
 }
 
 test!(
@@ -114,6 +114,23 @@ function foo([_param]) {
 "#
 );
 
+test!(
+    syntax(),
+    |_| object_rest_spread(object_rest_spread::Config { use_builtins: true }),
+    rest_use_builtins,
+    r#"
+function foo([{...bar}]) {
+}
+var { ...a } = b;
+"#,
+    r#"
+function foo([_param]) {
+  var bar = Object.assign({}, _param);
+}
+var a = Object.assign({}, b);
+"#
+);
+
 test!(
     syntax(),
     |_| tr(),
@@ -27,6 +27,7 @@ fn syntax() -> Syntax {
     Syntax::Es(EsConfig {
         class_private_props: true,
         class_props: true,
+        static_blocks: true,
         ..Default::default()
     })
 }
@@ -36,7 +37,7 @@ fn tr() -> impl Fold {
         resolver(),
         function_name(),
         class_properties(),
-        classes(),
+        classes(None, Default::default()),
         block_scoping(),
         reserved_words(false),
     )
@@ -2847,7 +2848,11 @@ class Foo{
 
 test!(
     syntax(),
-    |_| chain!(resolver(), class_properties(), classes()),
+    |_| chain!(
+        resolver(),
+        class_properties(),
+        classes(None, Default::default())
+    ),
     issue_342,
     "class Foo {
   constructor(bar) {
@@ -3167,7 +3172,11 @@ expect(Foo.test()).toBe("foo");
 // private_destructuring_object_pattern_1
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(),
+        classes(None, Default::default()),
+        block_scoping()
+    ),
     private_destructuring_object_pattern_1,
     r#"
 class Foo {
@@ -3351,7 +3360,11 @@ var _bar = {
 // private_destructuring_array_pattern
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(),
+        classes(None, Default::default()),
+        block_scoping()
+    ),
     private_destructuring_array_pattern,
     r#"
 class Foo {
@@ -3508,7 +3521,11 @@ export { MyClass3 as default }
 // private_destructuring_array_pattern_1
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(),
+        classes(None, Default::default()),
+        block_scoping()
+    ),
     private_destructuring_array_pattern_1,
     r#"
 class Foo {
@@ -3551,7 +3568,7 @@ test!(
             ..Default::default()
         }),
         class_properties(),
-        classes(),
+        classes(None, Default::default()),
     ),
     decorators_legacy_interop_strict,
     r#"
@@ -3770,7 +3787,7 @@ export { MyClass2 as default }
 // static_property_tdz_edgest_case
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes()),
+    |_| chain!(class_properties(), classes(None, Default::default())),
     static_property_tdz_edgest_case,
     r#"
 class A {
@@ -4108,7 +4125,11 @@ new ComputedField();
 // private_canonical
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(),
+        classes(None, Default::default()),
+        block_scoping()
+    ),
     private_canonical,
     r#"
 class Point {
@@ -4274,7 +4295,11 @@ class C {
 // private_destructuring_array_pattern_3
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(),
+        classes(None, Default::default()),
+        block_scoping()
+    ),
     private_destructuring_array_pattern_3,
     r#"
 class Foo {
@@ -4332,7 +4357,11 @@ expect(getPropA()).toBe(1);
 // private_destructuring_array_pattern_2
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(),
+        classes(None, Default::default()),
+        block_scoping()
+    ),
     private_destructuring_array_pattern_2,
     r#"
 class Foo {
@@ -4439,7 +4468,7 @@ test!(
             ..Default::default()
         }),
         class_properties(),
-        classes()
+        classes(None, Default::default())
     ),
     decorators_legacy_interop_local_define_property,
     r#"
@@ -4512,7 +4541,7 @@ test!(
     |_| chain!(
         class_properties(),
         exponentation(),
-        classes(),
+        classes(None, Default::default()),
         block_scoping(),
     ),
     private_instance,
@@ -4542,7 +4571,7 @@ var _bar = new WeakMap();
 // static_property_tdz_general
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes()),
+    |_| chain!(class_properties(), classes(None, Default::default())),
     static_property_tdz_general,
     r#"
 class C {
@@ -4715,7 +4744,11 @@ var _bar = new WeakMap();
 // public_computed_without_block
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(),
+        classes(None, Default::default()),
+        block_scoping()
+    ),
     public_computed_without_block,
     r#"
 const createClass = (k) => class { [k()] = 2 };
@@ -4763,7 +4796,11 @@ expect(foo.getClient()).toEqual(['bar', 'baz', 'quu']);
 // public_static_super
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(),
+        classes(None, Default::default()),
+        block_scoping()
+    ),
     public_static_super,
     r#"
 class A {
@@ -4962,3 +4999,145 @@ class Foo {
 Foo.identifier = 5;
   "
 );
+
+test!(
+    syntax(),
+    |_| class_properties(),
+    static_block,
+    r#"
+class Foo {
+  static bar = 1;
+  static {
+    var baz = this.bar;
+    this.qux = baz + 1;
+  }
+  static quux = 2;
+}
+"#,
+    r#"
+class Foo {
+}
+_defineProperty(Foo, "bar", 1);
+(function() {
+  var baz = Foo.bar;
+  Foo.qux = baz + 1;
+})();
+_defineProperty(Foo, "quux", 2);
+"#
+);
+
+test!(
+    syntax(),
+    |_| class_properties(),
+    static_block_nested_fn,
+    r#"
+class Foo {
+  static {
+    this.bar = () => this;
+    this.baz = function () { return this; };
+  }
+}
+"#,
+    r#"
+class Foo {
+}
+(function() {
+  Foo.bar = () => Foo;
+  Foo.baz = function() {
+    return this;
+  };
+})();
+"#
+);
+
+test!(
+    syntax(),
+    |_| class_properties(),
+    private_in,
+    r#"
+class Foo {
+  #bar = 1;
+  static #baz = 2;
+
+  test(other) {
+    return #bar in other && #baz in other;
+  }
+}
+"#,
+    r#"
+class Foo {
+  test(other) {
+    return _bar.has(other) && other === Foo;
+  }
+  constructor(){
+    _bar.set(this, {
+      writable: true,
+      value: 1
+    });
+  }
+}
+var _bar = new WeakMap();
+var _baz = {
+  writable: true,
+  value: 2
+};
+"#
+);
+
+test!(
+    syntax(),
+    |_| class_properties(),
+    private_in_method,
+    r#"
+class Foo {
+  #bar = 1;
+  #foo() {}
+  static #baz() {}
+
+  test(other) {
+    return #foo in other && #baz in other;
+  }
+}
+"#,
+    r#"
+class Foo {
+  #foo() {}
+  static #baz() {}
+  test(other) {
+    return _foo.has(other) && other === Foo;
+  }
+  constructor(){
+    _foo.add(this);
+    _bar.set(this, {
+      writable: true,
+      value: 1
+    });
+  }
+}
+var _bar = new WeakMap();
+var _foo = new WeakSet();
+"#
+);
+
+test!(
+    syntax(),
+    |_| class_properties(),
+    private_method_without_brand_check,
+    r#"
+class Foo {
+  #foo() {}
+
+  test(other) {
+    return other;
+  }
+}
+"#,
+    r#"
+class Foo {
+  #foo() {}
+  test(other) {
+    return other;
+  }
+}
+"#
+);
@@ -167,7 +167,10 @@ fn identity_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
 
                     {
                         let mut emitter = Emitter {
-                            cfg: swc_ecma_codegen::Config { minify: false },
+                            cfg: swc_ecma_codegen::Config {
+                                minify: false,
+                                ..Default::default()
+                            },
                             cm: cm.clone(),
                             wr: Box::new(swc_ecma_codegen::text_writer::JsWriter::new(
                                 cm.clone(),
@@ -178,7 +181,10 @@ fn identity_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
                             comments: None,
                         };
                         let mut expected_emitter = Emitter {
-                            cfg: swc_ecma_codegen::Config { minify: false },
+                            cfg: swc_ecma_codegen::Config {
+                                minify: false,
+                                ..Default::default()
+                            },
                             cm: cm.clone(),
                             wr: Box::new(swc_ecma_codegen::text_writer::JsWriter::new(
                                 cm, "\n", &mut wr2, None,
@@ -297,6 +303,7 @@ impl Fold for Normalizer {
                 value: i.sym,
                 span: i.span,
                 has_escape: false,
+                raw: None,
             }),
             PropName::Num(n) => {
                 let s = if n.value.is_infinite() {
@@ -312,6 +319,7 @@ impl Fold for Normalizer {
                     value: s.into(),
                     span: n.span,
                     has_escape: false,
+                    raw: None,
                 })
             }
             _ => name,
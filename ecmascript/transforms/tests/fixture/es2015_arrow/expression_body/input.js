@@ -0,0 +1,2 @@
+const double = (a) => a * 2;
+const pair = (a, b) => ({ a, b });
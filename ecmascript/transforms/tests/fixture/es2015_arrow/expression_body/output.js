@@ -0,0 +1,9 @@
+const double = function(a) {
+    return a * 2;
+};
+const pair = function(a, b) {
+    return {
+        a,
+        b
+    };
+};
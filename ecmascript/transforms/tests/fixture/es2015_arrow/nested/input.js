@@ -0,0 +1 @@
+const add = (a) => (b) => a + b;
@@ -0,0 +1,5 @@
+const add = function(a) {
+    return function(b) {
+        return a + b;
+    };
+};
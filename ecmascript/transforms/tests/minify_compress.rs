@@ -0,0 +1,429 @@
+#![feature(test)]
+use swc_common::chain;
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms::{
+    minify::{compress::Config, compressor},
+    resolver,
+};
+
+#[macro_use]
+mod common;
+
+macro_rules! to {
+    ($name:ident, $src:expr, $expected:expr) => {
+        to!($name, Default::default(), $src, $expected);
+    };
+
+    ($name:ident, $config:expr, $src:expr, $expected:expr) => {
+        test!(
+            Syntax::default(),
+            |_| chain!(resolver(), compressor($config)),
+            $name,
+            $src,
+            $expected
+        );
+    };
+}
+
+to!(if_else, "if (a) b(); else c();", "a ? b() : c();");
+
+to!(if_else_not, "if (!a) b(); else c();", "a ? c() : b();");
+
+to!(if_only, "if (a) b();", "a && b();");
+
+to!(if_not, "if (!a) b();", "a || b();");
+
+to!(empty_cons, "if (a) {} else { b(); }", "a || b();");
+
+to!(empty_if, "if (a()) {}", "a();");
+
+to!(
+    if_with_decl,
+    "if (a) { let b = 1; } else { c(); }",
+    "if (a) { let b = 1; } else c();"
+);
+
+to!(
+    dangling_else,
+    "if (a) { if (b) c(); } else { for (;;) d(); }",
+    "if (a) b && c(); else for (;;) d();"
+);
+
+to!(
+    dangling_else_nested,
+    "if (a) { for (;;) if (b) throw c; } else throw d;",
+    "if (a) { for (;;) if (b) throw c; } else throw d;"
+);
+
+to!(sequences, "a(); b(); c();", "a(), b(), c();");
+
+to!(sequences_after_if, "if (a) b(); c();", "a && b(), c();");
+
+to!(
+    directives,
+    "'use strict'; a(); b();",
+    "'use strict'; a(), b();"
+);
+
+to!(
+    sequences_in_module,
+    "import a from 'a'; a(); b(); export default c; d();",
+    "import a from 'a'; a(), b(); export default c; d();"
+);
+
+to!(
+    if_return_else,
+    "function foo() { if (a) return b; else return c; }",
+    "function foo() { return a ? b : c; }"
+);
+
+to!(
+    if_return,
+    "function foo() { d(); if (a) return b; return c; }",
+    "function foo() { return d(), a ? b : c; }"
+);
+
+to!(
+    if_return_chain,
+    "function foo() { if (a) return 1; if (b) return 2; return 3; }",
+    "function foo() { return a ? 1 : b ? 2 : 3; }"
+);
+
+to!(
+    join_blocks,
+    "{ a(); { b(); } } { let c; } while (a) { b(); }",
+    "a(), b(); { let c; } while (a) b();"
+);
+
+to!(
+    conditionals_disabled,
+    Config {
+        conditionals: false,
+        ..Default::default()
+    },
+    "if (a) { b(); } c(); d();",
+    "if (a) b(); c(), d();"
+);
+
+to!(
+    sequences_disabled,
+    Config {
+        sequences: false,
+        ..Default::default()
+    },
+    "a(); if (b) c();",
+    "a(); b && c();"
+);
+
+to!(
+    if_return_disabled,
+    Config {
+        if_return: false,
+        ..Default::default()
+    },
+    "function foo() { if (a) return b; return c; }",
+    "function foo() { if (a) return b; return c; }"
+);
+
+to!(
+    join_blocks_disabled,
+    Config {
+        join_blocks: false,
+        ..Default::default()
+    },
+    "{ a(); } if (b) { c(); }",
+    "{ a(); } if (b) { c(); }"
+);
+
+to!(
+    unused,
+    "function foo(a) {
+        var b = 1, c = a(), d = 2;
+        function bar() {}
+        class Baz {}
+        return d;
+    }",
+    "function foo(a) {
+        var c = a(), d = 2;
+        return d;
+    }"
+);
+
+to!(
+    unused_self_reference,
+    "function foo() {
+        function bar() { return bar() + baz(); }
+        function baz() { return bar(); }
+        return 1;
+    }",
+    "function foo() { return 1; }"
+);
+
+to!(
+    unused_assigned,
+    "function foo() { var a; a = bar(); var b = 1; b += 1; }",
+    "function foo() { bar(); var b = 1; b += 1; }"
+);
+
+to!(
+    unused_nested,
+    "function foo() { function bar() { var a = baz(); } }",
+    "function foo() {}"
+);
+
+to!(
+    unused_class_side_effects,
+    "function foo() {
+        class A extends bar() {}
+        class B { static b = bar(); }
+        class C { [bar()]() {} }
+        class D { static d = 1; e = bar(); }
+    }",
+    "function foo() {
+        class A extends bar() {}
+        class B { static b = bar(); }
+        class C { [bar()]() {} }
+    }"
+);
+
+to!(
+    unused_eval,
+    "function foo() { var a = 1; return function () { eval(''); }; }
+    function bar() { var a = 1; with (baz) {} }",
+    "function foo() { var a = 1; return function () { eval(''); }; }
+    function bar() { var a = 1; with (baz) {} }"
+);
+
+to!(
+    unused_for_in,
+    "function foo(obj) { for (var a in obj); for (var b = 0; ;); }",
+    "function foo(obj) { for (var a in obj); for (;;); }"
+);
+
+to!(
+    unused_top_level_kept,
+    "var a = 1; function foo() {}",
+    "var a = 1; function foo() {}"
+);
+
+to!(
+    unused_top_level,
+    Config {
+        top_level: true,
+        ..Default::default()
+    },
+    "import foo from 'foo';
+    var a = 1, b = 2, c = 3;
+    function bar() { return a; }
+    function baz() {}
+    export function qux() { return foo(b); }
+    export { c as d };
+    export default baz;",
+    "import foo from 'foo';
+    var b = 2, c = 3;
+    function baz() {}
+    export function qux() { return foo(b); }
+    export { c as d };
+    export default baz;"
+);
+
+to!(
+    unused_disabled,
+    Config {
+        unused: false,
+        ..Default::default()
+    },
+    "function foo() { var a = 1; }",
+    "function foo() { var a = 1; }"
+);
+
+to!(
+    inline_fn,
+    "function foo(x) {
+        function one() { return 1; }
+        function add(a, b) { return a + b; }
+        const sub = (a, b) => a - b;
+        return add(x, one()) + sub(x, 2);
+    }",
+    "function foo(x) { return x + 1 + (x - 2); }"
+);
+
+to!(
+    inline_fn_missing_args,
+    "function foo(x) { function add(a, b) { return a + b; } return add(x); }",
+    "function foo(x) { return x + void 0; }"
+);
+
+to!(
+    inline_fn_not_trivial,
+    "function foo(x) {
+        function a(b) { return b(); }
+        function c() { return this; }
+        function d() { return arguments; }
+        function e({ f }) { return f; }
+        function g() { return () => 1; }
+        return [a(x), c(), d(), e(x), g()];
+    }",
+    "function foo(x) {
+        function a(b) { return b(); }
+        function c() { return this; }
+        function d() { return arguments; }
+        function e({ f }) { return f; }
+        function g() { return () => 1; }
+        return [a(x), c(), d(), e(x), g()];
+    }"
+);
+
+to!(
+    inline_fn_args,
+    "function foo(x) {
+        function double(a) { return a + a; }
+        return [double(x), double(1), double([]), double(x())];
+    }",
+    "function foo(x) {
+        function double(a) { return a + a; }
+        return [x + x, 1 + 1, double([]), double(x())];
+    }"
+);
+
+to!(
+    inline_fn_reassigned,
+    "function foo() { function one() { return 1; } one = bar; return one(); }",
+    "function foo() { function one() { return 1; } return one = bar, one(); }"
+);
+
+to!(
+    inline_const,
+    "function foo() {
+        const a = 1, b = 'b' + a, c = {};
+        const d = 2;
+        return [b, c, d, d];
+    }",
+    "function foo() {
+        const c = {};
+        const d = 2;
+        return ['b' + 1, c, d, d];
+    }"
+);
+
+to!(
+    inline_const_shorthand,
+    "function foo() { const a = 1; return { a }; }",
+    "function foo() { return { a: 1 }; }"
+);
+
+to!(
+    inline_eval,
+    "function foo() { const a = 1; eval(''); return a; }",
+    "function foo() { const a = 1; return eval(''), a; }"
+);
+
+to!(
+    inline_level_1,
+    Config {
+        inline: 1,
+        ..Default::default()
+    },
+    "function foo(x) {
+        function one() { return 1; }
+        function id(a) { return a; }
+        const b = 2;
+        return id(one()) + b;
+    }",
+    "function foo(x) {
+        function id(a) { return a; }
+        const b = 2;
+        return id(1) + b;
+    }"
+);
+
+to!(
+    inline_top_level,
+    Config {
+        top_level: true,
+        ..Default::default()
+    },
+    "const a = 1; export const b = 2; function c() { return 3; } export default a + b + c();",
+    "export const b = 2; export default 1 + b + 3;"
+);
+
+to!(
+    inline_disabled,
+    Config {
+        inline: 0,
+        ..Default::default()
+    },
+    "function foo() { const a = 1; return a; }",
+    "function foo() { const a = 1; return a; }"
+);
+
+to!(
+    dead_code,
+    "function foo() {
+        a: {
+            if (x) break a;
+            else return;
+            bar();
+        }
+        while (true) {
+            try { return baz(); } finally { qux(b, c); }
+        }
+        var b = 1, { c } = d;
+        quux();
+    }",
+    "function foo() {
+        a: { if (x) break a; else return; }
+        while (true) try { return baz(); } finally { qux(b, c); }
+        var b, c;
+    }"
+);
+
+to!(
+    dead_code_reachable,
+    "function foo() {
+        a: for (;;) {
+            try { throw bar; } catch (e) { break a; }
+        }
+        b: do { continue b; } while (x);
+        switch (y) { case 1: return; }
+        try { return; } catch (e) {}
+        baz();
+    }",
+    "function foo() {
+        a: for (;;) try { throw bar; } catch (e) { break a; }
+        b: do continue b; while (x);
+        switch (y) { case 1: return; }
+        try { return; } catch (e) {}
+        baz();
+    }"
+);
+
+to!(
+    dead_code_decls,
+    Config {
+        unused: false,
+        ..Default::default()
+    },
+    "function foo() {
+        return bar;
+        function bar() {}
+        let a = 1;
+        if (x) { var b = 1; } else { function c() {} }
+    }",
+    "function foo() {
+        return bar;
+        function bar() {}
+        let a = 1;
+        if (x) var b = 1; else { function c() {} }
+    }"
+);
+
+to!(
+    dead_code_disabled,
+    Config {
+        dead_code: false,
+        ..Default::default()
+    },
+    "function foo() { return; bar(); }",
+    "function foo() { return; bar(); }"
+);
@@ -0,0 +1,99 @@
+#![feature(test)]
+use swc_common::chain;
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms::{
+    minify::{compress, compressor, mangle, mangler, retain_exports},
+    resolver,
+};
+
+#[macro_use]
+mod common;
+
+macro_rules! to {
+    ($name:ident, [$($export:expr),*], $src:expr, $expected:expr) => {
+        test!(
+            Syntax::default(),
+            |_| retain_exports(vec![$($export.into()),*]),
+            $name,
+            $src,
+            $expected
+        );
+    };
+}
+
+to!(
+    decls,
+    ["foo"],
+    "export function foo() {}
+    export function bar() {}
+    export class Baz {}",
+    "export function foo() {}
+    function bar() {}
+    class Baz {}"
+);
+
+to!(
+    vars,
+    ["a", "c", "e"],
+    "export var a = 1, b = 2, c = 3, d = 4;
+    export const { e, f } = g, h = 5;",
+    "export var a = 1;
+    var b = 2;
+    export var c = 3;
+    var d = 4;
+    export const { e, f } = g;
+    const h = 5;"
+);
+
+to!(
+    named,
+    ["a", "bar"],
+    "export { a, b, c as bar };
+    export { d } from 'd';
+    export * as e from 'e';
+    export * from 'f';",
+    "export { a, c as bar };
+    import 'd';
+    import 'e';
+    export * from 'f';"
+);
+
+to!(default_expr, [], "export default foo();", "foo();");
+
+to!(
+    default_decl,
+    [],
+    "export default function foo() {}",
+    "function foo() {}"
+);
+
+to!(default_anonymous, [], "export default function () {}", "");
+
+to!(
+    default_retained,
+    ["default"],
+    "export default foo();",
+    "export default foo();"
+);
+
+test!(
+    Syntax::default(),
+    |_| chain!(
+        resolver(),
+        retain_exports(vec!["api".into()]),
+        compressor(compress::Config {
+            top_level: true,
+            ..Default::default()
+        }),
+        mangler(mangle::Config {
+            top_level: true,
+            ..Default::default()
+        })
+    ),
+    library,
+    "export function helper(value) { return value + 1; }
+    export function internal() {}
+    export function api(value) { return [helper(value), helper]; }",
+    "function a(a) { return a + 1; }
+    export function api(b) { return [b + 1, a]; }"
+);
@@ -0,0 +1,259 @@
+#![feature(test)]
+use regex::Regex;
+use swc_common::chain;
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms::{
+    minify::{
+        mangle::{Config, NameCache, PropsConfig},
+        mangler, mangler_with_cache,
+    },
+    resolver,
+};
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        jsx: true,
+        ..Default::default()
+    })
+}
+
+macro_rules! to {
+    ($name:ident, $src:expr, $expected:expr) => {
+        to!($name, Default::default(), $src, $expected);
+    };
+
+    ($name:ident, $config:expr, $src:expr, $expected:expr) => {
+        test!(
+            syntax(),
+            |_| chain!(resolver(), mangler($config)),
+            $name,
+            $src,
+            $expected
+        );
+    };
+}
+
+to!(
+    params,
+    "function foo(first, second) { return second(first, first); }",
+    "function foo(a, b) { return b(a, a); }"
+);
+
+to!(
+    frequency,
+    "function foo(rare, often) { var local = often + often; return often(local); }",
+    "function foo(c, a) { var b = a + a; return a(b); }"
+);
+
+to!(
+    shadowing_outer,
+    "function foo(outer) {
+        return function (inner) {
+            return outer + inner;
+        };
+    }",
+    "function foo(a) {
+        return function (b) {
+            return a + b;
+        };
+    }"
+);
+
+to!(
+    reuse_in_siblings,
+    "function foo(first) { return first; }
+    function bar(second) { return second; }",
+    "function foo(a) { return a; }
+    function bar(a) { return a; }"
+);
+
+to!(
+    global_reference,
+    "function foo(first) { return a(first); }",
+    "function foo(b) { return a(b); }"
+);
+
+to!(
+    top_level_not_mangled,
+    "var first = 1; function foo() { return first; }",
+    "var first = 1; function foo() { return first; }"
+);
+
+to!(
+    top_level,
+    Config {
+        top_level: true,
+        ..Default::default()
+    },
+    "var first = 1; function foo() { return first; } foo();",
+    "var a = 1; function b() { return a; } b();"
+);
+
+to!(
+    top_level_exports,
+    Config {
+        top_level: true,
+        ..Default::default()
+    },
+    "var first = 1; export function foo() { return first; } export { first as second };
+    import { imported } from 'foo'; imported();",
+    "var first = 1; export function foo() { return first; } export { first as second };
+    import { imported as a } from 'foo'; a();"
+);
+
+to!(
+    eval,
+    "function foo(first) { return function (second) { eval(''); return first + second; }; }",
+    "function foo(first) { return function (second) { eval(''); return first + second; }; }"
+);
+
+to!(
+    eval_child_scope,
+    "function foo(first) { eval(''); return function (second) { return second; }; }",
+    "function foo(first) { eval(''); return function (a) { return a; }; }"
+);
+
+to!(
+    eval_getter,
+    "function foo(first) { return { get second() { return eval('first'); } }; }",
+    "function foo(first) { return { get second() { return eval('first'); } }; }"
+);
+
+to!(
+    with,
+    "function foo(first, second) { with (first) { return second; } }",
+    "function foo(first, second) { with (first) { return second; } }"
+);
+
+to!(
+    reserved,
+    Config {
+        reserved: vec!["first".into(), "a".into()],
+        ..Default::default()
+    },
+    "function foo(first, second) { return first + second; }",
+    "function foo(first, b) { return first + b; }"
+);
+
+to!(
+    keep_fnames,
+    Config {
+        keep_fnames: true,
+        ..Default::default()
+    },
+    "function foo() { function bar() {} return function baz() { return bar; }; }",
+    "function foo() { function bar() {} return function baz() { return bar; }; }"
+);
+
+to!(
+    keep_classnames,
+    Config {
+        keep_classnames: true,
+        ..Default::default()
+    },
+    "function foo() { class Bar {} return [Bar, function baz() {}]; }",
+    "function foo() { class Bar {} return [Bar, function a() {}]; }"
+);
+
+to!(
+    names_are_not_renamed,
+    "function foo(first) {
+        label: for (;;) break label;
+        return { first: first.first, [first]: 1 };
+    }",
+    "function foo(a) {
+        label: for (;;) break label;
+        return { first: a.first, [a]: 1 };
+    }"
+);
+
+to!(
+    shorthand,
+    "function foo(first, { second, third = 1 }) { return { first }; }",
+    "function foo(a, { second: b, third: c = 1 }) { return { first: a }; }"
+);
+
+to!(
+    jsx_component,
+    "function foo(Component, other) { return <Component>{other}</Component>; }",
+    "function foo(Component, a) { return <Component>{a}</Component>; }"
+);
+
+fn props(keep_quoted: bool) -> Config {
+    Config {
+        props: Some(PropsConfig {
+            regex: Regex::new("^_").unwrap(),
+            reserved: vec!["_reserved".into()],
+            keep_quoted,
+        }),
+        ..Default::default()
+    }
+}
+
+to!(
+    props_regex,
+    props(false),
+    "obj._foo = obj._bar + obj.baz + obj._reserved; obj._foo();",
+    "obj.a = obj.b + obj.baz + obj._reserved; obj.a();"
+);
+
+to!(
+    props_existing_names,
+    props(false),
+    "obj._foo = obj.a;",
+    "obj.b = obj.a;"
+);
+
+to!(
+    props_quoted,
+    props(false),
+    "obj._foo = obj['_bar']; x = { _bar: 1, '_foo': 2, [_foo]: 3 };",
+    "obj.a = obj['b']; x = { b: 1, 'a': 2, [_foo]: 3 };"
+);
+
+to!(
+    props_keep_quoted,
+    props(true),
+    "obj._foo = obj['_bar']; x = { _bar: 1, _baz: 2 }; obj._baz;",
+    "obj.b = obj['_bar']; x = { _bar: 1, a: 2 }; obj.a;"
+);
+
+to!(
+    props_shorthand,
+    props(false),
+    "function foo(_foo, { _bar, _baz = 1 }) { return { _foo }; }",
+    "function foo(a, { a: b, b: c = 1 }) { return { c: a }; }"
+);
+
+to!(
+    props_class,
+    props(false),
+    "class Foo { _foo = 1; _bar() { return this._foo; } }",
+    "class Foo { a = 1; b() { return this.a; } }"
+);
+
+#[test]
+fn props_name_cache() {
+    let mut cache = NameCache::default();
+    cache.props.insert("_foo".into(), "a".into());
+
+    test_transform!(
+        syntax(),
+        |_| chain!(resolver(), mangler_with_cache(props(false), &mut cache)),
+        "obj._bar = obj._foo + obj._bar;",
+        "obj.b = obj.a + obj.b;"
+    );
+
+    let json = serde_json::to_string(&cache).unwrap();
+    assert_eq!(json, r#"{"props":{"_bar":"b","_foo":"a"}}"#);
+    assert_eq!(serde_json::from_str::<NameCache>(&json).unwrap(), cache);
+}
+
+#[test]
+fn props_invalid_regex() {
+    let err = serde_json::from_str::<PropsConfig>(r#"{ "regex": "(" }"#).unwrap_err();
+    assert!(err.to_string().contains("regex parse error"), "{}", err);
+}
@@ -65,6 +65,25 @@ define(['exports', 'foo'], function(_exports, _foo) {
 "
 );
 
+test!(
+    syntax(),
+    |_| tr(Config {
+        config: util::Config {
+            loose: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    }),
+    loose_top_level_this,
+    r#"this.foo();"#,
+    r#"
+define(['exports'], function(_exports) {
+    'use strict';
+    _exports.foo();
+});
+"#
+);
+
 test!(
     syntax(),
     |_| tr(Config {
@@ -444,15 +463,7 @@ define(["exports", "foo"], function (_exports, _foo) {
   Object.defineProperty(_exports, "__esModule", {
     value: true
   });
-  Object.keys(_foo).forEach(function (key) {
-    if (key === "default" || key === "__esModule") return;
-    Object.defineProperty(_exports, key, {
-      enumerable: true,
-      get: function () {
-        return _foo[key];
-      }
-    });
-  });
+  _exportStar(_foo, _exports);
 });
 
 "#
@@ -1200,3 +1211,22 @@ test!(
       }
   });"
 );
+
+test!(
+    Syntax::Es(EsConfig {
+        export_namespace_from: true,
+        ..Default::default()
+    }),
+    |_| tr(Default::default()),
+    export_namespace_from,
+    "export * as foo from 'bar';",
+    "define(['exports', 'bar'], function(_exports, _foo) {
+    'use strict';
+    _foo = _interopRequireWildcard(_foo);
+    Object.defineProperty(_exports, '__esModule', {
+        value: true
+    });
+    _exports.foo = void 0;
+    _exports.foo = _foo;
+});"
+);
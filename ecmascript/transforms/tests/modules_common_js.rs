@@ -9,8 +9,8 @@ use swc_ecma_transforms::{
     hygiene,
     modules::{
         common_js::{common_js, Config},
-        import_analysis::import_analyzer,
-        util::Lazy,
+        import_analysis::{import_analyzer, import_analyzer_with_interop},
+        util::{ImportInterop, Lazy},
     },
     optimization::simplifier,
     proposals::decorators,
@@ -87,6 +87,96 @@ _foo.default.bar = true;
 "
 );
 
+test!(
+    syntax(),
+    |_| {
+        let mark = Mark::fresh(Mark::root());
+
+        chain!(
+            resolver_with_mark(mark),
+            import_analyzer_with_interop(ImportInterop::Node),
+            inject_helpers(),
+            common_js(
+                mark,
+                Config {
+                    import_interop: Some(ImportInterop::Node),
+                    ..Default::default()
+                }
+            ),
+            hygiene(),
+            fixer(None)
+        )
+    },
+    import_interop_node,
+    "
+import Foo from 'foo';
+import Bar, { baz } from 'bar';
+Foo.bar = true;
+Bar(baz);
+",
+    "
+'use strict';
+var _foo = require('foo');
+var _bar = _interopRequireWildcard(require('bar'));
+function _interopRequireWildcard(obj) {
+    if (obj && obj.__esModule) {
+        return obj;
+    } else {
+        var newObj = {};
+        if (obj != null) {
+            for(var key in obj){
+                if (Object.prototype.hasOwnProperty.call(obj, key)) {
+                    var desc = Object.defineProperty && Object.getOwnPropertyDescriptor ? \
+     Object.getOwnPropertyDescriptor(obj, key) : {};
+                    if (desc.get || desc.set) {
+                        Object.defineProperty(newObj, key, desc);
+                    } else {
+                        newObj[key] = obj[key];
+                    }
+                }
+            }
+        }
+        newObj.default = obj;
+        return newObj;
+    }
+}
+_foo.bar = true;
+_bar.default(_bar.baz);
+"
+);
+
+test!(
+    syntax(),
+    |_| {
+        let mark = Mark::fresh(Mark::root());
+
+        chain!(
+            resolver_with_mark(mark),
+            import_analyzer_with_interop(ImportInterop::None),
+            inject_helpers(),
+            common_js(
+                mark,
+                Config {
+                    import_interop: Some(ImportInterop::None),
+                    ..Default::default()
+                }
+            ),
+            hygiene(),
+            fixer(None)
+        )
+    },
+    import_interop_none,
+    "
+import Foo from 'foo';
+Foo.bar = true;
+",
+    "
+'use strict';
+var _foo = require('foo');
+_foo.default.bar = true;
+"
+);
+
 test!(
     syntax(),
     |_| {
@@ -129,7 +219,7 @@ test!(
         compat::es2018(),
         compat::es2017(),
         compat::es2016(),
-        compat::es2015(Mark::fresh(Mark::root()), Default::default()),
+        compat::es2015(Mark::fresh(Mark::root()), None, Default::default()),
         compat::es3(true),
         import_analyzer(),
         inject_helpers(),
@@ -881,15 +971,7 @@ Object.defineProperty(exports, "__esModule", {
 
 var _foo = require("foo");
 
-Object.keys(_foo).forEach(function (key) {
-  if (key === "default" || key === "__esModule") return;
-  Object.defineProperty(exports, key, {
-    enumerable: true,
-    get: function () {
-      return _foo[key];
-    }
-  });
-});
+_exportStar(_foo, exports);
 
 "#
 );
@@ -966,15 +1048,7 @@ Object.defineProperty(exports, "__esModule", {
 
 var _foo = require("./foo");
 
-Object.keys(_foo).forEach(function (key) {
-  if (key === "default" || key === "__esModule") return;
-  Object.defineProperty(exports, key, {
-    enumerable: true,
-    get: function () {
-      return _foo[key];
-    }
-  });
-});
+_exportStar(_foo, exports);
 
 "#
 );
@@ -1279,25 +1353,9 @@ Object.defineProperty(exports, "__esModule", {
 var _white = require("white");
 var _black = require("black");
 
-Object.keys(_white).forEach(function (key) {
-  if (key === "default" || key === "__esModule") return;
-  Object.defineProperty(exports, key, {
-    enumerable: true,
-    get: function () {
-      return _white[key];
-    }
-  });
-});
+_exportStar(_white, exports);
 
-Object.keys(_black).forEach(function (key) {
-  if (key === "default" || key === "__esModule") return;
-  Object.defineProperty(exports, key, {
-    enumerable: true,
-    get: function () {
-      return _black[key];
-    }
-  });
-});
+_exportStar(_black, exports);
 
 "#
 );
@@ -2214,15 +2272,7 @@ Object.defineProperty(exports, "__esModule", {
 
 var _foo = require("foo");
 
-Object.keys(_foo).forEach(function (key) {
-  if (key === "default" || key === "__esModule") return;
-  Object.defineProperty(exports, key, {
-    enumerable: true,
-    get: function () {
-      return _foo[key];
-    }
-  });
-});
+_exportStar(_foo, exports);
 
 "#
 );
@@ -3584,6 +3634,135 @@ this.foo();
 "#
 );
 
+test!(
+    syntax(),
+    |_| tr(Config {
+        allow_top_level_this: true,
+        ..Default::default()
+    }),
+    allow_top_level_this,
+    r#"
+this.foo();
+var foo = () => this;
+
+"#,
+    r#"
+"use strict";
+
+this.foo();
+var foo = () => this;
+
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(Config {
+        ..Default::default()
+    }),
+    this_in_class_prop,
+    r#"
+class Foo {
+    foo = this;
+    #bar = () => this;
+}
+
+"#,
+    r#"
+"use strict";
+
+class Foo {
+    foo = this;
+    #bar = () => this;
+}
+
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(Config {
+        ..Default::default()
+    }),
+    this_in_computed_class_prop_key,
+    r#"
+class Foo {
+    [this.key] = this;
+}
+
+"#,
+    r#"
+"use strict";
+
+class Foo {
+    [(void 0).key] = this;
+}
+
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(Config {
+        loose: true,
+        ..Default::default()
+    }),
+    loose_top_level_this,
+    r#"
+this.foo();
+var foo = () => this;
+function bar() {
+    return this;
+}
+
+"#,
+    r#"
+"use strict";
+
+exports.foo();
+var foo = () => exports;
+function bar() {
+    return this;
+}
+
+"#
+);
+
+test!(
+    syntax(),
+    |_| chain!(
+        tr(Config {
+            ..Default::default()
+        }),
+        hygiene()
+    ),
+    shadowed_exports_and_require,
+    r#"
+export var foo = 1;
+function bar(exports, require) {
+    foo = 2;
+    return import('./baz');
+}
+
+"#,
+    r#"
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+exports.foo = void 0;
+var foo = 1;
+exports.foo = foo;
+function bar(exports1, require1) {
+    exports.foo = foo = 2;
+    return Promise.resolve().then(function() {
+        return require('./baz');
+    });
+}
+
+"#
+);
+
 // strict_import_wildcard
 test!(
     syntax(),
@@ -3823,16 +4002,7 @@ var _react = _interopRequireWildcard(require("react"));
 var _default = _react.default;
 exports.default = _default;
 
-Object.keys(_react).forEach(function (key) {
-  if (key === "default" || key === "__esModule") return;
-  if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
-  Object.defineProperty(exports, key, {
-    enumerable: true,
-    get: function () {
-      return _react[key];
-    }
-  });
-});
+_exportStar(_react, exports, _exportNames);
 
 "#
 );
@@ -4099,15 +4269,7 @@ Object.defineProperty(exports, '__esModule', {
     value: true
 });
 var _c = require('c');
-Object.keys(_c).forEach(function(key) {
-    if (key === 'default' || key === '__esModule') return;
-    Object.defineProperty(exports, key, {
-        enumerable: true,
-        get: function() {
-            return _c[key];
-        }
-    });
-});
+_exportStar(_c, exports);
 "
 );
 
@@ -4283,15 +4445,7 @@ Object.defineProperty(exports, "Scope", {
         return _interfaces.Scope;
     }
 });
-Object.keys(_http).forEach(function(key) {
-    if (key === "default" || key === "__esModule") return;
-    Object.defineProperty(exports, key, {
-        enumerable: true,
-        get: function() {
-            return _http[key];
-        }
-    });
-});
+_exportStar(_http, exports);
   "#
 );
 
@@ -4323,23 +4477,42 @@ export * from './pipes';
             return _interfaces.id;
         }
     });
-    Object.keys(_http).forEach(function(key) {
-        if (key === "default" || key === "__esModule") return;
-        Object.defineProperty(exports, key, {
-            enumerable: true,
-            get: function() {
-                return _http[key];
-            }
-        });
-    });
-    Object.keys(_pipes).forEach(function(key) {
-        if (key === "default" || key === "__esModule") return;
-        Object.defineProperty(exports, key, {
-            enumerable: true,
-            get: function() {
-                return _pipes[key];
-            }
-        });
-    });
+    _exportStar(_http, exports);
+    _exportStar(_pipes, exports);
     "#
 );
+
+test!(
+    syntax(),
+    |_| tr(Config {
+        ..Default::default()
+    }),
+    use_strict_after_other_directive,
+    r#"
+"use asm";
+"use strict";
+foo();
+"#,
+    r#"
+"use asm";
+"use strict";
+foo();
+"#
+);
+
+test!(
+    Syntax::Es(EsConfig {
+        export_namespace_from: true,
+        ..Default::default()
+    }),
+    |_| tr(Default::default()),
+    export_namespace_from,
+    "export * as foo from 'bar';",
+    "'use strict';
+Object.defineProperty(exports, '__esModule', {
+    value: true
+});
+exports.foo = void 0;
+var _foo = _interopRequireWildcard(require('bar'));
+exports.foo = _foo;"
+);
@@ -1777,15 +1777,7 @@ export * from "foo";
   Object.defineProperty(_exports, "__esModule", {
     value: true
   });
-  Object.keys(_foo).forEach(function (key) {
-    if (key === "default" || key === "__esModule") return;
-    Object.defineProperty(_exports, key, {
-      enumerable: true,
-      get: function () {
-        return _foo[key];
-      }
-    });
-  });
+  _exportStar(_foo, _exports);
 });
 
 "#
@@ -1924,3 +1916,35 @@ test!(
     ",
     ok_if_code_eq
 );
+
+test!(
+    Syntax::Es(EsConfig {
+        export_namespace_from: true,
+        ..Default::default()
+    }),
+    |tester| tr(tester, Default::default()),
+    export_namespace_from,
+    "export * as foo from 'bar';",
+    "(function(global, factory) {
+    if (typeof define === 'function' && define.amd) {
+        define(['exports', 'bar'], factory);
+    } else if (typeof exports !== 'undefined') {
+        factory(exports, require('bar'));
+    } else {
+        var mod = {
+            exports: {
+            }
+        };
+        factory(mod.exports, global.bar);
+        global.input = mod.exports;
+    }
+})(this, function(_exports, _foo) {
+    'use strict';
+    _foo = _interopRequireWildcard(_foo);
+    Object.defineProperty(_exports, '__esModule', {
+        value: true
+    });
+    _exports.foo = void 0;
+    _exports.foo = _foo;
+});"
+);
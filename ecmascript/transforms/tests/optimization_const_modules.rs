@@ -2,8 +2,10 @@
 #![feature(test)]
 use common::Tester;
 use std::collections::HashMap;
-use swc_ecma_transforms::const_modules;
-use swc_ecma_visit::Fold;
+use swc_common::FileName;
+use swc_ecma_parser::{Parser, StringInput, Syntax};
+use swc_ecma_transforms::{const_modules, util::HANDLER};
+use swc_ecma_visit::{Fold, FoldWith};
 
 #[macro_use]
 mod common;
@@ -77,3 +79,113 @@ if (false) {
 }
 "
 );
+
+test!(
+    ::swc_ecma_parser::Syntax::default(),
+    |tester| tr(tester, &[("@ember/env-flags", &[("DEBUG", "true")])]),
+    renamed_flags,
+    r#"import { DEBUG as IS_DEBUG } from '@ember/env-flags';
+        if (IS_DEBUG) {
+            console.log('Foo!');
+        }"#,
+    r#"
+        if (true) {
+            console.log('Foo!');
+        }"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::default(),
+    |tester| tr(tester, &[("@ember/env-flags", &[("DEBUG", "true")])]),
+    namespace_flags,
+    r#"import * as flags from '@ember/env-flags';
+        if (flags.DEBUG) {
+            console.log('Foo!');
+        }"#,
+    r#"
+        if (true) {
+            console.log('Foo!');
+        }"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::default(),
+    |tester| tr(tester, &[("@ember/env-flags", &[("DEBUG", "true")])]),
+    shorthand_prop,
+    r#"import { DEBUG } from '@ember/env-flags';
+        const config = { DEBUG };"#,
+    r#"
+        const config = { DEBUG: true };"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::default(),
+    |tester| tr(
+        tester,
+        &[("@ember/env-flags", &[("DEBUG", "true"), ("TEST", "false")])]
+    ),
+    namespace_value,
+    r#"import * as flags from '@ember/env-flags';
+        foo(flags);
+        const config = { flags };"#,
+    r#"
+        foo({ "DEBUG": true, "TEST": false });
+        const config = { flags: { "DEBUG": true, "TEST": false } };"#
+);
+
+/// Returns the errors reported while folding `src`.
+fn errors(src: &str) -> String {
+    let res = testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let module = Parser::new(Syntax::default(), StringInput::from(&*fm), None)
+            .parse_module()
+            .unwrap();
+
+        let mut globals = HashMap::default();
+        let mut values = HashMap::default();
+        values.insert("DEBUG".into(), "true".to_string());
+        globals.insert("@ember/env-flags".into(), values);
+
+        HANDLER.set(handler, || {
+            module.fold_with(&mut const_modules(cm, globals))
+        });
+
+        if handler.has_errors() {
+            Err(())
+        } else {
+            Ok(())
+        }
+    });
+
+    res.unwrap_err().to_string()
+}
+
+#[test]
+fn namespace_missing_flag() {
+    let stderr = errors("import * as flags from '@ember/env-flags';\nif (flags.MISSING) {}");
+    assert!(
+        stderr.contains("@ember/env-flags does not contain flags named MISSING"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn named_missing_flag() {
+    let stderr = errors("import { MISSING } from '@ember/env-flags';\nif (MISSING) {}");
+    assert!(
+        stderr.contains("@ember/env-flags does not contain flags named MISSING"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn default_import() {
+    let stderr = errors("import flags from '@ember/env-flags';\nif (flags) {}");
+    assert!(
+        stderr.contains("const_modules does not support default import"),
+        "{}",
+        stderr
+    );
+}
@@ -1,11 +1,13 @@
 //! Copied from PeepholeIntegrationTest from the google closure compiler.
 
 #![feature(test)]
-use swc_common::chain;
+use std::collections::HashMap;
+use swc_common::{chain, DUMMY_SP};
+use swc_ecma_ast::*;
 use swc_ecma_parser::{Syntax, TsConfig};
 use swc_ecma_transforms::{
     optimization::{
-        simplifier,
+        inline_globals, simplifier,
         simplify::{dce::dce, inlining::inlining},
     },
     resolver,
@@ -515,3 +517,101 @@ test!(
     new A();
     "
 );
+
+fn production() -> HashMap<swc_atoms::JsWord, Expr> {
+    let mut envs = HashMap::default();
+    envs.insert(
+        "NODE_ENV".into(),
+        Expr::Lit(Lit::Str(Str {
+            span: DUMMY_SP,
+            value: "production".into(),
+            has_escape: false,
+            raw: None,
+        })),
+    );
+    envs
+}
+
+test!(
+    Default::default(),
+    |_| chain!(
+        resolver(),
+        inline_globals(production(), Default::default()),
+        simplifier(Default::default())
+    ),
+    inline_globals_dead_branches,
+    "
+    import * as devtools from 'devtools';
+    import { warn } from 'warn';
+    import { check, prod } from 'check';
+    if (process.env.NODE_ENV !== 'production') {
+        devtools.install();
+    } else {
+        prod();
+    }
+    process.env.NODE_ENV !== 'production' && warn();
+    process.env.NODE_ENV === 'production' || check();
+    export const mode = process.env.NODE_ENV === 'production' ? 'prod' : check;
+    ",
+    "
+    import { prod } from 'check';
+    prod();
+    export const mode = 'prod';
+    "
+);
+
+test!(
+    Default::default(),
+    |_| chain!(
+        resolver(),
+        inline_globals(production(), Default::default()),
+        simplifier(Default::default())
+    ),
+    inline_globals_dead_branches_const,
+    "
+    import { warn } from 'warn';
+    const dev = process.env.NODE_ENV !== 'production';
+    export function foo() {
+        if (dev) {
+            warn();
+        }
+        return 1;
+    }
+    ",
+    "
+    export function foo() {
+        return 1;
+    }
+    "
+);
+
+#[test]
+fn eval_prevents_inlining() {
+    test(
+        "function f() { var a = 1; eval('a = 2'); return a; }
+        function g() { var b = 1; return b; }
+        use(f(), g());",
+        "function f() { var a = 1; eval('a = 2'); return a; }
+        function g() { var b; return 1; }
+        use(f(), g());",
+    );
+}
+
+#[test]
+fn eval_prevents_inlining_in_parents() {
+    test_same(
+        "var a = 1;
+        function f() { return eval('a'); }
+        use(a, f());",
+    );
+}
+
+#[test]
+fn with_prevents_inlining() {
+    test(
+        "function f(obj) { var a = 1; with (obj) { return a; } }
+        use(f());",
+        "function f(obj) { var a = 1; with (obj) return a; }
+        use(f());",
+    );
+}
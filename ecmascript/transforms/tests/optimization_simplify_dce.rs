@@ -1,4 +1,5 @@
 #![feature(test)]
+use common::Tester;
 use swc_common::{chain, Mark, SyntaxContext};
 use swc_ecma_parser::{EsConfig, Syntax, TsConfig};
 use swc_ecma_transforms::{
@@ -6,7 +7,9 @@ use swc_ecma_transforms::{
     proposals::decorators,
     resolver,
     typescript::{self, strip},
+    util::SideEffectCtx,
 };
+use swc_ecma_visit::Fold;
 #[macro_use]
 mod common;
 
@@ -795,3 +798,106 @@ optimized_out!(
     }
     "
 );
+
+fn annotated_dce(t: &Tester) -> impl Fold {
+    let comments: &'static _ = Box::leak(Box::new(t.comments.clone()));
+
+    chain!(
+        resolver(),
+        dce(dce::Config {
+            side_effect_ctx: SideEffectCtx {
+                comments: Some(comments),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    )
+}
+
+test!(
+    Default::default(),
+    |t| annotated_dce(t),
+    pure_annotation,
+    "
+    /*#__PURE__*/ foo();
+    /*#__PURE__*/ new Foo();
+    /*#__PURE__*/ bar(baz());
+    qux();
+    ",
+    "
+    bar(baz());
+    qux();
+    "
+);
+
+test!(
+    Default::default(),
+    |t| annotated_dce(t),
+    no_side_effects_annotation,
+    "
+    /*#__NO_SIDE_EFFECTS__*/ function foo() {}
+    const bar = /*#__NO_SIDE_EFFECTS__*/ () => {};
+    /*#__NO_SIDE_EFFECTS__*/ const baz = function () {};
+    function qux() {}
+    foo();
+    bar(1);
+    baz(quux());
+    qux();
+    ",
+    "
+    const baz = function () {};
+    function qux() {}
+    baz(quux());
+    qux();
+    "
+);
+
+test!(
+    Default::default(),
+    |t| annotated_dce(t),
+    no_side_effects_annotation_export,
+    "
+    /*#__NO_SIDE_EFFECTS__*/ export function foo() {}
+    /*#__NO_SIDE_EFFECTS__*/ export const bar = () => {};
+    foo();
+    bar();
+    ",
+    "
+    export function foo() {}
+    export const bar = () => {};
+    "
+);
+
+test!(
+    Default::default(),
+    |t| annotated_dce(t),
+    no_side_effects_annotation_let,
+    "
+    /*#__NO_SIDE_EFFECTS__*/ let foo = () => {};
+    foo = bar;
+    foo();
+    ",
+    "
+    let foo = () => {};
+    foo = bar;
+    foo();
+    "
+);
+
+to!(
+    eval,
+    "var a = 1; function foo() { return eval('a'); } foo();",
+    "var a = 1; function foo() { return eval('a'); } foo();"
+);
+
+to!(
+    with,
+    "var a = 1; with (obj) { b = 1; }",
+    "var a = 1; with (obj) { b = 1; }"
+);
+
+to!(
+    unreachable,
+    "function foo() { throw 1; bar(); var a = baz(); } export { foo };",
+    "function foo() { throw 1; var a; } export { foo };"
+);
@@ -4363,7 +4363,7 @@ test!(
             ..Default::default()
         }),
         class_properties(),
-        // classes(),
+        // classes(None, Default::default()),
     ),
     issue_823_2,
     "import {Debounce} from 'lodash-decorators';
@@ -4409,7 +4409,7 @@ test!(
             ..Default::default()
         }),
         class_properties(),
-        classes(),
+        classes(None, Default::default()),
     ),
     issue_823_3,
     "import {Debounce} from 'lodash-decorators';
@@ -4731,9 +4731,7 @@ let MyClass = _class = Decorate(_class = _dec4(_class = _dec3(((_class = class M
     }
     method(generic: Inter<A>, generic2: InterGen<A, B>) {
     }
-}) || _class, _dec = function(target, key) {
-    return Arg()(target, key, 1);
-}, _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+}) || _class, _dec = _tsParam(1, Arg()), _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
     typeof Inter === "undefined" ? Object : Inter,
     typeof InterGen === "undefined" ? Object : InterGen
 ]), _applyDecoratedDescriptor(_class.prototype, "method", [
@@ -4850,11 +4848,7 @@ test!(
         _dec,
         _dec1,
         _dec2
-    ], Object.getOwnPropertyDescriptor(_class.prototype, "getHello"), _class.prototype), _dec3 = Get("/callback"), _dec4 = function(target, key) {
-        return Res()(target, key, 0);
-    }, _dec5 = function(target, key) {
-        return Session()(target, key, 1);
-    }, _dec6 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec7 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    ], Object.getOwnPropertyDescriptor(_class.prototype, "getHello"), _class.prototype), _dec3 = Get("/callback"), _dec4 = _tsParam(0, Res()), _dec5 = _tsParam(1, Session()), _dec6 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec7 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
         typeof express === "undefined" || typeof express.Response === "undefined" ? Object : express.Response,
         typeof express === "undefined" || typeof express.Express === "undefined" || typeof express.Express.Session === "undefined" ? Object : express.Express.Session
     ]), _applyDecoratedDescriptor(_class.prototype, "callback", [
@@ -4907,9 +4901,7 @@ test!(
     }
     var _dec12 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
         typeof Injected === "undefined" ? Object : Injected
-    ]), _dec13 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec14 = function(target, key) {
-        return inject()(target, undefined, 0);
-    };
+    ]), _dec13 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec14 = _tsParam(0, inject());
     let MyClass = _class = _dec14(_class = _dec13(_class = _dec12((_class = class MyClass {
         constructor(parameter: Injected){
         }
@@ -4917,11 +4909,7 @@ test!(
     var _dec15 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
         typeof Injected === "undefined" ? Object : Injected,
         typeof Injected === "undefined" ? Object : Injected
-    ]), _dec16 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec17 = function(target, key) {
-        return inject("KIND")(target, undefined, 1);
-    }, _dec18 = function(target, key) {
-        return inject()(target, undefined, 0);
-    };
+    ]), _dec16 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec17 = _tsParam(1, inject("KIND")), _dec18 = _tsParam(0, inject());
     let MyOtherClass = _class1 = _dec18(_class1 = _dec17(_class1 = _dec16(_class1 = _dec15(((_class1 = class MyOtherClass {
         constructor(private readonly parameter: Injected, otherParam: Injected){
         }
@@ -4929,20 +4917,14 @@ test!(
         }
         method(param: Injected, schema: Schema) {
         }
-    }) || _class1, _dec = function(target, key) {
-        return demo()(target, key, 0);
-    }, _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    }) || _class1, _dec = _tsParam(0, demo()), _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
         String,
         void 0
     ]), _applyDecoratedDescriptor(_class1.prototype, "methodUndecorated", [
         _dec,
         _dec1,
         _dec2
-    ], Object.getOwnPropertyDescriptor(_class1.prototype, "methodUndecorated"), _class1.prototype), _dec3 = decorate("named"), _dec4 = function(target, key) {
-        return inject()(target, key, 0);
-    }, _dec5 = function(target, key) {
-        return arg()(target, key, 1);
-    }, _dec6 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec7 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    ], Object.getOwnPropertyDescriptor(_class1.prototype, "methodUndecorated"), _class1.prototype), _dec3 = decorate("named"), _dec4 = _tsParam(0, inject()), _dec5 = _tsParam(1, arg()), _dec6 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec7 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
         typeof Injected === "undefined" ? Object : Injected,
         typeof Schema === "undefined" ? Object : Schema
     ]), _applyDecoratedDescriptor(_class1.prototype, "method", [
@@ -4955,19 +4937,13 @@ test!(
     var _dec19 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
         typeof Injected === "undefined" ? Object : Injected,
         typeof Injected === "undefined" ? Object : Injected
-    ]), _dec20 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec21 = function(target, key) {
-        return inject()(target, undefined, 1);
-    }, _dec22 = function(target, key) {
-        return inject()(target, undefined, 0);
-    };
+    ]), _dec20 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec21 = _tsParam(1, inject()), _dec22 = _tsParam(0, inject());
     let DecoratedClass = _class2 = Decorate(_class2 = _dec22(_class2 = _dec21(_class2 = _dec20(_class2 = _dec19(((_class2 = class DecoratedClass {
         constructor(private readonly module: Injected, otherModule: Injected){
         }
         method(param: string) {
         }
-    }) || _class2, _dec8 = decorate("example"), _dec9 = function(target, key) {
-        return inject()(target, key, 0);
-    }, _dec10 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec11 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    }) || _class2, _dec8 = decorate("example"), _dec9 = _tsParam(0, inject()), _dec10 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec11 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
         String
     ]), _applyDecoratedDescriptor(_class2.prototype, "method", [
         _dec8,
@@ -5067,7 +5043,7 @@ var _dec9 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "funct
     Number,
     typeof Object === "undefined" ? Object : Object,
     Function,
-    void 0,
+    String,
     Object,
     typeof Function === "undefined" ? Object : Function,
     void 0,
@@ -5079,7 +5055,7 @@ var _dec9 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "funct
     void 0
 ]), _dec10 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec11 = Decorate();
 let Sample = _class = _dec11(_class = _dec10(_class = _dec9(((_class = class Sample {
-    constructor(private p0: String, p1: Number, p2: 10, p3: "ABC", p4: boolean, p5: string, p6: number, p7: Object, p8: () => any, p9: "abc" | "def", p10: String | Number, p11: Function, p12: null, p13: undefined, p14: any, p15: (abc: any) => void, p16: falsee, p17: true, p18: string = "abc"){
+    constructor(private p0: String, p1: Number, p2: 10, p3: "ABC", p4: boolean, p5: string, p6: number, p7: Object, p8: () => any, p9: "abc" | "def", p10: String | Number, p11: Function, p12: null, p13: undefined, p14: any, p15: (abc: any) => void, p16: false, p17: true, p18: string = "abc"){
     }
     method(p0: Symbol, p1: typeof sym, p2: string | null, p3: never, p4: string | never, p5: (string | null), p6: Maybe<string>, p7: Object | string, p8: string & MyStringType, p9: string[], p10: [string, number], p11: void, p12: this is number, p13: null | undefined, p14: (string | (string | null)), p15: Object, p16: any, p17: bigint) {
     }
@@ -5087,15 +5063,13 @@ let Sample = _class = _dec11(_class = _dec10(_class = _dec9(((_class = class Sam
     }
     assignments(p0: string = "abc") {
     }
-}) || _class, _dec = function(target, key) {
-    return Arg()(target, key, 0);
-}, _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+}) || _class, _dec = _tsParam(0, Arg()), _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
     typeof Symbol === "undefined" ? Object : Symbol,
     Object,
+    String,
     void 0,
-    void 0,
-    void 0,
-    void 0,
+    String,
+    String,
     typeof Maybe === "undefined" ? Object : Maybe,
     Object,
     Object,
@@ -5104,7 +5078,7 @@ let Sample = _class = _dec11(_class = _dec10(_class = _dec9(((_class = class Sam
     void 0,
     Boolean,
     void 0,
-    Object,
+    String,
     typeof Object === "undefined" ? Object : Object,
     Object,
     Number
@@ -5129,3 +5103,76 @@ let Sample = _class = _dec11(_class = _dec10(_class = _dec9(((_class = class Sam
 ], Object.getOwnPropertyDescriptor(_class.prototype, "assignments"), _class.prototype), _class)) || _class) || _class) || _class;"##,
     ok_if_code_eq
 );
+
+test!(
+    ts(),
+    |_| decorators(Config {
+        legacy: true,
+        emit_metadata: true,
+    }),
+    legacy_metadata_union_type,
+    "class Foo {
+  @Field()
+  a: string | null;
+
+  @Field()
+  b: string | number;
+
+  @Field()
+  c: null | undefined;
+}",
+    r#"
+var _class, _descriptor, _descriptor1, _descriptor2;
+var _dec = Field(), _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", String), _dec2 = Field(), _dec3 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Object), _dec4 = Field(), _dec5 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", void 0);
+let Foo = ((_class = class Foo {
+    constructor(){
+        _initializerDefineProperty(this, "a", _descriptor, this);
+        _initializerDefineProperty(this, "b", _descriptor1, this);
+        _initializerDefineProperty(this, "c", _descriptor2, this);
+    }
+}) || _class, _descriptor = _applyDecoratedDescriptor(_class.prototype, "a", [
+    _dec,
+    _dec1
+], {
+    configurable: true,
+    enumerable: true,
+    writable: true,
+    initializer: void 0
+}), _descriptor1 = _applyDecoratedDescriptor(_class.prototype, "b", [
+    _dec2,
+    _dec3
+], {
+    configurable: true,
+    enumerable: true,
+    writable: true,
+    initializer: void 0
+}), _descriptor2 = _applyDecoratedDescriptor(_class.prototype, "c", [
+    _dec4,
+    _dec5
+], {
+    configurable: true,
+    enumerable: true,
+    writable: true,
+    initializer: void 0
+}), _class);
+"#
+);
+
+test!(
+    ts(),
+    |_| decorators(Config {
+        legacy: true,
+        emit_metadata: false,
+    }),
+    legacy_constructor_param_decorator,
+    "@Injectable()
+class Service {
+  constructor(@Inject(TOKEN) private dep, other) {}
+}",
+    "var _class;
+var _dec = _tsParam(0, Inject(TOKEN)), _dec1 = Injectable();
+let Service = _class = _dec1(_class = _dec((_class = class Service {
+    constructor(private dep, other){
+    }
+}) || _class) || _class) || _class;"
+);
@@ -0,0 +1,242 @@
+#![feature(test)]
+use swc_common::FileName;
+use swc_ecma_parser::{EsConfig, Parser, StringInput, Syntax};
+use swc_ecma_transforms::{proposals::do_expressions, util::HANDLER};
+use swc_ecma_visit::FoldWith;
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        do_expressions: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    simple,
+    "let a = do { 1; };",
+    "let a = (() => {
+    return 1;
+})();"
+);
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    if_else,
+    "
+let a = do {
+    if (x > 10) {
+        'big';
+    } else 'small';
+};
+",
+    "
+let a = (() => {
+    if (x > 10) {
+        return 'big';
+    } else return 'small';
+})();
+"
+);
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    try_catch,
+    "
+let a = do {
+    try {
+        JSON.parse(s);
+    } catch (e) {
+        null;
+    } finally {
+        cleanup();
+    }
+};
+",
+    "
+let a = (() => {
+    try {
+        return JSON.parse(s);
+    } catch (e) {
+        return null;
+    } finally {
+        cleanup();
+    }
+})();
+"
+);
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    trailing_fn_decl,
+    "
+let a = do {
+    let x = 1;
+    f(x);
+    function f(v) { return v + 1; }
+};
+",
+    "
+let a = (() => {
+    let x = 1;
+    return f(x);
+    function f(v) { return v + 1; }
+})();
+"
+);
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    await_in_body,
+    "
+async function f() {
+    let a = do {
+        await x;
+    };
+}
+",
+    "
+async function f() {
+    let a = await (async () => {
+        return await x;
+    })();
+}
+"
+);
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    await_in_nested_fn,
+    "
+let a = do {
+    async () => await x;
+};
+",
+    "
+let a = (() => {
+    return async () => await x;
+})();
+"
+);
+
+fn errors(src: &str) -> String {
+    let res = testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let module = Parser::new(syntax(), StringInput::from(&*fm), None)
+            .parse_module()
+            .unwrap();
+
+        HANDLER.set(handler, || module.fold_with(&mut do_expressions()));
+
+        if handler.has_errors() {
+            Err(())
+        } else {
+            Ok(())
+        }
+    });
+
+    match res {
+        Ok(()) => String::new(),
+        Err(stderr) => stderr.to_string(),
+    }
+}
+
+#[test]
+fn unsupported_return_and_yield() {
+    let errors = errors(
+        "
+    function* f() {
+        let a = do {
+            if (x) {
+                return 1;
+            }
+            yield 2;
+        };
+    }
+    ",
+    );
+
+    assert!(
+        errors.contains("`return` is not supported in do expressions"),
+        "{}",
+        errors
+    );
+    assert!(
+        errors.contains("`yield` is not supported in do expressions"),
+        "{}",
+        errors
+    );
+}
+
+#[test]
+fn unsupported_var() {
+    let errors = errors("let a = do { var b = 1; b; };");
+
+    assert!(
+        errors.contains("`var` declarations are not supported in do expressions"),
+        "{}",
+        errors
+    );
+}
+
+#[test]
+fn unsupported_break_and_continue() {
+    let errors = errors(
+        "
+    outer: for (;;) {
+        let a = do {
+            if (x) break;
+            if (y) continue outer;
+            1;
+        };
+    }
+    ",
+    );
+
+    assert!(
+        errors.contains("`break` out of a do expression is not supported"),
+        "{}",
+        errors
+    );
+    assert!(
+        errors.contains("`continue` out of a do expression is not supported"),
+        "{}",
+        errors
+    );
+}
+
+#[test]
+fn supported_jumps() {
+    assert_eq!(
+        errors(
+            "
+    let a = do {
+        inner: for (const x of xs) {
+            switch (x) {
+                case 1:
+                    break;
+                default:
+                    continue inner;
+            }
+            if (x) break inner;
+        }
+        function f() {
+            var b;
+            return b;
+        }
+        f();
+    };
+    ",
+        ),
+        ""
+    );
+}
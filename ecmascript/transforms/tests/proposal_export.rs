@@ -1,7 +1,10 @@
 #![feature(test)]
-use swc_common::chain;
+use swc_common::{chain, Mark};
 use swc_ecma_parser::{EsConfig, Syntax};
-use swc_ecma_transforms::{compat::es2020::export_namespace_from, proposals::export_default_from};
+use swc_ecma_transforms::{
+    compat::es2020::export_namespace_from, modules::common_js::common_js,
+    proposals::export_default_from,
+};
 use swc_ecma_visit::Fold;
 
 #[macro_use]
@@ -47,6 +50,40 @@ export { x, y as w } from "mod";
 "#
 );
 
+test!(
+    syntax_default(),
+    |_| tr(),
+    default_default_es6,
+    r#"export default from "bar";"#,
+    r#"
+import _default from "bar";
+export { _default as default };
+"#
+);
+
+test!(
+    syntax_default(),
+    |_| chain!(
+        tr(),
+        common_js(Mark::fresh(Mark::root()), Default::default())
+    ),
+    default_commonjs,
+    r#"export foo from "bar";"#,
+    r#"
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+  value: true
+});
+var _bar = _interopRequireDefault(require("bar"));
+Object.defineProperty(exports, "foo", {
+  enumerable: true,
+  get: function() {
+    return _bar.default;
+  }
+});
+"#
+);
+
 test!(
     syntax_namespace(),
     |_| tr(),
@@ -0,0 +1,78 @@
+#![feature(test)]
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms::proposals::function_bind;
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        fn_bind: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| function_bind(),
+    bind,
+    "let f = obj::func;",
+    "let f = func.bind(obj);"
+);
+
+test!(
+    syntax(),
+    |_| function_bind(),
+    bind_member,
+    "let f = ::obj.func;",
+    "let f = obj.func.bind(obj);"
+);
+
+test!(
+    syntax(),
+    |_| function_bind(),
+    call,
+    "obj::func(a, b);",
+    "func.call(obj, a, b);"
+);
+
+test!(
+    syntax(),
+    |_| function_bind(),
+    call_member,
+    "::obj.func(a);",
+    "obj.func.call(obj, a);"
+);
+
+test!(
+    syntax(),
+    |_| function_bind(),
+    complex_obj,
+    "let f = a.b::c.d;",
+    "
+var _b;
+let f = (_b = a.b, c.d).bind(_b);
+"
+);
+
+test!(
+    syntax(),
+    |_| function_bind(),
+    complex_obj_member,
+    "::a.b.c(d);",
+    "
+var _b;
+(_b = a.b).c.call(_b, d);
+"
+);
+
+test!(
+    syntax(),
+    |_| function_bind(),
+    chained,
+    "obj::f::g;",
+    "
+var _context;
+(_context = f.bind(obj), g).bind(_context);
+"
+);
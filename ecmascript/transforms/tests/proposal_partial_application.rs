@@ -0,0 +1,52 @@
+#![feature(test)]
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms::proposals::partial_application;
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        partial_application: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| partial_application(),
+    simple,
+    "const addOne = add(1, ?);",
+    "
+var _add;
+const addOne = (_add = add, function(_argPlaceholder) {
+    return _add(1, _argPlaceholder);
+});
+"
+);
+
+test!(
+    syntax(),
+    |_| partial_application(),
+    args_are_evaluated_eagerly,
+    "const f = g(?, a, ...b, ?);",
+    "
+var _g, _a, _b;
+const f = (_g = g, _a = a, _b = b, function(_argPlaceholder, _argPlaceholder1) {
+    return _g(_argPlaceholder, _a, ..._b, _argPlaceholder1);
+});
+"
+);
+
+test!(
+    syntax(),
+    |_| partial_application(),
+    member,
+    "const log = console.log(?);",
+    "
+var _console, _log;
+const log = (_console = console, _log = _console.log, function(_argPlaceholder) {
+    return _log.call(_console, _argPlaceholder);
+});
+"
+);
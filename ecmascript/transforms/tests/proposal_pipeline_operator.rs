@@ -0,0 +1,75 @@
+#![feature(test)]
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms::proposals::pipeline_operator;
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        pipeline_operator: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| pipeline_operator(),
+    ident,
+    "let result = x |> double;",
+    "let result = double(x);"
+);
+
+test!(
+    syntax(),
+    |_| pipeline_operator(),
+    chained,
+    r#"let result = "hello" |> doubleSay |> capitalize;"#,
+    r#"
+var _ref, _ref1;
+let result = (_ref1 = (_ref = "hello", doubleSay(_ref)), capitalize(_ref1));
+"#
+);
+
+test!(
+    syntax(),
+    |_| pipeline_operator(),
+    arrow,
+    "let result = a + b |> (x => x * 2);",
+    "
+var _ref;
+let result = (_ref = a + b, ((x)=>x * 2)(_ref));
+"
+);
+
+test!(
+    syntax(),
+    |_| pipeline_operator(),
+    precedence,
+    "let result = a || b |> f;",
+    "
+var _ref;
+let result = (_ref = a || b, f(_ref));
+"
+);
+
+test!(
+    Syntax::Es(EsConfig {
+        pipeline_operator: true,
+        fsharp_pipeline: true,
+        ..Default::default()
+    }),
+    |_| pipeline_operator(),
+    fsharp_await,
+    "
+async function f() {
+    return x |> fetch |> await |> json;
+}
+",
+    "
+async function f() {
+    var _ref;
+    return _ref = await fetch(x), json(_ref);
+}
+"
+);
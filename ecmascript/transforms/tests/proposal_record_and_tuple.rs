@@ -0,0 +1,111 @@
+#![feature(test)]
+use common::Tester;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms::proposals::record_and_tuple::{self, record_and_tuple};
+use swc_ecma_utils::DropSpan;
+use swc_ecma_visit::{FoldWith, VisitMutWith};
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        record_and_tuple: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| record_and_tuple(Default::default()),
+    globals,
+    "
+const point = #{ x: 1, y: 2, ...rest };
+const pair = #[1, ...others];
+",
+    "
+const point = Record({
+    x: 1,
+    y: 2,
+    ...rest
+});
+const pair = Tuple(1, ...others);
+"
+);
+
+test!(
+    syntax(),
+    |_| record_and_tuple(Default::default()),
+    nested,
+    "const a = #[#{ a: #[] }];",
+    "const a = Tuple(Record({
+    a: Tuple()
+}));"
+);
+
+test!(
+    syntax(),
+    |_| record_and_tuple(record_and_tuple::Config {
+        import_polyfill: true,
+        ..Default::default()
+    }),
+    import_polyfill,
+    "const a = #[#{}];",
+    r#"
+import { Record as _Record, Tuple as _Tuple } from "@bloomberg/record-tuple-polyfill";
+const a = _Tuple(_Record({}));
+"#
+);
+
+test!(
+    syntax(),
+    |_| record_and_tuple(record_and_tuple::Config {
+        import_polyfill: true,
+        polyfill_module_name: Some("my-polyfill".into()),
+    }),
+    polyfill_module_name,
+    "const a = #[];",
+    r#"
+import { Tuple as _Tuple } from "my-polyfill";
+const a = _Tuple();
+"#
+);
+
+#[test]
+fn import_polyfill_script() {
+    Tester::run(|tester| {
+        let mut print = |src: &str, fold: bool| -> Result<String, ()> {
+            let mut script = tester.with_parser("input.js", syntax(), src, |p| p.parse_script())?;
+            if fold {
+                script = script.fold_with(&mut record_and_tuple(record_and_tuple::Config {
+                    import_polyfill: true,
+                    ..Default::default()
+                }));
+            }
+            script.visit_mut_with(&mut DropSpan {
+                preserve_ctxt: false,
+            });
+
+            Ok(tester.print(&Module {
+                span: DUMMY_SP,
+                body: script.body.into_iter().map(ModuleItem::Stmt).collect(),
+                shebang: None,
+            }))
+        };
+
+        let actual = print("const a = #[#{}];", true)?;
+        let expected = print(
+            r#"
+var { Record: _Record, Tuple: _Tuple } = require("@bloomberg/record-tuple-polyfill");
+const a = _Tuple(_Record({}));
+"#,
+            false,
+        )?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    });
+}
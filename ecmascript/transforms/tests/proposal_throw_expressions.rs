@@ -0,0 +1,59 @@
+#![feature(test)]
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms::proposals::throw_expressions;
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        throw_expressions: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| throw_expressions(),
+    default_param,
+    r#"function save(filename = throw new TypeError("Argument required")) {}"#,
+    r#"
+function save(filename = (function(e) {
+    throw e;
+})(new TypeError("Argument required"))) {}
+"#
+);
+
+test!(
+    syntax(),
+    |_| throw_expressions(),
+    logical,
+    "const a = b || throw new Error();",
+    "
+const a = b || function(e) {
+    throw e;
+}(new Error());
+"
+);
+
+test!(
+    syntax(),
+    |_| throw_expressions(),
+    await_in_arg,
+    "async function f() { return a ?? throw await g(); }",
+    "
+async function f() {
+    return a ?? (function(e) {
+        throw e;
+    })(await g());
+}
+"
+);
+
+test!(
+    syntax(),
+    |_| throw_expressions(),
+    statement_is_unchanged,
+    "throw new Error();",
+    "throw new Error();"
+);
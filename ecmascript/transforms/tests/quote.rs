@@ -0,0 +1,53 @@
+use swc_common::{BytePos, Globals, DUMMY_SP, GLOBALS};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{Parser, StringInput, Syntax};
+use swc_ecma_transforms_macros::quote;
+use swc_ecma_utils::{drop_span, quote_ident};
+
+fn parse(src: &str) -> Box<Expr> {
+    GLOBALS.set(&Globals::new(), || {
+        let input = StringInput::new(src, BytePos(0), BytePos(src.len() as _));
+        drop_span(
+            Parser::new(Syntax::default(), input, None)
+                .parse_expr()
+                .unwrap(),
+        )
+    })
+}
+
+#[test]
+fn member() {
+    assert_eq!(quote!("React.createElement"), parse("React.createElement"));
+}
+
+#[test]
+fn exprs() {
+    assert_eq!(
+        quote!("new Foo(a[0], ...b, 'c', true, null, this)"),
+        parse("new Foo(a[0], ...b, 'c', true, null, this)")
+    );
+    assert_eq!(
+        quote!("typeof a === 'undefined' ? void 0 : (a, [b, , 1.5])"),
+        parse("typeof a === 'undefined' ? void 0 : (a, [b, , 1.5])")
+    );
+}
+
+#[test]
+fn placeholders() {
+    let obj = Expr::Ident(quote_ident!("obj"));
+    let key = Box::new(Expr::Lit(Lit::Str(Str {
+        span: DUMMY_SP,
+        value: "key".into(),
+        has_escape: false,
+        raw: None,
+    })));
+
+    assert_eq!(
+        quote!(
+            "Object.prototype.hasOwnProperty.call($obj, $key) && $obj.$key",
+            obj = obj,
+            key = key,
+        ),
+        parse("Object.prototype.hasOwnProperty.call(obj, 'key') && obj.$key")
+    );
+}
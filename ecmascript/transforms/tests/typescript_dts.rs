@@ -0,0 +1,216 @@
+#![feature(test)]
+use swc_ecma_parser::{Syntax, TsConfig};
+use swc_ecma_transforms::typescript::dts;
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::Typescript(TsConfig {
+        decorators: true,
+        ..Default::default()
+    })
+}
+
+macro_rules! to {
+    ($name:ident, $from:expr, $to:expr) => {
+        test!(syntax(), |_| dts(), $name, $from, $to, ok_if_code_eq);
+    };
+}
+
+to!(
+    functions,
+    "
+    export function foo(a: string, b = 1, ...rest: number[]): string {
+        return a;
+    }
+    export async function bar(c?: boolean) {
+        await foo('');
+    }
+    export function baz() {
+        return 1;
+    }
+    ",
+    "
+    export declare function foo(a: string, b?: number, ...rest: number[]): string;
+    export declare function bar(c?: boolean): Promise<void>;
+    export declare function baz(): any;
+    "
+);
+
+to!(
+    overloads,
+    "
+    export function foo(a: string): string;
+    export function foo(a: number): number;
+    export function foo(a: any): any {
+        return a;
+    }
+    ",
+    "
+    export declare function foo(a: string): string;
+    export declare function foo(a: number): number;
+    "
+);
+
+to!(
+    vars,
+    "
+    export const a = 1, b = 'b', c = -1;
+    export let d = 1, e: string | undefined, f = [1, 2], g = { h: true };
+    export const i = new Map<string, number>();
+    export const j = (k: number, l = '') => k;
+    ",
+    "
+    export declare const a = 1, b = 'b', c = -1;
+    export declare let d: number, e: string | undefined, f: number[], g: {
+        h: boolean;
+    };
+    export declare const i: Map<string, number>;
+    export declare const j: (k: number, l?: string) => any;
+    "
+);
+
+to!(
+    classes,
+    "
+    export class Foo<T> extends Bar implements Baz {
+        static a = 1;
+        readonly b = 'b';
+        c: T;
+        private d = 1;
+        #e = 1;
+
+        constructor(public f: string, private g: number, h = 1) {
+            super();
+        }
+
+        get i() {
+            return this.c;
+        }
+
+        set i(v: T) {
+            this.c = v;
+        }
+
+        private j() {}
+
+        k(l: T): void {}
+    }
+    ",
+    "
+    export declare class Foo<T> extends Bar implements Baz {
+        #private;
+        static a: number;
+        readonly b: 'b';
+        c: T;
+        private d;
+        public f: string;
+        private g;
+        constructor(f: string, g: number, h?: number);
+        get i(): any;
+        set i(v: T);
+        private j;
+        k(l: T): void;
+    }
+    "
+);
+
+to!(
+    unexported,
+    "
+    import { A, B } from './a';
+    import './side-effect';
+    interface Options {
+        a: A;
+    }
+    type Unused = string;
+    function helper() {}
+    const value = 1;
+    console.log(value);
+    export function foo(options: Options): typeof value {
+        helper();
+        return value;
+    }
+    ",
+    "
+    import { A } from './a';
+    import './side-effect';
+    interface Options {
+        a: A;
+    }
+    declare const value = 1;
+    export declare function foo(options: Options): typeof value;
+    "
+);
+
+to!(
+    export_default,
+    "
+    export default function (a: number) {
+        return a;
+    }
+    ",
+    "
+    export default function (a: number): any;
+    "
+);
+
+to!(
+    export_default_expr,
+    "
+    export default { a: 1 };
+    ",
+    "
+    declare const _default: {
+        a: number;
+    };
+    export default _default;
+    "
+);
+
+to!(
+    export_named,
+    "
+    class Foo {
+        bar() {}
+    }
+    const baz = 1;
+    export { Foo, baz as qux };
+    ",
+    "
+    declare class Foo {
+        bar(): void;
+    }
+    declare const baz = 1;
+    export { Foo, baz as qux };
+    "
+);
+
+to!(
+    namespace,
+    "
+    export namespace Foo {
+        export const a = 1;
+        const b = 2;
+        export function c() {}
+    }
+    ",
+    "
+    export declare namespace Foo {
+        export const a = 1;
+        export function c(): void;
+    }
+    "
+);
+
+to!(
+    module_without_exports,
+    "
+    import { a } from './a';
+    function foo() {}
+    ",
+    "
+    export {};
+    "
+);
@@ -1,11 +1,12 @@
 #![feature(test)]
 use strip::strip_with_config;
-use swc_common::chain;
-use swc_ecma_parser::{Syntax, TsConfig};
+use swc_common::{chain, FileName};
+use swc_ecma_parser::{Parser, StringInput, Syntax, TsConfig};
 use swc_ecma_transforms::{
-    compat::es2020::typescript_class_properties, proposals::decorators, resolver, typescript::strip,
+    compat::es2020::typescript_class_properties, proposals::decorators, resolver,
+    typescript::strip, util::HANDLER,
 };
-use swc_ecma_visit::Fold;
+use swc_ecma_visit::{Fold, FoldWith};
 
 #[macro_use]
 mod common;
@@ -689,7 +690,7 @@ to!(
     "export abstract class Kernel {
   [key: string]: any
 }",
-    "export abstract class Kernel {}"
+    "export class Kernel {}"
 );
 
 to!(
@@ -3096,6 +3097,88 @@ test!(
     "
 );
 
+test!(
+    Syntax::Typescript(Default::default()),
+    |_| strip(),
+    unused_import_after_strip,
+    "
+    import * as types from './types';
+    import def, { a, b } from './foo';
+    import './side-effect';
+    let x: types.Type = a;
+    let y: typeof b = x;
+    ",
+    "
+    import { a } from './foo';
+    import './side-effect';
+    let x = a;
+    let y = x;
+    "
+);
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |_| {
+        let mut config = strip::Config::default();
+        config.import_not_used_as_values = strip::ImportNotUsedAsValues::Preserve;
+        strip_with_config(config)
+    },
+    unused_import_after_strip_preserve,
+    "
+    import * as types from './types';
+    import def, { a, b } from './foo';
+    let x: types.Type = a;
+    let y: typeof b = x;
+    ",
+    "
+    import './types';
+    import { a } from './foo';
+    let x = a;
+    let y = x;
+    "
+);
+
+test!(
+    Syntax::Typescript(TsConfig {
+        tsx: true,
+        ..Default::default()
+    }),
+    |_| strip(),
+    unused_import_after_strip_jsx,
+    "
+    import React from 'react';
+    import { Unused } from './foo';
+    let a = <div />;
+    ",
+    "
+    import React from 'react';
+    let a = <div />;
+    "
+);
+
+test!(
+    Syntax::Typescript(TsConfig {
+        tsx: true,
+        ..Default::default()
+    }),
+    |_| {
+        let mut config = strip::Config::default();
+        config.pragma = Some("h".into());
+        config.pragma_frag = Some("Fragment".into());
+        strip_with_config(config)
+    },
+    unused_import_after_strip_jsx_pragma,
+    "
+    import React from 'react';
+    import { h, Fragment } from 'preact';
+    let a = <></>;
+    ",
+    "
+    import { h, Fragment } from 'preact';
+    let a = <></>;
+    "
+);
+
 test!(
     Syntax::Typescript(TsConfig {
         decorators: true,
@@ -3110,3 +3193,177 @@ test!(
     "
     "
 );
+
+/// Returns errors reported by the strip pass with `isolated_modules`.
+fn isolated_modules_errors(src: &str) -> String {
+    let res = testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let module = Parser::new(
+            Syntax::Typescript(Default::default()),
+            StringInput::from(&*fm),
+            None,
+        )
+        .parse_module()
+        .unwrap();
+
+        let mut config = strip::Config::default();
+        config.isolated_modules = true;
+        HANDLER.set(handler, || module.fold_with(&mut strip_with_config(config)));
+
+        if handler.has_errors() {
+            Err(())
+        } else {
+            Ok(())
+        }
+    });
+
+    match res {
+        Ok(()) => String::new(),
+        Err(stderr) => stderr.to_string(),
+    }
+}
+
+#[test]
+fn isolated_modules_reexported_type() {
+    let errors = isolated_modules_errors(
+        "
+    import { Type } from './types';
+    let a: Type;
+    export { Type };
+    ",
+    );
+
+    assert!(
+        errors.contains("`Type` is imported and used only as a type"),
+        "{}",
+        errors
+    );
+    assert!(errors.contains("export type { Type }"), "{}", errors);
+}
+
+#[test]
+fn isolated_modules_reexported_value() {
+    assert_eq!(
+        isolated_modules_errors(
+            "
+    import { Type, value } from './types';
+    let a: Type = value;
+    export type { Type };
+    export { value };
+    ",
+        ),
+        ""
+    );
+}
+
+#[test]
+fn isolated_modules_ambient_const_enum() {
+    let errors = isolated_modules_errors(
+        "
+    declare const enum Direction { Up, Down }
+    const enum Local { A }
+    console.log(Direction.Up, Local.A);
+    ",
+    );
+
+    assert!(
+        errors.contains("`Direction` is an ambient const enum"),
+        "{}",
+        errors
+    );
+    assert!(!errors.contains("`Local`"), "{}", errors);
+}
+
+fn collect_const_enums(src: &str) -> strip::ConstEnums {
+    testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let module = Parser::new(
+            Syntax::Typescript(TsConfig {
+                dts: true,
+                ..Default::default()
+            }),
+            StringInput::from(&*fm),
+            None,
+        )
+        .parse_module()
+        .unwrap();
+
+        Ok(strip::collect_const_enums(&module))
+    })
+    .unwrap()
+}
+
+const DIRECTION_DTS: &str = "
+export declare const enum Direction {
+    Up = 1,
+    Down,
+    Left = 'left',
+}
+export declare const enum Flags {
+    A = 1 << 2,
+    B = A | 1,
+}
+export declare enum NotConst {
+    A,
+}
+";
+
+#[test]
+fn collect_const_enums_dts() {
+    let enums = collect_const_enums(DIRECTION_DTS);
+
+    assert_eq!(
+        serde_json::to_value(&enums).unwrap(),
+        serde_json::json!({
+            "Direction": { "Up": 1.0, "Down": 2.0, "Left": "left" },
+            "Flags": { "A": 4.0, "B": 5.0 },
+        })
+    );
+}
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |_| {
+        let mut config = strip::Config::default();
+        config
+            .const_enums
+            .insert("./direction".into(), collect_const_enums(DIRECTION_DTS));
+        strip_with_config(config)
+    },
+    const_enums_across_files,
+    "
+    import { Direction, Flags as F } from './direction';
+    import * as ns from './direction';
+    import { other } from './other';
+    console.log(Direction.Up, Direction['Down'], F.B, ns.Direction.Left, other);
+    console.log(Direction.Right);
+    ",
+    "
+    import { Direction } from './direction';
+    import { other } from './other';
+    console.log(1, 2, 5, 'left', other);
+    console.log(Direction.Right);
+    "
+);
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |_| strip(),
+    optional_class_members,
+    "
+    class Foo {
+        a?: string = 'a';
+        #b!: number;
+        #c?: string;
+        d?() {}
+    }
+    ",
+    "
+    class Foo {
+        a = 'a';
+        #b;
+        #c;
+        d() {}
+    }
+    "
+);
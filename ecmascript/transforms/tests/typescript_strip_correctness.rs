@@ -160,7 +160,10 @@ fn correctness_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
 
                     {
                         let mut emitter = Emitter {
-                            cfg: swc_ecma_codegen::Config { minify: false },
+                            cfg: swc_ecma_codegen::Config {
+                                minify: false,
+                                ..Default::default()
+                            },
                             cm: cm.clone(),
                             wr: Box::new(swc_ecma_codegen::text_writer::JsWriter::new(
                                 cm.clone(),
@@ -274,6 +277,7 @@ impl Fold for Normalizer {
                 value: i.sym,
                 span: i.span,
                 has_escape: false,
+                raw: None,
             }),
             PropName::Num(n) => {
                 let s = if n.value.is_infinite() {
@@ -289,6 +293,7 @@ impl Fold for Normalizer {
                     value: s.into(),
                     span: n.span,
                     has_escape: false,
+                    raw: None,
                 })
             }
             _ => name,
@@ -0,0 +1,614 @@
+use crate::{ExprExt, Value::Known};
+use std::mem::{replace, take};
+use swc_atoms::JsWord;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{
+    noop_visit_mut_type, noop_visit_type, Node, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+const ENTRY: usize = 0;
+const EXIT: usize = 1;
+
+/// A control flow graph of the statements of a function body.
+///
+/// Statements are identified by their index in a pre-order traversal of the
+/// body, which doesn't enter expressions, so statements of nested functions
+/// and classes are not included.
+///
+/// Expressions are assumed to have no effect on control flow, except that any
+/// statement in the block of a `try` statement may throw. Loop and `if` tests
+/// which are known to be truthy or falsy are taken into account.
+///
+/// # Example
+///
+/// In
+///
+/// ```js
+/// a: {
+///     if (x) break a;
+///     else return;
+///     foo();
+/// }
+/// while (true) {}
+/// bar();
+/// ```
+///
+/// `foo()` and `bar()` are unreachable.
+#[derive(Debug)]
+pub struct Cfg {
+    /// Node of each statement.
+    stmts: Vec<usize>,
+    reachable: Vec<bool>,
+}
+
+impl Cfg {
+    pub fn build(body: &[Stmt]) -> Self {
+        let mut b = Builder {
+            succs: vec![vec![], vec![]],
+            stmts: vec![],
+            scopes: vec![],
+            labels: vec![],
+        };
+        let end = b.stmts(body, vec![ENTRY]);
+        b.edges(&end, EXIT);
+
+        let mut reachable = vec![false; b.succs.len()];
+        let mut queue = vec![ENTRY];
+        while let Some(node) = queue.pop() {
+            if !replace(&mut reachable[node], true) {
+                queue.extend(&b.succs[node]);
+            }
+        }
+
+        Cfg {
+            stmts: b.stmts,
+            reachable,
+        }
+    }
+
+    /// Returns true if control can reach the statement with index `stmt`.
+    pub fn is_reachable(&self, stmt: usize) -> bool {
+        self.reachable[self.stmts[stmt]]
+    }
+}
+
+/// Removes statements which can never be reached from the bodies of all
+/// functions in `node`, and returns true if anything was removed.
+///
+/// Variables declared by removed statements are still declared, without an
+/// initializer. Unreachable function declarations and block-scoped
+/// declarations are kept.
+pub fn drop_unreachable_code<N>(node: &mut N) -> bool
+where
+    N: VisitMutWith<UnreachableCodeRemover>,
+{
+    let mut v = UnreachableCodeRemover { changed: false };
+    node.visit_mut_with(&mut v);
+    v.changed
+}
+
+/// Removes statements which can never be reached from a function body, and
+/// returns true if anything was removed.
+///
+/// See [drop_unreachable_code].
+pub fn drop_unreachable_stmts(body: &mut Vec<Stmt>) -> bool {
+    let mut v = Dropper {
+        cfg: Cfg::build(body),
+        idx: 0,
+        changed: false,
+    };
+    body.visit_mut_with(&mut v);
+    v.changed
+}
+
+#[derive(Clone, PartialEq)]
+enum Jump {
+    Break(Option<JsWord>),
+    Continue(Option<JsWord>),
+    Return,
+    Throw,
+}
+
+enum Scope {
+    /// A loop, a `switch` or a labeled statement.
+    Breakable {
+        labels: Vec<JsWord>,
+        /// True for loops and `switch`.
+        unlabeled: bool,
+        /// The target of `continue` if this is a loop.
+        cont: Option<usize>,
+        breaks: Vec<usize>,
+    },
+    /// The block of a `try` statement with a `catch` clause.
+    Catch(usize),
+    /// The block and `catch` clause of a `try` statement with a `finally`
+    /// clause.
+    Finally(Vec<(usize, Jump)>),
+}
+
+struct Builder {
+    succs: Vec<Vec<usize>>,
+    stmts: Vec<usize>,
+    scopes: Vec<Scope>,
+    /// Labels of the next statement.
+    labels: Vec<JsWord>,
+}
+
+impl Builder {
+    fn node(&mut self, preds: &[usize]) -> usize {
+        let node = self.succs.len();
+        self.succs.push(vec![]);
+        self.edges(preds, node);
+        node
+    }
+
+    fn edges(&mut self, preds: &[usize], to: usize) {
+        for &pred in preds {
+            self.succs[pred].push(to);
+        }
+    }
+
+    fn jump(&mut self, from: usize, jump: Jump) {
+        for scope in self.scopes.iter_mut().rev() {
+            match scope {
+                Scope::Breakable {
+                    labels,
+                    unlabeled,
+                    cont,
+                    breaks,
+                } => match (&jump, *cont) {
+                    (Jump::Break(None), _) if *unlabeled => {
+                        breaks.push(from);
+                        return;
+                    }
+                    (Jump::Break(Some(label)), _) if labels.contains(label) => {
+                        breaks.push(from);
+                        return;
+                    }
+                    (Jump::Continue(None), Some(cont)) => {
+                        self.succs[from].push(cont);
+                        return;
+                    }
+                    (Jump::Continue(Some(label)), Some(cont)) if labels.contains(label) => {
+                        self.succs[from].push(cont);
+                        return;
+                    }
+                    _ => {}
+                },
+                Scope::Catch(handler) => {
+                    if jump == Jump::Throw {
+                        self.succs[from].push(*handler);
+                        return;
+                    }
+                }
+                Scope::Finally(pending) => {
+                    pending.push((from, jump));
+                    return;
+                }
+            }
+        }
+
+        self.succs[from].push(EXIT);
+    }
+
+    /// Builds a loop, a `switch` or a labeled statement with `op`, which
+    /// returns the nodes which leave it without `break`.
+    fn breakable<F>(
+        &mut self,
+        labels: Vec<JsWord>,
+        unlabeled: bool,
+        cont: Option<usize>,
+        op: F,
+    ) -> Vec<usize>
+    where
+        F: FnOnce(&mut Self) -> Vec<usize>,
+    {
+        self.scopes.push(Scope::Breakable {
+            labels,
+            unlabeled,
+            cont,
+            breaks: vec![],
+        });
+        let mut end = op(self);
+        match self.scopes.pop() {
+            Some(Scope::Breakable { breaks, .. }) => end.extend(breaks),
+            _ => unreachable!(),
+        }
+        end
+    }
+
+    fn stmts(&mut self, stmts: &[Stmt], preds: Vec<usize>) -> Vec<usize> {
+        stmts.iter().fold(preds, |preds, s| self.stmt(s, preds))
+    }
+
+    /// Returns the nodes from which control continues after `s`.
+    fn stmt(&mut self, s: &Stmt, preds: Vec<usize>) -> Vec<usize> {
+        let labels = take(&mut self.labels);
+        let node = self.node(&preds);
+        self.stmts.push(node);
+
+        match s {
+            Stmt::Block(s) => self.stmts(&s.stmts, vec![node]),
+
+            Stmt::With(s) => self.stmt(&s.body, vec![node]),
+
+            Stmt::Return(..) => {
+                self.jump(node, Jump::Return);
+                vec![]
+            }
+
+            Stmt::Throw(..) => {
+                self.jump(node, Jump::Throw);
+                vec![]
+            }
+
+            Stmt::Break(s) => {
+                self.jump(node, Jump::Break(s.label.as_ref().map(|l| l.sym.clone())));
+                vec![]
+            }
+
+            Stmt::Continue(s) => {
+                self.jump(
+                    node,
+                    Jump::Continue(s.label.as_ref().map(|l| l.sym.clone())),
+                );
+                vec![]
+            }
+
+            Stmt::Labeled(s) => {
+                let mut labels = labels;
+                labels.push(s.label.sym.clone());
+
+                match &*s.body {
+                    Stmt::Labeled(..)
+                    | Stmt::While(..)
+                    | Stmt::DoWhile(..)
+                    | Stmt::For(..)
+                    | Stmt::ForIn(..)
+                    | Stmt::ForOf(..)
+                    | Stmt::Switch(..) => {
+                        self.labels = labels;
+                        self.stmt(&s.body, vec![node])
+                    }
+                    body => self.breakable(labels, false, None, |b| b.stmt(body, vec![node])),
+                }
+            }
+
+            Stmt::If(s) => {
+                let test = s.test.as_bool().1;
+                let mut end = self.stmt(
+                    &s.cons,
+                    if test == Known(false) {
+                        vec![]
+                    } else {
+                        vec![node]
+                    },
+                );
+                let alt = if test == Known(true) {
+                    vec![]
+                } else {
+                    vec![node]
+                };
+                end.extend(match &s.alt {
+                    Some(s) => self.stmt(s, alt),
+                    None => alt,
+                });
+                end
+            }
+
+            Stmt::While(WhileStmt { test, body, .. })
+            | Stmt::For(ForStmt {
+                test: Some(test),
+                body,
+                ..
+            }) => {
+                let test = test.as_bool().1;
+                self.breakable(labels, true, Some(node), |b| {
+                    let body = b.stmt(
+                        body,
+                        if test == Known(false) {
+                            vec![]
+                        } else {
+                            vec![node]
+                        },
+                    );
+                    b.edges(&body, node);
+
+                    if test == Known(true) {
+                        vec![]
+                    } else {
+                        vec![node]
+                    }
+                })
+            }
+
+            Stmt::For(ForStmt {
+                test: None, body, ..
+            }) => self.breakable(labels, true, Some(node), |b| {
+                let body = b.stmt(body, vec![node]);
+                b.edges(&body, node);
+                vec![]
+            }),
+
+            Stmt::ForIn(ForInStmt { body, .. }) | Stmt::ForOf(ForOfStmt { body, .. }) => self
+                .breakable(labels, true, Some(node), |b| {
+                    let body = b.stmt(body, vec![node]);
+                    b.edges(&body, node);
+                    vec![node]
+                }),
+
+            Stmt::DoWhile(s) => {
+                let test_node = self.node(&[]);
+                let test = s.test.as_bool().1;
+                self.breakable(labels, true, Some(test_node), |b| {
+                    let body = b.stmt(&s.body, vec![node]);
+                    b.edges(&body, test_node);
+
+                    if test == Known(true) {
+                        b.edges(&[test_node], node);
+                        vec![]
+                    } else {
+                        if test != Known(false) {
+                            b.edges(&[test_node], node);
+                        }
+                        vec![test_node]
+                    }
+                })
+            }
+
+            Stmt::Switch(s) => self.breakable(labels, true, None, |b| {
+                let mut end = vec![];
+                for case in &s.cases {
+                    end.push(node);
+                    end = b.stmts(&case.cons, end);
+                }
+                if s.cases.iter().all(|case| case.test.is_some()) {
+                    end.push(node);
+                }
+                end
+            }),
+
+            Stmt::Try(s) => {
+                if s.finalizer.is_some() {
+                    self.scopes.push(Scope::Finally(vec![]));
+                }
+
+                let end = match &s.handler {
+                    Some(handler) => {
+                        // Any statement in the block may throw.
+                        let entry = self.node(&[node]);
+                        self.scopes.push(Scope::Catch(entry));
+                        let mut end = self.stmts(&s.block.stmts, vec![node]);
+                        self.scopes.pop();
+                        end.extend(self.stmts(&handler.body.stmts, vec![entry]));
+                        end
+                    }
+                    None => self.stmts(&s.block.stmts, vec![node]),
+                };
+
+                let finalizer = match &s.finalizer {
+                    Some(finalizer) => finalizer,
+                    None => return end,
+                };
+                let pending = match self.scopes.pop() {
+                    Some(Scope::Finally(pending)) => pending,
+                    _ => unreachable!(),
+                };
+
+                let entry = self.node(&[node]);
+                self.edges(&end, entry);
+                let mut jumps = vec![Jump::Throw];
+                for (from, jump) in pending {
+                    self.succs[from].push(entry);
+                    if !jumps.contains(&jump) {
+                        jumps.push(jump);
+                    }
+                }
+
+                // The jumps which entered the `finally` clause continue after it.
+                let finalizer_end = self.stmts(&finalizer.stmts, vec![entry]);
+                for jump in jumps {
+                    for &from in &finalizer_end {
+                        self.jump(from, jump.clone());
+                    }
+                }
+
+                if end.is_empty() {
+                    vec![]
+                } else {
+                    finalizer_end
+                }
+            }
+
+            Stmt::Empty(..) | Stmt::Debugger(..) | Stmt::Decl(..) | Stmt::Expr(..) => vec![node],
+        }
+    }
+}
+
+/// Drops unreachable statements from each function body.
+pub struct UnreachableCodeRemover {
+    changed: bool,
+}
+
+impl UnreachableCodeRemover {
+    fn drop_from_body(&mut self, body: &mut Option<BlockStmt>) {
+        if let Some(body) = body {
+            self.changed |= drop_unreachable_stmts(&mut body.stmts);
+        }
+    }
+}
+
+impl VisitMut for UnreachableCodeRemover {
+    noop_visit_mut_type!();
+
+    fn visit_mut_arrow_expr(&mut self, n: &mut ArrowExpr) {
+        n.visit_mut_children_with(self);
+
+        if let BlockStmtOrExpr::BlockStmt(body) = &mut n.body {
+            self.changed |= drop_unreachable_stmts(&mut body.stmts);
+        }
+    }
+
+    fn visit_mut_constructor(&mut self, n: &mut Constructor) {
+        n.visit_mut_children_with(self);
+        self.drop_from_body(&mut n.body);
+    }
+
+    fn visit_mut_function(&mut self, n: &mut Function) {
+        n.visit_mut_children_with(self);
+        self.drop_from_body(&mut n.body);
+    }
+
+    fn visit_mut_getter_prop(&mut self, n: &mut GetterProp) {
+        n.visit_mut_children_with(self);
+        self.drop_from_body(&mut n.body);
+    }
+
+    fn visit_mut_setter_prop(&mut self, n: &mut SetterProp) {
+        n.visit_mut_children_with(self);
+        self.drop_from_body(&mut n.body);
+    }
+}
+
+/// Replaces unreachable statements of a function body, visiting statements in
+/// the same order as [Builder].
+struct Dropper {
+    cfg: Cfg,
+    idx: usize,
+    changed: bool,
+}
+
+impl Dropper {
+    fn is_next_reachable(&mut self) -> bool {
+        let reachable = self.cfg.is_reachable(self.idx);
+        self.idx += 1;
+        reachable
+    }
+
+    /// Returns what should be left of an unreachable statement.
+    fn drop_stmt(&mut self, s: Stmt) -> Option<Stmt> {
+        let mut v = DeclFinder::default();
+        s.visit_children_with(&mut v);
+        self.idx += v.stmts;
+
+        match &s {
+            Stmt::Empty(..) | Stmt::Decl(Decl::Fn(..)) => return Some(s),
+            // Hoisted by `var`, so `var a;` is all that's left.
+            Stmt::Decl(Decl::Var(VarDecl {
+                kind: VarDeclKind::Var,
+                decls,
+                ..
+            })) if decls
+                .iter()
+                .all(|d| d.init.is_none() && matches!(d.name, Pat::Ident(..))) =>
+            {
+                return Some(s)
+            }
+            Stmt::Decl(Decl::Var(VarDecl {
+                kind: VarDeclKind::Var,
+                ..
+            })) => {}
+            Stmt::Decl(..) => return Some(s),
+            // Function declarations in blocks may be hoisted outside of them.
+            _ if v.has_fn => return Some(s),
+            _ => {}
+        }
+
+        self.changed = true;
+        if v.vars.is_empty() {
+            return None;
+        }
+
+        Some(Stmt::Decl(Decl::Var(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            declare: false,
+            decls: v
+                .vars
+                .into_iter()
+                .map(|i| VarDeclarator {
+                    span: i.span,
+                    name: Pat::Ident(i),
+                    init: None,
+                    definite: false,
+                })
+                .collect(),
+        })))
+    }
+}
+
+impl VisitMut for Dropper {
+    noop_visit_mut_type!();
+
+    fn visit_mut_class(&mut self, _: &mut Class) {}
+
+    fn visit_mut_expr(&mut self, _: &mut Expr) {}
+
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        if self.is_next_reachable() {
+            s.visit_mut_children_with(self);
+            return;
+        }
+
+        let stmt = take_stmt(s);
+        *s = self
+            .drop_stmt(stmt)
+            .unwrap_or(Stmt::Empty(EmptyStmt { span: DUMMY_SP }));
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        let mut buf = Vec::with_capacity(stmts.len());
+
+        for mut stmt in take(stmts) {
+            if self.is_next_reachable() {
+                stmt.visit_mut_children_with(self);
+                buf.push(stmt);
+            } else {
+                buf.extend(self.drop_stmt(stmt));
+            }
+        }
+
+        *stmts = buf;
+    }
+}
+
+fn take_stmt(s: &mut Stmt) -> Stmt {
+    replace(s, Stmt::Empty(EmptyStmt { span: DUMMY_SP }))
+}
+
+/// Finds the variables and functions declared by a statement, and counts the
+/// statements in it.
+#[derive(Default)]
+struct DeclFinder {
+    vars: Vec<Ident>,
+    has_fn: bool,
+    stmts: usize,
+}
+
+impl Visit for DeclFinder {
+    noop_visit_type!();
+
+    fn visit_class(&mut self, _: &Class, _: &dyn Node) {}
+
+    fn visit_expr(&mut self, _: &Expr, _: &dyn Node) {}
+
+    fn visit_fn_decl(&mut self, _: &FnDecl, _: &dyn Node) {
+        self.has_fn = true;
+    }
+
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+    fn visit_stmt(&mut self, s: &Stmt, _: &dyn Node) {
+        self.stmts += 1;
+        s.visit_children_with(self);
+    }
+
+    fn visit_var_decl(&mut self, n: &VarDecl, _: &dyn Node) {
+        if n.kind == VarDeclKind::Var {
+            self.vars.extend(crate::find_ids::<_, Ident>(&n.decls));
+        }
+    }
+}
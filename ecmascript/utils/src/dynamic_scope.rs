@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+use swc_atoms::js_word;
+use swc_common::{Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitWith};
+
+/// Functions whose bindings may be accessed by name at runtime, because they
+/// contain a direct call to `eval` or a `with` statement.
+///
+/// Code evaluated by `eval` can access the bindings of all enclosing
+/// functions, and an identifier in the body of `with` may refer to a property
+/// of the object instead of a binding, so the functions enclosing a dynamic
+/// function are dynamic as well. Bindings declared in a dynamic function
+/// should not be renamed, inlined or removed.
+///
+/// Functions are identified by their spans, so generated functions with dummy
+/// spans are all dynamic if any of them is.
+#[derive(Debug, Default)]
+pub struct DynamicScopes {
+    top_level: bool,
+    fns: HashSet<Span>,
+}
+
+impl DynamicScopes {
+    pub fn analyze<N>(node: &N) -> Self
+    where
+        N: VisitWith<DynamicScopeFinder>,
+    {
+        let mut v = DynamicScopeFinder {
+            stack: vec![],
+            scopes: Default::default(),
+        };
+        node.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+        v.scopes
+    }
+
+    /// Returns true if the top level is dynamic, which is the case if any
+    /// function is.
+    pub fn is_top_level_dynamic(&self) -> bool {
+        self.top_level
+    }
+
+    /// Returns true if the function, arrow function, getter, setter or
+    /// constructor at `span` is dynamic.
+    pub fn is_dynamic(&self, span: Span) -> bool {
+        self.fns.contains(&span)
+    }
+}
+
+/// Returns true if `call` is a direct call to `eval`, which can access the
+/// bindings of the caller.
+pub fn is_direct_eval(call: &CallExpr) -> bool {
+    fn is_eval(e: &Expr) -> bool {
+        match e {
+            Expr::Ident(Ident {
+                sym: js_word!("eval"),
+                ..
+            }) => true,
+            Expr::Paren(ParenExpr { expr, .. }) => is_eval(expr),
+            _ => false,
+        }
+    }
+
+    match &call.callee {
+        ExprOrSuper::Expr(callee) => is_eval(callee),
+        ExprOrSuper::Super(..) => false,
+    }
+}
+
+pub struct DynamicScopeFinder {
+    /// Spans of the functions being visited.
+    stack: Vec<Span>,
+    scopes: DynamicScopes,
+}
+
+impl DynamicScopeFinder {
+    fn with_scope<F>(&mut self, span: Span, op: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.stack.push(span);
+        op(self);
+        self.stack.pop();
+    }
+
+    fn mark_dynamic(&mut self) {
+        self.scopes.top_level = true;
+        self.scopes.fns.extend(self.stack.iter().copied());
+    }
+}
+
+impl Visit for DynamicScopeFinder {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr, _: &dyn Node) {
+        self.with_scope(n.span, |v| n.visit_children_with(v))
+    }
+
+    fn visit_call_expr(&mut self, n: &CallExpr, _: &dyn Node) {
+        if is_direct_eval(n) {
+            self.mark_dynamic();
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_constructor(&mut self, n: &Constructor, _: &dyn Node) {
+        self.with_scope(n.span, |v| n.visit_children_with(v))
+    }
+
+    fn visit_function(&mut self, n: &Function, _: &dyn Node) {
+        self.with_scope(n.span, |v| n.visit_children_with(v))
+    }
+
+    fn visit_getter_prop(&mut self, n: &GetterProp, _: &dyn Node) {
+        n.key.visit_with(n as _, self);
+        self.with_scope(n.span, |v| n.body.visit_with(n as _, v))
+    }
+
+    fn visit_setter_prop(&mut self, n: &SetterProp, _: &dyn Node) {
+        n.key.visit_with(n as _, self);
+        self.with_scope(n.span, |v| {
+            n.param.visit_with(n as _, v);
+            n.body.visit_with(n as _, v);
+        })
+    }
+
+    fn visit_with_stmt(&mut self, n: &WithStmt, _: &dyn Node) {
+        self.mark_dynamic();
+        n.visit_children_with(self);
+    }
+}
@@ -3,6 +3,19 @@ use swc_atoms::js_word;
 use swc_common::{Span, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
 
+/// Returns true if `e` is a call, or a member or a tagged template of a call.
+fn is_call_chain(e: &Expr) -> bool {
+    match e {
+        Expr::Call(..) => true,
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            ..
+        }) => is_call_chain(obj),
+        Expr::TaggedTpl(TaggedTpl { tag, .. }) => is_call_chain(tag),
+        _ => false,
+    }
+}
+
 /// Extension methods for [Expr].
 pub trait ExprFactory: Into<Expr> {
     #[inline]
@@ -43,6 +56,17 @@ pub trait ExprFactory: Into<Expr> {
         })
     }
 
+    /// Creates `(self)()`, which is useful for functions and arrows.
+    #[inline]
+    fn into_iife(self) -> Expr {
+        Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: self.wrap_with_paren_if_needed().as_callee(),
+            args: vec![],
+            type_args: None,
+        })
+    }
+
     #[inline]
     fn wrap_with_paren(self) -> Expr {
         let expr = Box::new(self.into());
@@ -50,6 +74,23 @@ pub trait ExprFactory: Into<Expr> {
         Expr::Paren(ParenExpr { expr, span })
     }
 
+    /// Wraps `self` with parens unless it can be used as a callee, the callee
+    /// of a `new` expression or the object of a member expression as is.
+    fn wrap_with_paren_if_needed(self) -> Expr {
+        let expr = self.into();
+        match expr {
+            Expr::Ident(..)
+            | Expr::Lit(..)
+            | Expr::This(..)
+            | Expr::Array(..)
+            | Expr::Paren(..)
+            | Expr::Tpl(..) => expr,
+            // `new (foo())()` is not `new foo()()`
+            Expr::Member(..) | Expr::TaggedTpl(..) if !is_call_chain(&expr) => expr,
+            _ => expr.wrap_with_paren(),
+        }
+    }
+
     /// Creates a binrary expr `$self === `
     #[inline]
     fn make_eq<T>(self, right: T) -> Expr
@@ -103,3 +144,61 @@ pub trait ExprFactory: Into<Expr> {
 }
 
 impl<T: Into<Expr>> ExprFactory for T {}
+
+/// Creates an array literal.
+pub fn array_lit<I>(span: Span, elems: I) -> Expr
+where
+    I: IntoIterator,
+    I::Item: Into<Expr>,
+{
+    Expr::Array(ArrayLit {
+        span,
+        elems: elems.into_iter().map(|elem| Some(elem.as_arg())).collect(),
+    })
+}
+
+/// Creates an object literal from pairs of keys and values.
+pub fn object_lit<I, K, V>(span: Span, props: I) -> Expr
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<PropName>,
+    V: Into<Expr>,
+{
+    Expr::Object(ObjectLit {
+        span,
+        props: props
+            .into_iter()
+            .map(|(key, value)| {
+                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: key.into(),
+                    value: Box::new(value.into()),
+                })))
+            })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_with_paren_if_needed() {
+        let call = Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: quote_ident!("foo").as_callee(),
+            args: vec![],
+            type_args: None,
+        });
+
+        assert!(quote_ident!("foo")
+            .make_member(quote_ident!("bar"))
+            .wrap_with_paren_if_needed()
+            .is_member());
+        assert!(call.clone().wrap_with_paren_if_needed().is_paren());
+        assert!(call
+            .make_member(quote_ident!("bar"))
+            .wrap_with_paren_if_needed()
+            .is_paren());
+    }
+}
@@ -1,6 +1,9 @@
 pub use self::{
-    factory::ExprFactory,
+    cfg::{drop_unreachable_code, drop_unreachable_stmts, Cfg, UnreachableCodeRemover},
+    dynamic_scope::{is_direct_eval, DynamicScopeFinder, DynamicScopes},
+    factory::{array_lit, object_lit, ExprFactory},
     ident::{id, Id},
+    side_effect::{find_no_side_effect_fns, has_side_effects, NoSideEffectFnFinder, SideEffectCtx},
     value::{
         Type::{
             self, Bool as BoolType, Null as NullType, Num as NumberType, Obj as ObjectType,
@@ -12,23 +15,30 @@ pub use self::{
 };
 use crate::ident::IdentLike;
 use scoped_tls::scoped_thread_local;
+use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
     f64::{INFINITY, NAN},
+    mem::take,
     num::FpCategory,
     ops::Add,
 };
 use swc_atoms::{js_word, JsWord};
-use swc_common::{errors::Handler, Mark, Span, Spanned, DUMMY_SP};
+use swc_common::{errors::Handler, BytePos, Mark, Span, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
-use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitMut, VisitMutWith, VisitWith};
+use swc_ecma_visit::{
+    noop_visit_type, Fold, FoldWith, Node, Visit, VisitMut, VisitMutWith, VisitWith,
+};
 use unicode_xid::UnicodeXID;
 
 #[macro_use]
 mod macros;
+mod cfg;
 pub mod constructor;
+mod dynamic_scope;
 mod factory;
 pub mod ident;
+mod side_effect;
 mod value;
 pub mod var;
 
@@ -157,6 +167,39 @@ impl StmtLike for ModuleItem {
     }
 }
 
+/// A folder which declares the variables it introduces right before the
+/// statement they are introduced in.
+pub trait HoistVars: Fold + Sized {
+    /// Variables introduced while folding the current statement.
+    fn vars(&mut self) -> &mut Vec<VarDeclarator>;
+
+    /// Folds `stmts`, declaring the variables introduced by each statement
+    /// with `var` before it.
+    fn fold_stmt_like<T>(&mut self, stmts: Vec<T>) -> Vec<T>
+    where
+        T: StmtLike + FoldWith<Self>,
+    {
+        let mut buf = Vec::with_capacity(stmts.len() + 2);
+
+        for stmt in stmts {
+            let stmt = stmt.fold_with(self);
+
+            if !self.vars().is_empty() {
+                buf.push(T::from_stmt(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    decls: take(self.vars()),
+                    declare: false,
+                }))));
+            }
+
+            buf.push(stmt);
+        }
+
+        buf
+    }
+}
+
 pub type BoolValue = Value<bool>;
 
 pub trait IsEmpty {
@@ -862,18 +905,39 @@ pub trait ExprExt {
     }
 
     fn may_have_side_effects(&self) -> bool {
+        self.may_have_side_effects_with(SideEffectCtx::default())
+    }
+
+    /// Like [ExprExt::may_have_side_effects], but calls annotated with
+    /// `/*#__PURE__*/` are pure if `ctx` has comments, and so are calls to the
+    /// functions annotated with `/*#__NO_SIDE_EFFECTS__*/` in `ctx`.
+    fn may_have_side_effects_with(&self, ctx: SideEffectCtx) -> bool {
         if self.is_pure_callee() {
             return false;
         }
 
         match *self.as_expr() {
+            Expr::Call(CallExpr {
+                span,
+                ref callee,
+                ref args,
+                ..
+            }) if ctx.is_pure_call(span, callee) => args
+                .iter()
+                .any(|arg| arg.spread.is_some() || arg.expr.may_have_side_effects_with(ctx)),
+            Expr::New(NewExpr { span, ref args, .. }) if ctx.is_pure_annotated(span) => args
+                .iter()
+                .flatten()
+                .any(|arg| arg.spread.is_some() || arg.expr.may_have_side_effects_with(ctx)),
+
             Expr::Lit(..)
             | Expr::Ident(..)
             | Expr::This(..)
             | Expr::PrivateName(..)
+            | Expr::ArgPlaceholder(..)
             | Expr::TsConstAssertion(..) => false,
 
-            Expr::Paren(ref e) => e.expr.may_have_side_effects(),
+            Expr::Paren(ref e) => e.expr.may_have_side_effects_with(ctx),
 
             // Function expression does not have any side effect if it's not used.
             Expr::Fn(..) | Expr::Arrow(ArrowExpr { .. }) => false,
@@ -883,13 +947,13 @@ pub trait ExprExt {
             Expr::Array(ArrayLit { ref elems, .. }) => elems
                 .iter()
                 .filter_map(|e| e.as_ref())
-                .any(|e| e.expr.may_have_side_effects()),
-            Expr::Unary(UnaryExpr { ref arg, .. }) => arg.may_have_side_effects(),
+                .any(|e| e.expr.may_have_side_effects_with(ctx)),
+            Expr::Unary(UnaryExpr { ref arg, .. }) => arg.may_have_side_effects_with(ctx),
             Expr::Bin(BinExpr {
                 ref left,
                 ref right,
                 ..
-            }) => left.may_have_side_effects() || right.may_have_side_effects(),
+            }) => left.may_have_side_effects_with(ctx) || right.may_have_side_effects_with(ctx),
 
             //TODO
             Expr::Tpl(_) => true,
@@ -900,7 +964,13 @@ pub trait ExprExt {
             | Expr::Yield(_)
             | Expr::Member(_)
             | Expr::Update(_)
-            | Expr::Assign(_) => true,
+            | Expr::Assign(_)
+            | Expr::Do(_)
+            | Expr::Bind(_)
+            | Expr::Throw(_) => true,
+
+            // Records and tuples throw if they contain objects.
+            Expr::Record(_) | Expr::Tuple(_) => true,
 
             // TODO
             Expr::New(_) => true,
@@ -911,7 +981,9 @@ pub trait ExprExt {
             }) if callee.is_pure_callee() => false,
             Expr::Call(_) => true,
 
-            Expr::Seq(SeqExpr { ref exprs, .. }) => exprs.iter().any(|e| e.may_have_side_effects()),
+            Expr::Seq(SeqExpr { ref exprs, .. }) => {
+                exprs.iter().any(|e| e.may_have_side_effects_with(ctx))
+            }
 
             Expr::Cond(CondExpr {
                 ref test,
@@ -919,9 +991,9 @@ pub trait ExprExt {
                 ref alt,
                 ..
             }) => {
-                test.may_have_side_effects()
-                    || cons.may_have_side_effects()
-                    || alt.may_have_side_effects()
+                test.may_have_side_effects_with(ctx)
+                    || cons.may_have_side_effects_with(ctx)
+                    || alt.may_have_side_effects_with(ctx)
             }
 
             Expr::Object(ObjectLit { ref props, .. }) => props.iter().any(|node| match node {
@@ -929,15 +1001,17 @@ pub trait ExprExt {
                     Prop::Shorthand(..) => false,
                     Prop::KeyValue(KeyValueProp { ref key, ref value }) => {
                         let k = match *key {
-                            PropName::Computed(ref e) => e.expr.may_have_side_effects(),
+                            PropName::Computed(ref e) => e.expr.may_have_side_effects_with(ctx),
                             _ => false,
                         };
 
-                        k || value.may_have_side_effects()
+                        k || value.may_have_side_effects_with(ctx)
                     }
                     _ => true,
                 },
-                PropOrSpread::Spread(SpreadElement { expr, .. }) => expr.may_have_side_effects(),
+                PropOrSpread::Spread(SpreadElement { expr, .. }) => {
+                    expr.may_have_side_effects_with(ctx)
+                }
             }),
 
             Expr::JSXMember(..)
@@ -949,8 +1023,10 @@ pub trait ExprExt {
             Expr::TsAs(TsAsExpr { ref expr, .. })
             | Expr::TsNonNull(TsNonNullExpr { ref expr, .. })
             | Expr::TsTypeAssertion(TsTypeAssertion { ref expr, .. })
-            | Expr::TsTypeCast(TsTypeCastExpr { ref expr, .. }) => expr.may_have_side_effects(),
-            Expr::OptChain(ref e) => e.expr.may_have_side_effects(),
+            | Expr::TsTypeCast(TsTypeCastExpr { ref expr, .. }) => {
+                expr.may_have_side_effects_with(ctx)
+            }
+            Expr::OptChain(ref e) => e.expr.may_have_side_effects_with(ctx),
 
             Expr::Invalid(..) => unreachable!(),
         }
@@ -1363,6 +1439,7 @@ pub fn prop_name_to_expr_value(p: PropName) -> Expr {
             span: i.span,
             value: i.sym,
             has_escape: false,
+            raw: None,
         })),
         PropName::Str(s) => Expr::Lit(Lit::Str(s)),
         PropName::Num(n) => Expr::Lit(Lit::Num(n)),
@@ -1432,11 +1509,41 @@ pub fn undefined(span: Span) -> Box<Expr> {
     Expr::Unary(UnaryExpr {
         span,
         op: op!("void"),
-        arg: Expr::Lit(Lit::Num(Number { value: 0.0, span })).into(),
+        arg: Expr::Lit(Lit::Num(Number {
+            value: 0.0,
+            span,
+            raw: None,
+        }))
+        .into(),
     })
     .into()
 }
 
+/// Returns the span which covers all spans of `nodes`, ignoring dummy spans.
+///
+/// Nodes synthesized from `nodes` should use this span instead of
+/// [DUMMY_SP], so that source maps point to the original code.
+pub fn span_of_all<'a, T, I>(nodes: I) -> Option<Span>
+where
+    T: 'a + Spanned,
+    I: IntoIterator<Item = &'a T>,
+{
+    nodes
+        .into_iter()
+        .map(|n| n.span())
+        .filter(|span| !span.is_dummy())
+        .fold(None, |acc: Option<Span>, span| {
+            Some(match acc {
+                Some(acc) => Span::new(
+                    acc.lo().min(span.lo()),
+                    acc.hi().max(span.hi()),
+                    Default::default(),
+                ),
+                None => span.with_ctxt(Default::default()),
+            })
+        })
+}
+
 /// inject `branch` after directives
 #[inline(never)]
 pub fn prepend<T: StmtLike>(stmts: &mut Vec<T>, stmt: T) {
@@ -1495,19 +1602,27 @@ pub fn prepend_stmts<T: StmtLike>(
 
 pub trait IsDirective {
     fn as_ref(&self) -> Option<&Stmt>;
-    fn is_use_strict(&self) -> bool {
+
+    /// Returns the value of the directive if `self` is a string literal
+    /// statement, like `'use strict';`.
+    fn as_directive(&self) -> Option<&JsWord> {
         match self.as_ref() {
             Some(&Stmt::Expr(ref expr)) => match *expr.expr {
                 Expr::Lit(Lit::Str(Str {
                     ref value,
                     has_escape: false,
                     ..
-                })) => value == "use strict",
-                _ => false,
+                })) => Some(value),
+                _ => None,
             },
-            _ => false,
+            _ => None,
         }
     }
+
+    fn is_use_strict(&self) -> bool {
+        self.as_directive()
+            .map_or(false, |value| value == "use strict")
+    }
 }
 
 impl IsDirective for Stmt {
@@ -1516,6 +1631,29 @@ impl IsDirective for Stmt {
     }
 }
 
+impl IsDirective for ModuleItem {
+    fn as_ref(&self) -> Option<&Stmt> {
+        match *self {
+            ModuleItem::Stmt(ref s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the directives at the start of `stmts`.
+pub fn directive_prologue<T: IsDirective>(stmts: &[T]) -> impl Iterator<Item = &JsWord> {
+    stmts
+        .iter()
+        .map(|stmt| stmt.as_directive())
+        .take_while(Option::is_some)
+        .flatten()
+}
+
+/// Returns true if the directive prologue of `stmts` contains `'use strict'`.
+pub fn has_use_strict<T: IsDirective>(stmts: &[T]) -> bool {
+    directive_prologue(stmts).any(|value| value == "use strict")
+}
+
 pub trait IdentExt {
     fn prefix(&self, prefix: &str) -> Ident;
 
@@ -1585,10 +1723,16 @@ where
     t
 }
 
+/// Drops spans, and the raw source text of literals.
 pub struct DropSpan {
     pub preserve_ctxt: bool,
 }
 impl VisitMut for DropSpan {
+    fn visit_mut_number(&mut self, n: &mut Number) {
+        n.visit_mut_children_with(self);
+        n.raw = None;
+    }
+
     fn visit_mut_span(&mut self, span: &mut Span) {
         *span = if self.preserve_ctxt {
             DUMMY_SP.with_ctxt(span.ctxt())
@@ -1596,6 +1740,30 @@ impl VisitMut for DropSpan {
             DUMMY_SP
         };
     }
+
+    fn visit_mut_str(&mut self, s: &mut Str) {
+        s.visit_mut_children_with(self);
+        s.raw = None;
+    }
+}
+
+/// Moves spans of code parsed from a file starting at `BytePos(0)` by
+/// `start_pos`, the start of a copy of the file in another
+/// [SourceMap](swc_common::SourceMap).
+pub struct MoveSpans {
+    pub start_pos: BytePos,
+}
+
+impl VisitMut for MoveSpans {
+    fn visit_mut_span(&mut self, span: &mut Span) {
+        if !span.is_dummy() {
+            *span = Span::new(
+                span.lo() + self.start_pos,
+                span.hi() + self.start_pos,
+                span.ctxt(),
+            );
+        }
+    }
 }
 
 /// Finds usage of `ident`
@@ -1639,6 +1807,48 @@ impl<'a> UsageFinder<'a> {
 // Used for error reporting in transform.
 scoped_thread_local!(pub static HANDLER: Handler);
 
+// Used to configure how warnings are reported. See [warn].
+scoped_thread_local!(pub static WARNINGS: WarningsMode);
+
+/// How recoverable problems reported with [warn] are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WarningsMode {
+    /// Reports warnings as errors.
+    Error,
+    Warn,
+    Ignore,
+}
+
+impl Default for WarningsMode {
+    fn default() -> Self {
+        WarningsMode::Warn
+    }
+}
+
+/// Reports a problem which doesn't prevent the transform, like usage of
+/// deprecated syntax, with [HANDLER].
+///
+/// It's reported as a warning unless [WARNINGS] is set to another mode, and
+/// ignored if [HANDLER] is not set.
+pub fn warn(span: Span, msg: &str) {
+    if !HANDLER.is_set() {
+        return;
+    }
+
+    let mode = if WARNINGS.is_set() {
+        WARNINGS.with(|mode| *mode)
+    } else {
+        WarningsMode::Warn
+    };
+
+    match mode {
+        WarningsMode::Error => HANDLER.with(|handler| handler.struct_span_err(span, msg).emit()),
+        WarningsMode::Warn => HANDLER.with(|handler| handler.struct_span_warn(span, msg).emit()),
+        WarningsMode::Ignore => {}
+    }
+}
+
 /// make a new expression which evaluates `val` preserving side effects, if any.
 pub fn preserve_effects<I>(span: Span, val: Expr, exprs: I) -> Expr
 where
@@ -1655,12 +1865,19 @@ where
             | Expr::Fn(..)
             | Expr::Arrow(..)
             | Expr::Ident(..)
-            | Expr::PrivateName(..) => {}
+            | Expr::PrivateName(..)
+            | Expr::ArgPlaceholder(..) => {}
 
             // In most case, we can do nothing for this.
-            Expr::Update(_) | Expr::Assign(_) | Expr::Yield(_) | Expr::Await(_) => {
-                v.push(Box::new(expr))
-            }
+            Expr::Update(_)
+            | Expr::Assign(_)
+            | Expr::Yield(_)
+            | Expr::Await(_)
+            | Expr::Do(_)
+            | Expr::Bind(_)
+            | Expr::Throw(_)
+            | Expr::Record(_)
+            | Expr::Tuple(_) => v.push(Box::new(expr)),
 
             // TODO
             Expr::MetaProp(_) => v.push(Box::new(expr)),
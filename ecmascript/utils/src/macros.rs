@@ -1,14 +1,12 @@
-/// Shortcut for `quote_ident!(span.apply_mark(Mark::fresh(Mark::root())), s)`
+/// Creates an identifier which can't collide with user code. See
+/// [swc_ecma_ast::Ident::new_private].
 #[macro_export]
 macro_rules! private_ident {
     ($s:expr) => {
         private_ident!(::swc_common::DUMMY_SP, $s)
     };
     ($span:expr, $s:expr) => {{
-        use swc_common::Mark;
-        let mark = Mark::fresh(Mark::root());
-        let span = $span.apply_mark(mark);
-        ::swc_ecma_ast::Ident::new($s.into(), span)
+        ::swc_ecma_ast::Ident::new_private($s.into(), $span)
     }};
 }
 
@@ -32,6 +30,7 @@ macro_rules! quote_str {
             span: $span,
             value: $s.into(),
             has_escape: false,
+            raw: None,
         }
     }};
 }
@@ -73,7 +72,7 @@ macro_rules! member_expr {
         let prop = member_expr!($span, $first);
 
         member_expr!(@EXT, $span, Box::new(Expr::Member(MemberExpr{
-            span: ::swc_common::DUMMY_SP,
+            span: $span,
             obj: ExprOrSuper::Expr($obj),
             computed: false,
             prop,
@@ -85,7 +84,7 @@ macro_rules! member_expr {
         let prop = member_expr!($span, $first);
 
         Box::new(Expr::Member(MemberExpr{
-            span: ::swc_common::DUMMY_SP,
+            span: $span,
             obj: ExprOrSuper::Expr($obj),
             computed: false,
             prop,
@@ -0,0 +1,265 @@
+use crate::{ident::IdentLike, ExprExt, Id};
+use std::fmt::{self, Debug, Formatter};
+use swc_common::{comments::Comments, BytePos, Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitWith};
+
+/// Context of side effect analysis.
+#[derive(Clone, Copy, Default)]
+pub struct SideEffectCtx<'a> {
+    /// Used to find calls annotated with `/*#__PURE__*/`.
+    pub comments: Option<&'a dyn Comments>,
+
+    /// Functions annotated with `/*#__NO_SIDE_EFFECTS__*/`, which can be
+    /// collected with [find_no_side_effect_fns].
+    pub no_side_effect_fns: &'a [Id],
+}
+
+impl Debug for SideEffectCtx<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SideEffectCtx")
+            .field("comments", &self.comments.is_some())
+            .field("no_side_effect_fns", &self.no_side_effect_fns)
+            .finish()
+    }
+}
+
+impl SideEffectCtx<'_> {
+    /// Returns true if the call or new expression at `span` is annotated with
+    /// `/*#__PURE__*/`.
+    pub fn is_pure_annotated(&self, span: Span) -> bool {
+        match self.comments {
+            Some(comments) if !span.is_dummy() => comments.has_flag(span.lo, "PURE"),
+            _ => false,
+        }
+    }
+
+    /// Returns true if the call at `span` is annotated with `/*#__PURE__*/`,
+    /// or `callee` is a function annotated with `/*#__NO_SIDE_EFFECTS__*/`.
+    pub fn is_pure_call(&self, span: Span, callee: &ExprOrSuper) -> bool {
+        if self.is_pure_annotated(span) {
+            return true;
+        }
+
+        match callee {
+            ExprOrSuper::Expr(callee) => match &**callee {
+                Expr::Ident(i) => {
+                    !self.no_side_effect_fns.is_empty()
+                        && self.no_side_effect_fns.contains(&i.to_id())
+                }
+                _ => false,
+            },
+            ExprOrSuper::Super(..) => false,
+        }
+    }
+}
+
+/// Finds functions annotated with `/*#__NO_SIDE_EFFECTS__*/`, calls to which
+/// can be removed if their results are unused.
+///
+/// The annotation can precede a function declaration, or a `const`
+/// declaration (or its initializer) of a function or an arrow function.
+///
+/// ```js
+/// /*#__NO_SIDE_EFFECTS__*/ function foo() {}
+/// /*#__NO_SIDE_EFFECTS__*/ export const bar = () => {};
+/// const baz = /*#__NO_SIDE_EFFECTS__*/ function () {};
+/// ```
+pub fn find_no_side_effect_fns<N>(node: &N, comments: &dyn Comments) -> Vec<Id>
+where
+    N: for<'a> VisitWith<NoSideEffectFnFinder<'a>>,
+{
+    let mut v = NoSideEffectFnFinder {
+        comments,
+        fns: vec![],
+    };
+    node.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+    v.fns
+}
+
+pub struct NoSideEffectFnFinder<'a> {
+    comments: &'a dyn Comments,
+    fns: Vec<Id>,
+}
+
+impl NoSideEffectFnFinder<'_> {
+    fn is_annotated(&self, pos: BytePos) -> bool {
+        pos != BytePos(0) && self.comments.has_flag(pos, "NO_SIDE_EFFECTS")
+    }
+
+    /// `annotated` is true if the annotation precedes the declaration.
+    fn add_var_decl(&mut self, var: &VarDecl, annotated: bool) {
+        // Reassigned bindings may hold anything.
+        if var.kind != VarDeclKind::Const {
+            return;
+        }
+
+        for decl in &var.decls {
+            let id = match &decl.name {
+                Pat::Ident(i) => i,
+                _ => continue,
+            };
+            let init_annotated = match decl.init.as_deref() {
+                Some(Expr::Fn(FnExpr { function, .. })) => self.is_annotated(function.span.lo),
+                Some(Expr::Arrow(arrow)) => self.is_annotated(arrow.span.lo),
+                _ => continue,
+            };
+
+            if annotated || init_annotated {
+                self.fns.push(id.to_id());
+            }
+        }
+    }
+}
+
+impl Visit for NoSideEffectFnFinder<'_> {
+    noop_visit_type!();
+
+    fn visit_export_decl(&mut self, export: &ExportDecl, _: &dyn Node) {
+        let annotated = self.is_annotated(export.span.lo);
+
+        match &export.decl {
+            Decl::Fn(f) if annotated => self.fns.push(f.ident.to_id()),
+            Decl::Var(var) => {
+                let annotated = annotated || self.is_annotated(var.span.lo);
+                self.add_var_decl(var, annotated);
+                var.visit_children_with(self);
+                return;
+            }
+            _ => {}
+        }
+
+        export.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, f: &FnDecl, _: &dyn Node) {
+        if self.is_annotated(f.function.span.lo) {
+            self.fns.push(f.ident.to_id());
+        }
+
+        f.visit_children_with(self);
+    }
+
+    fn visit_var_decl(&mut self, var: &VarDecl, _: &dyn Node) {
+        let annotated = self.is_annotated(var.span.lo);
+        self.add_var_decl(var, annotated);
+
+        var.visit_children_with(self);
+    }
+}
+
+/// Returns true if evaluating `items` may have side effects other than
+/// declaring bindings.
+///
+/// Imported modules are not checked.
+pub fn has_side_effects(items: &[ModuleItem], ctx: SideEffectCtx) -> bool {
+    items.iter().any(|item| match item {
+        ModuleItem::ModuleDecl(decl) => module_decl_has_side_effects(decl, ctx),
+        ModuleItem::Stmt(stmt) => stmt_has_side_effects(stmt, ctx),
+    })
+}
+
+fn module_decl_has_side_effects(decl: &ModuleDecl, ctx: SideEffectCtx) -> bool {
+    match decl {
+        ModuleDecl::Import(..)
+        | ModuleDecl::ExportNamed(..)
+        | ModuleDecl::ExportAll(..)
+        | ModuleDecl::TsImportEquals(..)
+        | ModuleDecl::TsNamespaceExport(..) => false,
+
+        ModuleDecl::ExportDecl(ExportDecl { decl, .. }) => decl_has_side_effects(decl, ctx),
+        ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { decl, .. }) => match decl {
+            DefaultDecl::Class(ClassExpr { class, .. }) => class_has_side_effects(class, ctx),
+            DefaultDecl::Fn(..) | DefaultDecl::TsInterfaceDecl(..) => false,
+        },
+        ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { expr, .. })
+        | ModuleDecl::TsExportAssignment(TsExportAssignment { expr, .. }) => {
+            expr.may_have_side_effects_with(ctx)
+        }
+    }
+}
+
+fn stmt_has_side_effects(stmt: &Stmt, ctx: SideEffectCtx) -> bool {
+    match stmt {
+        Stmt::Empty(..) => false,
+        Stmt::Block(BlockStmt { stmts, .. }) => {
+            stmts.iter().any(|stmt| stmt_has_side_effects(stmt, ctx))
+        }
+        Stmt::Decl(decl) => decl_has_side_effects(decl, ctx),
+        Stmt::Expr(ExprStmt { expr, .. }) => expr.may_have_side_effects_with(ctx),
+        _ => true,
+    }
+}
+
+fn decl_has_side_effects(decl: &Decl, ctx: SideEffectCtx) -> bool {
+    match decl {
+        Decl::Fn(..) | Decl::TsInterface(..) | Decl::TsTypeAlias(..) => false,
+        Decl::Class(ClassDecl { class, .. }) => class_has_side_effects(class, ctx),
+        Decl::Var(VarDecl { decls, .. }) => decls.iter().any(|decl| match &decl.init {
+            // Destructuring may invoke getters or iterators.
+            Some(init) => match decl.name {
+                Pat::Ident(..) => init.may_have_side_effects_with(ctx),
+                _ => true,
+            },
+            None => false,
+        }),
+        Decl::TsEnum(..) | Decl::TsModule(..) => true,
+    }
+}
+
+fn class_has_side_effects(class: &Class, ctx: SideEffectCtx) -> bool {
+    if !class.decorators.is_empty() {
+        return true;
+    }
+
+    if let Some(super_class) = &class.super_class {
+        if super_class.may_have_side_effects_with(ctx) {
+            return true;
+        }
+    }
+
+    class.body.iter().any(|member| match member {
+        ClassMember::Method(ClassMethod { key, function, .. }) => {
+            !function.decorators.is_empty() || prop_name_has_side_effects(key, ctx)
+        }
+        ClassMember::ClassProp(ClassProp {
+            key,
+            computed,
+            is_static,
+            value,
+            decorators,
+            ..
+        }) => {
+            !decorators.is_empty()
+                || (*computed && key.may_have_side_effects_with(ctx))
+                || (*is_static
+                    && value
+                        .as_ref()
+                        .map_or(false, |value| value.may_have_side_effects_with(ctx)))
+        }
+        ClassMember::PrivateProp(PrivateProp {
+            is_static,
+            value,
+            decorators,
+            ..
+        }) => {
+            !decorators.is_empty()
+                || (*is_static
+                    && value
+                        .as_ref()
+                        .map_or(false, |value| value.may_have_side_effects_with(ctx)))
+        }
+        ClassMember::StaticBlock(..) => true,
+        ClassMember::Constructor(..)
+        | ClassMember::PrivateMethod(..)
+        | ClassMember::TsIndexSignature(..)
+        | ClassMember::Empty(..) => false,
+    })
+}
+
+fn prop_name_has_side_effects(key: &PropName, ctx: SideEffectCtx) -> bool {
+    match key {
+        PropName::Computed(ComputedPropName { expr, .. }) => expr.may_have_side_effects_with(ctx),
+        _ => false,
+    }
+}
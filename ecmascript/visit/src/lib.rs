@@ -49,6 +49,22 @@ where
     }
 }
 
+impl<A, B> VisitMut for AndThen<A, B>
+where
+    A: VisitMut,
+    B: VisitMut,
+{
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        self.first.visit_mut_module(n);
+        self.second.visit_mut_module(n);
+    }
+
+    fn visit_mut_script(&mut self, n: &mut Script) {
+        self.first.visit_mut_script(n);
+        self.second.visit_mut_script(n);
+    }
+}
+
 impl<V> Fold for Repeat<V>
 where
     V: Fold + Repeated,
@@ -437,6 +453,12 @@ define!({
         PrivateProp(PrivateProp),
         TsIndexSignature(TsIndexSignature),
         Empty(EmptyStmt),
+        StaticBlock(StaticBlock),
+    }
+
+    pub struct StaticBlock {
+        pub span: Span,
+        pub body: BlockStmt,
     }
 
     pub struct ClassProp {
@@ -577,6 +599,12 @@ define!({
         TsAs(TsAsExpr),
         PrivateName(PrivateName),
         OptChain(OptChainExpr),
+        ArgPlaceholder(ArgPlaceholder),
+        Do(DoExpr),
+        Bind(BindExpr),
+        Throw(ThrowExpr),
+        Record(RecordLit),
+        Tuple(TupleLit),
         Invalid(Invalid),
     }
     pub struct ThisExpr {
@@ -590,6 +618,14 @@ define!({
         pub span: Span,
         pub props: Vec<PropOrSpread>,
     }
+    pub struct RecordLit {
+        pub span: Span,
+        pub props: Vec<PropOrSpread>,
+    }
+    pub struct TupleLit {
+        pub span: Span,
+        pub elems: Vec<ExprOrSpread>,
+    }
     pub enum PropOrSpread {
         Spread(SpreadElement),
         Prop(Box<Prop>),
@@ -708,6 +744,22 @@ define!({
     pub struct Super {
         pub span: Span,
     }
+    pub struct ArgPlaceholder {
+        pub span: Span,
+    }
+    pub struct DoExpr {
+        pub span: Span,
+        pub body: BlockStmt,
+    }
+    pub struct BindExpr {
+        pub span: Span,
+        pub obj: Option<Box<Expr>>,
+        pub callee: Box<Expr>,
+    }
+    pub struct ThrowExpr {
+        pub span: Span,
+        pub arg: Box<Expr>,
+    }
     pub struct ExprOrSpread {
         pub spread: Option<Span>,
         pub expr: Box<Expr>,
@@ -867,6 +919,7 @@ define!({
         pub span: Span,
         pub value: JsWord,
         pub has_escape: bool,
+        pub raw: Option<JsWord>,
     }
     pub struct Bool {
         pub span: Span,
@@ -883,6 +936,7 @@ define!({
     pub struct Number {
         pub span: Span,
         pub value: f64,
+        pub raw: Option<JsWord>,
     }
     pub enum Program {
         Module(Module),
@@ -1008,6 +1062,7 @@ define!({
         InstanceOf,
         Exp,
         NullishCoalescing,
+        Pipeline,
     }
     pub enum AssignOp {
         Assign,
@@ -0,0 +1,51 @@
+use swc_common::{chain, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, FoldWith, VisitMut, VisitMutWith};
+
+/// Appends `suffix` to all identifiers.
+struct Suffix(&'static str);
+
+impl VisitMut for Suffix {
+    fn visit_mut_ident(&mut self, i: &mut Ident) {
+        i.sym = format!("{}{}", i.sym, self.0).into();
+    }
+}
+
+fn module() -> Module {
+    Module {
+        span: DUMMY_SP,
+        body: vec![ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Ident(Ident::new("a".into(), DUMMY_SP))),
+        }))],
+        shebang: None,
+    }
+}
+
+fn ident(m: &Module) -> &str {
+    match &m.body[0] {
+        ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match &**expr {
+            Expr::Ident(i) => &i.sym,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn visit_mut_in_order() {
+    let mut m = module();
+    m.visit_mut_with(&mut chain!(Suffix("b"), Suffix("c"), Suffix("d")));
+
+    assert_eq!(ident(&m), "abcd");
+}
+
+#[test]
+fn visit_mut_as_folder() {
+    let m = module().fold_with(&mut chain!(
+        as_folder(Suffix("b")),
+        as_folder(chain!(Suffix("c"), Suffix("d"))),
+    ));
+
+    assert_eq!(ident(&m), "abcd");
+}
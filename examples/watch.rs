@@ -0,0 +1,42 @@
+//! Compiles files given as arguments, and compiles them again whenever they
+//! are modified.
+//!
+//! ```sh
+//! cargo run --example watch -- src/a.js src/b.js .swcrc
+//! ```
+use std::{env, sync::Arc, time::Duration};
+use swc::{self, cache::Cache, config::Options, watch::Watcher};
+use swc_common::{
+    errors::{ColorConfig, Handler},
+    SourceMap,
+};
+
+fn main() {
+    let cm = Arc::<SourceMap>::default();
+    let handler = Arc::new(Handler::with_tty_emitter(
+        ColorConfig::Auto,
+        true,
+        false,
+        Some(cm.clone()),
+    ));
+    let c = swc::Compiler::new(cm, handler);
+
+    let mut watcher = Watcher::new(env::args_os().skip(1));
+    let cache = Cache::new();
+
+    c.watch(
+        &mut watcher,
+        &Options::default(),
+        &cache,
+        Duration::from_millis(100),
+        |outputs| {
+            for (path, output) in outputs {
+                match output {
+                    Ok(output) => println!("// {}\n{}", path.display(), output.code),
+                    Err(err) => eprintln!("failed to compile {}: {:?}", path.display(), err),
+                }
+            }
+            true
+        },
+    );
+}
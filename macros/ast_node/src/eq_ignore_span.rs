@@ -0,0 +1,98 @@
+use pmutil::{smart_quote, Quote};
+use swc_macros_common::prelude::*;
+use syn::*;
+
+pub fn derive(input: DeriveInput) -> ItemImpl {
+    let mut arms: Vec<Arm> = Binder::new_from(&input)
+        .variants()
+        .into_iter()
+        .map(|v| {
+            let (l_pat, l_bindings) = v.bind("_l_", Some(def_site()), None);
+            let (r_pat, r_bindings) = v.bind("_r_", Some(def_site()), None);
+
+            let body = l_bindings.iter().zip(r_bindings.iter()).fold(
+                Quote::new(def_site::<Span>())
+                    .quote_with(smart_quote!(Vars {}, { true }))
+                    .parse::<Expr>(),
+                |body, (l, r)| {
+                    let cmp = if has_use_eq_attr(&l.field().attrs) {
+                        Quote::new(def_site::<Span>())
+                            .quote_with(smart_quote!(Vars { l, r }, { *l == *r }))
+                            .parse::<Expr>()
+                    } else {
+                        Quote::new(def_site::<Span>())
+                            .quote_with(smart_quote!(Vars { l, r }, {
+                                swc_common::EqIgnoreSpan::eq_ignore_span(l, r)
+                            }))
+                            .parse::<Expr>()
+                    };
+
+                    Quote::new(def_site::<Span>())
+                        .quote_with(smart_quote!(Vars { body, cmp }, { body && cmp }))
+                        .parse()
+                },
+            );
+
+            Arm {
+                body: Box::new(body),
+                attrs: v
+                    .attrs()
+                    .iter()
+                    .filter(|attr| is_attr_name(attr, "cfg"))
+                    .cloned()
+                    .collect(),
+                pat: Quote::new(def_site::<Span>())
+                    .quote_with(smart_quote!(Vars { l_pat, r_pat }, { (l_pat, r_pat) }))
+                    .parse(),
+                guard: None,
+                fat_arrow_token: def_site(),
+                comma: Some(def_site()),
+            }
+        })
+        .collect();
+
+    // Different variants of an enum are never equal.
+    if let Data::Enum(ref data) = input.data {
+        if data.variants.len() > 1 {
+            arms.push(
+                Quote::new(def_site::<Span>())
+                    .quote_with(smart_quote!(Vars {}, { _ => false, }))
+                    .parse(),
+            );
+        }
+    }
+
+    let body = Expr::Match(ExprMatch {
+        attrs: Default::default(),
+        match_token: def_site(),
+        brace_token: def_site(),
+        expr: Box::new(
+            Quote::new(def_site::<Span>())
+                .quote_with(smart_quote!(Vars {}, { (self, other) }))
+                .parse(),
+        ),
+        arms,
+    });
+
+    Quote::new(def_site::<Span>())
+        .quote_with(smart_quote!(
+            Vars {
+                Type: &input.ident,
+                body,
+            },
+            {
+                impl swc_common::EqIgnoreSpan for Type {
+                    fn eq_ignore_span(&self, other: &Self) -> bool {
+                        body
+                    }
+                }
+            }
+        ))
+        .parse::<ItemImpl>()
+        .with_generics(input.generics)
+}
+
+/// Search for `#[use_eq]`
+fn has_use_eq_attr(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| is_attr_name(attr, "use_eq"))
+}
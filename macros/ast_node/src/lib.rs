@@ -9,6 +9,7 @@ use syn::{self, *};
 
 mod ast_node_macro;
 mod enum_deserialize;
+mod eq_ignore_span;
 mod spanned;
 
 #[proc_macro_derive(Spanned, attributes(span))]
@@ -25,6 +26,20 @@ pub fn derive_spanned(input: proc_macro::TokenStream) -> proc_macro::TokenStream
     )
 }
 
+#[proc_macro_derive(EqIgnoreSpan, attributes(use_eq))]
+pub fn derive_eq_ignore_span(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse::<DeriveInput>(input).expect("failed to parse input as DeriveInput");
+    let name = input.ident.clone();
+
+    let item = self::eq_ignore_span::derive(input);
+
+    print_item(
+        "derive(EqIgnoreSpan)",
+        &format!("IMPL_EQ_IGNORE_SPAN_FOR_{}", name),
+        item.dump(),
+    )
+}
+
 #[proc_macro_derive(DeserializeEnum, attributes(tag))]
 pub fn derive_deserialize_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse::<DeriveInput>(input).expect("failed to parse input as DeriveInput");
@@ -41,9 +56,9 @@ pub fn derive_deserialize_enum(input: proc_macro::TokenStream) -> proc_macro::To
 }
 
 /// Alias for
-/// `#[derive(Spanned, Fold, Clone, Debug, PartialEq)]` for a struct and
-/// `#[derive(Spanned, Fold, Clone, Debug, PartialEq, FromVariant)]` for an
-/// enum.
+/// `#[derive(Spanned, EqIgnoreSpan, Clone, Debug, PartialEq)]` for a struct
+/// and `#[derive(Spanned, EqIgnoreSpan, Clone, Debug, PartialEq,
+/// FromVariant)]` for an enum.
 #[proc_macro_attribute]
 pub fn ast_node(
     args: proc_macro::TokenStream,
@@ -63,6 +78,7 @@ pub fn ast_node(
                 #[derive(
                     ::swc_common::FromVariant,
                     ::swc_common::Spanned,
+                    ::swc_common::EqIgnoreSpan,
                     Clone,
                     Debug,
                     PartialEq,
@@ -109,7 +125,7 @@ pub fn ast_node(
 
             let mut quote =
                 item.quote_with(smart_quote!(Vars { input, serde_tag, serde_rename }, {
-                    #[derive(::swc_common::Spanned, Clone, Debug, PartialEq)]
+                    #[derive(::swc_common::Spanned, ::swc_common::EqIgnoreSpan, Clone, Debug, PartialEq)]
                     #[derive(::serde::Serialize, ::serde::Deserialize)]
                     serde_tag
                     #[serde(rename_all = "camelCase")]
@@ -2,7 +2,7 @@ use crate::{
     get_compiler,
     util::{CtxtExt, MapErr},
 };
-use anyhow::{bail, Error};
+use anyhow::Error;
 use fxhash::FxHashMap;
 use napi::{CallContext, Env, JsObject, Status, Task};
 use serde::Deserialize;
@@ -88,6 +88,14 @@ impl Task for BundleTask {
                         "zlib",
                     ]
                     .into_iter()
+                    .chain(
+                        self.config
+                            .static_items
+                            .config
+                            .external
+                            .iter()
+                            .map(|s| &**s),
+                    )
                     .map(From::from)
                     .collect(),
                     ..Default::default()
@@ -105,7 +113,7 @@ impl Task for BundleTask {
                     BundleKind::Named { name } | BundleKind::Lib { name } => {
                         Ok((name, bundle.module))
                     }
-                    BundleKind::Dynamic => bail!("unimplemented: dynamic code splitting"),
+                    BundleKind::Dynamic => Ok((format!("dynamic.{}", bundle.id), bundle.module)),
                 })
                 .map(|res| {
                     res.and_then(|(k, m)| {
@@ -125,9 +133,13 @@ impl Task for BundleTask {
                             })
                             .unwrap_or(false);
 
-                        let output =
-                            self.swc
-                                .print(&m, SourceMapsConfig::Bool(true), None, minify)?;
+                        let output = self.swc.print(
+                            &m,
+                            SourceMapsConfig::Bool(true),
+                            &Default::default(),
+                            None,
+                            minify,
+                        )?;
 
                         Ok((k, output))
                     })
@@ -15,11 +15,12 @@ static ALLOC: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
 use backtrace::Backtrace;
 use napi::{CallContext, Env, JsFunction, JsObject, JsUndefined, Module};
+use serde::Serialize;
 use std::{env, panic::set_hook, sync::Arc};
 use swc::{Compiler, TransformOutput};
 use swc_common::{
     self,
-    errors::{ColorConfig, Handler},
+    errors::{BufferedEmitter, ColorConfig, Diagnostic, EmitterWriter, Handler, Level, TeeEmitter},
     sync::Lazy,
     FilePathMapping, SourceMap,
 };
@@ -80,6 +81,30 @@ fn get_compiler(_ctx: &CallContext) -> Arc<Compiler> {
     COMPILER.clone()
 }
 
+/// Creates a compiler for a single call, which shares the source map of
+/// [COMPILER].
+///
+/// Warnings and errors are collected by the returned emitter, so they can be
+/// returned to js. Errors are also printed to stderr.
+fn new_compiler() -> (Arc<Compiler>, BufferedEmitter) {
+    let cm = COMPILER.cm.clone();
+    let diagnostics = BufferedEmitter::default();
+    let emitter = TeeEmitter::new()
+        .with_emitter(Box::new(diagnostics.clone()), Level::Warning)
+        .with_emitter(
+            Box::new(EmitterWriter::stderr(
+                ColorConfig::Always,
+                Some(cm.clone()),
+                false,
+                false,
+            )),
+            Level::Error,
+        );
+    let handler = Handler::with_emitter(true, false, Box::new(emitter));
+
+    (Arc::new(Compiler::new(cm, Arc::new(handler))), diagnostics)
+}
+
 #[js_function]
 fn define_compiler_class(ctx: CallContext) -> napi::Result<JsFunction> {
     ctx.env
@@ -96,4 +121,66 @@ pub fn complete_output(env: &Env, output: TransformOutput) -> napi::Result<JsObj
     env.to_js_value(&output)?.coerce_to_object()
 }
 
+/// [TransformOutput] with the diagnostics emitted while transforming.
+#[derive(Serialize)]
+struct OutputWithDiagnostics {
+    #[serde(flatten)]
+    output: TransformOutput,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    diagnostics: Vec<JsDiagnostic>,
+}
+
+#[derive(Serialize)]
+struct JsDiagnostic {
+    level: &'static str,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file: Option<String>,
+    /// 1-based
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    /// 0-based
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+}
+
+impl JsDiagnostic {
+    fn new(cm: &SourceMap, d: &Diagnostic) -> Self {
+        let loc = d
+            .span
+            .primary_span()
+            .filter(|span| !span.is_dummy())
+            .map(|span| cm.lookup_char_pos(span.lo()));
+
+        JsDiagnostic {
+            level: d.level.to_str(),
+            message: d.message(),
+            file: loc.as_ref().map(|loc| loc.file.name.to_string()),
+            line: loc.as_ref().map(|loc| loc.line),
+            column: loc.as_ref().map(|loc| loc.col_display),
+        }
+    }
+}
+
+/// Like [complete_output], but also returns warnings and errors collected by
+/// `diagnostics`.
+pub fn complete_output_with_diagnostics(
+    env: &Env,
+    cm: &SourceMap,
+    output: TransformOutput,
+    diagnostics: &BufferedEmitter,
+) -> napi::Result<JsObject> {
+    let diagnostics = diagnostics
+        .take()
+        .iter()
+        .map(|d| JsDiagnostic::new(cm, d))
+        .collect();
+
+    env.to_js_value(&OutputWithDiagnostics {
+        output,
+        diagnostics,
+    })?
+    .coerce_to_object()
+}
+
 pub type ArcCompiler = Arc<Compiler>;
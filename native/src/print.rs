@@ -30,6 +30,7 @@ impl Task for PrintTask {
                     .source_maps
                     .clone()
                     .unwrap_or(SourceMapsConfig::Bool(false)),
+                &self.options.source_map_options(),
                 None,
                 self.options
                     .config
@@ -79,6 +80,7 @@ pub fn print_sync(cx: CallContext) -> napi::Result<JsObject> {
                 .source_maps
                 .clone()
                 .unwrap_or(SourceMapsConfig::Bool(false)),
+            &options.source_map_options(),
             None,
             options.config.unwrap_or_default().minify.unwrap_or(false),
         )
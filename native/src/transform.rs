@@ -1,5 +1,5 @@
 use crate::{
-    complete_output, get_compiler,
+    complete_output_with_diagnostics, new_compiler,
     util::{CtxtExt, MapErr},
 };
 use anyhow::{Context as _, Error};
@@ -10,7 +10,7 @@ use std::{
     sync::Arc,
 };
 use swc::{config::Options, Compiler, TransformOutput};
-use swc_common::{FileName, SourceFile};
+use swc_common::{errors::BufferedEmitter, FileName, SourceFile};
 use swc_ecma_ast::Program;
 
 /// Input to transform
@@ -28,6 +28,8 @@ pub struct TransformTask {
     pub c: Arc<Compiler>,
     pub input: Input,
     pub options: Options,
+    /// Warnings and errors emitted by `c`.
+    pub diagnostics: BufferedEmitter,
 }
 
 impl Task for TransformTask {
@@ -55,22 +57,28 @@ impl Task for TransformTask {
     }
 
     fn resolve(&self, env: &mut Env, result: Self::Output) -> napi::Result<Self::JsValue> {
-        complete_output(env, result)
+        complete_output_with_diagnostics(env, &self.c.cm, result, &self.diagnostics)
     }
 }
 
 /// returns `compiler, (src / path), options, plugin, callback`
 pub fn schedule_transform<F>(cx: CallContext, op: F) -> napi::Result<JsObject>
 where
-    F: FnOnce(&Arc<Compiler>, String, bool, Options) -> TransformTask,
+    F: FnOnce(&Compiler, String, bool, &Options) -> Input,
 {
-    let c = get_compiler(&cx);
+    let (c, diagnostics) = new_compiler();
 
     let s = cx.get::<JsString>(0)?.as_str()?.to_string();
     let is_module = cx.get::<JsBoolean>(1)?;
     let options: Options = cx.get_deserialized(2)?;
 
-    let task = op(&c, s, is_module.get_value()?, options);
+    let input = op(&c, s, is_module.get_value()?, &options);
+    let task = TransformTask {
+        c,
+        input,
+        options,
+        diagnostics,
+    };
 
     cx.env.spawn(task)
 }
@@ -79,7 +87,7 @@ pub fn exec_transform<F>(cx: CallContext, op: F) -> napi::Result<JsObject>
 where
     F: FnOnce(&Compiler, String, &Options) -> Result<Arc<SourceFile>, Error>,
 {
-    let c = get_compiler(&cx);
+    let (c, diagnostics) = new_compiler();
 
     let s = cx.get::<JsString>(0)?;
     let is_module = cx.get::<JsBoolean>(1)?;
@@ -96,13 +104,13 @@ where
         }
     })?;
 
-    complete_output(cx.env, output)
+    complete_output_with_diagnostics(cx.env, &c.cm, output, &diagnostics)
 }
 
 #[js_function(4)]
 pub fn transform(cx: CallContext) -> napi::Result<JsObject> {
     schedule_transform(cx, |c, src, is_module, options| {
-        let input = if is_module {
+        if is_module {
             Input::Program(src)
         } else {
             Input::Source(c.cm.new_source_file(
@@ -113,12 +121,6 @@ pub fn transform(cx: CallContext) -> napi::Result<JsObject> {
                 },
                 src,
             ))
-        };
-
-        TransformTask {
-            c: c.clone(),
-            input,
-            options,
         }
     })
 }
@@ -139,15 +141,7 @@ pub fn transform_sync(cx: CallContext) -> napi::Result<JsObject> {
 
 #[js_function(4)]
 pub fn transform_file(cx: CallContext) -> napi::Result<JsObject> {
-    schedule_transform(cx, |c, path, _, options| {
-        let path = clean(&path);
-
-        TransformTask {
-            c: c.clone(),
-            input: Input::File(path.into()),
-            options,
-        }
-    })
+    schedule_transform(cx, |_, path, _, _| Input::File(clean(&path).into()))
 }
 
 #[js_function(4)]
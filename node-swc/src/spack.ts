@@ -68,6 +68,12 @@ export interface SpackConfig {
     module: ModuleConfig
 
     options?: Options
+
+    /**
+     * Modules which are not bundled, like node builtins or peer dependencies.
+     * Imports of them are preserved.
+     */
+    external?: string[]
 }
 
 export interface OutputConfig {
@@ -187,6 +187,13 @@ export interface Options extends Config {
    */
   sourceRoot?: string;
 
+  /**
+   * Embeds the source code in the source map as `sourcesContent`.
+   *
+   * Defaults to `true`.
+   */
+  inlineSourcesContent?: boolean;
+
   plugin?: Plugin;
 
   isModule?: boolean;
@@ -253,13 +260,81 @@ export interface EnvConfig {
   forceAllTransforms?: boolean;
 }
 
+/**
+ * Assumptions about the code which allow swc to emit smaller and faster
+ * output, like babel's `assumptions`.
+ */
+export interface Assumptions {
+  /**
+   * Iterables used in spread, `for-of` and array destructuring are arrays.
+   */
+  iterableIsArray?: boolean;
+
+  /**
+   * Class methods can be assigned to the prototype.
+   */
+  setClassMethods?: boolean;
+
+  /**
+   * Classes are never called without `new`.
+   */
+  noClassCalls?: boolean;
+
+  /**
+   * Computed properties can be assigned instead of defined.
+   */
+  setComputedProperties?: boolean;
+}
+
 export interface JscConfig {
+  /**
+   * Trades spec compliance for smaller and faster output.
+   * This enables all of `assumptions`.
+   */
   loose?: boolean;
 
+  assumptions?: Assumptions;
+
+  /**
+   * Preserves `.name` of classes while lowering them.
+   *
+   * Defaults to `false`
+   */
+  keepClassNames?: boolean;
+
+  /**
+   * Preserves `.name` of arrow functions while lowering them.
+   *
+   * Defaults to `false`
+   */
+  keepFnNames?: boolean;
+
+  /**
+   * How warnings of transforms, like usage of deprecated syntax, are
+   * reported. `error` fails the compilation and `ignore` drops them.
+   *
+   * Defaults to `warn`
+   */
+  warnings?: "error" | "warn" | "ignore";
+
   /**
-   * Defaults to EsParserConfig
+   * Features which are not lowered even if `target` doesn't support them,
+   * like `["transform-classes", "transform-async-to-generator"]`.
+   *
+   * This is ignored if `env` is used, as it has its own `exclude`.
+   */
+  exclude?: string[];
+
+  /**
+   * Defaults to the syntax of the extension of the file, or EsParserConfig
    */
   parser?: ParserConfig;
+  /**
+   * Syntax of files with an extension, like `{ js: { syntax: "ecmascript", jsx: true } }`.
+   *
+   * `parser` takes precedence over this.
+   */
+  parserByExtension?: { [extension: string]: ParserConfig };
   transform?: TransformConfig;
   /**
    * Use `@swc/helpers` instead of inline helpers.
@@ -324,6 +399,36 @@ export interface EsParserConfig {
    * Defaults to `false`
    */
   classProperty?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  staticBlocks?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  pipelineOperator?: boolean;
+  /**
+   * Use the F# variant of the pipeline operator, which supports `a |> await`.
+   *
+   * Defaults to `false`
+   */
+  fsharpPipeline?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  partialApplication?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  doExpressions?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  throwExpressions?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  recordAndTuple?: boolean;
   /**
    * Defaults to `false`
    */
@@ -385,6 +490,68 @@ export interface TransformConfig {
    * https://swc.rs/docs/configuring-swc.html#jsctransformdecoratormetadata
    */
   decoratorMetadata?: boolean
+
+  /**
+   * Record and tuple literals are not emitted unless this is set.
+   */
+  recordAndTuple?: RecordAndTupleConfig;
+
+  /**
+   * Reports typescript constructs which can't be compiled per file, like
+   * `--isolatedModules` of tsc.
+   *
+   * Defaults to `false`
+   */
+  isolatedModules?: boolean;
+
+  /**
+   * Values of const enums exported by other modules, keyed by paths of the
+   * modules relative to the working directory like `./src/enums.ts` or by
+   * package names, and then by names of enums and members.
+   * Members of imported const enums are inlined.
+   */
+  constEnums?: {
+    [module: string]: {
+      [enumName: string]: { [member: string]: number | string };
+    };
+  };
+
+  /**
+   * Order of transforms which can be reordered, like
+   * `["typescript", "decorators", "jsx"]`. Transforms which are not listed
+   * run after the listed ones, in the default order.
+   *
+   * `decorators` should run before `typescript` if `decoratorMetadata` is
+   * enabled.
+   *
+   * Defaults to `["jsx", "decorators", "typescript"]`
+   */
+  order?: ("jsx" | "decorators" | "typescript")[];
+
+  /**
+   * How `"use client"` and `"use server"` directives are handled.
+   *
+   *  - `preserve` keeps them at the top of the output.
+   *  - `strip` removes them from the output.
+   *  - `enforce` is same as `preserve`, but reports misplaced directives.
+   *
+   * Defaults to `preserve`
+   */
+  directives?: "preserve" | "strip" | "enforce";
+}
+
+export interface RecordAndTupleConfig {
+  /**
+   * Import `Record` and `Tuple` instead of using globals.
+   *
+   * Defaults to `false`
+   */
+  importPolyfill?: boolean;
+
+  /**
+   * Defaults to `@bloomberg/record-tuple-polyfill`
+   */
+  polyfillModuleName?: string;
 }
 
 export interface ReactConfig {
@@ -401,14 +568,26 @@ export interface ReactConfig {
    */
   pragmaFrag: String;
   /**
-   * Toggles whether or not to throw an error if a XML namespaced tag name is used. For example:
-   * `<f:image />`
+   * Toggles whether or not to throw an error if a XML namespaced tag or
+   * attribute name is used. For example: `<f:image />` or `<image n:attr />`
    *
    * Though the JSX spec allows this, it is disabled by default since React's
-   * JSX does not currently have support for it.
+   * JSX does not currently have support for it. If disabled, they are
+   * reported as warnings instead (see `jsc.warnings`).
    *
    */
   throwIfNamespace: boolean;
+  /**
+   * How attributes in the `xlink`, `xml` and `xmlns` namespaces, like
+   * `xlink:href`, are handled.
+   *
+   *  - `check`: handled like other namespaced names (see `throwIfNamespace`).
+   *  - `preserve`: kept as string props, like `"xlink:href"`.
+   *  - `camelCase`: renamed to the props used by React, like `xlinkHref`.
+   *
+   * Defaults to `check`
+   */
+  xmlNamespaceAttrs?: "check" | "preserve" | "camelCase";
   /**
    * Toggles plugins that aid in development, such as @swc/plugin-transform-react-jsx-self
    * and @swc/plugin-transform-react-jsx-source.
@@ -417,8 +596,26 @@ export interface ReactConfig {
    *
    */
   development: boolean;
+  /**
+   * Adds checks for common mistakes when `development` is enabled, like
+   * spreading props which contain `key` (checked at runtime) or elements
+   * without a key returned from `.map()` (reported as warnings).
+   *
+   * Defaults to `false`
+   */
+  devChecks?: boolean;
+  /**
+   * Reports warnings for invalid usage of DOM elements, like children of
+   * void elements (`<img>children</img>`), duplicate props, or
+   * `dangerouslySetInnerHTML` combined with children.
+   *
+   * Defaults to `false`
+   */
+  validate?: boolean;
   /**
    * Use `Object.assign()` instead of `_extends`. Defaults to false.
+   *
+   * This is enabled automatically if all targets support `Object.assign()`.
    */
   useBuiltins: boolean;
 }
@@ -531,6 +728,30 @@ export interface BaseModuleConfig {
    * Defaults to `false`.
    */
   noInterop?: boolean;
+  /**
+   * How `import foo from "cjs"` is mapped when importing CommonJS modules.
+   *
+   *  - `"babel"` - `foo` is `_interopRequireDefault(require("cjs")).default`.
+   *  - `"node"` - `foo` is `require("cjs")`, like in node and webpack.
+   *  - `"none"` - `foo` is `require("cjs").default`.
+   *
+   * Ignored if `noInterop` is set. Defaults to `"babel"`.
+   */
+  importInterop?: "babel" | "node" | "none";
+  /**
+   * By default, `this` at the top level of a module is replaced with `undefined`, as it is in
+   *  native ES modules. Set this to true to keep `this` as is.
+   *
+   * Defaults to `false`.
+   */
+  allowTopLevelThis?: boolean;
+  /**
+   * Replaces `this` at the top level of a module with `exports` instead of `undefined`.
+   *  Ignored if `allowTopLevelThis` is set.
+   *
+   * Defaults to `false`.
+   */
+  loose?: boolean;
 }
 
 export interface CommonJsConfig extends BaseModuleConfig {
@@ -556,6 +777,28 @@ export interface Output {
    * Sourcemap (**not** base64 encoded)
    */
   map?: string;
+  /**
+   * Directives at the top of the input, like `"use client"`.
+   */
+  directives?: string[];
+  /**
+   * Warnings and errors emitted while transforming. Errors are also printed to stderr.
+   */
+  diagnostics?: Diagnostic[];
+}
+
+export interface Diagnostic {
+  level: "error" | "warning" | "note" | "help";
+  message: string;
+  file?: string;
+  /**
+   * 1-based
+   */
+  line?: number;
+  /**
+   * 0-based
+   */
+  column?: number;
 }
 
 export interface MatchPattern { }
@@ -602,7 +845,8 @@ export type ClassMember =
   | PrivateMethod
   | ClassProperty
   | PrivateProperty
-  | TsIndexSignature;
+  | TsIndexSignature
+  | StaticBlock;
 
 export interface ClassPropertyBase extends Node, HasSpan, HasDecorator {
   value?: Expression;
@@ -637,6 +881,12 @@ export interface PrivateProperty extends ClassPropertyBase {
   key: PrivateName;
 }
 
+export interface StaticBlock extends Node, HasSpan {
+  type: "StaticBlock";
+
+  body: BlockStatement;
+}
+
 export interface Param extends Node, HasSpan, HasDecorator {
   type: 'Parameter'
   pat: Pattern
@@ -774,10 +1024,51 @@ export type Expression =
   | TsAsExpression
   | PrivateName
   | OptionalChainingExpression
+  | ArgumentPlaceholder
+  | DoExpression
+  | BindExpression
+  | ThrowExpression
+  | RecordExpression
+  | TupleExpression
   | Invalid;
 
 interface ExpressionBase extends Node, HasSpan { }
 
+export interface ArgumentPlaceholder extends ExpressionBase {
+  type: "ArgumentPlaceholder";
+}
+
+export interface DoExpression extends ExpressionBase {
+  type: "DoExpression";
+
+  body: BlockStatement;
+}
+
+export interface BindExpression extends ExpressionBase {
+  type: "BindExpression";
+
+  object?: Expression;
+  callee: Expression;
+}
+
+export interface ThrowExpression extends ExpressionBase {
+  type: "ThrowExpression";
+
+  argument: Expression;
+}
+
+export interface RecordExpression extends ExpressionBase {
+  type: "RecordExpression";
+
+  properties: (Property | SpreadElement)[];
+}
+
+export interface TupleExpression extends ExpressionBase {
+  type: "TupleExpression";
+
+  elements: (Expression | SpreadElement)[];
+}
+
 export interface OptionalChainingExpression extends ExpressionBase {
   type: "OptionalChainingExpression";
   /**
@@ -1356,7 +1647,8 @@ export type BinaryOperator =
   | "&&"
   | "in"
   | "instanceof"
-  | "??";
+  | "??"
+  | "|>";
 
 export type AssignmentOperator =
   | "="
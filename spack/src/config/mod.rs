@@ -38,6 +38,11 @@ pub struct Config {
 
     #[serde(default)]
     pub options: Option<swc::config::Options>,
+
+    /// Modules which are not bundled, like node builtins or peer
+    /// dependencies. Imports of them are preserved.
+    #[serde(default)]
+    pub external: Vec<String>,
 }
 
 #[derive(StringEnum)]
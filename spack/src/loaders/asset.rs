@@ -0,0 +1,44 @@
+use anyhow::Error;
+use std::path::Path;
+
+/// Output of an [AssetHandler].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Asset {
+    /// JavaScript source of the module, which is compiled like any other
+    /// file.
+    Module(String),
+    /// Url of an emitted asset. The module exports it as default.
+    ///
+    /// ```js
+    /// export default "/assets/logo.svg";
+    /// ```
+    Url(String),
+}
+
+/// Handles imports of files which are not JavaScript, like `.css`, `.svg` or
+/// `.wasm`.
+///
+/// Handlers are registered by extension with
+/// [crate::loaders::swc::SwcLoader::with_asset_handler].
+pub trait AssetHandler: Send + Sync {
+    fn load(&self, path: &Path) -> Result<Asset, Error>;
+}
+
+impl<F> AssetHandler for F
+where
+    F: Fn(&Path) -> Result<Asset, Error> + Send + Sync,
+{
+    fn load(&self, path: &Path) -> Result<Asset, Error> {
+        (self)(path)
+    }
+}
+
+impl Asset {
+    /// Returns the source of the module for this asset.
+    pub(crate) fn into_source(self) -> Result<String, Error> {
+        match self {
+            Asset::Module(src) => Ok(src),
+            Asset::Url(url) => Ok(format!("export default {};", serde_json::to_string(&url)?)),
+        }
+    }
+}
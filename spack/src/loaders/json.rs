@@ -0,0 +1,160 @@
+use anyhow::{anyhow, bail, Error};
+use std::collections::HashMap;
+use swc_atoms::{js_word, JsWord};
+use swc_common::{SourceFile, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{lexer::Lexer, JscTarget, Parser, StringInput, Syntax};
+use swc_ecma_utils::{is_valid_ident, member_expr, quote_ident, ExprFactory};
+
+/// Files larger than this are embedded with `JSON.parse`, which is faster
+/// to evaluate than an object literal.
+const JSON_PARSE_THRESHOLD: usize = 10 * 1024;
+
+/// Converts a json file to a module.
+///
+/// Top-level properties of objects with a valid name are exported, so
+/// unused properties can be removed. The last value of a duplicate key is
+/// used, like `JSON.parse`.
+///
+/// ```js
+/// export const name = "foo";
+/// export const version = "1.0.0";
+/// export default {
+///     name,
+///     version,
+///     "dev-dependencies": {}
+/// };
+/// ```
+pub fn load_json_as_module(fm: &SourceFile) -> Result<Module, Error> {
+    let expr = parse(fm)?;
+
+    if fm.src.len() > JSON_PARSE_THRESHOLD {
+        // JSON.parse("...")
+        let parse = CallExpr {
+            span: DUMMY_SP,
+            callee: member_expr!(DUMMY_SP, JSON.parse).as_callee(),
+            args: vec![Lit::Str(Str {
+                span: DUMMY_SP,
+                value: (*fm.src).clone().into(),
+                has_escape: false,
+                raw: None,
+            })
+            .as_arg()],
+            type_args: Default::default(),
+        };
+        return Ok(module(vec![export_default(Box::new(Expr::Call(parse)))]));
+    }
+
+    let obj = match *expr {
+        Expr::Object(obj) => obj,
+        expr => return Ok(module(vec![export_default(Box::new(expr))])),
+    };
+
+    // Duplicate keys are replaced in place, so the order of keys is kept.
+    let mut deduped: Vec<Prop> = Vec::with_capacity(obj.props.len());
+    let mut indices = HashMap::new();
+    for prop in obj.props {
+        let prop = match prop {
+            PropOrSpread::Prop(prop) => *prop,
+            PropOrSpread::Spread(..) => bail!("invalid json file `{}`: unexpected spread", fm.name),
+        };
+
+        if let Prop::KeyValue(KeyValueProp {
+            key: PropName::Str(key),
+            ..
+        }) = &prop
+        {
+            if let Some(&idx) = indices.get(&key.value) {
+                deduped[idx] = prop;
+                continue;
+            }
+            indices.insert(key.value.clone(), deduped.len());
+        }
+        deduped.push(prop);
+    }
+
+    let mut body = vec![];
+    let mut props = Vec::with_capacity(deduped.len());
+    for prop in deduped {
+        let (key, value) = match prop {
+            Prop::KeyValue(KeyValueProp {
+                key: PropName::Str(key),
+                value,
+            }) => (key, value),
+            prop => {
+                props.push(PropOrSpread::Prop(Box::new(prop)));
+                continue;
+            }
+        };
+
+        if !can_export(&key.value) {
+            props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Str(key),
+                value,
+            }))));
+            continue;
+        }
+
+        let ident = Ident::new(key.value, DUMMY_SP);
+        body.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            span: DUMMY_SP,
+            decl: Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Const,
+                declare: false,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(ident.clone()),
+                    init: Some(value),
+                    definite: false,
+                }],
+            }),
+        })));
+        props.push(PropOrSpread::Prop(Box::new(Prop::Shorthand(ident))));
+    }
+
+    body.push(export_default(Box::new(Expr::Object(ObjectLit {
+        span: obj.span,
+        props,
+    }))));
+
+    Ok(module(body))
+}
+
+fn parse(fm: &SourceFile) -> Result<Box<Expr>, Error> {
+    let lexer = Lexer::new(
+        Syntax::default(),
+        JscTarget::Es2020,
+        StringInput::from(fm),
+        None,
+    );
+    let mut parser = Parser::new_from(lexer);
+
+    parser
+        .parse_expr()
+        .map_err(|err| anyhow!("failed to parse json file `{}`: {:?}", fm.name, err))
+}
+
+/// Returns true if `name` can be the name of a `const`.
+fn can_export(name: &JsWord) -> bool {
+    if !is_valid_ident(name) || name.is_reserved_for_es3() {
+        return false;
+    }
+
+    !matches!(*name, js_word!("eval") | js_word!("arguments"))
+}
+
+fn export_default(expr: Box<Expr>) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+        span: DUMMY_SP,
+        expr,
+    }))
+}
+
+fn module(body: Vec<ModuleItem>) -> Module {
+    Module {
+        span: DUMMY_SP,
+        body,
+        shebang: None,
+    }
+}
@@ -1 +1,3 @@
+pub mod asset;
+pub mod json;
 pub mod swc;
@@ -1,3 +1,5 @@
+use super::{asset::AssetHandler, json::load_json_as_module};
+use crate::resolvers::is_empty_module;
 use anyhow::{bail, Context, Error};
 use helpers::Helpers;
 use std::{collections::HashMap, env, sync::Arc};
@@ -5,7 +7,7 @@ use swc::config::{InputSourceMap, JscConfig, TransformConfig};
 use swc_atoms::JsWord;
 use swc_bundler::{Load, ModuleData};
 use swc_common::{FileName, DUMMY_SP};
-use swc_ecma_ast::{Expr, Lit, Program, Str};
+use swc_ecma_ast::{Expr, Lit, Module, Program, Str};
 use swc_ecma_parser::JscTarget;
 use swc_ecma_transforms::{
     helpers,
@@ -20,6 +22,8 @@ use swc_ecma_visit::FoldWith;
 pub struct SwcLoader {
     compiler: Arc<swc::Compiler>,
     options: swc::config::Options,
+    /// Handlers for non-JavaScript files, by extension.
+    assets: HashMap<String, Box<dyn AssetHandler>>,
 }
 
 impl SwcLoader {
@@ -28,7 +32,22 @@ impl SwcLoader {
             options.config = Some(Default::default());
         }
 
-        SwcLoader { compiler, options }
+        SwcLoader {
+            compiler,
+            options,
+            assets: Default::default(),
+        }
+    }
+
+    /// Routes imports of files with extension `ext` (without the leading dot)
+    /// to `handler`.
+    pub fn with_asset_handler(
+        mut self,
+        ext: impl Into<String>,
+        handler: impl AssetHandler + 'static,
+    ) -> Self {
+        self.assets.insert(ext.into(), Box::new(handler));
+        self
     }
 }
 
@@ -37,17 +56,53 @@ impl Load for SwcLoader {
         log::debug!("JsLoader.load({})", name);
         let helpers = Helpers::new(false);
 
-        let fm = self
-            .compiler
-            .cm
-            .load_file(match name {
-                FileName::Real(v) => &v,
-                _ => bail!("swc-loader only accepts path. Got `{}`", name),
-            })
-            .with_context(|| format!("failed to load file `{}`", name))?;
+        if is_empty_module(name) {
+            return Ok(ModuleData {
+                fm: self
+                    .compiler
+                    .cm
+                    .new_source_file(name.clone(), String::new()),
+                module: Module {
+                    span: DUMMY_SP,
+                    body: vec![],
+                    shebang: None,
+                },
+                helpers,
+            });
+        }
+
+        let path = match name {
+            FileName::Real(v) => v,
+            _ => bail!("swc-loader only accepts path. Got `{}`", name),
+        };
+        let ext = path.extension().and_then(|ext| ext.to_str());
+
+        let fm = match ext.and_then(|ext| self.assets.get(ext)) {
+            Some(handler) => {
+                let src = handler
+                    .load(path)
+                    .and_then(|asset| asset.into_source())
+                    .with_context(|| format!("failed to load asset `{}`", name))?;
+                self.compiler.cm.new_source_file(name.clone(), src)
+            }
+            None => self
+                .compiler
+                .cm
+                .load_file(path)
+                .with_context(|| format!("failed to load file `{}`", name))?,
+        };
 
         log::trace!("JsLoader.load: loaded");
 
+        if ext == Some("json") && !self.assets.contains_key("json") {
+            let module = load_json_as_module(&fm)?;
+            return Ok(ModuleData {
+                fm,
+                module,
+                helpers,
+            });
+        }
+
         let program = if fm.name.to_string().contains("node_modules") {
             let program = self.compiler.parse_js(
                 fm.clone(),
@@ -78,19 +133,16 @@ impl Load for SwcLoader {
                                     transform: {
                                         if let Some(c) = &c.jsc.transform {
                                             Some(TransformConfig {
-                                                react: c.react.clone(),
-                                                const_modules: c.const_modules.clone(),
                                                 optimizer: None,
-                                                legacy_decorator: c.legacy_decorator,
-                                                decorator_metadata: c.decorator_metadata,
                                                 hidden: Default::default(),
+                                                ..c.clone()
                                             })
                                         } else {
                                             None
                                         }
                                     },
                                     external_helpers: true,
-                                    ..c.jsc
+                                    ..c.jsc.clone()
                                 },
                                 module: None,
                                 minify: Some(false),
@@ -120,6 +172,7 @@ impl Load for SwcLoader {
                     source_maps: None,
                     source_file_name: None,
                     source_root: None,
+                    inline_sources_content: None,
                     is_module: true,
                 },
                 &fm.name,
@@ -180,6 +233,7 @@ fn env_map() -> HashMap<JsWord, Expr> {
                 span: DUMMY_SP,
                 value: s,
                 has_escape: false,
+                raw: None,
             })),
         );
     }
@@ -2,16 +2,22 @@
 //!
 //! See: https://github.com/goto-bus-stop/node-resolve
 
+pub use self::tsconfig::TsConfigResolver;
 use anyhow::{bail, Context, Error};
+use regex::Regex;
 use serde::Deserialize;
+use serde_json::Value;
 use std::{
+    collections::HashMap,
     fs::File,
     io::BufReader,
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
 };
 use swc_bundler::Resolve;
 use swc_common::FileName;
 
+mod tsconfig;
+
 #[derive(Deserialize)]
 struct PackageJson {
     #[serde(rename = "swc-main", default)]
@@ -20,15 +26,109 @@ struct PackageJson {
     esnext: Option<String>,
     #[serde(default)]
     main: Option<String>,
+    #[serde(default)]
+    browser: Option<Browser>,
+    #[serde(default)]
+    exports: Option<Value>,
+    #[serde(rename = "sideEffects", default)]
+    side_effects: Option<SideEffects>,
+}
+
+/// https://webpack.js.org/guides/tree-shaking/#mark-the-file-as-side-effect-free
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SideEffects {
+    Bool(bool),
+    /// Globs of files with side effects. Globs without a slash match file
+    /// names.
+    Files(Vec<String>),
+}
+
+/// https://github.com/defunctzombie/package-browser-field-spec
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Browser {
+    /// Replaces `main`.
+    Main(String),
+    /// Replaces files or modules. Modules replaced with `false` are resolved
+    /// to an empty module.
+    Map(HashMap<String, BrowserReplacement>),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BrowserReplacement {
+    Path(String),
+    Disabled(bool),
+}
+
+/// Replacement of a file or a module by the browser field.
+enum Replacement {
+    Path(PathBuf),
+    Module(String),
+    /// Disabled with `false`.
+    Empty,
 }
 
-pub struct NodeResolver;
+/// Prefix of names of empty modules.
+const EMPTY_MODULE_PREFIX: &str = "empty:";
+
+/// Returns the name of the empty module which replaces `target`.
+fn empty_module(target: &str) -> FileName {
+    FileName::Custom(format!("{}{}", EMPTY_MODULE_PREFIX, target))
+}
+
+/// Returns true if `name` is an empty module, which replaces a module
+/// disabled by the `browser` field of package.json. Loaders should load it
+/// as a module without any statement.
+pub fn is_empty_module(name: &FileName) -> bool {
+    match name {
+        FileName::Custom(name) => name.starts_with(EMPTY_MODULE_PREFIX),
+        _ => false,
+    }
+}
+
+/// Resolves modules like node.js, including the `exports` field of
+/// package.json.
+///
+/// [NodeResolver::browser] creates a resolver which also respects the
+/// `browser` field of package.json.
+pub struct NodeResolver {
+    browser: bool,
+}
 
 static EXTENSIONS: &[&str] = &["ts", "tsx", "js", "jsx", "json", "node"];
 
 impl NodeResolver {
     pub fn new() -> Self {
-        Self
+        Self { browser: false }
+    }
+
+    /// Creates a resolver for browsers.
+    ///
+    /// The `browser` field of package.json is used instead of `main`, and
+    /// files or modules replaced by it are resolved to their replacements.
+    /// The `browser` condition of `exports` is preferred over `node`.
+    pub fn browser() -> Self {
+        Self { browser: true }
+    }
+
+    /// Conditions of `exports`, in the order of priority.
+    ///
+    /// Objects of package.json are unordered once deserialized, so this is
+    /// used instead of the order of keys.
+    fn conditions(&self) -> &'static [&'static str] {
+        if self.browser {
+            &["browser", "import", "module", "require", "default"]
+        } else {
+            &["node", "import", "module", "require", "default"]
+        }
+    }
+
+    fn read_package_json(&self, pkg_path: &Path) -> Result<PackageJson, Error> {
+        let file = File::open(pkg_path)?;
+        let reader = BufReader::new(file);
+        serde_json::from_reader(reader).context("failed to deserialize package.json")
     }
 
     fn wrap(&self, path: PathBuf) -> Result<FileName, Error> {
@@ -76,12 +176,14 @@ impl NodeResolver {
         // TODO how to not always initialise this here?
         let root = PathBuf::from("/");
         let pkg_dir = pkg_path.parent().unwrap_or(&root);
-        let file = File::open(pkg_path)?;
-        let reader = BufReader::new(file);
-        let pkg: PackageJson =
-            serde_json::from_reader(reader).context("failed to deserialize package.json")?;
+        let pkg = self.read_package_json(pkg_path)?;
 
-        for main in &[&pkg.swc_main, &pkg.esnext, &pkg.main] {
+        let browser = match &pkg.browser {
+            Some(Browser::Main(main)) if self.browser => Some(main.clone()),
+            _ => None,
+        };
+
+        for main in &[&browser, &pkg.swc_main, &pkg.esnext, &pkg.main] {
             if let Some(target) = main {
                 let path = pkg_dir.join(target);
                 return self
@@ -112,6 +214,18 @@ impl NodeResolver {
     fn resolve_node_modules(&self, base_dir: &Path, target: &str) -> Result<PathBuf, Error> {
         let node_modules = base_dir.join("node_modules");
         if node_modules.is_dir() {
+            let (name, subpath) = split_package_name(target);
+            let pkg_path = node_modules.join(name).join("package.json");
+            if pkg_path.is_file() {
+                let pkg = self.read_package_json(&pkg_path)?;
+                if let Some(exports) = &pkg.exports {
+                    // Files which are not exported can't be imported.
+                    return self
+                        .resolve_exports(&node_modules.join(name), exports, &subpath)
+                        .with_context(|| format!("failed to resolve `{}`", target));
+                }
+            }
+
             let path = node_modules.join(target);
             let result = self
                 .resolve_as_file(&path)
@@ -126,6 +240,229 @@ impl NodeResolver {
             None => bail!("not found"),
         }
     }
+
+    /// Resolves `subpath` of a package using its `exports` field.
+    ///
+    /// See: https://nodejs.org/api/packages.html#packages_package_entry_points
+    fn resolve_exports(
+        &self,
+        pkg_dir: &Path,
+        exports: &Value,
+        subpath: &str,
+    ) -> Result<PathBuf, Error> {
+        let subpaths = match exports {
+            Value::Object(map) if map.keys().all(|key| key.starts_with('.')) => map,
+            // Sugar for `{ ".": exports }`
+            _ => {
+                if subpath != "." {
+                    bail!("`{}` is not exported", subpath)
+                }
+                return self.resolve_export_target(pkg_dir, exports, "");
+            }
+        };
+
+        if let Some(target) = subpaths.get(subpath) {
+            return self.resolve_export_target(pkg_dir, target, "");
+        }
+
+        // Patterns like `./features/*` and legacy folder mappings like `./lib/`.
+        // The longest prefix wins.
+        let mut best: Option<(&str, &str, &Value)> = None;
+        for (key, target) in subpaths {
+            let matched = match key.find('*') {
+                Some(idx) => {
+                    let (prefix, suffix) = (&key[..idx], &key[idx + 1..]);
+                    if subpath.len() >= key.len() - 1
+                        && subpath.starts_with(prefix)
+                        && subpath.ends_with(suffix)
+                    {
+                        Some((prefix, &subpath[prefix.len()..subpath.len() - suffix.len()]))
+                    } else {
+                        None
+                    }
+                }
+                None if key.ends_with('/') && subpath.starts_with(&**key) => {
+                    Some((&**key, &subpath[key.len()..]))
+                }
+                None => None,
+            };
+
+            if let Some((prefix, rest)) = matched {
+                if best.map_or(true, |(best, _, _)| prefix.len() > best.len()) {
+                    best = Some((prefix, rest, target));
+                }
+            }
+        }
+
+        match best {
+            Some((_, rest, target)) => self.resolve_export_target(pkg_dir, target, rest),
+            None => bail!("`{}` is not exported", subpath),
+        }
+    }
+
+    /// Resolves a target of `exports`. `*` in the target is replaced with
+    /// `rest`.
+    fn resolve_export_target(
+        &self,
+        pkg_dir: &Path,
+        target: &Value,
+        rest: &str,
+    ) -> Result<PathBuf, Error> {
+        match target {
+            Value::String(target) => {
+                if !target.starts_with("./") {
+                    bail!("invalid target of exports: {}", target)
+                }
+
+                let target = if target.contains('*') {
+                    target.replace('*', rest)
+                } else {
+                    format!("{}{}", target, rest)
+                };
+                // Targets should not escape the package.
+                if Path::new(&target)
+                    .components()
+                    .any(|c| c == Component::ParentDir)
+                {
+                    bail!("invalid target of exports: {}", target)
+                }
+
+                self.resolve_as_file(&pkg_dir.join(target))
+            }
+            // Fallbacks
+            Value::Array(targets) => {
+                for target in targets {
+                    if let Ok(path) = self.resolve_export_target(pkg_dir, target, rest) {
+                        return Ok(path);
+                    }
+                }
+                bail!("no valid target in exports")
+            }
+            Value::Object(conditions) => {
+                for condition in self.conditions() {
+                    if let Some(target) = conditions.get(*condition) {
+                        return self.resolve_export_target(pkg_dir, target, rest);
+                    }
+                }
+                bail!("no matching condition in exports")
+            }
+            _ => bail!("the module is not exported"),
+        }
+    }
+
+    /// Applies the `browser` field of the package which contains `base_dir`.
+    ///
+    /// `target` is a module name or a path of a resolved file. Returns `None`
+    /// if the target is not replaced.
+    fn replace_for_browser(
+        &self,
+        base_dir: &Path,
+        target: &str,
+    ) -> Result<Option<Replacement>, Error> {
+        if !self.browser {
+            return Ok(None);
+        }
+
+        let pkg_dir = match base_dir
+            .ancestors()
+            .find(|dir| dir.join("package.json").is_file())
+        {
+            Some(dir) => dir,
+            None => return Ok(None),
+        };
+        let map = match self
+            .read_package_json(&pkg_dir.join("package.json"))?
+            .browser
+        {
+            Some(Browser::Map(map)) => map,
+            _ => return Ok(None),
+        };
+
+        for (key, replacement) in &map {
+            let matches = if key.starts_with("./") || key.starts_with("../") {
+                self.resolve_as_file(&pkg_dir.join(key))
+                    .and_then(|file| Ok(file.canonicalize()? == Path::new(target).canonicalize()?))
+                    .unwrap_or(false)
+            } else {
+                key == target
+            };
+
+            if matches {
+                return match replacement {
+                    BrowserReplacement::Path(path) if path.starts_with('.') => {
+                        Ok(Some(Replacement::Path(pkg_dir.join(path))))
+                    }
+                    BrowserReplacement::Path(module) => {
+                        Ok(Some(Replacement::Module(module.clone())))
+                    }
+                    BrowserReplacement::Disabled(false) => Ok(Some(Replacement::Empty)),
+                    BrowserReplacement::Disabled(true) => Ok(None),
+                };
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns `None` for [Replacement::Empty].
+    fn resolve_replacement(
+        &self,
+        base_dir: &Path,
+        replacement: Replacement,
+    ) -> Result<Option<PathBuf>, Error> {
+        match replacement {
+            Replacement::Path(path) => self.resolve_path(&path).map(Some),
+            Replacement::Module(module) => self.resolve_node_modules(base_dir, &module).map(Some),
+            Replacement::Empty => Ok(None),
+        }
+    }
+
+    fn resolve_path(&self, path: &Path) -> Result<PathBuf, Error> {
+        self.resolve_as_file(path)
+            .or_else(|_| self.resolve_as_directory(&path.to_path_buf()))
+    }
+}
+
+/// Returns true if `path`, relative to the package directory, matches the glob
+/// `pattern` of `sideEffects`.
+fn matches_side_effects(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim_start_matches("./");
+    let mut re = String::from(if pattern.contains('/') {
+        "^"
+    } else {
+        "^(?:.*/)?"
+    });
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                re.push_str(".*");
+            }
+            '*' => re.push_str("[^/]*"),
+            '?' => re.push_str("[^/]"),
+            _ => re.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    re.push('$');
+
+    Regex::new(&re).map(|re| re.is_match(path)).unwrap_or(false)
+}
+
+/// Splits `target` into the package name and the subpath, like `.` or
+/// `./utils`.
+fn split_package_name(target: &str) -> (&str, String) {
+    let mut slashes = target.match_indices('/');
+    let end = if target.starts_with('@') {
+        slashes.nth(1)
+    } else {
+        slashes.next()
+    };
+
+    match end {
+        Some((idx, _)) => (&target[..idx], format!(".{}", &target[idx..])),
+        None => (target, ".".into()),
+    }
 }
 
 impl Resolve for NodeResolver {
@@ -135,29 +472,65 @@ impl Resolve for NodeResolver {
             _ => bail!("node-resolver supports only files"),
         };
 
-        // Absolute path
-        if target.starts_with("/") {
-            let base_dir = &Path::new("/");
-
-            let path = base_dir.join(target);
-            return self
-                .resolve_as_file(&path)
-                .or_else(|_| self.resolve_as_directory(&path))
-                .and_then(|p| self.wrap(p));
-        }
-
         let cwd = &Path::new(".");
         let base_dir = base.parent().unwrap_or(&cwd);
 
-        if target.starts_with("./") || target.starts_with("../") {
-            let path = base_dir.join(target);
-            return self
-                .resolve_as_file(&path)
-                .or_else(|_| self.resolve_as_directory(&path))
-                .and_then(|p| self.wrap(p));
-        }
+        let path = if Path::new(target).is_absolute() {
+            // Absolute path
+            self.resolve_path(Path::new(target))?
+        } else if target.starts_with("./") || target.starts_with("../") {
+            self.resolve_path(&base_dir.join(target))?
+        } else {
+            match self.replace_for_browser(base_dir, target)? {
+                Some(replacement) => match self.resolve_replacement(base_dir, replacement)? {
+                    Some(path) => path,
+                    None => return Ok(empty_module(target)),
+                },
+                None => self.resolve_node_modules(base_dir, target)?,
+            }
+        };
+
+        // Files replaced by the browser field of their package.
+        let dir = path.parent().unwrap_or(cwd).to_path_buf();
+        let path = match self.replace_for_browser(&dir, &path.to_string_lossy())? {
+            Some(replacement) => match self.resolve_replacement(&dir, replacement)? {
+                Some(path) => path,
+                None => return Ok(empty_module(&path.to_string_lossy())),
+            },
+            None => path,
+        };
+
+        self.wrap(path)
+    }
+
+    fn has_side_effects(&self, path: &FileName) -> bool {
+        let path = match path {
+            FileName::Real(v) => v,
+            _ => return true,
+        };
+        let pkg_dir = match path
+            .ancestors()
+            .skip(1)
+            .find(|dir| dir.join("package.json").is_file())
+        {
+            Some(dir) => dir,
+            None => return true,
+        };
+        let side_effects = match self.read_package_json(&pkg_dir.join("package.json")) {
+            Ok(pkg) => pkg.side_effects,
+            Err(_) => return true,
+        };
 
-        self.resolve_node_modules(base_dir, target)
-            .and_then(|p| self.wrap(p))
+        match side_effects {
+            None | Some(SideEffects::Bool(true)) => true,
+            Some(SideEffects::Bool(false)) => false,
+            Some(SideEffects::Files(patterns)) => {
+                let path = path.strip_prefix(pkg_dir).unwrap_or(path);
+                let path = path.to_string_lossy().replace('\\', "/");
+                patterns
+                    .iter()
+                    .any(|pattern| matches_side_effects(pattern, &path))
+            }
+        }
     }
 }
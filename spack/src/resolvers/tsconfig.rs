@@ -0,0 +1,195 @@
+use anyhow::{Context, Error};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+use swc_bundler::Resolve;
+use swc_common::FileName;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TsConfig {
+    #[serde(default)]
+    compiler_options: CompilerOptions,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CompilerOptions {
+    #[serde(default)]
+    base_url: Option<PathBuf>,
+    #[serde(default)]
+    paths: HashMap<String, Vec<String>>,
+}
+
+/// Resolves non-relative imports using `baseUrl` and `paths` of
+/// `tsconfig.json`, like `tsc`.
+///
+/// Imports which are not mapped are resolved by the inner resolver.
+///
+/// See: https://www.typescriptlang.org/docs/handbook/module-resolution.html#path-mapping
+pub struct TsConfigResolver<R> {
+    /// Directory used to resolve non-relative imports, if `baseUrl` is
+    /// specified.
+    base_url: Option<PathBuf>,
+    /// Directory which targets of `paths` are relative to.
+    paths_base: PathBuf,
+    paths: Vec<(String, Vec<String>)>,
+    inner: R,
+}
+
+impl<R> TsConfigResolver<R>
+where
+    R: Resolve,
+{
+    /// `paths` is a map like `{ "@app/*": ["src/app/*"] }` with targets
+    /// relative to `base_url`.
+    pub fn new(base_url: PathBuf, paths: HashMap<String, Vec<String>>, inner: R) -> Self {
+        TsConfigResolver {
+            base_url: Some(base_url.clone()),
+            paths_base: base_url,
+            paths: paths.into_iter().collect(),
+            inner,
+        }
+    }
+
+    /// Reads `compilerOptions` of a `tsconfig.json`.
+    ///
+    /// Comments are allowed, but `extends` is not supported.
+    pub fn from_file(path: &Path, inner: R) -> Result<Self, Error> {
+        let src = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let config: TsConfig = serde_json::from_str(&strip_comments(&src))
+            .with_context(|| format!("failed to deserialize {}", path.display()))?;
+
+        // Targets are resolved from the directories of importing files, so
+        // they should be absolute.
+        let path = path
+            .canonicalize()
+            .with_context(|| format!("failed to canonicalize {}", path.display()))?;
+        let dir = path.parent().unwrap_or_else(|| Path::new("/"));
+        let base_url = config.compiler_options.base_url.map(|url| dir.join(url));
+
+        Ok(TsConfigResolver {
+            // `paths` are relative to the directory of tsconfig.json if
+            // `baseUrl` is not specified.
+            paths_base: base_url.clone().unwrap_or_else(|| dir.to_path_buf()),
+            base_url,
+            paths: config.compiler_options.paths.into_iter().collect(),
+            inner,
+        })
+    }
+
+    /// Finds the pattern of `paths` matching `specifier`, and returns its
+    /// targets with the text matched by `*`.
+    ///
+    /// An exact match is preferred, and the longest prefix wins otherwise.
+    fn match_paths<'a>(&self, specifier: &'a str) -> Option<(&[String], &'a str)> {
+        let mut best: Option<(usize, &[String], &'a str)> = None;
+
+        for (pattern, targets) in &self.paths {
+            match pattern.find('*') {
+                Some(idx) => {
+                    let (prefix, suffix) = (&pattern[..idx], &pattern[idx + 1..]);
+                    if specifier.len() >= prefix.len() + suffix.len()
+                        && specifier.starts_with(prefix)
+                        && specifier.ends_with(suffix)
+                        && best.map_or(true, |(len, _, _)| prefix.len() > len)
+                    {
+                        let matched = &specifier[prefix.len()..specifier.len() - suffix.len()];
+                        best = Some((prefix.len(), targets, matched));
+                    }
+                }
+                None if pattern == specifier => return Some((targets, "")),
+                None => {}
+            }
+        }
+
+        best.map(|(_, targets, matched)| (targets, matched))
+    }
+}
+
+impl<R> Resolve for TsConfigResolver<R>
+where
+    R: Resolve,
+{
+    fn resolve(&self, base: &FileName, specifier: &str) -> Result<FileName, Error> {
+        if specifier.starts_with("./")
+            || specifier.starts_with("../")
+            || Path::new(specifier).is_absolute()
+        {
+            return self.inner.resolve(base, specifier);
+        }
+
+        if let Some((targets, matched)) = self.match_paths(specifier) {
+            for target in targets {
+                let path = self.paths_base.join(target.replace('*', matched));
+                if let Ok(resolved) = self.inner.resolve(base, &path.to_string_lossy()) {
+                    return Ok(resolved);
+                }
+            }
+        }
+
+        if let Some(base_url) = &self.base_url {
+            let path = base_url.join(specifier);
+            if let Ok(resolved) = self.inner.resolve(base, &path.to_string_lossy()) {
+                return Ok(resolved);
+            }
+        }
+
+        self.inner.resolve(base, specifier)
+    }
+
+    fn has_side_effects(&self, path: &FileName) -> bool {
+        self.inner.has_side_effects(path)
+    }
+}
+
+/// Removes `//` and `/* */` comments outside of strings.
+fn strip_comments(src: &str) -> String {
+    let mut buf = String::with_capacity(src.len());
+    let mut chars = src.chars().peekable();
+    let mut in_str = false;
+
+    while let Some(c) = chars.next() {
+        if in_str {
+            buf.push(c);
+            match c {
+                '\\' => buf.extend(chars.next()),
+                '"' => in_str = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_str = true;
+                buf.push(c);
+            }
+            ('/', Some('/')) => {
+                while let Some(&c) = chars.peek() {
+                    if c == '\n' {
+                        break;
+                    }
+                    chars.next();
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = ' ';
+                for c in &mut chars {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            _ => buf.push(c),
+        }
+    }
+
+    buf
+}
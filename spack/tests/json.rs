@@ -0,0 +1,37 @@
+use spack::loaders::json::load_json_as_module;
+use swc_common::{sync::Lrc, FileName, SourceMap};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+
+fn load(src: &str) -> Result<String, String> {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Anon, src.into());
+    let module = load_json_as_module(&fm).map_err(|err| err.to_string())?;
+
+    let mut buf = vec![];
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: None,
+            wr: Box::new(JsWriter::new(cm, "\n", &mut buf, None)),
+        };
+        emitter.emit_module(&module).unwrap();
+    }
+    Ok(String::from_utf8(buf).unwrap())
+}
+
+#[test]
+fn json_duplicate_keys() {
+    let code = load(r#"{ "a": 1, "b": 2, "a": 3 }"#).unwrap();
+
+    assert_eq!(code.matches("export const a").count(), 1, "{}", code);
+    assert!(code.contains("export const a = 3;"), "{}", code);
+    assert!(code.contains("export const b = 2;"), "{}", code);
+}
+
+#[test]
+fn json_spread() {
+    let err = load("{ ...a }").unwrap_err();
+
+    assert!(err.contains("unexpected spread"), "{}", err);
+}
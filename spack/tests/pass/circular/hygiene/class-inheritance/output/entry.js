@@ -1,16 +1,55 @@
-class C {
-    a() {
-        throw new Error('Unimplemented');
-    }
-    b() {
-        return new B();
+var C;
+var _c = {
+    get C () {
+        _init_c();
+        return C;
     }
+};
+var _c_initialized = false;
+function _init_c() {
+    if (_c_initialized) return;
+    _c_initialized = true;
+    C = class C {
+        a() {
+            throw new Error('Unimplemented');
+        }
+        b() {
+            return new _b.B();
+        }
+    };
 }
-class B extends C {
-    a() {
-        return new A();
+var B;
+var _b = {
+    get B () {
+        _init_b();
+        return B;
     }
+};
+var _b_initialized = false;
+function _init_b() {
+    if (_b_initialized) return;
+    _b_initialized = true;
+    B = class B extends _c.C {
+        a() {
+            return new _a.A();
+        }
+    };
 }
-class A extends B {
+var A;
+var _a = {
+    get A () {
+        _init_a();
+        return A;
+    }
+};
+var _a_initialized = false;
+function _init_a() {
+    if (_a_initialized) return;
+    _a_initialized = true;
+    A = class A extends _b.B {
+    };
 }
+_init_c();
+_init_b();
+_init_a();
 console.log(A, 'Loaded!');
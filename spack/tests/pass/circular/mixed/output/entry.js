@@ -1,9 +1,35 @@
-console.log('c');
-class A {
-    method() {
-        return new B();
+var B;
+var _b = {
+    get B () {
+        _init_b();
+        return B;
     }
+};
+var _b_initialized = false;
+function _init_b() {
+    if (_b_initialized) return;
+    _b_initialized = true;
+    console.log('c');
+    B = class B extends _a.A {
+    };
 }
-class B extends A {
+var A;
+var _a = {
+    get A () {
+        _init_a();
+        return A;
+    }
+};
+var _a_initialized = false;
+function _init_a() {
+    if (_a_initialized) return;
+    _a_initialized = true;
+    A = class A {
+        method() {
+            return new _b.B();
+        }
+    };
 }
+_init_b();
+_init_a();
 console.log(A, B);
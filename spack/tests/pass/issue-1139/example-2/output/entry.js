@@ -1,8 +1,8 @@
 function f2() {
     console.log("f2");
 }
+f1();
 function f1() {
     console.log("f1");
 }
-f1();
 f2();
@@ -0,0 +1,274 @@
+use spack::resolvers::{is_empty_module, NodeResolver, TsConfigResolver};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use swc_bundler::Resolve;
+use swc_common::FileName;
+use tempfile::TempDir;
+
+/// Creates a directory with `files`.
+fn dir(files: &[(&str, &str)]) -> TempDir {
+    write(tempfile::tempdir().unwrap(), files)
+}
+
+fn write(dir: TempDir, files: &[(&str, &str)]) -> TempDir {
+    for (path, content) in files {
+        let path = dir.path().join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    dir
+}
+
+fn resolve(r: &dyn Resolve, dir: &Path, base: &str, specifier: &str) -> PathBuf {
+    let base = FileName::Real(dir.join(base));
+    match r.resolve(&base, specifier).unwrap() {
+        FileName::Real(path) => path
+            .strip_prefix(dir.canonicalize().unwrap())
+            .unwrap()
+            .to_path_buf(),
+        name => panic!("unexpected file name: {}", name),
+    }
+}
+
+#[test]
+fn node_exports() {
+    let dir = dir(&[
+        ("index.js", ""),
+        (
+            "node_modules/pkg/package.json",
+            r#"{
+                "main": "./main.js",
+                "exports": {
+                    ".": { "browser": "./browser.js", "default": "./main.js" },
+                    "./utils": "./lib/utils.js",
+                    "./features/*": "./lib/features/*.js"
+                }
+            }"#,
+        ),
+        ("node_modules/pkg/main.js", ""),
+        ("node_modules/pkg/browser.js", ""),
+        ("node_modules/pkg/lib/utils.js", ""),
+        ("node_modules/pkg/lib/features/a.js", ""),
+        ("node_modules/pkg/lib/private.js", ""),
+        (
+            "node_modules/@scope/pkg/package.json",
+            r#"{ "exports": "./a.js" }"#,
+        ),
+        ("node_modules/@scope/pkg/a.js", ""),
+    ]);
+    let dir = dir.path();
+    let r = NodeResolver::new();
+
+    assert_eq!(
+        resolve(&r, dir, "index.js", "pkg"),
+        Path::new("node_modules/pkg/main.js")
+    );
+    assert_eq!(
+        resolve(&NodeResolver::browser(), dir, "index.js", "pkg"),
+        Path::new("node_modules/pkg/browser.js")
+    );
+    assert_eq!(
+        resolve(&r, dir, "index.js", "pkg/utils"),
+        Path::new("node_modules/pkg/lib/utils.js")
+    );
+    assert_eq!(
+        resolve(&r, dir, "index.js", "pkg/features/a"),
+        Path::new("node_modules/pkg/lib/features/a.js")
+    );
+    assert_eq!(
+        resolve(&r, dir, "index.js", "@scope/pkg"),
+        Path::new("node_modules/@scope/pkg/a.js")
+    );
+
+    let base = FileName::Real(dir.join("index.js"));
+    assert!(r.resolve(&base, "pkg/lib/private.js").is_err());
+}
+
+#[test]
+fn node_browser_field() {
+    let dir = dir(&[
+        (
+            "package.json",
+            r#"{
+                "browser": {
+                    "./src/node.js": "./src/browser.js",
+                    "fs": false,
+                    "http": "stream-http"
+                }
+            }"#,
+        ),
+        ("src/index.js", ""),
+        ("src/node.js", ""),
+        ("src/browser.js", ""),
+        ("node_modules/http/index.js", ""),
+        ("node_modules/stream-http/index.js", ""),
+        (
+            "node_modules/pkg/package.json",
+            r#"{ "main": "./node.js", "browser": "./browser.js" }"#,
+        ),
+        ("node_modules/pkg/node.js", ""),
+        ("node_modules/pkg/browser.js", ""),
+    ]);
+    let dir = dir.path();
+    let r = NodeResolver::browser();
+
+    assert_eq!(
+        resolve(&r, dir, "src/index.js", "./node"),
+        Path::new("src/browser.js")
+    );
+    assert_eq!(
+        resolve(&r, dir, "src/index.js", "http"),
+        Path::new("node_modules/stream-http/index.js")
+    );
+    assert_eq!(
+        resolve(&r, dir, "src/index.js", "pkg"),
+        Path::new("node_modules/pkg/browser.js")
+    );
+
+    // Modules disabled with `false` are empty.
+    let base = FileName::Real(dir.join("src/index.js"));
+    assert!(is_empty_module(&r.resolve(&base, "fs").unwrap()));
+
+    // The browser field is ignored by default.
+    let r = NodeResolver::new();
+    assert_eq!(
+        resolve(&r, dir, "src/index.js", "./node"),
+        Path::new("src/node.js")
+    );
+    assert_eq!(
+        resolve(&r, dir, "src/index.js", "pkg"),
+        Path::new("node_modules/pkg/node.js")
+    );
+}
+
+#[test]
+fn node_side_effects() {
+    let dir = dir(&[
+        ("index.js", ""),
+        (
+            "node_modules/pure/package.json",
+            r#"{ "main": "./index.js", "sideEffects": false }"#,
+        ),
+        ("node_modules/pure/index.js", ""),
+        (
+            "node_modules/some/package.json",
+            r#"{ "sideEffects": ["./src/polyfill.js", "*.css"] }"#,
+        ),
+        ("node_modules/some/index.js", ""),
+        ("node_modules/some/src/polyfill.js", ""),
+        ("node_modules/some/src/style.css", ""),
+        (
+            "node_modules/impure/package.json",
+            r#"{ "main": "./index.js" }"#,
+        ),
+        ("node_modules/impure/index.js", ""),
+    ]);
+    let dir = dir.path().canonicalize().unwrap();
+    let r = NodeResolver::new();
+
+    let has_side_effects = |specifier: &str| {
+        let base = FileName::Real(dir.join("index.js"));
+        r.has_side_effects(&r.resolve(&base, specifier).unwrap())
+    };
+    assert!(!has_side_effects("pure"));
+    assert!(!has_side_effects("some"));
+    assert!(has_side_effects("some/src/polyfill.js"));
+    assert!(has_side_effects("some/src/style.css"));
+    assert!(has_side_effects("impure"));
+    assert!(has_side_effects("./index.js"));
+}
+
+#[test]
+fn tsconfig_paths() {
+    let dir = dir(&[
+        (
+            "tsconfig.json",
+            r#"{
+                // Comments are allowed.
+                "compilerOptions": {
+                    "baseUrl": "./src",
+                    /* "paths": {} */
+                    "paths": {
+                        "@app/*": ["app/*", "fallback/*"],
+                        "@app/special": ["special.ts"],
+                        "config": ["../config/index.ts"]
+                    }
+                }
+            }"#,
+        ),
+        ("src/index.ts", ""),
+        ("src/app/a.ts", ""),
+        ("src/fallback/b.ts", ""),
+        ("src/special.ts", ""),
+        ("src/utils/index.ts", ""),
+        ("config/index.ts", ""),
+        ("node_modules/pkg/index.js", ""),
+    ]);
+    let dir = dir.path();
+    let r = TsConfigResolver::from_file(&dir.join("tsconfig.json"), NodeResolver::new()).unwrap();
+
+    assert_eq!(
+        resolve(&r, dir, "src/index.ts", "@app/a"),
+        Path::new("src/app/a.ts")
+    );
+    assert_eq!(
+        resolve(&r, dir, "src/index.ts", "@app/b"),
+        Path::new("src/fallback/b.ts")
+    );
+    assert_eq!(
+        resolve(&r, dir, "src/index.ts", "@app/special"),
+        Path::new("src/special.ts")
+    );
+    assert_eq!(
+        resolve(&r, dir, "src/index.ts", "config"),
+        Path::new("config/index.ts")
+    );
+    // `baseUrl`
+    assert_eq!(
+        resolve(&r, dir, "src/index.ts", "utils"),
+        Path::new("src/utils/index.ts")
+    );
+    // Not mapped
+    assert_eq!(
+        resolve(&r, dir, "src/index.ts", "pkg"),
+        Path::new("node_modules/pkg/index.js")
+    );
+    assert_eq!(
+        resolve(&r, dir, "src/app/a.ts", "../special"),
+        Path::new("src/special.ts")
+    );
+}
+
+#[test]
+fn tsconfig_relative_path() {
+    let dir = write(
+        tempfile::tempdir_in(".").unwrap(),
+        &[
+            (
+                "tsconfig.json",
+                r#"{ "compilerOptions": { "baseUrl": "./src", "paths": { "@app/*": ["app/*"] } } }"#,
+            ),
+            ("src/index.ts", ""),
+            ("src/app/a.ts", ""),
+            ("src/utils/index.ts", ""),
+        ],
+    );
+    let relative = dir
+        .path()
+        .strip_prefix(std::env::current_dir().unwrap())
+        .unwrap();
+    let r =
+        TsConfigResolver::from_file(&relative.join("tsconfig.json"), NodeResolver::new()).unwrap();
+
+    let dir = dir.path().canonicalize().unwrap();
+    assert_eq!(
+        resolve(&r, &dir, "src/index.ts", "@app/a"),
+        Path::new("src/app/a.ts")
+    );
+    assert_eq!(
+        resolve(&r, &dir, "src/index.ts", "utils"),
+        Path::new("src/utils/index.ts")
+    );
+}
@@ -1,12 +1,18 @@
-use crate::config::{GlobalPassOption, JscTarget, ModuleConfig};
+use crate::config::{
+    feature_target, Assumptions, Feature, GlobalPassOption, JscTarget, ModuleConfig,
+};
 use either::Either;
 use std::{collections::HashMap, sync::Arc};
 use swc_atoms::JsWord;
 use swc_common::{chain, comments::Comments, errors::Handler, Mark, SourceMap};
 use swc_ecma_parser::Syntax;
 use swc_ecma_transforms::{
-    compat, const_modules, fixer, helpers, hygiene, modules, pass::Optional,
-    proposals::import_assertions, typescript,
+    compat::{self, es2015, es2016, es2017, es2018, es2020, es3},
+    const_modules, fixer, helpers, hygiene,
+    modules::{self, util::ImportInterop},
+    pass::{fused, Optional},
+    proposals::import_assertions,
+    typescript,
 };
 
 /// Builder is used to create a high performance `Compiler`.
@@ -17,7 +23,11 @@ pub struct PassBuilder<'a, 'b, P: swc_ecma_visit::Fold> {
     pass: P,
     global_mark: Mark,
     target: JscTarget,
+    exclude: Vec<Feature>,
     loose: bool,
+    assumptions: Assumptions,
+    keep_class_names: bool,
+    keep_fn_names: bool,
     hygiene: bool,
     fixer: bool,
     inject_helpers: bool,
@@ -36,8 +46,12 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
             handler,
             pass,
             target: JscTarget::Es5,
+            exclude: vec![],
             global_mark,
             loose,
+            assumptions: Default::default(),
+            keep_class_names: false,
+            keep_fn_names: false,
             hygiene: true,
             env: None,
             fixer: true,
@@ -55,7 +69,11 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
             handler: self.handler,
             pass,
             target: self.target,
+            exclude: self.exclude,
             loose: self.loose,
+            assumptions: self.assumptions,
+            keep_class_names: self.keep_class_names,
+            keep_fn_names: self.keep_fn_names,
             hygiene: self.hygiene,
             env: self.env,
             global_mark: self.global_mark,
@@ -102,6 +120,32 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
         self
     }
 
+    /// Features which are not lowered even if `target` doesn't support them.
+    ///
+    /// Note: This is ignored if `preset_env` is used.
+    pub fn exclude(mut self, features: Vec<Feature>) -> Self {
+        self.exclude = features;
+        self
+    }
+
+    /// Note: all assumptions are made if `loose` is enabled.
+    pub fn assumptions(mut self, assumptions: Assumptions) -> Self {
+        self.assumptions = assumptions;
+        self
+    }
+
+    /// Preserves `.name` of classes while lowering them.
+    pub fn keep_class_names(mut self, keep: bool) -> Self {
+        self.keep_class_names = keep;
+        self
+    }
+
+    /// Preserves `.name` of arrow functions while lowering them.
+    pub fn keep_fn_names(mut self, keep: bool) -> Self {
+        self.keep_fn_names = keep;
+        self
+    }
+
     pub fn preset_env(mut self, env: Option<swc_ecma_preset_env::Config>) -> Self {
         self.env = env;
         self
@@ -128,11 +172,17 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
     where
         P: 'cmt,
     {
-        let need_interop_analysis = match module {
-            Some(ModuleConfig::CommonJs(ref c)) => !c.no_interop,
-            Some(ModuleConfig::Amd(ref c)) => !c.config.no_interop,
-            Some(ModuleConfig::Umd(ref c)) => !c.config.no_interop,
-            Some(ModuleConfig::Es6) | None => false,
+        let import_interop = match module {
+            Some(ModuleConfig::CommonJs(ref c)) => c.import_interop(),
+            Some(ModuleConfig::Amd(ref c)) => c.config.import_interop(),
+            Some(ModuleConfig::Umd(ref c)) => c.config.import_interop(),
+            Some(ModuleConfig::Es6) | None => ImportInterop::None,
+        };
+
+        let assumptions = if self.loose {
+            Assumptions::all()
+        } else {
+            self.assumptions
         };
 
         // compat
@@ -140,34 +190,129 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
             Either::Left(chain!(
                 import_assertions(),
                 Optional::new(typescript::strip(), syntax.typescript()),
-                swc_ecma_preset_env::preset_env(self.global_mark, env)
+                Optional::new(compat::es2015::function_name(), self.keep_class_names),
+                swc_ecma_preset_env::preset_env(self.global_mark, env),
+                Optional::new(compat::es2015::function_name(), self.keep_fn_names)
             ))
         } else {
+            let target = self.target;
+            let exclude = self.exclude;
+            let lower = |feature| match feature_target(feature) {
+                Some(feature_target) => target < feature_target && !exclude.contains(&feature),
+                None => false,
+            };
+
             Either::Right(chain!(
                 import_assertions(),
-                Optional::new(compat::es2020::es2020(), self.target < JscTarget::Es2020),
+                // es2020
+                Optional::new(
+                    es2020::nullish_coalescing(),
+                    lower(Feature::NullishCoalescing)
+                ),
+                Optional::new(
+                    es2020::optional_chaining(),
+                    lower(Feature::OptionalChaining)
+                ),
+                Optional::new(es2020::class_properties(), lower(Feature::ClassProperties)),
+                Optional::new(es2020::export_namespace_from(), target < JscTarget::Es2020),
                 Optional::new(typescript::strip(), syntax.typescript()),
-                Optional::new(compat::es2018(), self.target <= JscTarget::Es2018),
-                Optional::new(compat::es2017(), self.target <= JscTarget::Es2017),
-                Optional::new(compat::es2016(), self.target <= JscTarget::Es2016),
-                Optional::new(
-                    compat::es2015(
-                        self.global_mark,
-                        compat::es2015::Config {
-                            for_of: compat::es2015::for_of::Config {
-                                assume_array: self.loose
-                            },
-                            spread: compat::es2015::spread::Config { loose: self.loose },
-                            destructuring: compat::es2015::destructuring::Config {
-                                loose: self.loose
-                            },
+                // es2018
+                Optional::new(
+                    es2018::object_rest_spread(es2018::object_rest_spread::Config {
+                        // `Object.assign` is supported since es2015.
+                        use_builtins: target >= JscTarget::Es2015,
+                    }),
+                    lower(Feature::ObjectRestSpread)
+                ),
+                Optional::new(
+                    es2018::optional_catch_binding(),
+                    lower(Feature::OptionalCatchBinding)
+                ),
+                // es2017
+                Optional::new(
+                    es2017::async_to_generator(),
+                    lower(Feature::AsyncToGenerator)
+                ),
+                // es2016
+                Optional::new(
+                    es2016::exponentation(),
+                    lower(Feature::ExponentiationOperator)
+                ),
+                // es2015, in the order of `compat::es2015`
+                Optional::new(
+                    es2015::block_scoped_functions(),
+                    lower(Feature::BlockScopedFunctions)
+                ),
+                Optional::new(es2015::template_literal(), lower(Feature::TemplateLiterals)),
+                Optional::new(
+                    es2015::function_name(),
+                    self.keep_class_names && lower(Feature::Classes)
+                ),
+                Optional::new(
+                    es2015::classes(
+                        comments,
+                        es2015::classes::Config {
+                            set_class_methods: assumptions.set_class_methods,
+                            no_class_calls: assumptions.no_class_calls,
                         }
                     ),
-                    self.target <= JscTarget::Es2015
+                    lower(Feature::Classes)
+                ),
+                Optional::new(
+                    es2015::spread(es2015::spread::Config {
+                        loose: assumptions.iterable_is_array,
+                    }),
+                    lower(Feature::Spread)
+                ),
+                Optional::new(es2015::function_name(), lower(Feature::FunctionName)),
+                Optional::new(es2015::arrow(), lower(Feature::ArrowFunctions)),
+                Optional::new(es2015::duplicate_keys(), lower(Feature::DuplicateKeys)),
+                fused(chain!(
+                    Optional::new(es2015::literals(), lower(Feature::Literals)),
+                    Optional::new(es2015::sticky_regex(), lower(Feature::StickyRegex)),
+                    Optional::new(es2015::instance_of(), target < JscTarget::Es2015),
+                    Optional::new(es2015::shorthand(), lower(Feature::ShorthandProperties))
+                )),
+                Optional::new(es2015::typeof_symbol(), lower(Feature::TypeOfSymbol)),
+                Optional::new(
+                    es2015::function_name(),
+                    self.keep_fn_names && lower(Feature::ArrowFunctions)
+                ),
+                Optional::new(
+                    es2015::for_of(es2015::for_of::Config {
+                        assume_array: assumptions.iterable_is_array,
+                    }),
+                    lower(Feature::ForOf)
+                ),
+                Optional::new(
+                    es2015::regenerator(self.global_mark),
+                    lower(Feature::Regenerator)
+                ),
+                Optional::new(es2015::parameters(), lower(Feature::Parameters)),
+                Optional::new(
+                    es2015::computed_properties(es2015::computed_props::Config {
+                        loose: assumptions.set_computed_properties,
+                    }),
+                    lower(Feature::ComputedProperties)
+                ),
+                Optional::new(
+                    es2015::destructuring(es2015::destructuring::Config {
+                        loose: assumptions.iterable_is_array,
+                    }),
+                    lower(Feature::Destructuring)
                 ),
+                Optional::new(es2015::block_scoping(), lower(Feature::BlockScoping)),
+                // es3
+                fused(chain!(
+                    Optional::new(es3::property_literals(), lower(Feature::PropertyLiterals)),
+                    Optional::new(
+                        es3::member_expression_literals(),
+                        lower(Feature::MemberExpressionLiterals)
+                    )
+                )),
                 Optional::new(
-                    compat::es3(syntax.dynamic_import()),
-                    self.target <= JscTarget::Es3
+                    es3::reserved_words(syntax.dynamic_import()),
+                    lower(Feature::ReservedWords)
                 )
             ))
         };
@@ -178,8 +323,8 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
             compat::reserved_words::reserved_words(),
             // module / helper
             Optional::new(
-                modules::import_analysis::import_analyzer(),
-                need_interop_analysis
+                modules::import_analysis::import_analyzer_with_interop(import_interop),
+                import_interop != ImportInterop::None
             ),
             Optional::new(helpers::inject_helpers(), self.inject_helpers),
             ModuleConfig::build(self.cm.clone(), self.global_mark, module),
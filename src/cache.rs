@@ -0,0 +1,202 @@
+//! Cache for transformed files.
+//!
+//! Entries are keyed by the hash of the source file and the hash of the
+//! version of swc and the options used to process it, so an entry is reused
+//! only if all of them are unchanged.
+
+use crate::{
+    config::{Config, Options},
+    TransformOutput,
+};
+use anyhow::{Context, Error};
+use dashmap::DashMap;
+use sha1::{Digest, Sha1};
+use std::{
+    fmt::{self, Display, Formatter},
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use swc_common::SourceFile;
+
+/// Outputs cached by other versions of swc are never reused.
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    source: [u8; 20],
+    options: [u8; 20],
+}
+
+impl CacheKey {
+    /// `config` should be the config resolved for `fm`, as `.swcrc` files are
+    /// not part of `opts`.
+    pub fn new(fm: &SourceFile, opts: &Options, config: &Config) -> Self {
+        let mut source = Sha1::new();
+        // The name of the file is stored in the source map.
+        source.update(fm.name.to_string().as_bytes());
+        source.update([0]);
+        source.update(fm.src.as_bytes());
+
+        let mut options = Sha1::new();
+        options.update(VERSION.as_bytes());
+        options.update(
+            format!(
+                "{:?}",
+                Options {
+                    config: None,
+                    ..opts.clone()
+                }
+            )
+            .as_bytes(),
+        );
+        if let Some(config) = &opts.config {
+            hash_config(&mut options, config);
+        }
+        options.update([0]);
+        hash_config(&mut options, config);
+
+        CacheKey {
+            source: source.finalize().into(),
+            options: options.finalize().into(),
+        }
+    }
+}
+
+/// Hashes `config` as json, as maps are printed in arbitrary order by
+/// `Debug`. Keys of json objects are sorted.
+///
+/// Options which can only be set from rust are not serialized, so they are
+/// hashed separately.
+fn hash_config(hasher: &mut Sha1, config: &Config) {
+    let json = serde_json::to_value(config).expect("failed to serialize config");
+    hasher.update(json.to_string().as_bytes());
+
+    hasher.update(format!("{:?}", config.jsc.syntax).as_bytes());
+    if let Some(transform) = &config.jsc.transform {
+        hasher.update(format!("{:?}", transform.react).as_bytes());
+    }
+}
+
+impl Display for CacheKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for b in self.source.iter().chain(self.options.iter()) {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+/// In-memory cache of transformed files, optionally persisted to a
+/// directory.
+///
+/// The cache can be shared between threads.
+#[derive(Default)]
+pub struct Cache {
+    entries: DashMap<CacheKey, TransformOutput>,
+    dir: Option<PathBuf>,
+}
+
+impl Cache {
+    /// Creates a cache which is stored only in memory.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a cache which also stores entries in `dir`, so they can be
+    /// reused by other processes.
+    pub fn with_dir(dir: impl Into<PathBuf>) -> Self {
+        Cache {
+            entries: Default::default(),
+            dir: Some(dir.into()),
+        }
+    }
+
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+
+    pub fn get(&self, key: &CacheKey) -> Option<TransformOutput> {
+        if let Some(output) = self.entries.get(key) {
+            return Some(output.clone());
+        }
+
+        let path = self.path_of(key)?;
+        let output: TransformOutput = match File::open(&path) {
+            Ok(file) => match serde_json::from_reader(file) {
+                Ok(output) => output,
+                Err(err) => {
+                    log::debug!("ignoring invalid cache entry {}: {}", path.display(), err);
+                    return None;
+                }
+            },
+            Err(..) => return None,
+        };
+
+        self.entries.insert(*key, output.clone());
+        Some(output)
+    }
+
+    pub fn insert(&self, key: CacheKey, output: TransformOutput) -> Result<(), Error> {
+        if let Some(path) = self.path_of(&key) {
+            write_entry(&path, &output)
+                .with_context(|| format!("failed to write cache entry {}", path.display()))?;
+        }
+
+        self.entries.insert(key, output);
+        Ok(())
+    }
+
+    /// Number of entries in memory.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all entries, including the ones stored on disk.
+    pub fn clear(&self) -> Result<(), Error> {
+        self.entries.clear();
+
+        if let Some(dir) = &self.dir {
+            if dir.exists() {
+                fs::remove_dir_all(dir)
+                    .with_context(|| format!("failed to remove {}", dir.display()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn path_of(&self, key: &CacheKey) -> Option<PathBuf> {
+        self.dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{}.json", key)))
+    }
+}
+
+fn write_entry(path: &Path, output: &TransformOutput) -> Result<(), Error> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    // Write to a temporary file first, so other processes never see a
+    // partially written entry.
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let tmp = path.with_extension(format!(
+        "{}-{}.tmp",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    {
+        let mut file = File::create(&tmp)?;
+        serde_json::to_writer(&mut file, output)?;
+        file.flush()?;
+    }
+    fs::rename(&tmp, path)?;
+
+    Ok(())
+}
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use std::{
     collections::{HashMap, HashSet},
     env,
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
     sync::Arc,
     usize,
 };
@@ -18,17 +18,23 @@ use swc_common::{comments::Comments, errors::Handler, FileName, Mark, SourceMap}
 use swc_ecma_ast::{Expr, ExprStmt, ModuleItem, Stmt};
 use swc_ecma_ext_transforms::jest;
 pub use swc_ecma_parser::JscTarget;
-use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
+use swc_ecma_parser::{lexer::Lexer, EsConfig, Parser, StringInput, Syntax, TsConfig};
+pub use swc_ecma_preset_env::Feature;
+pub use swc_ecma_transforms::util::WarningsMode;
 use swc_ecma_transforms::{
     compat::es2020::typescript_class_properties,
     const_modules, modules,
     optimization::{inline_globals, json_parse, simplifier},
     pass::{noop, Optional},
-    proposals::{decorators, export_default_from},
+    proposals::{
+        decorators, do_expressions, export_default_from, function_bind, partial_application,
+        pipeline_operator, record_and_tuple, throw_expressions,
+    },
     react, resolver_with_mark, typescript,
 };
 use swc_ecma_visit::Fold;
 
+mod json;
 #[cfg(test)]
 mod tests;
 
@@ -110,12 +116,20 @@ pub struct Options {
     #[serde(default)]
     pub source_maps: Option<SourceMapsConfig>,
 
+    /// Name of the source file in the source map.
     #[serde(default)]
     pub source_file_name: Option<String>,
 
+    /// `sourceRoot` of the source map.
     #[serde(default)]
     pub source_root: Option<String>,
 
+    /// Embeds the source code in the source map as `sourcesContent`.
+    ///
+    /// Defaults to `true`.
+    #[serde(default)]
+    pub inline_sources_content: Option<bool>,
+
     #[serde(default = "default_is_module")]
     pub is_module: bool,
 }
@@ -137,13 +151,35 @@ impl SourceMapsConfig {
         match *self {
             SourceMapsConfig::Bool(b) => b,
             SourceMapsConfig::Str(ref s) => {
-                assert_eq!(s, "inline", "Source map must be true, false or inline");
+                assert!(
+                    s == "inline" || s == "both",
+                    "Source map must be true, false, inline or both"
+                );
                 true
             }
         }
     }
 }
 
+/// Content of the source map generated by swc.
+#[derive(Debug, Clone)]
+pub struct SourceMapOptions {
+    /// Replaces the name of the source file, if there is only one.
+    pub source_file_name: Option<String>,
+    pub source_root: Option<String>,
+    pub inline_sources_content: bool,
+}
+
+impl Default for SourceMapOptions {
+    fn default() -> Self {
+        SourceMapOptions {
+            source_file_name: None,
+            source_root: None,
+            inline_sources_content: true,
+        }
+    }
+}
+
 impl Default for SourceMapsConfig {
     fn default() -> Self {
         SourceMapsConfig::Bool(true)
@@ -164,29 +200,56 @@ impl Default for InputSourceMap {
 }
 
 impl Options {
+    pub fn source_map_options(&self) -> SourceMapOptions {
+        SourceMapOptions {
+            source_file_name: self.source_file_name.clone(),
+            source_root: self.source_root.clone(),
+            inline_sources_content: self.inline_sources_content.unwrap_or(true),
+        }
+    }
+
+    /// `name` is used to determine the syntax if `jsc.parser` is not
+    /// specified. [Options::filename] is used instead if `name` is not a
+    /// real file.
+    ///
+    /// Returns an error if `jsc.transform.order` is invalid.
     pub fn build<'a>(
         &self,
         cm: &Arc<SourceMap>,
         handler: &Handler,
+        name: &FileName,
         is_module: bool,
         config: Option<Config>,
         comments: Option<&'a dyn Comments>,
-    ) -> BuiltConfig<impl 'a + swc_ecma_visit::Fold> {
+    ) -> Result<BuiltConfig<impl 'a + swc_ecma_visit::Fold>, Error> {
         let mut config = config.unwrap_or_else(Default::default);
         if let Some(ref c) = self.config {
             config.merge(c)
         }
 
+        let path = match name {
+            FileName::Real(path) => Some(path.as_path()),
+            _ if !self.filename.is_empty() => Some(Path::new(&self.filename)),
+            _ => None,
+        };
+        let syntax = config.jsc.syntax_for(path);
+
         let JscConfig {
             transform,
-            syntax,
+            syntax: _,
+            parser_by_extension: _,
             external_helpers,
             target,
             loose,
+            assumptions,
+            keep_class_names,
+            keep_fn_names,
+            warnings,
+            exclude,
         } = config.jsc;
 
-        let syntax = syntax.unwrap_or_default();
         let mut transform = transform.unwrap_or_default();
+        let directives = transform.directives.unwrap_or_default();
 
         if syntax.typescript() {
             transform.legacy_decorator = true;
@@ -202,6 +265,13 @@ impl Options {
             Optional::new(const_modules(cm.clone(), globals), enabled)
         };
 
+        let record_and_tuple = {
+            let enabled = syntax.record_and_tuple() && transform.record_and_tuple.is_some();
+            let config = transform.record_and_tuple.unwrap_or_default();
+
+            Optional::new(record_and_tuple(config), enabled)
+        };
+
         let json_parse_pass = {
             if let Some(ref cfg) = optimizer.as_ref().and_then(|v| v.jsonify) {
                 Either::Left(json_parse(cfg.min_cost))
@@ -221,36 +291,75 @@ impl Options {
             pass
         };
 
+        // Use `Object.assign` instead of the `_extends` helper if all targets
+        // support it, which is the case since es2015.
+        transform.react.use_builtins |= match &config.env {
+            Some(env) => env.supports_builtin("es.object.assign"),
+            None => target >= JscTarget::Es2015,
+        };
+
+        let mut strip_config = typescript::strip::Config::default();
+        strip_config.isolated_modules = transform.isolated_modules;
+        strip_config.const_enums = const_enum_specifiers(path, transform.const_enums)
+            .into_iter()
+            .collect();
+        strip_config.pragma = Some(transform.react.pragma.clone());
+        strip_config.pragma_frag = Some(transform.react.pragma_frag.clone());
+
         let root_mark = self
             .global_mark
             .unwrap_or_else(|| Mark::fresh(Mark::root()));
 
-        let pass = chain!(
-            // handle jsx
-            Optional::new(
-                react::react(cm.clone(), comments, transform.react),
-                syntax.jsx()
-            ),
-            // Decorators may use type information
-            Optional::new(
-                decorators(decorators::Config {
-                    legacy: transform.legacy_decorator,
-                    emit_metadata: transform.decorator_metadata,
-                }),
-                syntax.decorators()
-            ),
+        let order = transform_order(&transform.order, transform.decorator_metadata)?;
+        let mut jsx = Some(Optional::new(
+            react::react(cm.clone(), comments, transform.react),
+            syntax.jsx(),
+        ));
+        let mut decorators = Some(Optional::new(
+            decorators(decorators::Config {
+                legacy: transform.legacy_decorator,
+                emit_metadata: transform.decorator_metadata,
+            }),
+            syntax.decorators(),
+        ));
+        let mut typescript = Some(chain!(
             Optional::new(typescript_class_properties(), syntax.typescript()),
-            Optional::new(typescript::strip(), syntax.typescript()),
+            Optional::new(
+                typescript::strip::strip_with_config(strip_config),
+                syntax.typescript()
+            )
+        ));
+        let mut stages: Box<dyn 'a + swc_ecma_visit::Fold> = Box::new(noop());
+        for stage in order {
+            stages = match stage {
+                TransformStage::Jsx => Box::new(chain!(stages, jsx.take().unwrap())),
+                TransformStage::Decorators => Box::new(chain!(stages, decorators.take().unwrap())),
+                TransformStage::Typescript => Box::new(chain!(stages, typescript.take().unwrap())),
+            };
+        }
+
+        let pass = chain!(
+            stages,
             resolver_with_mark(root_mark),
             const_modules,
             optimization,
             Optional::new(export_default_from(), syntax.export_default_from()),
+            Optional::new(partial_application(), syntax.partial_application()),
+            Optional::new(pipeline_operator(), syntax.pipeline_operator()),
+            Optional::new(do_expressions(), syntax.do_expressions()),
+            Optional::new(function_bind(), syntax.fn_bind()),
+            Optional::new(throw_expressions(), syntax.throw_expressions()),
+            record_and_tuple,
             Optional::new(simplifier(Default::default()), enable_optimizer),
             json_parse_pass
         );
 
         let pass = PassBuilder::new(&cm, &handler, loose, root_mark, pass)
             .target(target)
+            .exclude(exclude)
+            .assumptions(assumptions)
+            .keep_class_names(keep_class_names)
+            .keep_fn_names(keep_fn_names)
             .skip_helper_injection(self.skip_helper_injection)
             .hygiene(!self.disable_hygiene)
             .fixer(!self.disable_fixer)
@@ -259,7 +368,7 @@ impl Options {
 
         let pass = chain!(pass, Optional::new(jest::jest(), transform.hidden.jest));
 
-        BuiltConfig {
+        Ok(BuiltConfig {
             minify: config.minify.unwrap_or(false),
             pass,
             external_helpers,
@@ -270,9 +379,140 @@ impl Options {
                 .source_maps
                 .clone()
                 .unwrap_or(SourceMapsConfig::Bool(false)),
+            source_map_options: self.source_map_options(),
             input_source_map: self.input_source_map.clone(),
+            warnings: warnings.unwrap_or_default(),
+            directives,
+        })
+    }
+}
+
+/// Returns the order of all stages, with the ones not in `order` appended in
+/// the default order.
+fn transform_order(
+    order: &[TransformStage],
+    decorator_metadata: bool,
+) -> Result<Vec<TransformStage>, Error> {
+    let mut stages = vec![];
+    for &stage in order {
+        if stages.contains(&stage) {
+            bail!(
+                "jsc.transform.order: `{:?}` is specified more than once",
+                stage
+            )
+        }
+        stages.push(stage);
+    }
+    for &stage in TransformStage::DEFAULT_ORDER {
+        if !stages.contains(&stage) {
+            stages.push(stage);
+        }
+    }
+
+    let position = |stage| stages.iter().position(|&s| s == stage);
+    if decorator_metadata
+        && position(TransformStage::Typescript) < position(TransformStage::Decorators)
+    {
+        bail!(
+            "jsc.transform.order: decorators should run before typescript to emit metadata, as \
+             types are removed by typescript"
+        )
+    }
+
+    Ok(stages)
+}
+
+/// Converts keys of [TransformConfig::const_enums] to the import specifiers
+/// of the modules from the file at `path`.
+///
+/// Paths are resolved from the working directory. Other keys are package
+/// names, which are used as-is.
+fn const_enum_specifiers(
+    path: Option<&Path>,
+    const_enums: HashMap<JsWord, typescript::strip::ConstEnums>,
+) -> Vec<(JsWord, typescript::strip::ConstEnums)> {
+    let cwd = env::current_dir().unwrap_or_default();
+    let dir = normalize_path(&cwd.join(path.and_then(Path::parent).unwrap_or(Path::new(""))));
+
+    let mut specifiers = vec![];
+    for (key, values) in const_enums {
+        let module = Path::new(&*key);
+        if !key.starts_with('.') && !module.is_absolute() {
+            specifiers.push((key, values));
+            continue;
+        }
+
+        let module = normalize_path(&cwd.join(module));
+        for specifier in module_specifiers(&relative_specifier(&dir, &module)) {
+            specifiers.push((specifier.into(), values.clone()));
+        }
+    }
+    specifiers
+}
+
+/// Removes `.` and `..` from `path` without accessing the file system.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for c in path.components() {
+        match c {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            c => normalized.push(c),
+        }
+    }
+    normalized
+}
+
+/// Returns a relative import specifier of `module` from `dir`, like
+/// `../enums.ts`.
+fn relative_specifier(dir: &Path, module: &Path) -> String {
+    let dir = dir.components().collect::<Vec<_>>();
+    let module = module.components().collect::<Vec<_>>();
+    let common = dir.iter().zip(&module).take_while(|(a, b)| a == b).count();
+
+    let mut parts = vec![String::from(".."); dir.len() - common];
+    parts.extend(
+        module[common..]
+            .iter()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned()),
+    );
+
+    let specifier = parts.join("/");
+    if specifier.starts_with("..") {
+        specifier
+    } else {
+        format!("./{}", specifier)
+    }
+}
+
+/// Returns specifiers which can be used to import the module at
+/// `specifier`, which may omit the extension or use `.js` instead of it.
+fn module_specifiers(specifier: &str) -> Vec<String> {
+    let base = match specifier.strip_suffix(".d.ts") {
+        Some(base) => base,
+        None => match Path::new(specifier)
+            .extension()
+            .and_then(|ext| ext.to_str())
+        {
+            Some(ext) => &specifier[..specifier.len() - ext.len() - 1],
+            None => specifier,
+        },
+    };
+
+    let mut specifiers = vec![specifier.to_string()];
+    let mut add = |s: String| {
+        if !specifiers.contains(&s) {
+            specifiers.push(s);
         }
+    };
+    add(base.to_string());
+    add(format!("{}.js", base));
+    if let Some(dir) = base.strip_suffix("/index") {
+        add(dir.to_string());
     }
+    specifiers
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -326,58 +566,30 @@ pub enum Rc {
     Multi(Vec<Config>),
 }
 
+impl Rc {
+    /// Parses the content of a `.swcrc` file.
+    ///
+    /// Comments and trailing commas are allowed. Errors contain the path of
+    /// the invalid value.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let s = json::strip(s);
+
+        // `Rc` is untagged, so deserializing it directly would lose the error
+        // of the variant.
+        let res = if s.trim_start().starts_with('[') {
+            serde_json::from_str(&s).map(Rc::Multi)
+        } else {
+            serde_json::from_str(&s).map(Rc::Single)
+        };
+
+        res.map_err(|err| json::convert_err(&s, err))
+    }
+}
+
 impl Default for Rc {
     fn default() -> Self {
-        Rc::Multi(vec![
-            Config {
-                env: None,
-                test: None,
-                exclude: Some(FileMatcher::Regex("\\.tsx?$".into())),
-                jsc: JscConfig {
-                    syntax: Some(Default::default()),
-                    transform: None,
-                    external_helpers: false,
-                    target: Default::default(),
-                    loose: false,
-                },
-                module: None,
-                minify: None,
-            },
-            Config {
-                env: None,
-                test: Some(FileMatcher::Regex("\\.tsx$".into())),
-                exclude: None,
-                jsc: JscConfig {
-                    syntax: Some(Syntax::Typescript(TsConfig {
-                        tsx: true,
-                        ..Default::default()
-                    })),
-                    transform: None,
-                    external_helpers: false,
-                    target: Default::default(),
-                    loose: false,
-                },
-                module: None,
-                minify: None,
-            },
-            Config {
-                env: None,
-                test: Some(FileMatcher::Regex("\\.ts$".into())),
-                exclude: None,
-                jsc: JscConfig {
-                    syntax: Some(Syntax::Typescript(TsConfig {
-                        tsx: false,
-                        ..Default::default()
-                    })),
-                    transform: None,
-                    external_helpers: false,
-                    target: Default::default(),
-                    loose: false,
-                },
-                module: None,
-                minify: None,
-            },
-        ])
+        // The syntax is determined by the extension of files.
+        Rc::Single(Default::default())
     }
 }
 
@@ -415,7 +627,7 @@ impl Rc {
 }
 
 /// A single object in the `.swcrc` file
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Config {
     #[serde(default)]
@@ -502,6 +714,52 @@ impl Config {
     }
 }
 
+/// Returns the first version of ecmascript which supports the syntax lowered
+/// by `feature`.
+///
+/// Features are lowered if `jsc.target` is older than this, and `None` is
+/// returned for features which are only supported by `env`.
+pub fn feature_target(feature: Feature) -> Option<JscTarget> {
+    Some(match feature {
+        Feature::PropertyLiterals | Feature::MemberExpressionLiterals | Feature::ReservedWords => {
+            JscTarget::Es5
+        }
+
+        Feature::TemplateLiterals
+        | Feature::Literals
+        | Feature::FunctionName
+        | Feature::ArrowFunctions
+        | Feature::BlockScopedFunctions
+        | Feature::Classes
+        | Feature::ShorthandProperties
+        | Feature::DuplicateKeys
+        | Feature::ComputedProperties
+        | Feature::ForOf
+        | Feature::StickyRegex
+        | Feature::Spread
+        | Feature::Parameters
+        | Feature::Destructuring
+        | Feature::BlockScoping
+        | Feature::TypeOfSymbol
+        | Feature::Regenerator => JscTarget::Es2015,
+
+        Feature::ExponentiationOperator => JscTarget::Es2016,
+
+        Feature::AsyncToGenerator => JscTarget::Es2017,
+
+        Feature::ObjectRestSpread => JscTarget::Es2018,
+
+        Feature::OptionalCatchBinding => JscTarget::Es2019,
+
+        // Class properties are lowered for all targets before es2020.
+        Feature::NullishCoalescing | Feature::OptionalChaining | Feature::ClassProperties => {
+            JscTarget::Es2020
+        }
+
+        _ => return None,
+    })
+}
+
 /// One `BuiltConfig` per a directory with swcrc
 pub struct BuiltConfig<P: swc_ecma_visit::Fold> {
     pub pass: P,
@@ -510,8 +768,11 @@ pub struct BuiltConfig<P: swc_ecma_visit::Fold> {
     pub minify: bool,
     pub external_helpers: bool,
     pub source_maps: SourceMapsConfig,
+    pub source_map_options: SourceMapOptions,
     pub input_source_map: InputSourceMap,
     pub is_module: bool,
+    pub warnings: WarningsMode,
+    pub directives: DirectivesMode,
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -520,6 +781,12 @@ pub struct JscConfig {
     #[serde(rename = "parser", default)]
     pub syntax: Option<Syntax>,
 
+    /// Syntax of files with an extension, like `{ "js": { "syntax":
+    /// "ecmascript", "jsx": true } }`. These override the syntax inferred
+    /// from the extension, but not `parser`.
+    #[serde(default)]
+    pub parser_by_extension: HashMap<String, Syntax>,
+
     #[serde(default)]
     pub transform: Option<TransformConfig>,
 
@@ -529,8 +796,107 @@ pub struct JscConfig {
     #[serde(default)]
     pub target: JscTarget,
 
+    /// Trades spec compliance for smaller and faster output. This enables all
+    /// of `assumptions`.
     #[serde(default)]
     pub loose: bool,
+
+    #[serde(default)]
+    pub assumptions: Assumptions,
+
+    /// Preserves `.name` of classes while lowering them.
+    #[serde(default)]
+    pub keep_class_names: bool,
+
+    /// Preserves `.name` of arrow functions while lowering them.
+    #[serde(default)]
+    pub keep_fn_names: bool,
+
+    /// How warnings of transforms are reported. Defaults to `warn`.
+    #[serde(default)]
+    pub warnings: Option<WarningsMode>,
+
+    /// Features which are not lowered even if `target` doesn't support them,
+    /// like `["transform-classes", "transform-async-to-generator"]`.
+    ///
+    /// This is ignored if `env` is used, as it has its own `exclude`.
+    #[serde(default)]
+    pub exclude: Vec<Feature>,
+}
+
+impl JscConfig {
+    /// Returns the syntax of the file at `path`.
+    ///
+    /// If `parser` is not specified, the syntax is determined by the
+    /// extension of `path`.
+    pub fn syntax_for(&self, path: Option<&Path>) -> Syntax {
+        if let Some(syntax) = self.syntax {
+            return syntax;
+        }
+
+        let path = match path {
+            Some(path) => path,
+            None => return Default::default(),
+        };
+        let ext = match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) => ext,
+            None => return Default::default(),
+        };
+
+        if let Some(syntax) = self.parser_by_extension.get(ext) {
+            return *syntax;
+        }
+
+        match ext {
+            "jsx" => Syntax::Es(EsConfig {
+                jsx: true,
+                ..Default::default()
+            }),
+            "ts" | "mts" | "cts" => Syntax::Typescript(TsConfig {
+                dts: path.to_string_lossy().ends_with(".d.ts"),
+                ..Default::default()
+            }),
+            "tsx" => Syntax::Typescript(TsConfig {
+                tsx: true,
+                ..Default::default()
+            }),
+            _ => Default::default(),
+        }
+    }
+}
+
+/// Assumptions about the code which allow compat passes to emit smaller and
+/// faster output, like babel's `assumptions`.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Assumptions {
+    /// Iterables used in spread, `for-of` and array destructuring are arrays.
+    #[serde(default)]
+    pub iterable_is_array: bool,
+
+    /// Class methods can be assigned to the prototype.
+    #[serde(default)]
+    pub set_class_methods: bool,
+
+    /// Classes are never called without `new`.
+    #[serde(default)]
+    pub no_class_calls: bool,
+
+    /// Computed properties can be assigned instead of defined.
+    #[serde(default)]
+    pub set_computed_properties: bool,
+}
+
+impl Assumptions {
+    /// All assumptions, which is what `loose` enables.
+    pub fn all() -> Self {
+        Assumptions {
+            iterable_is_array: true,
+            set_class_methods: true,
+            no_class_calls: true,
+            set_computed_properties: true,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -582,10 +948,79 @@ pub struct TransformConfig {
     #[serde(default)]
     pub decorator_metadata: bool,
 
+    /// Lowers record and tuple literals. They can't be emitted as-is.
+    #[serde(default)]
+    pub record_and_tuple: Option<record_and_tuple::Config>,
+
+    /// Reports typescript constructs which can't be compiled per file.
+    #[serde(default)]
+    pub isolated_modules: bool,
+
+    /// Values of const enums exported by other modules, keyed by paths of the
+    /// modules relative to the working directory, like `./src/enums.ts`, or
+    /// by package names. Members of them are inlined.
+    #[serde(default)]
+    pub const_enums: HashMap<JsWord, typescript::strip::ConstEnums>,
+
+    /// Order of transforms which can be reordered, like
+    /// `["typescript", "decorators", "jsx"]`. Transforms which are not listed
+    /// run after the listed ones, in the default order.
+    #[serde(default)]
+    pub order: Vec<TransformStage>,
+
+    /// How `"use client"` and `"use server"` directives of react server
+    /// components are handled. Defaults to `preserve`.
+    #[serde(default)]
+    pub directives: Option<DirectivesMode>,
+
     #[serde(default)]
     pub hidden: HiddenTransformConfig,
 }
 
+/// How `"use client"` and `"use server"` directives are handled.
+///
+/// Directives of the input are reported in
+/// [TransformOutput::directives](crate::TransformOutput::directives)
+/// regardless of the mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DirectivesMode {
+    /// Keeps the directives at the top of the output, even if transforms
+    /// insert imports or helpers.
+    Preserve,
+    /// Removes the directives from the output.
+    Strip,
+    /// Same as `preserve`, but reports an error if one of them is not at the
+    /// top of the file, or both of them are used in a file.
+    Enforce,
+}
+
+impl Default for DirectivesMode {
+    fn default() -> Self {
+        DirectivesMode::Preserve
+    }
+}
+
+/// Transforms which can be reordered with `jsc.transform.order`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransformStage {
+    Jsx,
+    /// Decorators may use type information, so they run before typescript by
+    /// default.
+    Decorators,
+    /// Strips types and lowers class properties of typescript.
+    Typescript,
+}
+
+impl TransformStage {
+    pub const DEFAULT_ORDER: &'static [TransformStage] = &[
+        TransformStage::Jsx,
+        TransformStage::Decorators,
+        TransformStage::Typescript,
+    ];
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct HiddenTransformConfig {
@@ -757,9 +1192,39 @@ impl Merge for swc_ecma_preset_env::Config {
 impl Merge for JscConfig {
     fn merge(&mut self, from: &Self) {
         self.syntax.merge(&from.syntax);
+        self.parser_by_extension
+            .extend(from.parser_by_extension.clone());
         self.transform.merge(&from.transform);
         self.target.merge(&from.target);
         self.external_helpers.merge(&from.external_helpers);
+        self.loose.merge(&from.loose);
+        self.assumptions.merge(&from.assumptions);
+        self.keep_class_names.merge(&from.keep_class_names);
+        self.keep_fn_names.merge(&from.keep_fn_names);
+        self.warnings.merge(&from.warnings);
+        self.exclude.extend(from.exclude.iter().copied());
+    }
+}
+
+impl Merge for Assumptions {
+    fn merge(&mut self, from: &Self) {
+        self.iterable_is_array.merge(&from.iterable_is_array);
+        self.set_class_methods.merge(&from.set_class_methods);
+        self.no_class_calls.merge(&from.no_class_calls);
+        self.set_computed_properties
+            .merge(&from.set_computed_properties);
+    }
+}
+
+impl Merge for WarningsMode {
+    fn merge(&mut self, from: &Self) {
+        *self = *from;
+    }
+}
+
+impl Merge for DirectivesMode {
+    fn merge(&mut self, from: &Self) {
+        *self = *from;
     }
 }
 
@@ -797,6 +1262,16 @@ impl Merge for TransformConfig {
         self.optimizer.merge(&from.optimizer);
         self.const_modules.merge(&from.const_modules);
         self.react.merge(&from.react);
+        self.record_and_tuple.merge(&from.record_and_tuple);
+        if !from.order.is_empty() {
+            self.order = from.order.clone();
+        }
+        self.directives.merge(&from.directives);
+        for (k, v) in &from.const_enums {
+            self.const_enums
+                .entry(k.clone())
+                .or_insert_with(|| v.clone());
+        }
     }
 }
 
@@ -823,3 +1298,9 @@ impl Merge for ConstModulesConfig {
         *self = from.clone()
     }
 }
+
+impl Merge for record_and_tuple::Config {
+    fn merge(&mut self, from: &Self) {
+        *self = from.clone()
+    }
+}
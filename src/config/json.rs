@@ -0,0 +1,213 @@
+//! Lenient parsing and error reporting for `.swcrc` files.
+
+use anyhow::Error;
+
+/// Adds the path of the invalid value to a deserialization error, and
+/// suggests a field name if the error is caused by a misspelled field.
+pub(super) fn convert_err(src: &str, err: serde_json::Error) -> Error {
+    let line = err.line();
+    let column = err.column();
+
+    let msg = err.to_string();
+    // The position is reported separately.
+    let mut msg = match msg.rfind(" at line ") {
+        Some(pos) => msg[..pos].to_string(),
+        None => msg,
+    };
+    if let Some(suggestion) = suggest(&msg) {
+        msg.push_str(&format!(", did you mean `{}`?", suggestion));
+    }
+
+    let path = path_at(src, line, column);
+    let msg = if path.is_empty() {
+        format!(
+            "failed to deserialize .swcrc (json) file: {}: {}:{}",
+            msg, line, column
+        )
+    } else {
+        format!(
+            "failed to deserialize .swcrc (json) file: {}: {}: {}:{}",
+            path, msg, line, column
+        )
+    };
+
+    Error::new(err).context(msg)
+}
+
+/// Finds the most similar field for `unknown field` errors.
+fn suggest(msg: &str) -> Option<&str> {
+    let rest = msg.strip_prefix("unknown field `")?;
+    let end = rest.find('`')?;
+    let (field, expected) = (&rest[..end], &rest[end + 1..]);
+
+    expected
+        .split('`')
+        .skip(1)
+        .step_by(2)
+        .map(|candidate| (strsim::jaro_winkler(field, candidate), candidate))
+        .filter(|&(score, _)| score > 0.8)
+        .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+        .map(|(_, candidate)| candidate)
+}
+
+enum Frame {
+    Object {
+        key: Option<String>,
+        expect_key: bool,
+    },
+    Array {
+        index: usize,
+    },
+}
+
+/// Returns the path of the value at `line` and `column`, like
+/// `jsc.transform.react.pragma` or `[1].jsc`.
+///
+/// `line` and `column` are one-based, as reported by `serde_json`.
+fn path_at(src: &str, line: usize, column: usize) -> String {
+    let end = src
+        .split('\n')
+        .take(line.saturating_sub(1))
+        .map(|l| l.len() + 1)
+        .sum::<usize>()
+        + column;
+    let mut stack = vec![];
+    let mut chars = src
+        .char_indices()
+        .take_while(|&(i, _)| i < end)
+        .map(|(_, c)| c);
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                let mut s = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => {
+                            chars.next();
+                        }
+                        _ => s.push(c),
+                    }
+                }
+
+                if let Some(Frame::Object { key, expect_key }) = stack.last_mut() {
+                    if *expect_key {
+                        *key = Some(s);
+                        *expect_key = false;
+                    }
+                }
+            }
+            '{' => stack.push(Frame::Object {
+                key: None,
+                expect_key: true,
+            }),
+            '[' => stack.push(Frame::Array { index: 0 }),
+            '}' | ']' => {
+                stack.pop();
+            }
+            ',' => match stack.last_mut() {
+                Some(Frame::Object { key, expect_key }) => {
+                    *key = None;
+                    *expect_key = true;
+                }
+                Some(Frame::Array { index }) => *index += 1,
+                None => {}
+            },
+            _ => {}
+        }
+    }
+
+    let mut path = String::new();
+    for frame in &stack {
+        match frame {
+            Frame::Object { key: Some(key), .. } => {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(key);
+            }
+            Frame::Object { key: None, .. } => {}
+            Frame::Array { index } => path.push_str(&format!("[{}]", index)),
+        }
+    }
+    path
+}
+
+/// Replaces comments and trailing commas with whitespace, so the result can
+/// be parsed as strict json.
+///
+/// Line breaks are kept and other characters are replaced with spaces, so
+/// positions in errors are still valid for `src`.
+pub(super) fn strip(src: &str) -> String {
+    fn blank(out: &mut String, c: char) {
+        if c == '\n' || c == '\r' {
+            out.push(c);
+        } else {
+            out.extend(std::iter::repeat(' ').take(c.len_utf8()));
+        }
+    }
+
+    let mut out = String::with_capacity(src.len());
+    // Position of the last comma in `out`, if only whitespace or comments are
+    // after it.
+    let mut comma = None;
+    let mut chars = src.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                comma = None;
+                out.push(c);
+                while let Some(c) = chars.next() {
+                    out.push(c);
+                    match c {
+                        '"' => break,
+                        '\\' => out.extend(chars.next()),
+                        _ => {}
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                blank(&mut out, c);
+                while let Some(&c) = chars.peek() {
+                    if c == '\n' {
+                        break;
+                    }
+                    blank(&mut out, c);
+                    chars.next();
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                blank(&mut out, c);
+                blank(&mut out, chars.next().unwrap());
+                let mut prev = None;
+                for c in chars.by_ref() {
+                    blank(&mut out, c);
+                    if prev == Some('*') && c == '/' {
+                        break;
+                    }
+                    prev = Some(c);
+                }
+            }
+            ',' => {
+                comma = Some(out.len());
+                out.push(c);
+            }
+            '}' | ']' => {
+                if let Some(pos) = comma.take() {
+                    out.replace_range(pos..pos + 1, " ");
+                }
+                out.push(c);
+            }
+            _ => {
+                if !c.is_whitespace() {
+                    comma = None;
+                }
+                out.push(c);
+            }
+        }
+    }
+
+    out
+}
@@ -10,3 +10,137 @@ fn object() {
 fn array() {
     let _: Rc = serde_json::from_str(include_str!("array.json")).expect("failed to parse");
 }
+
+#[test]
+fn unknown_field() {
+    let err = Rc::parse(
+        r#"{
+            "jsc": {
+                "transform": {
+                    "react": {
+                        "pragmaa": "h"
+                    }
+                }
+            }
+        }"#,
+    )
+    .unwrap_err();
+
+    let msg = err.to_string();
+    assert!(
+        msg.contains("jsc.transform.react.pragmaa: unknown field `pragmaa`"),
+        "{}",
+        msg
+    );
+    assert!(msg.contains("did you mean `pragma`?"), "{}", msg);
+}
+
+#[test]
+fn invalid_type() {
+    let err = Rc::parse(r#"[{}, { "jsc": { "transform": {}, "loose": 1 } }]"#).unwrap_err();
+
+    let msg = err.to_string();
+    assert!(
+        msg.contains("[1].jsc.loose: invalid type: integer `1`"),
+        "{}",
+        msg
+    );
+}
+
+#[test]
+fn comments() {
+    let rc = Rc::parse(
+        r#"{
+            // Line comment
+            "jsc": {
+                /* Block comment with "quotes" */
+                "parser": {
+                    "syntax": "ecmascript", // Trailing comment
+                    "jsx": true,
+                },
+                "target": "es2016",
+            },
+        }"#,
+    )
+    .unwrap();
+
+    match rc {
+        Rc::Single(config) => assert_eq!(config.jsc.target, super::JscTarget::Es2016),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn comments_in_strings() {
+    let rc = Rc::parse(r#"[{ "exclude": "//,}" }]"#).unwrap();
+
+    match rc {
+        Rc::Multi(configs) => assert_eq!(
+            format!("{:?}", configs[0].exclude),
+            r#"Some(Regex("//,}"))"#
+        ),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn const_enum_specifiers() {
+    use super::{const_enum_specifiers, Merge, TransformConfig};
+    use std::path::Path;
+    use swc_atoms::JsWord;
+
+    let mut config = TransformConfig::default();
+    config
+        .const_enums
+        .insert("/p/src/enums.ts".into(), Default::default());
+    config
+        .const_enums
+        .insert("/p/src/flags/index.d.ts".into(), Default::default());
+    config.const_enums.insert("pkg".into(), Default::default());
+
+    let mut other = TransformConfig::default();
+    let mut values = swc_ecma_transforms::typescript::strip::ConstEnums::default();
+    values.insert("E".into(), Default::default());
+    other.const_enums.insert("pkg".into(), values.clone());
+    other.const_enums.insert("other".into(), values);
+    config.merge(&other);
+
+    assert!(config.const_enums[&JsWord::from("pkg")].is_empty());
+    assert!(config.const_enums.contains_key(&JsWord::from("other")));
+
+    let mut specifiers =
+        const_enum_specifiers(Some(Path::new("/p/src/a/b.ts")), config.const_enums)
+            .into_iter()
+            .map(|(s, _)| s.to_string())
+            .collect::<Vec<_>>();
+    specifiers.sort();
+
+    assert_eq!(
+        specifiers,
+        vec![
+            "../enums",
+            "../enums.js",
+            "../enums.ts",
+            "../flags",
+            "../flags/index",
+            "../flags/index.d.ts",
+            "../flags/index.js",
+            "other",
+            "pkg",
+        ]
+    );
+}
+
+#[test]
+fn invalid_type_after_non_ascii() {
+    let err =
+        Rc::parse(r#"{ "jsc": { "transform": { "react": { "pragma": "h€" } }, "loose": "€" } }"#)
+            .unwrap_err();
+
+    let msg = err.to_string();
+    assert!(
+        msg.contains("jsc.loose: invalid type: string \"€\""),
+        "{}",
+        msg
+    );
+}
@@ -0,0 +1,164 @@
+//! Compilation of directories, like `babel src --out-dir lib`.
+
+use crate::TransformOutput;
+use anyhow::{Context, Error};
+use glob::Pattern;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use walkdir::WalkDir;
+
+/// Source files with the path of their output, or the error which occurred
+/// while compiling or copying them.
+pub type Outputs = Vec<(PathBuf, Result<PathBuf, Error>)>;
+
+/// Options for [crate::Compiler::compile_dir].
+#[derive(Debug, Clone)]
+pub struct DirOptions {
+    /// Extensions of files to compile, without the leading dot.
+    pub extensions: Vec<String>,
+
+    /// Copies files which are not compiled to the output directory.
+    pub copy_files: bool,
+
+    /// Glob patterns of files to skip, like `**/*.test.js`. Patterns are
+    /// matched against paths relative to the source directory.
+    pub ignore: Vec<String>,
+}
+
+impl Default for DirOptions {
+    fn default() -> Self {
+        DirOptions {
+            extensions: ["js", "jsx", "es6", "mjs", "cjs", "ts", "tsx"]
+                .iter()
+                .map(|ext| ext.to_string())
+                .collect(),
+            copy_files: false,
+            ignore: vec![],
+        }
+    }
+}
+
+/// What to do with a file in the source directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Action {
+    Compile { out: PathBuf },
+    Copy { out: PathBuf },
+}
+
+/// Returns the files in `src` with the action for each of them.
+///
+/// `out_dir` is skipped if it's inside `src`.
+pub(crate) fn plan(
+    src: &Path,
+    out_dir: &Path,
+    opts: &DirOptions,
+) -> Result<Vec<(PathBuf, Action)>, Error> {
+    let ignore = opts
+        .ignore
+        .iter()
+        .map(|pattern| {
+            Pattern::new(pattern).with_context(|| format!("invalid ignore pattern: {}", pattern))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut files = vec![];
+    for entry in WalkDir::new(src)
+        .sort_by(|a, b| a.file_name().cmp(b.file_name()))
+        .into_iter()
+        .filter_entry(|entry| entry.path() != out_dir)
+    {
+        let entry =
+            entry.with_context(|| format!("failed to read directory: {}", src.display()))?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let path = entry.into_path();
+        let rel = path.strip_prefix(src).unwrap();
+        if ignore.iter().any(|pattern| pattern.matches_path(rel)) {
+            continue;
+        }
+
+        let action = if should_compile(rel, opts) {
+            Action::Compile {
+                out: out_dir.join(rel).with_extension(out_extension(rel)),
+            }
+        } else if opts.copy_files {
+            Action::Copy {
+                out: out_dir.join(rel),
+            }
+        } else {
+            continue;
+        };
+        files.push((path, action));
+    }
+
+    Ok(files)
+}
+
+fn should_compile(path: &Path, opts: &DirOptions) -> bool {
+    // Declarations don't have any output.
+    if path.to_string_lossy().ends_with(".d.ts") {
+        return false;
+    }
+
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) => opts.extensions.iter().any(|e| e == ext),
+        None => false,
+    }
+}
+
+/// `.mjs` and `.cjs` are kept, as they determine the module type.
+fn out_extension(path: &Path) -> &str {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext @ "mjs") | Some(ext @ "cjs") => ext,
+        _ => "js",
+    }
+}
+
+/// Writes `output` to `out`, and the source map to `out` with a `.map`
+/// extension if there is one.
+pub(crate) fn write(out: &Path, output: &TransformOutput) -> Result<(), Error> {
+    create_parent(out)?;
+
+    match output.map {
+        Some(ref map) => {
+            let mut map_path = out.as_os_str().to_owned();
+            map_path.push(".map");
+            let map_path = PathBuf::from(map_path);
+            fs::write(&map_path, map)
+                .with_context(|| format!("failed to write {}", map_path.display()))?;
+
+            // The map is already inlined if `sourceMaps` is `both`.
+            if output.code.contains("\n//# sourceMappingURL=") {
+                fs::write(out, &output.code)
+            } else {
+                let code = format!(
+                    "{}\n//# sourceMappingURL={}",
+                    output.code,
+                    map_path.file_name().unwrap().to_string_lossy()
+                );
+                fs::write(out, code)
+            }
+        }
+        None => fs::write(out, &output.code),
+    }
+    .with_context(|| format!("failed to write {}", out.display()))
+}
+
+pub(crate) fn copy(path: &Path, out: &Path) -> Result<(), Error> {
+    create_parent(out)?;
+    fs::copy(path, out)
+        .with_context(|| format!("failed to copy {} to {}", path.display(), out.display()))?;
+    Ok(())
+}
+
+fn create_parent(path: &Path) -> Result<(), Error> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create directory: {}", dir.display()))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,129 @@
+//! Module level directives like `"use client"` of react server components.
+
+use crate::config::DirectivesMode;
+use anyhow::{bail, Error};
+use swc_common::{errors::Handler, Span};
+use swc_ecma_ast::*;
+
+/// Directives which mark the boundaries of react server components.
+static REACT_DIRECTIVES: &[&str] = &["use client", "use server"];
+
+/// Returns the directive of `stmt`.
+fn directive(stmt: &Stmt) -> Option<&Str> {
+    match stmt {
+        Stmt::Expr(ExprStmt { expr, .. }) => match &**expr {
+            // Escaped strings are not directives.
+            Expr::Lit(Lit::Str(s)) if !s.has_escape => Some(s),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn react_directive(stmt: &Stmt) -> Option<&Str> {
+    directive(stmt).filter(|s| REACT_DIRECTIVES.contains(&&*s.value))
+}
+
+fn as_stmt(item: &ModuleItem) -> Option<&Stmt> {
+    match item {
+        ModuleItem::Stmt(stmt) => Some(stmt),
+        ModuleItem::ModuleDecl(..) => None,
+    }
+}
+
+fn stmts(program: &Program) -> Vec<Option<&Stmt>> {
+    match program {
+        Program::Module(m) => m.body.iter().map(as_stmt).collect(),
+        Program::Script(s) => s.body.iter().map(Some).collect(),
+    }
+}
+
+/// Returns directives in the prologue of `program`.
+pub(crate) fn collect(program: &Program) -> Vec<String> {
+    stmts(program)
+        .into_iter()
+        .map(|stmt| stmt.and_then(directive))
+        .take_while(Option::is_some)
+        .flatten()
+        .map(|s| s.value.to_string())
+        .collect()
+}
+
+/// Reports react directives which are not in the prologue, and files which
+/// use both of `"use client"` and `"use server"`.
+pub(crate) fn check(handler: &Handler, program: &Program) -> Result<(), Error> {
+    let stmts = stmts(program);
+    let prologue = stmts
+        .iter()
+        .take_while(|stmt| stmt.and_then(directive).is_some())
+        .count();
+
+    let mut errors: Vec<(Span, String)> = vec![];
+    let mut first: Option<&Str> = None;
+    for (i, stmt) in stmts.iter().enumerate() {
+        let s = match stmt.and_then(react_directive) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        if i >= prologue {
+            errors.push((
+                s.span,
+                format!("`\"{}\"` should be at the top of the file", s.value),
+            ));
+        } else if let Some(first) = first.filter(|first| first.value != s.value) {
+            errors.push((
+                s.span,
+                format!(
+                    "`\"{}\"` can't be used with `\"{}\"` in the same file",
+                    s.value, first.value
+                ),
+            ));
+        } else {
+            first = first.or(Some(s));
+        }
+    }
+
+    for (span, msg) in &errors {
+        handler.struct_span_err(*span, msg).emit();
+    }
+    if !errors.is_empty() {
+        bail!("invalid directives")
+    }
+
+    Ok(())
+}
+
+/// Applies `mode` to react directives of the transformed program.
+/// `directives` are the ones collected from the input.
+///
+/// Transforms can insert imports or helpers before directives, which makes
+/// them normal statements. For other modes than [DirectivesMode::Strip], they
+/// are moved back to the top.
+pub(crate) fn apply(program: &mut Program, directives: &[String], mode: DirectivesMode) {
+    match program {
+        Program::Module(m) => apply_to(&mut m.body, directives, mode, as_stmt),
+        Program::Script(s) => apply_to(&mut s.body, directives, mode, |stmt| Some(stmt)),
+    }
+}
+
+fn apply_to<T>(
+    body: &mut Vec<T>,
+    directives: &[String],
+    mode: DirectivesMode,
+    as_stmt: impl Fn(&T) -> Option<&Stmt>,
+) {
+    let is_target = |item: &T| match as_stmt(item).and_then(react_directive) {
+        Some(s) => directives.iter().any(|d| *d == *s.value),
+        None => false,
+    };
+    if !body.iter().any(is_target) {
+        return;
+    }
+
+    let (targets, rest): (Vec<_>, Vec<_>) = body.drain(..).partition(is_target);
+    if mode != DirectivesMode::Strip {
+        body.extend(targets);
+    }
+    body.extend(rest);
+}
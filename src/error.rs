@@ -0,0 +1,44 @@
+//! Error handlers for bindings which return errors as strings.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    io::{self, Write},
+    sync::{Arc, RwLock},
+};
+use swc_common::errors::{EmitterWriter, Handler, SourceMapperDyn};
+
+/// Creates a new handler which emits to returned buffer.
+///
+/// Errors are rendered with the source code around the span of the error.
+pub fn new_handler(cm: Arc<SourceMapperDyn>) -> (Arc<Handler>, BufferedError) {
+    let e = BufferedError::default();
+
+    let emitter = EmitterWriter::new(Box::new(e.clone()), Some(cm), false, false);
+    let handler = Handler::with_emitter(true, false, Box::new(emitter));
+
+    (Arc::new(handler), e)
+}
+
+/// Collects errors rendered by the emitter.
+#[derive(Clone, Default)]
+pub struct BufferedError(Arc<RwLock<String>>);
+
+impl Write for BufferedError {
+    fn write(&mut self, d: &[u8]) -> io::Result<usize> {
+        self.0
+            .write()
+            .unwrap()
+            .push_str(&String::from_utf8_lossy(d));
+
+        Ok(d.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Display for BufferedError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0.read().unwrap(), f)
+    }
+}
@@ -1,20 +1,29 @@
 #![deny(unused)]
 
 pub use sourcemap;
+pub use swc_ecma_dep_graph::ModuleInfo;
 
 pub use crate::builder::PassBuilder;
-use crate::config::{
-    BuiltConfig, Config, ConfigFile, InputSourceMap, JscTarget, Merge, Options, Rc, RootMode,
-    SourceMapsConfig,
+use crate::{
+    cache::{Cache, CacheKey},
+    config::{
+        BuiltConfig, Config, ConfigFile, DirectivesMode, InputSourceMap, JscTarget, Merge, Options,
+        Rc, RootMode, SourceMapOptions, SourceMapsConfig,
+    },
+    dir::{Action, DirOptions},
+    watch::{is_config_file, Watcher},
 };
 use anyhow::{bail, Context, Error};
 use dashmap::DashMap;
-use serde::Serialize;
-use serde_json::error::Category;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::{
     fs::{read_to_string, File},
+    io::{Read, Write},
     path::{Path, PathBuf},
     sync::Arc,
+    thread,
+    time::Duration,
 };
 use swc_common::{
     chain,
@@ -29,12 +38,17 @@ use swc_ecma_parser::{lexer::Lexer, Parser, Syntax};
 use swc_ecma_transforms::{
     helpers::{self, Helpers},
     pass::noop,
-    util,
+    typescript, util,
 };
 use swc_ecma_visit::FoldWith;
 
 mod builder;
+pub mod cache;
 pub mod config;
+pub mod dir;
+mod directives;
+pub mod error;
+pub mod watch;
 
 pub struct Compiler {
     /// swc uses rustc's span interning.
@@ -47,11 +61,15 @@ pub struct Compiler {
     comments: SwcComments,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransformOutput {
     pub code: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub map: Option<String>,
+    /// Directives at the top of the input, like `"use client"`. This is only
+    /// filled by transforms.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub directives: Vec<String>,
 }
 
 /// These are **low-level** apis.
@@ -190,6 +208,7 @@ impl Compiler {
         &self,
         node: &T,
         source_map: SourceMapsConfig,
+        map_opts: &SourceMapOptions,
         orig: Option<&sourcemap::SourceMap>,
         minify: bool,
     ) -> Result<TransformOutput, Error>
@@ -202,20 +221,27 @@ impl Compiler {
             let src = {
                 let mut buf = vec![];
                 {
+                    let cfg = swc_ecma_codegen::Config {
+                        minify,
+                        ..Default::default()
+                    };
                     let mut emitter = Emitter {
-                        cfg: swc_ecma_codegen::Config { minify },
+                        cfg,
                         comments: if minify { None } else { Some(&self.comments) },
                         cm: self.cm.clone(),
-                        wr: Box::new(swc_ecma_codegen::text_writer::JsWriter::new(
-                            self.cm.clone(),
-                            "\n",
-                            &mut buf,
-                            if source_map.enabled() {
-                                Some(&mut src_map_buf)
-                            } else {
-                                None
-                            },
-                        )),
+                        wr: Box::new(
+                            swc_ecma_codegen::text_writer::JsWriter::new(
+                                self.cm.clone(),
+                                "\n",
+                                &mut buf,
+                                if source_map.enabled() {
+                                    Some(&mut src_map_buf)
+                                } else {
+                                    None
+                                },
+                            )
+                            .with_config(&cfg),
+                        ),
                     };
 
                     node.emit_with(&mut emitter)
@@ -224,43 +250,50 @@ impl Compiler {
                 // Invalid utf8 is valid in javascript world.
                 unsafe { String::from_utf8_unchecked(buf) }
             };
-            let (code, map) = match source_map {
-                SourceMapsConfig::Bool(v) => {
-                    if v {
-                        let mut buf = vec![];
-
-                        self.cm
-                            .build_source_map_from(&mut src_map_buf, orig)
-                            .to_writer(&mut buf)
-                            .context("failed to write source map")?;
-                        let map = String::from_utf8(buf).context("source map is not utf-8")?;
-                        (src, Some(map))
-                    } else {
-                        (src, None)
+            let map = if source_map.enabled() {
+                let mut map = self.cm.build_source_map_from(&mut src_map_buf, orig);
+                if let Some(ref name) = map_opts.source_file_name {
+                    if map.get_source_count() == 1 {
+                        map.set_source(0, name);
+                    }
+                }
+                if map_opts.source_root.is_some() {
+                    map.set_source_root(map_opts.source_root.clone());
+                }
+                if !map_opts.inline_sources_content {
+                    for idx in 0..map.get_source_count() {
+                        map.set_source_contents(idx, None);
                     }
                 }
-                SourceMapsConfig::Str(_) => {
-                    let mut src = src;
-
-                    let mut buf = vec![];
 
-                    self.cm
-                        .build_source_map_from(&mut src_map_buf, orig)
-                        .to_writer(&mut buf)
-                        .context("failed to write source map file")?;
-                    let map = String::from_utf8(buf).context("source map is not utf-8")?;
+                let mut buf = vec![];
+                map.to_writer(&mut buf)
+                    .context("failed to write source map")?;
+                Some(String::from_utf8(buf).context("source map is not utf-8")?)
+            } else {
+                None
+            };
 
+            let (code, map) = match (source_map, map) {
+                (SourceMapsConfig::Str(ref s), Some(map)) => {
+                    let mut src = src;
                     src.push_str("\n//# sourceMappingURL=data:application/json;base64,");
                     base64::encode_config_buf(
                         map.as_bytes(),
                         base64::Config::new(base64::CharacterSet::UrlSafe, true),
                         &mut src,
                     );
-                    (src, None)
+                    // `both` returns the map in addition to inlining it.
+                    (src, if s == "both" { Some(map) } else { None })
                 }
+                (_, map) => (src, map),
             };
 
-            Ok(TransformOutput { code, map })
+            Ok(TransformOutput {
+                code,
+                map,
+                directives: vec![],
+            })
         })
     }
 }
@@ -356,10 +389,11 @@ impl Compiler {
             let built = opts.build(
                 &self.cm,
                 &self.handler,
+                name,
                 opts.is_module,
                 Some(config),
                 Some(&self.comments),
-            );
+            )?;
             Ok(built)
         })
         .with_context(|| format!("failed to load config for file '{:?}'", name))
@@ -370,9 +404,8 @@ impl Compiler {
         F: FnOnce() -> Ret,
     {
         self.run(|| {
-            helpers::HELPERS.set(&Helpers::new(external_helpers), || {
-                util::HANDLER.set(&self.handler, || op())
-            })
+            let helpers = Helpers::new(external_helpers).with_source_map(self.cm.clone());
+            helpers::HELPERS.set(&helpers, || util::HANDLER.set(&self.handler, || op()))
         })
     }
 
@@ -388,29 +421,111 @@ impl Compiler {
         })
     }
 
-    /// `custom_after_pass` is applied after swc transforms are applied.
-    pub fn process_js_with_custom_pass<P>(
+    /// `custom_before_pass` is applied before swc transforms are applied, and
+    /// `custom_after_pass` is applied after them.
+    ///
+    /// Both of them are created from the parsed program, so they can depend
+    /// on it.
+    pub fn process_js_with_custom_pass<P1, P2>(
         &self,
         fm: Arc<SourceFile>,
         opts: &Options,
-        custom_after_pass: P,
+        custom_before_pass: impl FnOnce(&Program) -> P1,
+        custom_after_pass: impl FnOnce(&Program) -> P2,
     ) -> Result<TransformOutput, Error>
     where
-        P: swc_ecma_visit::Fold,
+        P1: swc_ecma_visit::Fold,
+        P2: swc_ecma_visit::Fold,
     {
         self.run(|| -> Result<_, Error> {
             let config = self.run(|| self.config_for_file(opts, &fm.name))?;
+            let orig = self.get_orig_src_map(&fm, &opts.input_source_map)?;
+            let program = self.parse_js(
+                fm.clone(),
+                config.target,
+                config.syntax,
+                config.is_module,
+                true,
+            )?;
+
             let config = BuiltConfig {
-                pass: chain!(config.pass, custom_after_pass),
+                pass: chain!(
+                    custom_before_pass(&program),
+                    config.pass,
+                    custom_after_pass(&program)
+                ),
                 syntax: config.syntax,
                 target: config.target,
                 minify: config.minify,
                 external_helpers: config.external_helpers,
                 source_maps: config.source_maps,
+                source_map_options: config.source_map_options,
                 input_source_map: config.input_source_map,
                 is_module: config.is_module,
+                warnings: config.warnings,
+                directives: config.directives,
             };
-            let orig = self.get_orig_src_map(&fm, &opts.input_source_map)?;
+
+            self.process_js_inner(program, orig.as_ref(), config)
+        })
+        .context("failed to process js file")
+    }
+
+    pub fn process_js_file(
+        &self,
+        fm: Arc<SourceFile>,
+        opts: &Options,
+    ) -> Result<TransformOutput, Error> {
+        self.process_js_with_custom_pass(fm, opts, |_| noop(), |_| noop())
+    }
+
+    /// Compiles source code read from `input` and writes the output to
+    /// `output`, like `swc --filename foo.tsx < input > output`.
+    ///
+    /// [Options::filename] is used to find `.swcrc` files and to determine
+    /// the syntax. If a source map is generated, it's written to `map`.
+    /// Use `sourceMaps: "inline"` to embed it in the output instead.
+    pub fn process_stream(
+        &self,
+        mut input: impl Read,
+        mut output: impl Write,
+        map: Option<&mut dyn Write>,
+        opts: &Options,
+    ) -> Result<(), Error> {
+        let mut src = String::new();
+        input
+            .read_to_string(&mut src)
+            .context("failed to read input")?;
+
+        let name = if opts.filename.is_empty() {
+            FileName::Anon
+        } else {
+            FileName::Real(opts.filename.clone().into())
+        };
+        let fm = self.cm.new_source_file(name, src);
+        let out = self.process_js_file(fm, opts)?;
+
+        output
+            .write_all(out.code.as_bytes())
+            .context("failed to write output")?;
+        if let (Some(map), Some(out_map)) = (map, out.map) {
+            map.write_all(out_map.as_bytes())
+                .context("failed to write source map")?;
+        }
+
+        Ok(())
+    }
+
+    /// Emits a declaration file (`.d.ts`) for a typescript file.
+    ///
+    /// Transforms in `opts` are ignored.
+    pub fn emit_dts(&self, fm: Arc<SourceFile>, opts: &Options) -> Result<TransformOutput, Error> {
+        self.run(|| -> Result<_, Error> {
+            let config = self.run(|| self.config_for_file(opts, &fm.name))?;
+            if !config.syntax.typescript() {
+                bail!("declarations can only be emitted for typescript files")
+            }
+
             let program = self.parse_js(
                 fm.clone(),
                 config.target,
@@ -418,18 +533,199 @@ impl Compiler {
                 config.is_module,
                 true,
             )?;
+            let program = program.fold_with(&mut typescript::dts());
+
+            self.print(
+                &program,
+                SourceMapsConfig::Bool(false),
+                &Default::default(),
+                None,
+                false,
+            )
+        })
+        .context("failed to emit declarations")
+    }
 
-            self.process_js_inner(program, orig.as_ref(), config)
+    /// Returns imports and exports of a file without transforming it.
+    ///
+    /// Only the syntax in `opts` is used. `require()` calls and CommonJS
+    /// exports are reported for both of modules and scripts.
+    pub fn analyze(&self, fm: Arc<SourceFile>, opts: &Options) -> Result<ModuleInfo, Error> {
+        self.run(|| -> Result<_, Error> {
+            let config = self.run(|| self.config_for_file(opts, &fm.name))?;
+            let program = self.parse_js(
+                fm.clone(),
+                config.target,
+                config.syntax,
+                config.is_module,
+                false,
+            )?;
+
+            Ok(match program {
+                Program::Module(module) => swc_ecma_dep_graph::analyze_module(&module),
+                Program::Script(script) => swc_ecma_dep_graph::analyze_script(&script),
+            })
         })
-        .context("failed to process js file")
+        .context("failed to analyze the module")
     }
 
-    pub fn process_js_file(
+    /// Same as [Compiler::process_js_file], but reuses the output stored in
+    /// `cache` if neither the file nor the options changed.
+    pub fn process_js_file_cached(
         &self,
         fm: Arc<SourceFile>,
         opts: &Options,
+        cache: &Cache,
     ) -> Result<TransformOutput, Error> {
-        self.process_js_with_custom_pass(fm, opts, noop())
+        let config = self.read_config(opts, &fm.name)?;
+        let key = CacheKey::new(&fm, opts, &config);
+
+        if let Some(output) = cache.get(&key) {
+            return Ok(output);
+        }
+
+        let output = self.process_js_file(fm, opts)?;
+        cache.insert(key, output.clone())?;
+
+        Ok(output)
+    }
+
+    /// Loads and processes `paths` concurrently.
+    ///
+    /// The source map, span interner and comments are shared by all files, so
+    /// the outputs can be used the same way as if [Compiler::process_js_file]
+    /// was called for each file. Results are in the same order as `paths`.
+    pub fn process_files_parallel<P>(
+        &self,
+        paths: &[P],
+        opts: &Options,
+    ) -> Vec<Result<TransformOutput, Error>>
+    where
+        P: AsRef<Path> + Sync,
+    {
+        paths
+            .par_iter()
+            .map(|path| {
+                let path = path.as_ref();
+                let fm = self
+                    .cm
+                    .load_file(path)
+                    .with_context(|| format!("failed to load file: {}", path.display()))?;
+
+                self.process_js_file(fm, opts)
+            })
+            .collect()
+    }
+
+    /// Compiles files in `src` to `out_dir` concurrently, like `babel src
+    /// --out-dir lib`.
+    ///
+    /// The directory structure is preserved, and compiled files are renamed
+    /// to `.js`. If a source map is generated, it's written next to the
+    /// output. Results contain the path of each output, in the order of the
+    /// source files.
+    pub fn compile_dir(
+        &self,
+        src: &Path,
+        out_dir: &Path,
+        opts: &Options,
+        dir_opts: &DirOptions,
+    ) -> Result<dir::Outputs, Error> {
+        let files = dir::plan(src, out_dir, dir_opts)?;
+
+        Ok(files
+            .into_par_iter()
+            .map(|(path, action)| {
+                let res = match action {
+                    Action::Compile { out } => self
+                        .cm
+                        .load_file(&path)
+                        .with_context(|| format!("failed to load file: {}", path.display()))
+                        .and_then(|fm| self.process_js_file(fm, opts))
+                        .and_then(|output| dir::write(&out, &output))
+                        .map(|_| out),
+                    Action::Copy { out } => dir::copy(&path, &out).map(|_| out),
+                };
+
+                (path, res)
+            })
+            .collect())
+    }
+
+    /// Processes files modified since the last call to [Watcher::poll].
+    ///
+    /// If a watched `.swcrc` file is modified, all watched files are processed
+    /// again, but outputs stored in `cache` are reused for files whose config
+    /// is not affected by the change. Removed files are skipped.
+    pub fn rebuild(
+        &self,
+        watcher: &mut Watcher,
+        opts: &Options,
+        cache: &Cache,
+    ) -> Vec<(PathBuf, Result<TransformOutput, Error>)> {
+        let changed = watcher.poll();
+        let paths = if changed.iter().any(|path| is_config_file(path)) {
+            let mut paths: Vec<_> = watcher.files().map(Path::to_path_buf).collect();
+            paths.sort();
+            paths
+        } else {
+            changed
+        };
+
+        self.process_watched(paths, opts, cache)
+    }
+
+    /// Processes all files watched by `watcher`, and then processes them
+    /// again whenever they are modified, until `on_build` returns `false`.
+    ///
+    /// `on_build` is called with the outputs of each build which processed at
+    /// least one file.
+    pub fn watch<F>(
+        &self,
+        watcher: &mut Watcher,
+        opts: &Options,
+        cache: &Cache,
+        interval: Duration,
+        mut on_build: F,
+    ) where
+        F: FnMut(Vec<(PathBuf, Result<TransformOutput, Error>)>) -> bool,
+    {
+        watcher.poll();
+        let mut paths: Vec<_> = watcher.files().map(Path::to_path_buf).collect();
+        paths.sort();
+
+        let mut outputs = self.process_watched(paths, opts, cache);
+        loop {
+            if !outputs.is_empty() && !on_build(outputs) {
+                return;
+            }
+
+            thread::sleep(interval);
+            outputs = self.rebuild(watcher, opts, cache);
+        }
+    }
+
+    fn process_watched(
+        &self,
+        paths: Vec<PathBuf>,
+        opts: &Options,
+        cache: &Cache,
+    ) -> Vec<(PathBuf, Result<TransformOutput, Error>)> {
+        paths
+            .into_par_iter()
+            .filter(|path| !is_config_file(path) && path.exists())
+            .map(|path| {
+                // Replaces the file loaded by the previous build, so the source
+                // map doesn't keep the old source alive.
+                let output = self
+                    .cm
+                    .reload_file(&path)
+                    .with_context(|| format!("failed to load file: {}", path.display()))
+                    .and_then(|fm| self.process_js_file_cached(fm, opts, cache));
+
+                (path, output)
+            })
+            .collect()
     }
 
     /// You can use custom pass with this method.
@@ -463,46 +759,45 @@ impl Compiler {
                 self.comments.leading.retain(preserve_excl);
                 self.comments.trailing.retain(preserve_excl);
             }
+            let directives = directives::collect(&program);
+            if config.directives == DirectivesMode::Enforce {
+                directives::check(&self.handler, &program)?;
+            }
+
             let mut pass = config.pass;
-            let program = helpers::HELPERS.set(&Helpers::new(config.external_helpers), || {
+            let warnings = config.warnings;
+            let helpers = Helpers::new(config.external_helpers).with_source_map(self.cm.clone());
+            let mut program = helpers::HELPERS.set(&helpers, || {
                 util::HANDLER.set(&self.handler, || {
-                    // Fold module
-                    program.fold_with(&mut pass)
+                    util::WARNINGS.set(&warnings, || {
+                        // Fold module
+                        program.fold_with(&mut pass)
+                    })
                 })
             });
 
-            self.print(&program, config.source_maps, orig, config.minify)
+            directives::apply(&mut program, &directives, config.directives);
+
+            let output = self.print(
+                &program,
+                config.source_maps,
+                &config.source_map_options,
+                orig,
+                config.minify,
+            )?;
+
+            Ok(TransformOutput {
+                directives,
+                ..output
+            })
         })
     }
 }
 
 fn load_swcrc(path: &Path) -> Result<Rc, Error> {
-    fn convert_json_err(e: serde_json::Error) -> Error {
-        let line = e.line();
-        let column = e.column();
-
-        let msg = match e.classify() {
-            Category::Io => "io error",
-            Category::Syntax => "syntax error",
-            Category::Data => "unmatched data",
-            Category::Eof => "unexpected eof",
-        };
-        Error::new(e).context(format!(
-            "failed to deserialize .swcrc (json) file: {}: {}:{}",
-            msg, line, column
-        ))
-    }
-
     let content = read_to_string(path).context("failed to read config (.swcrc) file")?;
 
-    match serde_json::from_str(&content) {
-        Ok(v) => return Ok(v),
-        Err(..) => {}
-    }
-
-    serde_json::from_str::<Config>(&content)
-        .map(Rc::Single)
-        .map_err(convert_json_err)
+    Rc::parse(&content)
 }
 
 type CommentMap = Arc<DashMap<BytePos, Vec<Comment>>>;
@@ -562,4 +857,10 @@ impl Comments for SwcComments {
     fn take_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
         self.trailing.remove(&pos).map(|v| v.1)
     }
+
+    fn has_flag(&self, pos: BytePos, flag: &str) -> bool {
+        self.leading
+            .get(&pos)
+            .map_or(false, |comments| comments.iter().any(|c| c.is_flag(flag)))
+    }
 }
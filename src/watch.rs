@@ -0,0 +1,80 @@
+//! Polling based file watcher used to rebuild modified files.
+//!
+//! This is not exposed by the cli or the node binding yet. See
+//! `examples/watch.rs` for a minimal driver.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// Modification time and length of a file, or `None` if it does not exist.
+type Stamp = Option<(SystemTime, u64)>;
+
+/// Tracks modifications of a set of files.
+///
+/// A `.swcrc` file can be watched to rebuild all files when it changes. See
+/// [crate::Compiler::rebuild].
+#[derive(Debug, Default)]
+pub struct Watcher {
+    files: HashMap<PathBuf, Stamp>,
+}
+
+impl Watcher {
+    pub fn new<I, P>(files: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        let mut watcher = Watcher::default();
+        for path in files {
+            watcher.add(path);
+        }
+        watcher
+    }
+
+    /// Starts watching `path`.
+    ///
+    /// The current state of the file is recorded, so it is not reported by
+    /// [Watcher::poll] unless it's modified afterwards.
+    pub fn add(&mut self, path: impl Into<PathBuf>) {
+        let path = path.into();
+        let stamp = stamp(&path);
+        self.files.insert(path, stamp);
+    }
+
+    pub fn remove(&mut self, path: &Path) {
+        self.files.remove(path);
+    }
+
+    pub fn files(&self) -> impl Iterator<Item = &Path> {
+        self.files.keys().map(|path| &**path)
+    }
+
+    /// Returns files created, modified or removed since the last call.
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let mut changed = vec![];
+
+        for (path, prev) in self.files.iter_mut() {
+            let cur = stamp(path);
+            if cur != *prev {
+                *prev = cur;
+                changed.push(path.clone());
+            }
+        }
+
+        changed.sort();
+        changed
+    }
+}
+
+fn stamp(path: &Path) -> Stamp {
+    let meta = fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
+
+pub(crate) fn is_config_file(path: &Path) -> bool {
+    path.file_name() == Some(".swcrc".as_ref())
+}
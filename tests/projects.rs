@@ -1,10 +1,22 @@
 use rayon::prelude::*;
-use std::{path::Path, sync::Arc};
+use std::{cell::Cell, collections::HashMap, path::Path, rc::Rc, sync::Arc, time::Duration};
 use swc::{
-    config::{Config, JscConfig, ModuleConfig, Options, SourceMapsConfig, TransformConfig},
+    cache::{Cache, CacheKey},
+    config::{
+        Assumptions, Config, Feature, JscConfig, JscTarget, ModuleConfig, Options,
+        SourceMapsConfig, TransformConfig, TransformStage, WarningsMode,
+    },
+    dir::DirOptions,
+    sourcemap,
+    watch::Watcher,
     Compiler,
 };
-use swc_ecma_parser::{Syntax, TsConfig};
+use swc_common::{errors::Level, FileName};
+use swc_ecma_ast::ArrowExpr;
+use swc_ecma_parser::{EsConfig, Syntax, TsConfig};
+use swc_ecma_preset_env::{Targets, Versions};
+use swc_ecma_transforms::{pass::noop, util};
+use swc_ecma_visit::{Fold, FoldWith};
 use testing::{NormalizedOutput, StdErr, Tester};
 use walkdir::WalkDir;
 
@@ -359,7 +371,7 @@ fn issue_605() {
     let f = file("tests/projects/issue-605/input.js").unwrap();
     println!("{}", f);
 
-    assert!(f.contains("Object.keys(_c)"));
+    assert!(f.contains("_exportStar(_c, exports)"));
 }
 
 #[test]
@@ -565,3 +577,1105 @@ fn issue_1203() {
 
     assert!(!f.contains("return //"))
 }
+
+#[test]
+fn jest_hoist() {
+    let f = file("tests/projects/jest-hoist/input.js").unwrap();
+    println!("{}", f);
+
+    let foo = f.find("require(\"./foo\")").unwrap();
+    assert!(f.find("jest.mock('./foo').unmock('./bar')").unwrap() < foo);
+    assert!(f.find("jest.mock('./baz'").unwrap() < foo);
+}
+
+#[test]
+fn export_namespace_from_es2020() {
+    let f = file("tests/projects/export-namespace-from/input.js").unwrap();
+    println!("{}", f);
+
+    assert!(f.contains("_interopRequireWildcard(require(\"./foo\"))"));
+    assert!(f.contains("exports.ns = "));
+}
+
+#[test]
+fn record_and_tuple() {
+    let f = file("tests/projects/record-and-tuple/input.js").unwrap();
+    println!("{}", f);
+
+    assert!(f.contains("from \"@bloomberg/record-tuple-polyfill\""));
+    assert!(f.contains("_Record({"));
+    assert!(f.contains("_Tuple(point, 3)"));
+}
+
+#[test]
+#[should_panic(expected = "failed to emit module")]
+fn record_and_tuple_without_lowering() {
+    let _ = file_with_opt(
+        "tests/projects/record-and-tuple/input.js",
+        Options {
+            config: Some(Config {
+                jsc: JscConfig {
+                    syntax: Some(Syntax::Es(EsConfig {
+                        record_and_tuple: true,
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    );
+}
+
+fn builtins(path: &str, target: JscTarget, env: Option<swc_ecma_preset_env::Config>) -> String {
+    file_with_opt(
+        path,
+        Options {
+            config: Some(Config {
+                env,
+                jsc: JscConfig {
+                    syntax: Some(Syntax::Es(EsConfig {
+                        jsx: true,
+                        ..Default::default()
+                    })),
+                    target,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    )
+    .unwrap()
+    .to_string()
+}
+
+fn react_builtins(target: JscTarget, env: Option<swc_ecma_preset_env::Config>) -> String {
+    builtins("tests/projects/react-builtins/input.js", target, env)
+}
+
+fn object_rest_builtins(target: JscTarget, env: Option<swc_ecma_preset_env::Config>) -> String {
+    builtins("tests/projects/object-rest-builtins/input.js", target, env)
+}
+
+#[test]
+fn react_builtins_for_target() {
+    assert!(react_builtins(JscTarget::Es5, None).contains("_extends({"));
+    assert!(react_builtins(JscTarget::Es2015, None).contains("Object.assign({"));
+    assert!(react_builtins(JscTarget::Es2016, None).contains("Object.assign({"));
+}
+
+#[test]
+fn react_builtins_for_env() {
+    let env = |chrome: &str, ie: Option<&str>| swc_ecma_preset_env::Config {
+        targets: Some(Targets::Versions(Versions {
+            chrome: Some(chrome.parse().unwrap()),
+            ie: ie.map(|v| v.parse().unwrap()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    };
+
+    assert!(react_builtins(JscTarget::Es5, Some(env("60", None))).contains("Object.assign({"));
+    assert!(react_builtins(JscTarget::Es5, Some(env("60", Some("11")))).contains("_extends({"));
+}
+
+#[test]
+fn object_rest_builtins_for_target() {
+    assert!(object_rest_builtins(JscTarget::Es5, None).contains("_extends({"));
+    assert!(object_rest_builtins(JscTarget::Es2015, None).contains("Object.assign({"));
+}
+
+#[test]
+fn object_rest_builtins_for_env() {
+    let env = |chrome: &str, ie: Option<&str>| swc_ecma_preset_env::Config {
+        targets: Some(Targets::Versions(Versions {
+            chrome: Some(chrome.parse().unwrap()),
+            ie: ie.map(|v| v.parse().unwrap()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    };
+
+    assert!(object_rest_builtins(JscTarget::Es5, Some(env("55", None))).contains("Object.assign({"));
+    assert!(
+        object_rest_builtins(JscTarget::Es5, Some(env("55", Some("11")))).contains("_extends({")
+    );
+}
+
+#[test]
+fn process_files_parallel() {
+    let paths = [
+        "tests/projects/issue-225/input.js",
+        "tests/projects/issue-226/input.js",
+        "tests/projects/issue-351/input.js",
+        "tests/projects/export-namespace-from/input.js",
+        "tests/projects/does-not-exist.js",
+    ];
+
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let opts = Options {
+                swcrc: true,
+                is_module: true,
+                ..Default::default()
+            };
+
+            let outputs = c.process_files_parallel(&paths, &opts);
+            assert_eq!(outputs.len(), paths.len());
+
+            for (path, output) in paths.iter().zip(outputs) {
+                match cm.load_file(Path::new(path)) {
+                    Ok(fm) => {
+                        let expected = c.process_js_file(fm, &opts).unwrap();
+                        assert_eq!(output.unwrap().code, expected.code, "{}", path);
+                    }
+                    Err(..) => assert!(output.is_err(), "{}", path),
+                }
+            }
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn process_js_file_cached() {
+    let dir = std::env::temp_dir().join(format!("swc-cache-test-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let opts = Options {
+                swcrc: true,
+                is_module: true,
+                ..Default::default()
+            };
+            let fm = cm
+                .load_file(Path::new("tests/projects/issue-225/input.js"))
+                .unwrap();
+            let expected = c.process_js_file(fm.clone(), &opts).unwrap();
+
+            let cache = Cache::with_dir(&dir);
+            let output = c.process_js_file_cached(fm.clone(), &opts, &cache).unwrap();
+            assert_eq!(output.code, expected.code);
+            assert_eq!(cache.len(), 1);
+
+            // Hit
+            let output = c.process_js_file_cached(fm.clone(), &opts, &cache).unwrap();
+            assert_eq!(output.code, expected.code);
+            assert_eq!(cache.len(), 1);
+
+            // Changed options
+            let minified = Options {
+                config: Some(Config {
+                    minify: Some(true),
+                    ..Default::default()
+                }),
+                ..opts.clone()
+            };
+            c.process_js_file_cached(fm.clone(), &minified, &cache)
+                .unwrap();
+            assert_eq!(cache.len(), 2);
+
+            // Changed source
+            let changed = cm.new_source_file(fm.name.clone(), format!("{}\nbar();", fm.src));
+            let output = c.process_js_file_cached(changed, &opts, &cache).unwrap();
+            assert!(output.code.contains("bar()"));
+            assert_eq!(cache.len(), 3);
+
+            // Entries are loaded from disk by another cache.
+            let cache = Cache::with_dir(&dir);
+            assert!(cache.is_empty());
+            let key = CacheKey::new(&fm, &opts, &c.read_config(&opts, &fm.name).unwrap());
+            assert_eq!(cache.get(&key).unwrap().code, expected.code);
+
+            cache.clear().unwrap();
+            assert!(cache.get(&key).is_none());
+            assert!(!dir.exists());
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn cache_key_of_maps() {
+    let fm = swc_common::SourceMap::default()
+        .new_source_file(FileName::Real("input.js".into()), "foo();".into());
+    let config = |keys: &[&str]| {
+        let mut transform = TransformConfig::default();
+        for key in keys {
+            transform
+                .const_enums
+                .insert((*key).into(), Default::default());
+        }
+
+        Config {
+            jsc: JscConfig {
+                transform: Some(transform),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    };
+
+    // Keys of maps are hashed in the same order regardless of their insertion
+    // order.
+    let keys = ["a", "b", "c", "d", "e", "f", "g", "h"];
+    let mut reversed = keys;
+    reversed.reverse();
+    assert_eq!(
+        CacheKey::new(&fm, &Default::default(), &config(&keys)),
+        CacheKey::new(&fm, &Default::default(), &config(&reversed))
+    );
+    assert_ne!(
+        CacheKey::new(&fm, &Default::default(), &config(&keys)),
+        CacheKey::new(&fm, &Default::default(), &config(&keys[1..]))
+    );
+}
+
+#[test]
+fn watch() {
+    let dir = std::env::temp_dir().join(format!("swc-watch-test-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let a = dir.join("a.js");
+    let b = dir.join("b.js");
+    let swcrc = dir.join(".swcrc");
+    std::fs::write(&a, "export const a = () => 1;").unwrap();
+    std::fs::write(&b, "export const b = () => 2;").unwrap();
+    std::fs::write(&swcrc, r#"{ "jsc": { "target": "es2016" } }"#).unwrap();
+
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let opts = Options {
+                swcrc: true,
+                is_module: true,
+                ..Default::default()
+            };
+            let cache = Cache::new();
+            let mut watcher = Watcher::new(vec![&a, &b, &swcrc]);
+
+            let mut builds = vec![];
+            c.watch(
+                &mut watcher,
+                &opts,
+                &cache,
+                Duration::from_millis(10),
+                |outputs| {
+                    let outputs: Vec<_> = outputs
+                        .into_iter()
+                        .map(|(path, output)| (path, output.unwrap().code))
+                        .collect();
+                    builds.push(outputs);
+
+                    match builds.len() {
+                        1 => std::fs::write(&a, "export const a = () => 30;").unwrap(),
+                        2 => std::fs::write(&swcrc, r#"{ "jsc": { "target": "es5" } }"#).unwrap(),
+                        _ => return false,
+                    }
+
+                    true
+                },
+            );
+
+            assert_eq!(builds.len(), 3);
+
+            let paths: Vec<_> = builds[0].iter().map(|(path, _)| path).collect();
+            assert_eq!(paths, vec![&a, &b]);
+            assert!(builds[0][0].1.contains("=>"));
+
+            // Only the modified file is processed.
+            assert_eq!(builds[1].len(), 1);
+            assert_eq!(builds[1][0].0, a);
+            assert!(builds[1][0].1.contains("30"));
+
+            // Changing the config rebuilds everything.
+            let paths: Vec<_> = builds[2].iter().map(|(path, _)| path).collect();
+            assert_eq!(paths, vec![&a, &b]);
+            assert!(builds[2].iter().all(|(_, code)| !code.contains("=>")));
+
+            // Files loaded by previous builds are replaced.
+            let name = FileName::Real(a.clone());
+            assert_eq!(cm.files().iter().filter(|f| f.name == name).count(), 1);
+
+            Ok(())
+        })
+        .unwrap();
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Counts arrow functions.
+struct ArrowCounter(Rc<Cell<usize>>);
+
+impl Fold for ArrowCounter {
+    fn fold_arrow_expr(&mut self, e: ArrowExpr) -> ArrowExpr {
+        self.0.set(self.0.get() + 1);
+        e.fold_children_with(self)
+    }
+}
+
+#[test]
+fn custom_pass() {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let fm = cm.new_source_file(FileName::Anon, "const f = () => () => 1;".into());
+            let before = Rc::new(Cell::new(0));
+            let after = Rc::new(Cell::new(0));
+
+            let output = c
+                .process_js_with_custom_pass(
+                    fm,
+                    &Default::default(),
+                    |_| ArrowCounter(before.clone()),
+                    |_| ArrowCounter(after.clone()),
+                )
+                .unwrap();
+
+            assert_eq!(before.get(), 2);
+            // Arrows are converted to functions by default.
+            assert_eq!(after.get(), 0);
+            assert!(output.code.contains("function"));
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn emit_dts() {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let fm = cm.new_source_file(
+                FileName::Real("foo.ts".into()),
+                "
+import { Base } from './base';
+
+interface Options<T> {
+    value?: T;
+}
+
+export abstract class Foo<T> extends Base<T> implements Options<T> {
+    static readonly id = 'foo';
+    value?: T;
+    private cache = new Map<string, T>();
+
+    constructor(protected options: Options<T>) {
+        super();
+    }
+
+    abstract run(): void;
+
+    get(key: string) {
+        return this.cache.get(key);
+    }
+}
+
+export namespace Foo {
+    export const version = 1;
+}
+"
+                .into(),
+            );
+
+            let output = c
+                .emit_dts(
+                    fm,
+                    &Options {
+                        is_module: true,
+                        config: Some(Config {
+                            jsc: JscConfig {
+                                syntax: Some(Syntax::Typescript(TsConfig::default())),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+
+            assert_eq!(
+                output.code,
+                "import { Base } from './base';
+interface Options<T> {
+    value?: T;
+}
+export declare abstract class Foo<T> extends Base<T> implements Options<T> {
+    static readonly id: 'foo';
+    value?: T;
+    private cache;
+    protected options: Options<T>;
+    constructor(options: Options<T>);
+    abstract run(): void;
+    get(key: string): any;
+}
+export declare module Foo {
+    export const version = 1;
+}
+"
+            );
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn analyze() {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let fm = cm.new_source_file(
+                FileName::Real("foo.ts".into()),
+                "
+import { a } from './a';
+import type { T } from './types';
+export * from './b';
+export const c: T = a;
+export default function () {
+    return import('./lazy');
+}
+"
+                .into(),
+            );
+
+            let info = c
+                .analyze(
+                    fm,
+                    &Options {
+                        is_module: true,
+                        config: Some(Config {
+                            jsc: JscConfig {
+                                syntax: Some(Syntax::Typescript(TsConfig {
+                                    dynamic_import: true,
+                                    ..Default::default()
+                                })),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+
+            assert_eq!(
+                serde_json::to_string(&info).unwrap(),
+                r#"{"imports":["./a","./b"],"dynamicImports":["./lazy"],"exports":["c","default"],"starExports":["./b"],"requires":[],"commonjs":false,"cjsExports":[],"cjsExportsReplaced":false}"#
+            );
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn keep_names() {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let fm = cm.new_source_file(
+                FileName::Anon,
+                "var Foo = class {}; var bar = () => {};".into(),
+            );
+
+            let output = c
+                .process_js_file(
+                    fm,
+                    &Options {
+                        config: Some(Config {
+                            jsc: JscConfig {
+                                keep_class_names: true,
+                                keep_fn_names: true,
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+
+            assert!(output.code.contains("function Foo()"), "{}", output.code);
+            assert!(output.code.contains("function bar()"), "{}", output.code);
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn assumptions() {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let fm = cm.new_source_file(FileName::Anon, "class Foo { bar() {} }".into());
+
+            let output = c
+                .process_js_file(
+                    fm,
+                    &Options {
+                        config: Some(Config {
+                            jsc: JscConfig {
+                                assumptions: Assumptions {
+                                    set_class_methods: true,
+                                    ..Default::default()
+                                },
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+
+            assert!(
+                output.code.contains("Foo.prototype.bar = function bar()"),
+                "{}",
+                output.code
+            );
+            assert!(output.code.contains("_classCallCheck"), "{}", output.code);
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn syntax_from_extension() {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let fm = cm.new_source_file(
+                FileName::Real("foo.tsx".into()),
+                "let a: JSX.Element = <div />;".into(),
+            );
+            let output = c.process_js_file(fm, &Options::default()).unwrap();
+            assert!(
+                output.code.contains("React.createElement(\"div\""),
+                "{}",
+                output.code
+            );
+
+            let fm = cm.new_source_file(FileName::Real("foo.js".into()), "<div />;".into());
+            let mut parser_by_extension = HashMap::new();
+            parser_by_extension.insert(
+                "js".into(),
+                Syntax::Es(EsConfig {
+                    jsx: true,
+                    ..Default::default()
+                }),
+            );
+            let output = c
+                .process_js_file(
+                    fm,
+                    &Options {
+                        config: Some(Config {
+                            jsc: JscConfig {
+                                parser_by_extension,
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+            assert!(
+                output.code.contains("React.createElement(\"div\""),
+                "{}",
+                output.code
+            );
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn compile_dir() {
+    let dir = std::env::temp_dir().join(format!("swc-compile-dir-test-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let src = dir.join("src");
+    let out_dir = dir.join("lib");
+    std::fs::create_dir_all(src.join("nested")).unwrap();
+    std::fs::write(src.join("a.ts"), "export const a: number = 1;").unwrap();
+    std::fs::write(src.join("nested/b.jsx"), "export const b = <div />;").unwrap();
+    std::fs::write(src.join("nested/c.mjs"), "export const c = () => 1;").unwrap();
+    std::fs::write(src.join("nested/d.test.js"), "test();").unwrap();
+    std::fs::write(src.join("types.d.ts"), "export type A = string;").unwrap();
+    std::fs::write(src.join("data.json"), "{}").unwrap();
+
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let outputs = c
+                .compile_dir(
+                    &src,
+                    &out_dir,
+                    &Options {
+                        is_module: true,
+                        ..Default::default()
+                    },
+                    &DirOptions {
+                        copy_files: true,
+                        ignore: vec!["**/*.test.js".into()],
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+            let outputs: Vec<_> = outputs
+                .into_iter()
+                .map(|(_, out)| out.unwrap().strip_prefix(&out_dir).unwrap().to_path_buf())
+                .collect();
+
+            assert_eq!(
+                outputs,
+                vec![
+                    Path::new("a.js"),
+                    Path::new("data.json"),
+                    Path::new("nested/b.js"),
+                    Path::new("nested/c.mjs"),
+                    Path::new("types.d.ts"),
+                ]
+            );
+
+            let a = std::fs::read_to_string(out_dir.join("a.js")).unwrap();
+            assert!(a.contains("var a = 1;"), "{}", a);
+            let b = std::fs::read_to_string(out_dir.join("nested/b.js")).unwrap();
+            assert!(b.contains("React.createElement"), "{}", b);
+            assert!(!out_dir.join("nested/d.test.js").exists());
+
+            Ok(())
+        })
+        .unwrap();
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Compiles files with helpers using `threads` threads.
+fn compile_files_with_threads(paths: &[std::path::PathBuf], threads: usize) -> Vec<String> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let outputs = pool.install(|| {
+                c.process_files_parallel(
+                    paths,
+                    &Options {
+                        is_module: true,
+                        source_maps: Some(SourceMapsConfig::Bool(true)),
+                        ..Default::default()
+                    },
+                )
+            });
+
+            Ok(outputs
+                .into_iter()
+                .map(|out| {
+                    let out = out.unwrap();
+                    format!("{}\n{}", out.code, out.map.unwrap())
+                })
+                .collect())
+        })
+        .unwrap()
+}
+
+#[test]
+fn deterministic_output() {
+    let dir = std::env::temp_dir().join(format!("swc-determinism-test-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let paths: Vec<_> = (0..16)
+        .map(|i| {
+            let path = dir.join(format!("{}.js", i));
+            std::fs::write(
+                &path,
+                format!(
+                    "export class A{} extends B {{ async *foo() {{ const {{ a, ...b }} = await \
+                     bar(...args); for (const c of [a, b]) yield* c; }} }}",
+                    i
+                ),
+            )
+            .unwrap();
+            path
+        })
+        .collect();
+
+    let expected = compile_files_with_threads(&paths, 1);
+    for _ in 0..4 {
+        assert_eq!(compile_files_with_threads(&paths, 8), expected);
+    }
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn process_stream() {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let input = "const a: number = 1;\nexport const b = <div />;";
+
+            let mut output = vec![];
+            let mut map = vec![];
+            c.process_stream(
+                input.as_bytes(),
+                &mut output,
+                Some(&mut map),
+                &Options {
+                    filename: "foo.tsx".into(),
+                    is_module: true,
+                    source_maps: Some(SourceMapsConfig::Bool(true)),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+            let output = String::from_utf8(output).unwrap();
+            assert!(output.contains("React.createElement"), "{}", output);
+            let map = String::from_utf8(map).unwrap();
+            assert!(map.contains("\"mappings\""), "{}", map);
+
+            let mut output = vec![];
+            c.process_stream(
+                input.as_bytes(),
+                &mut output,
+                None,
+                &Options {
+                    filename: "foo.tsx".into(),
+                    is_module: true,
+                    source_maps: Some(SourceMapsConfig::Str("inline".into())),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+            let output = String::from_utf8(output).unwrap();
+            assert!(
+                output.contains("//# sourceMappingURL=data:application/json;base64,"),
+                "{}",
+                output
+            );
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn source_map_options() {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let fm = cm.new_source_file(
+                FileName::Real("src/foo.js".into()),
+                "export const a = () => 1;".into(),
+            );
+
+            let output = c
+                .process_js_file(
+                    fm,
+                    &Options {
+                        is_module: true,
+                        source_maps: Some(SourceMapsConfig::Str("both".into())),
+                        source_file_name: Some("foo.js".into()),
+                        source_root: Some("/src/".into()),
+                        inline_sources_content: Some(false),
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+
+            assert!(
+                output
+                    .code
+                    .contains("//# sourceMappingURL=data:application/json;base64,"),
+                "{}",
+                output.code
+            );
+            let map = output.map.unwrap();
+            assert!(map.contains("\"sources\":[\"foo.js\"]"), "{}", map);
+            assert!(map.contains("\"sourceRoot\":\"/src/\""), "{}", map);
+            assert!(!map.contains("sourcesContent"), "{}", map);
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+fn compile_with_target(target: JscTarget, exclude: Vec<Feature>) -> String {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let fm = cm.new_source_file(
+                FileName::Anon,
+                "export class Foo { async bar() { await (() => 2 ** 2)(); } }".into(),
+            );
+
+            let output = c
+                .process_js_file(
+                    fm,
+                    &Options {
+                        is_module: true,
+                        config: Some(Config {
+                            jsc: JscConfig {
+                                target,
+                                exclude,
+                                ..Default::default()
+                            },
+                            module: Some(ModuleConfig::CommonJs(Default::default())),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+
+            Ok(output.code)
+        })
+        .unwrap()
+}
+
+#[test]
+fn target_features() {
+    let code = compile_with_target(JscTarget::Es2017, vec![]);
+    assert!(code.contains("class Foo"), "{}", code);
+    assert!(code.contains("async bar()"), "{}", code);
+    assert!(code.contains("2 ** 2"), "{}", code);
+    assert!(code.contains("exports.Foo"), "{}", code);
+
+    let code = compile_with_target(JscTarget::Es2016, vec![]);
+    assert!(code.contains("class Foo"), "{}", code);
+    assert!(code.contains("_asyncToGenerator"), "{}", code);
+
+    let code = compile_with_target(
+        JscTarget::Es5,
+        vec![Feature::Classes, Feature::AsyncToGenerator],
+    );
+    assert!(code.contains("class Foo"), "{}", code);
+    assert!(code.contains("async bar()"), "{}", code);
+    assert!(code.contains("function()"), "{}", code);
+    assert!(code.contains("Math.pow"), "{}", code);
+}
+
+fn compile_with_order(
+    order: Vec<TransformStage>,
+    decorator_metadata: bool,
+) -> Result<String, String> {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let fm = cm.new_source_file(
+                FileName::Real("foo.tsx".into()),
+                "const a: number = 1; export const b = <div>{a}</div>;".into(),
+            );
+
+            let output = c.process_js_file(
+                fm,
+                &Options {
+                    is_module: true,
+                    config: Some(Config {
+                        jsc: JscConfig {
+                            transform: Some(TransformConfig {
+                                order,
+                                decorator_metadata,
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            );
+
+            Ok(output
+                .map(|output| output.code)
+                .map_err(|err| format!("{:?}", err)))
+        })
+        .unwrap()
+}
+
+#[test]
+fn transform_order() {
+    let code =
+        compile_with_order(vec![TransformStage::Typescript, TransformStage::Jsx], false).unwrap();
+    assert!(code.contains("React.createElement(\"div\""), "{}", code);
+    assert!(!code.contains("number"), "{}", code);
+
+    let err =
+        compile_with_order(vec![TransformStage::Jsx, TransformStage::Jsx], false).unwrap_err();
+    assert!(err.contains("specified more than once"), "{}", err);
+
+    let err = compile_with_order(vec![TransformStage::Typescript], true).unwrap_err();
+    assert!(
+        err.contains("decorators should run before typescript"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn lazy_commonjs() {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let fm = cm.new_source_file(
+                FileName::Anon,
+                "import { foo } from 'foo'; import { bar } from './bar'; export function baz() { \
+                 return foo + bar; }"
+                    .into(),
+            );
+            let config: Config =
+                serde_json::from_str(r#"{ "module": { "type": "commonjs", "lazy": true } }"#)
+                    .unwrap();
+
+            let output = c
+                .process_js_file(
+                    fm,
+                    &Options {
+                        is_module: true,
+                        config: Some(config),
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+
+            // Dependencies are required on first use, and local modules eagerly.
+            assert!(output.code.contains("function _foo()"), "{}", output.code);
+            assert!(output.code.contains("_foo().foo"), "{}", output.code);
+            assert!(
+                output.code.contains("var _bar = require(\"./bar\")"),
+                "{}",
+                output.code
+            );
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+fn compile_with_directives(src: &str, config: &str) -> Result<swc::TransformOutput, Vec<String>> {
+    Tester::new()
+        .errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let fm = cm.new_source_file(FileName::Anon, src.into());
+            let config: Config = serde_json::from_str(config).unwrap();
+
+            c.process_js_file(
+                fm,
+                &Options {
+                    is_module: true,
+                    config: Some(config),
+                    ..Default::default()
+                },
+            )
+            .map_err(|_| ())
+        })
+        .map_err(|errors| errors.into_iter().map(|d| d.message()).collect())
+}
+
+#[test]
+fn directives() {
+    let src = "'use client';\nimport { a } from 'a';\nexport class Foo {}";
+
+    // Helpers and `require()` calls are inserted after directives.
+    let output = compile_with_directives(
+        src,
+        r#"{ "module": { "type": "commonjs" }, "jsc": { "target": "es5" } }"#,
+    )
+    .unwrap();
+    assert_eq!(output.directives, vec!["use client"]);
+    assert!(
+        output.code.starts_with("'use client';\n\"use strict\";\n"),
+        "{}",
+        output.code
+    );
+
+    let output = compile_with_directives(
+        src,
+        r#"{ "jsc": { "target": "es5", "transform": { "directives": "strip" } } }"#,
+    )
+    .unwrap();
+    assert_eq!(output.directives, vec!["use client"]);
+    assert!(!output.code.contains("use client"), "{}", output.code);
+
+    let enforce = r#"{ "jsc": { "transform": { "directives": "enforce" } } }"#;
+    compile_with_directives(src, enforce).unwrap();
+    assert_eq!(
+        compile_with_directives("import { a } from 'a';\n'use client';", enforce).unwrap_err(),
+        vec!["`\"use client\"` should be at the top of the file"]
+    );
+    assert_eq!(
+        compile_with_directives("'use client';\n'use server';", enforce).unwrap_err(),
+        vec!["`\"use server\"` can't be used with `\"use client\"` in the same file"]
+    );
+}
+
+/// Warns about arrow functions.
+struct ArrowWarner;
+
+impl Fold for ArrowWarner {
+    fn fold_arrow_expr(&mut self, e: ArrowExpr) -> ArrowExpr {
+        util::warn(e.span, "arrow function");
+        e.fold_children_with(self)
+    }
+}
+
+fn warnings_with(mode: WarningsMode) -> Vec<Level> {
+    Tester::new()
+        .errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let fm = cm.new_source_file(FileName::Anon, "const f = () => 1;".into());
+
+            c.process_js_with_custom_pass(
+                fm,
+                &Options {
+                    config: Some(Config {
+                        jsc: JscConfig {
+                            warnings: Some(mode),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                |_| ArrowWarner,
+                |_| noop(),
+            )
+            .unwrap();
+
+            Err::<(), _>(())
+        })
+        .unwrap_err()
+        .into_iter()
+        .map(|d| d.level)
+        .collect()
+}
+
+#[test]
+fn warnings() {
+    assert_eq!(warnings_with(WarningsMode::Warn), vec![Level::Warning]);
+    assert_eq!(warnings_with(WarningsMode::Error), vec![Level::Error]);
+    assert_eq!(warnings_with(WarningsMode::Ignore), vec![]);
+}
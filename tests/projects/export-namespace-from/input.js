@@ -0,0 +1 @@
+export * as ns from './foo';
@@ -1 +1,2 @@
 import bar from "bar";
+console.log(bar);
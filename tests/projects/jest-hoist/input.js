@@ -0,0 +1,9 @@
+import { foo } from './foo';
+import bar from './bar';
+
+jest.mock('./foo').unmock('./bar');
+jest.mock('./baz', () => ({ baz: 1 }));
+
+test('foo', () => {
+    expect(foo()).toBe(bar);
+});
@@ -0,0 +1 @@
+const el = <div {...props} a="1" />;
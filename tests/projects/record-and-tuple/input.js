@@ -0,0 +1,2 @@
+export const point = #{ x: 1, y: 2 };
+export const pair = #[point, 3];
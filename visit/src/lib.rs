@@ -36,6 +36,11 @@ pub struct AndThen<A, B> {
     pub second: B,
 }
 
+/// Composes passes, which are applied to a module or a script in the order
+/// they are given.
+///
+/// This works for `Fold`, `Visit` and `VisitMut`. A `VisitMut` can be chained
+/// with `Fold`s by wrapping it with `as_folder`.
 #[macro_export]
 macro_rules! chain {
     ($a:expr, $b:expr) => {{
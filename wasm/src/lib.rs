@@ -1,17 +1,11 @@
 use once_cell::sync::Lazy;
-use std::{
-    fmt::{self, Display, Formatter},
-    io::{self, Write},
-    sync::{Arc, RwLock},
-};
+use std::sync::Arc;
 use swc::{
     config::{Options, ParseOptions, SourceMapsConfig},
+    error::{new_handler, BufferedError},
     Compiler,
 };
-use swc_common::{
-    errors::{DiagnosticBuilder, Emitter, Handler, SourceMapperDyn},
-    FileName, FilePathMapping, SourceMap,
-};
+use swc_common::{FileName, FilePathMapping, SourceMap};
 use swc_ecmascript::ast::Program;
 use wasm_bindgen::prelude::*;
 
@@ -53,6 +47,7 @@ pub fn print_sync(s: JsValue, opts: JsValue) -> Result<JsValue, JsValue> {
             opts.source_maps
                 .clone()
                 .unwrap_or(SourceMapsConfig::Bool(false)),
+            &opts.source_map_options(),
             None,
             opts.config.unwrap_or_default().minify.unwrap_or_default(),
         )
@@ -96,47 +91,3 @@ fn codemap() -> Arc<SourceMap> {
 
     CM.clone()
 }
-
-/// Creates a new handler which emits to returned buffer.
-fn new_handler(_cm: Arc<SourceMapperDyn>) -> (Arc<Handler>, BufferedError) {
-    let e = BufferedError::default();
-
-    let handler = Handler::with_emitter(true, false, Box::new(MyEmiter::default()));
-
-    (Arc::new(handler), e)
-}
-
-#[derive(Clone, Default)]
-struct MyEmiter(BufferedError);
-
-impl Emitter for MyEmiter {
-    fn emit(&mut self, db: &DiagnosticBuilder<'_>) {
-        let z = &(self.0).0;
-        for msg in &db.message {
-            z.write().unwrap().push_str(&msg.0);
-        }
-    }
-}
-
-#[derive(Clone, Default)]
-pub(crate) struct BufferedError(Arc<RwLock<String>>);
-
-impl Write for BufferedError {
-    fn write(&mut self, d: &[u8]) -> io::Result<usize> {
-        self.0
-            .write()
-            .unwrap()
-            .push_str(&String::from_utf8_lossy(d));
-
-        Ok(d.len())
-    }
-    fn flush(&mut self) -> io::Result<()> {
-        Ok(())
-    }
-}
-
-impl Display for BufferedError {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        Display::fmt(&self.0.read().unwrap(), f)
-    }
-}